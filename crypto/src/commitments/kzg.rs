@@ -135,6 +135,14 @@ where
     }
 }
 
+/// An opening claim `(x, y, commitment, proof)` stating that the committed polynomial evaluates to `y` at `x`.
+pub type Opening<F, P> = (
+    FieldElement<F>,
+    FieldElement<F>,
+    <P as IsPairing>::G1Point,
+    <P as IsPairing>::G1Point,
+);
+
 #[derive(Clone)]
 pub struct KateZaveruchaGoldberg<F: IsPrimeField, P: IsPairing> {
     srs: StructuredReferenceString<P::G1Point, P::G2Point>,
@@ -150,6 +158,39 @@ impl<F: IsPrimeField, P: IsPairing> KateZaveruchaGoldberg<F, P> {
     }
 }
 
+impl<const N: usize, F: IsPrimeField<RepresentativeType = UnsignedInteger<N>>, P: IsPairing>
+    KateZaveruchaGoldberg<F, P>
+{
+    /// Verifies many openings `(x, y, commitment, proof)` with a single pairing check.
+    /// Each opening satisfies `e(C - y·G₁ + x·π, G₂) = e(π, α·G₂)`, so the equations are
+    /// combined as a random linear combination using `randomness`, which must contain
+    /// one scalar per opening. The caller is responsible for sampling the scalars after
+    /// all the openings are fixed, for example from a transcript.
+    pub fn verify_many(&self, openings: &[Opening<F, P>], randomness: &[FieldElement<F>]) -> bool {
+        if openings.len() != randomness.len() {
+            return false;
+        }
+
+        let g1 = &self.srs.powers_main_group[0];
+        let g2 = &self.srs.powers_secondary_group[0];
+        let alpha_g2 = &self.srs.powers_secondary_group[1];
+
+        let mut acc_y = FieldElement::<F>::zero();
+        let mut acc_lhs = P::G1Point::neutral_element();
+        let mut acc_proof = P::G1Point::neutral_element();
+        for ((x, y, commitment, proof), r) in openings.iter().zip(randomness) {
+            acc_y += r * y;
+            let lhs = commitment.operate_with(&proof.operate_with_self(x.representative()));
+            acc_lhs = acc_lhs.operate_with(&lhs.operate_with_self(r.representative()));
+            acc_proof = acc_proof.operate_with(&proof.operate_with_self(r.representative()));
+        }
+        acc_lhs = acc_lhs.operate_with(&g1.operate_with_self(acc_y.representative()).neg());
+
+        let e = P::compute_batch(&[(&acc_lhs, g2), (&acc_proof.neg(), alpha_g2)]);
+        e == Ok(FieldElement::one())
+    }
+}
+
 impl<const N: usize, F: IsPrimeField<RepresentativeType = UnsignedInteger<N>>, P: IsPairing>
     IsCommitmentScheme<F> for KateZaveruchaGoldberg<F, P>
 {
@@ -401,6 +442,69 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn many_openings_verify_with_a_single_pairing_check() {
+        let kzg = KZG::new(create_srs());
+
+        let p0 = Polynomial::<FrElement>::new(&[FieldElement::from(9000)]);
+        let p1 = Polynomial::<FrElement>::new(&[
+            FieldElement::from(1),
+            FieldElement::from(2),
+            -FieldElement::from(1),
+        ]);
+        let x0 = FieldElement::from(3);
+        let x1 = FieldElement::from(5);
+        let y0 = p0.evaluate(&x0);
+        let y1 = p1.evaluate(&x1);
+
+        let openings = [
+            (
+                x0.clone(),
+                y0.clone(),
+                kzg.commit(&p0),
+                kzg.open(&x0, &y0, &p0),
+            ),
+            (
+                x1.clone(),
+                y1.clone(),
+                kzg.commit(&p1),
+                kzg.open(&x1, &y1, &p1),
+            ),
+        ];
+        let randomness = [FieldElement::from(7), FieldElement::from(11)];
+
+        assert!(kzg.verify_many(&openings, &randomness));
+    }
+
+    #[test]
+    fn many_openings_with_a_wrong_evaluation_do_not_verify() {
+        let kzg = KZG::new(create_srs());
+
+        let p0 = Polynomial::<FrElement>::new(&[FieldElement::from(9000)]);
+        let p1 = Polynomial::<FrElement>::new(&[FieldElement::from(1), FieldElement::from(2)]);
+        let x = FieldElement::from(3);
+        let y0 = p0.evaluate(&x);
+        let y1 = p1.evaluate(&x);
+
+        let openings = [
+            (
+                x.clone(),
+                y0.clone(),
+                kzg.commit(&p0),
+                kzg.open(&x, &y0, &p0),
+            ),
+            (
+                x.clone(),
+                y1.clone() + FieldElement::one(),
+                kzg.commit(&p1),
+                kzg.open(&x, &y1, &p1),
+            ),
+        ];
+        let randomness = [FieldElement::from(7), FieldElement::from(11)];
+
+        assert!(!kzg.verify_many(&openings, &randomness));
+    }
+
     #[test]
     fn serialize_deserialize_srs() {
         let srs = create_srs();
@@ -428,4 +532,53 @@ mod tests {
 
         assert_eq!(srs.powers_main_group.len(), 3);
     }
+
+    #[test]
+    fn kzg_works_over_bls12_377() {
+        use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_377::{
+            curve::BLS12377Curve,
+            default_types::{FrElement, FrField},
+            pairing::BLS12377AtePairing,
+            twist::BLS12377TwistCurve,
+        };
+
+        let toxic_waste = FrElement::from(0x1234_5678_u64);
+        let g1 = BLS12377Curve::generator();
+        let g2 = BLS12377TwistCurve::generator();
+        let powers_main_group: Vec<_> = (0..10)
+            .map(|exponent| {
+                g1.operate_with_self(toxic_waste.pow(exponent as u128).representative())
+            })
+            .collect();
+        let powers_secondary_group = [
+            g2.clone(),
+            g2.operate_with_self(toxic_waste.representative()),
+        ];
+        let srs = StructuredReferenceString::new(&powers_main_group, &powers_secondary_group);
+        let deserialized = StructuredReferenceString::deserialize(&srs.as_bytes()).unwrap();
+        assert_eq!(srs, deserialized);
+        let kzg = KateZaveruchaGoldberg::<FrField, BLS12377AtePairing>::new(srs);
+
+        let x = FrElement::from(3);
+        let p0 = Polynomial::new(&[FrElement::from(9000)]);
+        let p1 = Polynomial::new(&[FrElement::from(1), FrElement::from(2), -FrElement::from(1)]);
+        let y0 = p0.evaluate(&x);
+        let y1 = p1.evaluate(&x);
+
+        let p1_commitment = kzg.commit(&p1);
+        let proof = kzg.open(&x, &y1, &p1);
+        assert!(kzg.verify(&x, &y1, &p1_commitment, &proof));
+        assert!(!kzg.verify(&x, &(y1.clone() + FrElement::one()), &p1_commitment, &proof));
+
+        let upsilon = FrElement::from(5);
+        let batch_proof =
+            kzg.open_batch(&x, &[y0.clone(), y1.clone()], &[p0.clone(), p1], &upsilon);
+        assert!(kzg.verify_batch(
+            &x,
+            &[y0, y1],
+            &[kzg.commit(&p0), p1_commitment],
+            &batch_proof,
+            &upsilon
+        ));
+    }
 }
@@ -26,6 +26,10 @@ impl From<lambdaworks_math::errors::DeserializationError> for SrsFromFileError {
             DeserializationError::InvalidValue => {
                 SrsFromFileError::DeserializationError(DeserializationError::InvalidValue)
             }
+
+            DeserializationError::PointNotInSubgroup => {
+                SrsFromFileError::DeserializationError(DeserializationError::PointNotInSubgroup)
+            }
         }
     }
 }
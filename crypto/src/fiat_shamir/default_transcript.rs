@@ -1,24 +1,36 @@
 use super::is_transcript::IsTranscript;
+use crate::hash::blake3::Blake3;
+use blake2::Blake2s256;
 use core::marker::PhantomData;
 use lambdaworks_math::{
     field::{element::FieldElement, traits::IsField},
     traits::ByteConversion,
 };
-use sha3::{Digest, Keccak256};
+use sha3::{
+    digest::{consts::U32, FixedOutputReset},
+    Digest, Keccak256,
+};
 
-pub struct DefaultTranscript<F: IsField> {
-    hasher: Keccak256,
+/// A transcript that absorbs data into a hash with a 32 byte output. Each sample is the digest of
+/// everything absorbed so far, which is then absorbed itself.
+pub struct HashTranscript<F: IsField, D: Digest> {
+    hasher: D,
     phantom: PhantomData<F>,
 }
 
-impl<F> DefaultTranscript<F>
+pub type DefaultTranscript<F> = HashTranscript<F, Keccak256>;
+pub type Blake2sTranscript<F> = HashTranscript<F, Blake2s256>;
+pub type Blake3Transcript<F> = HashTranscript<F, Blake3>;
+
+impl<F, D> HashTranscript<F, D>
 where
     F: IsField,
     FieldElement<F>: ByteConversion,
+    D: Digest<OutputSize = U32> + FixedOutputReset + Clone,
 {
     pub fn new(data: &[u8]) -> Self {
         let mut res = Self {
-            hasher: Keccak256::new(),
+            hasher: D::new(),
             phantom: PhantomData,
         };
         res.append_bytes(data);
@@ -26,31 +38,32 @@ where
     }
 
     pub fn sample(&mut self) -> [u8; 32] {
-        let mut result_hash = [0_u8; 32];
-        result_hash.copy_from_slice(&self.hasher.finalize_reset());
+        let mut result_hash: [u8; 32] = Digest::finalize_reset(&mut self.hasher).into();
         result_hash.reverse();
-        self.hasher.update(result_hash);
+        Digest::update(&mut self.hasher, result_hash);
         result_hash
     }
 }
 
-impl<F> Default for DefaultTranscript<F>
+impl<F, D> Default for HashTranscript<F, D>
 where
     F: IsField,
     FieldElement<F>: ByteConversion,
+    D: Digest<OutputSize = U32> + FixedOutputReset + Clone,
 {
     fn default() -> Self {
         Self::new(&[])
     }
 }
 
-impl<F> IsTranscript<F> for DefaultTranscript<F>
+impl<F, D> IsTranscript<F> for HashTranscript<F, D>
 where
     F: IsField,
     FieldElement<F>: ByteConversion,
+    D: Digest<OutputSize = U32> + FixedOutputReset + Clone,
 {
     fn append_bytes(&mut self, new_bytes: &[u8]) {
-        self.hasher.update(new_bytes);
+        Digest::update(&mut self.hasher, new_bytes);
     }
 
     fn append_field_element(&mut self, element: &FieldElement<F>) {
@@ -115,4 +128,41 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn blake_transcripts_sample_the_reversed_digest_of_the_appended_data() {
+        let mut blake2s_transcript = Blake2sTranscript::<FrField>::new(b"lambdaworks");
+        let mut expected: [u8; 32] = Blake2s256::digest(b"lambdaworks").into();
+        expected.reverse();
+        assert_eq!(blake2s_transcript.sample(), expected);
+
+        let mut blake3_transcript = Blake3Transcript::<FrField>::new(b"lambdaworks");
+        let mut expected: [u8; 32] = Blake3::digest(b"lambdaworks").into();
+        expected.reverse();
+        assert_eq!(blake3_transcript.sample(), expected);
+
+        blake3_transcript.append_bytes(&[0x01, 0x02]);
+        let mut hasher = Blake3::new();
+        Digest::update(&mut hasher, expected);
+        Digest::update(&mut hasher, [0x01, 0x02]);
+        let mut expected: [u8; 32] = hasher.finalize().into();
+        expected.reverse();
+        assert_eq!(blake3_transcript.sample(), expected);
+    }
+
+    #[test]
+    fn transcripts_with_different_hashes_diverge() {
+        let mut keccak_transcript = DefaultTranscript::<FrField>::new(b"lambdaworks");
+        let mut blake2s_transcript = Blake2sTranscript::<FrField>::new(b"lambdaworks");
+        let mut blake3_transcript = Blake3Transcript::<FrField>::new(b"lambdaworks");
+
+        let samples = [
+            keccak_transcript.sample_field_element(),
+            blake2s_transcript.sample_field_element(),
+            blake3_transcript.sample_field_element(),
+        ];
+        assert_ne!(samples[0], samples[1]);
+        assert_ne!(samples[1], samples[2]);
+        assert_ne!(samples[0], samples[2]);
+    }
 }
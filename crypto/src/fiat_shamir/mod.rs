@@ -1,4 +1,5 @@
 pub mod default_transcript;
 pub mod is_transcript;
+pub mod rpo_transcript;
 #[cfg(feature = "test_fiat_shamir")]
 pub mod test_transcript;
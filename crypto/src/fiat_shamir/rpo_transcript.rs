@@ -0,0 +1,243 @@
+use super::is_transcript::IsTranscript;
+use crate::hash::rescue_prime::{RescuePrimeOptimizedField, RescuePrimeOptimizedOver};
+use alloc::vec::Vec;
+use lambdaworks_math::{field::element::FieldElement, traits::ByteConversion};
+use sha3::{Digest, Keccak256};
+
+/// A transcript based on the Rescue Prime Optimized sponge, which works like Miden's
+/// `RpoRandomCoin`: absorbed elements are added to the first half of the rate, a digest at a time,
+/// and each absorption is followed by a permutation. Sampled elements are read from the rate,
+/// which is permuted again once all of it has been used.
+pub struct RpoTranscript<F: RescuePrimeOptimizedField> {
+    rescue: RescuePrimeOptimizedOver<F>,
+    state: Vec<FieldElement<F>>,
+    /// Index of the next element of the state to be sampled.
+    current: usize,
+    /// Appended elements that have not been absorbed yet.
+    pending: Vec<FieldElement<F>>,
+}
+
+impl<F> RpoTranscript<F>
+where
+    F: RescuePrimeOptimizedField,
+    FieldElement<F>: ByteConversion,
+{
+    /// Creates a transcript seeded with the given elements, like `RpoRandomCoin::new`.
+    pub fn new(seed: &[FieldElement<F>]) -> Self {
+        let rescue = RescuePrimeOptimizedOver::<F>::default();
+        let capacity = rescue.capacity();
+        let state = vec![FieldElement::zero(); capacity + rescue.rate()];
+        let mut transcript = Self {
+            rescue,
+            state,
+            current: capacity,
+            pending: Vec::new(),
+        };
+        transcript.reseed(seed);
+        transcript
+    }
+
+    /// Absorbs the elements in chunks of half the rate, like `RpoRandomCoin::reseed`, and
+    /// restarts sampling from the beginning of the rate.
+    pub fn reseed(&mut self, data: &[FieldElement<F>]) {
+        let capacity = self.rescue.capacity();
+        let half_rate = self.rescue.rate() / 2;
+        let mut data = data.to_vec();
+        data.resize(
+            data.len().div_ceil(half_rate).max(1) * half_rate,
+            FieldElement::zero(),
+        );
+
+        for chunk in data.chunks(half_rate) {
+            for (value, input) in self.state[capacity..].iter_mut().zip(chunk) {
+                *value = &*value + input;
+            }
+            self.rescue.permutation(&mut self.state);
+        }
+        self.current = capacity;
+    }
+
+    fn absorb_pending(&mut self) {
+        if !self.pending.is_empty() {
+            let pending = core::mem::take(&mut self.pending);
+            self.reseed(&pending);
+        }
+    }
+}
+
+impl<F> Default for RpoTranscript<F>
+where
+    F: RescuePrimeOptimizedField,
+    FieldElement<F>: ByteConversion,
+{
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+impl<F> IsTranscript<F> for RpoTranscript<F>
+where
+    F: RescuePrimeOptimizedField,
+    FieldElement<F>: ByteConversion,
+{
+    /// Elements are absorbed once there are enough of them to fill half of the rate, or before
+    /// the next sample.
+    fn append_field_element(&mut self, element: &FieldElement<F>) {
+        self.pending.push(element.clone());
+        if self.pending.len() == self.rescue.rate() / 2 {
+            self.absorb_pending();
+        }
+    }
+
+    /// The bytes are hashed, and their digest is absorbed.
+    fn append_bytes(&mut self, new_bytes: &[u8]) {
+        self.absorb_pending();
+        let digest = self.rescue.hash_bytes(new_bytes);
+        self.reseed(&digest);
+    }
+
+    fn state(&self) -> [u8; 32] {
+        let mut hasher = Keccak256::new();
+        for element in self.state.iter().chain(&self.pending) {
+            hasher.update(element.to_bytes_be());
+        }
+        hasher.update((self.current as u64).to_be_bytes());
+        hasher.finalize().into()
+    }
+
+    fn sample_field_element(&mut self) -> FieldElement<F> {
+        self.absorb_pending();
+        if self.current == self.state.len() {
+            self.rescue.permutation(&mut self.state);
+            self.current = self.rescue.capacity();
+        }
+        self.current += 1;
+        self.state[self.current - 1].clone()
+    }
+
+    /// Samples an integer in `[0, upper_bound)` like Miden's `draw_integers`: the least
+    /// significant bits of a sampled element are masked to the bit length of `upper_bound - 1`,
+    /// so power of two bounds give the same integers as Miden. Masked values that are not smaller
+    /// than `upper_bound` are rejected, and new elements are sampled until one is accepted.
+    ///
+    /// It panics if `upper_bound` is zero, or if it needs as many bits as the elements of the
+    /// field, since then some integers could never be sampled.
+    fn sample_u64(&mut self, upper_bound: u64) -> u64 {
+        assert!(upper_bound > 0, "the upper bound must be positive");
+        let bits = u64::BITS - (upper_bound - 1).leading_zeros();
+        assert!(
+            (bits as usize) < F::field_bit_size(),
+            "the upper bound must have fewer bits than the field elements"
+        );
+        let mask = u64::MAX.checked_shr(u64::BITS - bits).unwrap_or(0);
+
+        loop {
+            let bytes = self.sample_field_element().to_bytes_be();
+            let value = bytes[bytes.len().saturating_sub(8)..]
+                .iter()
+                .fold(0u64, |acc, byte| (acc << 8) | u64::from(*byte));
+            let value = value & mask;
+            if value < upper_bound {
+                return value;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::rescue_prime::Fp;
+    use lambdaworks_math::field::fields::{
+        fft_friendly::babybear::Babybear31PrimeField, u64_goldilocks_field::Goldilocks64Field,
+    };
+
+    #[test]
+    fn samples_are_read_from_the_rate_of_the_seeded_state() {
+        let seed: Vec<Fp> = (1..5).map(Fp::from).collect();
+        let mut transcript = RpoTranscript::<Goldilocks64Field>::new(&seed);
+
+        let rescue = RescuePrimeOptimizedOver::<Goldilocks64Field>::default();
+        let mut state = vec![Fp::zero(); 12];
+        state[4..8].copy_from_slice(&seed);
+        rescue.permutation(&mut state);
+        let mut expected = state[4..].to_vec();
+        rescue.permutation(&mut state);
+        expected.push(state[4]);
+
+        let samples: Vec<Fp> = (0..9).map(|_| transcript.sample_field_element()).collect();
+        assert_eq!(samples, expected);
+    }
+
+    #[test]
+    fn appending_a_digest_is_reseeding_with_it() {
+        let digest: Vec<Fp> = (10..14).map(Fp::from).collect();
+        let mut appended = RpoTranscript::<Goldilocks64Field>::default();
+        let mut reseeded = RpoTranscript::<Goldilocks64Field>::default();
+
+        appended.sample_field_element();
+        reseeded.sample_field_element();
+        digest
+            .iter()
+            .for_each(|element| appended.append_field_element(element));
+        reseeded.reseed(&digest);
+
+        assert_eq!(appended.state(), reseeded.state());
+        assert_eq!(
+            appended.sample_field_element(),
+            reseeded.sample_field_element()
+        );
+    }
+
+    #[test]
+    fn transcripts_with_different_data_diverge() {
+        let mut transcript_1 = RpoTranscript::<Babybear31PrimeField>::default();
+        let mut transcript_2 = RpoTranscript::<Babybear31PrimeField>::default();
+
+        transcript_1.append_bytes(b"lambdaworks");
+        transcript_2.append_bytes(b"lambdaworks");
+        assert_eq!(transcript_1.state(), transcript_2.state());
+
+        transcript_1.append_field_element(&FieldElement::from(1));
+        transcript_2.append_field_element(&FieldElement::from(2));
+        assert_ne!(
+            transcript_1.sample_field_element(),
+            transcript_2.sample_field_element()
+        );
+        assert!(transcript_1.sample_u64(10) < 10);
+    }
+
+    #[test]
+    fn integers_with_a_power_of_two_bound_are_the_masked_samples() {
+        let mut transcript = RpoTranscript::<Goldilocks64Field>::new(&[Fp::from(7)]);
+        let mut sampled = RpoTranscript::<Goldilocks64Field>::new(&[Fp::from(7)]);
+
+        for _ in 0..10 {
+            let value = sampled.sample_field_element().representative();
+            assert_eq!(transcript.sample_u64(1 << 20), value & ((1 << 20) - 1));
+        }
+        assert_eq!(transcript.sample_u64(1), 0);
+    }
+
+    #[test]
+    fn integers_are_sampled_below_any_bound() {
+        let mut transcript = RpoTranscript::<Babybear31PrimeField>::default();
+        let mut seen = [false; 5];
+        for _ in 0..100 {
+            seen[transcript.sample_u64(5) as usize] = true;
+        }
+        assert!(seen.iter().all(|seen| *seen));
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_bound_is_rejected() {
+        RpoTranscript::<Goldilocks64Field>::default().sample_u64(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bounds_as_wide_as_the_field_are_rejected() {
+        RpoTranscript::<Babybear31PrimeField>::default().sample_u64(1 << 31);
+    }
+}
@@ -0,0 +1,72 @@
+use sha3::digest::{
+    consts::U32, FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+/// BLAKE3 with a 32 byte output, exposed through the `digest` traits so that it can be used
+/// wherever a `Digest` is expected, like the Merkle tree backends and the transcripts.
+/// The `blake3` crate only implements these traits behind an unstable feature, whose `digest`
+/// version has changed between patch releases.
+#[derive(Clone, Default)]
+pub struct Blake3 {
+    hasher: ::blake3::Hasher,
+}
+
+impl HashMarker for Blake3 {}
+
+impl OutputSizeUser for Blake3 {
+    type OutputSize = U32;
+}
+
+impl Update for Blake3 {
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+}
+
+impl FixedOutput for Blake3 {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(self.hasher.finalize().as_bytes());
+    }
+}
+
+impl Reset for Blake3 {
+    fn reset(&mut self) {
+        self.hasher.reset();
+    }
+}
+
+impl FixedOutputReset for Blake3 {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        out.copy_from_slice(self.hasher.finalize().as_bytes());
+        self.hasher.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha3::Digest;
+
+    #[test]
+    fn blake3_of_empty_input() {
+        let digest: [u8; 32] = Blake3::digest(b"").into();
+        assert_eq!(
+            digest,
+            [
+                0xaf, 0x13, 0x49, 0xb9, 0xf5, 0xf9, 0xa1, 0xa6, 0xa0, 0x40, 0x4d, 0xea, 0x36, 0xdc,
+                0xc9, 0x49, 0x9b, 0xcb, 0x25, 0xc9, 0xad, 0xc1, 0x12, 0xb7, 0xcc, 0x9a, 0x93, 0xca,
+                0xe4, 0x1f, 0x32, 0x62
+            ]
+        );
+    }
+
+    #[test]
+    fn finalize_reset_starts_a_new_hash() {
+        let mut hasher = Blake3::new();
+        Digest::update(&mut hasher, b"lambdaworks");
+        let first = hasher.finalize_reset();
+        Digest::update(&mut hasher, b"lambdaworks");
+        assert_eq!(first, hasher.finalize());
+        assert_eq!(first, Blake3::digest(b"lambdaworks"));
+    }
+}
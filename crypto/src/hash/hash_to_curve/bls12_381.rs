@@ -0,0 +1,493 @@
+//! The `BLS12381G1_XMD:SHA-256_SSWU_RO_`, `BLS12381G1_XMD:SHA-256_SSWU_NU_`,
+//! `BLS12381G2_XMD:SHA-256_SSWU_RO_` and `BLS12381G2_XMD:SHA-256_SSWU_NU_` suites of RFC 9380.
+//!
+//! Both curves have `a = 0`, so the simplified SWU map is applied to an isogenous curve
+//! `E'` and the result is moved to the curve with the isogeny.
+
+use super::{
+    isogeny_map, map_to_curve_simple_swu, os2ip_mod_p, prime_field_sgn0, IsHashToCurve,
+    IsHashToCurveField,
+};
+use lambdaworks_math::{
+    elliptic_curve::short_weierstrass::{
+        curves::bls12_381::{
+            curve::{BLS12381Curve, BLS12381FieldElement, BLS12381TwistCurveFieldElement},
+            field_extension::{BLS12381PrimeField, Degree2ExtensionField},
+            sqrt::sqrt_qfe,
+            twist::BLS12381TwistCurve,
+        },
+        point::ShortWeierstrassProjectivePoint,
+    },
+    field::element::FieldElement,
+};
+
+/// `A'` of the curve 11-isogenous to G1.
+const G1_ISO_A: BLS12381FieldElement = FieldElement::from_hex_unchecked(
+    "144698a3b8e9433d693a02c96d4982b0ea985383ee66a8d8e8981aefd881ac98936f8da0e0f97f5cf428082d584c1d",
+);
+/// `B'` of the curve 11-isogenous to G1.
+const G1_ISO_B: BLS12381FieldElement = FieldElement::from_hex_unchecked(
+    "12e2908d11688030018b12e8753eee3b2016c1f0f24f4070a0b9c14fcef35ef55a23215a316ceaa5d1cc48e98e172be0",
+);
+/// `Z` of the G1 suites.
+const G1_Z: BLS12381FieldElement = FieldElement::from_hex_unchecked("b");
+
+/// `A' = 240 * i` of the curve 3-isogenous to G2.
+const G2_ISO_A: BLS12381TwistCurveFieldElement = BLS12381TwistCurveFieldElement::const_from_raw([
+    FieldElement::from_hex_unchecked("0"),
+    FieldElement::from_hex_unchecked("f0"),
+]);
+/// `B' = 1012 * (1 + i)` of the curve 3-isogenous to G2.
+const G2_ISO_B: BLS12381TwistCurveFieldElement = BLS12381TwistCurveFieldElement::const_from_raw([
+    FieldElement::from_hex_unchecked("3f4"),
+    FieldElement::from_hex_unchecked("3f4"),
+]);
+/// `Z = -(2 + i)` of the G2 suites.
+const G2_Z: BLS12381TwistCurveFieldElement = BLS12381TwistCurveFieldElement::const_from_raw([
+    FieldElement::from_hex_unchecked(
+        "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaa9",
+    ),
+    FieldElement::from_hex_unchecked(
+        "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaaa",
+    ),
+]);
+
+/// Coefficients of the numerator of the x-coordinate of the isogeny, lowest degree first.
+const G1_ISO_X_NUMERATOR: [BLS12381FieldElement; 12] = [
+    FieldElement::from_hex_unchecked("11a05f2b1e833340b809101dd99815856b303e88a2d7005ff2627b56cdb4e2c85610c2d5f2e62d6eaeac1662734649b7"),
+    FieldElement::from_hex_unchecked("17294ed3e943ab2f0588bab22147a81c7c17e75b2f6a8417f565e33c70d1e86b4838f2a6f318c356e834eef1b3cb83bb"),
+    FieldElement::from_hex_unchecked("0d54005db97678ec1d1048c5d10a9a1bce032473295983e56878e501ec68e25c958c3e3d2a09729fe0179f9dac9edcb0"),
+    FieldElement::from_hex_unchecked("1778e7166fcc6db74e0609d307e55412d7f5e4656a8dbf25f1b33289f1b330835336e25ce3107193c5b388641d9b6861"),
+    FieldElement::from_hex_unchecked("0e99726a3199f4436642b4b3e4118e5499db995a1257fb3f086eeb65982fac18985a286f301e77c451154ce9ac8895d9"),
+    FieldElement::from_hex_unchecked("1630c3250d7313ff01d1201bf7a74ab5db3cb17dd952799b9ed3ab9097e68f90a0870d2dcae73d19cd13c1c66f652983"),
+    FieldElement::from_hex_unchecked("0d6ed6553fe44d296a3726c38ae652bfb11586264f0f8ce19008e218f9c86b2a8da25128c1052ecaddd7f225a139ed84"),
+    FieldElement::from_hex_unchecked("17b81e7701abdbe2e8743884d1117e53356de5ab275b4db1a682c62ef0f2753339b7c8f8c8f475af9ccb5618e3f0c88e"),
+    FieldElement::from_hex_unchecked("080d3cf1f9a78fc47b90b33563be990dc43b756ce79f5574a2c596c928c5d1de4fa295f296b74e956d71986a8497e317"),
+    FieldElement::from_hex_unchecked("169b1f8e1bcfa7c42e0c37515d138f22dd2ecb803a0c5c99676314baf4bb1b7fa3190b2edc0327797f241067be390c9e"),
+    FieldElement::from_hex_unchecked("10321da079ce07e272d8ec09d2565b0dfa7dccdde6787f96d50af36003b14866f69b771f8c285decca67df3f1605fb7b"),
+    FieldElement::from_hex_unchecked("06e08c248e260e70bd1e962381edee3d31d79d7e22c837bc23c0bf1bc24c6b68c24b1b80b64d391fa9c8ba2e8ba2d229"),
+];
+
+/// Coefficients of the denominator of the x-coordinate of the isogeny, lowest degree first.
+const G1_ISO_X_DENOMINATOR: [BLS12381FieldElement; 11] = [
+    FieldElement::from_hex_unchecked("08ca8d548cff19ae18b2e62f4bd3fa6f01d5ef4ba35b48ba9c9588617fc8ac62b558d681be343df8993cf9fa40d21b1c"),
+    FieldElement::from_hex_unchecked("12561a5deb559c4348b4711298e536367041e8ca0cf0800c0126c2588c48bf5713daa8846cb026e9e5c8276ec82b3bff"),
+    FieldElement::from_hex_unchecked("0b2962fe57a3225e8137e629bff2991f6f89416f5a718cd1fca64e00b11aceacd6a3d0967c94fedcfcc239ba5cb83e19"),
+    FieldElement::from_hex_unchecked("03425581a58ae2fec83aafef7c40eb545b08243f16b1655154cca8abc28d6fd04976d5243eecf5c4130de8938dc62cd8"),
+    FieldElement::from_hex_unchecked("13a8e162022914a80a6f1d5f43e7a07dffdfc759a12062bb8d6b44e833b306da9bd29ba81f35781d539d395b3532a21e"),
+    FieldElement::from_hex_unchecked("0e7355f8e4e667b955390f7f0506c6e9395735e9ce9cad4d0a43bcef24b8982f7400d24bc4228f11c02df9a29f6304a5"),
+    FieldElement::from_hex_unchecked("0772caacf16936190f3e0c63e0596721570f5799af53a1894e2e073062aede9cea73b3538f0de06cec2574496ee84a3a"),
+    FieldElement::from_hex_unchecked("14a7ac2a9d64a8b230b3f5b074cf01996e7f63c21bca68a81996e1cdf9822c580fa5b9489d11e2d311f7d99bbdcc5a5e"),
+    FieldElement::from_hex_unchecked("0a10ecf6ada54f825e920b3dafc7a3cce07f8d1d7161366b74100da67f39883503826692abba43704776ec3a79a1d641"),
+    FieldElement::from_hex_unchecked("095fc13ab9e92ad4476d6e3eb3a56680f682b4ee96f7d03776df533978f31c1593174e4b4b7865002d6384d168ecdd0a"),
+    FieldElement::from_hex_unchecked("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001"),
+];
+
+/// Coefficients of the numerator of the y-coordinate of the isogeny, lowest degree first.
+const G1_ISO_Y_NUMERATOR: [BLS12381FieldElement; 16] = [
+    FieldElement::from_hex_unchecked("090d97c81ba24ee0259d1f094980dcfa11ad138e48a869522b52af6c956543d3cd0c7aee9b3ba3c2be9845719707bb33"),
+    FieldElement::from_hex_unchecked("134996a104ee5811d51036d776fb46831223e96c254f383d0f906343eb67ad34d6c56711962fa8bfe097e75a2e41c696"),
+    FieldElement::from_hex_unchecked("00cc786baa966e66f4a384c86a3b49942552e2d658a31ce2c344be4b91400da7d26d521628b00523b8dfe240c72de1f6"),
+    FieldElement::from_hex_unchecked("01f86376e8981c217898751ad8746757d42aa7b90eeb791c09e4a3ec03251cf9de405aba9ec61deca6355c77b0e5f4cb"),
+    FieldElement::from_hex_unchecked("08cc03fdefe0ff135caf4fe2a21529c4195536fbe3ce50b879833fd221351adc2ee7f8dc099040a841b6daecf2e8fedb"),
+    FieldElement::from_hex_unchecked("16603fca40634b6a2211e11db8f0a6a074a7d0d4afadb7bd76505c3d3ad5544e203f6326c95a807299b23ab13633a5f0"),
+    FieldElement::from_hex_unchecked("04ab0b9bcfac1bbcb2c977d027796b3ce75bb8ca2be184cb5231413c4d634f3747a87ac2460f415ec961f8855fe9d6f2"),
+    FieldElement::from_hex_unchecked("0987c8d5333ab86fde9926bd2ca6c674170a05bfe3bdd81ffd038da6c26c842642f64550fedfe935a15e4ca31870fb29"),
+    FieldElement::from_hex_unchecked("09fc4018bd96684be88c9e221e4da1bb8f3abd16679dc26c1e8b6e6a1f20cabe69d65201c78607a360370e577bdba587"),
+    FieldElement::from_hex_unchecked("0e1bba7a1186bdb5223abde7ada14a23c42a0ca7915af6fe06985e7ed1e4d43b9b3f7055dd4eba6f2bafaaebca731c30"),
+    FieldElement::from_hex_unchecked("19713e47937cd1be0dfd0b8f1d43fb93cd2fcbcb6caf493fd1183e416389e61031bf3a5cce3fbafce813711ad011c132"),
+    FieldElement::from_hex_unchecked("18b46a908f36f6deb918c143fed2edcc523559b8aaf0c2462e6bfe7f911f643249d9cdf41b44d606ce07c8a4d0074d8e"),
+    FieldElement::from_hex_unchecked("0b182cac101b9399d155096004f53f447aa7b12a3426b08ec02710e807b4633f06c851c1919211f20d4c04f00b971ef8"),
+    FieldElement::from_hex_unchecked("0245a394ad1eca9b72fc00ae7be315dc757b3b080d4c158013e6632d3c40659cc6cf90ad1c232a6442d9d3f5db980133"),
+    FieldElement::from_hex_unchecked("05c129645e44cf1102a159f748c4a3fc5e673d81d7e86568d9ab0f5d396a7ce46ba1049b6579afb7866b1e715475224b"),
+    FieldElement::from_hex_unchecked("15e6be4e990f03ce4ea50b3b42df2eb5cb181d8f84965a3957add4fa95af01b2b665027efec01c7704b456be69c8b604"),
+];
+
+/// Coefficients of the denominator of the y-coordinate of the isogeny, lowest degree first.
+const G1_ISO_Y_DENOMINATOR: [BLS12381FieldElement; 16] = [
+    FieldElement::from_hex_unchecked("16112c4c3a9c98b252181140fad0eae9601a6de578980be6eec3232b5be72e7a07f3688ef60c206d01479253b03663c1"),
+    FieldElement::from_hex_unchecked("1962d75c2381201e1a0cbd6c43c348b885c84ff731c4d59ca4a10356f453e01f78a4260763529e3532f6102c2e49a03d"),
+    FieldElement::from_hex_unchecked("058df3306640da276faaae7d6e8eb15778c4855551ae7f310c35a5dd279cd2eca6757cd636f96f891e2538b53dbf67f2"),
+    FieldElement::from_hex_unchecked("16b7d288798e5395f20d23bf89edb4d1d115c5dbddbcd30e123da489e726af41727364f2c28297ada8d26d98445f5416"),
+    FieldElement::from_hex_unchecked("0be0e079545f43e4b00cc912f8228ddcc6d19c9f0f69bbb0542eda0fc9dec916a20b15dc0fd2ededda39142311a5001d"),
+    FieldElement::from_hex_unchecked("08d9e5297186db2d9fb266eaac783182b70152c65550d881c5ecd87b6f0f5a6449f38db9dfa9cce202c6477faaf9b7ac"),
+    FieldElement::from_hex_unchecked("166007c08a99db2fc3ba8734ace9824b5eecfdfa8d0cf8ef5dd365bc400a0051d5fa9c01a58b1fb93d1a1399126a775c"),
+    FieldElement::from_hex_unchecked("16a3ef08be3ea7ea03bcddfabba6ff6ee5a4375efa1f4fd7feb34fd206357132b920f5b00801dee460ee415a15812ed9"),
+    FieldElement::from_hex_unchecked("1866c8ed336c61231a1be54fd1d74cc4f9fb0ce4c6af5920abc5750c4bf39b4852cfe2f7bb9248836b233d9d55535d4a"),
+    FieldElement::from_hex_unchecked("167a55cda70a6e1cea820597d94a84903216f763e13d87bb5308592e7ea7d4fbc7385ea3d529b35e346ef48bb8913f55"),
+    FieldElement::from_hex_unchecked("04d2f259eea405bd48f010a01ad2911d9c6dd039bb61a6290e591b36e636a5c871a5c29f4f83060400f8b49cba8f6aa8"),
+    FieldElement::from_hex_unchecked("0accbb67481d033ff5852c1e48c50c477f94ff8aefce42d28c0f9a88cea7913516f968986f7ebbea9684b529e2561092"),
+    FieldElement::from_hex_unchecked("0ad6b9514c767fe3c3613144b45f1496543346d98adf02267d5ceef9a00d9b8693000763e3b90ac11e99b138573345cc"),
+    FieldElement::from_hex_unchecked("02660400eb2e4f3b628bdd0d53cd76f2bf565b94e72927c1cb748df27942480e420517bd8714cc80d1fadc1326ed06f7"),
+    FieldElement::from_hex_unchecked("0e0fa1d816ddc03e6b24255e0d7819c171c40f65e273b853324efcd6356caa205ca2f570f13497804415473a1d634b8f"),
+    FieldElement::from_hex_unchecked("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001"),
+];
+
+/// Coefficients of the numerator of the x-coordinate of the isogeny, lowest degree first.
+const G2_ISO_X_NUMERATOR: [BLS12381TwistCurveFieldElement; 4] = [
+    BLS12381TwistCurveFieldElement::const_from_raw([
+        FieldElement::from_hex_unchecked("05c759507e8e333ebb5b7a9a47d7ed8532c52d39fd3a042a88b58423c50ae15d5c2638e343d9c71c6238aaaaaaaa97d6"),
+        FieldElement::from_hex_unchecked("05c759507e8e333ebb5b7a9a47d7ed8532c52d39fd3a042a88b58423c50ae15d5c2638e343d9c71c6238aaaaaaaa97d6"),
+    ]),
+    BLS12381TwistCurveFieldElement::const_from_raw([
+        FieldElement::from_hex_unchecked("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        FieldElement::from_hex_unchecked("11560bf17baa99bc32126fced787c88f984f87adf7ae0c7f9a208c6b4f20a4181472aaa9cb8d555526a9ffffffffc71a"),
+    ]),
+    BLS12381TwistCurveFieldElement::const_from_raw([
+        FieldElement::from_hex_unchecked("11560bf17baa99bc32126fced787c88f984f87adf7ae0c7f9a208c6b4f20a4181472aaa9cb8d555526a9ffffffffc71e"),
+        FieldElement::from_hex_unchecked("08ab05f8bdd54cde190937e76bc3e447cc27c3d6fbd7063fcd104635a790520c0a395554e5c6aaaa9354ffffffffe38d"),
+    ]),
+    BLS12381TwistCurveFieldElement::const_from_raw([
+        FieldElement::from_hex_unchecked("171d6541fa38ccfaed6dea691f5fb614cb14b4e7f4e810aa22d6108f142b85757098e38d0f671c7188e2aaaaaaaa5ed1"),
+        FieldElement::from_hex_unchecked("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+    ]),
+];
+
+/// Coefficients of the denominator of the x-coordinate of the isogeny, lowest degree first.
+const G2_ISO_X_DENOMINATOR: [BLS12381TwistCurveFieldElement; 3] = [
+    BLS12381TwistCurveFieldElement::const_from_raw([
+        FieldElement::from_hex_unchecked("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        FieldElement::from_hex_unchecked("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaa63"),
+    ]),
+    BLS12381TwistCurveFieldElement::const_from_raw([
+        FieldElement::from_hex_unchecked("00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000c"),
+        FieldElement::from_hex_unchecked("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaa9f"),
+    ]),
+    BLS12381TwistCurveFieldElement::const_from_raw([
+        FieldElement::from_hex_unchecked("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001"),
+        FieldElement::from_hex_unchecked("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+    ]),
+];
+
+/// Coefficients of the numerator of the y-coordinate of the isogeny, lowest degree first.
+const G2_ISO_Y_NUMERATOR: [BLS12381TwistCurveFieldElement; 4] = [
+    BLS12381TwistCurveFieldElement::const_from_raw([
+        FieldElement::from_hex_unchecked("1530477c7ab4113b59a4c18b076d11930f7da5d4a07f649bf54439d87d27e500fc8c25ebf8c92f6812cfc71c71c6d706"),
+        FieldElement::from_hex_unchecked("1530477c7ab4113b59a4c18b076d11930f7da5d4a07f649bf54439d87d27e500fc8c25ebf8c92f6812cfc71c71c6d706"),
+    ]),
+    BLS12381TwistCurveFieldElement::const_from_raw([
+        FieldElement::from_hex_unchecked("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        FieldElement::from_hex_unchecked("05c759507e8e333ebb5b7a9a47d7ed8532c52d39fd3a042a88b58423c50ae15d5c2638e343d9c71c6238aaaaaaaa97be"),
+    ]),
+    BLS12381TwistCurveFieldElement::const_from_raw([
+        FieldElement::from_hex_unchecked("11560bf17baa99bc32126fced787c88f984f87adf7ae0c7f9a208c6b4f20a4181472aaa9cb8d555526a9ffffffffc71c"),
+        FieldElement::from_hex_unchecked("08ab05f8bdd54cde190937e76bc3e447cc27c3d6fbd7063fcd104635a790520c0a395554e5c6aaaa9354ffffffffe38f"),
+    ]),
+    BLS12381TwistCurveFieldElement::const_from_raw([
+        FieldElement::from_hex_unchecked("124c9ad43b6cf79bfbf7043de3811ad0761b0f37a1e26286b0e977c69aa274524e79097a56dc4bd9e1b371c71c718b10"),
+        FieldElement::from_hex_unchecked("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+    ]),
+];
+
+/// Coefficients of the denominator of the y-coordinate of the isogeny, lowest degree first.
+const G2_ISO_Y_DENOMINATOR: [BLS12381TwistCurveFieldElement; 4] = [
+    BLS12381TwistCurveFieldElement::const_from_raw([
+        FieldElement::from_hex_unchecked("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffa8fb"),
+        FieldElement::from_hex_unchecked("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffa8fb"),
+    ]),
+    BLS12381TwistCurveFieldElement::const_from_raw([
+        FieldElement::from_hex_unchecked("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+        FieldElement::from_hex_unchecked("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffa9d3"),
+    ]),
+    BLS12381TwistCurveFieldElement::const_from_raw([
+        FieldElement::from_hex_unchecked("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000012"),
+        FieldElement::from_hex_unchecked("1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaa99"),
+    ]),
+    BLS12381TwistCurveFieldElement::const_from_raw([
+        FieldElement::from_hex_unchecked("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001"),
+        FieldElement::from_hex_unchecked("000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000"),
+    ]),
+];
+
+impl IsHashToCurveField for BLS12381PrimeField {
+    fn sqrt(a: &FieldElement<Self>) -> Option<FieldElement<Self>> {
+        a.sqrt().map(|(root, _)| root)
+    }
+
+    fn sgn0(a: &FieldElement<Self>) -> bool {
+        prime_field_sgn0(a)
+    }
+}
+
+impl IsHashToCurveField for Degree2ExtensionField {
+    fn sqrt(a: &FieldElement<Self>) -> Option<FieldElement<Self>> {
+        sqrt_qfe(a, 0)
+    }
+
+    fn sgn0(a: &FieldElement<Self>) -> bool {
+        let [a0, a1] = a.value();
+        prime_field_sgn0(a0) || (*a0 == FieldElement::zero() && prime_field_sgn0(a1))
+    }
+}
+
+impl IsHashToCurve for BLS12381Curve {
+    const UNIFORM_BYTES_PER_ELEMENT: usize = 64;
+
+    fn field_element_from_uniform_bytes(bytes: &[u8]) -> BLS12381FieldElement {
+        os2ip_mod_p(bytes)
+    }
+
+    fn map_to_curve(u: &BLS12381FieldElement) -> ShortWeierstrassProjectivePoint<Self> {
+        isogeny_map(
+            &map_to_curve_simple_swu(u, &G1_ISO_A, &G1_ISO_B, &G1_Z),
+            [
+                &G1_ISO_X_NUMERATOR,
+                &G1_ISO_X_DENOMINATOR,
+                &G1_ISO_Y_NUMERATOR,
+                &G1_ISO_Y_DENOMINATOR,
+            ],
+        )
+    }
+}
+
+impl IsHashToCurve for BLS12381TwistCurve {
+    const UNIFORM_BYTES_PER_ELEMENT: usize = 64;
+    const EXTENSION_DEGREE: usize = 2;
+
+    fn field_element_from_uniform_bytes(bytes: &[u8]) -> BLS12381TwistCurveFieldElement {
+        let (a0, a1) = bytes.split_at(Self::UNIFORM_BYTES_PER_ELEMENT);
+        BLS12381TwistCurveFieldElement::new([os2ip_mod_p(a0), os2ip_mod_p(a1)])
+    }
+
+    fn map_to_curve(u: &BLS12381TwistCurveFieldElement) -> ShortWeierstrassProjectivePoint<Self> {
+        isogeny_map(
+            &map_to_curve_simple_swu(u, &G2_ISO_A, &G2_ISO_B, &G2_Z),
+            [
+                &G2_ISO_X_NUMERATOR,
+                &G2_ISO_X_DENOMINATOR,
+                &G2_ISO_Y_NUMERATOR,
+                &G2_ISO_Y_DENOMINATOR,
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::hash_to_curve::tests::with_rfc_messages;
+
+    const G1_RO_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_RO_";
+    const G1_NU_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G1_XMD:SHA-256_SSWU_NU_";
+    const G2_RO_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_RO_";
+    const G2_NU_DST: &[u8] = b"QUUX-V01-CS02-with-BLS12381G2_XMD:SHA-256_SSWU_NU_";
+
+    // RFC 9380 Appendix J.9.1
+    const G1_RO_VECTORS: [(&str, &str); 5] = [
+        (
+            "052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1",
+            "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265",
+        ),
+        (
+            "03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903",
+            "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d",
+        ),
+        (
+            "11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98",
+            "03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709",
+        ),
+        (
+            "15f68eaa693b95ccb85215dc65fa81038d69629f70aeee0d0f677cf22285e7bf58d7cb86eefe8f2e9bc3f8cb84fac488",
+            "1807a1d50c29f430b8cafc4f8638dfeeadf51211e1602a5f184443076715f91bb90a48ba1e370edce6ae1062f5e6dd38",
+        ),
+        (
+            "082aabae8b7dedb0e78aeb619ad3bfd9277a2f77ba7fad20ef6aabdc6c31d19ba5a6d12283553294c1825c4b3ca2dcfe",
+            "05b84ae5a942248eea39e1d91030458c40153f3b654ab7872d779ad1e942856a20c438e8d99bc8abfbf74729ce1f7ac8",
+        ),
+    ];
+
+    // RFC 9380 Appendix J.9.2
+    const G1_NU_VECTORS: [(&str, &str); 5] = [
+        (
+            "184bb665c37ff561a89ec2122dd343f20e0f4cbcaec84e3c3052ea81d1834e192c426074b02ed3dca4e7676ce4ce48ba",
+            "04407b8d35af4dacc809927071fc0405218f1401a6d15af775810e4e460064bcc9468beeba82fdc751be70476c888bf3",
+        ),
+        (
+            "009769f3ab59bfd551d53a5f846b9984c59b97d6842b20a2c565baa167945e3d026a3755b6345df8ec7e6acb6868ae6d",
+            "1532c00cf61aa3d0ce3e5aa20c3b531a2abd2c770a790a2613818303c6b830ffc0ecf6c357af3317b9575c567f11cd2c",
+        ),
+        (
+            "1974dbb8e6b5d20b84df7e625e2fbfecb2cdb5f77d5eae5fb2955e5ce7313cae8364bc2fff520a6c25619739c6bdcb6a",
+            "15f9897e11c6441eaa676de141c8d83c37aab8667173cbe1dfd6de74d11861b961dccebcd9d289ac633455dfcc7013a3",
+        ),
+        (
+            "0a7a047c4a8397b3446450642c2ac64d7239b61872c9ae7a59707a8f4f950f101e766afe58223b3bff3a19a7f754027c",
+            "1383aebba1e4327ccff7cf9912bda0dbc77de048b71ef8c8a81111d71dc33c5e3aa6edee9cf6f5fe525d50cc50b77cc9",
+        ),
+        (
+            "0e7a16a975904f131682edbb03d9560d3e48214c9986bd50417a77108d13dc957500edf96462a3d01e62dc6cd468ef11",
+            "0ae89e677711d05c30a48d6d75e76ca9fb70fe06c6dd6ff988683d89ccde29ac7d46c53bb97a59b1901abf1db66052db",
+        ),
+    ];
+
+    // RFC 9380 Appendix J.10.1
+    const G2_RO_VECTORS: [([&str; 2], [&str; 2]); 5] = [
+        (
+            [
+                "0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a",
+                "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d",
+            ],
+            [
+                "0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92",
+                "12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6",
+            ],
+        ),
+        (
+            [
+                "02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6",
+                "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8",
+            ],
+            [
+                "1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48",
+                "00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16",
+            ],
+        ),
+        (
+            [
+                "121982811d2491fde9ba7ed31ef9ca474f0e1501297f68c298e9f4c0028add35aea8bb83d53c08cfc007c1e005723cd0",
+                "190d119345b94fbd15497bcba94ecf7db2cbfd1e1fe7da034d26cbba169fb3968288b3fafb265f9ebd380512a71c3f2c",
+            ],
+            [
+                "05571a0f8d3c08d094576981f4a3b8eda0a8e771fcdcc8ecceaf1356a6acf17574518acb506e435b639353c2e14827c8",
+                "0bb5e7572275c567462d91807de765611490205a941a5a6af3b1691bfe596c31225d3aabdf15faff860cb4ef17c7c3be",
+            ],
+        ),
+        (
+            [
+                "19a84dd7248a1066f737cc34502ee5555bd3c19f2ecdb3c7d9e24dc65d4e25e50d83f0f77105e955d78f4762d33c17da",
+                "0934aba516a52d8ae479939a91998299c76d39cc0c035cd18813bec433f587e2d7a4fef038260eef0cef4d02aae3eb91",
+            ],
+            [
+                "14f81cd421617428bc3b9fe25afbb751d934a00493524bc4e065635b0555084dd54679df1536101b2c979c0152d09192",
+                "09bcccfa036b4847c9950780733633f13619994394c23ff0b32fa6b795844f4a0673e20282d07bc69641cee04f5e5662",
+            ],
+        ),
+        (
+            [
+                "01a6ba2f9a11fa5598b2d8ace0fbe0a0eacb65deceb476fbbcb64fd24557c2f4b18ecfc5663e54ae16a84f5ab7f62534",
+                "11fca2ff525572795a801eed17eb12785887c7b63fb77a42be46ce4a34131d71f7a73e95fee3f812aea3de78b4d01569",
+            ],
+            [
+                "0b6798718c8aed24bc19cb27f866f1c9effcdbf92397ad6448b5c9db90d2b9da6cbabf48adc1adf59a1a28344e79d57e",
+                "03a47f8e6d1763ba0cad63d6114c0accbef65707825a511b251a660a9b3994249ae4e63fac38b23da0c398689ee2ab52",
+            ],
+        ),
+    ];
+
+    // RFC 9380 Appendix J.10.2
+    const G2_NU_VECTORS: [([&str; 2], [&str; 2]); 5] = [
+        (
+            [
+                "00e7f4568a82b4b7dc1f14c6aaa055edf51502319c723c4dc2688c7fe5944c213f510328082396515734b6612c4e7bb7",
+                "126b855e9e69b1f691f816e48ac6977664d24d99f8724868a184186469ddfd4617367e94527d4b74fc86413483afb35b",
+            ],
+            [
+                "0caead0fd7b6176c01436833c79d305c78be307da5f6af6c133c47311def6ff1e0babf57a0fb5539fce7ee12407b0a42",
+                "1498aadcf7ae2b345243e281ae076df6de84455d766ab6fcdaad71fab60abb2e8b980a440043cd305db09d283c895e3d",
+            ],
+        ),
+        (
+            [
+                "108ed59fd9fae381abfd1d6bce2fd2fa220990f0f837fa30e0f27914ed6e1454db0d1ee957b219f61da6ff8be0d6441f",
+                "0296238ea82c6d4adb3c838ee3cb2346049c90b96d602d7bb1b469b905c9228be25c627bffee872def773d5b2a2eb57d",
+            ],
+            [
+                "033f90f6057aadacae7963b0a0b379dd46750c1c94a6357c99b65f63b79e321ff50fe3053330911c56b6ceea08fee656",
+                "153606c417e59fb331b7ae6bce4fbf7c5190c33ce9402b5ebe2b70e44fca614f3f1382a3625ed5493843d0b0a652fc3f",
+            ],
+        ),
+        (
+            [
+                "038af300ef34c7759a6caaa4e69363cafeed218a1f207e93b2c70d91a1263d375d6730bd6b6509dcac3ba5b567e85bf3",
+                "0da75be60fb6aa0e9e3143e40c42796edf15685cafe0279afd2a67c3dff1c82341f17effd402e4f1af240ea90f4b659b",
+            ],
+            [
+                "19b148cbdf163cf0894f29660d2e7bfb2b68e37d54cc83fd4e6e62c020eaa48709302ef8e746736c0e19342cc1ce3df4",
+                "0492f4fed741b073e5a82580f7c663f9b79e036b70ab3e51162359cec4e77c78086fe879b65ca7a47d34374c8315ac5e",
+            ],
+        ),
+        (
+            [
+                "0c5ae723be00e6c3f0efe184fdc0702b64588fe77dda152ab13099a3bacd3876767fa7bbad6d6fd90b3642e902b208f9",
+                "12c8c05c1d5fc7bfa847f4d7d81e294e66b9a78bc9953990c358945e1f042eedafce608b67fdd3ab0cb2e6e263b9b1ad",
+            ],
+            [
+                "04e77ddb3ede41b5ec4396b7421dd916efc68a358a0d7425bddd253547f2fb4830522358491827265dfc5bcc1928a569",
+                "11c624c56dbe154d759d021eec60fab3d8b852395a89de497e48504366feedd4662d023af447d66926a28076813dd646",
+            ],
+        ),
+        (
+            [
+                "0ea4e7c33d43e17cc516a72f76437c4bf81d8f4eac69ac355d3bf9b71b8138d55dc10fd458be115afa798b55dac34be1",
+                "1565c2f625032d232f13121d3cfb476f45275c303a037faa255f9da62000c2c864ea881e2bcddd111edc4a3c0da3e88d",
+            ],
+            [
+                "043b6f5fe4e52c839148dc66f2b3751e69a0f6ebb3d056d6465d50d4108543ecd956e10fa1640dfd9bc0030cc2558d28",
+                "0f8991d2a1ad662e7b6f58ab787947f1fa607fce12dde171bc17903b012091b657e15333e11701edcf5b63ba2a561247",
+            ],
+        ),
+    ];
+
+    fn assert_g1_point(p: ShortWeierstrassProjectivePoint<BLS12381Curve>, (x, y): (&str, &str)) {
+        assert!(p.is_in_subgroup());
+        let p = p.to_affine();
+        assert_eq!(p.x(), &BLS12381FieldElement::from_hex_unchecked(x));
+        assert_eq!(p.y(), &BLS12381FieldElement::from_hex_unchecked(y));
+    }
+
+    fn assert_g2_point(
+        p: ShortWeierstrassProjectivePoint<BLS12381TwistCurve>,
+        (x, y): ([&str; 2], [&str; 2]),
+    ) {
+        let from_hex = |[a0, a1]: [&str; 2]| {
+            BLS12381TwistCurveFieldElement::new([
+                FieldElement::from_hex_unchecked(a0),
+                FieldElement::from_hex_unchecked(a1),
+            ])
+        };
+        assert!(p.is_in_subgroup());
+        let p = p.to_affine();
+        assert_eq!(p.x(), &from_hex(x));
+        assert_eq!(p.y(), &from_hex(y));
+    }
+
+    #[test]
+    fn hash_to_g1_matches_the_rfc_test_vectors() {
+        for (msg, expected) in with_rfc_messages(G1_RO_VECTORS) {
+            assert_g1_point(
+                BLS12381Curve::hash_to_curve(&msg, G1_RO_DST).unwrap(),
+                expected,
+            );
+        }
+    }
+
+    #[test]
+    fn encode_to_g1_matches_the_rfc_test_vectors() {
+        for (msg, expected) in with_rfc_messages(G1_NU_VECTORS) {
+            assert_g1_point(
+                BLS12381Curve::encode_to_curve(&msg, G1_NU_DST).unwrap(),
+                expected,
+            );
+        }
+    }
+
+    #[test]
+    fn hash_to_g2_matches_the_rfc_test_vectors() {
+        for (msg, expected) in with_rfc_messages(G2_RO_VECTORS) {
+            assert_g2_point(
+                BLS12381TwistCurve::hash_to_curve(&msg, G2_RO_DST).unwrap(),
+                expected,
+            );
+        }
+    }
+
+    #[test]
+    fn encode_to_g2_matches_the_rfc_test_vectors() {
+        for (msg, expected) in with_rfc_messages(G2_NU_VECTORS) {
+            assert_g2_point(
+                BLS12381TwistCurve::encode_to_curve(&msg, G2_NU_DST).unwrap(),
+                expected,
+            );
+        }
+    }
+}
@@ -0,0 +1,137 @@
+//! The `BN254G1_XMD:SHA-256_SVDW_RO_` and `BN254G1_XMD:SHA-256_SVDW_NU_` suites, built as
+//! in RFC 9380 from the parameters of the hash to curve drafts.
+//!
+//! BN254 has no isogeny of small degree to a curve with `ab ≠ 0`, so it uses the
+//! Shallue-van de Woestijne method directly.
+
+use super::{
+    map_to_curve_svdw, os2ip_mod_p, prime_field_sgn0, IsHashToCurve, IsHashToCurveField,
+    SvdwConstants,
+};
+use lambdaworks_math::{
+    elliptic_curve::short_weierstrass::{
+        curves::bn_254::{
+            curve::{BN254Curve, BN254FieldElement},
+            field_extension::BN254PrimeField,
+        },
+        point::ShortWeierstrassProjectivePoint,
+        traits::IsShortWeierstrass,
+    },
+    field::element::FieldElement,
+};
+
+/// `Z = 1` of the suites.
+const Z: BN254FieldElement = FieldElement::from_hex_unchecked("1");
+
+/// The constants of the Shallue-van de Woestijne method for BN254 and `Z`.
+const SVDW_CONSTANTS: SvdwConstants<BN254PrimeField> = [
+    FieldElement::from_hex_unchecked("4"),
+    FieldElement::from_hex_unchecked(
+        "183227397098d014dc2822db40c0ac2ecbc0b548b438e5469e10460b6c3e7ea3",
+    ),
+    FieldElement::from_hex_unchecked("16789af3a83522eb353c98fc6b36d713d5d8d1cc5dffffffa"),
+    FieldElement::from_hex_unchecked(
+        "10216f7ba065e00de81ac1e7808072c9dd2b2385cd7b438469602eb24829a9bd",
+    ),
+];
+
+impl IsHashToCurveField for BN254PrimeField {
+    fn sqrt(a: &FieldElement<Self>) -> Option<FieldElement<Self>> {
+        a.sqrt().map(|(root, _)| root)
+    }
+
+    fn sgn0(a: &FieldElement<Self>) -> bool {
+        prime_field_sgn0(a)
+    }
+}
+
+impl IsHashToCurve for BN254Curve {
+    const UNIFORM_BYTES_PER_ELEMENT: usize = 48;
+
+    fn field_element_from_uniform_bytes(bytes: &[u8]) -> BN254FieldElement {
+        os2ip_mod_p(bytes)
+    }
+
+    fn map_to_curve(u: &BN254FieldElement) -> ShortWeierstrassProjectivePoint<Self> {
+        let (x, y) = map_to_curve_svdw(u, &Self::a(), &Self::b(), &Z, &SVDW_CONSTANTS);
+        ShortWeierstrassProjectivePoint::new([x, y, FieldElement::one()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::hash_to_curve::tests::with_rfc_messages;
+
+    const RO_DST: &[u8] = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_";
+    const NU_DST: &[u8] = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_NU_";
+
+    // RFC 9380 has no BN254 suite. These vectors are the ones of gnark-crypto for the
+    // suite parameters `Z = 1` and `L = 48` with the messages of the RFC appendices, see
+    // `ecc/bn254/hash_vectors_test.go` in https://github.com/Consensys/gnark-crypto
+    const RO_VECTORS: [(&str, &str); 5] = [
+        (
+            "0a976ab906170db1f9638d376514dbf8c42aef256a54bbd48521f20749e59e86",
+            "02925ead66b9e68bfc309b014398640ab55f6619ab59bc1fab2210ad4c4d53d5",
+        ),
+        (
+            "23f717bee89b1003957139f193e6be7da1df5f1374b26a4643b0378b5baf53d1",
+            "04142f826b71ee574452dbc47e05bc3e1a647478403a7ba38b7b93948f4e151d",
+        ),
+        (
+            "187dbf1c3c89aceceef254d6548d7163fdfa43084145f92c4c91c85c21442d4a",
+            "0abd99d5b0000910b56058f9cc3b0ab0a22d47cf27615f588924fac1e5c63b4d",
+        ),
+        (
+            "00fe2b0743575324fc452d590d217390ad48e5a16cf051bee5c40a2eba233f5c",
+            "0794211e0cc72d3cbbdf8e4e5cd6e7d7e78d101ff94862caae8acbe63e9fdc78",
+        ),
+        (
+            "01b05dc540bd79fd0fea4fbb07de08e94fc2e7bd171fe025c479dc212a2173ce",
+            "1bf028afc00c0f843d113758968f580640541728cfc6d32ced9779aa613cd9b0",
+        ),
+    ];
+
+    const NU_VECTORS: [(&str, &str); 5] = [
+        (
+            "1bb8810e2ceaf04786d4efd216fc2820ddd9363712efc736ada11049d8af5925",
+            "1efbf8d54c60d865cce08437668ea30f5bf90d287dbd9b5af31da852915e8f11",
+        ),
+        (
+            "0da4a96147df1f35b0f820bd35c6fac3b80e8e320de7c536b1e054667b22c332",
+            "189bd3fbffe4c8740d6543754d95c790e44cd2d162858e3b733d2b8387983bb7",
+        ),
+        (
+            "2ff727cfaaadb3acab713fa22d91f5fddab3ed77948f3ef6233d7ea9b03f4da1",
+            "304080768fd2f87a852155b727f97db84b191e41970506f0326ed4046d1141aa",
+        ),
+        (
+            "11a2eaa8e3e89de056d1b3a288a7f733c8a1282efa41d28e71af065ab245df9b",
+            "060f37c447ac29fd97b9bb83be98ddccf15e34831a9cdf5493b7fede0777ae06",
+        ),
+        (
+            "27409dccc6ee4ce90e24744fda8d72c0bc64e79766f778da0c1c0ef1c186ea84",
+            "1ac201a542feca15e77f30370da183514dc99d8a0b2c136d64ede35cd0b51dc0",
+        ),
+    ];
+
+    fn assert_point(p: ShortWeierstrassProjectivePoint<BN254Curve>, (x, y): (&str, &str)) {
+        let p = p.to_affine();
+        assert_eq!(p.x(), &BN254FieldElement::from_hex_unchecked(x));
+        assert_eq!(p.y(), &BN254FieldElement::from_hex_unchecked(y));
+    }
+
+    #[test]
+    fn hash_to_curve_matches_the_test_vectors() {
+        for (msg, expected) in with_rfc_messages(RO_VECTORS) {
+            assert_point(BN254Curve::hash_to_curve(&msg, RO_DST).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn encode_to_curve_matches_the_test_vectors() {
+        for (msg, expected) in with_rfc_messages(NU_VECTORS) {
+            assert_point(BN254Curve::encode_to_curve(&msg, NU_DST).unwrap(), expected);
+        }
+    }
+}
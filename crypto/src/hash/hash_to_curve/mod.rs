@@ -0,0 +1,369 @@
+//! Hashing to elliptic curves, as specified in RFC 9380.
+//! https://www.rfc-editor.org/rfc/rfc9380.html
+//!
+//! `hash_to_curve` maps two field elements derived from the message to the curve and adds
+//! them up, so that the result is indistinguishable from a random point, while
+//! `encode_to_curve` maps a single one and is cheaper, but only covers a fraction of the points.
+//! Both clear the cofactor, so the result is in the subgroup of prime order.
+//!
+//! All the suites use `expand_message_xmd` with SHA-256.
+
+pub mod bls12_381;
+pub mod bn_254;
+pub mod secp256k1;
+
+use alloc::vec::Vec;
+use core::fmt::Display;
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::{point::ShortWeierstrassProjectivePoint, traits::IsShortWeierstrass},
+        traits::HasSubgroupCheck,
+    },
+    field::{
+        element::FieldElement,
+        traits::{IsField, IsPrimeField},
+    },
+};
+use sha2::{
+    digest::{core_api::BlockSizeUser, Digest},
+    Sha256,
+};
+
+/// Longest domain separation tag that is used as is. Longer ones are hashed first.
+const MAX_DST_LENGTH: usize = 255;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum HashToCurveError {
+    /// The domain separation tag is empty.
+    EmptyDomainSeparationTag,
+    /// `expand_message_xmd` can't output the requested amount of bytes.
+    InvalidLength(usize),
+}
+
+impl Display for HashToCurveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HashToCurveError::EmptyDomainSeparationTag => {
+                write!(f, "The domain separation tag must not be empty")
+            }
+            HashToCurveError::InvalidLength(len) => {
+                write!(f, "Can't expand a message to {len} bytes")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HashToCurveError {}
+
+/// Expands `msg` into `len_in_bytes` uniformly random bytes with the hash function `H`,
+/// as in RFC 9380 Section 5.3.1.
+///
+/// Domain separation tags longer than 255 bytes are hashed, as in RFC 9380 Section 5.3.3.
+pub fn expand_message_xmd<H: Digest + BlockSizeUser>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, HashToCurveError> {
+    if dst.is_empty() {
+        return Err(HashToCurveError::EmptyDomainSeparationTag);
+    }
+    let hashed_dst;
+    let dst = if dst.len() > MAX_DST_LENGTH {
+        hashed_dst = H::new()
+            .chain_update(b"H2C-OVERSIZE-DST-")
+            .chain_update(dst)
+            .finalize();
+        &hashed_dst[..]
+    } else {
+        dst
+    };
+
+    let b_in_bytes = <H as Digest>::output_size();
+    let ell = len_in_bytes.div_ceil(b_in_bytes);
+    if ell > 255 || len_in_bytes > u16::MAX as usize {
+        return Err(HashToCurveError::InvalidLength(len_in_bytes));
+    }
+
+    let dst_prime = [dst, &[dst.len() as u8]].concat();
+    let b_0 = H::new()
+        .chain_update(vec![0; H::block_size()])
+        .chain_update(msg)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0])
+        .chain_update(&dst_prime)
+        .finalize();
+    let mut b_i = H::new()
+        .chain_update(&b_0)
+        .chain_update([1])
+        .chain_update(&dst_prime)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * b_in_bytes);
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        let xored: Vec<u8> = b_0.iter().zip(&b_i).map(|(a, b)| a ^ b).collect();
+        b_i = H::new()
+            .chain_update(xored)
+            .chain_update([i as u8])
+            .chain_update(&dst_prime)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
+
+/// Returns the big-endian integer `bytes` modulo the characteristic of `F`.
+pub fn os2ip_mod_p<F: IsPrimeField>(bytes: &[u8]) -> FieldElement<F> {
+    let base = FieldElement::<F>::from(256);
+    bytes.iter().fold(FieldElement::zero(), |acc, &byte| {
+        acc * &base + FieldElement::from(byte as u64)
+    })
+}
+
+/// Fields with the operations needed to map their elements to curve points.
+pub trait IsHashToCurveField: IsField {
+    /// Returns one of the square roots of `a`, or `None` if it is not a square.
+    fn sqrt(a: &FieldElement<Self>) -> Option<FieldElement<Self>>;
+
+    /// Returns the sign of `a`, as defined in RFC 9380 Section 4.1.
+    fn sgn0(a: &FieldElement<Self>) -> bool;
+}
+
+/// `sgn0` of the elements of prime fields, which is the parity of their representative.
+pub fn prime_field_sgn0<F: IsPrimeField>(a: &FieldElement<F>) -> bool {
+    let one = F::RepresentativeType::from(1);
+    a.representative() & one == one
+}
+
+/// Curves with a hash to curve suite of RFC 9380, using `expand_message_xmd` with SHA-256.
+/// The cofactor is cleared with [`HasSubgroupCheck::clear_cofactor`].
+pub trait IsHashToCurve:
+    IsShortWeierstrass + HasSubgroupCheck<PointRepresentation = ShortWeierstrassProjectivePoint<Self>>
+{
+    /// Number of uniform bytes reduced to each element of the base prime field, `L` in RFC 9380.
+    const UNIFORM_BYTES_PER_ELEMENT: usize;
+
+    /// Degree of the base field over its prime field, `m` in RFC 9380.
+    const EXTENSION_DEGREE: usize = 1;
+
+    /// Returns the element of the base field for `EXTENSION_DEGREE * UNIFORM_BYTES_PER_ELEMENT`
+    /// uniform bytes.
+    fn field_element_from_uniform_bytes(bytes: &[u8]) -> FieldElement<Self::BaseField>;
+
+    /// Deterministically maps a field element to a curve point, not necessarily in the
+    /// subgroup of prime order.
+    fn map_to_curve(u: &FieldElement<Self::BaseField>) -> ShortWeierstrassProjectivePoint<Self>;
+
+    /// Hashes `msg` to `count` elements of the base field, as in RFC 9380 Section 5.2.
+    fn hash_to_field(
+        msg: &[u8],
+        dst: &[u8],
+        count: usize,
+    ) -> Result<Vec<FieldElement<Self::BaseField>>, HashToCurveError> {
+        let len = Self::EXTENSION_DEGREE * Self::UNIFORM_BYTES_PER_ELEMENT;
+        let uniform_bytes = expand_message_xmd::<Sha256>(msg, dst, count * len)?;
+        Ok(uniform_bytes
+            .chunks_exact(len)
+            .map(Self::field_element_from_uniform_bytes)
+            .collect())
+    }
+
+    /// Hashes `msg` to a point of the subgroup of prime order, indistinguishable from a random one.
+    fn hash_to_curve(
+        msg: &[u8],
+        dst: &[u8],
+    ) -> Result<ShortWeierstrassProjectivePoint<Self>, HashToCurveError> {
+        let u = Self::hash_to_field(msg, dst, 2)?;
+        let q = Self::map_to_curve(&u[0]).operate_with(&Self::map_to_curve(&u[1]));
+        Ok(Self::clear_cofactor(&q))
+    }
+
+    /// Encodes `msg` as a point of the subgroup of prime order, which is cheaper than
+    /// [`IsHashToCurve::hash_to_curve`] but only reaches a fraction of the points.
+    fn encode_to_curve(
+        msg: &[u8],
+        dst: &[u8],
+    ) -> Result<ShortWeierstrassProjectivePoint<Self>, HashToCurveError> {
+        let u = Self::hash_to_field(msg, dst, 1)?;
+        Ok(Self::clear_cofactor(&Self::map_to_curve(&u[0])))
+    }
+}
+
+/// The simplified Shallue-van de Woestijne-Ulas method, RFC 9380 Section 6.6.2.
+///
+/// Returns the affine coordinates of the point of `u` on `y² = x³ + ax + b`, which needs
+/// `ab ≠ 0`. `z` must satisfy the conditions of that section, and in particular be a non-square.
+pub fn map_to_curve_simple_swu<F: IsHashToCurveField>(
+    u: &FieldElement<F>,
+    a: &FieldElement<F>,
+    b: &FieldElement<F>,
+    z: &FieldElement<F>,
+) -> (FieldElement<F>, FieldElement<F>) {
+    let g = |x: &FieldElement<F>| x.square() * x + a * x + b;
+    let z_u2 = z * u.square();
+    let x1 = match (z_u2.square() + &z_u2).inv() {
+        Ok(tv1) => (-b / a) * (FieldElement::<F>::one() + tv1),
+        Err(_) => b / (z * a),
+    };
+    let (x, y) = match F::sqrt(&g(&x1)) {
+        Some(y) => (x1, y),
+        None => {
+            let x2 = z_u2 * x1;
+            let y = F::sqrt(&g(&x2)).expect("g(x2) is a square when g(x1) is not");
+            (x2, y)
+        }
+    };
+    let y = if F::sgn0(u) != F::sgn0(&y) { -y } else { y };
+    (x, y)
+}
+
+/// The constants `[c1, c2, c3, c4]` of the Shallue-van de Woestijne method for a curve and `z`,
+/// as in RFC 9380 Section 6.6.1.
+pub type SvdwConstants<F> = [FieldElement<F>; 4];
+
+/// The Shallue-van de Woestijne method, RFC 9380 Section 6.6.1, which works for any curve
+/// `y² = x³ + ax + b`. Returns the affine coordinates of the point of `u`.
+pub fn map_to_curve_svdw<F: IsHashToCurveField>(
+    u: &FieldElement<F>,
+    a: &FieldElement<F>,
+    b: &FieldElement<F>,
+    z: &FieldElement<F>,
+    [c1, c2, c3, c4]: &SvdwConstants<F>,
+) -> (FieldElement<F>, FieldElement<F>) {
+    let g = |x: &FieldElement<F>| x.square() * x + a * x + b;
+    let tv1 = u.square() * c1;
+    let tv2 = FieldElement::<F>::one() + &tv1;
+    let tv1 = FieldElement::<F>::one() - tv1;
+    let tv3 = (&tv1 * &tv2).inv().unwrap_or(FieldElement::zero());
+    let tv4 = u * tv1 * &tv3 * c3;
+
+    let x1 = c2 - &tv4;
+    let x2 = c2 + tv4;
+    let (x, y) = match (F::sqrt(&g(&x1)), F::sqrt(&g(&x2))) {
+        (Some(y), _) => (x1, y),
+        (None, Some(y)) => (x2, y),
+        (None, None) => {
+            let x3 = (tv2.square() * tv3).square() * c4 + z;
+            let y = F::sqrt(&g(&x3)).expect("g(x3) is a square when g(x1) and g(x2) are not");
+            (x3, y)
+        }
+    };
+    let y = if F::sgn0(u) != F::sgn0(&y) { -y } else { y };
+    (x, y)
+}
+
+/// Evaluates the isogeny `(x, y) -> (x_num(x) / x_den(x), y * y_num(x) / y_den(x))`, where
+/// each polynomial is given by its coefficients from the lowest degree.
+/// Returns the point at infinity when a denominator vanishes.
+pub fn isogeny_map<E: IsShortWeierstrass>(
+    (x, y): &(FieldElement<E::BaseField>, FieldElement<E::BaseField>),
+    [x_num, x_den, y_num, y_den]: [&[FieldElement<E::BaseField>]; 4],
+) -> ShortWeierstrassProjectivePoint<E> {
+    let evaluate = |coefficients: &[FieldElement<E::BaseField>]| {
+        coefficients
+            .iter()
+            .rev()
+            .fold(FieldElement::zero(), |acc, coefficient| {
+                acc * x + coefficient
+            })
+    };
+    match (evaluate(x_den).inv(), evaluate(y_den).inv()) {
+        (Ok(x_den_inv), Ok(y_den_inv)) => ShortWeierstrassProjectivePoint::new([
+            evaluate(x_num) * x_den_inv,
+            y * evaluate(y_num) * y_den_inv,
+            FieldElement::one(),
+        ]),
+        _ => ShortWeierstrassProjectivePoint::neutral_element(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::decode_hex;
+
+    /// Pairs the messages of the RFC 9380 test vectors with the expected outputs.
+    /// Taking an array makes a missing vector a compile error rather than a
+    /// message that is silently skipped.
+    pub(super) fn with_rfc_messages<T>(vectors: [T; 5]) -> impl Iterator<Item = (Vec<u8>, T)> {
+        rfc_messages().into_iter().zip(vectors)
+    }
+
+    fn rfc_messages() -> [Vec<u8>; 5] {
+        [
+            b"".to_vec(),
+            b"abc".to_vec(),
+            b"abcdef0123456789".to_vec(),
+            [b"q128_".as_slice(), &[b'q'; 128]].concat(),
+            [b"a512_".as_slice(), &[b'a'; 512]].concat(),
+        ]
+    }
+
+    // RFC 9380 Appendix K.1
+    #[test]
+    fn expand_message_xmd_sha256_matches_the_rfc_test_vectors() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let vectors: [(&[u8], usize, &str); 4] = [
+            (
+                b"",
+                0x20,
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            ),
+            (
+                b"abc",
+                0x20,
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            ),
+            (
+                b"abcdef0123456789",
+                0x20,
+                "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+            ),
+            (
+                b"",
+                0x80,
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            ),
+        ];
+        for (msg, len, expected) in vectors {
+            assert_eq!(
+                expand_message_xmd::<Sha256>(msg, dst, len).unwrap(),
+                decode_hex(expected)
+            );
+        }
+    }
+
+    #[test]
+    fn expand_message_xmd_checks_its_parameters() {
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"msg", b"", 32),
+            Err(HashToCurveError::EmptyDomainSeparationTag)
+        );
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"msg", b"dst", 255 * 32 + 1),
+            Err(HashToCurveError::InvalidLength(255 * 32 + 1))
+        );
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"msg", b"dst", 255 * 32)
+                .unwrap()
+                .len(),
+            255 * 32
+        );
+    }
+
+    #[test]
+    fn long_domain_separation_tags_are_hashed() {
+        let dst = [b'a'; 300];
+        let hashed_dst = Sha256::new()
+            .chain_update(b"H2C-OVERSIZE-DST-")
+            .chain_update(dst)
+            .finalize();
+        assert_eq!(
+            expand_message_xmd::<Sha256>(b"msg", &dst, 64).unwrap(),
+            expand_message_xmd::<Sha256>(b"msg", &hashed_dst, 64).unwrap()
+        );
+    }
+}
@@ -0,0 +1,202 @@
+//! The `secp256k1_XMD:SHA-256_SSWU_RO_` and `secp256k1_XMD:SHA-256_SSWU_NU_` suites of RFC 9380.
+//!
+//! secp256k1 has `a = 0`, so the simplified SWU map is applied to a 3-isogenous curve `E'`
+//! and the result is moved to secp256k1 with the isogeny.
+
+use super::{
+    isogeny_map, map_to_curve_simple_swu, os2ip_mod_p, prime_field_sgn0, IsHashToCurve,
+    IsHashToCurveField,
+};
+use lambdaworks_math::{
+    elliptic_curve::short_weierstrass::{
+        curves::secp256k1::curve::Secp256k1Curve, point::ShortWeierstrassProjectivePoint,
+    },
+    field::{element::FieldElement, fields::secp256k1_field::Secp256k1PrimeField},
+};
+
+type Secp256k1FieldElement = FieldElement<Secp256k1PrimeField>;
+
+/// `A'` of the curve 3-isogenous to secp256k1.
+const ISO_A: Secp256k1FieldElement = FieldElement::from_hex_unchecked(
+    "3f8731abdd661adca08a5558f0f5d272e953d363cb6f0e5d405447c01a444533",
+);
+/// `B'` of the curve 3-isogenous to secp256k1.
+const ISO_B: Secp256k1FieldElement = FieldElement::from_hex_unchecked("6eb");
+/// `Z = -11` of the suites.
+const Z: Secp256k1FieldElement = FieldElement::from_hex_unchecked(
+    "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc24",
+);
+
+/// Coefficients of the numerator of the x-coordinate of the isogeny, lowest degree first.
+const ISO_X_NUMERATOR: [Secp256k1FieldElement; 4] = [
+    FieldElement::from_hex_unchecked(
+        "8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa8c7",
+    ),
+    FieldElement::from_hex_unchecked(
+        "07d3d4c80bc321d5b9f315cea7fd44c5d595d2fc0bf63b92dfff1044f17c6581",
+    ),
+    FieldElement::from_hex_unchecked(
+        "534c328d23f234e6e2a413deca25caece4506144037c40314ecbd0b53d9dd262",
+    ),
+    FieldElement::from_hex_unchecked(
+        "8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa88c",
+    ),
+];
+
+/// Coefficients of the denominator of the x-coordinate of the isogeny, lowest degree first.
+const ISO_X_DENOMINATOR: [Secp256k1FieldElement; 3] = [
+    FieldElement::from_hex_unchecked(
+        "d35771193d94918a9ca34ccbb7b640dd86cd409542f8487d9fe6b745781eb49b",
+    ),
+    FieldElement::from_hex_unchecked(
+        "edadc6f64383dc1df7c4b2d51b54225406d36b641f5e41bbc52a56612a8c6d14",
+    ),
+    FieldElement::from_hex_unchecked(
+        "0000000000000000000000000000000000000000000000000000000000000001",
+    ),
+];
+
+/// Coefficients of the numerator of the y-coordinate of the isogeny, lowest degree first.
+const ISO_Y_NUMERATOR: [Secp256k1FieldElement; 4] = [
+    FieldElement::from_hex_unchecked(
+        "4bda12f684bda12f684bda12f684bda12f684bda12f684bda12f684b8e38e23c",
+    ),
+    FieldElement::from_hex_unchecked(
+        "c75e0c32d5cb7c0fa9d0a54b12a0a6d5647ab046d686da6fdffc90fc201d71a3",
+    ),
+    FieldElement::from_hex_unchecked(
+        "29a6194691f91a73715209ef6512e576722830a201be2018a765e85a9ecee931",
+    ),
+    FieldElement::from_hex_unchecked(
+        "2f684bda12f684bda12f684bda12f684bda12f684bda12f684bda12f38e38d84",
+    ),
+];
+
+/// Coefficients of the denominator of the y-coordinate of the isogeny, lowest degree first.
+const ISO_Y_DENOMINATOR: [Secp256k1FieldElement; 4] = [
+    FieldElement::from_hex_unchecked(
+        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffff93b",
+    ),
+    FieldElement::from_hex_unchecked(
+        "7a06534bb8bdb49fd5e9e6632722c2989467c1bfc8e8d978dfb425d2685c2573",
+    ),
+    FieldElement::from_hex_unchecked(
+        "6484aa716545ca2cf3a70c3fa8fe337e0a3d21162f0d6299a7bf8192bfd2a76f",
+    ),
+    FieldElement::from_hex_unchecked(
+        "0000000000000000000000000000000000000000000000000000000000000001",
+    ),
+];
+
+impl IsHashToCurveField for Secp256k1PrimeField {
+    fn sqrt(a: &FieldElement<Self>) -> Option<FieldElement<Self>> {
+        a.sqrt().map(|(root, _)| root)
+    }
+
+    fn sgn0(a: &FieldElement<Self>) -> bool {
+        prime_field_sgn0(a)
+    }
+}
+
+impl IsHashToCurve for Secp256k1Curve {
+    const UNIFORM_BYTES_PER_ELEMENT: usize = 48;
+
+    fn field_element_from_uniform_bytes(bytes: &[u8]) -> Secp256k1FieldElement {
+        os2ip_mod_p(bytes)
+    }
+
+    fn map_to_curve(u: &Secp256k1FieldElement) -> ShortWeierstrassProjectivePoint<Self> {
+        isogeny_map(
+            &map_to_curve_simple_swu(u, &ISO_A, &ISO_B, &Z),
+            [
+                &ISO_X_NUMERATOR,
+                &ISO_X_DENOMINATOR,
+                &ISO_Y_NUMERATOR,
+                &ISO_Y_DENOMINATOR,
+            ],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::hash_to_curve::tests::with_rfc_messages;
+
+    const RO_DST: &[u8] = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+    const NU_DST: &[u8] = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_NU_";
+
+    // RFC 9380 Appendix J.8.1
+    const RO_VECTORS: [(&str, &str); 5] = [
+        (
+            "c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+            "64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067",
+        ),
+        (
+            "3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+            "7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6",
+        ),
+        (
+            "bac54083f293f1fe08e4a70137260aa90783a5cb84d3f35848b324d0674b0e3a",
+            "4436476085d4c3c4508b60fcf4389c40176adce756b398bdee27bca19758d828",
+        ),
+        (
+            "e2167bc785333a37aa562f021f1e881defb853839babf52a7f72b102e41890e9",
+            "f2401dd95cc35867ffed4f367cd564763719fbc6a53e969fb8496a1e6685d873",
+        ),
+        (
+            "e3c8d35aaaf0b9b647e88a0a0a7ee5d5bed5ad38238152e4e6fd8c1f8cb7c998",
+            "8446eeb6181bf12f56a9d24e262221cc2f0c4725c7e3803024b5888ee5823aa6",
+        ),
+    ];
+
+    // RFC 9380 Appendix J.8.2
+    const NU_VECTORS: [(&str, &str); 5] = [
+        (
+            "a4792346075feae77ac3b30026f99c1441b4ecf666ded19b7522cf65c4c55c5b",
+            "62c59e2a6aeed1b23be5883e833912b08ba06be7f57c0e9cdc663f31639ff3a7",
+        ),
+        (
+            "3f3b5842033fff837d504bb4ce2a372bfeadbdbd84a1d2b678b6e1d7ee426b9d",
+            "902910d1fef15d8ae2006fc84f2a5a7bda0e0407dc913062c3a493c4f5d876a5",
+        ),
+        (
+            "07644fa6281c694709f53bdd21bed94dab995671e4a8cd1904ec4aa50c59bfdf",
+            "c79f8d1dad79b6540426922f7fbc9579c3018dafeffcd4552b1626b506c21e7b",
+        ),
+        (
+            "b734f05e9b9709ab631d960fa26d669c4aeaea64ae62004b9d34f483aa9acc33",
+            "03fc8a4a5a78632e2eb4d8460d69ff33c1d72574b79a35e402e801f2d0b1d6ee",
+        ),
+        (
+            "17d22b867658977b5002dbe8d0ee70a8cfddec3eec50fb93f36136070fd9fa6c",
+            "e9178ff02f4dab73480f8dd590328aea99856a7b6cc8e5a6cdf289ecc2a51718",
+        ),
+    ];
+
+    fn assert_point(p: ShortWeierstrassProjectivePoint<Secp256k1Curve>, (x, y): (&str, &str)) {
+        let p = p.to_affine();
+        assert_eq!(p.x(), &Secp256k1FieldElement::from_hex_unchecked(x));
+        assert_eq!(p.y(), &Secp256k1FieldElement::from_hex_unchecked(y));
+    }
+
+    #[test]
+    fn hash_to_curve_matches_the_rfc_test_vectors() {
+        for (msg, expected) in with_rfc_messages(RO_VECTORS) {
+            assert_point(
+                Secp256k1Curve::hash_to_curve(&msg, RO_DST).unwrap(),
+                expected,
+            );
+        }
+    }
+
+    #[test]
+    fn encode_to_curve_matches_the_rfc_test_vectors() {
+        for (msg, expected) in with_rfc_messages(NU_VECTORS) {
+            assert_point(
+                Secp256k1Curve::encode_to_curve(&msg, NU_DST).unwrap(),
+                expected,
+            );
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! HMAC with SHA-256, as in RFC 2104.
+
+use sha2::{Digest, Sha256};
+
+const SHA256_BLOCK_SIZE: usize = 64;
+
+/// Returns the HMAC-SHA256 with `key` of the concatenation of `data`.
+pub fn hmac_sha256(key: &[u8], data: &[&[u8]]) -> [u8; 32] {
+    let mut padded_key = [0; SHA256_BLOCK_SIZE];
+    if key.len() > SHA256_BLOCK_SIZE {
+        padded_key[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        padded_key[..key.len()].copy_from_slice(key);
+    }
+    let inner = data
+        .iter()
+        .fold(
+            Sha256::new().chain_update(padded_key.map(|byte| byte ^ 0x36)),
+            |hasher, chunk| hasher.chain_update(chunk),
+        )
+        .finalize();
+    Sha256::new()
+        .chain_update(padded_key.map(|byte| byte ^ 0x5c))
+        .chain_update(inner)
+        .finalize()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::decode_hex;
+
+    // RFC 4231, test cases 1, 2 and 6
+    #[test]
+    fn hmac_sha256_matches_the_rfc_test_vectors() {
+        assert_eq!(
+            hmac_sha256(&[0x0b; 20], &[b"Hi There"]).to_vec(),
+            decode_hex("b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7")
+        );
+        assert_eq!(
+            hmac_sha256(b"Jefe", &[b"what do ya want ", b"for nothing?"]).to_vec(),
+            decode_hex("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")
+        );
+        assert_eq!(
+            hmac_sha256(
+                &[0xaa; 131],
+                &[b"Test Using Larger Than Block-Size Key - Hash Key First"]
+            )
+            .to_vec(),
+            decode_hex("60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54")
+        );
+    }
+}
@@ -1,6 +1,10 @@
+pub mod blake3;
+pub mod hash_to_curve;
 pub mod hash_to_field;
+pub mod hmac;
 pub mod monolith;
 pub mod pedersen;
 pub mod poseidon;
+pub mod poseidon2;
 pub mod rescue_prime;
 pub mod sha3;
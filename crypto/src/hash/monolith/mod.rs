@@ -1,10 +1,11 @@
 use alloc::vec::Vec;
 use lambdaworks_math::field::{
-    fields::mersenne31::field::MERSENNE_31_PRIME_FIELD_ORDER, traits::IsField,
-};
-use sha3::{
-    digest::{ExtendableOutput, Update},
-    Shake128, Shake128Reader,
+    element::FieldElement,
+    fields::mersenne31::{
+        extensions::{Degree2ExtensionField, Degree4ExtensionField},
+        field::Mersenne31Field,
+    },
+    traits::IsField,
 };
 
 mod utils;
@@ -13,17 +14,57 @@ use utils::*;
 // Ported from https://github.com/Plonky3/Plonky3/blob/main/monolith
 
 pub const NUM_BARS: usize = 8;
+/// Number of field elements in a digest, which is also the capacity of the sponge.
+pub const DIGEST_SIZE: usize = 8;
 const MATRIX_CIRC_MDS_16_MERSENNE31_MONOLITH: [u32; 16] = [
     61402, 17845, 26798, 59689, 12021, 40901, 41351, 27521, 56951, 12034, 53865, 43244, 7454,
     33823, 28750, 1108,
 ];
 
-pub struct MonolithMersenne31<const WIDTH: usize, const NUM_FULL_ROUNDS: usize> {
-    round_constants: Vec<Vec<u32>>,
-    lookup1: Vec<u16>,
-    lookup2: Vec<u16>,
+/// Fields whose elements Monolith over Mersenne31 can hash, through their coordinates over
+/// Mersenne31.
+pub trait IsMersenne31Field: IsField {
+    /// Appends the coordinates of `element` over Mersenne31 to `coordinates`.
+    fn push_coordinates(
+        element: &FieldElement<Self>,
+        coordinates: &mut Vec<FieldElement<Mersenne31Field>>,
+    );
+}
+
+impl IsMersenne31Field for Mersenne31Field {
+    fn push_coordinates(
+        element: &FieldElement<Self>,
+        coordinates: &mut Vec<FieldElement<Mersenne31Field>>,
+    ) {
+        coordinates.push(*element);
+    }
+}
+
+impl IsMersenne31Field for Degree2ExtensionField {
+    fn push_coordinates(
+        element: &FieldElement<Self>,
+        coordinates: &mut Vec<FieldElement<Mersenne31Field>>,
+    ) {
+        coordinates.extend_from_slice(element.value());
+    }
+}
+
+impl IsMersenne31Field for Degree4ExtensionField {
+    fn push_coordinates(
+        element: &FieldElement<Self>,
+        coordinates: &mut Vec<FieldElement<Mersenne31Field>>,
+    ) {
+        for coefficient in element.value() {
+            Degree2ExtensionField::push_coordinates(coefficient, coordinates);
+        }
+    }
 }
 
+/// Monolith over Mersenne31. Its round constants and MDS matrix are computed at compile time, so
+/// an instance holds no data and building one is free.
+#[derive(Clone, Copy, Debug)]
+pub struct MonolithMersenne31<const WIDTH: usize, const NUM_FULL_ROUNDS: usize>;
+
 impl<const WIDTH: usize, const NUM_FULL_ROUNDS: usize> Default
     for MonolithMersenne31<WIDTH, NUM_FULL_ROUNDS>
 {
@@ -33,45 +74,18 @@ impl<const WIDTH: usize, const NUM_FULL_ROUNDS: usize> Default
 }
 
 impl<const WIDTH: usize, const NUM_FULL_ROUNDS: usize> MonolithMersenne31<WIDTH, NUM_FULL_ROUNDS> {
-    pub fn new() -> Self {
+    const ROUND_CONSTANTS: [[u32; WIDTH]; NUM_FULL_ROUNDS] = {
         assert!(WIDTH >= 8);
         assert!(WIDTH <= 24);
-        assert!(WIDTH % 4 == 0);
-        Self {
-            round_constants: Self::instantiate_round_constants(),
-            lookup1: Self::instantiate_lookup1(),
-            lookup2: Self::instantiate_lookup2(),
-        }
-    }
-
-    fn instantiate_round_constants() -> Vec<Vec<u32>> {
-        let mut shake = Shake128::default();
-        shake.update("Monolith".as_bytes());
-        shake.update(&[WIDTH as u8, (NUM_FULL_ROUNDS + 1) as u8]);
-        shake.update(&MERSENNE_31_PRIME_FIELD_ORDER.to_le_bytes());
-        shake.update(&[8, 8, 8, 7]);
-        let mut shake_finalized = shake.finalize_xof();
-        random_matrix(&mut shake_finalized, NUM_FULL_ROUNDS, WIDTH)
-    }
+        assert!(WIDTH & 3 == 0);
+        random_matrix(monolith_shake(WIDTH, NUM_FULL_ROUNDS + 1, &[8, 8, 8, 7]))
+    };
 
-    fn instantiate_lookup1() -> Vec<u16> {
-        (0..=u16::MAX)
-            .map(|i| {
-                let hi = (i >> 8) as u8;
-                let lo = i as u8;
-                ((Self::s_box(hi) as u16) << 8) | Self::s_box(lo) as u16
-            })
-            .collect()
-    }
+    const CAUCHY_MDS_MATRIX: [[u32; WIDTH]; WIDTH] =
+        cauchy_mds_matrix(monolith_shake(WIDTH, NUM_FULL_ROUNDS + 1, b"\x10\x0fMDS"));
 
-    fn instantiate_lookup2() -> Vec<u16> {
-        (0..(1 << 15))
-            .map(|i| {
-                let hi = (i >> 8) as u8;
-                let lo: u8 = i as u8;
-                ((Self::final_s_box(hi) as u16) << 8) | Self::s_box(lo) as u16
-            })
-            .collect()
+    pub const fn new() -> Self {
+        Self
     }
 
     fn s_box(y: u8) -> u8 {
@@ -94,34 +108,90 @@ impl<const WIDTH: usize, const NUM_FULL_ROUNDS: usize> MonolithMersenne31<WIDTH,
             self.bars(state);
             Self::bricks(state);
             self.concrete(state);
-            Self::add_round_constants(state, &self.round_constants[round]);
+            Self::add_round_constants(state, &Self::ROUND_CONSTANTS[round]);
         }
         self.bars(state);
         Self::bricks(state);
         self.concrete(state);
     }
 
+    /// Sponge hash of an arbitrary number of elements. The inputs are padded with a 1 followed
+    /// by zeros and added to the first `WIDTH - DIGEST_SIZE` elements of the state, and the digest
+    /// is made of the first `DIGEST_SIZE` elements of the final state.
+    pub fn hash_many(
+        &self,
+        inputs: &[FieldElement<Mersenne31Field>],
+    ) -> Vec<FieldElement<Mersenne31Field>> {
+        assert!(WIDTH > DIGEST_SIZE);
+        let rate = WIDTH - DIGEST_SIZE;
+
+        let mut values: Vec<u32> = inputs.iter().map(|input| *input.value()).collect();
+        values.push(F::one());
+        values.resize(values.len().div_ceil(rate) * rate, F::zero());
+
+        let mut state = vec![F::zero(); WIDTH];
+        for block in values.chunks(rate) {
+            Self::add_round_constants(&mut state, block);
+            self.permutation(&mut state);
+        }
+
+        Self::to_field_elements(&state[..DIGEST_SIZE])
+    }
+
+    /// Hashes a single element with the sponge.
+    pub fn hash(
+        &self,
+        input: &FieldElement<Mersenne31Field>,
+    ) -> Vec<FieldElement<Mersenne31Field>> {
+        self.hash_many(core::slice::from_ref(input))
+    }
+
+    /// Two-to-one compression of digests: the permutation is applied to `left || right`,
+    /// padded with zeros, and the output is truncated to `DIGEST_SIZE` elements.
+    pub fn compress(
+        &self,
+        left: &[FieldElement<Mersenne31Field>],
+        right: &[FieldElement<Mersenne31Field>],
+    ) -> Vec<FieldElement<Mersenne31Field>> {
+        assert!(left.len() + right.len() <= WIDTH);
+        let mut state: Vec<u32> = left.iter().chain(right).map(|x| *x.value()).collect();
+        state.resize(WIDTH, F::zero());
+        self.permutation(&mut state);
+
+        Self::to_field_elements(&state[..DIGEST_SIZE])
+    }
+
+    fn to_field_elements(values: &[u32]) -> Vec<FieldElement<Mersenne31Field>> {
+        values
+            .iter()
+            .map(|value| FieldElement::from_raw(*value))
+            .collect()
+    }
+
     // MDS matrix
     fn concrete(&self, state: &mut Vec<u32>) {
         *state = if WIDTH == 16 {
             Self::apply_circulant(&mut MATRIX_CIRC_MDS_16_MERSENNE31_MONOLITH.clone(), state)
         } else {
-            let mut shake = Shake128::default();
-            shake.update("Monolith".as_bytes());
-            shake.update(&[WIDTH as u8, (NUM_FULL_ROUNDS + 1) as u8]);
-            shake.update(&MERSENNE_31_PRIME_FIELD_ORDER.to_le_bytes());
-            shake.update(&[16, 15]);
-            shake.update("MDS".as_bytes());
-            let mut shake_finalized = shake.finalize_xof();
-            Self::apply_cauchy_mds_matrix(&mut shake_finalized, state)
+            Self::CAUCHY_MDS_MATRIX
+                .iter()
+                .map(|row| dot_product(row, state))
+                .collect()
         };
     }
 
-    // S-box lookups
+    // S-boxes, applied to each byte. The most significant one has 7 bits.
+    // They are evaluated directly rather than through lookup tables, which would have to be
+    // built for every instance.
     fn bars(&self, state: &mut [u32]) {
         for state in state.iter_mut().take(NUM_BARS) {
-            *state = (self.lookup2[(*state >> 16) as u16 as usize] as u32) << 16
-                | self.lookup1[*state as u16 as usize] as u32;
+            let [b0, b1, b2, b3] = state.to_le_bytes();
+            *state = u32::from_le_bytes([
+                Self::s_box(b0),
+                Self::s_box(b1),
+                Self::s_box(b2),
+                Self::final_s_box(b3),
+            ]);
         }
     }
 
@@ -148,30 +218,6 @@ impl<const WIDTH: usize, const NUM_FULL_ROUNDS: usize> MonolithMersenne31<WIDTH,
         output[WIDTH - 1] = dot_product(circ_matrix, input);
         output
     }
-
-    fn apply_cauchy_mds_matrix(shake: &mut Shake128Reader, to_multiply: &[u32]) -> Vec<u32> {
-        let mut output = vec![F::zero(); WIDTH];
-
-        let bits: u32 = u64::BITS
-            - (MERSENNE_31_PRIME_FIELD_ORDER as u64)
-                .saturating_sub(1)
-                .leading_zeros();
-
-        let x_mask = (1 << (bits - 9)) - 1;
-        let y_mask = ((1 << bits) - 1) >> 2;
-
-        let y = get_random_y_i(shake, WIDTH, x_mask, y_mask);
-        let mut x = y.clone();
-        x.iter_mut().for_each(|x_i| *x_i &= x_mask);
-
-        for (i, x_i) in x.iter().enumerate() {
-            for (j, yj) in y.iter().enumerate() {
-                output[i] = F::add(&output[i], &F::div(&to_multiply[j], &F::add(x_i, yj)));
-            }
-        }
-
-        output
-    }
 }
 
 #[cfg(test)]
@@ -221,4 +267,89 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn bars_match_the_lookup_tables_of_plonky3() {
+        let lookup1: Vec<u16> = (0..=u16::MAX)
+            .map(|i| {
+                let hi = (i >> 8) as u8;
+                let lo = i as u8;
+                ((MonolithMersenne31::<16, 5>::s_box(hi) as u16) << 8)
+                    | MonolithMersenne31::<16, 5>::s_box(lo) as u16
+            })
+            .collect();
+        let lookup2: Vec<u16> = (0..(1 << 15))
+            .map(|i| {
+                let hi = (i >> 8) as u8;
+                let lo: u8 = i as u8;
+                ((MonolithMersenne31::<16, 5>::final_s_box(hi) as u16) << 8)
+                    | MonolithMersenne31::<16, 5>::s_box(lo) as u16
+            })
+            .collect();
+
+        let monolith = MonolithMersenne31::<16, 5>::new();
+        let mut state: Vec<u32> = (0..16u32)
+            .map(|i| i.wrapping_mul(0x9e3779b9) & 0x7fff_ffff)
+            .collect();
+        let expected: Vec<u32> = state
+            .iter()
+            .enumerate()
+            .map(|(i, x)| {
+                if i < NUM_BARS {
+                    (lookup2[(*x >> 16) as usize] as u32) << 16 | lookup1[*x as u16 as usize] as u32
+                } else {
+                    *x
+                }
+            })
+            .collect();
+        monolith.bars(&mut state);
+        assert_eq!(state, expected);
+    }
+
+    #[test]
+    fn hash_many_matches_the_permutation_of_the_padded_input() {
+        let monolith = MonolithMersenne31::<16, 5>::new();
+        let inputs: Vec<FieldElement<Mersenne31Field>> =
+            (1..4u64).map(FieldElement::from).collect();
+
+        let mut state = vec![1, 2, 3, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        monolith.permutation(&mut state);
+        let expected: Vec<FieldElement<Mersenne31Field>> = state[..DIGEST_SIZE]
+            .iter()
+            .map(FieldElement::from)
+            .collect();
+
+        assert_eq!(monolith.hash_many(&inputs), expected);
+    }
+
+    #[test]
+    fn hash_many_absorbs_several_blocks() {
+        let monolith = MonolithMersenne31::<16, 5>::new();
+        let inputs: Vec<FieldElement<Mersenne31Field>> =
+            (0..8u64).map(FieldElement::from).collect();
+
+        assert_ne!(
+            monolith.hash_many(&inputs),
+            monolith.hash_many(&inputs[..7])
+        );
+        assert_eq!(monolith.hash(&inputs[0]), monolith.hash_many(&inputs[..1]));
+    }
+
+    #[test]
+    fn compress_truncates_the_permutation() {
+        let monolith = MonolithMersenne31::<24, 5>::new();
+        let left: Vec<FieldElement<Mersenne31Field>> = (0..8u64).map(FieldElement::from).collect();
+        let right: Vec<FieldElement<Mersenne31Field>> =
+            (8..16u64).map(FieldElement::from).collect();
+
+        let mut state = get_test_input(16);
+        state.resize(24, 0);
+        monolith.permutation(&mut state);
+        let expected: Vec<FieldElement<Mersenne31Field>> = state[..DIGEST_SIZE]
+            .iter()
+            .map(FieldElement::from)
+            .collect();
+
+        assert_eq!(monolith.compress(&left, &right), expected);
+    }
 }
@@ -1,55 +1,311 @@
-use alloc::vec::Vec;
 use lambdaworks_math::field::{
     fields::mersenne31::field::{Mersenne31Field, MERSENNE_31_PRIME_FIELD_ORDER},
     traits::IsField,
 };
-use sha3::{digest::XofReader, Shake128Reader};
 
 // Ported from https://github.com/Plonky3/Plonky3/blob/main/monolith
+// The constants are sampled with SHAKE128 in const functions, so that every instance of Monolith
+// has them computed at compile time.
 
 pub type F = Mersenne31Field;
 
-pub fn random_matrix(shake: &mut Shake128Reader, n: usize, m: usize) -> Vec<Vec<u32>> {
-    (0..n)
-        .map(|_| (0..m).map(|_| random_field_element(shake)).collect())
-        .collect()
+const KECCAK_ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+const KECCAK_RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+const KECCAK_PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+/// Rate of SHAKE128 in bytes.
+const SHAKE128_RATE: usize = 168;
+
+const fn keccak_f(mut a: [u64; 25]) -> [u64; 25] {
+    let mut round = 0;
+    while round < 24 {
+        // θ
+        let mut c = [0u64; 5];
+        let mut x = 0;
+        while x < 5 {
+            c[x] = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+            x += 1;
+        }
+        x = 0;
+        while x < 5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            let mut y = 0;
+            while y < 25 {
+                a[y + x] ^= d;
+                y += 5;
+            }
+            x += 1;
+        }
+        // ρ and π
+        let mut last = a[1];
+        let mut i = 0;
+        while i < 24 {
+            let tmp = a[KECCAK_PI[i]];
+            a[KECCAK_PI[i]] = last.rotate_left(KECCAK_RHO[i]);
+            last = tmp;
+            i += 1;
+        }
+        // χ
+        let mut y = 0;
+        while y < 25 {
+            let row = [a[y], a[y + 1], a[y + 2], a[y + 3], a[y + 4]];
+            let mut x = 0;
+            while x < 5 {
+                a[y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+                x += 1;
+            }
+            y += 5;
+        }
+        // ι
+        a[0] ^= KECCAK_ROUND_CONSTANTS[round];
+        round += 1;
+    }
+    a
+}
+
+/// SHAKE128 evaluated in const functions. It only absorbs inputs shorter than a block, which is
+/// enough for the seeds of Monolith. Since const functions can't take mutable references, reading
+/// consumes the reader and returns it back.
+pub struct ConstShake128 {
+    state: [u64; 25],
+    position: usize,
+}
+
+impl ConstShake128 {
+    pub const fn new(input: &[u8]) -> Self {
+        assert!(input.len() < SHAKE128_RATE);
+        let mut block = [0u8; SHAKE128_RATE];
+        let mut i = 0;
+        while i < input.len() {
+            block[i] = input[i];
+            i += 1;
+        }
+        block[input.len()] ^= 0x1f;
+        block[SHAKE128_RATE - 1] ^= 0x80;
+
+        let mut state = [0u64; 25];
+        let mut lane = 0;
+        while lane < SHAKE128_RATE / 8 {
+            let mut byte = 0;
+            while byte < 8 {
+                state[lane] |= (block[8 * lane + byte] as u64) << (8 * byte);
+                byte += 1;
+            }
+            lane += 1;
+        }
+
+        Self {
+            state: keccak_f(state),
+            position: 0,
+        }
+    }
+
+    const fn read_u8(mut self) -> (Self, u8) {
+        if self.position == SHAKE128_RATE {
+            self.state = keccak_f(self.state);
+            self.position = 0;
+        }
+        let byte = (self.state[self.position / 8] >> (8 * (self.position % 8))) as u8;
+        self.position += 1;
+        (self, byte)
+    }
+
+    pub const fn read_u32(self) -> (Self, u32) {
+        let (shake, b0) = self.read_u8();
+        let (shake, b1) = shake.read_u8();
+        let (shake, b2) = shake.read_u8();
+        let (shake, b3) = shake.read_u8();
+        (shake, u32::from_le_bytes([b0, b1, b2, b3]))
+    }
+}
+
+/// Returns the SHAKE128 reader of Monolith over Mersenne31, seeded with the parameters of the
+/// instance followed by `suffix`.
+pub const fn monolith_shake(width: usize, num_rounds: usize, suffix: &[u8]) -> ConstShake128 {
+    let mut seed = [0u8; 32];
+    let mut len = 0;
+    let mut i = 0;
+    while i < 8 {
+        seed[len] = b"Monolith"[i];
+        len += 1;
+        i += 1;
+    }
+    seed[len] = width as u8;
+    seed[len + 1] = num_rounds as u8;
+    len += 2;
+    let order = MERSENNE_31_PRIME_FIELD_ORDER.to_le_bytes();
+    i = 0;
+    while i < 4 {
+        seed[len] = order[i];
+        len += 1;
+        i += 1;
+    }
+    i = 0;
+    while i < suffix.len() {
+        seed[len] = suffix[i];
+        len += 1;
+        i += 1;
+    }
+    let (seed, _) = seed.split_at(len);
+    ConstShake128::new(seed)
 }
 
-fn random_field_element(shake: &mut Shake128Reader) -> u32 {
-    let mut val = shake_random_u32(shake);
+pub const fn random_matrix<const N: usize, const M: usize>(
+    mut shake: ConstShake128,
+) -> [[u32; M]; N] {
+    let mut matrix = [[0; M]; N];
+    let mut i = 0;
+    while i < N {
+        let mut j = 0;
+        while j < M {
+            let (next, value) = random_field_element(shake);
+            shake = next;
+            matrix[i][j] = value;
+            j += 1;
+        }
+        i += 1;
+    }
+    matrix
+}
+
+const fn random_field_element(shake: ConstShake128) -> (ConstShake128, u32) {
+    let (mut shake, mut val) = shake.read_u32();
     while val >= MERSENNE_31_PRIME_FIELD_ORDER {
-        val = shake_random_u32(shake);
+        (shake, val) = shake.read_u32();
     }
-    F::from_base_type(val)
+    (shake, val)
 }
 
-pub fn dot_product(u: &[u32], v: &[u32]) -> u32 {
-    Mersenne31Field::sum(u.iter().zip(v).map(|(x, y)| F::mul(x, y)))
+/// Returns the Cauchy matrix `1 / (x_i + y_j)` sampled for the MDS layer of widths other than 16.
+pub const fn cauchy_mds_matrix<const WIDTH: usize>(shake: ConstShake128) -> [[u32; WIDTH]; WIDTH] {
+    let bits: u32 = u64::BITS
+        - (MERSENNE_31_PRIME_FIELD_ORDER as u64)
+            .saturating_sub(1)
+            .leading_zeros();
+
+    let x_mask = (1 << (bits - 9)) - 1;
+    let y_mask = ((1 << bits) - 1) >> 2;
+
+    let y = random_y_i::<WIDTH>(shake, x_mask, y_mask);
+
+    let mut matrix = [[0; WIDTH]; WIDTH];
+    let mut i = 0;
+    while i < WIDTH {
+        let mut j = 0;
+        while j < WIDTH {
+            matrix[i][j] = inverse((y[i] & x_mask) + y[j]);
+            j += 1;
+        }
+        i += 1;
+    }
+    matrix
 }
 
-pub fn get_random_y_i(
-    shake: &mut Shake128Reader,
-    width: usize,
+const fn random_y_i<const WIDTH: usize>(
+    mut shake: ConstShake128,
     x_mask: u32,
     y_mask: u32,
-) -> Vec<u32> {
-    let mut res = vec![0; width];
+) -> [u32; WIDTH] {
+    let mut res = [0; WIDTH];
 
-    for i in 0..width {
-        let mut y_i = shake_random_u32(shake) & y_mask;
-        let mut x_i = y_i & x_mask;
-        while res.iter().take(i).any(|r| r & x_mask == x_i) {
-            y_i = shake_random_u32(shake) & y_mask;
-            x_i = y_i & x_mask;
+    let mut i = 0;
+    while i < WIDTH {
+        loop {
+            let (next, value) = shake.read_u32();
+            shake = next;
+            let y_i = value & y_mask;
+            let x_i = y_i & x_mask;
+            let mut j = 0;
+            while j < i && res[j] & x_mask != x_i {
+                j += 1;
+            }
+            if j == i {
+                res[i] = y_i;
+                break;
+            }
         }
-        res[i] = y_i;
+        i += 1;
     }
 
     res
 }
 
-fn shake_random_u32(shake: &mut Shake128Reader) -> u32 {
-    let mut rand = [0u8; 4];
-    shake.read(&mut rand);
-    u32::from_le_bytes(rand)
+/// Inverse of a nonzero element smaller than the modulus, as `x^(p - 2)`.
+const fn inverse(x: u32) -> u32 {
+    let p = MERSENNE_31_PRIME_FIELD_ORDER as u64;
+    let mut result = 1u64;
+    let mut base = x as u64;
+    let mut exponent = p - 2;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % p;
+        }
+        base = base * base % p;
+        exponent >>= 1;
+    }
+    result as u32
+}
+
+pub fn dot_product(u: &[u32], v: &[u32]) -> u32 {
+    Mersenne31Field::sum(u.iter().zip(v).map(|(x, y)| F::mul(x, y)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sha3::{
+        digest::{ExtendableOutput, Update, XofReader},
+        Shake128,
+    };
+
+    #[test]
+    fn const_shake128_matches_sha3() {
+        let seed = b"Monolith\x10\x06\xff\xff\xff\x7f\x08\x08\x08\x07";
+        let mut shake = Shake128::default();
+        shake.update(seed);
+        let mut expected = [0u8; 4 * 100];
+        shake.finalize_xof().read(&mut expected);
+
+        let mut reader = ConstShake128::new(seed);
+        for chunk in expected.chunks(4) {
+            let (next, value) = reader.read_u32();
+            reader = next;
+            assert_eq!(value, u32::from_le_bytes(chunk.try_into().unwrap()));
+        }
+    }
+
+    #[test]
+    fn inverse_is_the_field_inverse() {
+        for x in [1, 2, 3, 12345, MERSENNE_31_PRIME_FIELD_ORDER - 1] {
+            assert_eq!(inverse(x), F::inv(&x).unwrap());
+        }
+    }
 }
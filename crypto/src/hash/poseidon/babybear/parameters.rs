@@ -0,0 +1,626 @@
+use crate::hash::poseidon::PermutationParameters;
+use lambdaworks_math::field::{
+    element::FieldElement as FE, fields::fft_friendly::babybear::Babybear31PrimeField,
+};
+
+impl PermutationParameters for PoseidonBabyBear {
+    type F = Babybear31PrimeField;
+    const RATE: usize = 8;
+    const CAPACITY: usize = 8;
+    const ALPHA: u32 = 7;
+    const N_FULL_ROUNDS: usize = 8;
+    const N_PARTIAL_ROUNDS: usize = 13;
+
+    const MDS_MATRIX: &'static [FE<Babybear31PrimeField>] = &PoseidonBabyBear::MDS_MATRIX;
+    const N_MDS_MATRIX_ROWS: usize = 16;
+    const N_MDS_MATRIX_COLS: usize = 16;
+
+    const ROUND_CONSTANTS: &'static [FE<Babybear31PrimeField>] = &PoseidonBabyBear::ROUND_CONSTANTS;
+    const N_ROUND_CONSTANTS_ROWS: usize = 21;
+    const N_ROUND_CONSTANTS_COLS: usize = 16;
+}
+
+/// Poseidon over the BabyBear field: width 16 with rate 8, S-box x^7, 8 full rounds and
+/// 13 partial rounds. The constants are the ones of the reference instance of
+/// https://github.com/HorizenLabs/poseidon2, generated by the Grain LFSR.
+#[derive(Clone, Default)]
+pub struct PoseidonBabyBear;
+
+impl PoseidonBabyBear {
+    const MDS_MATRIX: [FE<Babybear31PrimeField>; 16 * 16] = [
+        FE::from_hex_unchecked("6ed88b54"),
+        FE::from_hex_unchecked("365c29f9"),
+        FE::from_hex_unchecked("29047ae"),
+        FE::from_hex_unchecked("340f575"),
+        FE::from_hex_unchecked("68418255"),
+        FE::from_hex_unchecked("315e4e40"),
+        FE::from_hex_unchecked("51596faa"),
+        FE::from_hex_unchecked("71183465"),
+        FE::from_hex_unchecked("2d036fca"),
+        FE::from_hex_unchecked("9e4fa24"),
+        FE::from_hex_unchecked("38e00966"),
+        FE::from_hex_unchecked("13e81974"),
+        FE::from_hex_unchecked("60f6dafc"),
+        FE::from_hex_unchecked("3c664116"),
+        FE::from_hex_unchecked("2e2b9d4b"),
+        FE::from_hex_unchecked("6c5f6689"),
+        FE::from_hex_unchecked("4a1d7fc9"),
+        FE::from_hex_unchecked("6506dfb7"),
+        FE::from_hex_unchecked("2f49702b"),
+        FE::from_hex_unchecked("3ebaafe9"),
+        FE::from_hex_unchecked("718fc5d2"),
+        FE::from_hex_unchecked("14dcbeed"),
+        FE::from_hex_unchecked("27a38245"),
+        FE::from_hex_unchecked("528238e5"),
+        FE::from_hex_unchecked("10663404"),
+        FE::from_hex_unchecked("2a504978"),
+        FE::from_hex_unchecked("b027c2e"),
+        FE::from_hex_unchecked("4bdd7226"),
+        FE::from_hex_unchecked("1ebc59"),
+        FE::from_hex_unchecked("52555194"),
+        FE::from_hex_unchecked("148df9b7"),
+        FE::from_hex_unchecked("13587d5"),
+        FE::from_hex_unchecked("3a74096"),
+        FE::from_hex_unchecked("5b791ccd"),
+        FE::from_hex_unchecked("2e3a2688"),
+        FE::from_hex_unchecked("26b936dc"),
+        FE::from_hex_unchecked("a0df4c3"),
+        FE::from_hex_unchecked("4a1e89d8"),
+        FE::from_hex_unchecked("7a37b58"),
+        FE::from_hex_unchecked("3ff10121"),
+        FE::from_hex_unchecked("4912f3d0"),
+        FE::from_hex_unchecked("e5cbd22"),
+        FE::from_hex_unchecked("86832d"),
+        FE::from_hex_unchecked("5bc8209b"),
+        FE::from_hex_unchecked("ecaa423"),
+        FE::from_hex_unchecked("c8b360e"),
+        FE::from_hex_unchecked("542abb63"),
+        FE::from_hex_unchecked("198e5ede"),
+        FE::from_hex_unchecked("2ba24027"),
+        FE::from_hex_unchecked("5c61cbe2"),
+        FE::from_hex_unchecked("15dabd0c"),
+        FE::from_hex_unchecked("58763be5"),
+        FE::from_hex_unchecked("70a28315"),
+        FE::from_hex_unchecked("41a8f9fb"),
+        FE::from_hex_unchecked("3786bc6d"),
+        FE::from_hex_unchecked("19ca59f8"),
+        FE::from_hex_unchecked("42185a03"),
+        FE::from_hex_unchecked("48223bcf"),
+        FE::from_hex_unchecked("190d6883"),
+        FE::from_hex_unchecked("86269de"),
+        FE::from_hex_unchecked("3630ddef"),
+        FE::from_hex_unchecked("2c0dbdd3"),
+        FE::from_hex_unchecked("2c07ad23"),
+        FE::from_hex_unchecked("29561d75"),
+        FE::from_hex_unchecked("2db3561a"),
+        FE::from_hex_unchecked("349aabc3"),
+        FE::from_hex_unchecked("aeb611d"),
+        FE::from_hex_unchecked("32c2bd61"),
+        FE::from_hex_unchecked("38527028"),
+        FE::from_hex_unchecked("1483f37b"),
+        FE::from_hex_unchecked("3b28e234"),
+        FE::from_hex_unchecked("5a2762d4"),
+        FE::from_hex_unchecked("47c2d9d7"),
+        FE::from_hex_unchecked("372c5808"),
+        FE::from_hex_unchecked("54d14bd6"),
+        FE::from_hex_unchecked("49690d33"),
+        FE::from_hex_unchecked("e015d17"),
+        FE::from_hex_unchecked("6aabc1ec"),
+        FE::from_hex_unchecked("3070eff3"),
+        FE::from_hex_unchecked("20b26c05"),
+        FE::from_hex_unchecked("2e19ba8d"),
+        FE::from_hex_unchecked("d23450a"),
+        FE::from_hex_unchecked("2e5d9599"),
+        FE::from_hex_unchecked("57521a71"),
+        FE::from_hex_unchecked("fe30b31"),
+        FE::from_hex_unchecked("2d125f10"),
+        FE::from_hex_unchecked("bcd2b75"),
+        FE::from_hex_unchecked("1c5c0995"),
+        FE::from_hex_unchecked("13538cc1"),
+        FE::from_hex_unchecked("3e89bab1"),
+        FE::from_hex_unchecked("ecd2c52"),
+        FE::from_hex_unchecked("4a242476"),
+        FE::from_hex_unchecked("3bd8f726"),
+        FE::from_hex_unchecked("6f61a168"),
+        FE::from_hex_unchecked("4be24d17"),
+        FE::from_hex_unchecked("3612cac7"),
+        FE::from_hex_unchecked("2d922f25"),
+        FE::from_hex_unchecked("6edf0857"),
+        FE::from_hex_unchecked("33af4716"),
+        FE::from_hex_unchecked("724e5171"),
+        FE::from_hex_unchecked("2a7361d2"),
+        FE::from_hex_unchecked("123ecf2e"),
+        FE::from_hex_unchecked("2275c912"),
+        FE::from_hex_unchecked("21f75377"),
+        FE::from_hex_unchecked("b4a2d3c"),
+        FE::from_hex_unchecked("61940261"),
+        FE::from_hex_unchecked("335fa739"),
+        FE::from_hex_unchecked("3e18aca4"),
+        FE::from_hex_unchecked("1255c968"),
+        FE::from_hex_unchecked("627c139"),
+        FE::from_hex_unchecked("c870799"),
+        FE::from_hex_unchecked("1a271c86"),
+        FE::from_hex_unchecked("3722cccf"),
+        FE::from_hex_unchecked("3e2ac9f8"),
+        FE::from_hex_unchecked("3c6899ac"),
+        FE::from_hex_unchecked("6ced5ba0"),
+        FE::from_hex_unchecked("767999cd"),
+        FE::from_hex_unchecked("12fdf314"),
+        FE::from_hex_unchecked("284d52da"),
+        FE::from_hex_unchecked("10fa38b"),
+        FE::from_hex_unchecked("14e29a97"),
+        FE::from_hex_unchecked("58b4180e"),
+        FE::from_hex_unchecked("19b6c4ac"),
+        FE::from_hex_unchecked("722e27d3"),
+        FE::from_hex_unchecked("39a050e6"),
+        FE::from_hex_unchecked("4d05c08a"),
+        FE::from_hex_unchecked("3dbffefa"),
+        FE::from_hex_unchecked("61f01023"),
+        FE::from_hex_unchecked("4237a659"),
+        FE::from_hex_unchecked("4947e8c3"),
+        FE::from_hex_unchecked("69342f5"),
+        FE::from_hex_unchecked("15171937"),
+        FE::from_hex_unchecked("554eef00"),
+        FE::from_hex_unchecked("2ff329af"),
+        FE::from_hex_unchecked("a5129f2"),
+        FE::from_hex_unchecked("634b85d7"),
+        FE::from_hex_unchecked("2a8adb36"),
+        FE::from_hex_unchecked("c41318c"),
+        FE::from_hex_unchecked("5ac8ad85"),
+        FE::from_hex_unchecked("75823102"),
+        FE::from_hex_unchecked("49a8c8f4"),
+        FE::from_hex_unchecked("37b4dc23"),
+        FE::from_hex_unchecked("48383ddd"),
+        FE::from_hex_unchecked("2917cc4a"),
+        FE::from_hex_unchecked("64ded285"),
+        FE::from_hex_unchecked("2cf24480"),
+        FE::from_hex_unchecked("48a51fa3"),
+        FE::from_hex_unchecked("3f6d1fa6"),
+        FE::from_hex_unchecked("67fb2b58"),
+        FE::from_hex_unchecked("4ee85b14"),
+        FE::from_hex_unchecked("185c875"),
+        FE::from_hex_unchecked("5c7a942d"),
+        FE::from_hex_unchecked("700e51a2"),
+        FE::from_hex_unchecked("15aff07d"),
+        FE::from_hex_unchecked("303754a2"),
+        FE::from_hex_unchecked("18a3e923"),
+        FE::from_hex_unchecked("28f104b7"),
+        FE::from_hex_unchecked("4d81102d"),
+        FE::from_hex_unchecked("26eee317"),
+        FE::from_hex_unchecked("2624abc9"),
+        FE::from_hex_unchecked("1cabd063"),
+        FE::from_hex_unchecked("2100450e"),
+        FE::from_hex_unchecked("1f3be9dd"),
+        FE::from_hex_unchecked("2a76b379"),
+        FE::from_hex_unchecked("3f18d12a"),
+        FE::from_hex_unchecked("bb69105"),
+        FE::from_hex_unchecked("4d8a7ac"),
+        FE::from_hex_unchecked("1faded71"),
+        FE::from_hex_unchecked("b2d86dd"),
+        FE::from_hex_unchecked("5b4fd3ea"),
+        FE::from_hex_unchecked("d7f56e4"),
+        FE::from_hex_unchecked("4c934264"),
+        FE::from_hex_unchecked("578cbc"),
+        FE::from_hex_unchecked("4ac335a4"),
+        FE::from_hex_unchecked("6de618f4"),
+        FE::from_hex_unchecked("12430d70"),
+        FE::from_hex_unchecked("69a43964"),
+        FE::from_hex_unchecked("27be8c04"),
+        FE::from_hex_unchecked("5bbbf28"),
+        FE::from_hex_unchecked("6a6fd5f2"),
+        FE::from_hex_unchecked("29ff4a2"),
+        FE::from_hex_unchecked("7480e7d2"),
+        FE::from_hex_unchecked("6ebd8697"),
+        FE::from_hex_unchecked("505ac2f2"),
+        FE::from_hex_unchecked("42198491"),
+        FE::from_hex_unchecked("3e22dde8"),
+        FE::from_hex_unchecked("19b3ffc9"),
+        FE::from_hex_unchecked("1c029b85"),
+        FE::from_hex_unchecked("3c83bd24"),
+        FE::from_hex_unchecked("5d48ee0c"),
+        FE::from_hex_unchecked("51db5088"),
+        FE::from_hex_unchecked("6a044125"),
+        FE::from_hex_unchecked("33e3e9f"),
+        FE::from_hex_unchecked("320cdce6"),
+        FE::from_hex_unchecked("42ceb3a3"),
+        FE::from_hex_unchecked("2c39ef78"),
+        FE::from_hex_unchecked("129f22c0"),
+        FE::from_hex_unchecked("38ce048"),
+        FE::from_hex_unchecked("200c7aa6"),
+        FE::from_hex_unchecked("37297a16"),
+        FE::from_hex_unchecked("6957af38"),
+        FE::from_hex_unchecked("3efaaf35"),
+        FE::from_hex_unchecked("47086768"),
+        FE::from_hex_unchecked("65017cbd"),
+        FE::from_hex_unchecked("3e52dd44"),
+        FE::from_hex_unchecked("632cdfec"),
+        FE::from_hex_unchecked("1e21dc74"),
+        FE::from_hex_unchecked("2b68bcf"),
+        FE::from_hex_unchecked("2526f6b9"),
+        FE::from_hex_unchecked("18cdaeca"),
+        FE::from_hex_unchecked("1a64dd5e"),
+        FE::from_hex_unchecked("ed814ac"),
+        FE::from_hex_unchecked("32a9eb40"),
+        FE::from_hex_unchecked("2f276e0d"),
+        FE::from_hex_unchecked("30c6664b"),
+        FE::from_hex_unchecked("ecfbdf7"),
+        FE::from_hex_unchecked("6e70acbc"),
+        FE::from_hex_unchecked("1df04c92"),
+        FE::from_hex_unchecked("44090fa0"),
+        FE::from_hex_unchecked("69e69133"),
+        FE::from_hex_unchecked("1bca435a"),
+        FE::from_hex_unchecked("cb43110"),
+        FE::from_hex_unchecked("19f651ca"),
+        FE::from_hex_unchecked("3ea3be87"),
+        FE::from_hex_unchecked("32bf42c"),
+        FE::from_hex_unchecked("72b82f03"),
+        FE::from_hex_unchecked("2b56b081"),
+        FE::from_hex_unchecked("377703d9"),
+        FE::from_hex_unchecked("49cadd18"),
+        FE::from_hex_unchecked("3f7a6788"),
+        FE::from_hex_unchecked("1be5dac5"),
+        FE::from_hex_unchecked("1c81f75b"),
+        FE::from_hex_unchecked("6983b960"),
+        FE::from_hex_unchecked("67493e8"),
+        FE::from_hex_unchecked("4a4c05c9"),
+        FE::from_hex_unchecked("4dbd1a7"),
+        FE::from_hex_unchecked("2d30836d"),
+        FE::from_hex_unchecked("67454db1"),
+        FE::from_hex_unchecked("70de7fb4"),
+        FE::from_hex_unchecked("331eb4b8"),
+        FE::from_hex_unchecked("574bceed"),
+        FE::from_hex_unchecked("3c1e600e"),
+        FE::from_hex_unchecked("3a1b91b1"),
+        FE::from_hex_unchecked("69369cf9"),
+        FE::from_hex_unchecked("5e68d7ff"),
+        FE::from_hex_unchecked("2ddcd8d4"),
+        FE::from_hex_unchecked("5aff0a6d"),
+        FE::from_hex_unchecked("7068c4fc"),
+        FE::from_hex_unchecked("fcb3bfd"),
+        FE::from_hex_unchecked("133838c7"),
+        FE::from_hex_unchecked("bac1e88"),
+        FE::from_hex_unchecked("13ee81b1"),
+        FE::from_hex_unchecked("2eecf375"),
+        FE::from_hex_unchecked("1450e2a1"),
+        FE::from_hex_unchecked("474d780d"),
+        FE::from_hex_unchecked("3a9c651c"),
+    ];
+    const ROUND_CONSTANTS: [FE<Babybear31PrimeField>; 16 * 21] = [
+        FE::from_hex_unchecked("22d14fc7"),
+        FE::from_hex_unchecked("47743d29"),
+        FE::from_hex_unchecked("677f35c3"),
+        FE::from_hex_unchecked("3ae46df5"),
+        FE::from_hex_unchecked("24f86039"),
+        FE::from_hex_unchecked("4eb76fe3"),
+        FE::from_hex_unchecked("463c658a"),
+        FE::from_hex_unchecked("6ee674e"),
+        FE::from_hex_unchecked("21e71f9b"),
+        FE::from_hex_unchecked("50cd5cec"),
+        FE::from_hex_unchecked("5959fa83"),
+        FE::from_hex_unchecked("10a5b894"),
+        FE::from_hex_unchecked("cf4d6db"),
+        FE::from_hex_unchecked("32b95009"),
+        FE::from_hex_unchecked("40299d0f"),
+        FE::from_hex_unchecked("31762364"),
+        FE::from_hex_unchecked("77a932b9"),
+        FE::from_hex_unchecked("4d5f9435"),
+        FE::from_hex_unchecked("585e5f8d"),
+        FE::from_hex_unchecked("68852880"),
+        FE::from_hex_unchecked("186d0ef0"),
+        FE::from_hex_unchecked("bc8e16b"),
+        FE::from_hex_unchecked("33efc152"),
+        FE::from_hex_unchecked("4818fbb4"),
+        FE::from_hex_unchecked("60b89701"),
+        FE::from_hex_unchecked("217f693a"),
+        FE::from_hex_unchecked("242e7dd9"),
+        FE::from_hex_unchecked("2c602bd2"),
+        FE::from_hex_unchecked("3da5d2a9"),
+        FE::from_hex_unchecked("44d38735"),
+        FE::from_hex_unchecked("b8f704"),
+        FE::from_hex_unchecked("6d897b7f"),
+        FE::from_hex_unchecked("26d51110"),
+        FE::from_hex_unchecked("4b9eaa24"),
+        FE::from_hex_unchecked("59f60b8a"),
+        FE::from_hex_unchecked("481b4e03"),
+        FE::from_hex_unchecked("4898afc8"),
+        FE::from_hex_unchecked("21a0b0cc"),
+        FE::from_hex_unchecked("2b03c2df"),
+        FE::from_hex_unchecked("fea4ba0"),
+        FE::from_hex_unchecked("4bf2e3e8"),
+        FE::from_hex_unchecked("2073fdff"),
+        FE::from_hex_unchecked("3285abb7"),
+        FE::from_hex_unchecked("3ac0d1e9"),
+        FE::from_hex_unchecked("13d65258"),
+        FE::from_hex_unchecked("16b7a570"),
+        FE::from_hex_unchecked("45bcb00f"),
+        FE::from_hex_unchecked("5ba25311"),
+        FE::from_hex_unchecked("f78e922"),
+        FE::from_hex_unchecked("294613f3"),
+        FE::from_hex_unchecked("1c377a1d"),
+        FE::from_hex_unchecked("2c19a8f6"),
+        FE::from_hex_unchecked("46e75290"),
+        FE::from_hex_unchecked("34bdbe27"),
+        FE::from_hex_unchecked("103e09a6"),
+        FE::from_hex_unchecked("32f00a1c"),
+        FE::from_hex_unchecked("193d8310"),
+        FE::from_hex_unchecked("48f5804b"),
+        FE::from_hex_unchecked("6866d66a"),
+        FE::from_hex_unchecked("5e6320a"),
+        FE::from_hex_unchecked("6250e5ba"),
+        FE::from_hex_unchecked("f64c9de"),
+        FE::from_hex_unchecked("27f3a9c3"),
+        FE::from_hex_unchecked("367b028e"),
+        FE::from_hex_unchecked("3062eba8"),
+        FE::from_hex_unchecked("66794e00"),
+        FE::from_hex_unchecked("3a8857de"),
+        FE::from_hex_unchecked("311769e5"),
+        FE::from_hex_unchecked("269b55e6"),
+        FE::from_hex_unchecked("5962fc4d"),
+        FE::from_hex_unchecked("26e2a5a0"),
+        FE::from_hex_unchecked("1f6aec92"),
+        FE::from_hex_unchecked("66ff3568"),
+        FE::from_hex_unchecked("11c60cd"),
+        FE::from_hex_unchecked("2347af5a"),
+        FE::from_hex_unchecked("57bb56a4"),
+        FE::from_hex_unchecked("64de8246"),
+        FE::from_hex_unchecked("70f587ad"),
+        FE::from_hex_unchecked("4a91fd65"),
+        FE::from_hex_unchecked("3279477b"),
+        FE::from_hex_unchecked("6ded73c5"),
+        FE::from_hex_unchecked("75418e3f"),
+        FE::from_hex_unchecked("2a0229"),
+        FE::from_hex_unchecked("21fbe33c"),
+        FE::from_hex_unchecked("206b9591"),
+        FE::from_hex_unchecked("4133e248"),
+        FE::from_hex_unchecked("3e0d0cc5"),
+        FE::from_hex_unchecked("1e2351fd"),
+        FE::from_hex_unchecked("78e4519"),
+        FE::from_hex_unchecked("30a9d69e"),
+        FE::from_hex_unchecked("7689ab87"),
+        FE::from_hex_unchecked("ae7a298"),
+        FE::from_hex_unchecked("29923cb"),
+        FE::from_hex_unchecked("24a2b1b"),
+        FE::from_hex_unchecked("1d60dbdf"),
+        FE::from_hex_unchecked("3d8af2b3"),
+        FE::from_hex_unchecked("36174f16"),
+        FE::from_hex_unchecked("397bf853"),
+        FE::from_hex_unchecked("478f1171"),
+        FE::from_hex_unchecked("202b95f5"),
+        FE::from_hex_unchecked("22a49e9a"),
+        FE::from_hex_unchecked("593a115d"),
+        FE::from_hex_unchecked("65f5a466"),
+        FE::from_hex_unchecked("56fdefeb"),
+        FE::from_hex_unchecked("aa188c1"),
+        FE::from_hex_unchecked("5982426b"),
+        FE::from_hex_unchecked("71f6cb38"),
+        FE::from_hex_unchecked("594a711d"),
+        FE::from_hex_unchecked("2033eea8"),
+        FE::from_hex_unchecked("c20b93b"),
+        FE::from_hex_unchecked("4873d752"),
+        FE::from_hex_unchecked("413cdb68"),
+        FE::from_hex_unchecked("1020fb7b"),
+        FE::from_hex_unchecked("55642c83"),
+        FE::from_hex_unchecked("4c3ca55e"),
+        FE::from_hex_unchecked("5cc9d020"),
+        FE::from_hex_unchecked("1bf4025"),
+        FE::from_hex_unchecked("5d53256f"),
+        FE::from_hex_unchecked("12110fe3"),
+        FE::from_hex_unchecked("5f325b00"),
+        FE::from_hex_unchecked("5ed0223"),
+        FE::from_hex_unchecked("6f73787e"),
+        FE::from_hex_unchecked("4250e6af"),
+        FE::from_hex_unchecked("5a1f50f"),
+        FE::from_hex_unchecked("5cedfe10"),
+        FE::from_hex_unchecked("5da8026b"),
+        FE::from_hex_unchecked("86fe6ee"),
+        FE::from_hex_unchecked("29e60bc0"),
+        FE::from_hex_unchecked("1c725c10"),
+        FE::from_hex_unchecked("1c3f0e56"),
+        FE::from_hex_unchecked("3bd7fb18"),
+        FE::from_hex_unchecked("24d8e7d6"),
+        FE::from_hex_unchecked("502b1331"),
+        FE::from_hex_unchecked("1570f4c6"),
+        FE::from_hex_unchecked("7060c4b4"),
+        FE::from_hex_unchecked("2822b9fe"),
+        FE::from_hex_unchecked("379b4036"),
+        FE::from_hex_unchecked("3c9ab0d8"),
+        FE::from_hex_unchecked("35bd29cf"),
+        FE::from_hex_unchecked("3353ba79"),
+        FE::from_hex_unchecked("72bc5629"),
+        FE::from_hex_unchecked("2e553ca6"),
+        FE::from_hex_unchecked("23131dd6"),
+        FE::from_hex_unchecked("46e96cd6"),
+        FE::from_hex_unchecked("4d65f5dc"),
+        FE::from_hex_unchecked("3fe8d06f"),
+        FE::from_hex_unchecked("6a4c8483"),
+        FE::from_hex_unchecked("6a9d46ea"),
+        FE::from_hex_unchecked("51b39333"),
+        FE::from_hex_unchecked("773dd4bb"),
+        FE::from_hex_unchecked("75472220"),
+        FE::from_hex_unchecked("3536ac5"),
+        FE::from_hex_unchecked("3fc93d6f"),
+        FE::from_hex_unchecked("1bd92d69"),
+        FE::from_hex_unchecked("6620b70f"),
+        FE::from_hex_unchecked("26e72902"),
+        FE::from_hex_unchecked("25eebb36"),
+        FE::from_hex_unchecked("66edfccc"),
+        FE::from_hex_unchecked("37779413"),
+        FE::from_hex_unchecked("60e28ba3"),
+        FE::from_hex_unchecked("741d0b77"),
+        FE::from_hex_unchecked("60f2d017"),
+        FE::from_hex_unchecked("23a25699"),
+        FE::from_hex_unchecked("2f063482"),
+        FE::from_hex_unchecked("1c768088"),
+        FE::from_hex_unchecked("11733e6b"),
+        FE::from_hex_unchecked("65d84305"),
+        FE::from_hex_unchecked("11c4ca51"),
+        FE::from_hex_unchecked("fd5b6d7"),
+        FE::from_hex_unchecked("6469045d"),
+        FE::from_hex_unchecked("40257c38"),
+        FE::from_hex_unchecked("4c1d66ac"),
+        FE::from_hex_unchecked("3a86fb76"),
+        FE::from_hex_unchecked("6545288f"),
+        FE::from_hex_unchecked("947328"),
+        FE::from_hex_unchecked("40d6abcb"),
+        FE::from_hex_unchecked("6f5d7715"),
+        FE::from_hex_unchecked("9801dd4"),
+        FE::from_hex_unchecked("389b74f8"),
+        FE::from_hex_unchecked("23461c1a"),
+        FE::from_hex_unchecked("1ae8ae39"),
+        FE::from_hex_unchecked("111cc3cb"),
+        FE::from_hex_unchecked("5b08fb1f"),
+        FE::from_hex_unchecked("5d2826af"),
+        FE::from_hex_unchecked("59c2918b"),
+        FE::from_hex_unchecked("392ece30"),
+        FE::from_hex_unchecked("56cb9b3b"),
+        FE::from_hex_unchecked("66bc00b1"),
+        FE::from_hex_unchecked("33d307df"),
+        FE::from_hex_unchecked("69624a18"),
+        FE::from_hex_unchecked("66eb09dc"),
+        FE::from_hex_unchecked("6597fd4c"),
+        FE::from_hex_unchecked("612fbfbd"),
+        FE::from_hex_unchecked("32c37391"),
+        FE::from_hex_unchecked("6b8bde71"),
+        FE::from_hex_unchecked("77384d19"),
+        FE::from_hex_unchecked("6580ce59"),
+        FE::from_hex_unchecked("169b7580"),
+        FE::from_hex_unchecked("6616ad73"),
+        FE::from_hex_unchecked("3c1887de"),
+        FE::from_hex_unchecked("75e3f2e5"),
+        FE::from_hex_unchecked("3ef08074"),
+        FE::from_hex_unchecked("59f70462"),
+        FE::from_hex_unchecked("27012115"),
+        FE::from_hex_unchecked("2ecc37d6"),
+        FE::from_hex_unchecked("52b25aa4"),
+        FE::from_hex_unchecked("e71eb68"),
+        FE::from_hex_unchecked("6a5b543d"),
+        FE::from_hex_unchecked("2f47db02"),
+        FE::from_hex_unchecked("6b426d43"),
+        FE::from_hex_unchecked("22e2a257"),
+        FE::from_hex_unchecked("5689f5d"),
+        FE::from_hex_unchecked("53000206"),
+        FE::from_hex_unchecked("62174579"),
+        FE::from_hex_unchecked("41ee708e"),
+        FE::from_hex_unchecked("4c69f70e"),
+        FE::from_hex_unchecked("3a403a2c"),
+        FE::from_hex_unchecked("74a944ff"),
+        FE::from_hex_unchecked("c5369ae"),
+        FE::from_hex_unchecked("8360919"),
+        FE::from_hex_unchecked("20502e46"),
+        FE::from_hex_unchecked("3aaba434"),
+        FE::from_hex_unchecked("6a3f85ff"),
+        FE::from_hex_unchecked("4a9a527"),
+        FE::from_hex_unchecked("3b985df9"),
+        FE::from_hex_unchecked("5ed46d10"),
+        FE::from_hex_unchecked("4b48f8aa"),
+        FE::from_hex_unchecked("3365ee9c"),
+        FE::from_hex_unchecked("688d45f5"),
+        FE::from_hex_unchecked("52f52e90"),
+        FE::from_hex_unchecked("48bfad48"),
+        FE::from_hex_unchecked("f5474cb"),
+        FE::from_hex_unchecked("3b31034"),
+        FE::from_hex_unchecked("23c4e6fb"),
+        FE::from_hex_unchecked("37835e64"),
+        FE::from_hex_unchecked("24e8a57b"),
+        FE::from_hex_unchecked("700e9dd8"),
+        FE::from_hex_unchecked("44a95941"),
+        FE::from_hex_unchecked("3a495982"),
+        FE::from_hex_unchecked("3dd606f4"),
+        FE::from_hex_unchecked("6b990415"),
+        FE::from_hex_unchecked("5f419361"),
+        FE::from_hex_unchecked("18992a1f"),
+        FE::from_hex_unchecked("50d0225b"),
+        FE::from_hex_unchecked("16959009"),
+        FE::from_hex_unchecked("75c0bf50"),
+        FE::from_hex_unchecked("b95ce3d"),
+        FE::from_hex_unchecked("10deaf8f"),
+        FE::from_hex_unchecked("36ca8803"),
+        FE::from_hex_unchecked("52d760ce"),
+        FE::from_hex_unchecked("49a1d323"),
+        FE::from_hex_unchecked("1cee5d28"),
+        FE::from_hex_unchecked("517d182a"),
+        FE::from_hex_unchecked("445e59d5"),
+        FE::from_hex_unchecked("f9dd8a7"),
+        FE::from_hex_unchecked("5e137128"),
+        FE::from_hex_unchecked("70c68bd4"),
+        FE::from_hex_unchecked("4e452a12"),
+        FE::from_hex_unchecked("42c2636b"),
+        FE::from_hex_unchecked("5b5fa86c"),
+        FE::from_hex_unchecked("317568b1"),
+        FE::from_hex_unchecked("3e62c70"),
+        FE::from_hex_unchecked("1bb59265"),
+        FE::from_hex_unchecked("1baf7be7"),
+        FE::from_hex_unchecked("6376d40e"),
+        FE::from_hex_unchecked("548d4eca"),
+        FE::from_hex_unchecked("75e7d9f1"),
+        FE::from_hex_unchecked("4f9f6c94"),
+        FE::from_hex_unchecked("412cbbc5"),
+        FE::from_hex_unchecked("580e0d79"),
+        FE::from_hex_unchecked("21bb6865"),
+        FE::from_hex_unchecked("3f50966b"),
+        FE::from_hex_unchecked("3522ced6"),
+        FE::from_hex_unchecked("2a1988c9"),
+        FE::from_hex_unchecked("63cc080d"),
+        FE::from_hex_unchecked("2b1f3d4c"),
+        FE::from_hex_unchecked("4506613e"),
+        FE::from_hex_unchecked("60102247"),
+        FE::from_hex_unchecked("33a8ed0c"),
+        FE::from_hex_unchecked("4918947e"),
+        FE::from_hex_unchecked("4528a692"),
+        FE::from_hex_unchecked("1092a39e"),
+        FE::from_hex_unchecked("3b1f852a"),
+        FE::from_hex_unchecked("2691e16d"),
+        FE::from_hex_unchecked("36b79fd6"),
+        FE::from_hex_unchecked("108673bf"),
+        FE::from_hex_unchecked("66addb91"),
+        FE::from_hex_unchecked("f453f41"),
+        FE::from_hex_unchecked("48b36260"),
+        FE::from_hex_unchecked("6f748480"),
+        FE::from_hex_unchecked("429817b2"),
+        FE::from_hex_unchecked("23073ebf"),
+        FE::from_hex_unchecked("4dbba065"),
+        FE::from_hex_unchecked("4cdb2356"),
+        FE::from_hex_unchecked("72c494a2"),
+        FE::from_hex_unchecked("4733a44d"),
+        FE::from_hex_unchecked("76a19165"),
+        FE::from_hex_unchecked("6c7872c8"),
+        FE::from_hex_unchecked("4bc245e1"),
+        FE::from_hex_unchecked("6d1efcfa"),
+        FE::from_hex_unchecked("29be57e1"),
+        FE::from_hex_unchecked("5eef5121"),
+        FE::from_hex_unchecked("fda3d38"),
+        FE::from_hex_unchecked("2873e796"),
+        FE::from_hex_unchecked("10e8340b"),
+        FE::from_hex_unchecked("1bd00ac6"),
+        FE::from_hex_unchecked("4b8db5b7"),
+        FE::from_hex_unchecked("61fe659f"),
+        FE::from_hex_unchecked("247777c9"),
+        FE::from_hex_unchecked("468bdea"),
+        FE::from_hex_unchecked("e9f932b"),
+        FE::from_hex_unchecked("4d35799d"),
+        FE::from_hex_unchecked("449265f"),
+        FE::from_hex_unchecked("45e3b326"),
+        FE::from_hex_unchecked("220818ed"),
+        FE::from_hex_unchecked("686090e1"),
+        FE::from_hex_unchecked("69361c44"),
+        FE::from_hex_unchecked("ab186f2"),
+        FE::from_hex_unchecked("348a8c1a"),
+        FE::from_hex_unchecked("77a8cb46"),
+        FE::from_hex_unchecked("776673b7"),
+        FE::from_hex_unchecked("3764f92a"),
+        FE::from_hex_unchecked("4b49f079"),
+        FE::from_hex_unchecked("68c7915"),
+        FE::from_hex_unchecked("6ae894f1"),
+        FE::from_hex_unchecked("3ca29798"),
+        FE::from_hex_unchecked("27f0ccd0"),
+        FE::from_hex_unchecked("51bb1b3b"),
+        FE::from_hex_unchecked("1f45baaa"),
+        FE::from_hex_unchecked("22a18c6a"),
+        FE::from_hex_unchecked("5172d793"),
+        FE::from_hex_unchecked("46560975"),
+        FE::from_hex_unchecked("12ac2c28"),
+        FE::from_hex_unchecked("4ec30409"),
+        FE::from_hex_unchecked("f41009b"),
+        FE::from_hex_unchecked("71ea9382"),
+    ];
+}
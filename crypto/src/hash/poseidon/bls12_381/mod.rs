@@ -0,0 +1,2 @@
+pub mod parameters;
+pub use parameters::PoseidonBLS12381;
@@ -0,0 +1,236 @@
+use crate::hash::poseidon::PermutationParameters;
+use lambdaworks_math::{
+    elliptic_curve::short_weierstrass::curves::bls12_381::default_types::FrField,
+    field::element::FieldElement as FE,
+};
+
+impl PermutationParameters for PoseidonBLS12381 {
+    type F = FrField;
+    const RATE: usize = 2;
+    const CAPACITY: usize = 1;
+    const ALPHA: u32 = 5;
+    const N_FULL_ROUNDS: usize = 8;
+    const N_PARTIAL_ROUNDS: usize = 56;
+
+    const MDS_MATRIX: &'static [FE<FrField>] = &PoseidonBLS12381::MDS_MATRIX;
+    const N_MDS_MATRIX_ROWS: usize = 3;
+    const N_MDS_MATRIX_COLS: usize = 3;
+
+    const ROUND_CONSTANTS: &'static [FE<FrField>] = &PoseidonBLS12381::ROUND_CONSTANTS;
+    const N_ROUND_CONSTANTS_ROWS: usize = 64;
+    const N_ROUND_CONSTANTS_COLS: usize = 3;
+}
+
+/// Poseidon over the scalar field of BLS12-381: width 3, S-box x^5, 8 full rounds and
+/// 56 partial rounds. The constants are the ones of the reference instance of
+/// https://github.com/HorizenLabs/poseidon2, generated by the Grain LFSR.
+#[derive(Clone, Default)]
+pub struct PoseidonBLS12381;
+
+impl PoseidonBLS12381 {
+    const MDS_MATRIX: [FE<FrField>; 3 * 3] = [
+        FE::from_hex_unchecked("211d0c9c28920044b34c2ed4a4a2b4e3139485ee7cf3598c291cdcf51b48ba21"),
+        FE::from_hex_unchecked("b27c81f25198de28b7637b4c371222a703fdeee65998039126851dc77d585c2"),
+        FE::from_hex_unchecked("4603216569f6eeaecf37f66ce250c1bbfd638e9209014c39a833d3757a46bc7"),
+        FE::from_hex_unchecked("1f23916bea4ffb4a28f2f0d759325de7f90f2e137b5482853f50fde61859b73a"),
+        FE::from_hex_unchecked("5c1e7a8011313fa573f806471815824b70fbfbe4bc3e81819042b6b4efad659e"),
+        FE::from_hex_unchecked("4d349bb595829ac30788875eaba85d488cbcf4f2c370cdb424be193afc109e7b"),
+        FE::from_hex_unchecked("59db7377f026a6f02f7c90535b93db9cfc82bbaefa1deba36f1087880e6dc2c9"),
+        FE::from_hex_unchecked("4a83f6ddbf04a70f99e13e1fd72e4d4e0a9ca509c1c30bdae07508503cbc8ce9"),
+        FE::from_hex_unchecked("65d256a96817f3ab2a30d19785500fe898df9589cb8af15ec7c4a2e33aaaa87b"),
+    ];
+    const ROUND_CONSTANTS: [FE<FrField>; 3 * 64] = [
+        FE::from_hex_unchecked("452088f7ec90c80818a1b5665f38ea30116456becd6709977cd8a2e0a2b38b62"),
+        FE::from_hex_unchecked("21512346b8ece60d5951c1505089c2b4220707ca56373bb9d828fa33bbfd2a31"),
+        FE::from_hex_unchecked("2b3a40252c69e83e92c548e199bbbeba4291e0d7fc3b4810193606753da588c8"),
+        FE::from_hex_unchecked("2a1a778e3f303c4187c082ea4475734596fb10bd2954843e12be80e8c1c0d464"),
+        FE::from_hex_unchecked("400df3a9dd4631e354222e1b1c2ab7092feb19a96eef425270e2016e2348fb96"),
+        FE::from_hex_unchecked("667e2e3deaf0278725697f5acc7222fd572e22944d04d7fd2f86c5c1c0cea988"),
+        FE::from_hex_unchecked("224d2355fc17ee0b5e46455d2ef3a85cfaa88b08689b0d0e4c111094fd780093"),
+        FE::from_hex_unchecked("6d82b530a685ea27a3f7d47626bb7997b59cf2c6ec2ac14ff28ff0562a95e22a"),
+        FE::from_hex_unchecked("2241de16388cdd7ffda42e0838b5d59bc2182f14bbef622fa633d8b87250a740"),
+        FE::from_hex_unchecked("6b9fc67a95a7a01f9034b58ac9130de47ec903c21c77cbd05258b011e904b80e"),
+        FE::from_hex_unchecked("325f11e96905193f6836e6fa2c727dd0261ea083fedde9873f1e7b9d90419833"),
+        FE::from_hex_unchecked("6e39fe041e18592c01cb0ae2e7cf51f57797c498be313f3485de24974129f48f"),
+        FE::from_hex_unchecked("51693257ab2fa82eb0e8040a6866fe4d121c59b8a22ff9b17c72ad78e4dcb42a"),
+        FE::from_hex_unchecked("48805d48952999e8f52884e543f775f6bdac8da75cf37bad19754415183c6516"),
+        FE::from_hex_unchecked("1cc541b9ed19280c216f6b90876cbe83d07ba14fcc6f2af068e1dda739f5acb9"),
+        FE::from_hex_unchecked("f6f3f6703c0dcd136b24ddb8766fbcd69bbc9cb3bb20a1da2f7130c4ba62664"),
+        FE::from_hex_unchecked("352376600a75802c6e6c6da69001e0376328848bc7ada465176d571ba029a20b"),
+        FE::from_hex_unchecked("2b1708d59adc4ba04a6bcb2dd264c1b014e0b7bae9bb3af916eb276ee3a34565"),
+        FE::from_hex_unchecked("66c5bbb78c64b8369b845ed0a25af2d05e3a4ce053ff4fd056a5c8a059e2cb3c"),
+        FE::from_hex_unchecked("5e80c1b3b2e5b0b2cc4385a07f85777bf8d59ee2358ce694376f35c844d300f0"),
+        FE::from_hex_unchecked("6c2ae56c365577b154b04b7683b8910dd66808b89858e03cf627b50057425c6c"),
+        FE::from_hex_unchecked("65720f82218ce2bd09c504216f1fe44967596b1720ab267749537206c0ddf03f"),
+        FE::from_hex_unchecked("71a7333c7e07b20015f2c1f030adc0137e4d7453d795616eeda38b3a25e709b8"),
+        FE::from_hex_unchecked("161c8a77adcd1a5f8dad71c3b044ce64bab9de792195da91a0b0acca4f8b4568"),
+        FE::from_hex_unchecked("5b7f6f2e59eecbf4802a456ac633c38ba8895b41cdde35c1caa5dd1ff4bdef7e"),
+        FE::from_hex_unchecked("5a2569754178df0732e843339b2b7d55519590d8fe6f4f4bf9f35bcd8c589b4d"),
+        FE::from_hex_unchecked("5bf5026511b12bbfbde38a5394aa7dd9a8bc9a5055fadbcbaa541f3b523925e9"),
+        FE::from_hex_unchecked("1d53e37d6ddf6dd88beb25c0870b2d0af2a51efb6ada05c4ac7e5099a71499e0"),
+        FE::from_hex_unchecked("65e488523b0b3430d5f91e25b4b96ee6d628fbe0529c2868749657d7f7aa3f95"),
+        FE::from_hex_unchecked("b6d7adb7b72cfaee0184354accbff821a14efb48b46405b397c037a5e15f095"),
+        FE::from_hex_unchecked("2444c70bc898765b95c5438156c28671cfd20569a8d31b3f08cfa60d2bb18d6e"),
+        FE::from_hex_unchecked("6979d5cf3da00fcb59a9832188456fbe515261a9be5cf052ab8e50c874a07ff8"),
+        FE::from_hex_unchecked("1eca31224b0d4ae965b179fd952d958de48a5de147348ca5dd00790d5c76fb2f"),
+        FE::from_hex_unchecked("5e1c8f87eccb7e8cd338500800ca62b6bed6fc390597c5a7f21eb7e80cecad80"),
+        FE::from_hex_unchecked("c3f882f7a3bd8ae1eb328e026f6419db30a5026c279df1219499333ef8caa06"),
+        FE::from_hex_unchecked("38011264a16e7cf3e96f029dbfe344e778314b1e2e9d8a2f8f8f76ff5795430d"),
+        FE::from_hex_unchecked("3119da354a6f450bf8f700b89b8319a6f57d6278bfb0bbf9d8e37d55c9f3133d"),
+        FE::from_hex_unchecked("bbe0649314a68a31d5e8222bfec7b1298fc5bc1e6ea098675c94695aa3aa221"),
+        FE::from_hex_unchecked("4dfb208e602b401ba661e37ff5a5fd971874a1082cfb00ebefc6405c2d0a73d3"),
+        FE::from_hex_unchecked("2238971cffd7a12e565e591c0b28c8e76a4582d57892d3db5c8be394a60ba3e9"),
+        FE::from_hex_unchecked("4c466e3153daea54ee62f719c6f947207e7234dbd7257489b4a8a385be7645ba"),
+        FE::from_hex_unchecked("1badbb1e2e9734afa09ff6c92e98038b9e329e0c5d0bac9a7d7996392f5caf78"),
+        FE::from_hex_unchecked("37dbb6c5059651ae1362eba3195c08716dab6a61ce8476b5cebd09274a53413c"),
+        FE::from_hex_unchecked("7154aa15ad1736497d7902f9e74828b3e65f43295cc20bf69ba2c622592c224e"),
+        FE::from_hex_unchecked("3ca22f38e795e433ae9a8d2d5f1d535bbe1a3c3fdadcd549a718e72cd257bb09"),
+        FE::from_hex_unchecked("854ff209558742b8d9b0cbe9db7767f33668f1f8888458bc954025ffe84b7da"),
+        FE::from_hex_unchecked("377699a38c21b41939a5098a8202ed55b4d3472e01f185f4336f4c6c879051ad"),
+        FE::from_hex_unchecked("3492b7170200764635922920ad5e3c8761fbbcbaeaa2fc08a59f9ddac49a59ed"),
+        FE::from_hex_unchecked("372c88a6b45c1593f353789be4cdce85dd2ea1adfca9d8444c83b7a990921a25"),
+        FE::from_hex_unchecked("3d0996734441d7d414e14d72e46eb6cb3d403a822ce642357e1319d169f4ce6f"),
+        FE::from_hex_unchecked("26362f8c8a86b5afadc22b1cc0aa53e9da137607812778e47d5f86740722a52"),
+        FE::from_hex_unchecked("195b6294bdf65bb0c07569f6c6193de7e5e9ea5b17b5adc39179ff15280fda29"),
+        FE::from_hex_unchecked("440a155e09c263aa0c3fdfc62d5b6f6c759735d1b73efd60c5afd4deddbd7bf8"),
+        FE::from_hex_unchecked("5f0135b9b6a8ba9cc5d83221ba344778dfc00b1f4ac578455dd5d71dca2bb64e"),
+        FE::from_hex_unchecked("5d108be88e31ef2e03e46662075177281cdb28e77c120d158c89ce3850fd93d7"),
+        FE::from_hex_unchecked("11dfbb5f5e48ea973c6ef2ece89463c5316bc767896b67b88be18a1d858d6f52"),
+        FE::from_hex_unchecked("71180314ab2e242cc0552b728495b97e0c2e073970a264c43397356d6ef6c99"),
+        FE::from_hex_unchecked("13c1cc0a221c29fde3183f7dc644004d3f4dd341fe7626996ce68c69d73204c5"),
+        FE::from_hex_unchecked("6fc0f5f038d0ab20f4815ba721b366824bf534980265836b224fecfccb6fcaf"),
+        FE::from_hex_unchecked("122e97658c701fcb7b25d8ce0629f2942dd8f07d6ac06a91320a6f1f4421fd59"),
+        FE::from_hex_unchecked("3a19573d57741adba1942d72016391d115a1971af158cfe2a776cb506d714272"),
+        FE::from_hex_unchecked("4037de356b6cb97e4b73d9604342a7b3e5bf44dd44e4318cdc116edde917fffe"),
+        FE::from_hex_unchecked("3b34dfdced639990194ad27c8d3bebd9e6657350c0c522a2b65f2ace44dd16cf"),
+        FE::from_hex_unchecked("546b1eed6e680434955e6259f20ba428460181982eed9832898c945ce343f9cb"),
+        FE::from_hex_unchecked("4d144b9647ff9822cb76f838802bd249bc58c2782587d470f5cd906f860a79d6"),
+        FE::from_hex_unchecked("2d39cfe678f0816cf3bc7a0476517c070d9db6b0f20aa849fc9746e4be5bdf80"),
+        FE::from_hex_unchecked("69f92e5361b806c4e082661a39cf8ead1431c49536eaf8b8450804c99d9c8899"),
+        FE::from_hex_unchecked("1c2e919d0e061629fd6b5416898dc1d5a5cd0e130531151d18480906ea3d9cc5"),
+        FE::from_hex_unchecked("3dae30c784fd66c4a551a6b0a9551747fc1cae54522bb25238f06a7a3e4490cb"),
+        FE::from_hex_unchecked("264c23f67c44aa792f1c731655e1c9eefc4b4b808913f6bb3806ee56caf9c8b"),
+        FE::from_hex_unchecked("fa6d7c32c55e7621d72604c5abc1d970e7569dbf1475f989816be1ac248f889"),
+        FE::from_hex_unchecked("2516928d25d3fe4ca89ff71d5958f4f256d86457b58215dc8c1b02454314ff19"),
+        FE::from_hex_unchecked("10842e1683519bc44c3b3de92cf860e9185c5ed67b20662ae8c4f50008de4780"),
+        FE::from_hex_unchecked("6b7e2c013a40a2c5f5a992a0cd4bf198a64171617af313332c244edffcdf45be"),
+        FE::from_hex_unchecked("36c65d30abc46a63c4b26ea1e17c5325181354f800fa4c4f207ed1849bb8b3c"),
+        FE::from_hex_unchecked("568d2f7f0f8fb4fc28ef1bc673f2db513427dbab162b7c444a1070cd85f22397"),
+        FE::from_hex_unchecked("53a9fd8aefc965366ddcf373f85ce62ee77f1ef0bdd18243842c2eb313db9989"),
+        FE::from_hex_unchecked("1577e14026128fcbe30d7fe646e0cfcf5a91052f2cadc41553e10aa4ea94eb81"),
+        FE::from_hex_unchecked("38814490cf1681f17c23adf62ea2988d48fdce37b3a2fc259b090391d72be770"),
+        FE::from_hex_unchecked("3c24dd5b9460893f28e95b9cbaaba0e1b6af9c00d8182b66ec771ca957b4cb8d"),
+        FE::from_hex_unchecked("367180fac58037ec0d86a1932aabe6a5e353feaf64b1acaaf208a79f711fe35f"),
+        FE::from_hex_unchecked("28ad8a1ecc6d58bf0cbff23e654b824abecae09905278fffa65e9b0d634d9205"),
+        FE::from_hex_unchecked("56b8a175a8e0031f33f342e53f2b565f098aed0baff9a26e6981f7129b2a7b1f"),
+        FE::from_hex_unchecked("5b68b63c523f4fb76fcb5c63ee048575a221119373427cd74c5f32f542cd2895"),
+        FE::from_hex_unchecked("46fe9ca53b25a411a48d539b09984e2e04bbfe5bfdbb3d390e7489c2cecc4bf"),
+        FE::from_hex_unchecked("5cede9acf639ee099c37c71a78146aac713b1c9b2e13d8a587a46db424e19826"),
+        FE::from_hex_unchecked("5d77d40ebd612b82e63f42cdf351c96834cd60319d3fb55b812e263a211cdb80"),
+        FE::from_hex_unchecked("1b6869c3d4333173de4546f09cee0dea3ae707dfa5e1bcebf9d7671cf766aac0"),
+        FE::from_hex_unchecked("51fe61e53e88cc98192802e1df6a5eec1d095d00fad564f9013366bf9c02819d"),
+        FE::from_hex_unchecked("6c16983737754b8011c12b3ba9204894b7e8ed476998df08a4d2b5ba29133b7d"),
+        FE::from_hex_unchecked("1ac8cb354ad46130e09e72c559d322a6daf108002e5f1040c0db23e21ba7b12d"),
+        FE::from_hex_unchecked("16ff5289ab75696ff12f1a2207ee96d0824574cedc0bb3558f4a6ae6e674c446"),
+        FE::from_hex_unchecked("5a77dca1291f77077d5e6b6ff22accbc500a7065274df2a35a2c6b1fa82e6f04"),
+        FE::from_hex_unchecked("6b0e33788a959d17f135432898a7d6e610f28c9dbd60b9c49a6dc490c34ef98d"),
+        FE::from_hex_unchecked("6cf72c65df522e4266f8423fa1659ad66b2578b7f993ea6507d0721f28810f83"),
+        FE::from_hex_unchecked("5a8a16f926e64e5728140473f651e594593ffba73c8e0b3222dff49feaba351f"),
+        FE::from_hex_unchecked("718a33863594a8cae0d8034df7b5e9d5a695444b5df36b17f729ecf82ecf72db"),
+        FE::from_hex_unchecked("3b7f271e38ba8cde9b5fabbe82eae1b848b78a32022516d4cfa423062a3632a1"),
+        FE::from_hex_unchecked("23c4be170342843749dac4e883d34ef66ac377a41c53edfa136f0ba50d0fe5a3"),
+        FE::from_hex_unchecked("5c26d0a3e700edf5bf5aaa952903efa479f77e84294998cf64f10f7acc5e6574"),
+        FE::from_hex_unchecked("41d401cfcb2049b7ea928444a8683aa7c9f036572c25b1fc9eb5bf88a5a4e7c4"),
+        FE::from_hex_unchecked("5cfab88543d96c375141236314bc8f0b66a9bd70dfff1f9e5458c6ca7f2fd8e7"),
+        FE::from_hex_unchecked("366862ca8fd49db9b624d9a1e33b393cf761a181813955f48ff1d8d16d7d7d73"),
+        FE::from_hex_unchecked("2b12d66066df3e3446f8fcc6bc74aeb1dadb4f06f5d1b49646c52708c447cd14"),
+        FE::from_hex_unchecked("4c5c782c8c1541ffa5d1cc2d72f4ca7e0d269be6334d8694643a0fd716b02d9e"),
+        FE::from_hex_unchecked("a88d5bde48f9b752b856a2646a793cf285473c38870624240b143758c0b5289"),
+        FE::from_hex_unchecked("5e3fe7b3d5cfb69f87cd0c2d9189ecc88d0f104bacb79bd8b7f53c5ed87ab646"),
+        FE::from_hex_unchecked("4c1c38bc77690ebcf900b9cd0b753e227339ba81624dc85fa9539f2dc626b2e6"),
+        FE::from_hex_unchecked("1cfea95345ce89e544d0447eb5a655610588ac2097f3008389897701d404c98f"),
+        FE::from_hex_unchecked("3a9ec343c1c6a122f3897126da51f92d0187ce6e0221cb23bb1797fe4ba72e1d"),
+        FE::from_hex_unchecked("3bf940ada3cf20415d3b0b12dc1e0ecededea4dc1e5d7d1587edb6b4c79342f6"),
+        FE::from_hex_unchecked("8621ee09a0ef69dee34dcea9261a2adfa38e8304e461a9635bf49c0f36d4be"),
+        FE::from_hex_unchecked("60acd6ad5bbf46cb3d215c5bcc362349d08cdf5b01f90a2c1b945b316df4c40e"),
+        FE::from_hex_unchecked("704f5c935383a8f4d020c56284fa5a83cbb9115d409754640a6e1c4fb97c7a88"),
+        FE::from_hex_unchecked("580b48c347475fd5bd329eb4f4e98576fe31ce7f8a406325441c039e5421656b"),
+        FE::from_hex_unchecked("209cd0df3e50c56186a5db349b595bad4395036a310c098ecea3d041576725fd"),
+        FE::from_hex_unchecked("6e55adc04c723d3fbc8da51643c004b68d40a93bf745c04ab3f8daa77de33fa5"),
+        FE::from_hex_unchecked("648b107f3892d7a2bcb36612fae09615c67f008fb4b9a9f168e2c1258632a813"),
+        FE::from_hex_unchecked("25b9a4649aa7d962e12ba088d37d5b582f5f5c160c8d28c03294c26a52447f18"),
+        FE::from_hex_unchecked("5ec12eb5698ef5afe1a8e225a299afc37b923dfb5094870f4ad979aa416fbed"),
+        FE::from_hex_unchecked("58a840d7a870788599df218210d12b95f2e0aae7ffe8cea8d03e093281286551"),
+        FE::from_hex_unchecked("3e682fbf59f4cca0b6d50829d76c246e65b7f78d939191d570c977e4c5257d0b"),
+        FE::from_hex_unchecked("b850ec39f210fbf8797cd35565461378546c06a342edc84dc7831ce36614009"),
+        FE::from_hex_unchecked("68401ae3bc2fabff21b207f6d74201400cc3e8d984495e70b6afdaaa61e268e0"),
+        FE::from_hex_unchecked("290b9a83a1cb831f478bd70ebb8930d0cc23f44443b3d1e61dd60f41b6a49274"),
+        FE::from_hex_unchecked("4b69d520ecca8cea66882f338bcc0ef9ac7f361be13252d0b8a11d03edaf1831"),
+        FE::from_hex_unchecked("57b39cf1f0ea46850b019be2fec197260da68cb15b0675c41a7fa1530421210a"),
+        FE::from_hex_unchecked("4546c73d0c7f354b94a40aa83e09d75d87c9f11c865ee8cbe627eba08a3be38f"),
+        FE::from_hex_unchecked("62bf47c581239d648e958a838021caa7826a1513c1e71253a6fa98624d0a2a27"),
+        FE::from_hex_unchecked("4eebe4ae39389914984e52e9828e8ac485c4a0abb937767aa9127906588fd189"),
+        FE::from_hex_unchecked("4bc379725cf05684871ffeed475d2d2bba63620f86306bb445ab768ba2aa185"),
+        FE::from_hex_unchecked("440b5661c3d15e65143a090d6bd2b84b8b716e6ebd1b7d05771e91e7cde024ec"),
+        FE::from_hex_unchecked("5c77b598f3b97fba6d9973d25bdec455cbebac77d56c6acb1a60ee894409f8d8"),
+        FE::from_hex_unchecked("2328f4080eceecfef5ccd20da1be4bcd31ae5cc77aef7ae71c51e18c59c3f1ed"),
+        FE::from_hex_unchecked("302caec20a4e995e4ccc8565a77af10e49b3f7754b9a5967157944c30743b1bb"),
+        FE::from_hex_unchecked("10741146ca5ef1a2a085754a2ed15a71fb495875bb63c5438ee5bf9d10e8c058"),
+        FE::from_hex_unchecked("465a65f1aa261fda6a821a89632f22cf2dbdf84f37f2f8903afbae58dcdac5b6"),
+        FE::from_hex_unchecked("1a371581be47518396a1748d6538e2f4ff683d3405f7f5f73d6b9c52d1b99d9d"),
+        FE::from_hex_unchecked("49f92746cde1b4280a5d7e8da69b7eefe1a2c4905d2846a69c11f4c22e06bf8"),
+        FE::from_hex_unchecked("5c9bd12889f8cf15ae721e85a75821cb532e53ce79def307da6291f7e34f27dd"),
+        FE::from_hex_unchecked("7240300cf133215b386ab97daf5fda13dc874ff0253eef1cf9992070e26e2d12"),
+        FE::from_hex_unchecked("3b8e5dca75c3cfb9c2579fb74cae071c8dc339208ca47deec9065e78dc881ba7"),
+        FE::from_hex_unchecked("51e62a2d38bd6bed7480a0b4852d8f09ed0e339ec2073e5b0c513600cd67285a"),
+        FE::from_hex_unchecked("411a4c8134237ff4ad00bbd25479906ec6ef6dad629b117246003c31765f7fc2"),
+        FE::from_hex_unchecked("11a05a9592274f3cfd9b83b849c774f3c53dd187a65fdd807765337ffdb6ebbe"),
+        FE::from_hex_unchecked("32d4144bd5e6a92072e23ab63c252bf1bdcff45c185f2b14bec92202465278b4"),
+        FE::from_hex_unchecked("3d8a49dc1f8ff51cb94645a994a818a1483f37fe38c0a863610ae6d1f59f4566"),
+        FE::from_hex_unchecked("494206f22970f333d7d7efb2a7c1cb29e92ec199470aaf035a421fd005f9fbe2"),
+        FE::from_hex_unchecked("60ed9fa9211099f40edfe6b86e5435a8064297696b72ede5c0710adb400629d8"),
+        FE::from_hex_unchecked("399070ea6f6b8ec5569408200d7e3c9f1935e00ac893af9a766efb21166bfe36"),
+        FE::from_hex_unchecked("5fca75e89233230e5b85bc65f21bb5987c7ca77a090e03876982e0d444672368"),
+        FE::from_hex_unchecked("3242e136a19a866cc85712dcf62918bce13bdda1dfe67c24a4cabf36e365bc0a"),
+        FE::from_hex_unchecked("f46bb63560d481ebfe015bc67dd4e51bb7463ddc546d3d436e5cb688bde224d"),
+        FE::from_hex_unchecked("fcac22e2ed86b92c2bce7a64d6fe20785f8a64fc3cd44b7791798d6ffd2cb5f"),
+        FE::from_hex_unchecked("466defed67622325544f9da06f261b5adf6bc1fcdee0768d6afec8495c6b6d05"),
+        FE::from_hex_unchecked("56ee5fc88c2592e273326dd0196f088cd1d36758a83517355f7abd397c81efc9"),
+        FE::from_hex_unchecked("627635a23a4642ef2efcc5f71a6e48bfc6d2aa646bf1a78e03408b431493fc92"),
+        FE::from_hex_unchecked("db2a01d98799909617028dbbcd9ca49a27accfd4b5aaaf03050d78ae0c4e707"),
+        FE::from_hex_unchecked("343c446f363b07a7baf45a6fa516f2f5234c9da12da24c5c950ecf9d3698cffd"),
+        FE::from_hex_unchecked("6ad60300202131f4765c2f822c59111b3e7170a91df9d9ccd3f4dc866fa2656a"),
+        FE::from_hex_unchecked("35520e3e9547534a00c2ef16d42ae1fbb4f63896a4e261f571f2071ff0a434e8"),
+        FE::from_hex_unchecked("18e2bc16686fde3f4d65ab127982160400583faec949be8a14c0be267273e24e"),
+        FE::from_hex_unchecked("4ea4ba1b3cf0122acf9cfd809ca7c2b331ba0397badf4704cc26719d98cb2569"),
+        FE::from_hex_unchecked("5aca1effb98ee8ae39c14f75f232f6b9f8ea43b015df9e6f9aed75198a2959f3"),
+        FE::from_hex_unchecked("47d6fb839a2eeceba8624f1a8e0aa57036f6538dfa236b18a0d970f5433337ec"),
+        FE::from_hex_unchecked("49ee5bfcea8ab9d70b1092b5381c8a149b398741e38ccf1a62b27af2a6bbda9b"),
+        FE::from_hex_unchecked("1a9d7fdef949af9f8f4d7be3d6df2fb11fbcfa26bfdc1ea7eb9dec8a5b018f09"),
+        FE::from_hex_unchecked("e6dde8951c3906c528a73f7390da5a327c51bee762e85a649794366139967fb"),
+        FE::from_hex_unchecked("59cc9e4eecf44ec21f62b2ca73f46209b80d92a3c3afd34d00d74660d41eb3ed"),
+        FE::from_hex_unchecked("1224677711f1c8c1e7bf1cba520c187a6b0ce732fd15fec589a191ba2531e924"),
+        FE::from_hex_unchecked("5386031dcb90735ae19a680eeef02d9a48b0da8d5c2163dd9d3b8f79ccf72c71"),
+        FE::from_hex_unchecked("6869310f5c66f2ec7bfcd948da4fe0251097e84bf2a56d4d04cb759bae6c54d8"),
+        FE::from_hex_unchecked("5415058859a780ada562ce9e1a16c874846051fa2e89be4b716d38d90422f6ca"),
+        FE::from_hex_unchecked("923f9b8d8bf4e6da72c660dcf6c73323ab8464c1be4165d7f9c825b6e378da"),
+        FE::from_hex_unchecked("3121dbf9153b81e93bafe8f7fbdbe1ca51d08ab1954e1d81e103727126c9f997"),
+        FE::from_hex_unchecked("72a3e5fa7527fe237cb87699f34b6823e67d054f9e042009e64b8c6b430b685f"),
+        FE::from_hex_unchecked("5962fc48f13bfed03d82e939c18c6dcea60dd65ec04f12c64f306b52da3909cb"),
+        FE::from_hex_unchecked("55f21d1759cb5a531f8ce6738a259224a20fdf087bc91c6457bd172b2cc66af2"),
+        FE::from_hex_unchecked("725a78363a24dc7828224d5970c70cefd62c6894a293acd68a85b64bcd9da6a6"),
+        FE::from_hex_unchecked("53037f1a24c6e43891eb1ffa3e3fa01fa6fdfc692cd079e136f0aea007d11516"),
+        FE::from_hex_unchecked("32bdfa8034123b28726459bf81a351107bb51072303742efac53c5fdf86a7259"),
+        FE::from_hex_unchecked("591049f0c57009233aa0d64a5bd3692f26dcfd71569a093f88741fdc1e403e7c"),
+        FE::from_hex_unchecked("67c7e61d817e9eec5eed0be358a7022a063425741b41e3d7b2c8a59a10ea3c81"),
+        FE::from_hex_unchecked("39fc1a25f3aa12efcc435280b2661c3d86a3f3c7c23648ea11e43b22c0e2e5cc"),
+        FE::from_hex_unchecked("4cd7811037cfd93c871802e6cc2f9411a65c38baaeff2e7b1a4edab7a69bfbc4"),
+        FE::from_hex_unchecked("498be8a014709891e4cb7b925209d59af7bd0c371c58578b36e534176a85e379"),
+        FE::from_hex_unchecked("434a51d9e320ba7b35e2f6007a22aa187a98132df1268c80696cf69155f15493"),
+        FE::from_hex_unchecked("37eaa43631c3bd82683a32d73e7776604fd3c46f9d0cbeac6cd0e71ce7c3c5c7"),
+        FE::from_hex_unchecked("12cde892a12a2ad78f3eb3031e103ddc74329294cf77b60e4ba15af6f0604b75"),
+        FE::from_hex_unchecked("6c07a97aa2b56da4694ce3b2dbca8465273de26db67e914b7b43c278adfbc854"),
+        FE::from_hex_unchecked("19205f51def8e3d739cde771d326347828b25da3abcb32f4b1e42fe327eadba9"),
+        FE::from_hex_unchecked("69f1206c9e4c4aa974488a83f1ae73efee515f52f0b2b7c1ab4bde43a93fabc5"),
+    ];
+}
@@ -0,0 +1,539 @@
+use crate::hash::poseidon::PermutationParameters;
+use lambdaworks_math::field::{
+    element::FieldElement as FE, fields::u64_goldilocks_field::Goldilocks64Field,
+};
+
+impl PermutationParameters for PoseidonGoldilocks {
+    type F = Goldilocks64Field;
+    const RATE: usize = 8;
+    const CAPACITY: usize = 4;
+    const ALPHA: u32 = 7;
+    const N_FULL_ROUNDS: usize = 8;
+    const N_PARTIAL_ROUNDS: usize = 22;
+
+    const MDS_MATRIX: &'static [FE<Goldilocks64Field>] = &PoseidonGoldilocks::MDS_MATRIX;
+    const N_MDS_MATRIX_ROWS: usize = 12;
+    const N_MDS_MATRIX_COLS: usize = 12;
+
+    const ROUND_CONSTANTS: &'static [FE<Goldilocks64Field>] = &PoseidonGoldilocks::ROUND_CONSTANTS;
+    const N_ROUND_CONSTANTS_ROWS: usize = 30;
+    const N_ROUND_CONSTANTS_COLS: usize = 12;
+}
+
+/// Poseidon over the Goldilocks field: width 12 with rate 8, S-box x^7, 8 full rounds and
+/// 22 partial rounds. The constants are the ones of the reference instance of
+/// https://github.com/HorizenLabs/poseidon2, generated by the Grain LFSR. This is not Plonky2's
+/// Poseidon, which has the same rounds but its own round constants and a circulant MDS matrix.
+#[derive(Clone, Default)]
+pub struct PoseidonGoldilocks;
+
+impl PoseidonGoldilocks {
+    const MDS_MATRIX: [FE<Goldilocks64Field>; 12 * 12] = [
+        FE::const_from_raw(0x5f1d4cecfc89ba7a),
+        FE::const_from_raw(0x0ca24b673d0b08c7),
+        FE::const_from_raw(0x3f26361f21e1fcbc),
+        FE::const_from_raw(0xcf5c4373a541115b),
+        FE::const_from_raw(0x5cfd4a79844e2d2d),
+        FE::const_from_raw(0x1453474d5cb13e6d),
+        FE::const_from_raw(0xe716171e92c48eaa),
+        FE::const_from_raw(0xb2934f5e1251bb28),
+        FE::const_from_raw(0x7851f27525a981a5),
+        FE::const_from_raw(0x418c129224496139),
+        FE::const_from_raw(0x78e1278db390233a),
+        FE::const_from_raw(0x87428e7972077adc),
+        FE::const_from_raw(0xf355cf5c82963ea9),
+        FE::const_from_raw(0xe501d0c36086af6c),
+        FE::const_from_raw(0xa8fbc49375afed21),
+        FE::const_from_raw(0x5e0c770aab2dc181),
+        FE::const_from_raw(0x0565d30771c8f650),
+        FE::const_from_raw(0x75879e2cf755ab35),
+        FE::const_from_raw(0x4ea0b28f7f720232),
+        FE::const_from_raw(0x354f5ff594b578de),
+        FE::const_from_raw(0xfe07e7e63168967d),
+        FE::const_from_raw(0x72f3ee829f9eecdd),
+        FE::const_from_raw(0x16c47c647ef962c3),
+        FE::const_from_raw(0x7f8883ef41d15d48),
+        FE::const_from_raw(0xdc746c611121b0a5),
+        FE::const_from_raw(0xbe207e8953c33740),
+        FE::const_from_raw(0xffd52c0a8b3bd683),
+        FE::const_from_raw(0x3f76bbf0dba89407),
+        FE::const_from_raw(0x5e9c584ed8d0fe88),
+        FE::const_from_raw(0xa8e125983328e1a1),
+        FE::const_from_raw(0x26b77e514c04bb6f),
+        FE::const_from_raw(0xc14a72c31021b93e),
+        FE::const_from_raw(0x459a2f39d2363e2a),
+        FE::const_from_raw(0x0fb0d121ad9a0ac0),
+        FE::const_from_raw(0xa5a72aa8f9708cc6),
+        FE::const_from_raw(0x91a792606c45522b),
+        FE::const_from_raw(0xa81f336c52ebbfdc),
+        FE::const_from_raw(0x5ee3a892bb8fc7cf),
+        FE::const_from_raw(0x8e54ebd12c7db995),
+        FE::const_from_raw(0x4bfaf6dfff080ff9),
+        FE::const_from_raw(0x7f786b2231384856),
+        FE::const_from_raw(0x6e6cef25b53e4622),
+        FE::const_from_raw(0x0bff960556cdc6eb),
+        FE::const_from_raw(0x2225de7bf977bcdb),
+        FE::const_from_raw(0xd55d542052c36f6c),
+        FE::const_from_raw(0xb353c8ed5d3c1ca1),
+        FE::const_from_raw(0x80f58f25e3d496aa),
+        FE::const_from_raw(0x5ec309519ea8d128),
+        FE::const_from_raw(0x78ef2b156654ec59),
+        FE::const_from_raw(0xee1db713e6eac806),
+        FE::const_from_raw(0x739f0732db182f33),
+        FE::const_from_raw(0xca399840b6c660e6),
+        FE::const_from_raw(0x690c16859debda66),
+        FE::const_from_raw(0xc1110ae00b83c14b),
+        FE::const_from_raw(0x7fbf03e0ca82e8d8),
+        FE::const_from_raw(0x1833158bdd00eebd),
+        FE::const_from_raw(0x12c904f07f3413f2),
+        FE::const_from_raw(0x93034d0e76b4e611),
+        FE::const_from_raw(0x9e4cfd863fcc1c54),
+        FE::const_from_raw(0x11c9e0b3d24684a6),
+        FE::const_from_raw(0xa4d9c440006f11a7),
+        FE::const_from_raw(0x3235b5cda30310ab),
+        FE::const_from_raw(0x6e2599d007138680),
+        FE::const_from_raw(0x65d5baf8b8d40d68),
+        FE::const_from_raw(0x88b301c1d94b4e66),
+        FE::const_from_raw(0x6f1a4e963bc491ba),
+        FE::const_from_raw(0x9c11e953245256f2),
+        FE::const_from_raw(0xbac593f029e280fb),
+        FE::const_from_raw(0x997539053263f791),
+        FE::const_from_raw(0x46e53880101d8820),
+        FE::const_from_raw(0x9484140b7daf89e2),
+        FE::const_from_raw(0x52a39993049722d6),
+        FE::const_from_raw(0x17c4da56dce49dd3),
+        FE::const_from_raw(0x7716b33751e53bfd),
+        FE::const_from_raw(0x22e8f8ea831ba6fd),
+        FE::const_from_raw(0xe3a0570dcdd2ca90),
+        FE::const_from_raw(0x2d3fe778771c643c),
+        FE::const_from_raw(0xbcca1c5d0782a03a),
+        FE::const_from_raw(0x56e6eaedecd30fbc),
+        FE::const_from_raw(0x26a6c1cde627d734),
+        FE::const_from_raw(0xb4a1698170840e62),
+        FE::const_from_raw(0x0bde82da81e8f8d8),
+        FE::const_from_raw(0x917c86d06bcfa19a),
+        FE::const_from_raw(0xb293f41a4b4b1012),
+        FE::const_from_raw(0x2249f4003f17f288),
+        FE::const_from_raw(0x2d784d863b3f46a8),
+        FE::const_from_raw(0x4c440e95eb101332),
+        FE::const_from_raw(0x7922609f4364e2dc),
+        FE::const_from_raw(0x4388fefe814e14ea),
+        FE::const_from_raw(0xf1694a6c7e2d2e95),
+        FE::const_from_raw(0xd3d4795611ff8c9a),
+        FE::const_from_raw(0xf4421c8faba77924),
+        FE::const_from_raw(0xd37a607a16b67331),
+        FE::const_from_raw(0x6344e9a26ded9e81),
+        FE::const_from_raw(0x73d370cb72f61418),
+        FE::const_from_raw(0x775cd16e8161891d),
+        FE::const_from_raw(0xdf4589e1bc7fc4a8),
+        FE::const_from_raw(0x9b891bd586245062),
+        FE::const_from_raw(0xe14673eea6ff8a9f),
+        FE::const_from_raw(0x6ff49d004d7c76aa),
+        FE::const_from_raw(0xa43ba2253950249b),
+        FE::const_from_raw(0x5feb9656feae4095),
+        FE::const_from_raw(0x2d937c6444c12016),
+        FE::const_from_raw(0x7b0a59c6819b0e00),
+        FE::const_from_raw(0x7b5ca76444ccfefe),
+        FE::const_from_raw(0x9b18e4c66878ebba),
+        FE::const_from_raw(0x0d3924431c20793c),
+        FE::const_from_raw(0x8864968b4d01f232),
+        FE::const_from_raw(0x9ef08219283a49ac),
+        FE::const_from_raw(0x1146b5e38a000b5f),
+        FE::const_from_raw(0xca7a1184d70d6afc),
+        FE::const_from_raw(0xd51b27ff7dbc5ea7),
+        FE::const_from_raw(0x4bfc15638b5f5e48),
+        FE::const_from_raw(0x418d7966f04d3097),
+        FE::const_from_raw(0x14e6ca4bf05f217d),
+        FE::const_from_raw(0x92de94ea4d4ed93c),
+        FE::const_from_raw(0xf01234a31b1820f3),
+        FE::const_from_raw(0xa7f2afc69e14b27d),
+        FE::const_from_raw(0x562317b78ddbe1d8),
+        FE::const_from_raw(0x9a997f85c6b36924),
+        FE::const_from_raw(0xfe93722d1bae4842),
+        FE::const_from_raw(0xd6201e8712a9cf09),
+        FE::const_from_raw(0xe2c45e6ff610544e),
+        FE::const_from_raw(0x4af81416b1190fda),
+        FE::const_from_raw(0xb634a8c606b034dc),
+        FE::const_from_raw(0x9897b99756010c0f),
+        FE::const_from_raw(0x8f7a2c4d9845a939),
+        FE::const_from_raw(0x0690948bd80ee51f),
+        FE::const_from_raw(0x0adb54a45bc118f5),
+        FE::const_from_raw(0x15db3f12b969ec38),
+        FE::const_from_raw(0x43ea1b602c05d022),
+        FE::const_from_raw(0xc7e89ad4f06305eb),
+        FE::const_from_raw(0xcf6c422c4f64d877),
+        FE::const_from_raw(0x9bde7b81520b367d),
+        FE::const_from_raw(0x5793f8d971b94824),
+        FE::const_from_raw(0xeb864531bbf9f4f6),
+        FE::const_from_raw(0xbf6a654fa8f12e89),
+        FE::const_from_raw(0xcabf7b051b6c5c34),
+        FE::const_from_raw(0x5ee45fd9b1f39f5d),
+        FE::const_from_raw(0x991ad6d310a919ac),
+        FE::const_from_raw(0xedbb35616accd2ea),
+        FE::const_from_raw(0x10c28d695fdde711),
+        FE::const_from_raw(0xd3e562fdec3f3d6c),
+        FE::const_from_raw(0x555a187be44994c4),
+    ];
+    const ROUND_CONSTANTS: [FE<Goldilocks64Field>; 12 * 30] = [
+        FE::const_from_raw(0xe034a8785fd284a7),
+        FE::const_from_raw(0xe2463f1ea42e1b80),
+        FE::const_from_raw(0x048742e681ae290a),
+        FE::const_from_raw(0xe4af50ade990154c),
+        FE::const_from_raw(0x8b13ffaaf4f78f8a),
+        FE::const_from_raw(0xe3fbead7dccd8d63),
+        FE::const_from_raw(0x631a47705eb92bf8),
+        FE::const_from_raw(0x88fbbb8698548659),
+        FE::const_from_raw(0x74cd2003b0f349c9),
+        FE::const_from_raw(0xe16a3df6764a3f5d),
+        FE::const_from_raw(0x57ce63971a71aaa2),
+        FE::const_from_raw(0xdc1f7fd3e7823051),
+        FE::const_from_raw(0xbb8423be34c18d7a),
+        FE::const_from_raw(0xf8bc5a2a0c1b3d6d),
+        FE::const_from_raw(0xf1a01bbd6f7123e5),
+        FE::const_from_raw(0xed960a080f5e348b),
+        FE::const_from_raw(0x1b9c0c1e87e2390e),
+        FE::const_from_raw(0x18c83caf729a613e),
+        FE::const_from_raw(0x671ab9fe037a72c4),
+        FE::const_from_raw(0x508565f67d4c276a),
+        FE::const_from_raw(0x4d2cd8827a482590),
+        FE::const_from_raw(0xa48e11e84dd3500b),
+        FE::const_from_raw(0x825a8c955fc2442b),
+        FE::const_from_raw(0xf573a6ee07cddc68),
+        FE::const_from_raw(0x7dd3f19c73a39e0b),
+        FE::const_from_raw(0xcc0f13537a796fa6),
+        FE::const_from_raw(0x1d9006bfaedac57f),
+        FE::const_from_raw(0x4705f69b68b0b7de),
+        FE::const_from_raw(0x5b62bfb718bcc57f),
+        FE::const_from_raw(0x879d821770563827),
+        FE::const_from_raw(0x3da5ccb7f8dff0e3),
+        FE::const_from_raw(0xb49d6a706923fc5b),
+        FE::const_from_raw(0xb6a0babe883a969d),
+        FE::const_from_raw(0x2984f9b055401960),
+        FE::const_from_raw(0xcd3496f05511d79d),
+        FE::const_from_raw(0x4791da5d63854fc5),
+        FE::const_from_raw(0xdb7344d0580a39d4),
+        FE::const_from_raw(0x5aedc4dad1de120a),
+        FE::const_from_raw(0x5e1bdc1fb8e1abf0),
+        FE::const_from_raw(0x3904c09a0e46747c),
+        FE::const_from_raw(0xb54a0e23ab85ddcd),
+        FE::const_from_raw(0xc0c3cf05bccbdb3a),
+        FE::const_from_raw(0xb362076a73baf7e9),
+        FE::const_from_raw(0x212c953d81a5d5ba),
+        FE::const_from_raw(0x212d4cc965d898bd),
+        FE::const_from_raw(0xdd44ddd0f41509b9),
+        FE::const_from_raw(0x8931329fa67823c0),
+        FE::const_from_raw(0xc65510f4d2a873be),
+        FE::const_from_raw(0xe3ecbb6ba1e16211),
+        FE::const_from_raw(0x70f5b3266792bbb6),
+        FE::const_from_raw(0xe7560e690634757e),
+        FE::const_from_raw(0xafd0202bc7eaf66e),
+        FE::const_from_raw(0x349f4c5871f220fd),
+        FE::const_from_raw(0x3697eb3e31529e0d),
+        FE::const_from_raw(0x7735d5b0622d9900),
+        FE::const_from_raw(0x5f5b58b9cf997668),
+        FE::const_from_raw(0x645534b6548af9d9),
+        FE::const_from_raw(0x4232d29d91a426a8),
+        FE::const_from_raw(0xb987278aed485d35),
+        FE::const_from_raw(0x6dabeef669bb406e),
+        FE::const_from_raw(0x35ee78288b749d40),
+        FE::const_from_raw(0x6dcd560f14af0fc3),
+        FE::const_from_raw(0x71ed3dc007ea6383),
+        FE::const_from_raw(0x8b6b51caab7f5b6f),
+        FE::const_from_raw(0xcf2e8cc4181dbfa8),
+        FE::const_from_raw(0xa01d3f1c306f825a),
+        FE::const_from_raw(0xccee646a5d8ddb87),
+        FE::const_from_raw(0x70df6f277cbaffeb),
+        FE::const_from_raw(0x64ec0a6556b8f45c),
+        FE::const_from_raw(0x6f68c9664fda6e37),
+        FE::const_from_raw(0x387356e4516fab6f),
+        FE::const_from_raw(0x35310dce33903e67),
+        FE::const_from_raw(0x45f3e5251d30f912),
+        FE::const_from_raw(0x7c97f480ca428f45),
+        FE::const_from_raw(0x74d5874c20b50de2),
+        FE::const_from_raw(0xff1d5b7cee3dc67f),
+        FE::const_from_raw(0xa04d5d5ac0ff3de9),
+        FE::const_from_raw(0x1cefb5eb7d24580e),
+        FE::const_from_raw(0xf685e1bfcc0104ad),
+        FE::const_from_raw(0x6204dd95db22ead4),
+        FE::const_from_raw(0x8265c6c57c73c440),
+        FE::const_from_raw(0x4f708ab0b4e1e382),
+        FE::const_from_raw(0xcfc60c7a52fbffa7),
+        FE::const_from_raw(0x9c0c1951d8910306),
+        FE::const_from_raw(0x4d06df27c89819f2),
+        FE::const_from_raw(0x621bdb0e75eca660),
+        FE::const_from_raw(0x343adffd079cee57),
+        FE::const_from_raw(0xa760f0e5debde398),
+        FE::const_from_raw(0xe3110fefd97b188a),
+        FE::const_from_raw(0x0ed6584e6b150297),
+        FE::const_from_raw(0x2b10e625d0d079c0),
+        FE::const_from_raw(0xefa493442057264f),
+        FE::const_from_raw(0xebcfaa7b3f26a2b6),
+        FE::const_from_raw(0xf36bcda28e343e2a),
+        FE::const_from_raw(0xa1183cb63b67aa9e),
+        FE::const_from_raw(0x40f3e415d5e5b0ba),
+        FE::const_from_raw(0xc51fc2367eff7b15),
+        FE::const_from_raw(0xe07fe5f3aebc649f),
+        FE::const_from_raw(0xc9cb2be56968e8aa),
+        FE::const_from_raw(0x648600db69078a0e),
+        FE::const_from_raw(0x4e9135ab1256edb9),
+        FE::const_from_raw(0x00382c73435556c2),
+        FE::const_from_raw(0x1d78cafac9150ddf),
+        FE::const_from_raw(0xb8df60ab6215a233),
+        FE::const_from_raw(0xa7a65ba31f8fcd9a),
+        FE::const_from_raw(0x907d436dd964006b),
+        FE::const_from_raw(0x3bdf7fd528633b97),
+        FE::const_from_raw(0x265adb359c0cc0f8),
+        FE::const_from_raw(0xf16cfc4034b39614),
+        FE::const_from_raw(0x71f0751b08fa0947),
+        FE::const_from_raw(0x3165eda4b5403a37),
+        FE::const_from_raw(0xca30fc5680467e46),
+        FE::const_from_raw(0x4c743354d37777c5),
+        FE::const_from_raw(0x3d1f0a4e6bba4a09),
+        FE::const_from_raw(0xc0c2e289afa75181),
+        FE::const_from_raw(0x1e4fa2ad948978b7),
+        FE::const_from_raw(0x2a226a127a0bb26a),
+        FE::const_from_raw(0xe61738a70357ce76),
+        FE::const_from_raw(0x965f66eada2905e4),
+        FE::const_from_raw(0xe2560d17d78f997b),
+        FE::const_from_raw(0x491135cfb0b193c4),
+        FE::const_from_raw(0x88a7d811ba8c8172),
+        FE::const_from_raw(0x7d41dd29039a1493),
+        FE::const_from_raw(0x0cd9e984523d5ef9),
+        FE::const_from_raw(0xe758a718011f225e),
+        FE::const_from_raw(0xa1b19355c645c206),
+        FE::const_from_raw(0x2b3f098c8ba43f7e),
+        FE::const_from_raw(0xa79ec72dff37d575),
+        FE::const_from_raw(0x7f64bb4c1a7ba443),
+        FE::const_from_raw(0x0e9b008eda4002d2),
+        FE::const_from_raw(0xcf6f77ac16722afa),
+        FE::const_from_raw(0x3fd4c0d74672aebd),
+        FE::const_from_raw(0x9b72bf1c1c3d08a9),
+        FE::const_from_raw(0xe4940f84b71e4ac3),
+        FE::const_from_raw(0x61b27b077118bc73),
+        FE::const_from_raw(0x2efd8379b8e661e3),
+        FE::const_from_raw(0x858edcf353df0342),
+        FE::const_from_raw(0x2d9c20affb5c4517),
+        FE::const_from_raw(0x5120143f0695defc),
+        FE::const_from_raw(0x62fc898ae34a5c5c),
+        FE::const_from_raw(0xa3d9560c99123ed3),
+        FE::const_from_raw(0x98fd739d8e7fc934),
+        FE::const_from_raw(0x49c0bad1b2023adf),
+        FE::const_from_raw(0x2bc9cf2aec60ebf6),
+        FE::const_from_raw(0x43b995c4ef12dcc6),
+        FE::const_from_raw(0x3c69a9d2d4555790),
+        FE::const_from_raw(0x43fc8b0b247132c0),
+        FE::const_from_raw(0x217b0f6f3b52feff),
+        FE::const_from_raw(0xa10cebbb66f1f5b3),
+        FE::const_from_raw(0xcc81ed1130a2c36e),
+        FE::const_from_raw(0x63da22539da7b97e),
+        FE::const_from_raw(0x8bf756d728bf5553),
+        FE::const_from_raw(0x373ce92bcc4dfab8),
+        FE::const_from_raw(0x2f1720d02fb0b850),
+        FE::const_from_raw(0xa04ad342d9e5071b),
+        FE::const_from_raw(0x4a2758463a4bc975),
+        FE::const_from_raw(0x3d95dc5c0aae0025),
+        FE::const_from_raw(0xadaca762a70a4139),
+        FE::const_from_raw(0x19eafc0ac322234c),
+        FE::const_from_raw(0x90f7d010345e3191),
+        FE::const_from_raw(0x8de683caae7e23c6),
+        FE::const_from_raw(0xc8c4de0badedd6a5),
+        FE::const_from_raw(0x2982932ceb559a26),
+        FE::const_from_raw(0xecd9864a9b3046f5),
+        FE::const_from_raw(0xfe26e58fc0fdfc38),
+        FE::const_from_raw(0x34d8ccf408b18b11),
+        FE::const_from_raw(0x305263531bf413e5),
+        FE::const_from_raw(0x9d740626025dc1e2),
+        FE::const_from_raw(0x9bb62131c8873ac5),
+        FE::const_from_raw(0x39a2e90839d2b1b5),
+        FE::const_from_raw(0x79b976bd771e389a),
+        FE::const_from_raw(0xdd518ffeb5b209d6),
+        FE::const_from_raw(0xcc0ae5430247d957),
+        FE::const_from_raw(0x69d45a7c309cece9),
+        FE::const_from_raw(0xcab8f84a11dc89c2),
+        FE::const_from_raw(0xa2f298798ac57fd0),
+        FE::const_from_raw(0x31a1581972494aab),
+        FE::const_from_raw(0x3d7247a8bad73c1b),
+        FE::const_from_raw(0xcfd1cf2abfdf8e9d),
+        FE::const_from_raw(0xd2b3f55a53c0f36e),
+        FE::const_from_raw(0xc1b5c91f9528675f),
+        FE::const_from_raw(0xaea262169d3ce0d6),
+        FE::const_from_raw(0xcf744aeea9a85186),
+        FE::const_from_raw(0xb14caf9c8e665072),
+        FE::const_from_raw(0x9036fdf0f750c7d7),
+        FE::const_from_raw(0xd50ed0260936689a),
+        FE::const_from_raw(0x67d5900d08a564a7),
+        FE::const_from_raw(0x02ff541f79857b26),
+        FE::const_from_raw(0xc83ad8ef65a5dd34),
+        FE::const_from_raw(0xcd9087f66c8913a6),
+        FE::const_from_raw(0xc81bcc8f049cec68),
+        FE::const_from_raw(0xd04bb2943fdb30ad),
+        FE::const_from_raw(0x16e3179444fdc702),
+        FE::const_from_raw(0x16b1d70d695e019e),
+        FE::const_from_raw(0x4ff845a458f34230),
+        FE::const_from_raw(0x766e3f44b85c9dbe),
+        FE::const_from_raw(0x7c4c3acf8514557c),
+        FE::const_from_raw(0x57adabf739fd4da0),
+        FE::const_from_raw(0xc66f77381d247cbc),
+        FE::const_from_raw(0x6e2a4a99a74cfc14),
+        FE::const_from_raw(0xea8d1862edc8f863),
+        FE::const_from_raw(0xc6db4cd97dc665da),
+        FE::const_from_raw(0x949056b5e892bf9a),
+        FE::const_from_raw(0x462fe0ca6f15ab99),
+        FE::const_from_raw(0x9adb62723963213e),
+        FE::const_from_raw(0xe6727c5fd42965fc),
+        FE::const_from_raw(0x7fbdd9a508ac0f2d),
+        FE::const_from_raw(0x81fe716d0f7abc16),
+        FE::const_from_raw(0x9576f06f87da27da),
+        FE::const_from_raw(0xfc365eab4c817bc4),
+        FE::const_from_raw(0xfd9ef2ac09e90378),
+        FE::const_from_raw(0xd617a6538caecd71),
+        FE::const_from_raw(0x65b8045fbc97224e),
+        FE::const_from_raw(0xa9d715b7578ad6db),
+        FE::const_from_raw(0xc2dea08b2f8e0fec),
+        FE::const_from_raw(0xb2a75f374961bd18),
+        FE::const_from_raw(0xbd384569c776ea85),
+        FE::const_from_raw(0x3830b682c3aaaf39),
+        FE::const_from_raw(0xae0fd86a8479f28a),
+        FE::const_from_raw(0x3af2201bcba3c6c8),
+        FE::const_from_raw(0xc62b22ab3d6edcef),
+        FE::const_from_raw(0xd82a8399ca086539),
+        FE::const_from_raw(0x9a8a1adb11b997e1),
+        FE::const_from_raw(0x62e9c6079f0f4489),
+        FE::const_from_raw(0x5b42e26cad54c3eb),
+        FE::const_from_raw(0x0d23026116e75052),
+        FE::const_from_raw(0x117b3df6a1bcabd3),
+        FE::const_from_raw(0xdc15f849a793f4ff),
+        FE::const_from_raw(0xcb55e856cff1a9fc),
+        FE::const_from_raw(0x4f276ed50185804c),
+        FE::const_from_raw(0x9a2a1c18334e8eee),
+        FE::const_from_raw(0x942eae69c7b2ac12),
+        FE::const_from_raw(0x372123fca5367880),
+        FE::const_from_raw(0x7299f05b81f6ab8c),
+        FE::const_from_raw(0xc4b6d222335c0d40),
+        FE::const_from_raw(0x54e175dc9898de82),
+        FE::const_from_raw(0x9a59ed2ff8185bb6),
+        FE::const_from_raw(0xe31cffc4a4d1595c),
+        FE::const_from_raw(0x953ea6a8cf91eb62),
+        FE::const_from_raw(0xd73df50b58e3de87),
+        FE::const_from_raw(0xcba164ac5529a437),
+        FE::const_from_raw(0xed86532f13f5a01b),
+        FE::const_from_raw(0x48638620ea9a8cdf),
+        FE::const_from_raw(0xbf39c6d292e61897),
+        FE::const_from_raw(0xcbeafcace75ae54b),
+        FE::const_from_raw(0x9b1c4ba273aef896),
+        FE::const_from_raw(0x34a3fa4e9ea8b222),
+        FE::const_from_raw(0x388497890444f9e4),
+        FE::const_from_raw(0x1ceeb6d09ae44039),
+        FE::const_from_raw(0xc934ed066fc000bd),
+        FE::const_from_raw(0x5420b49b40809695),
+        FE::const_from_raw(0x227bb866b6e43b27),
+        FE::const_from_raw(0x023cce4d47323bd5),
+        FE::const_from_raw(0x9bdd445cfb266aa9),
+        FE::const_from_raw(0xba558b69d5e89ac6),
+        FE::const_from_raw(0x45e50280e3d7c220),
+        FE::const_from_raw(0xc7b336bf7db5785e),
+        FE::const_from_raw(0x17c3a2296aa7cabc),
+        FE::const_from_raw(0xe7a055c8663e8ece),
+        FE::const_from_raw(0x7014aeac12a9562a),
+        FE::const_from_raw(0xf1a5396bf65b5aa2),
+        FE::const_from_raw(0x4e6642abd7507fb0),
+        FE::const_from_raw(0x630e0222d5393a15),
+        FE::const_from_raw(0x173af02aa4f69206),
+        FE::const_from_raw(0xaf5c39865a5eb017),
+        FE::const_from_raw(0x4ae10acdc3c41602),
+        FE::const_from_raw(0x0058046e6d9df692),
+        FE::const_from_raw(0xa44bff2bee5f1073),
+        FE::const_from_raw(0x944a687060c16827),
+        FE::const_from_raw(0xc43390133b0d7316),
+        FE::const_from_raw(0xd41f77f6bad6185a),
+        FE::const_from_raw(0x9af59b9c3c1d1cfc),
+        FE::const_from_raw(0x3e36dd171e4a675d),
+        FE::const_from_raw(0xc7ba958b07eb9943),
+        FE::const_from_raw(0xc4b47b8808de11c0),
+        FE::const_from_raw(0x001ef692416bc9cd),
+        FE::const_from_raw(0xa2fb05ec5711129e),
+        FE::const_from_raw(0x3a2f12f04b368596),
+        FE::const_from_raw(0x44fd1b36bd05ded4),
+        FE::const_from_raw(0xc132a1c940e7efac),
+        FE::const_from_raw(0x0990f606eef60c22),
+        FE::const_from_raw(0xed8ec2a68338a212),
+        FE::const_from_raw(0xbfb310dd70919411),
+        FE::const_from_raw(0xadf2a5d0d908ac0d),
+        FE::const_from_raw(0x6fd5fa590b36a39b),
+        FE::const_from_raw(0xa264d5481bcadb21),
+        FE::const_from_raw(0x2c0452d2bc532534),
+        FE::const_from_raw(0xfa6641a6cf17cc0b),
+        FE::const_from_raw(0x6ada4c9390f0eafa),
+        FE::const_from_raw(0x152c1b3439da0ac2),
+        FE::const_from_raw(0x615013a63de9adb0),
+        FE::const_from_raw(0xcd17255ec2e4cb01),
+        FE::const_from_raw(0xba7715fb4a4fadfa),
+        FE::const_from_raw(0xa0ca4b6d43eebdf4),
+        FE::const_from_raw(0xf290b6fed5af6f62),
+        FE::const_from_raw(0xdc0fe55c9e65aa26),
+        FE::const_from_raw(0xbd600ace449304c2),
+        FE::const_from_raw(0x0e53a360f26da9b5),
+        FE::const_from_raw(0x78605b519f96abe6),
+        FE::const_from_raw(0xea7e408734243799),
+        FE::const_from_raw(0xf53ac0707eb51726),
+        FE::const_from_raw(0x0336f478469cef21),
+        FE::const_from_raw(0xbc6af9b810b9f89c),
+        FE::const_from_raw(0xb722092616785496),
+        FE::const_from_raw(0xbca7b0ca58c04422),
+        FE::const_from_raw(0xf7870109a513441b),
+        FE::const_from_raw(0x8c71931c2de63eb8),
+        FE::const_from_raw(0xf79815be37e5ce04),
+        FE::const_from_raw(0xeee4e1205eab3d44),
+        FE::const_from_raw(0x52a23d6299839b6c),
+        FE::const_from_raw(0x9fc5362010ac1103),
+        FE::const_from_raw(0x9690d2f4abc80294),
+        FE::const_from_raw(0x0481281fb649ab93),
+        FE::const_from_raw(0xf2cd1f90ecede2fc),
+        FE::const_from_raw(0x301c378877734c25),
+        FE::const_from_raw(0xd20b8a3b7d6679d3),
+        FE::const_from_raw(0x33a8b5db96979da3),
+        FE::const_from_raw(0x13034e5c7269d9ef),
+        FE::const_from_raw(0xcfbce2ab85636d0e),
+        FE::const_from_raw(0x3f37a4c42edf4a97),
+        FE::const_from_raw(0xb63fd6ec7ff50302),
+        FE::const_from_raw(0x436b1e86ddc7362a),
+        FE::const_from_raw(0x6a54ae4b5c97b739),
+        FE::const_from_raw(0xf3bd6dd9365f3915),
+        FE::const_from_raw(0x4181aca49c9b271e),
+        FE::const_from_raw(0x49d4d7643da6aafb),
+        FE::const_from_raw(0x2036bf0f76786aa0),
+        FE::const_from_raw(0x7a42c4d2c7ae05b7),
+        FE::const_from_raw(0xd5ace3058744be86),
+        FE::const_from_raw(0x181a59418ab1c592),
+        FE::const_from_raw(0x77b67d60a5a07b36),
+        FE::const_from_raw(0x1e7cd334ecbf8178),
+        FE::const_from_raw(0x4c6e85d690a6141e),
+        FE::const_from_raw(0xf4b6a9f1be304bc9),
+        FE::const_from_raw(0x60d3f578fb9c343a),
+        FE::const_from_raw(0xdaac75db3c11fc58),
+        FE::const_from_raw(0x0864ae3ba35af1b9),
+        FE::const_from_raw(0xb6bc40765fb2570b),
+        FE::const_from_raw(0xd46b53cbe6a6f811),
+        FE::const_from_raw(0x8429e09e6ac7d398),
+        FE::const_from_raw(0x1ffb73140f60b153),
+        FE::const_from_raw(0x803d688fe62a93f8),
+        FE::const_from_raw(0xb41e9f0d9c051046),
+        FE::const_from_raw(0x098746d28211cd65),
+        FE::const_from_raw(0xe919f936e43f4b3b),
+        FE::const_from_raw(0x690416052f3471f3),
+        FE::const_from_raw(0x656d94333b449fc7),
+        FE::const_from_raw(0xf2b8a970984acf87),
+        FE::const_from_raw(0x8a3f96f9ca67752e),
+        FE::const_from_raw(0xce1efcd7a468c992),
+        FE::const_from_raw(0x5a4f3f1df0662069),
+        FE::const_from_raw(0xbece1eb8967e9e42),
+        FE::const_from_raw(0x872f99ff7891f554),
+        FE::const_from_raw(0xfd7cb913022fb888),
+        FE::const_from_raw(0xa4d257eb39902d5d),
+        FE::const_from_raw(0x67c99dfda1508416),
+        FE::const_from_raw(0x50cda61566da959e),
+        FE::const_from_raw(0xec462bbe31e2c852),
+        FE::const_from_raw(0x2569ce8db808f43c),
+        FE::const_from_raw(0x679a9dfdcf0fccb4),
+    ];
+}
@@ -0,0 +1,307 @@
+use alloc::vec::Vec;
+use lambdaworks_math::field::{element::FieldElement as FE, traits::IsPrimeField};
+
+/// Round constants and MDS matrix of a Poseidon instance, as generated by the reference
+/// implementation. The MDS matrix is stored row by row, like `PermutationParameters::MDS_MATRIX`.
+pub struct PoseidonConstants<F: IsPrimeField> {
+    pub round_constants: Vec<FE<F>>,
+    pub mds_matrix: Vec<FE<F>>,
+}
+
+/// Generates the parameters of Poseidon with S-box `x^alpha` over the prime field `F`, following
+/// Appendix F of the Poseidon paper (https://eprint.iacr.org/2019/458.pdf) and its reference script
+/// https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/generate_parameters_grain.sage
+/// The `(n_full_rounds + n_partial_rounds) * state_size` round constants are sampled first, and then
+/// Cauchy MDS matrices until one passes the script's checks against invariant subspace trails.
+/// `sbox` is the S-box identifier the LFSR is initialized with, see `GrainLFSR::new`.
+pub fn generate_constants<F: IsPrimeField>(
+    sbox: usize,
+    state_size: usize,
+    n_full_rounds: usize,
+    n_partial_rounds: usize,
+) -> PoseidonConstants<F> {
+    let mut grain = GrainLFSR::new(
+        F::field_bit_size(),
+        sbox,
+        state_size,
+        n_full_rounds,
+        n_partial_rounds,
+    );
+    let round_constants = (0..(n_full_rounds + n_partial_rounds) * state_size)
+        .map(|_| grain.next_field_element())
+        .collect();
+    let mds_matrix = loop {
+        let matrix = grain.cauchy_matrix(state_size);
+        if is_secure_mds_matrix(&matrix, state_size) {
+            break matrix;
+        }
+    };
+    PoseidonConstants {
+        round_constants,
+        mds_matrix,
+    }
+}
+
+/// Checks the MDS matrix `M` against the invariant subspace trails that Algorithms 1 to 3 of the
+/// reference script look for (https://eprint.iacr.org/2020/500), for partial rounds with a single
+/// S-box on the first element:
+/// - Algorithm 1: no nonzero subspace invariant under `M` keeps the S-box inactive in every round,
+///   that is, the vectors `e_0^T M^k` span the whole space.
+/// - Algorithms 2 and 3: no proper subspace containing `e_0` is invariant under `M^r`, for
+///   `1 <= r <= 4 * size`, that is, the vectors `M^(r * k) e_0` span the whole space.
+fn is_secure_mds_matrix<F: IsPrimeField>(matrix: &[FE<F>], size: usize) -> bool {
+    let transpose: Vec<FE<F>> = (0..size * size)
+        .map(|index| matrix[(index % size) * size + index / size].clone())
+        .collect();
+    if !generates_space(&transpose, size) {
+        return false;
+    }
+    let mut power = matrix.to_vec();
+    for _ in 0..4 * size {
+        if !generates_space(&power, size) {
+            return false;
+        }
+        power = matrix_product(&power, matrix, size);
+    }
+    true
+}
+
+/// Returns the product of two square matrices of `size` rows stored row by row.
+fn matrix_product<F: IsPrimeField>(a: &[FE<F>], b: &[FE<F>], size: usize) -> Vec<FE<F>> {
+    (0..size * size)
+        .map(|index| {
+            let (row, column) = (index / size, index % size);
+            (0..size).fold(FE::zero(), |acc, k| {
+                acc + &a[row * size + k] * &b[k * size + column]
+            })
+        })
+        .collect()
+}
+
+/// Returns whether the iterates `e_0, A e_0, ..., A^(size - 1) e_0` span the whole space, by
+/// Gaussian elimination.
+fn generates_space<F: IsPrimeField>(matrix: &[FE<F>], size: usize) -> bool {
+    let mut iterates: Vec<Vec<FE<F>>> = Vec::with_capacity(size);
+    let mut vector: Vec<FE<F>> = (0..size)
+        .map(|i| if i == 0 { FE::one() } else { FE::zero() })
+        .collect();
+    for _ in 0..size {
+        let next = (0..size)
+            .map(|row| {
+                (0..size).fold(FE::zero(), |acc, column| {
+                    acc + &matrix[row * size + column] * &vector[column]
+                })
+            })
+            .collect();
+        iterates.push(core::mem::replace(&mut vector, next));
+    }
+
+    for column in 0..size {
+        let Some(pivot) = (column..size).find(|row| iterates[*row][column] != FE::zero()) else {
+            return false;
+        };
+        iterates.swap(column, pivot);
+        let (reduced, rest) = iterates.split_at_mut(column + 1);
+        let pivot_row = &reduced[column];
+        let Ok(inverse) = pivot_row[column].inv() else {
+            return false;
+        };
+        for row in rest.iter_mut() {
+            let factor = &row[column] * &inverse;
+            for (x, y) in row.iter_mut().zip(pivot_row) {
+                *x = &*x - &factor * y;
+            }
+        }
+    }
+    true
+}
+
+/// The 80-bit Grain LFSR, used in self-shrinking mode as the source of randomness for the parameters.
+pub struct GrainLFSR {
+    state: u128,
+}
+
+impl GrainLFSR {
+    /// Initializes the LFSR for a prime field of `field_size` bits, and discards its first 160 bits.
+    /// The reference script identifies the S-box `x^alpha` with `sbox = 0` and `x^(-1)` with 1.
+    /// circomlib's instances use 0, while the Poseidon instances of
+    /// https://github.com/HorizenLabs/poseidon2 have `x^alpha` S-boxes but were generated with 1.
+    pub fn new(
+        field_size: usize,
+        sbox: usize,
+        state_size: usize,
+        n_full_rounds: usize,
+        n_partial_rounds: usize,
+    ) -> Self {
+        // The initial state encodes the field type (1 for prime fields) in 2 bits, the S-box
+        // in 4 bits, the field size and the state size in 12 bits each,
+        // and the numbers of rounds in 10 bits each, followed by 30 bits set to 1.
+        let fields = [
+            (1, 2),
+            (sbox, 4),
+            (field_size, 12),
+            (state_size, 12),
+            (n_full_rounds, 10),
+            (n_partial_rounds, 10),
+        ];
+        let mut bits: Vec<bool> = fields
+            .iter()
+            .flat_map(|(value, width)| (0..*width).rev().map(move |i| (value >> i) & 1 == 1))
+            .collect();
+        bits.resize(80, true);
+
+        let state = bits
+            .iter()
+            .enumerate()
+            .fold(0_u128, |state, (i, bit)| state | (u128::from(*bit) << i));
+        let mut grain = Self { state };
+        for _ in 0..160 {
+            grain.update();
+        }
+        grain
+    }
+
+    /// Shifts the register and returns the new bit b_{i+80} = b_{i+62} + b_{i+51} + b_{i+38} + b_{i+23} + b_{i+13} + b_i.
+    fn update(&mut self) -> bool {
+        let new_bit = [62, 51, 38, 23, 13, 0]
+            .iter()
+            .fold(0, |acc, i| acc ^ ((self.state >> i) & 1));
+        self.state = (self.state >> 1) | (new_bit << 79);
+        new_bit == 1
+    }
+
+    /// Returns the next output bit. Bits are taken in pairs, and the second one is output
+    /// only if the first one is 1.
+    pub fn next_bit(&mut self) -> bool {
+        loop {
+            let keep = self.update();
+            let bit = self.update();
+            if keep {
+                return bit;
+            }
+        }
+    }
+
+    /// Samples a field element from `F::field_bit_size()` bits, in big-endian order, resampling
+    /// until the value is smaller than the modulus.
+    pub fn next_field_element<F: IsPrimeField>(&mut self) -> FE<F> {
+        loop {
+            let (value, element) = self.next_integer::<F>();
+            if value <= F::modulus_minus_one() {
+                return element;
+            }
+        }
+    }
+
+    /// Samples a field element from `F::field_bit_size()` bits, reducing the value modulo p.
+    pub fn next_field_element_without_rejection<F: IsPrimeField>(&mut self) -> FE<F> {
+        self.next_integer::<F>().1
+    }
+
+    /// Samples `F::field_bit_size()` bits, returning them as an integer and reduced modulo p.
+    fn next_integer<F: IsPrimeField>(&mut self) -> (F::RepresentativeType, FE<F>) {
+        let zero = F::RepresentativeType::from(0);
+        let one = F::RepresentativeType::from(1);
+        (0..F::field_bit_size()).fold((zero, FE::zero()), |(value, element), _| {
+            let bit = self.next_bit();
+            (
+                value + value + if bit { one } else { zero },
+                element.double() + FE::from(u64::from(bit)),
+            )
+        })
+    }
+
+    /// Samples the Cauchy matrix `M[i][j] = 1 / (x_i + y_j)` from `2 * size` distinct field
+    /// elements, resampling them when some `x_i + y_j` is zero.
+    pub fn cauchy_matrix<F: IsPrimeField>(&mut self, size: usize) -> Vec<FE<F>> {
+        loop {
+            let mut elements: Vec<FE<F>> = (0..2 * size)
+                .map(|_| self.next_field_element_without_rejection())
+                .collect();
+            while (1..elements.len()).any(|i| elements[..i].contains(&elements[i])) {
+                elements = (0..2 * size)
+                    .map(|_| self.next_field_element_without_rejection())
+                    .collect();
+            }
+
+            let (xs, ys) = elements.split_at(size);
+            let matrix: Option<Vec<FE<F>>> = xs
+                .iter()
+                .flat_map(|x| ys.iter().map(move |y| (x + y).inv().ok()))
+                .collect();
+            if let Some(matrix) = matrix {
+                return matrix;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::poseidon::{
+        babybear::PoseidonBabyBear, bls12_381::PoseidonBLS12381, bn254::PoseidonBN254,
+        goldilocks::PoseidonGoldilocks, parameters::PermutationParameters,
+    };
+    use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bn_254::default_types::FrElement;
+
+    fn assert_constants_match_grain<P: PermutationParameters>(sbox: usize) {
+        let constants =
+            generate_constants::<P::F>(sbox, P::STATE_SIZE, P::N_FULL_ROUNDS, P::N_PARTIAL_ROUNDS);
+
+        assert_eq!(constants.round_constants, P::ROUND_CONSTANTS);
+        assert_eq!(constants.mds_matrix, P::MDS_MATRIX);
+    }
+
+    #[test]
+    fn bn254_round_constants_are_the_ones_of_circomlib() {
+        let constants =
+            generate_constants::<<PoseidonBN254 as PermutationParameters>::F>(0, 3, 8, 57);
+
+        assert_eq!(
+            constants.round_constants[0],
+            FrElement::from_hex_unchecked(
+                "0ee9a592ba9a9518d05986d656f40c2114c4993c11bb29938d21d47304cd8e6e"
+            )
+        );
+        assert_eq!(
+            constants.round_constants[1],
+            FrElement::from_hex_unchecked(
+                "00f1445235f2148c5986587169fc1bcd887b08d4d00868df5696fff40956e864"
+            )
+        );
+    }
+
+    #[test]
+    fn bn254_constants_match_grain() {
+        assert_constants_match_grain::<PoseidonBN254>(0);
+    }
+
+    #[test]
+    fn bls12_381_constants_match_grain() {
+        assert_constants_match_grain::<PoseidonBLS12381>(1);
+    }
+
+    #[test]
+    fn goldilocks_constants_match_grain() {
+        assert_constants_match_grain::<PoseidonGoldilocks>(1);
+    }
+
+    #[test]
+    fn babybear_constants_match_grain() {
+        assert_constants_match_grain::<PoseidonBabyBear>(1);
+    }
+
+    #[test]
+    fn matrices_with_invariant_subspaces_are_rejected() {
+        type F = <PoseidonBN254 as PermutationParameters>::F;
+        // The vectors with equal last two coordinates form a subspace that contains e_0 and is
+        // invariant under this matrix.
+        let matrix: Vec<FE<F>> = [2u64, 1, 1, 1, 2, 1, 1, 1, 2]
+            .into_iter()
+            .map(FE::from)
+            .collect();
+        assert!(!is_secure_mds_matrix(&matrix, 3));
+        assert!(is_secure_mds_matrix(PoseidonBN254::MDS_MATRIX, 3));
+    }
+}
@@ -26,6 +26,31 @@ pub trait PermutationParameters {
     const N_ROUND_CONSTANTS_ROWS: usize;
     const N_ROUND_CONSTANTS_COLS: usize;
 
+    /// Index of the state element the S-box is applied to in partial rounds.
+    const PARTIAL_ROUND_SBOX_INDEX: usize = 0;
+    /// Number of round constants consumed by each partial round. It is `STATE_SIZE` for the
+    /// constants of the reference implementation, and 1 when they have been optimized so that
+    /// only the element going through the S-box gets one (Appendix B of the Poseidon paper).
+    const N_PARTIAL_ROUND_CONSTANTS: usize = Self::STATE_SIZE;
+
+    /// Builds the state permuted by the fixed-length hashes, from at most `RATE` inputs.
+    /// By default, the inputs fill the rate and the first capacity element holds their number.
+    /// Panics with more than `RATE` inputs, e.g. when `hash` is called on an instance of rate 1,
+    /// since they wouldn't fit in the rate.
+    fn initial_state(inputs: &[FE<Self::F>]) -> Vec<FE<Self::F>> {
+        assert!(
+            inputs.len() <= Self::RATE,
+            "Poseidon instance of rate {} can't absorb {} inputs at once",
+            Self::RATE,
+            inputs.len()
+        );
+        let mut state = inputs.to_vec();
+        state.resize(Self::RATE, FE::zero());
+        state.push(FE::from(inputs.len() as u64));
+        state.resize(Self::STATE_SIZE, FE::zero());
+        state
+    }
+
     /// This is the mix function that operates with the MDS matrix
     /// Round Constants are sometimes picked to simplify this function,
     /// so it can be redefined by each set of permutation parameters if a simplification can be made to make it faster. Notice in that case, MDS constants may not be used.
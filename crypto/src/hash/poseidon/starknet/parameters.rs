@@ -20,6 +20,9 @@ impl PermutationParameters for PoseidonCairoStark252 {
 
     const N_ROUND_CONSTANTS_ROWS: usize = 91;
     const N_ROUND_CONSTANTS_COLS: usize = 3;
+
+    const PARTIAL_ROUND_SBOX_INDEX: usize = 2;
+    const N_PARTIAL_ROUND_CONSTANTS: usize = 1;
     /// Redefined mix function for optimization purposes
     #[inline(always)]
     fn mix(state: &mut [FE<Self::F>]) {
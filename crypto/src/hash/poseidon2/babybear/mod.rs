@@ -0,0 +1,2 @@
+pub mod parameters;
+pub use parameters::{Poseidon2BabyBearWidth16, Poseidon2BabyBearWidth24};
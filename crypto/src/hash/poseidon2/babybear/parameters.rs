@@ -0,0 +1,462 @@
+use crate::hash::poseidon2::Poseidon2Parameters;
+use lambdaworks_math::field::{
+    element::FieldElement as FE, fields::fft_friendly::babybear::Babybear31PrimeField,
+};
+
+impl Poseidon2Parameters for Poseidon2BabyBearWidth16 {
+    type F = Babybear31PrimeField;
+    const RATE: usize = 8;
+    const CAPACITY: usize = 8;
+    const ALPHA: u32 = 7;
+    const N_FULL_ROUNDS: usize = 8;
+    const N_PARTIAL_ROUNDS: usize = 13;
+
+    const EXTERNAL_ROUND_CONSTANTS: &'static [FE<Babybear31PrimeField>] =
+        &Poseidon2BabyBearWidth16::EXTERNAL_ROUND_CONSTANTS;
+    const INTERNAL_ROUND_CONSTANTS: &'static [FE<Babybear31PrimeField>] =
+        &Poseidon2BabyBearWidth16::INTERNAL_ROUND_CONSTANTS;
+    const INTERNAL_DIAGONAL: &'static [FE<Babybear31PrimeField>] =
+        &Poseidon2BabyBearWidth16::INTERNAL_DIAGONAL;
+}
+
+/// Poseidon2 over the BabyBear field: width 16 with rate 8, S-box x^7, 8 external rounds and
+/// 13 internal rounds. The internal diagonal is the one of the reference implementation
+/// (https://github.com/HorizenLabs/poseidon2), which Plonky3 also used, and the round constants
+/// are generated by the Grain LFSR. No other library is known to combine them, so the instance
+/// is specific to lambdaworks, see the [module docs](crate::hash::poseidon2).
+#[derive(Clone, Default)]
+pub struct Poseidon2BabyBearWidth16;
+
+impl Poseidon2BabyBearWidth16 {
+    const EXTERNAL_ROUND_CONSTANTS: [FE<Babybear31PrimeField>; 8 * 16] = [
+        FE::from_hex_unchecked("69cbb6af"),
+        FE::from_hex_unchecked("46ad93f9"),
+        FE::from_hex_unchecked("60a00f4e"),
+        FE::from_hex_unchecked("6b1297cd"),
+        FE::from_hex_unchecked("23189afe"),
+        FE::from_hex_unchecked("732e7bef"),
+        FE::from_hex_unchecked("72c246de"),
+        FE::from_hex_unchecked("2c941900"),
+        FE::from_hex_unchecked("557eede"),
+        FE::from_hex_unchecked("1580496f"),
+        FE::from_hex_unchecked("3a3ea77b"),
+        FE::from_hex_unchecked("54f3f271"),
+        FE::from_hex_unchecked("f49b029"),
+        FE::from_hex_unchecked("47872fe1"),
+        FE::from_hex_unchecked("221e2e36"),
+        FE::from_hex_unchecked("1ab7202e"),
+        FE::from_hex_unchecked("487779a6"),
+        FE::from_hex_unchecked("3851c9d8"),
+        FE::from_hex_unchecked("38dc17c0"),
+        FE::from_hex_unchecked("209f8849"),
+        FE::from_hex_unchecked("268dcee8"),
+        FE::from_hex_unchecked("350c48da"),
+        FE::from_hex_unchecked("5b9ad32e"),
+        FE::from_hex_unchecked("523272b"),
+        FE::from_hex_unchecked("3f89055b"),
+        FE::from_hex_unchecked("1e894b2"),
+        FE::from_hex_unchecked("13ddedde"),
+        FE::from_hex_unchecked("1b2ef334"),
+        FE::from_hex_unchecked("7507d8b4"),
+        FE::from_hex_unchecked("6ceeb94e"),
+        FE::from_hex_unchecked("52eb6ba2"),
+        FE::from_hex_unchecked("50642905"),
+        FE::from_hex_unchecked("5453f3f"),
+        FE::from_hex_unchecked("6349efc"),
+        FE::from_hex_unchecked("6922787c"),
+        FE::from_hex_unchecked("4bfff9c"),
+        FE::from_hex_unchecked("768c714a"),
+        FE::from_hex_unchecked("3e9ff21a"),
+        FE::from_hex_unchecked("15737c9c"),
+        FE::from_hex_unchecked("2229c807"),
+        FE::from_hex_unchecked("d47f88c"),
+        FE::from_hex_unchecked("97e0ecc"),
+        FE::from_hex_unchecked("27eadba0"),
+        FE::from_hex_unchecked("2d7d29e4"),
+        FE::from_hex_unchecked("3502aaa0"),
+        FE::from_hex_unchecked("f475fd7"),
+        FE::from_hex_unchecked("29fbda49"),
+        FE::from_hex_unchecked("18afffd"),
+        FE::from_hex_unchecked("315b618"),
+        FE::from_hex_unchecked("6d4497d1"),
+        FE::from_hex_unchecked("1b171d9e"),
+        FE::from_hex_unchecked("52861abd"),
+        FE::from_hex_unchecked("2e5d0501"),
+        FE::from_hex_unchecked("3ec8646c"),
+        FE::from_hex_unchecked("6e5f250a"),
+        FE::from_hex_unchecked("148ae8e6"),
+        FE::from_hex_unchecked("17f5fa4a"),
+        FE::from_hex_unchecked("3e66d284"),
+        FE::from_hex_unchecked("51aa3b"),
+        FE::from_hex_unchecked("483f7913"),
+        FE::from_hex_unchecked("2cfe5f15"),
+        FE::from_hex_unchecked("23427ca"),
+        FE::from_hex_unchecked("2cc78315"),
+        FE::from_hex_unchecked("1e36ea47"),
+        FE::from_hex_unchecked("7290a80d"),
+        FE::from_hex_unchecked("6f7e5329"),
+        FE::from_hex_unchecked("598ec8a8"),
+        FE::from_hex_unchecked("76a859a0"),
+        FE::from_hex_unchecked("6559e868"),
+        FE::from_hex_unchecked("657b83af"),
+        FE::from_hex_unchecked("13271d3f"),
+        FE::from_hex_unchecked("1f876063"),
+        FE::from_hex_unchecked("aeeae37"),
+        FE::from_hex_unchecked("706e9ca6"),
+        FE::from_hex_unchecked("46400cee"),
+        FE::from_hex_unchecked("72a05c26"),
+        FE::from_hex_unchecked("2c589c9e"),
+        FE::from_hex_unchecked("20bd37a7"),
+        FE::from_hex_unchecked("6a2d3d10"),
+        FE::from_hex_unchecked("20523767"),
+        FE::from_hex_unchecked("5b8fe9c4"),
+        FE::from_hex_unchecked("2aa501d6"),
+        FE::from_hex_unchecked("1e01ac3e"),
+        FE::from_hex_unchecked("1448bc54"),
+        FE::from_hex_unchecked("5ce5ad1c"),
+        FE::from_hex_unchecked("4918a14d"),
+        FE::from_hex_unchecked("2c46a83f"),
+        FE::from_hex_unchecked("4fcf6876"),
+        FE::from_hex_unchecked("61d8d5c8"),
+        FE::from_hex_unchecked("6ddf4ff9"),
+        FE::from_hex_unchecked("11fda4d3"),
+        FE::from_hex_unchecked("2933a8f"),
+        FE::from_hex_unchecked("170eaf81"),
+        FE::from_hex_unchecked("5a9c314f"),
+        FE::from_hex_unchecked("49a12590"),
+        FE::from_hex_unchecked("35ec52a1"),
+        FE::from_hex_unchecked("58eb1611"),
+        FE::from_hex_unchecked("5e481e65"),
+        FE::from_hex_unchecked("367125c9"),
+        FE::from_hex_unchecked("eba33ba"),
+        FE::from_hex_unchecked("1fc28ded"),
+        FE::from_hex_unchecked("66399ad"),
+        FE::from_hex_unchecked("cbec0ea"),
+        FE::from_hex_unchecked("75fd1af0"),
+        FE::from_hex_unchecked("50f5bf4e"),
+        FE::from_hex_unchecked("643d5f41"),
+        FE::from_hex_unchecked("6f4fe718"),
+        FE::from_hex_unchecked("5b3cbbde"),
+        FE::from_hex_unchecked("1e3afb3e"),
+        FE::from_hex_unchecked("296fb027"),
+        FE::from_hex_unchecked("45e1547b"),
+        FE::from_hex_unchecked("4a8db2ab"),
+        FE::from_hex_unchecked("59986d19"),
+        FE::from_hex_unchecked("30bcdfa3"),
+        FE::from_hex_unchecked("1db63932"),
+        FE::from_hex_unchecked("1d7c2824"),
+        FE::from_hex_unchecked("53b33681"),
+        FE::from_hex_unchecked("673b747"),
+        FE::from_hex_unchecked("38a98a3"),
+        FE::from_hex_unchecked("2c5bce60"),
+        FE::from_hex_unchecked("351979cd"),
+        FE::from_hex_unchecked("5008fb73"),
+        FE::from_hex_unchecked("547bca78"),
+        FE::from_hex_unchecked("711af481"),
+        FE::from_hex_unchecked("3f93bf64"),
+        FE::from_hex_unchecked("644d987b"),
+        FE::from_hex_unchecked("3c8bcd87"),
+        FE::from_hex_unchecked("608758b8"),
+    ];
+    const INTERNAL_ROUND_CONSTANTS: [FE<Babybear31PrimeField>; 13] = [
+        FE::from_hex_unchecked("5a8053c0"),
+        FE::from_hex_unchecked("693be639"),
+        FE::from_hex_unchecked("3858867d"),
+        FE::from_hex_unchecked("19334f6b"),
+        FE::from_hex_unchecked("128f0fd8"),
+        FE::from_hex_unchecked("4e2b1ccb"),
+        FE::from_hex_unchecked("61210ce0"),
+        FE::from_hex_unchecked("3c318939"),
+        FE::from_hex_unchecked("b5b2f22"),
+        FE::from_hex_unchecked("2edb11d5"),
+        FE::from_hex_unchecked("213effdf"),
+        FE::from_hex_unchecked("cac4606"),
+        FE::from_hex_unchecked("241af16d"),
+    ];
+    const INTERNAL_DIAGONAL: [FE<Babybear31PrimeField>; 16] = [
+        FE::from_hex_unchecked("a632d94"),
+        FE::from_hex_unchecked("6db657b7"),
+        FE::from_hex_unchecked("56fbdc9e"),
+        FE::from_hex_unchecked("52b3d8a"),
+        FE::from_hex_unchecked("33745201"),
+        FE::from_hex_unchecked("5c03108c"),
+        FE::from_hex_unchecked("beba37b"),
+        FE::from_hex_unchecked("258c2e8b"),
+        FE::from_hex_unchecked("12029f39"),
+        FE::from_hex_unchecked("694909ce"),
+        FE::from_hex_unchecked("6d231724"),
+        FE::from_hex_unchecked("21c3b222"),
+        FE::from_hex_unchecked("3c0904a5"),
+        FE::from_hex_unchecked("1d6acda"),
+        FE::from_hex_unchecked("27705c83"),
+        FE::from_hex_unchecked("5231c802"),
+    ];
+}
+
+impl Poseidon2Parameters for Poseidon2BabyBearWidth24 {
+    type F = Babybear31PrimeField;
+    const RATE: usize = 16;
+    const CAPACITY: usize = 8;
+    const ALPHA: u32 = 7;
+    const N_FULL_ROUNDS: usize = 8;
+    const N_PARTIAL_ROUNDS: usize = 21;
+
+    const EXTERNAL_ROUND_CONSTANTS: &'static [FE<Babybear31PrimeField>] =
+        &Poseidon2BabyBearWidth24::EXTERNAL_ROUND_CONSTANTS;
+    const INTERNAL_ROUND_CONSTANTS: &'static [FE<Babybear31PrimeField>] =
+        &Poseidon2BabyBearWidth24::INTERNAL_ROUND_CONSTANTS;
+    const INTERNAL_DIAGONAL: &'static [FE<Babybear31PrimeField>] =
+        &Poseidon2BabyBearWidth24::INTERNAL_DIAGONAL;
+}
+
+/// Poseidon2 over the BabyBear field: width 24 with rate 16, S-box x^7, 8 external rounds and
+/// 21 internal rounds. The round constants and the internal diagonal are the ones of the
+/// reference implementation (https://github.com/HorizenLabs/poseidon2).
+#[derive(Clone, Default)]
+pub struct Poseidon2BabyBearWidth24;
+
+impl Poseidon2BabyBearWidth24 {
+    const EXTERNAL_ROUND_CONSTANTS: [FE<Babybear31PrimeField>; 8 * 24] = [
+        FE::from_hex_unchecked("fa20c37"),
+        FE::from_hex_unchecked("795bb97"),
+        FE::from_hex_unchecked("12c60b9c"),
+        FE::from_hex_unchecked("eabd88e"),
+        FE::from_hex_unchecked("96485ca"),
+        FE::from_hex_unchecked("7093527"),
+        FE::from_hex_unchecked("1b1d4e50"),
+        FE::from_hex_unchecked("30a01ace"),
+        FE::from_hex_unchecked("3bd86f5a"),
+        FE::from_hex_unchecked("69af7c28"),
+        FE::from_hex_unchecked("3f94775f"),
+        FE::from_hex_unchecked("731560e8"),
+        FE::from_hex_unchecked("465a0ecd"),
+        FE::from_hex_unchecked("574ef807"),
+        FE::from_hex_unchecked("62fd4870"),
+        FE::from_hex_unchecked("52ccfe44"),
+        FE::from_hex_unchecked("14772b14"),
+        FE::from_hex_unchecked("4dedf371"),
+        FE::from_hex_unchecked("260acd7c"),
+        FE::from_hex_unchecked("1f51dc58"),
+        FE::from_hex_unchecked("75125532"),
+        FE::from_hex_unchecked("686a4d7b"),
+        FE::from_hex_unchecked("54bac179"),
+        FE::from_hex_unchecked("31947706"),
+        FE::from_hex_unchecked("29799d3b"),
+        FE::from_hex_unchecked("6e01ae90"),
+        FE::from_hex_unchecked("203a7a64"),
+        FE::from_hex_unchecked("4f7e25be"),
+        FE::from_hex_unchecked("72503f77"),
+        FE::from_hex_unchecked("45bd3b69"),
+        FE::from_hex_unchecked("769bd6b4"),
+        FE::from_hex_unchecked("5a867f08"),
+        FE::from_hex_unchecked("4fdba082"),
+        FE::from_hex_unchecked("251c4318"),
+        FE::from_hex_unchecked("28f06201"),
+        FE::from_hex_unchecked("6788c43a"),
+        FE::from_hex_unchecked("4c6d6a99"),
+        FE::from_hex_unchecked("357784a8"),
+        FE::from_hex_unchecked("2abaf051"),
+        FE::from_hex_unchecked("770f7de6"),
+        FE::from_hex_unchecked("1794b784"),
+        FE::from_hex_unchecked("4796c57a"),
+        FE::from_hex_unchecked("724b7a10"),
+        FE::from_hex_unchecked("449989a7"),
+        FE::from_hex_unchecked("64935cf1"),
+        FE::from_hex_unchecked("59e14aac"),
+        FE::from_hex_unchecked("e620bb8"),
+        FE::from_hex_unchecked("3af5a33b"),
+        FE::from_hex_unchecked("4465cc0e"),
+        FE::from_hex_unchecked("19df68f"),
+        FE::from_hex_unchecked("4af8d068"),
+        FE::from_hex_unchecked("8784f82"),
+        FE::from_hex_unchecked("cefdeae"),
+        FE::from_hex_unchecked("6337a467"),
+        FE::from_hex_unchecked("32fa7a16"),
+        FE::from_hex_unchecked("486f62d6"),
+        FE::from_hex_unchecked("386a7480"),
+        FE::from_hex_unchecked("20f17c4a"),
+        FE::from_hex_unchecked("54e50da8"),
+        FE::from_hex_unchecked("2012cf03"),
+        FE::from_hex_unchecked("5fe52950"),
+        FE::from_hex_unchecked("9afb6cd"),
+        FE::from_hex_unchecked("2523044e"),
+        FE::from_hex_unchecked("5c54d0ef"),
+        FE::from_hex_unchecked("71c01f3c"),
+        FE::from_hex_unchecked("60b2c4fb"),
+        FE::from_hex_unchecked("4050b379"),
+        FE::from_hex_unchecked("5e6a70a5"),
+        FE::from_hex_unchecked("418543f5"),
+        FE::from_hex_unchecked("71debe56"),
+        FE::from_hex_unchecked("1aad2994"),
+        FE::from_hex_unchecked("3368a483"),
+        FE::from_hex_unchecked("7a86f3a"),
+        FE::from_hex_unchecked("5ea43ff1"),
+        FE::from_hex_unchecked("2443780e"),
+        FE::from_hex_unchecked("4ce444f7"),
+        FE::from_hex_unchecked("146f9882"),
+        FE::from_hex_unchecked("3132b089"),
+        FE::from_hex_unchecked("197ea856"),
+        FE::from_hex_unchecked("667030c3"),
+        FE::from_hex_unchecked("2317d5dc"),
+        FE::from_hex_unchecked("c2c48a7"),
+        FE::from_hex_unchecked("56b2df66"),
+        FE::from_hex_unchecked("67bd81e9"),
+        FE::from_hex_unchecked("4fcdfb19"),
+        FE::from_hex_unchecked("4baaef32"),
+        FE::from_hex_unchecked("328d30a"),
+        FE::from_hex_unchecked("6235760d"),
+        FE::from_hex_unchecked("12432912"),
+        FE::from_hex_unchecked("a49e258"),
+        FE::from_hex_unchecked("30e1b70"),
+        FE::from_hex_unchecked("48caeb03"),
+        FE::from_hex_unchecked("49e4d9e9"),
+        FE::from_hex_unchecked("1051b5c6"),
+        FE::from_hex_unchecked("6a36dbbe"),
+        FE::from_hex_unchecked("4cff27a5"),
+        FE::from_hex_unchecked("32959ad"),
+        FE::from_hex_unchecked("2b18af6a"),
+        FE::from_hex_unchecked("55d3dc8c"),
+        FE::from_hex_unchecked("43bd26c8"),
+        FE::from_hex_unchecked("c41595f"),
+        FE::from_hex_unchecked("7048d2e2"),
+        FE::from_hex_unchecked("db8983"),
+        FE::from_hex_unchecked("2af563d7"),
+        FE::from_hex_unchecked("6e84758f"),
+        FE::from_hex_unchecked("611d64e1"),
+        FE::from_hex_unchecked("1f9977e2"),
+        FE::from_hex_unchecked("64163a0a"),
+        FE::from_hex_unchecked("5c5fc27b"),
+        FE::from_hex_unchecked("2e22561"),
+        FE::from_hex_unchecked("3a2d75db"),
+        FE::from_hex_unchecked("1ba7b71a"),
+        FE::from_hex_unchecked("34343f64"),
+        FE::from_hex_unchecked("7406b35d"),
+        FE::from_hex_unchecked("19df8299"),
+        FE::from_hex_unchecked("6ff4480a"),
+        FE::from_hex_unchecked("514a81c8"),
+        FE::from_hex_unchecked("57ab52ce"),
+        FE::from_hex_unchecked("6ad69f52"),
+        FE::from_hex_unchecked("3e0c0e0d"),
+        FE::from_hex_unchecked("48126114"),
+        FE::from_hex_unchecked("2a9d62cc"),
+        FE::from_hex_unchecked("17441f23"),
+        FE::from_hex_unchecked("485762bb"),
+        FE::from_hex_unchecked("2f218674"),
+        FE::from_hex_unchecked("6fdc64a"),
+        FE::from_hex_unchecked("861b7f2"),
+        FE::from_hex_unchecked("3b36eee6"),
+        FE::from_hex_unchecked("70a11040"),
+        FE::from_hex_unchecked("4b31737"),
+        FE::from_hex_unchecked("3722a872"),
+        FE::from_hex_unchecked("2a351c63"),
+        FE::from_hex_unchecked("623560dc"),
+        FE::from_hex_unchecked("62584ab2"),
+        FE::from_hex_unchecked("382c7c04"),
+        FE::from_hex_unchecked("3bf9edc7"),
+        FE::from_hex_unchecked("e38fe51"),
+        FE::from_hex_unchecked("376f3b10"),
+        FE::from_hex_unchecked("5381e178"),
+        FE::from_hex_unchecked("3afc61c7"),
+        FE::from_hex_unchecked("5c1bcb4d"),
+        FE::from_hex_unchecked("6643ce1f"),
+        FE::from_hex_unchecked("2d0af1c1"),
+        FE::from_hex_unchecked("8f583cc"),
+        FE::from_hex_unchecked("5d6ff60f"),
+        FE::from_hex_unchecked("6324c1e5"),
+        FE::from_hex_unchecked("74412fb7"),
+        FE::from_hex_unchecked("70c0192e"),
+        FE::from_hex_unchecked("b72f141"),
+        FE::from_hex_unchecked("4067a111"),
+        FE::from_hex_unchecked("57388c4f"),
+        FE::from_hex_unchecked("351009ec"),
+        FE::from_hex_unchecked("974c159"),
+        FE::from_hex_unchecked("539a58b3"),
+        FE::from_hex_unchecked("38c0cff"),
+        FE::from_hex_unchecked("476c0392"),
+        FE::from_hex_unchecked("3f7bc15f"),
+        FE::from_hex_unchecked("4491dd2c"),
+        FE::from_hex_unchecked("4d1fef55"),
+        FE::from_hex_unchecked("4936ae3"),
+        FE::from_hex_unchecked("58214dd4"),
+        FE::from_hex_unchecked("683c6aad"),
+        FE::from_hex_unchecked("1b42f16b"),
+        FE::from_hex_unchecked("6dc79135"),
+        FE::from_hex_unchecked("2d4e71ec"),
+        FE::from_hex_unchecked("3e2946ea"),
+        FE::from_hex_unchecked("59dce8db"),
+        FE::from_hex_unchecked("6cee892a"),
+        FE::from_hex_unchecked("47f07350"),
+        FE::from_hex_unchecked("7106ce93"),
+        FE::from_hex_unchecked("3bd4a7a9"),
+        FE::from_hex_unchecked("2bfe636a"),
+        FE::from_hex_unchecked("430011e9"),
+        FE::from_hex_unchecked("1cd66a"),
+        FE::from_hex_unchecked("307faf5b"),
+        FE::from_hex_unchecked("d9ef3fe"),
+        FE::from_hex_unchecked("6d40043a"),
+        FE::from_hex_unchecked("2e8f470c"),
+        FE::from_hex_unchecked("1b6865e8"),
+        FE::from_hex_unchecked("c0e6c01"),
+        FE::from_hex_unchecked("4d41981f"),
+        FE::from_hex_unchecked("423b9d3d"),
+        FE::from_hex_unchecked("410408cc"),
+        FE::from_hex_unchecked("263f0884"),
+        FE::from_hex_unchecked("5311bbd0"),
+        FE::from_hex_unchecked("4dae58d8"),
+        FE::from_hex_unchecked("30401cea"),
+        FE::from_hex_unchecked("9afa575"),
+        FE::from_hex_unchecked("4b3d5b42"),
+        FE::from_hex_unchecked("63ac0b37"),
+        FE::from_hex_unchecked("5fe5bb14"),
+        FE::from_hex_unchecked("5244e9d4"),
+    ];
+    const INTERNAL_ROUND_CONSTANTS: [FE<Babybear31PrimeField>; 21] = [
+        FE::from_hex_unchecked("1da78ec2"),
+        FE::from_hex_unchecked("730b0924"),
+        FE::from_hex_unchecked("3eb56cf3"),
+        FE::from_hex_unchecked("5bd93073"),
+        FE::from_hex_unchecked("37204c97"),
+        FE::from_hex_unchecked("51642d89"),
+        FE::from_hex_unchecked("66e943e8"),
+        FE::from_hex_unchecked("1a3e72de"),
+        FE::from_hex_unchecked("70beb1e9"),
+        FE::from_hex_unchecked("30ff3b3f"),
+        FE::from_hex_unchecked("4240d1c4"),
+        FE::from_hex_unchecked("12647b8d"),
+        FE::from_hex_unchecked("65d86965"),
+        FE::from_hex_unchecked("49ef4d7c"),
+        FE::from_hex_unchecked("47785697"),
+        FE::from_hex_unchecked("46b3969f"),
+        FE::from_hex_unchecked("5c7b7a0e"),
+        FE::from_hex_unchecked("7078fc60"),
+        FE::from_hex_unchecked("4f22d482"),
+        FE::from_hex_unchecked("482a9aee"),
+        FE::from_hex_unchecked("6beb839d"),
+    ];
+    const INTERNAL_DIAGONAL: [FE<Babybear31PrimeField>; 24] = [
+        FE::from_hex_unchecked("409133f0"),
+        FE::from_hex_unchecked("1667a8a1"),
+        FE::from_hex_unchecked("6a6c7b6"),
+        FE::from_hex_unchecked("6f53160e"),
+        FE::from_hex_unchecked("273b11d1"),
+        FE::from_hex_unchecked("3176c5d"),
+        FE::from_hex_unchecked("72f9bbf9"),
+        FE::from_hex_unchecked("73ceba91"),
+        FE::from_hex_unchecked("5cdef81d"),
+        FE::from_hex_unchecked("1393285"),
+        FE::from_hex_unchecked("46daee06"),
+        FE::from_hex_unchecked("65d7ba6"),
+        FE::from_hex_unchecked("52d72d6f"),
+        FE::from_hex_unchecked("5dd05e0"),
+        FE::from_hex_unchecked("3bab4b63"),
+        FE::from_hex_unchecked("6ada3842"),
+        FE::from_hex_unchecked("2fc5fbec"),
+        FE::from_hex_unchecked("770d61b0"),
+        FE::from_hex_unchecked("5715aae9"),
+        FE::from_hex_unchecked("3ef0e90"),
+        FE::from_hex_unchecked("75b6c770"),
+        FE::from_hex_unchecked("242adf5f"),
+        FE::from_hex_unchecked("d0ca4c"),
+        FE::from_hex_unchecked("36c0e388"),
+    ];
+}
@@ -0,0 +1,2 @@
+pub mod parameters;
+pub use parameters::{Poseidon2GoldilocksWidth12, Poseidon2GoldilocksWidth8};
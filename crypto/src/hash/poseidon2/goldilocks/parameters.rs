@@ -0,0 +1,292 @@
+use crate::hash::poseidon2::Poseidon2Parameters;
+use lambdaworks_math::field::{
+    element::FieldElement as FE, fields::u64_goldilocks_field::Goldilocks64Field,
+};
+
+impl Poseidon2Parameters for Poseidon2GoldilocksWidth8 {
+    type F = Goldilocks64Field;
+    const RATE: usize = 4;
+    const CAPACITY: usize = 4;
+    const ALPHA: u32 = 7;
+    const N_FULL_ROUNDS: usize = 8;
+    const N_PARTIAL_ROUNDS: usize = 22;
+
+    const EXTERNAL_ROUND_CONSTANTS: &'static [FE<Goldilocks64Field>] =
+        &Poseidon2GoldilocksWidth8::EXTERNAL_ROUND_CONSTANTS;
+    const INTERNAL_ROUND_CONSTANTS: &'static [FE<Goldilocks64Field>] =
+        &Poseidon2GoldilocksWidth8::INTERNAL_ROUND_CONSTANTS;
+    const INTERNAL_DIAGONAL: &'static [FE<Goldilocks64Field>] =
+        &Poseidon2GoldilocksWidth8::INTERNAL_DIAGONAL;
+}
+
+/// Poseidon2 over the Goldilocks field: width 8 with rate 4, S-box x^7, 8 external rounds and
+/// 22 internal rounds. The round constants and the internal diagonal are the ones of the
+/// reference implementation (https://github.com/HorizenLabs/poseidon2), but it has no known
+/// answers for this width, so the instance is treated as specific to lambdaworks, see the
+/// [module docs](crate::hash::poseidon2).
+#[derive(Clone, Default)]
+pub struct Poseidon2GoldilocksWidth8;
+
+impl Poseidon2GoldilocksWidth8 {
+    const EXTERNAL_ROUND_CONSTANTS: [FE<Goldilocks64Field>; 8 * 8] = [
+        FE::const_from_raw(0xdd5743e7f2a5a5d9),
+        FE::const_from_raw(0xcb3a864e58ada44b),
+        FE::const_from_raw(0xffa2449ed32f8cdc),
+        FE::const_from_raw(0x42025f65d6bd13ee),
+        FE::const_from_raw(0x7889175e25506323),
+        FE::const_from_raw(0x34b98bb03d24b737),
+        FE::const_from_raw(0xbdcc535ecc4faa2a),
+        FE::const_from_raw(0x5b20ad869fc0d033),
+        FE::const_from_raw(0xf1dda5b9259dfcb4),
+        FE::const_from_raw(0x27515210be112d59),
+        FE::const_from_raw(0x4227d1718c766c3f),
+        FE::const_from_raw(0x26d333161a5bd794),
+        FE::const_from_raw(0x49b938957bf4b026),
+        FE::const_from_raw(0x4a56b5938b213669),
+        FE::const_from_raw(0x1120426b48c8353d),
+        FE::const_from_raw(0x6b323c3f10a56cad),
+        FE::const_from_raw(0xce57d6245ddca6b2),
+        FE::const_from_raw(0xb1fc8d402bba1eb1),
+        FE::const_from_raw(0xb5c5096ca959bd04),
+        FE::const_from_raw(0x6db55cd306d31f7f),
+        FE::const_from_raw(0xc49d293a81cb9641),
+        FE::const_from_raw(0x1ce55a4fe979719f),
+        FE::const_from_raw(0xa92e60a9d178a4d1),
+        FE::const_from_raw(0x002cc64973bcfd8c),
+        FE::const_from_raw(0xcea721cce82fb11b),
+        FE::const_from_raw(0xe5b55eb8098ece81),
+        FE::const_from_raw(0x4e30525c6f1ddd66),
+        FE::const_from_raw(0x43c6702827070987),
+        FE::const_from_raw(0xaca68430a7b5762a),
+        FE::const_from_raw(0x3674238634df9c93),
+        FE::const_from_raw(0x88cee1c825e33433),
+        FE::const_from_raw(0xde99ae8d74b57176),
+        FE::const_from_raw(0x014ef1197d341346),
+        FE::const_from_raw(0x9725e20825d07394),
+        FE::const_from_raw(0xfdb25aef2c5bae3b),
+        FE::const_from_raw(0xbe5402dc598c971e),
+        FE::const_from_raw(0x93a5711f04cdca3d),
+        FE::const_from_raw(0xc45a9a5b2f8fb97b),
+        FE::const_from_raw(0xfe8946a924933545),
+        FE::const_from_raw(0x2af997a27369091c),
+        FE::const_from_raw(0xaa62c88e0b294011),
+        FE::const_from_raw(0x058eb9d810ce9f74),
+        FE::const_from_raw(0xb3cb23eced349ae4),
+        FE::const_from_raw(0xa3648177a77b4a84),
+        FE::const_from_raw(0x43153d905992d95d),
+        FE::const_from_raw(0xf4e2a97cda44aa4b),
+        FE::const_from_raw(0x5baa2702b908682f),
+        FE::const_from_raw(0x082923bdf4f750d1),
+        FE::const_from_raw(0x98ae09a325893803),
+        FE::const_from_raw(0xf8a6475077968838),
+        FE::const_from_raw(0xceb0735bf00b2c5f),
+        FE::const_from_raw(0x0a1a5d953888e072),
+        FE::const_from_raw(0x2fcb190489f94475),
+        FE::const_from_raw(0xb5be06270dec69fc),
+        FE::const_from_raw(0x739cb934b09acf8b),
+        FE::const_from_raw(0x537750b75ec7f25b),
+        FE::const_from_raw(0xe9dd318bae1f3961),
+        FE::const_from_raw(0xf7462137299efe1a),
+        FE::const_from_raw(0xb1f6b8eee9adb940),
+        FE::const_from_raw(0xbdebcc8a809dfe6b),
+        FE::const_from_raw(0x40fc1f791b178113),
+        FE::const_from_raw(0x3ac1c3362d014864),
+        FE::const_from_raw(0x9a016184bdb8aeba),
+        FE::const_from_raw(0x95f2394459fbc25e),
+    ];
+    const INTERNAL_ROUND_CONSTANTS: [FE<Goldilocks64Field>; 22] = [
+        FE::const_from_raw(0x488897d85ff51f56),
+        FE::const_from_raw(0x1140737ccb162218),
+        FE::const_from_raw(0xa7eeb9215866ed35),
+        FE::const_from_raw(0x9bd2976fee49fcc9),
+        FE::const_from_raw(0xc0c8f0de580a3fcc),
+        FE::const_from_raw(0x4fb2dae6ee8fc793),
+        FE::const_from_raw(0x343a89f35f37395b),
+        FE::const_from_raw(0x223b525a77ca72c8),
+        FE::const_from_raw(0x56ccb62574aaa918),
+        FE::const_from_raw(0xc4d507d8027af9ed),
+        FE::const_from_raw(0xa080673cf0b7e95c),
+        FE::const_from_raw(0xf0184884eb70dcf8),
+        FE::const_from_raw(0x044f10b0cb3d5c69),
+        FE::const_from_raw(0xe9e3f7993938f186),
+        FE::const_from_raw(0x1b761c80e772f459),
+        FE::const_from_raw(0x606cec607a1b5fac),
+        FE::const_from_raw(0x14a0c2e1d45f03cd),
+        FE::const_from_raw(0x4eace8855398574f),
+        FE::const_from_raw(0xf905ca7103eff3e6),
+        FE::const_from_raw(0xf8c8f8d20862c059),
+        FE::const_from_raw(0xb524fe8bdd678e5a),
+        FE::const_from_raw(0xfbb7865901a1ec41),
+    ];
+    const INTERNAL_DIAGONAL: [FE<Goldilocks64Field>; 8] = [
+        FE::const_from_raw(0xa98811a1fed4e3a5),
+        FE::const_from_raw(0x1cc48b54f377e2a0),
+        FE::const_from_raw(0xe40cd4f6c5609a26),
+        FE::const_from_raw(0x11de79ebca97a4a3),
+        FE::const_from_raw(0x9177c73d8b7e929c),
+        FE::const_from_raw(0x2a6fe8085797e791),
+        FE::const_from_raw(0x3de6e93329f8d5ad),
+        FE::const_from_raw(0x3f7af9125da962fe),
+    ];
+}
+
+impl Poseidon2Parameters for Poseidon2GoldilocksWidth12 {
+    type F = Goldilocks64Field;
+    const RATE: usize = 8;
+    const CAPACITY: usize = 4;
+    const ALPHA: u32 = 7;
+    const N_FULL_ROUNDS: usize = 8;
+    const N_PARTIAL_ROUNDS: usize = 22;
+
+    const EXTERNAL_ROUND_CONSTANTS: &'static [FE<Goldilocks64Field>] =
+        &Poseidon2GoldilocksWidth12::EXTERNAL_ROUND_CONSTANTS;
+    const INTERNAL_ROUND_CONSTANTS: &'static [FE<Goldilocks64Field>] =
+        &Poseidon2GoldilocksWidth12::INTERNAL_ROUND_CONSTANTS;
+    const INTERNAL_DIAGONAL: &'static [FE<Goldilocks64Field>] =
+        &Poseidon2GoldilocksWidth12::INTERNAL_DIAGONAL;
+}
+
+/// Poseidon2 over the Goldilocks field: width 12 with rate 8, S-box x^7, 8 external rounds and
+/// 22 internal rounds. The round constants and the internal diagonal are the ones of the
+/// reference implementation (https://github.com/HorizenLabs/poseidon2).
+#[derive(Clone, Default)]
+pub struct Poseidon2GoldilocksWidth12;
+
+impl Poseidon2GoldilocksWidth12 {
+    const EXTERNAL_ROUND_CONSTANTS: [FE<Goldilocks64Field>; 8 * 12] = [
+        FE::const_from_raw(0x13dcf33aba214f46),
+        FE::const_from_raw(0x30b3b654a1da6d83),
+        FE::const_from_raw(0x1fc634ada6159b56),
+        FE::const_from_raw(0x937459964dc03466),
+        FE::const_from_raw(0xedd2ef2ca7949924),
+        FE::const_from_raw(0xede9affde0e22f68),
+        FE::const_from_raw(0x8515b9d6bac9282d),
+        FE::const_from_raw(0x6b5c07b4e9e900d8),
+        FE::const_from_raw(0x1ec66368838c8a08),
+        FE::const_from_raw(0x9042367d80d1fbab),
+        FE::const_from_raw(0x400283564a3c3799),
+        FE::const_from_raw(0x4a00be0466bca75e),
+        FE::const_from_raw(0x7913beee58e3817f),
+        FE::const_from_raw(0xf545e88532237d90),
+        FE::const_from_raw(0x22f8cb8736042005),
+        FE::const_from_raw(0x6f04990e247a2623),
+        FE::const_from_raw(0xfe22e87ba37c38cd),
+        FE::const_from_raw(0xd20e32c85ffe2815),
+        FE::const_from_raw(0x117227674048fe73),
+        FE::const_from_raw(0x4e9fb7ea98a6b145),
+        FE::const_from_raw(0xe0866c232b8af08b),
+        FE::const_from_raw(0x00bbc77916884964),
+        FE::const_from_raw(0x7031c0fb990d7116),
+        FE::const_from_raw(0x240a9e87cf35108f),
+        FE::const_from_raw(0x2e6363a5a12244b3),
+        FE::const_from_raw(0x5e1c3787d1b5011c),
+        FE::const_from_raw(0x4132660e2a196e8b),
+        FE::const_from_raw(0x3a013b648d3d4327),
+        FE::const_from_raw(0xf79839f49888ea43),
+        FE::const_from_raw(0xfe85658ebafe1439),
+        FE::const_from_raw(0xb6889825a14240bd),
+        FE::const_from_raw(0x578453605541382b),
+        FE::const_from_raw(0x4508cda8f6b63ce9),
+        FE::const_from_raw(0x9c3ef35848684c91),
+        FE::const_from_raw(0x0812bde23c87178c),
+        FE::const_from_raw(0xfe49638f7f722c14),
+        FE::const_from_raw(0x8e3f688ce885cbf5),
+        FE::const_from_raw(0xb8e110acf746a87d),
+        FE::const_from_raw(0xb4b2e8973a6dabef),
+        FE::const_from_raw(0x9e714c5da3d462ec),
+        FE::const_from_raw(0x6438f9033d3d0c15),
+        FE::const_from_raw(0x24312f7cf1a27199),
+        FE::const_from_raw(0x23f843bb47acbf71),
+        FE::const_from_raw(0x9183f11a34be9f01),
+        FE::const_from_raw(0x839062fbb9d45dbf),
+        FE::const_from_raw(0x24b56e7e6c2e43fa),
+        FE::const_from_raw(0xe1683da61c962a72),
+        FE::const_from_raw(0xa95c63971a19bfa7),
+        FE::const_from_raw(0xc68be7c94882a24d),
+        FE::const_from_raw(0xaf996d5d5cdaedd9),
+        FE::const_from_raw(0x9717f025e7daf6a5),
+        FE::const_from_raw(0x6436679e6e7216f4),
+        FE::const_from_raw(0x8a223d99047af267),
+        FE::const_from_raw(0xbb512e35a133ba9a),
+        FE::const_from_raw(0xfbbf44097671aa03),
+        FE::const_from_raw(0xf04058ebf6811e61),
+        FE::const_from_raw(0x5cca84703fac7ffb),
+        FE::const_from_raw(0x9b55c7945de6469f),
+        FE::const_from_raw(0x8e05bf09808e934f),
+        FE::const_from_raw(0x2ea900de876307d7),
+        FE::const_from_raw(0x7748fff2b38dfb89),
+        FE::const_from_raw(0x6b99a676dd3b5d81),
+        FE::const_from_raw(0xac4bb7c627cf7c13),
+        FE::const_from_raw(0xadb6ebe5e9e2f5ba),
+        FE::const_from_raw(0x2d33378cafa24ae3),
+        FE::const_from_raw(0x1e5b73807543f8c2),
+        FE::const_from_raw(0x09208814bfebb10f),
+        FE::const_from_raw(0x782e64b6bb5b93dd),
+        FE::const_from_raw(0xadd5a48eac90b50f),
+        FE::const_from_raw(0xadd4c54c736ea4b1),
+        FE::const_from_raw(0xd58dbb86ed817fd8),
+        FE::const_from_raw(0x6d5ed1a533f34ddd),
+        FE::const_from_raw(0x28686aa3e36b7cb9),
+        FE::const_from_raw(0x591abd3476689f36),
+        FE::const_from_raw(0x047d766678f13875),
+        FE::const_from_raw(0xa2a11112625f5b49),
+        FE::const_from_raw(0x21fd10a3f8304958),
+        FE::const_from_raw(0xf9b40711443b0280),
+        FE::const_from_raw(0xd2697eb8b2bde88e),
+        FE::const_from_raw(0x3493790b51731b3f),
+        FE::const_from_raw(0x11caf9dd73764023),
+        FE::const_from_raw(0x7acfb8f72878164e),
+        FE::const_from_raw(0x744ec4db23cefc26),
+        FE::const_from_raw(0x1e00e58f422c6340),
+        FE::const_from_raw(0x21dd28d906a62dda),
+        FE::const_from_raw(0xf32a46ab5f465b5f),
+        FE::const_from_raw(0xbfce13201f3f7e6b),
+        FE::const_from_raw(0xf30d2e7adb5304e2),
+        FE::const_from_raw(0xecdf4ee4abad48e9),
+        FE::const_from_raw(0xf94e82182d395019),
+        FE::const_from_raw(0x4ee52e3744d887c5),
+        FE::const_from_raw(0xa1341c7cac0083b2),
+        FE::const_from_raw(0x2302fb26c30c834a),
+        FE::const_from_raw(0xaea3c587273bf7d3),
+        FE::const_from_raw(0xf798e24961823ec7),
+        FE::const_from_raw(0x962deba3e9a2cd94),
+    ];
+    const INTERNAL_ROUND_CONSTANTS: [FE<Goldilocks64Field>; 22] = [
+        FE::const_from_raw(0x4adf842aa75d4316),
+        FE::const_from_raw(0xf8fbb871aa4ab4eb),
+        FE::const_from_raw(0x68e85b6eb2dd6aeb),
+        FE::const_from_raw(0x07a0b06b2d270380),
+        FE::const_from_raw(0xd94e0228bd282de4),
+        FE::const_from_raw(0x8bdd91d3250c5278),
+        FE::const_from_raw(0x209c68b88bba778f),
+        FE::const_from_raw(0xb5e18cdab77f3877),
+        FE::const_from_raw(0xb296a3e808da93fa),
+        FE::const_from_raw(0x8370ecbda11a327e),
+        FE::const_from_raw(0x3f9075283775dad8),
+        FE::const_from_raw(0xb78095bb23c6aa84),
+        FE::const_from_raw(0x3f36b9fe72ad4e5f),
+        FE::const_from_raw(0x69bc96780b10b553),
+        FE::const_from_raw(0x3f1d341f2eb7b881),
+        FE::const_from_raw(0x4e939e9815838818),
+        FE::const_from_raw(0xda366b3ae2a31604),
+        FE::const_from_raw(0xbc89db1e7287d509),
+        FE::const_from_raw(0x6102f411f9ef5659),
+        FE::const_from_raw(0x58725c5e7ac1f0ab),
+        FE::const_from_raw(0x0df5856c798883e7),
+        FE::const_from_raw(0xf7bb62a8da4c961b),
+    ];
+    const INTERNAL_DIAGONAL: [FE<Goldilocks64Field>; 12] = [
+        FE::const_from_raw(0xc3b6c08e23ba9300),
+        FE::const_from_raw(0xd84b5de94a324fb6),
+        FE::const_from_raw(0x0d0c371c5b35b84f),
+        FE::const_from_raw(0x7964f570e7188037),
+        FE::const_from_raw(0x5daf18bbd996604b),
+        FE::const_from_raw(0x6743bc47b9595257),
+        FE::const_from_raw(0x5528b9362c59bb70),
+        FE::const_from_raw(0xac45e25b7127b68b),
+        FE::const_from_raw(0xa2077d7dfbb606b5),
+        FE::const_from_raw(0xf3faac6faee378ae),
+        FE::const_from_raw(0x0c6388b51545e883),
+        FE::const_from_raw(0xd27dbb6944917b60),
+    ];
+}
@@ -0,0 +1,2 @@
+pub mod parameters;
+pub use parameters::{Poseidon2Mersenne31Width16, Poseidon2Mersenne31Width24};
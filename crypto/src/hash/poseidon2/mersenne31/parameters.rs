@@ -0,0 +1,466 @@
+use crate::hash::poseidon2::Poseidon2Parameters;
+use lambdaworks_math::field::{
+    element::FieldElement as FE, fields::mersenne31::field::Mersenne31Field,
+};
+
+impl Poseidon2Parameters for Poseidon2Mersenne31Width16 {
+    type F = Mersenne31Field;
+    const RATE: usize = 8;
+    const CAPACITY: usize = 8;
+    const ALPHA: u32 = 5;
+    const N_FULL_ROUNDS: usize = 8;
+    const N_PARTIAL_ROUNDS: usize = 14;
+
+    const EXTERNAL_ROUND_CONSTANTS: &'static [FE<Mersenne31Field>] =
+        &Poseidon2Mersenne31Width16::EXTERNAL_ROUND_CONSTANTS;
+    const INTERNAL_ROUND_CONSTANTS: &'static [FE<Mersenne31Field>] =
+        &Poseidon2Mersenne31Width16::INTERNAL_ROUND_CONSTANTS;
+    const INTERNAL_DIAGONAL: &'static [FE<Mersenne31Field>] =
+        &Poseidon2Mersenne31Width16::INTERNAL_DIAGONAL;
+}
+
+/// Poseidon2 over the Mersenne31 field: width 16 with rate 8, S-box x^5, 8 external rounds and
+/// 14 internal rounds. The reference implementation has no Mersenne31 instance: the round
+/// constants are generated by the Grain LFSR as it would generate them, and the internal diagonal
+/// is the one of Plonky3, `[-2, 2^0, ..., 2^8, 2^10, 2^12, ..., 2^16]`. No other library is known
+/// to combine them, so the instance is specific to lambdaworks, see the [module docs](crate::hash::poseidon2).
+#[derive(Clone, Default)]
+pub struct Poseidon2Mersenne31Width16;
+
+impl Poseidon2Mersenne31Width16 {
+    const EXTERNAL_ROUND_CONSTANTS: [FE<Mersenne31Field>; 8 * 16] = [
+        FE::const_from_raw(0x768bab52),
+        FE::const_from_raw(0x70e0ab7d),
+        FE::const_from_raw(0x3d266c8a),
+        FE::const_from_raw(0x6da42045),
+        FE::const_from_raw(0x600fef22),
+        FE::const_from_raw(0x41dace6b),
+        FE::const_from_raw(0x64f9bdd4),
+        FE::const_from_raw(0x5d42d4fe),
+        FE::const_from_raw(0x76b1516d),
+        FE::const_from_raw(0x6fc9a717),
+        FE::const_from_raw(0x70ac4fb6),
+        FE::const_from_raw(0x00194ef6),
+        FE::const_from_raw(0x22b644e2),
+        FE::const_from_raw(0x1f7916d5),
+        FE::const_from_raw(0x47581be2),
+        FE::const_from_raw(0x2710a123),
+        FE::const_from_raw(0x6284e867),
+        FE::const_from_raw(0x018d3afe),
+        FE::const_from_raw(0x5df99ef3),
+        FE::const_from_raw(0x4c1e467b),
+        FE::const_from_raw(0x566f6abc),
+        FE::const_from_raw(0x2994e427),
+        FE::const_from_raw(0x538a6d42),
+        FE::const_from_raw(0x5d7bf2cf),
+        FE::const_from_raw(0x7fda2dab),
+        FE::const_from_raw(0x0fd854c4),
+        FE::const_from_raw(0x46922fca),
+        FE::const_from_raw(0x3d7763a1),
+        FE::const_from_raw(0x19fd05ca),
+        FE::const_from_raw(0x0a4bbb43),
+        FE::const_from_raw(0x15075851),
+        FE::const_from_raw(0x3d903d76),
+        FE::const_from_raw(0x2d290ff7),
+        FE::const_from_raw(0x40809fa0),
+        FE::const_from_raw(0x59dac6ec),
+        FE::const_from_raw(0x127927a2),
+        FE::const_from_raw(0x6bbf0ea0),
+        FE::const_from_raw(0x0294140f),
+        FE::const_from_raw(0x24742976),
+        FE::const_from_raw(0x6e84c081),
+        FE::const_from_raw(0x22484f4a),
+        FE::const_from_raw(0x354cae59),
+        FE::const_from_raw(0x0453ffe1),
+        FE::const_from_raw(0x3f47a3cc),
+        FE::const_from_raw(0x0088204e),
+        FE::const_from_raw(0x6066e109),
+        FE::const_from_raw(0x3b7c4b80),
+        FE::const_from_raw(0x6b55665d),
+        FE::const_from_raw(0x3bc4b897),
+        FE::const_from_raw(0x735bf378),
+        FE::const_from_raw(0x508daf42),
+        FE::const_from_raw(0x1884fc2b),
+        FE::const_from_raw(0x7214f24c),
+        FE::const_from_raw(0x7498be0a),
+        FE::const_from_raw(0x1a60e640),
+        FE::const_from_raw(0x3303f928),
+        FE::const_from_raw(0x29b46376),
+        FE::const_from_raw(0x5c96bb68),
+        FE::const_from_raw(0x65d097a5),
+        FE::const_from_raw(0x1d358e9f),
+        FE::const_from_raw(0x4a9a9017),
+        FE::const_from_raw(0x4724cf76),
+        FE::const_from_raw(0x347af70f),
+        FE::const_from_raw(0x1e77e59a),
+        FE::const_from_raw(0x57090613),
+        FE::const_from_raw(0x1fa42108),
+        FE::const_from_raw(0x17bbef50),
+        FE::const_from_raw(0x1ff7e11c),
+        FE::const_from_raw(0x047b24ca),
+        FE::const_from_raw(0x4e140275),
+        FE::const_from_raw(0x4fa086f5),
+        FE::const_from_raw(0x079b309c),
+        FE::const_from_raw(0x1159bd47),
+        FE::const_from_raw(0x6d37e4e5),
+        FE::const_from_raw(0x075d8dce),
+        FE::const_from_raw(0x12121ca0),
+        FE::const_from_raw(0x7f6a7c40),
+        FE::const_from_raw(0x68e182ba),
+        FE::const_from_raw(0x5493201b),
+        FE::const_from_raw(0x0444a80e),
+        FE::const_from_raw(0x0064f4c6),
+        FE::const_from_raw(0x6467abe6),
+        FE::const_from_raw(0x66975762),
+        FE::const_from_raw(0x2af68f9b),
+        FE::const_from_raw(0x345b33be),
+        FE::const_from_raw(0x1b70d47f),
+        FE::const_from_raw(0x053db717),
+        FE::const_from_raw(0x381189cb),
+        FE::const_from_raw(0x43b915f8),
+        FE::const_from_raw(0x20df3694),
+        FE::const_from_raw(0x0f459d26),
+        FE::const_from_raw(0x77a0e97b),
+        FE::const_from_raw(0x2f73e739),
+        FE::const_from_raw(0x1876c2f9),
+        FE::const_from_raw(0x65a0e29a),
+        FE::const_from_raw(0x4cabefbe),
+        FE::const_from_raw(0x5abd1268),
+        FE::const_from_raw(0x4d34a760),
+        FE::const_from_raw(0x12771799),
+        FE::const_from_raw(0x69a0c9ac),
+        FE::const_from_raw(0x39091e55),
+        FE::const_from_raw(0x7f611cd0),
+        FE::const_from_raw(0x3af055da),
+        FE::const_from_raw(0x7ac0bbdf),
+        FE::const_from_raw(0x6e0f3a24),
+        FE::const_from_raw(0x41e3b6f7),
+        FE::const_from_raw(0x49b3756d),
+        FE::const_from_raw(0x568bc538),
+        FE::const_from_raw(0x20c079d8),
+        FE::const_from_raw(0x1701c72c),
+        FE::const_from_raw(0x7670dc6c),
+        FE::const_from_raw(0x5a439035),
+        FE::const_from_raw(0x7c93e00e),
+        FE::const_from_raw(0x561fbb4d),
+        FE::const_from_raw(0x1178907b),
+        FE::const_from_raw(0x02737406),
+        FE::const_from_raw(0x32fb24f1),
+        FE::const_from_raw(0x6323b60a),
+        FE::const_from_raw(0x6ab12418),
+        FE::const_from_raw(0x42c99cea),
+        FE::const_from_raw(0x155a0b97),
+        FE::const_from_raw(0x53d1c6aa),
+        FE::const_from_raw(0x2bd20347),
+        FE::const_from_raw(0x279b3d73),
+        FE::const_from_raw(0x4f5f3c70),
+        FE::const_from_raw(0x0245af6c),
+        FE::const_from_raw(0x238359d3),
+        FE::const_from_raw(0x49966a59),
+    ];
+    const INTERNAL_ROUND_CONSTANTS: [FE<Mersenne31Field>; 14] = [
+        FE::const_from_raw(0x7f7ec4bf),
+        FE::const_from_raw(0x0421926f),
+        FE::const_from_raw(0x5198e669),
+        FE::const_from_raw(0x34db3148),
+        FE::const_from_raw(0x4368bafd),
+        FE::const_from_raw(0x66685c7f),
+        FE::const_from_raw(0x78d3249a),
+        FE::const_from_raw(0x60187881),
+        FE::const_from_raw(0x76dad67a),
+        FE::const_from_raw(0x0690b437),
+        FE::const_from_raw(0x1ea95311),
+        FE::const_from_raw(0x40e5369a),
+        FE::const_from_raw(0x38f103fc),
+        FE::const_from_raw(0x1d226a21),
+    ];
+    const INTERNAL_DIAGONAL: [FE<Mersenne31Field>; 16] = [
+        FE::const_from_raw(0x7ffffffd),
+        FE::const_from_raw(0x00000001),
+        FE::const_from_raw(0x00000002),
+        FE::const_from_raw(0x00000004),
+        FE::const_from_raw(0x00000008),
+        FE::const_from_raw(0x00000010),
+        FE::const_from_raw(0x00000020),
+        FE::const_from_raw(0x00000040),
+        FE::const_from_raw(0x00000080),
+        FE::const_from_raw(0x00000100),
+        FE::const_from_raw(0x00000400),
+        FE::const_from_raw(0x00001000),
+        FE::const_from_raw(0x00002000),
+        FE::const_from_raw(0x00004000),
+        FE::const_from_raw(0x00008000),
+        FE::const_from_raw(0x00010000),
+    ];
+}
+
+impl Poseidon2Parameters for Poseidon2Mersenne31Width24 {
+    type F = Mersenne31Field;
+    const RATE: usize = 16;
+    const CAPACITY: usize = 8;
+    const ALPHA: u32 = 5;
+    const N_FULL_ROUNDS: usize = 8;
+    const N_PARTIAL_ROUNDS: usize = 22;
+
+    const EXTERNAL_ROUND_CONSTANTS: &'static [FE<Mersenne31Field>] =
+        &Poseidon2Mersenne31Width24::EXTERNAL_ROUND_CONSTANTS;
+    const INTERNAL_ROUND_CONSTANTS: &'static [FE<Mersenne31Field>] =
+        &Poseidon2Mersenne31Width24::INTERNAL_ROUND_CONSTANTS;
+    const INTERNAL_DIAGONAL: &'static [FE<Mersenne31Field>] =
+        &Poseidon2Mersenne31Width24::INTERNAL_DIAGONAL;
+}
+
+/// Poseidon2 over the Mersenne31 field: width 24 with rate 16, S-box x^5, 8 external rounds and
+/// 22 internal rounds. The reference implementation has no Mersenne31 instance: the round
+/// constants are generated by the Grain LFSR as it would generate them, and the internal diagonal
+/// is the one of Plonky3, `[-2, 2^0, 2^1, ..., 2^22]`. As for width 16, the instance is specific
+/// to lambdaworks, see the [module docs](crate::hash::poseidon2).
+#[derive(Clone, Default)]
+pub struct Poseidon2Mersenne31Width24;
+
+impl Poseidon2Mersenne31Width24 {
+    const EXTERNAL_ROUND_CONSTANTS: [FE<Mersenne31Field>; 8 * 24] = [
+        FE::const_from_raw(0x1feaba61),
+        FE::const_from_raw(0x53224454),
+        FE::const_from_raw(0x6bceb9e2),
+        FE::const_from_raw(0x5019f9b4),
+        FE::const_from_raw(0x48726592),
+        FE::const_from_raw(0x2b22d0a8),
+        FE::const_from_raw(0x6151bbf9),
+        FE::const_from_raw(0x2f474b21),
+        FE::const_from_raw(0x2eb5f337),
+        FE::const_from_raw(0x3b645d87),
+        FE::const_from_raw(0x0942cef0),
+        FE::const_from_raw(0x65228c52),
+        FE::const_from_raw(0x78ffb30f),
+        FE::const_from_raw(0x4d2837c8),
+        FE::const_from_raw(0x0e17ac4f),
+        FE::const_from_raw(0x05546686),
+        FE::const_from_raw(0x046c06cc),
+        FE::const_from_raw(0x0b51c3b6),
+        FE::const_from_raw(0x568db763),
+        FE::const_from_raw(0x38b334e4),
+        FE::const_from_raw(0x57f5acf0),
+        FE::const_from_raw(0x19d32611),
+        FE::const_from_raw(0x77d02f4b),
+        FE::const_from_raw(0x6c82e9b8),
+        FE::const_from_raw(0x7148c1b6),
+        FE::const_from_raw(0x08067c75),
+        FE::const_from_raw(0x46d1e8c9),
+        FE::const_from_raw(0x30973b07),
+        FE::const_from_raw(0x20614f3b),
+        FE::const_from_raw(0x5c3ff851),
+        FE::const_from_raw(0x30503329),
+        FE::const_from_raw(0x4972e7cc),
+        FE::const_from_raw(0x02d1d8bc),
+        FE::const_from_raw(0x09d5bfa6),
+        FE::const_from_raw(0x097104c0),
+        FE::const_from_raw(0x7ba49a34),
+        FE::const_from_raw(0x4a07c2fc),
+        FE::const_from_raw(0x24c1ee69),
+        FE::const_from_raw(0x28a6ab41),
+        FE::const_from_raw(0x5d9108a0),
+        FE::const_from_raw(0x3a7851c7),
+        FE::const_from_raw(0x1dd495f9),
+        FE::const_from_raw(0x12b49ff4),
+        FE::const_from_raw(0x7bad5760),
+        FE::const_from_raw(0x5fed64c2),
+        FE::const_from_raw(0x66f5c96c),
+        FE::const_from_raw(0x7eafbd02),
+        FE::const_from_raw(0x39b3593b),
+        FE::const_from_raw(0x4a653b49),
+        FE::const_from_raw(0x75091dc1),
+        FE::const_from_raw(0x56e488e0),
+        FE::const_from_raw(0x1704a355),
+        FE::const_from_raw(0x745e4ff3),
+        FE::const_from_raw(0x392ef16e),
+        FE::const_from_raw(0x31e33fdf),
+        FE::const_from_raw(0x02c28c66),
+        FE::const_from_raw(0x36c3083a),
+        FE::const_from_raw(0x3104d1fa),
+        FE::const_from_raw(0x5b03cda3),
+        FE::const_from_raw(0x6641e1af),
+        FE::const_from_raw(0x37754b56),
+        FE::const_from_raw(0x396f5af9),
+        FE::const_from_raw(0x1a1a461a),
+        FE::const_from_raw(0x688e26f2),
+        FE::const_from_raw(0x6f829784),
+        FE::const_from_raw(0x1bb91d69),
+        FE::const_from_raw(0x5b788016),
+        FE::const_from_raw(0x704aa5c5),
+        FE::const_from_raw(0x0181869c),
+        FE::const_from_raw(0x41211e56),
+        FE::const_from_raw(0x0ce803a0),
+        FE::const_from_raw(0x23bff3a0),
+        FE::const_from_raw(0x17fb7064),
+        FE::const_from_raw(0x47317220),
+        FE::const_from_raw(0x76914b53),
+        FE::const_from_raw(0x219c1905),
+        FE::const_from_raw(0x16655528),
+        FE::const_from_raw(0x4df35544),
+        FE::const_from_raw(0x60808465),
+        FE::const_from_raw(0x3350f833),
+        FE::const_from_raw(0x03bccdc7),
+        FE::const_from_raw(0x0a87180a),
+        FE::const_from_raw(0x017a99f5),
+        FE::const_from_raw(0x6e945726),
+        FE::const_from_raw(0x15445504),
+        FE::const_from_raw(0x780533b1),
+        FE::const_from_raw(0x3b91bf38),
+        FE::const_from_raw(0x3fc77eb1),
+        FE::const_from_raw(0x4b4d960e),
+        FE::const_from_raw(0x3cd93d2e),
+        FE::const_from_raw(0x0ea4e976),
+        FE::const_from_raw(0x1d5306cc),
+        FE::const_from_raw(0x3a7ac284),
+        FE::const_from_raw(0x0ec22934),
+        FE::const_from_raw(0x4d979713),
+        FE::const_from_raw(0x51a41c65),
+        FE::const_from_raw(0x1c662299),
+        FE::const_from_raw(0x057c955a),
+        FE::const_from_raw(0x7ab6c0f2),
+        FE::const_from_raw(0x25a6ad0a),
+        FE::const_from_raw(0x75850b58),
+        FE::const_from_raw(0x48fd3793),
+        FE::const_from_raw(0x0b4366b1),
+        FE::const_from_raw(0x0fdd0d49),
+        FE::const_from_raw(0x7db419f9),
+        FE::const_from_raw(0x49b9cc0f),
+        FE::const_from_raw(0x48949716),
+        FE::const_from_raw(0x29c35890),
+        FE::const_from_raw(0x76445485),
+        FE::const_from_raw(0x1c27d30c),
+        FE::const_from_raw(0x10aa7a3b),
+        FE::const_from_raw(0x30f34fb6),
+        FE::const_from_raw(0x6fe06435),
+        FE::const_from_raw(0x02135ecd),
+        FE::const_from_raw(0x6caaba96),
+        FE::const_from_raw(0x3eb290d0),
+        FE::const_from_raw(0x22fd8d3b),
+        FE::const_from_raw(0x768b1525),
+        FE::const_from_raw(0x5be95814),
+        FE::const_from_raw(0x523d7fe9),
+        FE::const_from_raw(0x55e94cec),
+        FE::const_from_raw(0x47c42e1f),
+        FE::const_from_raw(0x1aa53b5e),
+        FE::const_from_raw(0x2fd1fe7e),
+        FE::const_from_raw(0x59230e91),
+        FE::const_from_raw(0x7472da66),
+        FE::const_from_raw(0x6443f2df),
+        FE::const_from_raw(0x2d9de19d),
+        FE::const_from_raw(0x6f7f6a84),
+        FE::const_from_raw(0x77800430),
+        FE::const_from_raw(0x0f014bc8),
+        FE::const_from_raw(0x7bf3d095),
+        FE::const_from_raw(0x26afd318),
+        FE::const_from_raw(0x582561f7),
+        FE::const_from_raw(0x5ee3198c),
+        FE::const_from_raw(0x6acc0000),
+        FE::const_from_raw(0x2f315e26),
+        FE::const_from_raw(0x27cac040),
+        FE::const_from_raw(0x2595081e),
+        FE::const_from_raw(0x5963b7da),
+        FE::const_from_raw(0x7e073565),
+        FE::const_from_raw(0x6cf3f5f1),
+        FE::const_from_raw(0x09f8a3a4),
+        FE::const_from_raw(0x0da8ccfe),
+        FE::const_from_raw(0x60be2365),
+        FE::const_from_raw(0x7ed742f5),
+        FE::const_from_raw(0x668b8031),
+        FE::const_from_raw(0x4bb03494),
+        FE::const_from_raw(0x59019333),
+        FE::const_from_raw(0x700e2878),
+        FE::const_from_raw(0x1cc45856),
+        FE::const_from_raw(0x1d1617f7),
+        FE::const_from_raw(0x7b988da6),
+        FE::const_from_raw(0x4eb4936c),
+        FE::const_from_raw(0x78c9f87e),
+        FE::const_from_raw(0x63ce3e94),
+        FE::const_from_raw(0x7178341b),
+        FE::const_from_raw(0x45bc2f86),
+        FE::const_from_raw(0x05b775bc),
+        FE::const_from_raw(0x704b0244),
+        FE::const_from_raw(0x29eed278),
+        FE::const_from_raw(0x47f43032),
+        FE::const_from_raw(0x2127b2e5),
+        FE::const_from_raw(0x1997903f),
+        FE::const_from_raw(0x24b3ce03),
+        FE::const_from_raw(0x0c32298c),
+        FE::const_from_raw(0x7d2b6f3a),
+        FE::const_from_raw(0x17fcaa81),
+        FE::const_from_raw(0x72f37fef),
+        FE::const_from_raw(0x3028e7a9),
+        FE::const_from_raw(0x5edd4d96),
+        FE::const_from_raw(0x1f96583b),
+        FE::const_from_raw(0x4cd6918a),
+        FE::const_from_raw(0x14880f0e),
+        FE::const_from_raw(0x69170359),
+        FE::const_from_raw(0x173cbd33),
+        FE::const_from_raw(0x0969e7f4),
+        FE::const_from_raw(0x6e7f23ab),
+        FE::const_from_raw(0x6182ea87),
+        FE::const_from_raw(0x4dcb1f5c),
+        FE::const_from_raw(0x585fa113),
+        FE::const_from_raw(0x729cb3b6),
+        FE::const_from_raw(0x01b3a27a),
+        FE::const_from_raw(0x1ba173e7),
+        FE::const_from_raw(0x4b33bcea),
+        FE::const_from_raw(0x63d93bbb),
+        FE::const_from_raw(0x6b3fbf99),
+        FE::const_from_raw(0x6f17e9d1),
+        FE::const_from_raw(0x0c3dd8ba),
+        FE::const_from_raw(0x0bc1f9a8),
+        FE::const_from_raw(0x64d3f370),
+        FE::const_from_raw(0x465a6a18),
+    ];
+    const INTERNAL_ROUND_CONSTANTS: [FE<Mersenne31Field>; 22] = [
+        FE::const_from_raw(0x22776a11),
+        FE::const_from_raw(0x5fa34268),
+        FE::const_from_raw(0x1415528d),
+        FE::const_from_raw(0x563fbd14),
+        FE::const_from_raw(0x34f45244),
+        FE::const_from_raw(0x120ea1b6),
+        FE::const_from_raw(0x261368a5),
+        FE::const_from_raw(0x27665ec1),
+        FE::const_from_raw(0x36be2805),
+        FE::const_from_raw(0x345c4784),
+        FE::const_from_raw(0x17efdcc1),
+        FE::const_from_raw(0x393e6530),
+        FE::const_from_raw(0x6da0b4b8),
+        FE::const_from_raw(0x31e5ded3),
+        FE::const_from_raw(0x675b27ac),
+        FE::const_from_raw(0x0ae88c30),
+        FE::const_from_raw(0x577841cc),
+        FE::const_from_raw(0x5fe06dec),
+        FE::const_from_raw(0x56b0691a),
+        FE::const_from_raw(0x7242de1f),
+        FE::const_from_raw(0x3c377529),
+        FE::const_from_raw(0x339b7523),
+    ];
+    const INTERNAL_DIAGONAL: [FE<Mersenne31Field>; 24] = [
+        FE::const_from_raw(0x7ffffffd),
+        FE::const_from_raw(0x00000001),
+        FE::const_from_raw(0x00000002),
+        FE::const_from_raw(0x00000004),
+        FE::const_from_raw(0x00000008),
+        FE::const_from_raw(0x00000010),
+        FE::const_from_raw(0x00000020),
+        FE::const_from_raw(0x00000040),
+        FE::const_from_raw(0x00000080),
+        FE::const_from_raw(0x00000100),
+        FE::const_from_raw(0x00000200),
+        FE::const_from_raw(0x00000400),
+        FE::const_from_raw(0x00000800),
+        FE::const_from_raw(0x00001000),
+        FE::const_from_raw(0x00002000),
+        FE::const_from_raw(0x00004000),
+        FE::const_from_raw(0x00008000),
+        FE::const_from_raw(0x00010000),
+        FE::const_from_raw(0x00020000),
+        FE::const_from_raw(0x00040000),
+        FE::const_from_raw(0x00080000),
+        FE::const_from_raw(0x00100000),
+        FE::const_from_raw(0x00200000),
+        FE::const_from_raw(0x00400000),
+    ];
+}
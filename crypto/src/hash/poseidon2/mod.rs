@@ -0,0 +1,454 @@
+//! Poseidon2 permutations over BabyBear, Goldilocks and Mersenne31.
+//!
+//! Only BabyBear with width 24 and Goldilocks with width 12 are checked against the known answers
+//! of the reference implementation (https://github.com/HorizenLabs/poseidon2). The other instances
+//! are specific to lambdaworks: their known answers were computed with lambdaworks, and their
+//! hashes are not meant to match those of other libraries.
+
+use alloc::{borrow::ToOwned, vec::Vec};
+use lambdaworks_math::field::element::FieldElement as FE;
+
+pub mod babybear;
+pub mod goldilocks;
+pub mod mersenne31;
+pub mod parameters;
+
+use crate::hash::poseidon::grain::GrainLFSR;
+use lambdaworks_math::field::traits::IsPrimeField;
+use parameters::Poseidon2Parameters;
+
+mod private {
+    use super::*;
+
+    pub trait Sealed {}
+
+    impl<P: Poseidon2Parameters> Sealed for P {}
+}
+
+pub trait Poseidon2: Poseidon2Parameters + self::private::Sealed {
+    fn permutation(state: &mut [FE<Self::F>]);
+    fn external_round(state: &mut [FE<Self::F>], index: usize);
+    fn internal_round(state: &mut [FE<Self::F>], index: usize);
+    fn sbox(value: &FE<Self::F>) -> FE<Self::F>;
+    fn hash_many(inputs: &[FE<Self::F>]) -> Vec<FE<Self::F>>;
+    fn compress(left: &[FE<Self::F>], right: &[FE<Self::F>]) -> Vec<FE<Self::F>>;
+}
+
+impl<P: Poseidon2Parameters> Poseidon2 for P {
+    fn permutation(state: &mut [FE<Self::F>]) {
+        P::external_linear_layer(state);
+        let mut index = 0;
+        for _ in 0..P::N_FULL_ROUNDS / 2 {
+            Self::external_round(state, index);
+            index += P::STATE_SIZE;
+        }
+        for round in 0..P::N_PARTIAL_ROUNDS {
+            Self::internal_round(state, round);
+        }
+        for _ in 0..P::N_FULL_ROUNDS / 2 {
+            Self::external_round(state, index);
+            index += P::STATE_SIZE;
+        }
+    }
+
+    #[inline]
+    fn external_round(state: &mut [FE<Self::F>], index: usize) {
+        for (value, constant) in state.iter_mut().zip(&P::EXTERNAL_ROUND_CONSTANTS[index..]) {
+            *value = Self::sbox(&(&*value + constant));
+        }
+        P::external_linear_layer(state);
+    }
+
+    #[inline]
+    fn internal_round(state: &mut [FE<Self::F>], index: usize) {
+        state[0] = Self::sbox(&(&state[0] + &P::INTERNAL_ROUND_CONSTANTS[index]));
+        P::internal_linear_layer(state);
+    }
+
+    #[inline]
+    fn sbox(value: &FE<Self::F>) -> FE<Self::F> {
+        match P::ALPHA {
+            3 => value.square() * value,
+            5 => value.square().square() * value,
+            7 => {
+                let square = value.square();
+                &square.square() * &square * value
+            }
+            alpha => value.pow(alpha),
+        }
+    }
+
+    /// Sponge hash of an arbitrary number of elements. The inputs are padded with a 1 followed
+    /// by zeros and added to the rate, and the digest is made of the first `CAPACITY` elements
+    /// of the final state.
+    fn hash_many(inputs: &[FE<Self::F>]) -> Vec<FE<Self::F>> {
+        let r = P::RATE;
+
+        let mut values = inputs.to_owned();
+        values.push(FE::one());
+        values.resize(values.len().div_ceil(r) * r, FE::zero());
+
+        let mut state: Vec<FE<Self::F>> = vec![FE::zero(); P::STATE_SIZE];
+        for block in values.chunks(r) {
+            for (value, input) in state.iter_mut().zip(block) {
+                *value = &*value + input;
+            }
+            Self::permutation(&mut state);
+        }
+
+        state.truncate(P::CAPACITY);
+        state
+    }
+
+    /// Two-to-one compression of digests: the permutation is applied to `left || right`,
+    /// padded with zeros, and the output is truncated to the length of `left`.
+    fn compress(left: &[FE<Self::F>], right: &[FE<Self::F>]) -> Vec<FE<Self::F>> {
+        assert!(left.len() + right.len() <= P::STATE_SIZE);
+        let mut state = left.to_vec();
+        state.extend_from_slice(right);
+        state.resize(P::STATE_SIZE, FE::zero());
+        Self::permutation(&mut state);
+        state.truncate(left.len());
+        state
+    }
+}
+
+/// Round constants of a Poseidon2 instance, laid out as in `Poseidon2Parameters`.
+pub struct Poseidon2RoundConstants<F: IsPrimeField> {
+    pub external_round_constants: Vec<FE<F>>,
+    pub internal_round_constants: Vec<FE<F>>,
+}
+
+/// Generates the round constants of Poseidon2 with the Grain LFSR, as the reference implementation
+/// does (https://github.com/HorizenLabs/poseidon2). They are sampled in the order the rounds are
+/// applied: `state_size` constants for each external round and one for each internal round.
+/// The internal diagonal is not generated here: the reference samples it at random until `M_I` is
+/// invertible and the minimal polynomials of its first `2 * state_size` powers are irreducible of
+/// degree `state_size`, so the instances in this module take it from the reference instances, or
+/// from Plonky3 when the reference has none.
+pub fn generate_round_constants<F: IsPrimeField>(
+    state_size: usize,
+    n_full_rounds: usize,
+    n_partial_rounds: usize,
+) -> Poseidon2RoundConstants<F> {
+    let mut grain = GrainLFSR::new(
+        F::field_bit_size(),
+        0,
+        state_size,
+        n_full_rounds,
+        n_partial_rounds,
+    );
+    let half_full_rounds = n_full_rounds / 2 * state_size;
+    let mut external_round_constants: Vec<FE<F>> = (0..half_full_rounds)
+        .map(|_| grain.next_field_element())
+        .collect();
+    let internal_round_constants = (0..n_partial_rounds)
+        .map(|_| grain.next_field_element())
+        .collect();
+    external_round_constants.extend((0..half_full_rounds).map(|_| grain.next_field_element()));
+    Poseidon2RoundConstants {
+        external_round_constants,
+        internal_round_constants,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hash::poseidon2::{
+        babybear::{Poseidon2BabyBearWidth16, Poseidon2BabyBearWidth24},
+        goldilocks::{Poseidon2GoldilocksWidth12, Poseidon2GoldilocksWidth8},
+        mersenne31::{Poseidon2Mersenne31Width16, Poseidon2Mersenne31Width24},
+    };
+    use lambdaworks_math::field::element::FieldElement;
+
+    // The permutations of BabyBear with width 24 and Goldilocks with width 12 are the known answer
+    // tests of the reference implementation. The other expected values were computed with
+    // lambdaworks and only guard against regressions.
+
+    fn assert_round_constants_match_grain<P: Poseidon2Parameters>() {
+        let constants =
+            generate_round_constants::<P::F>(P::STATE_SIZE, P::N_FULL_ROUNDS, P::N_PARTIAL_ROUNDS);
+
+        assert_eq!(
+            constants.external_round_constants,
+            P::EXTERNAL_ROUND_CONSTANTS
+        );
+        assert_eq!(
+            constants.internal_round_constants,
+            P::INTERNAL_ROUND_CONSTANTS
+        );
+    }
+
+    fn permute_counting_state<P: Poseidon2Parameters>() -> Vec<FE<P::F>> {
+        let mut state: Vec<FE<P::F>> = (0..P::STATE_SIZE as u64).map(FE::from).collect();
+        P::permutation(&mut state);
+        state
+    }
+
+    #[test]
+    fn babybear_width16_round_constants_match_grain() {
+        assert_round_constants_match_grain::<Poseidon2BabyBearWidth16>();
+    }
+
+    #[test]
+    fn babybear_width16_permutation() {
+        let expected = vec![
+            FieldElement::from_hex_unchecked("35706d52"),
+            FieldElement::from_hex_unchecked("2dfede1f"),
+            FieldElement::from_hex_unchecked("7a2d988"),
+            FieldElement::from_hex_unchecked("523182b3"),
+            FieldElement::from_hex_unchecked("989b500"),
+            FieldElement::from_hex_unchecked("569707d2"),
+            FieldElement::from_hex_unchecked("28b377e1"),
+            FieldElement::from_hex_unchecked("d525885"),
+            FieldElement::from_hex_unchecked("1de385cb"),
+            FieldElement::from_hex_unchecked("6b9151dd"),
+            FieldElement::from_hex_unchecked("639f88a9"),
+            FieldElement::from_hex_unchecked("6a9e3d13"),
+            FieldElement::from_hex_unchecked("6786af9b"),
+            FieldElement::from_hex_unchecked("5098a430"),
+            FieldElement::from_hex_unchecked("72f26ae2"),
+            FieldElement::from_hex_unchecked("43482aa1"),
+        ];
+        assert_eq!(
+            permute_counting_state::<Poseidon2BabyBearWidth16>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn babybear_width24_round_constants_match_grain() {
+        assert_round_constants_match_grain::<Poseidon2BabyBearWidth24>();
+    }
+
+    #[test]
+    fn babybear_width24_permutation() {
+        let expected = vec![
+            FieldElement::from_hex_unchecked("2ed3e23d"),
+            FieldElement::from_hex_unchecked("12921fb0"),
+            FieldElement::from_hex_unchecked("e659e79"),
+            FieldElement::from_hex_unchecked("61d81dc9"),
+            FieldElement::from_hex_unchecked("32bae33b"),
+            FieldElement::from_hex_unchecked("62486ae3"),
+            FieldElement::from_hex_unchecked("1e681b60"),
+            FieldElement::from_hex_unchecked("24b91325"),
+            FieldElement::from_hex_unchecked("2a2ef5b9"),
+            FieldElement::from_hex_unchecked("50e8593e"),
+            FieldElement::from_hex_unchecked("5bc818ec"),
+            FieldElement::from_hex_unchecked("10691997"),
+            FieldElement::from_hex_unchecked("35a14520"),
+            FieldElement::from_hex_unchecked("2ba6a3c5"),
+            FieldElement::from_hex_unchecked("279d47ec"),
+            FieldElement::from_hex_unchecked("55014e81"),
+            FieldElement::from_hex_unchecked("5953a67f"),
+            FieldElement::from_hex_unchecked("2f403111"),
+            FieldElement::from_hex_unchecked("6b8828ff"),
+            FieldElement::from_hex_unchecked("1801301f"),
+            FieldElement::from_hex_unchecked("2749207a"),
+            FieldElement::from_hex_unchecked("3dc9cf21"),
+            FieldElement::from_hex_unchecked("3c985ba2"),
+            FieldElement::from_hex_unchecked("57a99864"),
+        ];
+        assert_eq!(
+            permute_counting_state::<Poseidon2BabyBearWidth24>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn mersenne31_width16_round_constants_match_grain() {
+        assert_round_constants_match_grain::<Poseidon2Mersenne31Width16>();
+    }
+
+    #[test]
+    fn mersenne31_width16_permutation() {
+        let expected = vec![
+            FieldElement::const_from_raw(0x6a2c2993),
+            FieldElement::const_from_raw(0x7f72a2b0),
+            FieldElement::const_from_raw(0x623a9b42),
+            FieldElement::const_from_raw(0x5684e396),
+            FieldElement::const_from_raw(0x73ba19ae),
+            FieldElement::const_from_raw(0x2150c130),
+            FieldElement::const_from_raw(0x795c4bba),
+            FieldElement::const_from_raw(0x351f2365),
+            FieldElement::const_from_raw(0x2f08432a),
+            FieldElement::const_from_raw(0x551239f7),
+            FieldElement::const_from_raw(0x122cfb42),
+            FieldElement::const_from_raw(0x2412df91),
+            FieldElement::const_from_raw(0x4b521208),
+            FieldElement::const_from_raw(0x51b72550),
+            FieldElement::const_from_raw(0x0ab3e2be),
+            FieldElement::const_from_raw(0x2815136b),
+        ];
+        assert_eq!(
+            permute_counting_state::<Poseidon2Mersenne31Width16>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn mersenne31_width24_round_constants_match_grain() {
+        assert_round_constants_match_grain::<Poseidon2Mersenne31Width24>();
+    }
+
+    #[test]
+    fn mersenne31_width24_permutation() {
+        let expected = vec![
+            FieldElement::const_from_raw(0x451bf0cf),
+            FieldElement::const_from_raw(0x516d670e),
+            FieldElement::const_from_raw(0x11fded9d),
+            FieldElement::const_from_raw(0x05d4d661),
+            FieldElement::const_from_raw(0x0ce3ada2),
+            FieldElement::const_from_raw(0x5a2d814a),
+            FieldElement::const_from_raw(0x50bcaca2),
+            FieldElement::const_from_raw(0x3a503d2e),
+            FieldElement::const_from_raw(0x1b1434fd),
+            FieldElement::const_from_raw(0x4b1460bf),
+            FieldElement::const_from_raw(0x539ef504),
+            FieldElement::const_from_raw(0x18b34147),
+            FieldElement::const_from_raw(0x62b6cddd),
+            FieldElement::const_from_raw(0x503acb10),
+            FieldElement::const_from_raw(0x59654b68),
+            FieldElement::const_from_raw(0x7e6de5af),
+            FieldElement::const_from_raw(0x53a55663),
+            FieldElement::const_from_raw(0x376ed640),
+            FieldElement::const_from_raw(0x26033c43),
+            FieldElement::const_from_raw(0x10a6a65d),
+            FieldElement::const_from_raw(0x59f17898),
+            FieldElement::const_from_raw(0x4dbdfb76),
+            FieldElement::const_from_raw(0x6084ba9c),
+            FieldElement::const_from_raw(0x11a17a49),
+        ];
+        assert_eq!(
+            permute_counting_state::<Poseidon2Mersenne31Width24>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn goldilocks_width8_round_constants_match_grain() {
+        assert_round_constants_match_grain::<Poseidon2GoldilocksWidth8>();
+    }
+
+    #[test]
+    fn goldilocks_width8_permutation() {
+        let expected = vec![
+            FieldElement::const_from_raw(0xc5fb1cfe0b4697bb),
+            FieldElement::const_from_raw(0x4a4a32ff849af473),
+            FieldElement::const_from_raw(0xd2fd266077f8efba),
+            FieldElement::const_from_raw(0xf4ad9b74e833916d),
+            FieldElement::const_from_raw(0xe6648eb0acc11463),
+            FieldElement::const_from_raw(0x8d5529a930d75194),
+            FieldElement::const_from_raw(0xe8c993aa10da6c90),
+            FieldElement::const_from_raw(0xa73104a95b68031c),
+        ];
+        assert_eq!(
+            permute_counting_state::<Poseidon2GoldilocksWidth8>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn goldilocks_width12_round_constants_match_grain() {
+        assert_round_constants_match_grain::<Poseidon2GoldilocksWidth12>();
+    }
+
+    #[test]
+    fn goldilocks_width12_permutation() {
+        let expected = vec![
+            FieldElement::const_from_raw(0x01eaef96bdf1c0c1),
+            FieldElement::const_from_raw(0x1f0d2cc525b2540c),
+            FieldElement::const_from_raw(0x6282c1dfe1e0358d),
+            FieldElement::const_from_raw(0xe780d721f698e1e6),
+            FieldElement::const_from_raw(0x280c0b6f753d833b),
+            FieldElement::const_from_raw(0x1b942dd5023156ab),
+            FieldElement::const_from_raw(0x43f0df3fcccb8398),
+            FieldElement::const_from_raw(0xe8e8190585489025),
+            FieldElement::const_from_raw(0x56bdbf72f77ada22),
+            FieldElement::const_from_raw(0x7911c32bf9dcd705),
+            FieldElement::const_from_raw(0xec467926508fbe67),
+            FieldElement::const_from_raw(0x6a50450ddf85a6ed),
+        ];
+        assert_eq!(
+            permute_counting_state::<Poseidon2GoldilocksWidth12>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn babybear_width16_hash_many() {
+        let inputs = [
+            FieldElement::from(1),
+            FieldElement::from(2),
+            FieldElement::from(3),
+        ];
+        let expected = vec![
+            FieldElement::from_hex_unchecked("6d3edb92"),
+            FieldElement::from_hex_unchecked("c2bc694"),
+            FieldElement::from_hex_unchecked("a32a88a"),
+            FieldElement::from_hex_unchecked("5aa69a35"),
+            FieldElement::from_hex_unchecked("5a988e6"),
+            FieldElement::from_hex_unchecked("2b59f2f5"),
+            FieldElement::from_hex_unchecked("689100c5"),
+            FieldElement::from_hex_unchecked("4cd6065"),
+        ];
+        assert_eq!(Poseidon2BabyBearWidth16::hash_many(&inputs), expected);
+    }
+
+    #[test]
+    fn babybear_width16_compress() {
+        let left: Vec<_> = (0..8).map(FieldElement::from).collect();
+        let right: Vec<_> = (8..16).map(FieldElement::from).collect();
+        let expected = vec![
+            FieldElement::from_hex_unchecked("35706d52"),
+            FieldElement::from_hex_unchecked("2dfede1f"),
+            FieldElement::from_hex_unchecked("7a2d988"),
+            FieldElement::from_hex_unchecked("523182b3"),
+            FieldElement::from_hex_unchecked("989b500"),
+            FieldElement::from_hex_unchecked("569707d2"),
+            FieldElement::from_hex_unchecked("28b377e1"),
+            FieldElement::from_hex_unchecked("d525885"),
+        ];
+        assert_eq!(Poseidon2BabyBearWidth16::compress(&left, &right), expected);
+    }
+
+    #[test]
+    fn goldilocks_width8_hash_many() {
+        let inputs = [
+            FieldElement::from(1),
+            FieldElement::from(2),
+            FieldElement::from(3),
+        ];
+        let expected = vec![
+            FieldElement::const_from_raw(0x429736e232619723),
+            FieldElement::const_from_raw(0x1909bc54cdbf4584),
+            FieldElement::const_from_raw(0x44687b1de3e996d0),
+            FieldElement::const_from_raw(0x3be3ce2a821140bb),
+        ];
+        assert_eq!(Poseidon2GoldilocksWidth8::hash_many(&inputs), expected);
+    }
+
+    #[test]
+    fn goldilocks_width8_compress() {
+        let left: Vec<_> = (0..4).map(FieldElement::from).collect();
+        let right: Vec<_> = (4..8).map(FieldElement::from).collect();
+        let expected = vec![
+            FieldElement::const_from_raw(0xc5fb1cfe0b4697bb),
+            FieldElement::const_from_raw(0x4a4a32ff849af473),
+            FieldElement::const_from_raw(0xd2fd266077f8efba),
+            FieldElement::const_from_raw(0xf4ad9b74e833916d),
+        ];
+        assert_eq!(Poseidon2GoldilocksWidth8::compress(&left, &right), expected);
+    }
+
+    #[test]
+    fn hash_many_of_a_full_block_differs_from_its_prefix() {
+        let inputs: Vec<_> = (0..8u64).map(FieldElement::from).collect();
+
+        assert_ne!(
+            Poseidon2BabyBearWidth16::hash_many(&inputs),
+            Poseidon2BabyBearWidth16::hash_many(&inputs[..7])
+        );
+    }
+}
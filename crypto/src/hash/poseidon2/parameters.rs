@@ -0,0 +1,84 @@
+use alloc::vec::Vec;
+use lambdaworks_math::field::{element::FieldElement as FE, traits::IsPrimeField};
+
+/// Parameters for Poseidon2 (https://eprint.iacr.org/2023/323.pdf).
+/// The external round constants are stored as a slice representing a matrix of
+/// `N_FULL_ROUNDS * STATE_SIZE`, with the constants of the first `N_FULL_ROUNDS / 2` rounds
+/// followed by those of the last ones. Internal rounds only add a constant to the first element
+/// of the state, so `INTERNAL_ROUND_CONSTANTS` has `N_PARTIAL_ROUNDS` elements.
+pub trait Poseidon2Parameters {
+    type F: IsPrimeField + 'static;
+
+    const RATE: usize;
+    const CAPACITY: usize;
+    const ALPHA: u32;
+    const N_FULL_ROUNDS: usize;
+    const N_PARTIAL_ROUNDS: usize;
+    const STATE_SIZE: usize = Self::RATE + Self::CAPACITY;
+
+    const EXTERNAL_ROUND_CONSTANTS: &'static [FE<Self::F>];
+    const INTERNAL_ROUND_CONSTANTS: &'static [FE<Self::F>];
+
+    /// The diagonal of `M_I - 1`, where `M_I` is the matrix of the internal linear layer and
+    /// `1` is the matrix whose entries are all one.
+    const INTERNAL_DIAGONAL: &'static [FE<Self::F>];
+
+    /// The external linear layer. For widths that are multiples of 4 it is the matrix
+    /// `circ(2 M_4, M_4, ..., M_4)` of the paper, with `M_4` from Section 5.1, and for widths 2 and 3
+    /// it is `circ(2, 1)` and `circ(2, 1, 1)` respectively.
+    fn external_linear_layer(state: &mut [FE<Self::F>]) {
+        match Self::STATE_SIZE {
+            2 | 3 => {
+                let sum = state.iter().fold(FE::zero(), |acc, value| acc + value);
+                for value in state.iter_mut() {
+                    *value = &*value + &sum;
+                }
+            }
+            _ => {
+                debug_assert!(Self::STATE_SIZE % 4 == 0);
+                for chunk in state.chunks_exact_mut(4) {
+                    apply_m4(chunk);
+                }
+                let sums: Vec<FE<Self::F>> = (0..4)
+                    .map(|i| {
+                        state
+                            .iter()
+                            .skip(i)
+                            .step_by(4)
+                            .fold(FE::zero(), |acc, value| acc + value)
+                    })
+                    .collect();
+                for (i, value) in state.iter_mut().enumerate() {
+                    *value = &*value + &sums[i % 4];
+                }
+            }
+        }
+    }
+
+    /// The internal linear layer, `M_I = 1 + diag(INTERNAL_DIAGONAL)`, which maps each element
+    /// `x_i` to `sum(x) + d_i * x_i`.
+    fn internal_linear_layer(state: &mut [FE<Self::F>]) {
+        let sum = state.iter().fold(FE::zero(), |acc, value| acc + value);
+        for (value, diagonal) in state.iter_mut().zip(Self::INTERNAL_DIAGONAL) {
+            *value = &sum + &(&*value * diagonal);
+        }
+    }
+}
+
+/// Multiplies a chunk of four elements by the matrix
+/// M_4 = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]
+/// with the addition chain of Appendix B of the paper.
+fn apply_m4<F: IsPrimeField>(x: &mut [FE<F>]) {
+    let t0 = &x[0] + &x[1];
+    let t1 = &x[2] + &x[3];
+    let t2 = x[1].double() + &t1;
+    let t3 = x[3].double() + &t0;
+    let t4 = t1.double().double() + &t3;
+    let t5 = t0.double().double() + &t2;
+    let t6 = &t3 + &t5;
+    let t7 = &t2 + &t4;
+    x[0] = t6;
+    x[1] = t5;
+    x[2] = t7;
+    x[3] = t4;
+}
@@ -0,0 +1,1002 @@
+use super::parameters::{RescuePrimeOptimizedField, RescuePrimeOptimizedParameters, SecurityLevel};
+use lambdaworks_math::field::{
+    element::FieldElement, fields::fft_friendly::babybear::Babybear31PrimeField,
+};
+
+// Parameters of Rescue Prime Optimized over BabyBear, with state size 24, capacity 8 for 128-bit security and a rate of 16, so that digests have 8 elements.
+// The S-box is x^7, and the permutation has 7 rounds, like over Goldilocks.
+// The round constants are generated with SHAKE256 as in the reference implementation
+// https://github.com/ASDiscreteMathematics/rpo/tree/master/reference_implementation
+// and the MDS matrix is the one of the Rescue-Prime reference implementation, built from a
+// systematic generator matrix of a Reed-Solomon code, so it is MDS but not circulant.
+
+const ALPHA_INV_BABYBEAR: u64 = 1725656503;
+
+const ROUND_CONSTANTS_BABYBEAR: [FieldElement<Babybear31PrimeField>; 336] = [
+    FieldElement::from_hex_unchecked("149b8f81"),
+    FieldElement::from_hex_unchecked("12decdb3"),
+    FieldElement::from_hex_unchecked("94e3fcc"),
+    FieldElement::from_hex_unchecked("60ba5a8e"),
+    FieldElement::from_hex_unchecked("7439375"),
+    FieldElement::from_hex_unchecked("64976fbd"),
+    FieldElement::from_hex_unchecked("69ac435e"),
+    FieldElement::from_hex_unchecked("5c94a3f"),
+    FieldElement::from_hex_unchecked("ad6ba26"),
+    FieldElement::from_hex_unchecked("3e76e7bd"),
+    FieldElement::from_hex_unchecked("322e30e7"),
+    FieldElement::from_hex_unchecked("1c6c6f1"),
+    FieldElement::from_hex_unchecked("17273a54"),
+    FieldElement::from_hex_unchecked("17666047"),
+    FieldElement::from_hex_unchecked("35af8b0e"),
+    FieldElement::from_hex_unchecked("5cf2d4ef"),
+    FieldElement::from_hex_unchecked("6fccd697"),
+    FieldElement::from_hex_unchecked("36bfa9a6"),
+    FieldElement::from_hex_unchecked("71bf07c1"),
+    FieldElement::from_hex_unchecked("15b31a7"),
+    FieldElement::from_hex_unchecked("6414d983"),
+    FieldElement::from_hex_unchecked("22dfb087"),
+    FieldElement::from_hex_unchecked("698ab166"),
+    FieldElement::from_hex_unchecked("66ac44c"),
+    FieldElement::from_hex_unchecked("62a9e5da"),
+    FieldElement::from_hex_unchecked("4fdb08d3"),
+    FieldElement::from_hex_unchecked("3bc62e8"),
+    FieldElement::from_hex_unchecked("1592a44c"),
+    FieldElement::from_hex_unchecked("4932a731"),
+    FieldElement::from_hex_unchecked("5cca4147"),
+    FieldElement::from_hex_unchecked("64ac015"),
+    FieldElement::from_hex_unchecked("1ca8711a"),
+    FieldElement::from_hex_unchecked("2045a9ec"),
+    FieldElement::from_hex_unchecked("278ec7f8"),
+    FieldElement::from_hex_unchecked("42ffc8a1"),
+    FieldElement::from_hex_unchecked("72566436"),
+    FieldElement::from_hex_unchecked("750f1b8e"),
+    FieldElement::from_hex_unchecked("52601517"),
+    FieldElement::from_hex_unchecked("5fe55600"),
+    FieldElement::from_hex_unchecked("4bd8bd70"),
+    FieldElement::from_hex_unchecked("4d10f4d1"),
+    FieldElement::from_hex_unchecked("41c2d00c"),
+    FieldElement::from_hex_unchecked("475378ae"),
+    FieldElement::from_hex_unchecked("5240fbf1"),
+    FieldElement::from_hex_unchecked("27572497"),
+    FieldElement::from_hex_unchecked("5ee1e275"),
+    FieldElement::from_hex_unchecked("750c69d3"),
+    FieldElement::from_hex_unchecked("532ed3ef"),
+    FieldElement::from_hex_unchecked("6d3a6d53"),
+    FieldElement::from_hex_unchecked("558fe6fc"),
+    FieldElement::from_hex_unchecked("6df641a7"),
+    FieldElement::from_hex_unchecked("5e8cbace"),
+    FieldElement::from_hex_unchecked("3786ee32"),
+    FieldElement::from_hex_unchecked("551fbeae"),
+    FieldElement::from_hex_unchecked("196eb489"),
+    FieldElement::from_hex_unchecked("1b71e04b"),
+    FieldElement::from_hex_unchecked("1ba5f192"),
+    FieldElement::from_hex_unchecked("1e4157f5"),
+    FieldElement::from_hex_unchecked("776d0d9b"),
+    FieldElement::from_hex_unchecked("12f71a7e"),
+    FieldElement::from_hex_unchecked("42d14ce1"),
+    FieldElement::from_hex_unchecked("249089dc"),
+    FieldElement::from_hex_unchecked("378b391c"),
+    FieldElement::from_hex_unchecked("17d38c6c"),
+    FieldElement::from_hex_unchecked("44ee425e"),
+    FieldElement::from_hex_unchecked("6ccf3053"),
+    FieldElement::from_hex_unchecked("2f51de96"),
+    FieldElement::from_hex_unchecked("632a9ebd"),
+    FieldElement::from_hex_unchecked("6d0d1606"),
+    FieldElement::from_hex_unchecked("13882af3"),
+    FieldElement::from_hex_unchecked("10fef6b8"),
+    FieldElement::from_hex_unchecked("10d6511"),
+    FieldElement::from_hex_unchecked("207ffa6"),
+    FieldElement::from_hex_unchecked("53861613"),
+    FieldElement::from_hex_unchecked("40ac1a6f"),
+    FieldElement::from_hex_unchecked("5d8187e5"),
+    FieldElement::from_hex_unchecked("2aa0462"),
+    FieldElement::from_hex_unchecked("33fa9817"),
+    FieldElement::from_hex_unchecked("691dcc6b"),
+    FieldElement::from_hex_unchecked("62e44d2"),
+    FieldElement::from_hex_unchecked("45a15490"),
+    FieldElement::from_hex_unchecked("2b402e42"),
+    FieldElement::from_hex_unchecked("bf86b15"),
+    FieldElement::from_hex_unchecked("260ff2be"),
+    FieldElement::from_hex_unchecked("7284dfd9"),
+    FieldElement::from_hex_unchecked("744c94d2"),
+    FieldElement::from_hex_unchecked("50a753c1"),
+    FieldElement::from_hex_unchecked("3f3443bc"),
+    FieldElement::from_hex_unchecked("4b8bbbf6"),
+    FieldElement::from_hex_unchecked("2247b79d"),
+    FieldElement::from_hex_unchecked("1a5e57ed"),
+    FieldElement::from_hex_unchecked("25721a6d"),
+    FieldElement::from_hex_unchecked("27d1b544"),
+    FieldElement::from_hex_unchecked("51a110b6"),
+    FieldElement::from_hex_unchecked("52f5c8b6"),
+    FieldElement::from_hex_unchecked("44ef1780"),
+    FieldElement::from_hex_unchecked("e247a54"),
+    FieldElement::from_hex_unchecked("588f4f41"),
+    FieldElement::from_hex_unchecked("29a0cca4"),
+    FieldElement::from_hex_unchecked("63289b2b"),
+    FieldElement::from_hex_unchecked("6cfe17fb"),
+    FieldElement::from_hex_unchecked("3136c667"),
+    FieldElement::from_hex_unchecked("6aa95ed5"),
+    FieldElement::from_hex_unchecked("5485c232"),
+    FieldElement::from_hex_unchecked("4bff3b28"),
+    FieldElement::from_hex_unchecked("2ad97080"),
+    FieldElement::from_hex_unchecked("88ea61b"),
+    FieldElement::from_hex_unchecked("491f57cb"),
+    FieldElement::from_hex_unchecked("3320d7cd"),
+    FieldElement::from_hex_unchecked("207b683f"),
+    FieldElement::from_hex_unchecked("2ab63849"),
+    FieldElement::from_hex_unchecked("76bc81d2"),
+    FieldElement::from_hex_unchecked("6aad05a6"),
+    FieldElement::from_hex_unchecked("6a1fc17"),
+    FieldElement::from_hex_unchecked("61a45d23"),
+    FieldElement::from_hex_unchecked("48704978"),
+    FieldElement::from_hex_unchecked("27c519a0"),
+    FieldElement::from_hex_unchecked("68e8758f"),
+    FieldElement::from_hex_unchecked("5614044f"),
+    FieldElement::from_hex_unchecked("4a2ec57d"),
+    FieldElement::from_hex_unchecked("416f4b44"),
+    FieldElement::from_hex_unchecked("77be5171"),
+    FieldElement::from_hex_unchecked("24e265a5"),
+    FieldElement::from_hex_unchecked("2ab781de"),
+    FieldElement::from_hex_unchecked("5492b4d9"),
+    FieldElement::from_hex_unchecked("b8b8175"),
+    FieldElement::from_hex_unchecked("22bbec92"),
+    FieldElement::from_hex_unchecked("6a1bd177"),
+    FieldElement::from_hex_unchecked("740cd962"),
+    FieldElement::from_hex_unchecked("1aa5551b"),
+    FieldElement::from_hex_unchecked("3fbea270"),
+    FieldElement::from_hex_unchecked("5a93daa8"),
+    FieldElement::from_hex_unchecked("645277ea"),
+    FieldElement::from_hex_unchecked("3cb9aa19"),
+    FieldElement::from_hex_unchecked("70f3a3f3"),
+    FieldElement::from_hex_unchecked("1e7d2514"),
+    FieldElement::from_hex_unchecked("e24787f"),
+    FieldElement::from_hex_unchecked("30c781c6"),
+    FieldElement::from_hex_unchecked("22740433"),
+    FieldElement::from_hex_unchecked("288cca7d"),
+    FieldElement::from_hex_unchecked("67520cf3"),
+    FieldElement::from_hex_unchecked("1e13e5a7"),
+    FieldElement::from_hex_unchecked("2de25da1"),
+    FieldElement::from_hex_unchecked("63ef6d1e"),
+    FieldElement::from_hex_unchecked("617aea81"),
+    FieldElement::from_hex_unchecked("29da42e"),
+    FieldElement::from_hex_unchecked("473ca66b"),
+    FieldElement::from_hex_unchecked("20267936"),
+    FieldElement::from_hex_unchecked("5a3a8a23"),
+    FieldElement::from_hex_unchecked("25cd14e7"),
+    FieldElement::from_hex_unchecked("18f353d8"),
+    FieldElement::from_hex_unchecked("67ac485e"),
+    FieldElement::from_hex_unchecked("68cb9838"),
+    FieldElement::from_hex_unchecked("23b9f04d"),
+    FieldElement::from_hex_unchecked("45fc3b7f"),
+    FieldElement::from_hex_unchecked("5604eece"),
+    FieldElement::from_hex_unchecked("2f709434"),
+    FieldElement::from_hex_unchecked("24ec69e9"),
+    FieldElement::from_hex_unchecked("557756eb"),
+    FieldElement::from_hex_unchecked("5077a818"),
+    FieldElement::from_hex_unchecked("2a109fb3"),
+    FieldElement::from_hex_unchecked("69529966"),
+    FieldElement::from_hex_unchecked("438f6598"),
+    FieldElement::from_hex_unchecked("770499d6"),
+    FieldElement::from_hex_unchecked("522f1c59"),
+    FieldElement::from_hex_unchecked("4c63b425"),
+    FieldElement::from_hex_unchecked("5a9b02d5"),
+    FieldElement::from_hex_unchecked("4cc92b7e"),
+    FieldElement::from_hex_unchecked("55da6a6a"),
+    FieldElement::from_hex_unchecked("9e74584"),
+    FieldElement::from_hex_unchecked("6302b3ef"),
+    FieldElement::from_hex_unchecked("5ec6ca90"),
+    FieldElement::from_hex_unchecked("2b8fa1f8"),
+    FieldElement::from_hex_unchecked("36330f5c"),
+    FieldElement::from_hex_unchecked("62fe3c14"),
+    FieldElement::from_hex_unchecked("4266fe1a"),
+    FieldElement::from_hex_unchecked("1750cd5c"),
+    FieldElement::from_hex_unchecked("2348f62"),
+    FieldElement::from_hex_unchecked("7785592a"),
+    FieldElement::from_hex_unchecked("16f38838"),
+    FieldElement::from_hex_unchecked("390f47cc"),
+    FieldElement::from_hex_unchecked("32705d7c"),
+    FieldElement::from_hex_unchecked("482cb7b4"),
+    FieldElement::from_hex_unchecked("6f578dda"),
+    FieldElement::from_hex_unchecked("73cd382c"),
+    FieldElement::from_hex_unchecked("175ec8a7"),
+    FieldElement::from_hex_unchecked("37f396d5"),
+    FieldElement::from_hex_unchecked("62ea9700"),
+    FieldElement::from_hex_unchecked("57e83948"),
+    FieldElement::from_hex_unchecked("7259e1d4"),
+    FieldElement::from_hex_unchecked("5cd1c4ff"),
+    FieldElement::from_hex_unchecked("591e2d3d"),
+    FieldElement::from_hex_unchecked("cb70b5d"),
+    FieldElement::from_hex_unchecked("4a5f7948"),
+    FieldElement::from_hex_unchecked("7020f2c3"),
+    FieldElement::from_hex_unchecked("1fe0471f"),
+    FieldElement::from_hex_unchecked("4b24923d"),
+    FieldElement::from_hex_unchecked("2545256b"),
+    FieldElement::from_hex_unchecked("4a69877f"),
+    FieldElement::from_hex_unchecked("15e54e50"),
+    FieldElement::from_hex_unchecked("2f9ea39d"),
+    FieldElement::from_hex_unchecked("4eb6ad96"),
+    FieldElement::from_hex_unchecked("201e9095"),
+    FieldElement::from_hex_unchecked("74a1f76b"),
+    FieldElement::from_hex_unchecked("3d85e8d1"),
+    FieldElement::from_hex_unchecked("471a1070"),
+    FieldElement::from_hex_unchecked("7669ab55"),
+    FieldElement::from_hex_unchecked("6b46da2d"),
+    FieldElement::from_hex_unchecked("25bf9045"),
+    FieldElement::from_hex_unchecked("49bbc56a"),
+    FieldElement::from_hex_unchecked("2b101707"),
+    FieldElement::from_hex_unchecked("35e174d9"),
+    FieldElement::from_hex_unchecked("5b2fe954"),
+    FieldElement::from_hex_unchecked("13675eb2"),
+    FieldElement::from_hex_unchecked("63841acd"),
+    FieldElement::from_hex_unchecked("71a28ad7"),
+    FieldElement::from_hex_unchecked("4cecae96"),
+    FieldElement::from_hex_unchecked("21ee2aea"),
+    FieldElement::from_hex_unchecked("26270497"),
+    FieldElement::from_hex_unchecked("37b332f9"),
+    FieldElement::from_hex_unchecked("213f7021"),
+    FieldElement::from_hex_unchecked("55447356"),
+    FieldElement::from_hex_unchecked("77fcfd24"),
+    FieldElement::from_hex_unchecked("3332232d"),
+    FieldElement::from_hex_unchecked("9f0fa29"),
+    FieldElement::from_hex_unchecked("53001eca"),
+    FieldElement::from_hex_unchecked("453f4aef"),
+    FieldElement::from_hex_unchecked("17100977"),
+    FieldElement::from_hex_unchecked("437b91c7"),
+    FieldElement::from_hex_unchecked("1cdc583"),
+    FieldElement::from_hex_unchecked("4ba608d"),
+    FieldElement::from_hex_unchecked("6bb4b9e1"),
+    FieldElement::from_hex_unchecked("25ec13f3"),
+    FieldElement::from_hex_unchecked("211a87af"),
+    FieldElement::from_hex_unchecked("2820eb47"),
+    FieldElement::from_hex_unchecked("199ef94b"),
+    FieldElement::from_hex_unchecked("284c85da"),
+    FieldElement::from_hex_unchecked("4034dcf3"),
+    FieldElement::from_hex_unchecked("3c14fdd1"),
+    FieldElement::from_hex_unchecked("74c757d1"),
+    FieldElement::from_hex_unchecked("3d9abd03"),
+    FieldElement::from_hex_unchecked("31a757c3"),
+    FieldElement::from_hex_unchecked("52962f42"),
+    FieldElement::from_hex_unchecked("5334b7fc"),
+    FieldElement::from_hex_unchecked("3dee4660"),
+    FieldElement::from_hex_unchecked("55aaaed1"),
+    FieldElement::from_hex_unchecked("716ab319"),
+    FieldElement::from_hex_unchecked("35d044a5"),
+    FieldElement::from_hex_unchecked("100dce83"),
+    FieldElement::from_hex_unchecked("3042d1a0"),
+    FieldElement::from_hex_unchecked("63dce146"),
+    FieldElement::from_hex_unchecked("6cf8db62"),
+    FieldElement::from_hex_unchecked("12e41761"),
+    FieldElement::from_hex_unchecked("63b98287"),
+    FieldElement::from_hex_unchecked("7282e151"),
+    FieldElement::from_hex_unchecked("64e500b4"),
+    FieldElement::from_hex_unchecked("57a4c4cf"),
+    FieldElement::from_hex_unchecked("59df73e"),
+    FieldElement::from_hex_unchecked("5147c69"),
+    FieldElement::from_hex_unchecked("1076e54e"),
+    FieldElement::from_hex_unchecked("13c486d2"),
+    FieldElement::from_hex_unchecked("57b94ccb"),
+    FieldElement::from_hex_unchecked("1ee1a6cd"),
+    FieldElement::from_hex_unchecked("68c9bcea"),
+    FieldElement::from_hex_unchecked("1834cf31"),
+    FieldElement::from_hex_unchecked("506beb1b"),
+    FieldElement::from_hex_unchecked("655a98d5"),
+    FieldElement::from_hex_unchecked("26e86afe"),
+    FieldElement::from_hex_unchecked("22b06a44"),
+    FieldElement::from_hex_unchecked("36f5a3c4"),
+    FieldElement::from_hex_unchecked("2cc3a75b"),
+    FieldElement::from_hex_unchecked("14dbad40"),
+    FieldElement::from_hex_unchecked("167a4646"),
+    FieldElement::from_hex_unchecked("5c05e76a"),
+    FieldElement::from_hex_unchecked("424dcd1a"),
+    FieldElement::from_hex_unchecked("662e42cc"),
+    FieldElement::from_hex_unchecked("465b31ba"),
+    FieldElement::from_hex_unchecked("3d1a8dfd"),
+    FieldElement::from_hex_unchecked("543660a7"),
+    FieldElement::from_hex_unchecked("2fb7cd8"),
+    FieldElement::from_hex_unchecked("7157c925"),
+    FieldElement::from_hex_unchecked("3a0c5127"),
+    FieldElement::from_hex_unchecked("68463bfa"),
+    FieldElement::from_hex_unchecked("3f55a315"),
+    FieldElement::from_hex_unchecked("2f644b09"),
+    FieldElement::from_hex_unchecked("2dc9b8d5"),
+    FieldElement::from_hex_unchecked("334546a5"),
+    FieldElement::from_hex_unchecked("1764207b"),
+    FieldElement::from_hex_unchecked("33d70473"),
+    FieldElement::from_hex_unchecked("586b503f"),
+    FieldElement::from_hex_unchecked("206151c6"),
+    FieldElement::from_hex_unchecked("31bf41c4"),
+    FieldElement::from_hex_unchecked("3dde22d1"),
+    FieldElement::from_hex_unchecked("835df68"),
+    FieldElement::from_hex_unchecked("28e87ba6"),
+    FieldElement::from_hex_unchecked("18291c0e"),
+    FieldElement::from_hex_unchecked("4b04bebc"),
+    FieldElement::from_hex_unchecked("11e96c68"),
+    FieldElement::from_hex_unchecked("307ace51"),
+    FieldElement::from_hex_unchecked("2bf57124"),
+    FieldElement::from_hex_unchecked("70cc2f8f"),
+    FieldElement::from_hex_unchecked("f2faf63"),
+    FieldElement::from_hex_unchecked("3d6005f9"),
+    FieldElement::from_hex_unchecked("4a9acbbe"),
+    FieldElement::from_hex_unchecked("1856f5bb"),
+    FieldElement::from_hex_unchecked("4b47077b"),
+    FieldElement::from_hex_unchecked("4e37e9fb"),
+    FieldElement::from_hex_unchecked("f56c14f"),
+    FieldElement::from_hex_unchecked("2efac8cc"),
+    FieldElement::from_hex_unchecked("2936532e"),
+    FieldElement::from_hex_unchecked("691d1a9b"),
+    FieldElement::from_hex_unchecked("976abbb"),
+    FieldElement::from_hex_unchecked("4b554cd3"),
+    FieldElement::from_hex_unchecked("3f4d6adf"),
+    FieldElement::from_hex_unchecked("63d34dcb"),
+    FieldElement::from_hex_unchecked("75b1ff47"),
+    FieldElement::from_hex_unchecked("45f35d3a"),
+    FieldElement::from_hex_unchecked("75c6b4d9"),
+    FieldElement::from_hex_unchecked("4b739e3a"),
+    FieldElement::from_hex_unchecked("288d935a"),
+    FieldElement::from_hex_unchecked("1ff8fdaa"),
+    FieldElement::from_hex_unchecked("34d68145"),
+    FieldElement::from_hex_unchecked("45306735"),
+    FieldElement::from_hex_unchecked("48c8000d"),
+    FieldElement::from_hex_unchecked("4eb53da2"),
+    FieldElement::from_hex_unchecked("6d35f1f7"),
+    FieldElement::from_hex_unchecked("6d51c6a1"),
+    FieldElement::from_hex_unchecked("5274dc22"),
+    FieldElement::from_hex_unchecked("4a55a81e"),
+    FieldElement::from_hex_unchecked("24628be6"),
+    FieldElement::from_hex_unchecked("42eec530"),
+    FieldElement::from_hex_unchecked("17992ed2"),
+    FieldElement::from_hex_unchecked("20e95862"),
+    FieldElement::from_hex_unchecked("14c0cbc3"),
+    FieldElement::from_hex_unchecked("7498a0be"),
+    FieldElement::from_hex_unchecked("7e3cd6d"),
+];
+
+const MDS_MATRIX_BABYBEAR: [[FieldElement<Babybear31PrimeField>; 24]; 24] = [
+    [
+        FieldElement::from_hex_unchecked("617b6f8f"),
+        FieldElement::from_hex_unchecked("4fac1310"),
+        FieldElement::from_hex_unchecked("2064e7d6"),
+        FieldElement::from_hex_unchecked("b9600f9"),
+        FieldElement::from_hex_unchecked("16c25d4c"),
+        FieldElement::from_hex_unchecked("67b10696"),
+        FieldElement::from_hex_unchecked("42e65f99"),
+        FieldElement::from_hex_unchecked("733492ae"),
+        FieldElement::from_hex_unchecked("491b3f2d"),
+        FieldElement::from_hex_unchecked("3d9614be"),
+        FieldElement::from_hex_unchecked("1d62d741"),
+        FieldElement::from_hex_unchecked("4a842d71"),
+        FieldElement::from_hex_unchecked("30a75556"),
+        FieldElement::from_hex_unchecked("6f3441a3"),
+        FieldElement::from_hex_unchecked("5480bbbd"),
+        FieldElement::from_hex_unchecked("4635cf0b"),
+        FieldElement::from_hex_unchecked("5fed1afc"),
+        FieldElement::from_hex_unchecked("6f3fe591"),
+        FieldElement::from_hex_unchecked("cc142fe"),
+        FieldElement::from_hex_unchecked("41d08c14"),
+        FieldElement::from_hex_unchecked("472ba998"),
+        FieldElement::from_hex_unchecked("afbc16a"),
+        FieldElement::from_hex_unchecked("4793c104"),
+        FieldElement::from_hex_unchecked("25aac874"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("c72f0b2"),
+        FieldElement::from_hex_unchecked("29f66523"),
+        FieldElement::from_hex_unchecked("4a753af4"),
+        FieldElement::from_hex_unchecked("3fd6dee8"),
+        FieldElement::from_hex_unchecked("4ddecd03"),
+        FieldElement::from_hex_unchecked("52e3f3d"),
+        FieldElement::from_hex_unchecked("165e080e"),
+        FieldElement::from_hex_unchecked("62b345c3"),
+        FieldElement::from_hex_unchecked("52d7c6df"),
+        FieldElement::from_hex_unchecked("22839c8"),
+        FieldElement::from_hex_unchecked("5b7b08d9"),
+        FieldElement::from_hex_unchecked("3f6dd71f"),
+        FieldElement::from_hex_unchecked("12d8651a"),
+        FieldElement::from_hex_unchecked("31f27109"),
+        FieldElement::from_hex_unchecked("6e309fff"),
+        FieldElement::from_hex_unchecked("34e74be8"),
+        FieldElement::from_hex_unchecked("73f69d93"),
+        FieldElement::from_hex_unchecked("4898c726"),
+        FieldElement::from_hex_unchecked("11d3bf93"),
+        FieldElement::from_hex_unchecked("414fb95a"),
+        FieldElement::from_hex_unchecked("3eb12030"),
+        FieldElement::from_hex_unchecked("674b86b9"),
+        FieldElement::from_hex_unchecked("22eaa608"),
+        FieldElement::from_hex_unchecked("62c6690b"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("176bfe93"),
+        FieldElement::from_hex_unchecked("2974cbd2"),
+        FieldElement::from_hex_unchecked("523d078d"),
+        FieldElement::from_hex_unchecked("715a0214"),
+        FieldElement::from_hex_unchecked("40813928"),
+        FieldElement::from_hex_unchecked("5cd7b95b"),
+        FieldElement::from_hex_unchecked("358ef4ef"),
+        FieldElement::from_hex_unchecked("59899e06"),
+        FieldElement::from_hex_unchecked("56697349"),
+        FieldElement::from_hex_unchecked("330f2316"),
+        FieldElement::from_hex_unchecked("65c6e4e9"),
+        FieldElement::from_hex_unchecked("fdc3ff0"),
+        FieldElement::from_hex_unchecked("d167ae7"),
+        FieldElement::from_hex_unchecked("f8c20ac"),
+        FieldElement::from_hex_unchecked("591a93ba"),
+        FieldElement::from_hex_unchecked("5b2355f1"),
+        FieldElement::from_hex_unchecked("41b3d5f"),
+        FieldElement::from_hex_unchecked("75716d84"),
+        FieldElement::from_hex_unchecked("63551dd7"),
+        FieldElement::from_hex_unchecked("30898120"),
+        FieldElement::from_hex_unchecked("19ba4a0a"),
+        FieldElement::from_hex_unchecked("cc87c60"),
+        FieldElement::from_hex_unchecked("42fcb6e2"),
+        FieldElement::from_hex_unchecked("282f9ef3"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("39a0fce3"),
+        FieldElement::from_hex_unchecked("68c7a7c0"),
+        FieldElement::from_hex_unchecked("15c3c7b6"),
+        FieldElement::from_hex_unchecked("280f5de2"),
+        FieldElement::from_hex_unchecked("62c251fb"),
+        FieldElement::from_hex_unchecked("5a6b8fdd"),
+        FieldElement::from_hex_unchecked("3b348e9c"),
+        FieldElement::from_hex_unchecked("4a5b00af"),
+        FieldElement::from_hex_unchecked("82bee5f"),
+        FieldElement::from_hex_unchecked("4eee9ac4"),
+        FieldElement::from_hex_unchecked("5bd34d55"),
+        FieldElement::from_hex_unchecked("44147927"),
+        FieldElement::from_hex_unchecked("37dc3ead"),
+        FieldElement::from_hex_unchecked("53e2d60d"),
+        FieldElement::from_hex_unchecked("15038914"),
+        FieldElement::from_hex_unchecked("5f78c36a"),
+        FieldElement::from_hex_unchecked("53ffb639"),
+        FieldElement::from_hex_unchecked("3dcf3181"),
+        FieldElement::from_hex_unchecked("70994d2a"),
+        FieldElement::from_hex_unchecked("64601ff1"),
+        FieldElement::from_hex_unchecked("2cb9d592"),
+        FieldElement::from_hex_unchecked("46dab15e"),
+        FieldElement::from_hex_unchecked("16ecd88e"),
+        FieldElement::from_hex_unchecked("c7f5f86"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("36cc71ee"),
+        FieldElement::from_hex_unchecked("2569ba7d"),
+        FieldElement::from_hex_unchecked("776bbef0"),
+        FieldElement::from_hex_unchecked("76f6cb1c"),
+        FieldElement::from_hex_unchecked("48c09c62"),
+        FieldElement::from_hex_unchecked("1c7addcf"),
+        FieldElement::from_hex_unchecked("e6abb1f"),
+        FieldElement::from_hex_unchecked("4b7a5d24"),
+        FieldElement::from_hex_unchecked("6ec5a5c2"),
+        FieldElement::from_hex_unchecked("5a45515c"),
+        FieldElement::from_hex_unchecked("8bbebff"),
+        FieldElement::from_hex_unchecked("112c4f10"),
+        FieldElement::from_hex_unchecked("6ceee103"),
+        FieldElement::from_hex_unchecked("49da3a35"),
+        FieldElement::from_hex_unchecked("5ae64619"),
+        FieldElement::from_hex_unchecked("6028162a"),
+        FieldElement::from_hex_unchecked("239edf75"),
+        FieldElement::from_hex_unchecked("8fb9bfb"),
+        FieldElement::from_hex_unchecked("758273df"),
+        FieldElement::from_hex_unchecked("4ecf550e"),
+        FieldElement::from_hex_unchecked("428cb03"),
+        FieldElement::from_hex_unchecked("6bb89399"),
+        FieldElement::from_hex_unchecked("3c59dbbe"),
+        FieldElement::from_hex_unchecked("1c548ec4"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("624b1b58"),
+        FieldElement::from_hex_unchecked("78eb0a0"),
+        FieldElement::from_hex_unchecked("1dd617dd"),
+        FieldElement::from_hex_unchecked("55c6604c"),
+        FieldElement::from_hex_unchecked("3b16db98"),
+        FieldElement::from_hex_unchecked("56adf37a"),
+        FieldElement::from_hex_unchecked("104ba186"),
+        FieldElement::from_hex_unchecked("61ecc4ca"),
+        FieldElement::from_hex_unchecked("3ee349df"),
+        FieldElement::from_hex_unchecked("2cfcc520"),
+        FieldElement::from_hex_unchecked("5bbc23d1"),
+        FieldElement::from_hex_unchecked("429fc6de"),
+        FieldElement::from_hex_unchecked("f5ad1b6"),
+        FieldElement::from_hex_unchecked("409497e1"),
+        FieldElement::from_hex_unchecked("569d9e31"),
+        FieldElement::from_hex_unchecked("2ff987d0"),
+        FieldElement::from_hex_unchecked("12b2f13d"),
+        FieldElement::from_hex_unchecked("67315d07"),
+        FieldElement::from_hex_unchecked("5070e8a1"),
+        FieldElement::from_hex_unchecked("75e71704"),
+        FieldElement::from_hex_unchecked("196215d2"),
+        FieldElement::from_hex_unchecked("3661db58"),
+        FieldElement::from_hex_unchecked("625297f9"),
+        FieldElement::from_hex_unchecked("63772639"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("4eb70a49"),
+        FieldElement::from_hex_unchecked("3cd5db7f"),
+        FieldElement::from_hex_unchecked("5c10437f"),
+        FieldElement::from_hex_unchecked("385d053"),
+        FieldElement::from_hex_unchecked("485d14fd"),
+        FieldElement::from_hex_unchecked("c42fcb7"),
+        FieldElement::from_hex_unchecked("11d54383"),
+        FieldElement::from_hex_unchecked("562a5b5d"),
+        FieldElement::from_hex_unchecked("44b0d91e"),
+        FieldElement::from_hex_unchecked("27a7f18d"),
+        FieldElement::from_hex_unchecked("6dcdd31b"),
+        FieldElement::from_hex_unchecked("36ac3258"),
+        FieldElement::from_hex_unchecked("33329594"),
+        FieldElement::from_hex_unchecked("4353e6d1"),
+        FieldElement::from_hex_unchecked("aec8a4d"),
+        FieldElement::from_hex_unchecked("223f3ed7"),
+        FieldElement::from_hex_unchecked("5567163f"),
+        FieldElement::from_hex_unchecked("41313456"),
+        FieldElement::from_hex_unchecked("51aa7a33"),
+        FieldElement::from_hex_unchecked("3769aad0"),
+        FieldElement::from_hex_unchecked("647686a5"),
+        FieldElement::from_hex_unchecked("775147d6"),
+        FieldElement::from_hex_unchecked("2676428b"),
+        FieldElement::from_hex_unchecked("21cdbf9a"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("1d89ea7f"),
+        FieldElement::from_hex_unchecked("bd1ebc3"),
+        FieldElement::from_hex_unchecked("4dbfe4e8"),
+        FieldElement::from_hex_unchecked("54281c32"),
+        FieldElement::from_hex_unchecked("53805e47"),
+        FieldElement::from_hex_unchecked("3bad4a19"),
+        FieldElement::from_hex_unchecked("57d830b2"),
+        FieldElement::from_hex_unchecked("2bc755f5"),
+        FieldElement::from_hex_unchecked("4c21e574"),
+        FieldElement::from_hex_unchecked("1b3fd141"),
+        FieldElement::from_hex_unchecked("437cc338"),
+        FieldElement::from_hex_unchecked("6b7c1bc"),
+        FieldElement::from_hex_unchecked("4a2a0f11"),
+        FieldElement::from_hex_unchecked("28ce3e8e"),
+        FieldElement::from_hex_unchecked("6861f63e"),
+        FieldElement::from_hex_unchecked("7cc1e6c"),
+        FieldElement::from_hex_unchecked("12c9d154"),
+        FieldElement::from_hex_unchecked("74e4a6f5"),
+        FieldElement::from_hex_unchecked("e3d3230"),
+        FieldElement::from_hex_unchecked("1f1b787d"),
+        FieldElement::from_hex_unchecked("75e1a91f"),
+        FieldElement::from_hex_unchecked("3600e5de"),
+        FieldElement::from_hex_unchecked("6b3f9e99"),
+        FieldElement::from_hex_unchecked("61090b2c"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("4335a957"),
+        FieldElement::from_hex_unchecked("f120c73"),
+        FieldElement::from_hex_unchecked("3d7416f6"),
+        FieldElement::from_hex_unchecked("3df55e1f"),
+        FieldElement::from_hex_unchecked("d70d25"),
+        FieldElement::from_hex_unchecked("76a2e6e0"),
+        FieldElement::from_hex_unchecked("38a96f26"),
+        FieldElement::from_hex_unchecked("68245d81"),
+        FieldElement::from_hex_unchecked("3aa475d8"),
+        FieldElement::from_hex_unchecked("73b2bc39"),
+        FieldElement::from_hex_unchecked("52026df3"),
+        FieldElement::from_hex_unchecked("1ddfe6fe"),
+        FieldElement::from_hex_unchecked("1ad36431"),
+        FieldElement::from_hex_unchecked("25c61f6c"),
+        FieldElement::from_hex_unchecked("314fce19"),
+        FieldElement::from_hex_unchecked("5b23e667"),
+        FieldElement::from_hex_unchecked("6e000759"),
+        FieldElement::from_hex_unchecked("19c5def0"),
+        FieldElement::from_hex_unchecked("129ab30b"),
+        FieldElement::from_hex_unchecked("7795edd5"),
+        FieldElement::from_hex_unchecked("3fb13a07"),
+        FieldElement::from_hex_unchecked("604ecb7e"),
+        FieldElement::from_hex_unchecked("5ff42620"),
+        FieldElement::from_hex_unchecked("34d5a296"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("1dfb4a24"),
+        FieldElement::from_hex_unchecked("5754d136"),
+        FieldElement::from_hex_unchecked("5bb20c62"),
+        FieldElement::from_hex_unchecked("2a0955d1"),
+        FieldElement::from_hex_unchecked("6118d9fe"),
+        FieldElement::from_hex_unchecked("4de262f1"),
+        FieldElement::from_hex_unchecked("22f634e0"),
+        FieldElement::from_hex_unchecked("56564645"),
+        FieldElement::from_hex_unchecked("2856c48c"),
+        FieldElement::from_hex_unchecked("48ad3d04"),
+        FieldElement::from_hex_unchecked("4ff8c7a4"),
+        FieldElement::from_hex_unchecked("526c8f07"),
+        FieldElement::from_hex_unchecked("270e659a"),
+        FieldElement::from_hex_unchecked("ca3c74d"),
+        FieldElement::from_hex_unchecked("130e1a5a"),
+        FieldElement::from_hex_unchecked("3196f63"),
+        FieldElement::from_hex_unchecked("3494fdab"),
+        FieldElement::from_hex_unchecked("395ff3e2"),
+        FieldElement::from_hex_unchecked("f203448"),
+        FieldElement::from_hex_unchecked("531b39ec"),
+        FieldElement::from_hex_unchecked("689f98ad"),
+        FieldElement::from_hex_unchecked("37f5a6fa"),
+        FieldElement::from_hex_unchecked("19d6b316"),
+        FieldElement::from_hex_unchecked("1ccd690e"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("179ef3ea"),
+        FieldElement::from_hex_unchecked("6de66e76"),
+        FieldElement::from_hex_unchecked("6e87ce9d"),
+        FieldElement::from_hex_unchecked("64e954a4"),
+        FieldElement::from_hex_unchecked("59542a82"),
+        FieldElement::from_hex_unchecked("6db566cc"),
+        FieldElement::from_hex_unchecked("2527b2d0"),
+        FieldElement::from_hex_unchecked("69aecab3"),
+        FieldElement::from_hex_unchecked("1c3a27c6"),
+        FieldElement::from_hex_unchecked("1a6ba8ba"),
+        FieldElement::from_hex_unchecked("3a5e09f7"),
+        FieldElement::from_hex_unchecked("4770f372"),
+        FieldElement::from_hex_unchecked("17c3f8b2"),
+        FieldElement::from_hex_unchecked("3babe50b"),
+        FieldElement::from_hex_unchecked("3ebf4778"),
+        FieldElement::from_hex_unchecked("42dfddb9"),
+        FieldElement::from_hex_unchecked("46e813b5"),
+        FieldElement::from_hex_unchecked("4da546e2"),
+        FieldElement::from_hex_unchecked("62c2117d"),
+        FieldElement::from_hex_unchecked("763414f1"),
+        FieldElement::from_hex_unchecked("5db0c380"),
+        FieldElement::from_hex_unchecked("3bf7c5e9"),
+        FieldElement::from_hex_unchecked("533ac88d"),
+        FieldElement::from_hex_unchecked("713ec7cc"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("31b85b4b"),
+        FieldElement::from_hex_unchecked("2a3f985c"),
+        FieldElement::from_hex_unchecked("63c86d47"),
+        FieldElement::from_hex_unchecked("5f3117a9"),
+        FieldElement::from_hex_unchecked("2d3f5ac4"),
+        FieldElement::from_hex_unchecked("afd3399"),
+        FieldElement::from_hex_unchecked("62852df4"),
+        FieldElement::from_hex_unchecked("607d6593"),
+        FieldElement::from_hex_unchecked("1e695823"),
+        FieldElement::from_hex_unchecked("13d7c43c"),
+        FieldElement::from_hex_unchecked("318555a2"),
+        FieldElement::from_hex_unchecked("5f10b466"),
+        FieldElement::from_hex_unchecked("33089406"),
+        FieldElement::from_hex_unchecked("23f6322a"),
+        FieldElement::from_hex_unchecked("69386221"),
+        FieldElement::from_hex_unchecked("2f9bfb4"),
+        FieldElement::from_hex_unchecked("2e4c7771"),
+        FieldElement::from_hex_unchecked("3267f4a4"),
+        FieldElement::from_hex_unchecked("4e34a540"),
+        FieldElement::from_hex_unchecked("1c9117a8"),
+        FieldElement::from_hex_unchecked("557a4cc7"),
+        FieldElement::from_hex_unchecked("60f6ce69"),
+        FieldElement::from_hex_unchecked("402b605d"),
+        FieldElement::from_hex_unchecked("3db0b29c"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("4dfc30b4"),
+        FieldElement::from_hex_unchecked("5dfc70f"),
+        FieldElement::from_hex_unchecked("2168682c"),
+        FieldElement::from_hex_unchecked("2195faa"),
+        FieldElement::from_hex_unchecked("170d8a89"),
+        FieldElement::from_hex_unchecked("43854274"),
+        FieldElement::from_hex_unchecked("11a36c84"),
+        FieldElement::from_hex_unchecked("4ef1d9e9"),
+        FieldElement::from_hex_unchecked("315ff3b5"),
+        FieldElement::from_hex_unchecked("5140fbfe"),
+        FieldElement::from_hex_unchecked("5852c041"),
+        FieldElement::from_hex_unchecked("760ad0a0"),
+        FieldElement::from_hex_unchecked("367b6685"),
+        FieldElement::from_hex_unchecked("268cd4b"),
+        FieldElement::from_hex_unchecked("6c70f1db"),
+        FieldElement::from_hex_unchecked("4e702119"),
+        FieldElement::from_hex_unchecked("428f76b"),
+        FieldElement::from_hex_unchecked("65a15ce"),
+        FieldElement::from_hex_unchecked("46d2c400"),
+        FieldElement::from_hex_unchecked("29a52ab"),
+        FieldElement::from_hex_unchecked("1b01aac5"),
+        FieldElement::from_hex_unchecked("489a61ff"),
+        FieldElement::from_hex_unchecked("35293c4a"),
+        FieldElement::from_hex_unchecked("4c00f8be"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("bf16e15"),
+        FieldElement::from_hex_unchecked("2fb3ade4"),
+        FieldElement::from_hex_unchecked("5cd6b850"),
+        FieldElement::from_hex_unchecked("3857c8e1"),
+        FieldElement::from_hex_unchecked("32520702"),
+        FieldElement::from_hex_unchecked("51250d6d"),
+        FieldElement::from_hex_unchecked("50bd2f53"),
+        FieldElement::from_hex_unchecked("17c949b1"),
+        FieldElement::from_hex_unchecked("3410c06d"),
+        FieldElement::from_hex_unchecked("73d9b9cc"),
+        FieldElement::from_hex_unchecked("287020f"),
+        FieldElement::from_hex_unchecked("373fc36a"),
+        FieldElement::from_hex_unchecked("1e16b0e3"),
+        FieldElement::from_hex_unchecked("746b39b0"),
+        FieldElement::from_hex_unchecked("6e4dd13e"),
+        FieldElement::from_hex_unchecked("407b48f5"),
+        FieldElement::from_hex_unchecked("55ccabef"),
+        FieldElement::from_hex_unchecked("4983a5d8"),
+        FieldElement::from_hex_unchecked("2e9bc41f"),
+        FieldElement::from_hex_unchecked("3d9ed34e"),
+        FieldElement::from_hex_unchecked("2439bf59"),
+        FieldElement::from_hex_unchecked("16450961"),
+        FieldElement::from_hex_unchecked("75e9e34a"),
+        FieldElement::from_hex_unchecked("43efbc0"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("23dd0c"),
+        FieldElement::from_hex_unchecked("4ebeba77"),
+        FieldElement::from_hex_unchecked("6f5ef1a3"),
+        FieldElement::from_hex_unchecked("6030a36c"),
+        FieldElement::from_hex_unchecked("63311d89"),
+        FieldElement::from_hex_unchecked("1d34c77d"),
+        FieldElement::from_hex_unchecked("2657c525"),
+        FieldElement::from_hex_unchecked("4e0c3e59"),
+        FieldElement::from_hex_unchecked("71799417"),
+        FieldElement::from_hex_unchecked("66ccbcb1"),
+        FieldElement::from_hex_unchecked("6bbbb630"),
+        FieldElement::from_hex_unchecked("20f1e613"),
+        FieldElement::from_hex_unchecked("76865f14"),
+        FieldElement::from_hex_unchecked("4f2d6d1f"),
+        FieldElement::from_hex_unchecked("1dd2e4eb"),
+        FieldElement::from_hex_unchecked("46d6e83a"),
+        FieldElement::from_hex_unchecked("967aee8"),
+        FieldElement::from_hex_unchecked("6cfc4b3a"),
+        FieldElement::from_hex_unchecked("103564f7"),
+        FieldElement::from_hex_unchecked("26e045b2"),
+        FieldElement::from_hex_unchecked("65f1600d"),
+        FieldElement::from_hex_unchecked("26f63f6b"),
+        FieldElement::from_hex_unchecked("7c45c3e"),
+        FieldElement::from_hex_unchecked("334cc414"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("d5c75e"),
+        FieldElement::from_hex_unchecked("6ede4db4"),
+        FieldElement::from_hex_unchecked("5fcf79f3"),
+        FieldElement::from_hex_unchecked("77cdb27a"),
+        FieldElement::from_hex_unchecked("23a359b0"),
+        FieldElement::from_hex_unchecked("5044812d"),
+        FieldElement::from_hex_unchecked("837e1ff"),
+        FieldElement::from_hex_unchecked("632867ee"),
+        FieldElement::from_hex_unchecked("51a6e292"),
+        FieldElement::from_hex_unchecked("928af80"),
+        FieldElement::from_hex_unchecked("350f5034"),
+        FieldElement::from_hex_unchecked("698abb91"),
+        FieldElement::from_hex_unchecked("1615c4a6"),
+        FieldElement::from_hex_unchecked("1eb625ea"),
+        FieldElement::from_hex_unchecked("47f4d3b1"),
+        FieldElement::from_hex_unchecked("ecbb540"),
+        FieldElement::from_hex_unchecked("3d3d145"),
+        FieldElement::from_hex_unchecked("a5826c"),
+        FieldElement::from_hex_unchecked("5b5419c2"),
+        FieldElement::from_hex_unchecked("158cea4f"),
+        FieldElement::from_hex_unchecked("26d502e2"),
+        FieldElement::from_hex_unchecked("56779e9b"),
+        FieldElement::from_hex_unchecked("48df014d"),
+        FieldElement::from_hex_unchecked("40ea8ddf"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("52f1d564"),
+        FieldElement::from_hex_unchecked("77c08fbd"),
+        FieldElement::from_hex_unchecked("2a8aeb9b"),
+        FieldElement::from_hex_unchecked("b58f40f"),
+        FieldElement::from_hex_unchecked("4ba7f267"),
+        FieldElement::from_hex_unchecked("247cae1e"),
+        FieldElement::from_hex_unchecked("6f423815"),
+        FieldElement::from_hex_unchecked("4486ef8e"),
+        FieldElement::from_hex_unchecked("6b45dcf1"),
+        FieldElement::from_hex_unchecked("152aa5d0"),
+        FieldElement::from_hex_unchecked("c555f4e"),
+        FieldElement::from_hex_unchecked("43485e4"),
+        FieldElement::from_hex_unchecked("2b987de1"),
+        FieldElement::from_hex_unchecked("4f974a21"),
+        FieldElement::from_hex_unchecked("374c27a0"),
+        FieldElement::from_hex_unchecked("15eff951"),
+        FieldElement::from_hex_unchecked("6ca6638c"),
+        FieldElement::from_hex_unchecked("6848ed06"),
+        FieldElement::from_hex_unchecked("742a5c48"),
+        FieldElement::from_hex_unchecked("3bd29a65"),
+        FieldElement::from_hex_unchecked("576e19fc"),
+        FieldElement::from_hex_unchecked("3e9ccdf5"),
+        FieldElement::from_hex_unchecked("106b61f0"),
+        FieldElement::from_hex_unchecked("6eb31115"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("3a985dbd"),
+        FieldElement::from_hex_unchecked("c042b69"),
+        FieldElement::from_hex_unchecked("4c2c9eea"),
+        FieldElement::from_hex_unchecked("55468de"),
+        FieldElement::from_hex_unchecked("28a1db8a"),
+        FieldElement::from_hex_unchecked("15d7c6ea"),
+        FieldElement::from_hex_unchecked("5dffad58"),
+        FieldElement::from_hex_unchecked("1b30914"),
+        FieldElement::from_hex_unchecked("71fb5f5b"),
+        FieldElement::from_hex_unchecked("54000a58"),
+        FieldElement::from_hex_unchecked("43e8d569"),
+        FieldElement::from_hex_unchecked("1f76e9af"),
+        FieldElement::from_hex_unchecked("10d93e42"),
+        FieldElement::from_hex_unchecked("43a0db71"),
+        FieldElement::from_hex_unchecked("37d1235a"),
+        FieldElement::from_hex_unchecked("2621bd0"),
+        FieldElement::from_hex_unchecked("60e41941"),
+        FieldElement::from_hex_unchecked("76e544a9"),
+        FieldElement::from_hex_unchecked("516c1949"),
+        FieldElement::from_hex_unchecked("8d877bb"),
+        FieldElement::from_hex_unchecked("244b0c9c"),
+        FieldElement::from_hex_unchecked("34e92c9e"),
+        FieldElement::from_hex_unchecked("695151e7"),
+        FieldElement::from_hex_unchecked("451a1a87"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("38cfc5b5"),
+        FieldElement::from_hex_unchecked("2a04f841"),
+        FieldElement::from_hex_unchecked("4fdab19a"),
+        FieldElement::from_hex_unchecked("73fe7345"),
+        FieldElement::from_hex_unchecked("33ea37b1"),
+        FieldElement::from_hex_unchecked("372d9e83"),
+        FieldElement::from_hex_unchecked("670d82ee"),
+        FieldElement::from_hex_unchecked("1ef368d6"),
+        FieldElement::from_hex_unchecked("54f4c05b"),
+        FieldElement::from_hex_unchecked("2810b6d3"),
+        FieldElement::from_hex_unchecked("1daa2101"),
+        FieldElement::from_hex_unchecked("2bcb4307"),
+        FieldElement::from_hex_unchecked("53173f8f"),
+        FieldElement::from_hex_unchecked("3596f92c"),
+        FieldElement::from_hex_unchecked("301dd9fd"),
+        FieldElement::from_hex_unchecked("2e839a8"),
+        FieldElement::from_hex_unchecked("3783bae1"),
+        FieldElement::from_hex_unchecked("7690beaf"),
+        FieldElement::from_hex_unchecked("161117ca"),
+        FieldElement::from_hex_unchecked("5cc1c49f"),
+        FieldElement::from_hex_unchecked("288c8d3f"),
+        FieldElement::from_hex_unchecked("2123a974"),
+        FieldElement::from_hex_unchecked("629ea32d"),
+        FieldElement::from_hex_unchecked("38d403d1"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("1c56a6fc"),
+        FieldElement::from_hex_unchecked("6c099725"),
+        FieldElement::from_hex_unchecked("fe7e58"),
+        FieldElement::from_hex_unchecked("700bccfe"),
+        FieldElement::from_hex_unchecked("1fed5402"),
+        FieldElement::from_hex_unchecked("1a178e7"),
+        FieldElement::from_hex_unchecked("4365f3f5"),
+        FieldElement::from_hex_unchecked("243a8e64"),
+        FieldElement::from_hex_unchecked("3e949180"),
+        FieldElement::from_hex_unchecked("1ee798f5"),
+        FieldElement::from_hex_unchecked("4231a1e3"),
+        FieldElement::from_hex_unchecked("5665ae4e"),
+        FieldElement::from_hex_unchecked("3e957d1d"),
+        FieldElement::from_hex_unchecked("3fd8157b"),
+        FieldElement::from_hex_unchecked("5562e26a"),
+        FieldElement::from_hex_unchecked("37552ffd"),
+        FieldElement::from_hex_unchecked("3e0ebe99"),
+        FieldElement::from_hex_unchecked("39c3ace8"),
+        FieldElement::from_hex_unchecked("606f1716"),
+        FieldElement::from_hex_unchecked("c5cc07d"),
+        FieldElement::from_hex_unchecked("728fc10a"),
+        FieldElement::from_hex_unchecked("29ee23f1"),
+        FieldElement::from_hex_unchecked("4171bdac"),
+        FieldElement::from_hex_unchecked("599f21f4"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("b0ae05c"),
+        FieldElement::from_hex_unchecked("763d243a"),
+        FieldElement::from_hex_unchecked("1d19d80"),
+        FieldElement::from_hex_unchecked("65175e30"),
+        FieldElement::from_hex_unchecked("24881ea9"),
+        FieldElement::from_hex_unchecked("1639dfee"),
+        FieldElement::from_hex_unchecked("469788ed"),
+        FieldElement::from_hex_unchecked("4a71c107"),
+        FieldElement::from_hex_unchecked("40b12660"),
+        FieldElement::from_hex_unchecked("644ffbdd"),
+        FieldElement::from_hex_unchecked("6348bb1f"),
+        FieldElement::from_hex_unchecked("24908756"),
+        FieldElement::from_hex_unchecked("31f6e091"),
+        FieldElement::from_hex_unchecked("5d09a2a0"),
+        FieldElement::from_hex_unchecked("73050c5f"),
+        FieldElement::from_hex_unchecked("6ec1f595"),
+        FieldElement::from_hex_unchecked("34acff20"),
+        FieldElement::from_hex_unchecked("5e861f67"),
+        FieldElement::from_hex_unchecked("23d59e91"),
+        FieldElement::from_hex_unchecked("4087e5b6"),
+        FieldElement::from_hex_unchecked("2317b198"),
+        FieldElement::from_hex_unchecked("114d0bc"),
+        FieldElement::from_hex_unchecked("76aa9672"),
+        FieldElement::from_hex_unchecked("33fa11d2"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("6e16cdee"),
+        FieldElement::from_hex_unchecked("669436ef"),
+        FieldElement::from_hex_unchecked("575c89f2"),
+        FieldElement::from_hex_unchecked("5a1440a7"),
+        FieldElement::from_hex_unchecked("67165071"),
+        FieldElement::from_hex_unchecked("40c1c360"),
+        FieldElement::from_hex_unchecked("6cfd5522"),
+        FieldElement::from_hex_unchecked("3b7afdc1"),
+        FieldElement::from_hex_unchecked("b453d23"),
+        FieldElement::from_hex_unchecked("560ddb79"),
+        FieldElement::from_hex_unchecked("6b6f6e27"),
+        FieldElement::from_hex_unchecked("45cdd99a"),
+        FieldElement::from_hex_unchecked("5d6855c8"),
+        FieldElement::from_hex_unchecked("2d33cb9a"),
+        FieldElement::from_hex_unchecked("24132e88"),
+        FieldElement::from_hex_unchecked("693d7024"),
+        FieldElement::from_hex_unchecked("5a98285a"),
+        FieldElement::from_hex_unchecked("4fc026ab"),
+        FieldElement::from_hex_unchecked("2307a0c4"),
+        FieldElement::from_hex_unchecked("653c1221"),
+        FieldElement::from_hex_unchecked("203870ec"),
+        FieldElement::from_hex_unchecked("368217"),
+        FieldElement::from_hex_unchecked("1301a878"),
+        FieldElement::from_hex_unchecked("290a0c15"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("219a4084"),
+        FieldElement::from_hex_unchecked("7379c9e7"),
+        FieldElement::from_hex_unchecked("43012457"),
+        FieldElement::from_hex_unchecked("2179ef92"),
+        FieldElement::from_hex_unchecked("1d94e363"),
+        FieldElement::from_hex_unchecked("6150a74e"),
+        FieldElement::from_hex_unchecked("83baa32"),
+        FieldElement::from_hex_unchecked("30933f0b"),
+        FieldElement::from_hex_unchecked("4d6ec93b"),
+        FieldElement::from_hex_unchecked("9e9f221"),
+        FieldElement::from_hex_unchecked("10bdcc21"),
+        FieldElement::from_hex_unchecked("40568594"),
+        FieldElement::from_hex_unchecked("4e403c53"),
+        FieldElement::from_hex_unchecked("4d1b651c"),
+        FieldElement::from_hex_unchecked("220bcbcb"),
+        FieldElement::from_hex_unchecked("5d9cc069"),
+        FieldElement::from_hex_unchecked("15fe1693"),
+        FieldElement::from_hex_unchecked("4c36d716"),
+        FieldElement::from_hex_unchecked("424dda8f"),
+        FieldElement::from_hex_unchecked("55f9f57a"),
+        FieldElement::from_hex_unchecked("175d18fb"),
+        FieldElement::from_hex_unchecked("4052ac51"),
+        FieldElement::from_hex_unchecked("4a0eeb8e"),
+        FieldElement::from_hex_unchecked("170fc48a"),
+    ],
+    [
+        FieldElement::from_hex_unchecked("6d98aff4"),
+        FieldElement::from_hex_unchecked("61950fc2"),
+        FieldElement::from_hex_unchecked("34aee05b"),
+        FieldElement::from_hex_unchecked("6df94cec"),
+        FieldElement::from_hex_unchecked("4cc2b4b0"),
+        FieldElement::from_hex_unchecked("64e9dc5d"),
+        FieldElement::from_hex_unchecked("6ccfb5b"),
+        FieldElement::from_hex_unchecked("eee127d"),
+        FieldElement::from_hex_unchecked("76380f63"),
+        FieldElement::from_hex_unchecked("58382fb"),
+        FieldElement::from_hex_unchecked("242901f6"),
+        FieldElement::from_hex_unchecked("4115c009"),
+        FieldElement::from_hex_unchecked("2090b3a5"),
+        FieldElement::from_hex_unchecked("12da6314"),
+        FieldElement::from_hex_unchecked("3512ec62"),
+        FieldElement::from_hex_unchecked("eb89f1f"),
+        FieldElement::from_hex_unchecked("326ada33"),
+        FieldElement::from_hex_unchecked("375096f2"),
+        FieldElement::from_hex_unchecked("501de774"),
+        FieldElement::from_hex_unchecked("247eb96e"),
+        FieldElement::from_hex_unchecked("33964d49"),
+        FieldElement::from_hex_unchecked("c422731"),
+        FieldElement::from_hex_unchecked("633a533f"),
+        FieldElement::from_hex_unchecked("1526a3d3"),
+    ],
+];
+
+impl RescuePrimeOptimizedField for Babybear31PrimeField {
+    const ALPHA: u64 = 7;
+
+    fn inverse_sbox(x: &FieldElement<Self>) -> FieldElement<Self> {
+        x.pow(ALPHA_INV_BABYBEAR)
+    }
+
+    fn parameters(security_level: &SecurityLevel) -> Option<RescuePrimeOptimizedParameters<Self>> {
+        match security_level {
+            SecurityLevel::Sec128 => Some(RescuePrimeOptimizedParameters {
+                state_size: 24,
+                capacity: 8,
+                num_rounds: 7,
+                round_constants: &ROUND_CONSTANTS_BABYBEAR,
+                mds_matrix: MDS_MATRIX_BABYBEAR.iter().map(|row| row.to_vec()).collect(),
+                mds_vector: None,
+                ntt_root: None,
+            }),
+            SecurityLevel::Sec160 => None,
+        }
+    }
+}
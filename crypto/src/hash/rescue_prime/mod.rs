@@ -1,9 +1,12 @@
+mod babybear;
 mod parameters;
 mod rescue_prime_optimized;
+mod stark252;
 mod utils;
 
+pub use parameters::{RescuePrimeOptimizedField, RescuePrimeOptimizedParameters, SecurityLevel};
 pub use rescue_prime_optimized::MdsMethod;
-pub use rescue_prime_optimized::RescuePrimeOptimized;
+pub use rescue_prime_optimized::{RescuePrimeOptimized, RescuePrimeOptimizedOver};
 
 use lambdaworks_math::field::element::FieldElement;
 use lambdaworks_math::field::fields::u64_goldilocks_field::Goldilocks64Field;
@@ -1,4 +1,11 @@
 use super::Fp;
+use alloc::vec::Vec;
+use lambdaworks_math::field::{
+    element::FieldElement,
+    fields::u64_goldilocks_field::Goldilocks64Field,
+    traits::{IsField, IsPrimeField},
+};
+
 pub const ALPHA: u64 = 7;
 pub const ALPHA_INV: u64 = 10540996611094048183;
 
@@ -907,57 +914,66 @@ pub enum SecurityLevel {
     Sec160,
 }
 
-pub fn get_state_size(security_level: &SecurityLevel) -> usize {
-    match security_level {
-        SecurityLevel::Sec128 => 12,
-        SecurityLevel::Sec160 => 16,
-    }
+/// Parameters of an instance of Rescue Prime Optimized.
+pub struct RescuePrimeOptimizedParameters<F: IsField + 'static> {
+    /// State width of the hash function.
+    pub state_size: usize,
+    /// Capacity of the sponge.
+    pub capacity: usize,
+    /// Number of rounds of the permutation.
+    pub num_rounds: usize,
+    /// Round constants, `2 * state_size` for each round.
+    pub round_constants: &'static [FieldElement<F>],
+    /// MDS matrix used in the permutation.
+    pub mds_matrix: Vec<Vec<FieldElement<F>>>,
+    /// First row of the MDS matrix, when it is circulant. It is needed by the NTT and
+    /// Karatsuba methods.
+    pub mds_vector: Option<&'static [FieldElement<F>]>,
+    /// Primitive `state_size`-th root of unity, needed by the NTT method.
+    pub ntt_root: Option<FieldElement<F>>,
 }
 
-pub fn get_capacity(security_level: &SecurityLevel) -> usize {
-    match security_level {
-        SecurityLevel::Sec128 => 4,
-        SecurityLevel::Sec160 => 6,
-    }
-}
+/// A prime field with parameter sets for Rescue Prime Optimized.
+pub trait RescuePrimeOptimizedField: IsPrimeField + Sized + 'static {
+    /// Exponent of the S-box.
+    const ALPHA: u64;
 
-pub fn get_round_constants(level: &SecurityLevel) -> &'static [Fp] {
-    match level {
-        SecurityLevel::Sec128 => &ROUND_CONSTANTS_128,
-        SecurityLevel::Sec160 => &ROUND_CONSTANTS_160,
-    }
-}
+    /// Applies the inverse S-box, `x^(1 / ALPHA)`, to an element.
+    fn inverse_sbox(x: &FieldElement<Self>) -> FieldElement<Self>;
 
-pub enum MdsVector {
-    Mds128([Fp; 12]),
-    Mds160([Fp; 16]),
+    /// Returns the parameters for the security level, or `None` if the field has no parameter
+    /// set for it.
+    fn parameters(security_level: &SecurityLevel) -> Option<RescuePrimeOptimizedParameters<Self>>;
 }
 
-pub fn get_mds_vector(level: SecurityLevel) -> MdsVector {
-    match level {
-        SecurityLevel::Sec128 => MdsVector::Mds128(MDS_VECTOR_128),
-        SecurityLevel::Sec160 => MdsVector::Mds160(MDS_VECTOR_160),
-    }
-}
+/// The parameters of Miden's RPO over Goldilocks.
+impl RescuePrimeOptimizedField for Goldilocks64Field {
+    const ALPHA: u64 = ALPHA;
 
-#[allow(clippy::large_enum_variant)]
-pub enum MdsMatrix {
-    Mds128([[Fp; 12]; 12]),
-    Mds160([[Fp; 16]; 16]),
-}
-
-pub fn get_mds_matrix(level: &SecurityLevel) -> MdsMatrix {
-    match level {
-        SecurityLevel::Sec128 => MdsMatrix::Mds128(MDS_MATRIX_128),
-        SecurityLevel::Sec160 => MdsMatrix::Mds160(MDS_MATRIX_160),
+    fn inverse_sbox(x: &Fp) -> Fp {
+        x.pow(ALPHA_INV)
     }
-}
 
-impl MdsVector {
-    pub fn as_slice(&self) -> &[Fp] {
-        match self {
-            MdsVector::Mds128(ref vec) => vec,
-            MdsVector::Mds160(ref vec) => vec,
-        }
+    fn parameters(security_level: &SecurityLevel) -> Option<RescuePrimeOptimizedParameters<Self>> {
+        Some(match security_level {
+            SecurityLevel::Sec128 => RescuePrimeOptimizedParameters {
+                state_size: 12,
+                capacity: 4,
+                num_rounds: 7,
+                round_constants: &ROUND_CONSTANTS_128,
+                mds_matrix: MDS_MATRIX_128.iter().map(|row| row.to_vec()).collect(),
+                mds_vector: Some(&MDS_VECTOR_128),
+                ntt_root: Some(Fp::from(281474976645120u64)),
+            },
+            SecurityLevel::Sec160 => RescuePrimeOptimizedParameters {
+                state_size: 16,
+                capacity: 6,
+                num_rounds: 7,
+                round_constants: &ROUND_CONSTANTS_160,
+                mds_matrix: MDS_MATRIX_160.iter().map(|row| row.to_vec()).collect(),
+                mds_vector: Some(&MDS_VECTOR_160),
+                ntt_root: Some(Fp::from(17293822564807737345u64)),
+            },
+        })
     }
 }
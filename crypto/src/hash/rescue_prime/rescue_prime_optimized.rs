@@ -1,15 +1,17 @@
 use super::parameters::*;
 use super::utils::*;
-use super::Fp;
 use crate::alloc::vec::Vec;
 use core::iter;
-use lambdaworks_math::field::errors::FieldError;
+use lambdaworks_math::field::{
+    element::FieldElement, errors::FieldError, fields::u64_goldilocks_field::Goldilocks64Field,
+};
 
 // Implementation of the Rescue Prime Optimized hash function.
 // https://eprint.iacr.org/2022/1577
 // https://github.com/ASDiscreteMathematics/rpo/tree/master/reference_implementation
-// It supports two security levels: 128-bit and 160-bit. Depending on the security level chosen
-// the integer parameters are set accordingly.
+// It is generic over the field, and each field provides parameter sets for the security levels
+// it supports (see `RescuePrimeOptimizedField`). Over Goldilocks, the parameters are the ones of
+// the paper and of Miden:
 
 // For the Security level (λ) of 128 bits we have:
 // Number of rounds (N): 7
@@ -25,76 +27,101 @@ use lambdaworks_math::field::errors::FieldError;
 
 // In the paper, the authors use a number of rounds equal to 7 as a trade-off between security and performance.
 // The number of rounds can be increased to 8 or 9 to achieve a higher level of security at the cost of performance.
-const NUM_FULL_ROUNDS: usize = 7;
 
-pub struct RescuePrimeOptimized {
+/// Rescue Prime Optimized over Goldilocks, with the parameters of the paper.
+pub type RescuePrimeOptimized = RescuePrimeOptimizedOver<Goldilocks64Field>;
+
+/// Rescue Prime Optimized over any field with parameters, see [`RescuePrimeOptimizedField`].
+pub struct RescuePrimeOptimizedOver<F: RescuePrimeOptimizedField> {
     /// State width of the hash function.
     m: usize,
     /// Capacity of the sponge.
     capacity: usize,
     /// Rate of the sponge.
     rate: usize,
+    /// Number of rounds of the permutation.
+    num_rounds: usize,
     /// Precomputed round constants used in the permutation.
-    round_constants: &'static [Fp],
+    round_constants: &'static [FieldElement<F>],
     /// MDS matrix used in the permutation.
-    mds_matrix: Vec<Vec<Fp>>,
-    /// MDS vector used for optimizing matrix multiplication.
-    mds_vector: MdsVector,
+    mds_matrix: Vec<Vec<FieldElement<F>>>,
+    /// First row of the MDS matrix, if it is circulant.
+    mds_vector: Option<&'static [FieldElement<F>]>,
+    /// Root of unity used by the NTT method.
+    ntt_root: Option<FieldElement<F>>,
     /// Method used for applying the MDS matrix.
     mds_method: MdsMethod,
 }
 
-impl Default for RescuePrimeOptimized {
+impl<F: RescuePrimeOptimizedField> Default for RescuePrimeOptimizedOver<F> {
     fn default() -> Self {
         Self::new(SecurityLevel::Sec128, MdsMethod::MatrixMultiplication).unwrap()
     }
 }
 
-impl RescuePrimeOptimized {
+impl<F: RescuePrimeOptimizedField> RescuePrimeOptimizedOver<F> {
     /// Creates a new instance of `RescuePrimeOptimized` with corresponding Security level and the specified MDS method.
+    /// It fails if the field has no parameters for the security level, or if the MDS method
+    /// can't be used with its MDS matrix.
     pub fn new(security_level: SecurityLevel, mds_method: MdsMethod) -> Result<Self, &'static str> {
-        let m = get_state_size(&security_level);
-        let capacity = get_capacity(&security_level);
-        let rate = m - capacity;
-        let mds_matrix = get_mds_matrix(&security_level);
-        let round_constants = get_round_constants(&security_level);
-        let mds_vector = get_mds_vector(security_level);
+        let parameters = F::parameters(&security_level)
+            .ok_or("The field has no parameters for this security level")?;
+        match mds_method {
+            MdsMethod::Ntt if parameters.ntt_root.is_none() => {
+                return Err("The NTT method is not supported by these parameters")
+            }
+            MdsMethod::Ntt | MdsMethod::Karatsuba if parameters.mds_vector.is_none() => {
+                return Err("The MDS matrix of these parameters is not circulant")
+            }
+            _ => {}
+        }
+        let m = parameters.state_size;
+        let capacity = parameters.capacity;
         Ok(Self {
             m,
             capacity,
-            rate,
-            round_constants,
-            mds_matrix: match mds_matrix {
-                MdsMatrix::Mds128(matrix) => matrix.iter().map(|&row| row.to_vec()).collect(),
-                MdsMatrix::Mds160(matrix) => matrix.iter().map(|&row| row.to_vec()).collect(),
-            },
-            mds_vector,
+            rate: m - capacity,
+            num_rounds: parameters.num_rounds,
+            round_constants: parameters.round_constants,
+            mds_matrix: parameters.mds_matrix,
+            mds_vector: parameters.mds_vector,
+            ntt_root: parameters.ntt_root,
             mds_method,
         })
     }
 
+    /// Returns the number of elements of the state that are absorbed at each permutation.
+    pub fn rate(&self) -> usize {
+        self.rate
+    }
+
+    /// Returns the capacity of the sponge.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
     /// Applies the inverse S-box to the state.
-    pub fn apply_inverse_sbox(state: &mut [Fp]) {
+    pub fn apply_inverse_sbox(state: &mut [FieldElement<F>]) {
         for x in state.iter_mut() {
-            *x = x.pow(ALPHA_INV);
+            *x = F::inverse_sbox(x);
         }
     }
 
     /// Applies the S-box to the state.
-    pub fn apply_sbox(state: &mut [Fp]) {
+    pub fn apply_sbox(state: &mut [FieldElement<F>]) {
         for x in state.iter_mut() {
-            *x = x.pow(ALPHA);
+            *x = x.pow(F::ALPHA);
         }
     }
 
     /// Performs MDS matrix-vector multiplication.
-    fn mds_matrix_vector_multiplication(&self, state: &[Fp]) -> Vec<Fp> {
+    fn mds_matrix_vector_multiplication(&self, state: &[FieldElement<F>]) -> Vec<FieldElement<F>> {
         let m = state.len();
-        let mut new_state = vec![Fp::zero(); m];
+        let mut new_state = vec![FieldElement::zero(); m];
 
         for (i, new_value) in new_state.iter_mut().enumerate() {
             for (j, state_value) in state.iter().enumerate() {
-                *new_value += self.mds_matrix[i][j] * state_value;
+                *new_value += &self.mds_matrix[i][j] * state_value;
             }
         }
 
@@ -102,66 +129,69 @@ impl RescuePrimeOptimized {
     }
 
     /// Performs MDS using Number Theoretic Transform.
-    fn mds_ntt(&self, state: &[Fp]) -> Result<Vec<Fp>, FieldError> {
+    fn mds_ntt(&self, state: &[FieldElement<F>]) -> Result<Vec<FieldElement<F>>, FieldError> {
         let m = state.len();
-        let omega = if m == 12 {
-            Fp::from(281474976645120u64)
-        } else {
-            Fp::from(17293822564807737345u64)
-        };
-        let mds_vector = self.mds_vector.as_slice();
+        let omega = self
+            .ntt_root
+            .as_ref()
+            .expect("the NTT method is only available for parameters with a root of unity");
+        let mds_vector = self
+            .mds_vector
+            .expect("the NTT method is only available for circulant MDS matrices");
 
         let mds_ntt = ntt(mds_vector, omega);
-        let state_rev: Vec<Fp> = iter::once(state[0])
+        let state_rev: Vec<FieldElement<F>> = iter::once(state[0].clone())
             .chain(state[1..].iter().rev().cloned())
             .collect();
         let state_ntt = ntt(&state_rev, omega);
 
-        let mut product_ntt = vec![Fp::zero(); m];
+        let mut product_ntt = vec![FieldElement::zero(); m];
         for i in 0..m {
-            product_ntt[i] = mds_ntt[i] * state_ntt[i];
+            product_ntt[i] = &mds_ntt[i] * &state_ntt[i];
         }
 
         let omega_inv = omega.inv()?;
-        let result = intt(&product_ntt, omega_inv)?;
+        let result = intt(&product_ntt, &omega_inv)?;
 
-        Ok(iter::once(result[0])
+        Ok(iter::once(result[0].clone())
             .chain(result[1..].iter().rev().cloned())
             .collect())
     }
 
     /// Performs MDS using the Karatsuba algorithm.
-    fn mds_karatsuba(&self, state: &[Fp]) -> Vec<Fp> {
+    fn mds_karatsuba(&self, state: &[FieldElement<F>]) -> Vec<FieldElement<F>> {
         let m = state.len();
-        let mds_vector = self.mds_vector.as_slice();
-        let mds_rev: Vec<Fp> = iter::once(mds_vector[0])
+        let mds_vector = self
+            .mds_vector
+            .expect("the Karatsuba method is only available for circulant MDS matrices");
+        let mds_rev: Vec<FieldElement<F>> = iter::once(mds_vector[0].clone())
             .chain(mds_vector[1..].iter().rev().cloned())
             .collect();
 
         let conv = karatsuba(&mds_rev, state);
 
-        let mut result = vec![Fp::zero(); m];
-        result[..m].copy_from_slice(&conv[..m]);
+        let mut result = vec![FieldElement::zero(); m];
+        result[..m].clone_from_slice(&conv[..m]);
         for i in m..conv.len() {
-            result[i - m] += conv[i];
+            result[i - m] = &result[i - m] + &conv[i];
         }
 
         result
     }
 
     /// Applies the MDS transformation to the state.
-    fn apply_mds(&self, state: &mut [Fp]) -> Result<(), FieldError> {
+    fn apply_mds(&self, state: &mut [FieldElement<F>]) -> Result<(), FieldError> {
         let new_state = match self.mds_method {
             MdsMethod::MatrixMultiplication => self.mds_matrix_vector_multiplication(state),
             MdsMethod::Ntt => self.mds_ntt(state)?,
             MdsMethod::Karatsuba => self.mds_karatsuba(state),
         };
-        state.copy_from_slice(&new_state);
+        state.clone_from_slice(&new_state);
         Ok(())
     }
 
     /// Adds the round constants to the state.
-    fn add_round_constants(&self, state: &mut [Fp], round: usize) {
+    fn add_round_constants(&self, state: &mut [FieldElement<F>], round: usize) {
         let m = self.m;
         let round_constants = &self.round_constants[round * 2 * m..];
 
@@ -169,13 +199,13 @@ impl RescuePrimeOptimized {
             .iter_mut()
             .zip(round_constants.iter())
             .take(m)
-            .for_each(|(state_elem, &constant)| {
-                *state_elem += constant;
+            .for_each(|(state_elem, constant)| {
+                *state_elem = &*state_elem + constant;
             });
     }
 
     /// Adds the second set of round constants to the state.
-    fn add_round_constants_second(&self, state: &mut [Fp], round: usize) {
+    fn add_round_constants_second(&self, state: &mut [FieldElement<F>], round: usize) {
         let m = self.m;
         let round_constants = &self.round_constants[round * 2 * m + m..];
 
@@ -183,15 +213,14 @@ impl RescuePrimeOptimized {
             .iter_mut()
             .zip(round_constants.iter())
             .take(m)
-            .for_each(|(state_elem, &constant)| {
-                *state_elem += constant;
+            .for_each(|(state_elem, constant)| {
+                *state_elem = &*state_elem + constant;
             });
     }
 
     /// Performs the full permutation on the state.
-    pub fn permutation(&self, state: &mut [Fp]) {
-        let num_rounds = NUM_FULL_ROUNDS;
-        for round in 0..num_rounds {
+    pub fn permutation(&self, state: &mut [FieldElement<F>]) {
+        for round in 0..self.num_rounds {
             let _ = self.apply_mds(state);
             self.add_round_constants(state, round);
             Self::apply_sbox(state);
@@ -202,39 +231,64 @@ impl RescuePrimeOptimized {
     }
 
     /// Hashes an input sequence of field elements.
-    pub fn hash(&self, input_sequence: &[Fp]) -> Vec<Fp> {
-        let mut state = vec![Fp::zero(); self.m];
+    pub fn hash(&self, input_sequence: &[FieldElement<F>]) -> Vec<FieldElement<F>> {
+        let mut state = vec![FieldElement::zero(); self.m];
         let input_len = input_sequence.len();
         if input_len % self.rate != 0 {
-            state[0] = Fp::one();
+            state[0] = FieldElement::one();
         }
         let num_full_chunks = input_len / self.rate;
         for i in 0..num_full_chunks {
             let chunk = &input_sequence[i * self.rate..(i + 1) * self.rate];
-            state[self.capacity..(self.rate + self.capacity)].copy_from_slice(&chunk[..self.rate]);
+            state[self.capacity..(self.rate + self.capacity)].clone_from_slice(&chunk[..self.rate]);
             self.permutation(&mut state);
         }
         let last_chunk_size = input_len % self.rate;
         if last_chunk_size != 0 {
-            let mut last_chunk = vec![Fp::zero(); self.rate];
+            let mut last_chunk = vec![FieldElement::zero(); self.rate];
             for j in 0..last_chunk_size {
-                last_chunk[j] = input_sequence[num_full_chunks * self.rate + j];
+                last_chunk[j] = input_sequence[num_full_chunks * self.rate + j].clone();
             }
-            last_chunk[last_chunk_size] = Fp::one();
+            last_chunk[last_chunk_size] = FieldElement::one();
             state[self.capacity..(self.rate + self.capacity)]
-                .copy_from_slice(&last_chunk[..self.rate]);
+                .clone_from_slice(&last_chunk[..self.rate]);
             self.permutation(&mut state);
         }
 
-        state[self.capacity..self.capacity + self.rate / 2].to_vec()
+        self.digest(&state)
     }
 
     /// Hashes an input sequence of bytes.
-    pub fn hash_bytes(&self, input: &[u8]) -> Vec<Fp> {
+    pub fn hash_bytes(&self, input: &[u8]) -> Vec<FieldElement<F>> {
         let field_elements = bytes_to_field_elements(input);
         self.hash(&field_elements)
     }
+
+    /// Two-to-one compression of digests, as in Miden's `merge`: the digests fill the rate,
+    /// the capacity is zero, and the output is the digest of the permuted state.
+    pub fn merge(
+        &self,
+        left: &[FieldElement<F>],
+        right: &[FieldElement<F>],
+    ) -> Vec<FieldElement<F>> {
+        assert!(left.len() + right.len() <= self.rate);
+        let mut state = vec![FieldElement::zero(); self.m];
+        for (value, input) in state[self.capacity..]
+            .iter_mut()
+            .zip(left.iter().chain(right))
+        {
+            *value = input.clone();
+        }
+        self.permutation(&mut state);
+        self.digest(&state)
+    }
+
+    /// The digest of a state: the first half of its rate.
+    fn digest(&self, state: &[FieldElement<F>]) -> Vec<FieldElement<F>> {
+        state[self.capacity..self.capacity + self.rate / 2].to_vec()
+    }
 }
+
 #[derive(Clone)]
 pub enum MdsMethod {
     /// Use standard matrix multiplication.
@@ -246,9 +300,17 @@ pub enum MdsMethod {
 }
 #[cfg(test)]
 mod tests {
+    use super::super::Fp;
     use super::*;
+    use lambdaworks_math::field::fields::fft_friendly::{
+        babybear::Babybear31PrimeField, stark_252_prime_field::Stark252PrimeField,
+    };
     use rand::rngs::StdRng;
     use rand::{Rng, SeedableRng};
+    use sha3::{
+        digest::{ExtendableOutput, Update, XofReader},
+        Shake256,
+    };
 
     // Values obtained from the Sage implemenstation in
     // https://github.com/ASDiscreteMathematics/rpo/tree/master/reference_implementation
@@ -812,4 +874,132 @@ mod tests {
         println!();
         assert_eq!(hash_result.len(), 4);
     }
+
+    fn assert_round_constants_match_shake256<F: RescuePrimeOptimizedField>(
+        security_level: SecurityLevel,
+        seed: &str,
+    ) {
+        let parameters = F::parameters(&security_level).unwrap();
+        let bytes_per_element = F::field_bit_size().div_ceil(8) + 1;
+        let mut shake = Shake256::default();
+        shake.update(seed.as_bytes());
+        let mut reader = shake.finalize_xof();
+        let round_constants: Vec<FieldElement<F>> = (0..parameters.round_constants.len())
+            .map(|_| {
+                let mut bytes = vec![0u8; bytes_per_element];
+                reader.read(&mut bytes);
+                bytes
+                    .iter()
+                    .rev()
+                    .fold(FieldElement::<F>::zero(), |acc, byte| {
+                        acc * FieldElement::<F>::from(256u64)
+                            + FieldElement::<F>::from(u64::from(*byte))
+                    })
+            })
+            .collect();
+
+        assert_eq!(round_constants, parameters.round_constants);
+    }
+
+    #[test]
+    fn round_constants_match_shake256() {
+        assert_round_constants_match_shake256::<Goldilocks64Field>(
+            SecurityLevel::Sec128,
+            "RPO(18446744069414584321,12,4,128)",
+        );
+        assert_round_constants_match_shake256::<Goldilocks64Field>(
+            SecurityLevel::Sec160,
+            "RPO(18446744069414584321,16,6,160)",
+        );
+        assert_round_constants_match_shake256::<Babybear31PrimeField>(
+            SecurityLevel::Sec128,
+            "RPO(2013265921,24,8,128)",
+        );
+        assert_round_constants_match_shake256::<Stark252PrimeField>(
+            SecurityLevel::Sec128,
+            "RPO(3618502788666131213697322783095070105623107215331596699973092056135872020481,4,2,128)",
+        );
+    }
+
+    #[test]
+    fn merge_of_two_digests_is_the_hash_of_their_concatenation() {
+        let rescue = RescuePrimeOptimized::default();
+        let left: Vec<Fp> = (0..4).map(Fp::from).collect();
+        let right: Vec<Fp> = (4..8).map(Fp::from).collect();
+
+        assert_eq!(rescue.merge(&left, &right), EXPECTED_128[7]);
+    }
+
+    #[test]
+    fn unsupported_parameters_are_rejected() {
+        assert!(RescuePrimeOptimizedOver::<Babybear31PrimeField>::new(
+            SecurityLevel::Sec160,
+            MdsMethod::MatrixMultiplication
+        )
+        .is_err());
+        assert!(RescuePrimeOptimizedOver::<Stark252PrimeField>::new(
+            SecurityLevel::Sec128,
+            MdsMethod::Ntt
+        )
+        .is_err());
+        assert!(RescuePrimeOptimizedOver::<Babybear31PrimeField>::new(
+            SecurityLevel::Sec128,
+            MdsMethod::Karatsuba
+        )
+        .is_err());
+    }
+
+    // The expected values for BabyBear and Stark252 were computed with an independent
+    // implementation in Python, which derives the Stark252 parameters with the procedures of the
+    // Rescue-Prime reference implementation.
+    #[test]
+    fn babybear_hash_and_merge() {
+        let rescue = RescuePrimeOptimizedOver::<Babybear31PrimeField>::default();
+        let input: Vec<FieldElement<Babybear31PrimeField>> =
+            (0..10).map(FieldElement::from).collect();
+
+        let expected_hash = vec![
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("57c5b8"),
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("214b9e7"),
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("859bc98"),
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("16c3c99a"),
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("16fce830"),
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("49608e1b"),
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("5e62a714"),
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("33a6cd34"),
+        ];
+        let expected_merge = vec![
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("2a4a5513"),
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("1c3c5296"),
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("513b5759"),
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("57401349"),
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("504ce0d7"),
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("58d3b6a"),
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("4ab8c64d"),
+            FieldElement::<Babybear31PrimeField>::from_hex_unchecked("102ce627"),
+        ];
+        assert_eq!(rescue.hash(&input), expected_hash);
+        assert_eq!(
+            rescue.merge(
+                &input[..8],
+                &(8..16).map(FieldElement::from).collect::<Vec<_>>()
+            ),
+            expected_merge
+        );
+    }
+
+    #[test]
+    fn stark252_hash_and_merge() {
+        let rescue = RescuePrimeOptimizedOver::<Stark252PrimeField>::default();
+        let input: Vec<FieldElement<Stark252PrimeField>> = (1..4).map(FieldElement::from).collect();
+
+        let expected_hash = vec![FieldElement::<Stark252PrimeField>::from_hex_unchecked(
+            "4db652b5e95e7baad8b4fe5e8e523c99c9e6c29a70dca23ab9ed6b0f5ea4b1e",
+        )];
+        let expected_merge = vec![FieldElement::<Stark252PrimeField>::from_hex_unchecked(
+            "5a3534bef2e0499ed4549ac6ec0c5250986bd16bbb457060234eb2e52d33e15",
+        )];
+        assert_eq!(rescue.hash(&input), expected_hash);
+        assert_eq!(rescue.merge(&input[..1], &input[1..2]), expected_merge);
+    }
 }
@@ -0,0 +1,425 @@
+use super::parameters::{RescuePrimeOptimizedField, RescuePrimeOptimizedParameters, SecurityLevel};
+use lambdaworks_math::{
+    field::{
+        element::FieldElement, fields::fft_friendly::stark_252_prime_field::Stark252PrimeField,
+    },
+    unsigned_integer::element::U256,
+};
+
+// Parameters of Rescue Prime Optimized over Stark252, with state size 4, capacity 2 for 128-bit security and a rate of 2, so that digests have 1 element.
+// Two elements of capacity are needed since a single one has less than 256 bits.
+// The S-box, the number of rounds and the MDS matrix are the ones that the Rescue-Prime reference
+// implementation (https://eprint.iacr.org/2020/1143) gives for these sizes. The S-box is x^3, the
+// smallest exponent coprime with p - 1, and the permutation has 14 rounds, the Groebner basis
+// bound of 9 rounds with a 50% margin. The MDS matrix is built from a systematic generator matrix
+// of a Reed-Solomon code with the primitive element 3, so it is MDS but not circulant.
+// The round constants are generated with SHAKE256 as in the reference implementation
+// https://github.com/ASDiscreteMathematics/rpo/tree/master/reference_implementation
+
+const ALPHA_INV_STARK252: U256 =
+    U256::from_hex_unchecked("555555555555560aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaab");
+
+const ROUND_CONSTANTS_STARK252: [FieldElement<Stark252PrimeField>; 112] = [
+    FieldElement::from_hex_unchecked(
+        "2d23971852ff3b9c40979674a49c9b3e0487fb6b749dcda756960194642822d",
+    ),
+    FieldElement::from_hex_unchecked(
+        "91eb209deabcc98f6b8bf113004324f260bb1476d17510cce98d4c146155ba",
+    ),
+    FieldElement::from_hex_unchecked(
+        "720eb0df3580cd43f92ea31c6d492a05693047296ddcc43a0f5c5d9d3d7d297",
+    ),
+    FieldElement::from_hex_unchecked(
+        "143eab51b2a7b325aa68093c489c08f7984ff10e7ebcf33105e8c0468ff91dc",
+    ),
+    FieldElement::from_hex_unchecked(
+        "3752ca82c2270b0aefd94328df602eb9cf5fa94b7dabeaea64de2432c169c48",
+    ),
+    FieldElement::from_hex_unchecked(
+        "5269283aaa0e345486fa574991efca1178125552b6e3706b4f9899d9eccf252",
+    ),
+    FieldElement::from_hex_unchecked(
+        "1728f054a0105d1cab02f758dbf65dd39c596b7bed2b7ab6dc4686be4c93548",
+    ),
+    FieldElement::from_hex_unchecked(
+        "3b02181daa17a80d5d81e18ed9fcd2fa36fa283f5820039a8d0d28de2961525",
+    ),
+    FieldElement::from_hex_unchecked(
+        "1ab57efb0a554c6f06d82814280020c3bf412fc0b470ac727b21cc4cb9e4edd",
+    ),
+    FieldElement::from_hex_unchecked(
+        "71adce087cd0f158adbd18f56d82b6bd907f62e7bdb43b607211f630c586d9c",
+    ),
+    FieldElement::from_hex_unchecked(
+        "155b342e0f50b09379a7507be9fbbb73fa34317ed744b41d4aa620be6fff22b",
+    ),
+    FieldElement::from_hex_unchecked(
+        "3bef17038b69d914af87080f8a3b782c0e111ae63eb8366bd5b1dd8ff1cd194",
+    ),
+    FieldElement::from_hex_unchecked(
+        "4cfcde1cfff3b2f214602b6f07857d4c0cb12578569d8cdd1c557fcaa9df803",
+    ),
+    FieldElement::from_hex_unchecked(
+        "3bbe714ac13e7ebde0abfc6c86f5a0e077ac38d894314c32e3986e7d6155a8e",
+    ),
+    FieldElement::from_hex_unchecked(
+        "51a55a5d6eb5f3ab0cf7ee48755a48ed85f34ccec97d71062d98a586f018bed",
+    ),
+    FieldElement::from_hex_unchecked(
+        "200ccb4012cd4ae9b222b8194e05dd2fbb6e766d98ca34867c28a2c0774f620",
+    ),
+    FieldElement::from_hex_unchecked(
+        "375ea15bd1f993a51403bbc2cbd9ec834c176e22ef5bcb7e3c81ec95d856ec",
+    ),
+    FieldElement::from_hex_unchecked(
+        "6eb3c466a2948985103cc13e044e0fe309f995699083ddd1a6d25b903f59b74",
+    ),
+    FieldElement::from_hex_unchecked(
+        "5292bfac35d200c55d1aa03184caa064218de21aaa2e12fe0f1946e609d0c8d",
+    ),
+    FieldElement::from_hex_unchecked(
+        "517a5f1a8d725c4670f3e131eaa6289231a3cd1f85667589aca1a6e044a4eac",
+    ),
+    FieldElement::from_hex_unchecked(
+        "6d63e5da9ce17a2577899b065461ea2930a4ada14b2a0396e827a02e837c99c",
+    ),
+    FieldElement::from_hex_unchecked(
+        "1ea478f0c77f1c4e4c774281f36df1bad5733f46e71fa1d4cd646614c013ccc",
+    ),
+    FieldElement::from_hex_unchecked(
+        "524f3a7a0e94e47a5bdad31afc77112877d7499bd738412cf488b9138b5a1e7",
+    ),
+    FieldElement::from_hex_unchecked(
+        "4c36a969bffc5c49fd14790060294d425744560cc5f40a2e221f7fd349358d8",
+    ),
+    FieldElement::from_hex_unchecked(
+        "61eb49c784b431db7d51ca1271f2939441a63b056c2d537dd914aa5804cb3c7",
+    ),
+    FieldElement::from_hex_unchecked(
+        "414d2000c1f09fa2d556ed9c56a517188fdd7bc3c3d347857b3d78a1bbe46fd",
+    ),
+    FieldElement::from_hex_unchecked(
+        "603ebe0f21ed2b1423285e24fed9bdc58c873a4789c38adb76548ace96f31f2",
+    ),
+    FieldElement::from_hex_unchecked(
+        "734fb6752f2e4e2576ec28ceeda951107f01d5ee7010d9d6ef014c551b6312c",
+    ),
+    FieldElement::from_hex_unchecked(
+        "652f7c462c00f3f9b7b70aaef0666e65e12fcbef8e62c33a295bc072d3fbdbf",
+    ),
+    FieldElement::from_hex_unchecked(
+        "68a174559faa7b6044b6629bd069c88094ab93f571f523a0d2aa874573f1012",
+    ),
+    FieldElement::from_hex_unchecked(
+        "fa5fa1bf1fee0970fd33dbbf044b2e0c98d8a31de208b12054ae96eb288220",
+    ),
+    FieldElement::from_hex_unchecked(
+        "13553d0569d991f5a92edeac83368f3214ff67f94d103af54bcca0073a18213",
+    ),
+    FieldElement::from_hex_unchecked(
+        "99392856449dcc62a5c315f57a04a1be4ecb72b6fee36282ea028ef0dd7e0",
+    ),
+    FieldElement::from_hex_unchecked(
+        "61dece0f22b4f4dca57cd0f5bc620968ea0b8ad0ee75895ad2dbdb2c0f74e2a",
+    ),
+    FieldElement::from_hex_unchecked(
+        "447c836b7e4b34e92451c8c55bd13e35000a2257641fec2415bfa81b6f8d7cd",
+    ),
+    FieldElement::from_hex_unchecked(
+        "5e6e3be6cf5342b90a0befab5370082286d40cfd3fd0a7cd362604789eaeace",
+    ),
+    FieldElement::from_hex_unchecked(
+        "29dccb20c60a3fe5584ea4e1debe9b80555181d249c970a73a96069b69a2f95",
+    ),
+    FieldElement::from_hex_unchecked(
+        "14a6f00b4093ca155fd278167f176c0c2075bf07b09376dafcce5168d8524b5",
+    ),
+    FieldElement::from_hex_unchecked(
+        "8d87b8ccbef389a184586fb250a1d1466cebf8a03715b12e3f14435ec0af",
+    ),
+    FieldElement::from_hex_unchecked(
+        "522868f494012f7815e5bb6a326c2c0563ad21a195b18b57eea3c89d6253373",
+    ),
+    FieldElement::from_hex_unchecked(
+        "213f1f0a5f3eb312b9e496249e92b64528aca1fc3a6888b503bc9de944af272",
+    ),
+    FieldElement::from_hex_unchecked(
+        "1a5f98e3a74a91cd7be3ce6f7e5940e4f59e05ea20d077c835bde49d866a583",
+    ),
+    FieldElement::from_hex_unchecked(
+        "e2ee8859439e7d9a155b64230867b4412c99489b991aa7c739f1471e267e52",
+    ),
+    FieldElement::from_hex_unchecked(
+        "25e8017d8029acf23fc8aad6899a8a1cc2e53dcce513d8ac6c17dd3d2c00359",
+    ),
+    FieldElement::from_hex_unchecked(
+        "31f2b978c9e312b5acbcf0723da7b1ee0c93feae8084ed13c89bc9c20734a2b",
+    ),
+    FieldElement::from_hex_unchecked(
+        "4b619547b14a274864052f8ba2a9f1c971ee9ac0d06d831ae9bd9ed85001ebd",
+    ),
+    FieldElement::from_hex_unchecked(
+        "73c82fa46ca0170a7750b5b270b575b846edafe31ba49b98327a5b89f687af1",
+    ),
+    FieldElement::from_hex_unchecked(
+        "2f1b7dcbc0471674fa97d8e9f914f1635d3335d2b98c57c2b4f3f4a9e5d1b74",
+    ),
+    FieldElement::from_hex_unchecked(
+        "6eedcc8e52d6fd8f2896dd8c87bbacffd6ee335158021953efb55a2b582dd81",
+    ),
+    FieldElement::from_hex_unchecked(
+        "7bbab35991f92d312f3d604e07db1321ea64098ebf8e97aea42e0457408738a",
+    ),
+    FieldElement::from_hex_unchecked(
+        "2d485d9076c9e49461290dd95cc26832c27203ba46530d66fcb9e98e49c88f0",
+    ),
+    FieldElement::from_hex_unchecked(
+        "4b75214961375476d55280497dd0a741e61c0c4769fbfe3a5f9528edba69fb6",
+    ),
+    FieldElement::from_hex_unchecked(
+        "17cbe672e575ba1110102517aa6400596166c3ad8c392032566848bb69a0df7",
+    ),
+    FieldElement::from_hex_unchecked(
+        "73c4b959874499088a06d6fca5e3e386395493fc042cca803c19cd5d525ce7a",
+    ),
+    FieldElement::from_hex_unchecked(
+        "58ab560f34a6053a6b8555cfaefc4accce303225aa9b92ddc66e7be198f66e5",
+    ),
+    FieldElement::from_hex_unchecked(
+        "593df7e0773b63430ef2b785989183480480a66e27b7f297e6a4382da7f50d8",
+    ),
+    FieldElement::from_hex_unchecked(
+        "b268bb10285247943d866f1d97aba7ad586301f5e3548e936c557d97d3e2ef",
+    ),
+    FieldElement::from_hex_unchecked(
+        "56ea128aa66f51d9ec96c09d7d7ebad4e9eb32439d08656dc2722cd83b7b338",
+    ),
+    FieldElement::from_hex_unchecked(
+        "54ac137eefe46a01107a767832f267537e9639cd4c3eee2004f5060d085f98a",
+    ),
+    FieldElement::from_hex_unchecked(
+        "28738799a9de9e0fafbdb2702119cf087852dc010a7740952f04744c0604c03",
+    ),
+    FieldElement::from_hex_unchecked(
+        "6236b2776bfe116223dffc2aac48426ded6f874da4d4dd09ea76036b97b85de",
+    ),
+    FieldElement::from_hex_unchecked(
+        "71546429c8adb4b476317f831595b7f2c1dc84443b2e73090370d252cf334ac",
+    ),
+    FieldElement::from_hex_unchecked(
+        "5b3d3a87f2dc44ae2250a834f2b45e6e8d571df06f01316405add540ee47a44",
+    ),
+    FieldElement::from_hex_unchecked(
+        "598699f12fd58a43a6983d57e0fc88e693b558eb3f3b55fe15ca97663652994",
+    ),
+    FieldElement::from_hex_unchecked(
+        "38f0391c7a5234560b6ca2d1a8d7c8fdf442160fc12b7e77d4e32102f24231a",
+    ),
+    FieldElement::from_hex_unchecked(
+        "5f618eafb8c3b4f25bff26a5fe2472f22081012c688e8cbb1f91be15ef7afef",
+    ),
+    FieldElement::from_hex_unchecked(
+        "2537f4cbbfb966f15ea6f89ea78d784319febccdf455f387ae761592139e446",
+    ),
+    FieldElement::from_hex_unchecked(
+        "6b43efac64a46aa9d347a2b161f7a3fe6d53cf939055253650ad8c92dcb210",
+    ),
+    FieldElement::from_hex_unchecked(
+        "27b9d47b0ae8d92c066843d5399642ceb93a36543a9407718bbb748919d46a8",
+    ),
+    FieldElement::from_hex_unchecked(
+        "72d06209a5454f8642ff8c5449af05463e0a1869c5c6eb676e0093140786370",
+    ),
+    FieldElement::from_hex_unchecked(
+        "22a30b1b15552254979f2d2b72a576ea69bba33b0aa26fef567363891eb67c6",
+    ),
+    FieldElement::from_hex_unchecked(
+        "61c66d0461544cdbd8b48c5a7250cf6f226c6e7a5f72587517508d9c5c72a47",
+    ),
+    FieldElement::from_hex_unchecked(
+        "562720cb65aa68af484554677219954de06ab3774a90f3d6bfe78fff18717db",
+    ),
+    FieldElement::from_hex_unchecked(
+        "88c112e36e09d980c4dfe365ecf31dd0d4ad3ca739d9dc97fad4eb6e95c71e",
+    ),
+    FieldElement::from_hex_unchecked(
+        "2b9ef6ca815d0f95901fefba30f204af3cde6313cbc8d88d560effe90277a1f",
+    ),
+    FieldElement::from_hex_unchecked(
+        "4228257c55efdeed16b9296a139e28186aa123d7efbd01f628c3bd6d4416518",
+    ),
+    FieldElement::from_hex_unchecked(
+        "1c582850bf111bda7351710653b447e48942046e5de3bad563c350676918d71",
+    ),
+    FieldElement::from_hex_unchecked(
+        "2841f2bd8b636905d1d32666b749b37930fec9505db0a425648fc41023f8265",
+    ),
+    FieldElement::from_hex_unchecked(
+        "4ddada9be544a9ab44c9119ff6c827f1995f7eefea56af06c4e393b87f87e91",
+    ),
+    FieldElement::from_hex_unchecked(
+        "ffe8944d50dc113fa676979039af4bdc1879585107e32adbe9243cc59c70e5",
+    ),
+    FieldElement::from_hex_unchecked(
+        "3969c7b8823252eeaa84c62cde3a98e2ef19aac9eb24c507817b44639ddfebb",
+    ),
+    FieldElement::from_hex_unchecked(
+        "3ffed8904d56b580522e5524b6583fd43ddef71d72975195d31bd2f3021e7df",
+    ),
+    FieldElement::from_hex_unchecked(
+        "1ad77a37067f20c0901f7ece2b0c9e5febed280fcf268f2e01b8efd82081426",
+    ),
+    FieldElement::from_hex_unchecked(
+        "321f8669c7c69e3e4c191691e1ab7d6da236d0522bef8ad39afdf4fcf60970e",
+    ),
+    FieldElement::from_hex_unchecked(
+        "41fff1069838a71b510bd592d29313d22eccf2727fe3030bb42c6270e987842",
+    ),
+    FieldElement::from_hex_unchecked(
+        "2b9a7db636d0072f846e3b6ae0e4513a5ca242105dd30ddcc1ae3077b9651a8",
+    ),
+    FieldElement::from_hex_unchecked(
+        "32ae5dfe618d5c5b521046d475316bfbe2aa6156f758d896707dfda9a61261f",
+    ),
+    FieldElement::from_hex_unchecked(
+        "6d977345da77cbff9dc557f5a120f7cf4863c4297890e587dd273c84110094e",
+    ),
+    FieldElement::from_hex_unchecked(
+        "461e99642c0e3d1e2e42eb14319cddafabbb36865f1703fa88549e8fde9525",
+    ),
+    FieldElement::from_hex_unchecked(
+        "1458fd7df9cd40a30d9560001eee682701b518e7121edb6353315cc0ec8cb21",
+    ),
+    FieldElement::from_hex_unchecked(
+        "1fc014d96947bdbf012550c51f9104bdb5de9f768cc28de78abd29b254cb260",
+    ),
+    FieldElement::from_hex_unchecked(
+        "4840e18a82cd6b33a1eb105f081ae7c8fc676567b281f095204b9d0044697a7",
+    ),
+    FieldElement::from_hex_unchecked(
+        "2c80e0486a11b0f129d647eb91738c80b39ee1f3dfef6ffe83c2e0ecc78655f",
+    ),
+    FieldElement::from_hex_unchecked(
+        "d850a7b18e424276fdd9f86659581fba45470d7710569fd717b424889a3add",
+    ),
+    FieldElement::from_hex_unchecked(
+        "60308279c56f97df76dad9399bcc15ca3ede4f9f9946e3791472a86eef05d27",
+    ),
+    FieldElement::from_hex_unchecked(
+        "45777f09b5e9c4f2bf2bbf85c25374ab475b571257f88a134b0fb66f30e6e76",
+    ),
+    FieldElement::from_hex_unchecked(
+        "283c94a7310999f560772385d88673ebe0421824d5a4fa9747ba28ba3596bc7",
+    ),
+    FieldElement::from_hex_unchecked(
+        "36f251569bff2024d0ba095d109a8c0444be5ed7645fa3d31c0bc12803b49cc",
+    ),
+    FieldElement::from_hex_unchecked(
+        "22688c9448e63d05e1bffbc65149c5245e49f5792de6a5962f6433c132f779",
+    ),
+    FieldElement::from_hex_unchecked(
+        "46efe548af5088f5e278b991331e746c98628ef877de7ebf749ad07de2a7a7e",
+    ),
+    FieldElement::from_hex_unchecked(
+        "3a571e0fc4c3e3cf5a17b247004f26d80ee117181afd768f713244afd93a82a",
+    ),
+    FieldElement::from_hex_unchecked(
+        "622b47e65e12ec20db139d13ee280edae2eabc4153b2fd2a768cfa2681634b7",
+    ),
+    FieldElement::from_hex_unchecked(
+        "7f9c4339781b5b185ac125819a140d39873c25dad3686ca2c45fd466f4e32b0",
+    ),
+    FieldElement::from_hex_unchecked(
+        "d6629208b671067b1c3a7a16d686f07896e91e6b6944ad6439cc49759971ca",
+    ),
+    FieldElement::from_hex_unchecked(
+        "23f62c2d9a06035211755cfa62aec44c28b1b5e7cbca4f33efe5437bdf35bf1",
+    ),
+    FieldElement::from_hex_unchecked(
+        "365b234a9a0cc59307d4e77b69d529b10bd24ee57b762efe0fbb4e5d214f531",
+    ),
+    FieldElement::from_hex_unchecked(
+        "35aa770df2c3eb8ac693bf486aa514d2d0efe270a3554c87010d824047044b5",
+    ),
+    FieldElement::from_hex_unchecked(
+        "2d2bb275d450beead4cd658acbc6b1511dd6799d67e48b6a6ab406d3c8dc415",
+    ),
+    FieldElement::from_hex_unchecked(
+        "194913a1689dd22c5805586470549452e68975b03f3062296c8aad9c9372434",
+    ),
+    FieldElement::from_hex_unchecked(
+        "3c321a3324617a9314a293e3fdd00db6fa1600deb5f462b7ea0cae15e77abd7",
+    ),
+    FieldElement::from_hex_unchecked(
+        "9f0b1ad0b27c306da4211a3d1a70118b572dacfcec1b47edaeabbe755d861",
+    ),
+    FieldElement::from_hex_unchecked(
+        "6d9314207101640e1eecdcc746a6e72102b35fdd139d09173ef8a28a6b82c6b",
+    ),
+];
+
+const MDS_MATRIX_STARK252: [[FieldElement<Stark252PrimeField>; 4]; 4] = [
+    [
+        FieldElement::from_hex_unchecked(
+            "800000000000010fffffffffffffffffffffffffffffffffffffffffffffd28",
+        ),
+        FieldElement::from_hex_unchecked("438"),
+        FieldElement::from_hex_unchecked(
+            "800000000000010fffffffffffffffffffffffffffffffffffffffffffffe7b",
+        ),
+        FieldElement::from_hex_unchecked("28"),
+    ],
+    [
+        FieldElement::from_hex_unchecked(
+            "800000000000010ffffffffffffffffffffffffffffffffffffffffffff8e19",
+        ),
+        FieldElement::from_hex_unchecked("a5e7"),
+        FieldElement::from_hex_unchecked(
+            "800000000000010ffffffffffffffffffffffffffffffffffffffffffffc749",
+        ),
+        FieldElement::from_hex_unchecked("4ba"),
+    ],
+    [
+        FieldElement::from_hex_unchecked(
+            "800000000000010fffffffffffffffffffffffffffffffffffffffffff28a57",
+        ),
+        FieldElement::from_hex_unchecked("137ec8"),
+        FieldElement::from_hex_unchecked(
+            "800000000000010fffffffffffffffffffffffffffffffffffffffffff9728c",
+        ),
+        FieldElement::from_hex_unchecked("8458"),
+    ],
+    [
+        FieldElement::from_hex_unchecked(
+            "800000000000010fffffffffffffffffffffffffffffffffffffffffe872169",
+        ),
+        FieldElement::from_hex_unchecked("220dd96"),
+        FieldElement::from_hex_unchecked(
+            "800000000000010ffffffffffffffffffffffffffffffffffffffffff49e0b9",
+        ),
+        FieldElement::from_hex_unchecked("e204b"),
+    ],
+];
+
+impl RescuePrimeOptimizedField for Stark252PrimeField {
+    const ALPHA: u64 = 3;
+
+    fn inverse_sbox(x: &FieldElement<Self>) -> FieldElement<Self> {
+        x.pow(ALPHA_INV_STARK252)
+    }
+
+    fn parameters(security_level: &SecurityLevel) -> Option<RescuePrimeOptimizedParameters<Self>> {
+        match security_level {
+            SecurityLevel::Sec128 => Some(RescuePrimeOptimizedParameters {
+                state_size: 4,
+                capacity: 2,
+                num_rounds: 14,
+                round_constants: &ROUND_CONSTANTS_STARK252,
+                mds_matrix: MDS_MATRIX_STARK252.iter().map(|row| row.to_vec()).collect(),
+                mds_vector: None,
+                ntt_root: None,
+            }),
+            SecurityLevel::Sec160 => None,
+        }
+    }
+}
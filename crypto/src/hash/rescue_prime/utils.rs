@@ -1,48 +1,68 @@
-use super::Fp;
 use alloc::vec::Vec;
-use lambdaworks_math::field::errors::FieldError;
+use lambdaworks_math::field::{
+    element::FieldElement,
+    errors::FieldError,
+    traits::{IsField, IsPrimeField},
+};
 
 // Auxiliary algorithms based on the reference implementation in Sage
 // https://github.com/ASDiscreteMathematics/rpo/tree/master/reference_implementation
 
-pub fn bytes_to_field_elements(input: &[u8]) -> Vec<Fp> {
+/// Splits the input into chunks of `(F::field_bit_size() - 1) / 8` bytes, which is 7 for Goldilocks,
+/// and reads each one as a little-endian integer. A byte 1 is appended to the last chunk if it is
+/// shorter than the others.
+pub fn bytes_to_field_elements<F: IsPrimeField>(input: &[u8]) -> Vec<FieldElement<F>> {
+    let chunk_size = (F::field_bit_size() - 1) / 8;
     input
-        .chunks(7)
+        .chunks(chunk_size)
         .map(|chunk| {
-            let mut buf = [0u8; 8];
-            buf[..chunk.len()].copy_from_slice(chunk);
-            if chunk.len() < 7 {
-                buf[chunk.len()] = 1;
+            let mut bytes = chunk.to_vec();
+            if chunk.len() < chunk_size {
+                bytes.push(1);
             }
-            let value = u64::from_le_bytes(buf);
-            Fp::from(value)
+            bytes
+                .iter()
+                .rev()
+                .fold(FieldElement::<F>::zero(), |acc, byte| {
+                    acc * FieldElement::<F>::from(256u64)
+                        + FieldElement::<F>::from(u64::from(*byte))
+                })
         })
         .collect()
 }
 
-pub fn ntt(input: &[Fp], omega: Fp) -> Vec<Fp> {
+pub fn ntt<F: IsField>(input: &[FieldElement<F>], omega: &FieldElement<F>) -> Vec<FieldElement<F>> {
     (0..input.len())
         .map(|i| {
-            input.iter().enumerate().fold(Fp::zero(), |acc, (j, val)| {
-                acc + *val * omega.pow((i * j) as u64)
-            })
+            input
+                .iter()
+                .enumerate()
+                .fold(FieldElement::zero(), |acc, (j, val)| {
+                    acc + val * omega.pow((i * j) as u64)
+                })
         })
         .collect()
 }
 
-pub fn intt(input: &[Fp], omega_inv: Fp) -> Result<Vec<Fp>, FieldError> {
+pub fn intt<F: IsField>(
+    input: &[FieldElement<F>],
+    omega_inv: &FieldElement<F>,
+) -> Result<Vec<FieldElement<F>>, FieldError> {
     let n = input.len() as u64;
-    let inv_n = Fp::from(n).inv()?;
+    let inv_n = FieldElement::<F>::from(n).inv()?;
     let transformed = ntt(input, omega_inv);
-    Ok(transformed.into_iter().map(|val| val * inv_n).collect())
+    Ok(transformed.into_iter().map(|val| val * &inv_n).collect())
 }
 
-pub fn karatsuba(lhs: &[Fp], rhs: &[Fp]) -> Vec<Fp> {
+pub fn karatsuba<F: IsField>(
+    lhs: &[FieldElement<F>],
+    rhs: &[FieldElement<F>],
+) -> Vec<FieldElement<F>> {
     let n = lhs.len();
     if n <= 32 {
-        let mut result = vec![Fp::zero(); 2 * n - 1];
-        lhs.iter().enumerate().for_each(|(i, &lhs_val)| {
-            rhs.iter().enumerate().for_each(|(j, &rhs_val)| {
+        let mut result = vec![FieldElement::zero(); 2 * n - 1];
+        lhs.iter().enumerate().for_each(|(i, lhs_val)| {
+            rhs.iter().enumerate().for_each(|(j, rhs_val)| {
                 result[i + j] += lhs_val * rhs_val;
             });
         });
@@ -56,22 +76,24 @@ pub fn karatsuba(lhs: &[Fp], rhs: &[Fp]) -> Vec<Fp> {
     let z0 = karatsuba(lhs_low, rhs_low);
     let z2 = karatsuba(lhs_high, rhs_high);
 
-    let lhs_sum: Vec<Fp> = lhs_low.iter().zip(lhs_high).map(|(a, b)| *a + *b).collect();
-    let rhs_sum: Vec<Fp> = rhs_low.iter().zip(rhs_high).map(|(a, b)| *a + *b).collect();
+    let lhs_sum: Vec<FieldElement<F>> = lhs_low.iter().zip(lhs_high).map(|(a, b)| a + b).collect();
+    let rhs_sum: Vec<FieldElement<F>> = rhs_low.iter().zip(rhs_high).map(|(a, b)| a + b).collect();
 
     let z1 = karatsuba(&lhs_sum, &rhs_sum);
 
-    let mut result = vec![Fp::zero(); 2 * n - 1];
+    let mut result = vec![FieldElement::zero(); 2 * n - 1];
 
-    z0.iter().enumerate().for_each(|(i, &val)| result[i] = val);
+    z0.iter()
+        .enumerate()
+        .for_each(|(i, val)| result[i] = val.clone());
     z2.iter()
         .enumerate()
-        .for_each(|(i, &val)| result[i + 2 * half] = val);
+        .for_each(|(i, val)| result[i + 2 * half] = val.clone());
 
-    z1.iter().enumerate().for_each(|(i, &val)| {
+    z1.iter().enumerate().for_each(|(i, val)| {
         result[i + half] += val
-            - z0.get(i).cloned().unwrap_or(Fp::zero())
-            - z2.get(i).cloned().unwrap_or(Fp::zero());
+            - z0.get(i).cloned().unwrap_or(FieldElement::zero())
+            - z2.get(i).cloned().unwrap_or(FieldElement::zero());
     });
 
     result
@@ -9,3 +9,6 @@ pub mod errors;
 pub mod fiat_shamir;
 pub mod hash;
 pub mod merkle_tree;
+pub mod signatures;
+#[cfg(test)]
+mod test_utils;
@@ -1,11 +1,18 @@
-use crate::hash::poseidon::Poseidon;
+use crate::hash::{
+    monolith::MonolithMersenne31,
+    poseidon::Poseidon,
+    rescue_prime::{RescuePrimeOptimizedField, RescuePrimeOptimizedOver},
+};
 
 use crate::merkle_tree::traits::IsMerkleTreeBackend;
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 use lambdaworks_math::{
-    field::{element::FieldElement, traits::IsField},
+    field::{element::FieldElement, fields::mersenne31::field::Mersenne31Field, traits::IsField},
     traits::AsBytes,
 };
+#[cfg(feature = "parallel")]
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 use sha3::{
     digest::{generic_array::GenericArray, OutputSizeUser},
     Digest,
@@ -75,15 +82,100 @@ where
     }
 }
 
+/// Merkle tree backend for field elements of Mersenne31 hashed with Monolith.
+/// Nodes are digests of `DIGEST_SIZE` elements, and parents are built with the two-to-one
+/// compression.
+#[derive(Clone, Default)]
+pub struct TreeMonolith<const WIDTH: usize, const NUM_FULL_ROUNDS: usize>;
+
+impl<const WIDTH: usize, const NUM_FULL_ROUNDS: usize> IsMerkleTreeBackend
+    for TreeMonolith<WIDTH, NUM_FULL_ROUNDS>
+{
+    type Node = Vec<FieldElement<Mersenne31Field>>;
+    type Data = FieldElement<Mersenne31Field>;
+
+    fn hash_data(input: &FieldElement<Mersenne31Field>) -> Vec<FieldElement<Mersenne31Field>> {
+        MonolithMersenne31::<WIDTH, NUM_FULL_ROUNDS>::new().hash(input)
+    }
+
+    fn hash_new_parent(
+        left: &Vec<FieldElement<Mersenne31Field>>,
+        right: &Vec<FieldElement<Mersenne31Field>>,
+    ) -> Vec<FieldElement<Mersenne31Field>> {
+        MonolithMersenne31::<WIDTH, NUM_FULL_ROUNDS>::new().compress(left, right)
+    }
+}
+
+/// Merkle tree backend for field elements hashed with Rescue Prime Optimized.
+/// The instance is the default one of the field, and parents are built with `merge`.
+#[derive(Clone)]
+pub struct TreeRescuePrimeOptimized<F: RescuePrimeOptimizedField> {
+    _field: PhantomData<F>,
+}
+
+impl<F: RescuePrimeOptimizedField> Default for TreeRescuePrimeOptimized<F> {
+    fn default() -> Self {
+        Self {
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F> IsMerkleTreeBackend for TreeRescuePrimeOptimized<F>
+where
+    F: RescuePrimeOptimizedField,
+    FieldElement<F>: Sync + Send,
+{
+    type Node = Vec<FieldElement<F>>;
+    type Data = FieldElement<F>;
+
+    fn hash_data(input: &FieldElement<F>) -> Vec<FieldElement<F>> {
+        let rescue = RescuePrimeOptimizedOver::<F>::default();
+        rescue.hash(core::slice::from_ref(input))
+    }
+
+    /// Builds a single instance of the hash for all the leaves.
+    fn hash_leaves(unhashed_leaves: &[FieldElement<F>]) -> Vec<Vec<FieldElement<F>>> {
+        let rescue = RescuePrimeOptimizedOver::<F>::default();
+        #[cfg(feature = "parallel")]
+        let iter = unhashed_leaves.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let iter = unhashed_leaves.iter();
+
+        iter.map(|leaf| rescue.hash(core::slice::from_ref(leaf)))
+            .collect()
+    }
+
+    fn hash_new_parent(
+        left: &Vec<FieldElement<F>>,
+        right: &Vec<FieldElement<F>>,
+    ) -> Vec<FieldElement<F>> {
+        RescuePrimeOptimizedOver::<F>::default().merge(left, right)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use alloc::vec::Vec;
+    use blake2::Blake2s256;
     use lambdaworks_math::field::{
-        element::FieldElement, fields::fft_friendly::stark_252_prime_field::Stark252PrimeField,
+        element::FieldElement,
+        fields::{
+            fft_friendly::stark_252_prime_field::Stark252PrimeField,
+            mersenne31::field::Mersenne31Field, u64_goldilocks_field::Goldilocks64Field,
+        },
     };
     use sha3::{Keccak256, Keccak512, Sha3_256, Sha3_512};
 
-    use crate::merkle_tree::{backends::field_element::FieldElementBackend, merkle::MerkleTree};
+    use crate::{
+        hash::blake3::Blake3,
+        merkle_tree::{
+            backends::field_element::{
+                FieldElementBackend, TreeMonolith, TreeRescuePrimeOptimized,
+            },
+            merkle::MerkleTree,
+        },
+    };
 
     type F = Stark252PrimeField;
     type FE = FieldElement<F>;
@@ -114,6 +206,36 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn hash_data_field_element_backend_works_with_blake2s_256() {
+        let values: Vec<FE> = (1..6).map(FE::from).collect();
+        let merkle_tree =
+            MerkleTree::<FieldElementBackend<F, Blake2s256, 32>>::build(&values).unwrap();
+        let proof = merkle_tree.get_proof_by_pos(3).unwrap();
+        assert!(proof.verify::<FieldElementBackend<F, Blake2s256, 32>>(
+            &merkle_tree.root,
+            3,
+            &values[3]
+        ));
+    }
+
+    #[test]
+    fn hash_data_field_element_backend_works_with_blake3() {
+        let values: Vec<FE> = (1..6).map(FE::from).collect();
+        let merkle_tree = MerkleTree::<FieldElementBackend<F, Blake3, 32>>::build(&values).unwrap();
+        let proof = merkle_tree.get_proof_by_pos(3).unwrap();
+        assert!(proof.verify::<FieldElementBackend<F, Blake3, 32>>(
+            &merkle_tree.root,
+            3,
+            &values[3]
+        ));
+        assert!(!proof.verify::<FieldElementBackend<F, Blake3, 32>>(
+            &merkle_tree.root,
+            3,
+            &values[2]
+        ));
+    }
+
     #[test]
     fn hash_data_field_element_backend_works_with_keccak_512() {
         let values: Vec<FE> = (1..6).map(FE::from).collect();
@@ -139,4 +261,29 @@ mod tests {
             &values[0]
         ));
     }
+
+    #[test]
+    fn tree_monolith_backend_works() {
+        let values: Vec<FieldElement<Mersenne31Field>> = (1..6).map(FieldElement::from).collect();
+        let merkle_tree = MerkleTree::<TreeMonolith<16, 5>>::build(&values).unwrap();
+        for (pos, value) in values.iter().enumerate() {
+            let proof = merkle_tree.get_proof_by_pos(pos).unwrap();
+            assert!(proof.verify::<TreeMonolith<16, 5>>(&merkle_tree.root, pos, value));
+        }
+        let proof = merkle_tree.get_proof_by_pos(0).unwrap();
+        assert!(!proof.verify::<TreeMonolith<16, 5>>(&merkle_tree.root, 0, &values[1]));
+    }
+
+    #[test]
+    fn tree_rescue_prime_optimized_backend_works() {
+        type Backend = TreeRescuePrimeOptimized<Goldilocks64Field>;
+        let values: Vec<FieldElement<Goldilocks64Field>> = (1..6).map(FieldElement::from).collect();
+        let merkle_tree = MerkleTree::<Backend>::build(&values).unwrap();
+        for (pos, value) in values.iter().enumerate() {
+            let proof = merkle_tree.get_proof_by_pos(pos).unwrap();
+            assert!(proof.verify::<Backend>(&merkle_tree.root, pos, value));
+        }
+        let proof = merkle_tree.get_proof_by_pos(0).unwrap();
+        assert!(!proof.verify::<Backend>(&merkle_tree.root, 0, &values[1]));
+    }
 }
@@ -1,12 +1,17 @@
 use core::marker::PhantomData;
 
+use crate::hash::monolith::{IsMersenne31Field, MonolithMersenne31, DIGEST_SIZE};
 use crate::hash::poseidon::Poseidon;
+use crate::hash::poseidon2::Poseidon2;
+use crate::hash::rescue_prime::{RescuePrimeOptimizedField, RescuePrimeOptimizedOver};
 use crate::merkle_tree::traits::IsMerkleTreeBackend;
 use alloc::vec::Vec;
 use lambdaworks_math::{
-    field::{element::FieldElement, traits::IsField},
+    field::{element::FieldElement, fields::mersenne31::field::Mersenne31Field, traits::IsField},
     traits::AsBytes,
 };
+#[cfg(feature = "parallel")]
+use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
 use sha3::{
     digest::{generic_array::GenericArray, OutputSizeUser},
     Digest,
@@ -84,16 +89,193 @@ where
     }
 }
 
+/// Merkle tree backend for vectors of field elements hashed with Poseidon2. Leaves are hashed
+/// with the sponge and parents with the two-to-one compression, so nodes are digests of
+/// `P::CAPACITY` field elements.
+#[derive(Clone, Default)]
+pub struct BatchPoseidon2Tree<P: Poseidon2 + Default> {
+    _poseidon2: PhantomData<P>,
+}
+
+impl<P> IsMerkleTreeBackend for BatchPoseidon2Tree<P>
+where
+    P: Poseidon2 + Default,
+    Vec<FieldElement<P::F>>: Sync + Send,
+{
+    type Node = Vec<FieldElement<P::F>>;
+    type Data = Vec<FieldElement<P::F>>;
+
+    fn hash_data(input: &Vec<FieldElement<P::F>>) -> Vec<FieldElement<P::F>> {
+        P::hash_many(input)
+    }
+
+    fn hash_new_parent(
+        left: &Vec<FieldElement<P::F>>,
+        right: &Vec<FieldElement<P::F>>,
+    ) -> Vec<FieldElement<P::F>> {
+        P::compress(left, right)
+    }
+}
+
+/// Merkle tree backend for vectors of field elements of Mersenne31 hashed with the Monolith sponge.
+/// Nodes are digests of `DIGEST_SIZE` elements, and parents are built with the two-to-one
+/// compression.
+#[derive(Clone, Default)]
+pub struct BatchMonolithTree<const WIDTH: usize, const NUM_FULL_ROUNDS: usize>;
+
+impl<const WIDTH: usize, const NUM_FULL_ROUNDS: usize> IsMerkleTreeBackend
+    for BatchMonolithTree<WIDTH, NUM_FULL_ROUNDS>
+{
+    type Node = Vec<FieldElement<Mersenne31Field>>;
+    type Data = Vec<FieldElement<Mersenne31Field>>;
+
+    fn hash_data(input: &Vec<FieldElement<Mersenne31Field>>) -> Vec<FieldElement<Mersenne31Field>> {
+        MonolithMersenne31::<WIDTH, NUM_FULL_ROUNDS>::new().hash_many(input)
+    }
+
+    fn hash_new_parent(
+        left: &Vec<FieldElement<Mersenne31Field>>,
+        right: &Vec<FieldElement<Mersenne31Field>>,
+    ) -> Vec<FieldElement<Mersenne31Field>> {
+        MonolithMersenne31::<WIDTH, NUM_FULL_ROUNDS>::new().compress(left, right)
+    }
+}
+
+/// Merkle tree backend for vectors of elements of Mersenne31 or its extensions hashed with the
+/// Monolith sponge over their coordinates. Nodes are the digests encoded as little-endian bytes,
+/// which is the kind of node the STARK prover commits with.
+#[derive(Clone)]
+pub struct BatchMonolithBytesTree<F, const WIDTH: usize, const NUM_FULL_ROUNDS: usize> {
+    _field: PhantomData<F>,
+}
+
+impl<F, const WIDTH: usize, const NUM_FULL_ROUNDS: usize> Default
+    for BatchMonolithBytesTree<F, WIDTH, NUM_FULL_ROUNDS>
+{
+    fn default() -> Self {
+        Self {
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F, const WIDTH: usize, const NUM_FULL_ROUNDS: usize>
+    BatchMonolithBytesTree<F, WIDTH, NUM_FULL_ROUNDS>
+{
+    fn to_bytes(digest: &[FieldElement<Mersenne31Field>]) -> [u8; 4 * DIGEST_SIZE] {
+        let mut bytes = [0; 4 * DIGEST_SIZE];
+        for (chunk, element) in bytes.chunks_exact_mut(4).zip(digest) {
+            chunk.copy_from_slice(&element.representative().to_le_bytes());
+        }
+        bytes
+    }
+
+    fn from_bytes(bytes: &[u8; 4 * DIGEST_SIZE]) -> Vec<FieldElement<Mersenne31Field>> {
+        bytes
+            .chunks_exact(4)
+            .map(|chunk| FieldElement::from(u32::from_le_bytes(chunk.try_into().unwrap()) as u64))
+            .collect()
+    }
+}
+
+impl<F, const WIDTH: usize, const NUM_FULL_ROUNDS: usize> IsMerkleTreeBackend
+    for BatchMonolithBytesTree<F, WIDTH, NUM_FULL_ROUNDS>
+where
+    F: IsMersenne31Field,
+    FieldElement<F>: Sync + Send,
+{
+    type Node = [u8; 4 * DIGEST_SIZE];
+    type Data = Vec<FieldElement<F>>;
+
+    fn hash_data(input: &Vec<FieldElement<F>>) -> [u8; 4 * DIGEST_SIZE] {
+        let mut coordinates = Vec::with_capacity(input.len());
+        for element in input {
+            F::push_coordinates(element, &mut coordinates);
+        }
+        Self::to_bytes(&MonolithMersenne31::<WIDTH, NUM_FULL_ROUNDS>::new().hash_many(&coordinates))
+    }
+
+    fn hash_new_parent(
+        left: &[u8; 4 * DIGEST_SIZE],
+        right: &[u8; 4 * DIGEST_SIZE],
+    ) -> [u8; 4 * DIGEST_SIZE] {
+        let digest = MonolithMersenne31::<WIDTH, NUM_FULL_ROUNDS>::new()
+            .compress(&Self::from_bytes(left), &Self::from_bytes(right));
+        Self::to_bytes(&digest)
+    }
+}
+
+/// Merkle tree backend for vectors of field elements hashed with Rescue Prime Optimized.
+/// The instance is the default one of the field, and parents are built with `merge`.
+#[derive(Clone)]
+pub struct BatchRescuePrimeOptimizedTree<F: RescuePrimeOptimizedField> {
+    _field: PhantomData<F>,
+}
+
+impl<F: RescuePrimeOptimizedField> Default for BatchRescuePrimeOptimizedTree<F> {
+    fn default() -> Self {
+        Self {
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<F> IsMerkleTreeBackend for BatchRescuePrimeOptimizedTree<F>
+where
+    F: RescuePrimeOptimizedField,
+    FieldElement<F>: Sync + Send,
+{
+    type Node = Vec<FieldElement<F>>;
+    type Data = Vec<FieldElement<F>>;
+
+    fn hash_data(input: &Vec<FieldElement<F>>) -> Vec<FieldElement<F>> {
+        let rescue = RescuePrimeOptimizedOver::<F>::default();
+        rescue.hash(input)
+    }
+
+    /// Builds a single instance of the hash for all the leaves.
+    fn hash_leaves(unhashed_leaves: &[Vec<FieldElement<F>>]) -> Vec<Vec<FieldElement<F>>> {
+        let rescue = RescuePrimeOptimizedOver::<F>::default();
+        #[cfg(feature = "parallel")]
+        let iter = unhashed_leaves.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let iter = unhashed_leaves.iter();
+
+        iter.map(|leaf| rescue.hash(leaf)).collect()
+    }
+
+    fn hash_new_parent(
+        left: &Vec<FieldElement<F>>,
+        right: &Vec<FieldElement<F>>,
+    ) -> Vec<FieldElement<F>> {
+        RescuePrimeOptimizedOver::<F>::default().merge(left, right)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use blake2::Blake2s256;
     use lambdaworks_math::field::{
-        element::FieldElement, fields::fft_friendly::stark_252_prime_field::Stark252PrimeField,
+        element::FieldElement,
+        fields::{
+            fft_friendly::{
+                babybear::Babybear31PrimeField, stark_252_prime_field::Stark252PrimeField,
+            },
+            mersenne31::{extensions::Degree2ExtensionField, field::Mersenne31Field},
+        },
     };
     use sha2::Sha512;
     use sha3::{Keccak256, Keccak512, Sha3_256, Sha3_512};
 
-    use crate::merkle_tree::{
-        backends::field_element_vector::FieldElementVectorBackend, merkle::MerkleTree,
+    use crate::{
+        hash::{blake3::Blake3, poseidon2::babybear::Poseidon2BabyBearWidth16},
+        merkle_tree::{
+            backends::field_element_vector::{
+                BatchMonolithBytesTree, BatchMonolithTree, BatchPoseidon2Tree,
+                BatchRescuePrimeOptimizedTree, FieldElementVectorBackend,
+            },
+            merkle::MerkleTree,
+        },
     };
 
     type F = Stark252PrimeField;
@@ -165,6 +347,52 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn hash_data_field_element_backend_works_with_blake2s256() {
+        let values = [
+            vec![FE::from(2u64), FE::from(11u64)],
+            vec![FE::from(3u64), FE::from(14u64)],
+            vec![FE::from(4u64), FE::from(7u64)],
+            vec![FE::from(5u64), FE::from(3u64)],
+            vec![FE::from(6u64), FE::from(5u64)],
+            vec![FE::from(7u64), FE::from(16u64)],
+            vec![FE::from(8u64), FE::from(19u64)],
+            vec![FE::from(9u64), FE::from(21u64)],
+        ];
+        let merkle_tree =
+            MerkleTree::<FieldElementVectorBackend<F, Blake2s256, 32>>::build(&values).unwrap();
+        let proof = merkle_tree.get_proof_by_pos(5).unwrap();
+        assert!(
+            proof.verify::<FieldElementVectorBackend<F, Blake2s256, 32>>(
+                &merkle_tree.root,
+                5,
+                &values[5]
+            )
+        );
+    }
+
+    #[test]
+    fn hash_data_field_element_backend_works_with_blake3() {
+        let values = [
+            vec![FE::from(2u64), FE::from(11u64)],
+            vec![FE::from(3u64), FE::from(14u64)],
+            vec![FE::from(4u64), FE::from(7u64)],
+            vec![FE::from(5u64), FE::from(3u64)],
+            vec![FE::from(6u64), FE::from(5u64)],
+            vec![FE::from(7u64), FE::from(16u64)],
+            vec![FE::from(8u64), FE::from(19u64)],
+            vec![FE::from(9u64), FE::from(21u64)],
+        ];
+        let merkle_tree =
+            MerkleTree::<FieldElementVectorBackend<F, Blake3, 32>>::build(&values).unwrap();
+        let proof = merkle_tree.get_proof_by_pos(5).unwrap();
+        assert!(proof.verify::<FieldElementVectorBackend<F, Blake3, 32>>(
+            &merkle_tree.root,
+            5,
+            &values[5]
+        ));
+    }
+
     #[test]
     fn hash_data_field_element_backend_works_with_keccak512() {
         let values = [
@@ -208,4 +436,113 @@ mod tests {
             &values[0]
         ));
     }
+
+    #[test]
+    fn batch_poseidon2_tree_works_over_babybear() {
+        type BabyBearFE = FieldElement<Babybear31PrimeField>;
+        let values: Vec<Vec<BabyBearFE>> = (0..8u64)
+            .map(|i| (0..10u64).map(|j| BabyBearFE::from(i * 10 + j)).collect())
+            .collect();
+        let merkle_tree =
+            MerkleTree::<BatchPoseidon2Tree<Poseidon2BabyBearWidth16>>::build(&values).unwrap();
+        for (pos, value) in values.iter().enumerate() {
+            let proof = merkle_tree.get_proof_by_pos(pos).unwrap();
+            assert!(
+                proof.verify::<BatchPoseidon2Tree<Poseidon2BabyBearWidth16>>(
+                    &merkle_tree.root,
+                    pos,
+                    value
+                )
+            );
+        }
+        assert_eq!(merkle_tree.root.len(), 8);
+
+        let proof = merkle_tree.get_proof_by_pos(0).unwrap();
+        assert!(
+            !proof.verify::<BatchPoseidon2Tree<Poseidon2BabyBearWidth16>>(
+                &merkle_tree.root,
+                0,
+                &values[1]
+            )
+        );
+    }
+
+    #[test]
+    fn batch_monolith_tree_works() {
+        type Mersenne31FE = FieldElement<Mersenne31Field>;
+        let values: Vec<Vec<Mersenne31FE>> = (0..5u64)
+            .map(|i| (0..12u64).map(|j| Mersenne31FE::from(i * 12 + j)).collect())
+            .collect();
+        let merkle_tree = MerkleTree::<BatchMonolithTree<16, 5>>::build(&values).unwrap();
+        for (pos, value) in values.iter().enumerate() {
+            let proof = merkle_tree.get_proof_by_pos(pos).unwrap();
+            assert!(proof.verify::<BatchMonolithTree<16, 5>>(&merkle_tree.root, pos, value));
+        }
+        let proof = merkle_tree.get_proof_by_pos(1).unwrap();
+        assert!(!proof.verify::<BatchMonolithTree<16, 5>>(&merkle_tree.root, 1, &values[0]));
+    }
+
+    #[test]
+    fn batch_monolith_bytes_tree_matches_the_monolith_tree() {
+        type Mersenne31FE = FieldElement<Mersenne31Field>;
+        type Fp2E = FieldElement<Degree2ExtensionField>;
+        let values: Vec<Vec<Mersenne31FE>> = (0..4u64)
+            .map(|i| (0..6u64).map(|j| Mersenne31FE::from(i * 6 + j)).collect())
+            .collect();
+        let extension_values: Vec<Vec<Fp2E>> = values
+            .iter()
+            .map(|row| {
+                row.chunks(2)
+                    .map(|pair| Fp2E::new([pair[0], pair[1]]))
+                    .collect()
+            })
+            .collect();
+
+        let merkle_tree = MerkleTree::<BatchMonolithTree<16, 5>>::build(&values).unwrap();
+        let bytes_tree =
+            MerkleTree::<BatchMonolithBytesTree<Mersenne31Field, 16, 5>>::build(&values).unwrap();
+        let extension_tree =
+            MerkleTree::<BatchMonolithBytesTree<Degree2ExtensionField, 16, 5>>::build(
+                &extension_values,
+            )
+            .unwrap();
+        let root: Vec<u8> = merkle_tree
+            .root
+            .iter()
+            .flat_map(|x| x.representative().to_le_bytes())
+            .collect();
+        assert_eq!(bytes_tree.root.to_vec(), root);
+        assert_eq!(extension_tree.root, bytes_tree.root);
+
+        let proof = extension_tree.get_proof_by_pos(2).unwrap();
+        assert!(
+            proof.verify::<BatchMonolithBytesTree<Degree2ExtensionField, 16, 5>>(
+                &extension_tree.root,
+                2,
+                &extension_values[2]
+            )
+        );
+        assert!(
+            !proof.verify::<BatchMonolithBytesTree<Degree2ExtensionField, 16, 5>>(
+                &extension_tree.root,
+                2,
+                &extension_values[1]
+            )
+        );
+    }
+
+    #[test]
+    fn batch_rescue_prime_optimized_tree_works_over_babybear() {
+        type Backend = BatchRescuePrimeOptimizedTree<Babybear31PrimeField>;
+        type BabyBearFE = FieldElement<Babybear31PrimeField>;
+        let values: Vec<Vec<BabyBearFE>> = (0..6u64)
+            .map(|i| (0..20u64).map(|j| BabyBearFE::from(i * 20 + j)).collect())
+            .collect();
+        let merkle_tree = MerkleTree::<Backend>::build(&values).unwrap();
+        for (pos, value) in values.iter().enumerate() {
+            let proof = merkle_tree.get_proof_by_pos(pos).unwrap();
+            assert!(proof.verify::<Backend>(&merkle_tree.root, pos, value));
+        }
+        assert_eq!(merkle_tree.root.len(), 8);
+    }
 }
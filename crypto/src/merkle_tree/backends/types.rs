@@ -1,7 +1,13 @@
+use blake2::Blake2s256;
 use sha2::{Sha256, Sha512};
 use sha3::{Keccak256, Keccak512, Sha3_256, Sha3_512};
 
-use super::{field_element::FieldElementBackend, field_element_vector::FieldElementVectorBackend};
+use crate::hash::blake3::Blake3;
+
+use super::{
+    field_element::{FieldElementBackend, TreeMonolith},
+    field_element_vector::{BatchMonolithBytesTree, BatchMonolithTree, FieldElementVectorBackend},
+};
 
 // Field element backend definitions
 
@@ -9,6 +15,8 @@ use super::{field_element::FieldElementBackend, field_element_vector::FieldEleme
 pub type Sha3_256Backend<F> = FieldElementBackend<F, Sha3_256, 32>;
 pub type Keccak256Backend<F> = FieldElementBackend<F, Keccak256, 32>;
 pub type Sha2_256Backend<F> = FieldElementBackend<F, Sha256, 32>;
+pub type Blake2s256Backend<F> = FieldElementBackend<F, Blake2s256, 32>;
+pub type Blake3_256Backend<F> = FieldElementBackend<F, Blake3, 32>;
 
 // - With 512 bit
 pub type Sha3_512Backend<F> = FieldElementBackend<F, Sha3_512, 64>;
@@ -21,8 +29,16 @@ pub type Sha2_512Backend<F> = FieldElementBackend<F, Sha512, 64>;
 pub type BatchSha3_256Backend<F> = FieldElementVectorBackend<F, Sha3_256, 32>;
 pub type BatchKeccak256Backend<F> = FieldElementVectorBackend<F, Keccak256, 32>;
 pub type BatchSha2_256Backend<F> = FieldElementVectorBackend<F, Sha256, 32>;
+pub type BatchBlake2s256Backend<F> = FieldElementVectorBackend<F, Blake2s256, 32>;
+pub type BatchBlake3_256Backend<F> = FieldElementVectorBackend<F, Blake3, 32>;
 
 // - With 512 bit
 pub type BatchSha3_512Backend<F> = FieldElementVectorBackend<F, Sha3_512, 64>;
 pub type BatchKeccak512Backend<F> = FieldElementVectorBackend<F, Keccak512, 64>;
 pub type BatchSha2_512Backend<F> = FieldElementVectorBackend<F, Sha512, 64>;
+
+// Monolith over Mersenne31 with width 16, as in Plonky3
+
+pub type Monolith31Backend = TreeMonolith<16, 5>;
+pub type BatchMonolith31Backend = BatchMonolithTree<16, 5>;
+pub type BatchMonolith31BytesBackend<F> = BatchMonolithBytesTree<F, 16, 5>;
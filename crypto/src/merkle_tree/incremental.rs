@@ -0,0 +1,301 @@
+use alloc::{collections::VecDeque, vec::Vec};
+
+use super::{merkle::Error, traits::IsMerkleTreeBackend};
+
+/// An append-only Merkle tree of fixed `depth`, like the note commitment trees of Tornado Cash
+/// and Zcash. Leaves are appended from left to right, and the leaves that haven't been appended
+/// yet hold the node `empty_leaf`.
+///
+/// Only the frontier of the tree is stored, that is, the last left child of each level, so
+/// appending a leaf takes `depth` hashes and the tree takes `O(depth)` memory no matter how many
+/// leaves it has. The last `history_size` roots are kept, so that proofs built against a
+/// slightly outdated root can still be accepted.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "UncheckedIncrementalMerkleTree<B>",
+        bound(
+            serialize = "B::Node: serde::Serialize",
+            deserialize = "B::Node: serde::Deserialize<'de>"
+        )
+    )
+)]
+pub struct IncrementalMerkleTree<B: IsMerkleTreeBackend> {
+    pub root: B::Node,
+    /// Root of an empty subtree of each height, from the leaves to the root.
+    empty_subtrees: Vec<B::Node>,
+    /// Last left child of each level, from the leaves to the level below the root.
+    frontier: Vec<B::Node>,
+    next_index: usize,
+    /// Last roots of the tree, from the oldest to the current one.
+    root_history: VecDeque<B::Node>,
+    history_size: usize,
+}
+
+impl<B> IncrementalMerkleTree<B>
+where
+    B: IsMerkleTreeBackend,
+{
+    /// Creates an empty tree of the given depth, which must be positive and smaller than the bit
+    /// size of `usize`, that remembers its last `history_size` roots. The history size must be
+    /// positive too.
+    pub fn new(depth: usize, empty_leaf: B::Node, history_size: usize) -> Option<Self> {
+        if !Self::are_valid_parameters(depth, history_size) {
+            return None;
+        }
+
+        let empty_subtrees = Self::build_empty_subtrees(depth, empty_leaf);
+        let root = empty_subtrees[depth].clone();
+
+        Some(Self {
+            root: root.clone(),
+            frontier: empty_subtrees[..depth].to_vec(),
+            empty_subtrees,
+            next_index: 0,
+            root_history: VecDeque::from(vec![root]),
+            history_size,
+        })
+    }
+
+    fn are_valid_parameters(depth: usize, history_size: usize) -> bool {
+        depth != 0 && depth < usize::BITS as usize && history_size != 0
+    }
+
+    /// Returns the roots of the empty subtrees of each height up to `depth`.
+    fn build_empty_subtrees(depth: usize, empty_leaf: B::Node) -> Vec<B::Node> {
+        let mut empty_subtrees = Vec::with_capacity(depth + 1);
+        empty_subtrees.push(empty_leaf);
+        for height in 0..depth {
+            let empty_subtree = &empty_subtrees[height];
+            empty_subtrees.push(B::hash_new_parent(empty_subtree, empty_subtree));
+        }
+        empty_subtrees
+    }
+
+    pub fn depth(&self) -> usize {
+        self.frontier.len()
+    }
+
+    /// Number of leaves appended so far.
+    pub fn len(&self) -> usize {
+        self.next_index
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.next_index == 0
+    }
+
+    /// Appends a leaf and returns its position, or fails if the tree is full.
+    pub fn append(&mut self, value: &B::Data) -> Result<usize, Error> {
+        let leaf_index = self.next_index;
+        if leaf_index >> self.depth() != 0 {
+            return Err(Error::OutOfBounds);
+        }
+
+        let mut index = leaf_index;
+        let mut node = B::hash_data(value);
+        for (height, frontier_node) in self.frontier.iter_mut().enumerate() {
+            node = if index & 1 == 0 {
+                *frontier_node = node;
+                B::hash_new_parent(frontier_node, &self.empty_subtrees[height])
+            } else {
+                B::hash_new_parent(frontier_node, &node)
+            };
+            index >>= 1;
+        }
+
+        self.next_index += 1;
+        self.root = node;
+        if self.root_history.len() == self.history_size {
+            self.root_history.pop_front();
+        }
+        self.root_history.push_back(self.root.clone());
+
+        Ok(leaf_index)
+    }
+
+    /// Returns true if `root` is one of the last `history_size` roots of the tree.
+    pub fn is_known_root(&self, root: &B::Node) -> bool {
+        self.root_history.contains(root)
+    }
+
+    /// Returns the last roots of the tree, from the oldest to the current one.
+    pub fn root_history(&self) -> impl Iterator<Item = &B::Node> {
+        self.root_history.iter()
+    }
+}
+
+/// The fields of a serialized [`IncrementalMerkleTree`], which are checked before building the
+/// tree from them.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound(deserialize = "B::Node: serde::Deserialize<'de>"))]
+struct UncheckedIncrementalMerkleTree<B: IsMerkleTreeBackend> {
+    root: B::Node,
+    empty_subtrees: Vec<B::Node>,
+    frontier: Vec<B::Node>,
+    next_index: usize,
+    root_history: VecDeque<B::Node>,
+    history_size: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<B> TryFrom<UncheckedIncrementalMerkleTree<B>> for IncrementalMerkleTree<B>
+where
+    B: IsMerkleTreeBackend,
+{
+    type Error = &'static str;
+
+    /// Checks the parameters as [`IncrementalMerkleTree::new`] does, that there is an empty
+    /// subtree of each height up to the depth, that the tree is not fuller than it can be and that
+    /// the history holds between one and `history_size` roots, the last of which is the root.
+    fn try_from(tree: UncheckedIncrementalMerkleTree<B>) -> Result<Self, Self::Error> {
+        let depth = tree.frontier.len();
+        if !Self::are_valid_parameters(depth, tree.history_size) {
+            return Err("invalid depth or history size");
+        }
+        let empty_leaf = tree.empty_subtrees.first().ok_or("missing empty leaf")?;
+        if tree.empty_subtrees != Self::build_empty_subtrees(depth, empty_leaf.clone()) {
+            return Err("invalid empty subtrees");
+        }
+        if tree.next_index > 1 << depth {
+            return Err("more leaves than the tree can hold");
+        }
+        if tree.root_history.is_empty() || tree.root_history.len() > tree.history_size {
+            return Err("invalid number of roots in the history");
+        }
+        if tree.root_history.back() != Some(&tree.root) {
+            return Err("the root is not the last root of the history");
+        }
+
+        Ok(Self {
+            root: tree.root,
+            empty_subtrees: tree.empty_subtrees,
+            frontier: tree.frontier,
+            next_index: tree.next_index,
+            root_history: tree.root_history,
+            history_size: tree.history_size,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle_tree::{
+        backends::types::Keccak256Backend, merkle::MerkleTree, sparse_merkle::SparseMerkleTree,
+    };
+    use lambdaworks_math::{
+        field::{element::FieldElement, fields::u64_goldilocks_field::Goldilocks64Field},
+        unsigned_integer::element::U256,
+    };
+
+    type FE = FieldElement<Goldilocks64Field>;
+    type KeccakBackend = Keccak256Backend<Goldilocks64Field>;
+
+    const EMPTY: [u8; 32] = [0; 32];
+
+    #[test]
+    fn full_incremental_tree_has_the_root_of_the_dense_tree() {
+        let values: Vec<FE> = (1..9).map(FE::from).collect();
+        let mut tree = IncrementalMerkleTree::<KeccakBackend>::new(3, EMPTY, 1).unwrap();
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(tree.append(value).unwrap(), i);
+        }
+
+        let dense_tree = MerkleTree::<KeccakBackend>::build(&values).unwrap();
+        assert_eq!(tree.root, dense_tree.root);
+        assert_eq!(tree.len(), 8);
+        assert!(tree.append(&FE::one()).is_err());
+        assert_eq!(tree.len(), 8);
+    }
+
+    #[test]
+    fn leaves_not_appended_yet_are_empty() {
+        let mut tree = IncrementalMerkleTree::<KeccakBackend>::new(20, EMPTY, 1).unwrap();
+        let mut sparse_tree = SparseMerkleTree::<KeccakBackend>::new(20, EMPTY).unwrap();
+        assert!(tree.is_empty());
+        assert_eq!(tree.root, sparse_tree.root);
+
+        for i in 0..13 {
+            tree.append(&FE::from(i * i)).unwrap();
+            sparse_tree
+                .insert(U256::from_u64(i), FE::from(i * i))
+                .unwrap();
+            assert_eq!(tree.root, sparse_tree.root);
+        }
+    }
+
+    #[test]
+    fn only_the_last_roots_are_known() {
+        let mut tree = IncrementalMerkleTree::<KeccakBackend>::new(4, EMPTY, 3).unwrap();
+        let mut roots = vec![tree.root];
+        for i in 0..5 {
+            tree.append(&FE::from(i)).unwrap();
+            roots.push(tree.root);
+        }
+
+        assert!(roots[..3].iter().all(|root| !tree.is_known_root(root)));
+        assert!(roots[3..].iter().all(|root| tree.is_known_root(root)));
+        assert!(tree.root_history().eq(roots[3..].iter()));
+    }
+
+    #[test]
+    fn invalid_parameters_are_rejected() {
+        assert!(IncrementalMerkleTree::<KeccakBackend>::new(0, EMPTY, 1).is_none());
+        assert!(
+            IncrementalMerkleTree::<KeccakBackend>::new(usize::BITS as usize, EMPTY, 1).is_none()
+        );
+        assert!(IncrementalMerkleTree::<KeccakBackend>::new(32, EMPTY, 0).is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialized_tree_round_trips() {
+        let mut tree = IncrementalMerkleTree::<KeccakBackend>::new(4, EMPTY, 3).unwrap();
+        for i in 0..5 {
+            tree.append(&FE::from(i)).unwrap();
+        }
+
+        let serialized = serde_json::to_string(&tree).unwrap();
+        let mut deserialized: IncrementalMerkleTree<KeccakBackend> =
+            serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.root, tree.root);
+        assert_eq!(deserialized.len(), tree.len());
+        assert!(deserialized.root_history().eq(tree.root_history()));
+        tree.append(&FE::from(5)).unwrap();
+        deserialized.append(&FE::from(5)).unwrap();
+        assert_eq!(deserialized.root, tree.root);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn inconsistent_serialized_trees_are_rejected() {
+        let mut tree = IncrementalMerkleTree::<KeccakBackend>::new(2, EMPTY, 2).unwrap();
+        tree.append(&FE::one()).unwrap();
+        let serialized = serde_json::to_value(&tree).unwrap();
+
+        let tampered_trees = [
+            ("frontier", serde_json::json!([EMPTY])),
+            ("next_index", serde_json::json!(5)),
+            ("history_size", serde_json::json!(0)),
+            ("history_size", serde_json::json!(1)),
+            ("root_history", serde_json::json!([])),
+            ("root", serde_json::json!(EMPTY)),
+        ]
+        .map(|(field, value)| {
+            let mut tampered = serialized.clone();
+            tampered[field] = value;
+            tampered
+        });
+
+        for tampered in tampered_trees {
+            assert!(
+                serde_json::from_value::<IncrementalMerkleTree<KeccakBackend>>(tampered).is_err()
+            );
+        }
+    }
+}
@@ -1,8 +1,15 @@
 use core::fmt::Display;
 
-use alloc::vec::Vec;
+use alloc::{collections::BTreeSet, vec::Vec};
 
-use super::{proof::Proof, traits::IsMerkleTreeBackend, utils::*};
+use super::{
+    proof::{MultiProof, Proof},
+    storage::IsMerkleTreeStorage,
+    traits::IsMerkleTreeBackend,
+    utils::*,
+};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 #[derive(Debug)]
 pub enum Error {
@@ -19,13 +26,17 @@ impl std::error::Error for Error {}
 
 #[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct MerkleTree<B: IsMerkleTreeBackend> {
+pub struct MerkleTree<B: IsMerkleTreeBackend, S = Vec<<B as IsMerkleTreeBackend>::Node>> {
     pub root: B::Node,
-    nodes: Vec<B::Node>,
+    nodes: S,
 }
 
 const ROOT: usize = 0;
 
+/// Number of leaves hashed, or of parents built, between two accesses to the storage when a tree
+/// is built in a storage.
+const STORAGE_CHUNK_LEN: usize = 1 << 12;
+
 impl<B> MerkleTree<B>
 where
     B: IsMerkleTreeBackend,
@@ -54,6 +65,85 @@ where
             nodes,
         })
     }
+}
+
+impl<B, S> MerkleTree<B, S>
+where
+    B: IsMerkleTreeBackend,
+    S: IsMerkleTreeStorage<B::Node>,
+{
+    /// Builds the tree in `storage`, reading the leaves as they are hashed, so that they don't
+    /// need to be in memory at once. The leaves are completed to a power of two like in `build`,
+    /// and the storage must have exactly the room for the nodes of the completed tree, that is,
+    /// `2n - 1` nodes for `n` leaves. Its previous contents are overwritten.
+    pub fn build_in_storage<I>(unhashed_leaves: I, mut storage: S) -> Option<Self>
+    where
+        I: IntoIterator<Item = B::Data>,
+    {
+        let nodes_len = storage.len();
+        if !nodes_len.wrapping_add(1).is_power_of_two() || nodes_len == 0 {
+            return None;
+        }
+        let first_leaf = nodes_len / 2;
+        let leaves_len = nodes_len - first_leaf;
+
+        let mut unhashed_leaves = unhashed_leaves.into_iter();
+        let mut hashed_leaves_len = 0;
+        loop {
+            let chunk: Vec<B::Data> = unhashed_leaves.by_ref().take(STORAGE_CHUNK_LEN).collect();
+            if chunk.is_empty() {
+                break;
+            }
+            if hashed_leaves_len + chunk.len() > leaves_len {
+                return None;
+            }
+            for (i, leaf) in B::hash_leaves(&chunk).into_iter().enumerate() {
+                storage.set_node(first_leaf + hashed_leaves_len + i, leaf);
+            }
+            hashed_leaves_len += chunk.len();
+        }
+        if hashed_leaves_len == 0 || hashed_leaves_len.next_power_of_two() != leaves_len {
+            return None;
+        }
+
+        let last_leaf = storage.get_node(first_leaf + hashed_leaves_len - 1);
+        for pos in first_leaf + hashed_leaves_len..nodes_len {
+            storage.set_node(pos, last_leaf.clone());
+        }
+
+        let mut level_begin = first_leaf;
+        while level_begin != ROOT {
+            let parents_begin = level_begin / 2;
+            for chunk_begin in (parents_begin..level_begin).step_by(STORAGE_CHUNK_LEN) {
+                let chunk_end = (chunk_begin + STORAGE_CHUNK_LEN).min(level_begin);
+                let parents: Vec<usize> = (chunk_begin..chunk_end).collect();
+                hash_parents::<B, S>(&mut storage, &parents);
+            }
+            level_begin = parents_begin;
+        }
+
+        Some(MerkleTree {
+            root: storage.get_node(ROOT),
+            nodes: storage,
+        })
+    }
+
+    /// Returns the tree whose nodes are in `storage`, like one that was built in a file and
+    /// opened again.
+    pub fn from_storage(storage: S) -> Option<Self> {
+        if !storage.len().wrapping_add(1).is_power_of_two() || storage.is_empty() {
+            return None;
+        }
+
+        Some(MerkleTree {
+            root: storage.get_node(ROOT),
+            nodes: storage,
+        })
+    }
+
+    pub fn storage(&self) -> &S {
+        &self.nodes
+    }
 
     pub fn get_proof_by_pos(&self, pos: usize) -> Option<Proof<B::Node>> {
         let pos = pos + self.nodes.len() / 2;
@@ -64,6 +154,104 @@ where
         self.create_proof(merkle_path)
     }
 
+    /// Returns a single proof for the leaves at the given positions, which doesn't repeat the
+    /// nodes shared by their merkle paths. See `MultiProof` for the order of the nodes.
+    pub fn get_multi_proof(&self, positions: &[usize]) -> Option<MultiProof<B::Node>> {
+        let first_leaf = self.nodes.len() / 2;
+        if positions.is_empty() || positions.iter().any(|pos| *pos > first_leaf) {
+            return None;
+        }
+
+        let mut level: BTreeSet<usize> = positions.iter().map(|pos| pos + first_leaf).collect();
+        let mut nodes = Vec::new();
+        let mut depth = 0;
+        while !level.contains(&ROOT) {
+            for pos in level.iter() {
+                let sibling = sibling_index(*pos);
+                if !level.contains(&sibling) {
+                    nodes.push(self.nodes.get_node(sibling));
+                }
+            }
+            level = level.iter().map(|pos| parent_index(*pos)).collect();
+            depth += 1;
+        }
+
+        Some(MultiProof { nodes, depth })
+    }
+
+    /// Number of leaves of the tree, including the ones added by `build` to complete a power of
+    /// two.
+    pub fn leaves_len(&self) -> usize {
+        self.nodes.len() / 2 + 1
+    }
+
+    /// Returns the hash of the leaf at `pos`, or `None` if it's out of bounds. The node is returned
+    /// by value, since the storage may not hold it in memory.
+    pub fn get_leaf_hash(&self, pos: usize) -> Option<B::Node> {
+        if pos >= self.leaves_len() {
+            return None;
+        }
+        Some(self.nodes.get_node(pos + self.nodes.len() / 2))
+    }
+
+    /// Replaces the leaf at `pos` and recomputes the nodes of its merkle path.
+    pub fn update_leaf(&mut self, pos: usize, unhashed_leaf: &B::Data) -> Result<(), Error> {
+        if pos >= self.leaves_len() {
+            return Err(Error::OutOfBounds);
+        }
+
+        let mut pos = pos + self.nodes.len() / 2;
+        self.nodes.set_node(pos, B::hash_data(unhashed_leaf));
+        while pos != ROOT {
+            pos = parent_index(pos);
+            let parent = B::hash_new_parent(
+                &self.nodes.get_node(2 * pos + 1),
+                &self.nodes.get_node(2 * pos + 2),
+            );
+            self.nodes.set_node(pos, parent);
+        }
+        self.root = self.nodes.get_node(ROOT);
+
+        Ok(())
+    }
+
+    /// Replaces the leaves at the given positions and recomputes the nodes of their merkle paths,
+    /// a level at a time, so that nodes shared by several paths are hashed once. If a position is
+    /// repeated, the last of its leaves is kept. No leaf is updated if any position is out of
+    /// bounds.
+    pub fn update_leaves(&mut self, updates: &[(usize, B::Data)]) -> Result<(), Error> {
+        if updates.iter().any(|(pos, _)| *pos >= self.leaves_len()) {
+            return Err(Error::OutOfBounds);
+        }
+        if updates.is_empty() {
+            return Ok(());
+        }
+
+        let first_leaf = self.nodes.len() / 2;
+        #[cfg(feature = "parallel")]
+        let iter = updates.par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let iter = updates.iter();
+        let leaves: Vec<(usize, B::Node)> = iter
+            .map(|(pos, unhashed_leaf)| (pos + first_leaf, B::hash_data(unhashed_leaf)))
+            .collect();
+
+        let mut level = BTreeSet::new();
+        for (pos, leaf) in leaves {
+            self.nodes.set_node(pos, leaf);
+            level.insert(pos);
+        }
+
+        while !level.contains(&ROOT) {
+            level = level.iter().map(|pos| parent_index(*pos)).collect();
+            let parents: Vec<usize> = level.iter().copied().collect();
+            hash_parents::<B, S>(&mut self.nodes, &parents);
+        }
+        self.root = self.nodes.get_node(ROOT);
+
+        Ok(())
+    }
+
     fn create_proof(&self, merkle_path: Vec<B::Node>) -> Option<Proof<B::Node>> {
         Some(Proof { merkle_path })
     }
@@ -73,11 +261,12 @@ where
         let mut pos = pos;
 
         while pos != ROOT {
-            let Some(node) = self.nodes.get(sibling_index(pos)) else {
+            let sibling = sibling_index(pos);
+            if sibling >= self.nodes.len() {
                 // out of bounds, exit returning the current merkle_path
                 return Err(Error::OutOfBounds);
-            };
-            merkle_path.push(node.clone());
+            }
+            merkle_path.push(self.nodes.get_node(sibling));
 
             pos = parent_index(pos);
         }
@@ -85,12 +274,41 @@ where
         Ok(merkle_path)
     }
 }
+
+/// Recomputes the given nodes from their children, which must be up to date.
+fn hash_parents<B, S>(storage: &mut S, parents: &[usize])
+where
+    B: IsMerkleTreeBackend,
+    S: IsMerkleTreeStorage<B::Node>,
+{
+    let children: Vec<(B::Node, B::Node)> = parents
+        .iter()
+        .map(|pos| (storage.get_node(2 * pos + 1), storage.get_node(2 * pos + 2)))
+        .collect();
+
+    #[cfg(feature = "parallel")]
+    let iter = children.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let iter = children.iter();
+    let new_parents: Vec<B::Node> = iter
+        .map(|(left, right)| B::hash_new_parent(left, right))
+        .collect();
+
+    for (pos, parent) in parents.iter().zip(new_parents) {
+        storage.set_node(*pos, parent);
+    }
+}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use lambdaworks_math::field::{element::FieldElement, fields::u64_prime_field::U64PrimeField};
+    use lambdaworks_math::field::{
+        element::FieldElement,
+        fields::{u64_goldilocks_field::Goldilocks64Field, u64_prime_field::U64PrimeField},
+    };
 
-    use crate::merkle_tree::{merkle::MerkleTree, test_merkle::TestBackend};
+    use crate::merkle_tree::{
+        backends::types::Keccak256Backend, merkle::MerkleTree, test_merkle::TestBackend,
+    };
 
     const MODULUS: u64 = 13;
     type U64PF = U64PrimeField<MODULUS>;
@@ -130,4 +348,69 @@ mod tests {
     fn build_empty_tree_should_not_panic() {
         assert!(MerkleTree::<TestBackend<U64PF>>::build(&[]).is_none());
     }
+
+    #[test]
+    fn build_merkle_tree_in_a_storage_from_an_iterator() {
+        let values: Vec<FE> = (1..6).map(FE::new).collect();
+        let merkle_tree = MerkleTree::<TestBackend<U64PF>>::build(&values).unwrap();
+
+        let storage = vec![FE::zero(); 15];
+        let stored_tree =
+            MerkleTree::<TestBackend<U64PF>>::build_in_storage((1..6).map(FE::new), storage)
+                .unwrap();
+        assert_eq!(stored_tree.root, merkle_tree.root);
+        assert_eq!(stored_tree.nodes, merkle_tree.nodes);
+
+        for (leaves_len, storage_len) in [(5, 31), (5, 7), (0, 1), (1, 2)] {
+            assert!(MerkleTree::<TestBackend<U64PF>>::build_in_storage(
+                (0..leaves_len).map(FE::new),
+                vec![FE::zero(); storage_len]
+            )
+            .is_none());
+        }
+    }
+
+    #[test]
+    // leaves | 1 | 2 | 9 | 4 |, hashed to | 2 | 4 | 5 | 8 |, root 2 + 4 + 5 + 8 = 19 = 6 mod 13
+    fn update_a_leaf_of_a_merkle_tree() {
+        let values: Vec<FE> = (1..5).map(FE::new).collect();
+        let mut merkle_tree = MerkleTree::<TestBackend<U64PF>>::build(&values).unwrap();
+
+        merkle_tree.update_leaf(2, &FE::new(9)).unwrap();
+        assert_eq!(merkle_tree.get_leaf_hash(2), Some(FE::new(5)));
+        assert_eq!(merkle_tree.root, FE::new(6));
+        assert!(merkle_tree.update_leaf(4, &FE::new(9)).is_err());
+        assert_eq!(merkle_tree.get_leaf_hash(4), None);
+    }
+
+    #[test]
+    fn updating_leaves_gives_the_tree_built_from_the_new_leaves() {
+        type FE = FieldElement<Goldilocks64Field>;
+        type Backend = Keccak256Backend<Goldilocks64Field>;
+
+        let mut values: Vec<FE> = (0..13).map(FE::from).collect();
+        let mut merkle_tree = MerkleTree::<Backend>::build(&values).unwrap();
+        assert_eq!(merkle_tree.leaves_len(), 16);
+
+        let updates: Vec<(usize, FE)> = [(3, 30), (12, 120), (4, 40), (3, 31), (15, 150)]
+            .iter()
+            .map(|(pos, value)| (*pos, FE::from(*value)))
+            .collect();
+        merkle_tree.update_leaves(&updates).unwrap();
+
+        values.extend([FE::from(12), FE::from(12), FE::from(150)]);
+        for (pos, value) in updates.iter() {
+            values[*pos] = *value;
+        }
+        let rebuilt_tree = MerkleTree::<Backend>::build(&values).unwrap();
+        assert_eq!(merkle_tree.root, rebuilt_tree.root);
+        assert_eq!(merkle_tree.nodes, rebuilt_tree.nodes);
+
+        let root = merkle_tree.root;
+        assert!(merkle_tree
+            .update_leaves(&[(0, FE::one()), (16, FE::one())])
+            .is_err());
+        assert_eq!(merkle_tree.root, root);
+        assert_eq!(merkle_tree.get_leaf_hash(0), rebuilt_tree.get_leaf_hash(0));
+    }
 }
@@ -0,0 +1,318 @@
+use std::{
+    fs::{File, OpenOptions},
+    io,
+    path::Path,
+};
+
+use lambdaworks_math::{
+    field::{element::FieldElement, traits::IsField},
+    traits::ByteConversion,
+};
+use memmap2::MmapMut;
+
+use super::storage::IsMerkleTreeStorage;
+
+/// A node that is always encoded in the same number of bytes, so that it can be stored at a fixed
+/// offset of a file.
+pub trait FixedSizeNode: Sized {
+    /// Number of bytes of the encoding of a node.
+    fn byte_len() -> usize;
+
+    fn write_bytes(&self, bytes: &mut [u8]);
+
+    /// Decodes a node, or returns `None` if `bytes` isn't the encoding of a node.
+    fn read_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+impl<const N: usize> FixedSizeNode for [u8; N] {
+    fn byte_len() -> usize {
+        N
+    }
+
+    fn write_bytes(&self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(self);
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<Self> {
+        bytes.try_into().ok()
+    }
+}
+
+impl<F: IsField> FixedSizeNode for FieldElement<F>
+where
+    FieldElement<F>: ByteConversion,
+{
+    fn byte_len() -> usize {
+        Self::zero().to_bytes_be().len()
+    }
+
+    fn write_bytes(&self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(&self.to_bytes_be());
+    }
+
+    /// Only canonical encodings are accepted, since `from_bytes_be` may reduce the value.
+    fn read_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_bytes_be(bytes)
+            .ok()
+            .filter(|node| node.to_bytes_be() == bytes)
+    }
+}
+
+/// Stores the nodes of a `MerkleTree` in a memory-mapped file, so that the operating system pages
+/// them in and out of memory as needed and the tree can be larger than the memory.
+///
+/// Every proof reads one node of each level, so a copy of the nodes of the top `cached_levels`
+/// levels is also kept in memory. Writes go both to the copy and to the file, which holds every
+/// node and can be opened again once it has been flushed.
+///
+/// The file must not be modified or truncated by anyone else while the storage exists: the nodes
+/// are read straight from the mapped memory, so that would be undefined behavior.
+pub struct MmapStorage<T> {
+    map: MmapMut,
+    nodes_len: usize,
+    node_byte_len: usize,
+    /// Nodes of the top levels of the tree, from the root.
+    cache: Vec<T>,
+}
+
+impl<T> MmapStorage<T>
+where
+    T: FixedSizeNode + Clone,
+{
+    /// Creates the file at `path`, truncating it if it exists, with room for the nodes of a tree of
+    /// `leaves_len` leaves completed to a power of two. It's meant to be passed to
+    /// `MerkleTree::build_in_storage`.
+    pub fn create<P: AsRef<Path>>(
+        path: P,
+        leaves_len: usize,
+        cached_levels: usize,
+    ) -> io::Result<Self> {
+        if leaves_len == 0 {
+            return Err(invalid_input("a tree must have leaves"));
+        }
+        let nodes_len = leaves_len
+            .checked_next_power_of_two()
+            .and_then(|leaves_len| leaves_len.checked_mul(2))
+            .ok_or_else(|| invalid_input("the tree has too many leaves"))?
+            - 1;
+        let file_len = nodes_len
+            .checked_mul(T::byte_len())
+            .ok_or_else(|| invalid_input("the tree doesn't fit in a file"))?;
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        file.set_len(file_len as u64)?;
+
+        Self::map(&file, nodes_len, cached_levels)
+    }
+
+    /// Opens a file written by a storage created with `create`, after it was flushed. The nodes that
+    /// are kept in memory are decoded here, and an error is returned if any of them is invalid.
+    /// The other nodes are decoded when they are read, which panics if the file was corrupted.
+    pub fn open<P: AsRef<Path>>(path: P, cached_levels: usize) -> io::Result<Self> {
+        let file = OpenOptions::new().read(true).write(true).open(path)?;
+        let file_len = usize::try_from(file.metadata()?.len())
+            .map_err(|_| invalid_input("the file doesn't fit in memory"))?;
+
+        let node_byte_len = T::byte_len();
+        let nodes_len = file_len / node_byte_len;
+        if file_len % node_byte_len != 0 || !nodes_len.wrapping_add(1).is_power_of_two() {
+            return Err(invalid_input("the file doesn't hold the nodes of a tree"));
+        }
+
+        Self::map(&file, nodes_len, cached_levels)
+    }
+
+    /// Writes the modified nodes to the file.
+    pub fn flush(&self) -> io::Result<()> {
+        self.map.flush()
+    }
+
+    fn map(file: &File, nodes_len: usize, cached_levels: usize) -> io::Result<Self> {
+        // SAFETY: mapping a file is only sound if nothing else modifies or truncates it while it's
+        // mapped. That can't be enforced here, so it's a documented requirement of `MmapStorage`;
+        // the storage itself only writes to the file through this map.
+        let map = unsafe { MmapMut::map_mut(file)? };
+
+        let cached_nodes_len = 1usize
+            .checked_shl(cached_levels as u32)
+            .map_or(nodes_len, |len| (len - 1).min(nodes_len));
+        let mut storage = Self {
+            map,
+            nodes_len,
+            node_byte_len: T::byte_len(),
+            cache: Vec::with_capacity(cached_nodes_len),
+        };
+        storage.cache = (0..cached_nodes_len)
+            .map(|index| {
+                storage.try_read(index).ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "the file holds an invalid node")
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(storage)
+    }
+
+    fn try_read(&self, index: usize) -> Option<T> {
+        T::read_bytes(&self.map[self.node_bytes(index)])
+    }
+
+    fn read(&self, index: usize) -> T {
+        self.try_read(index)
+            .expect("the file holds an invalid node")
+    }
+
+    fn node_bytes(&self, index: usize) -> core::ops::Range<usize> {
+        assert!(index < self.nodes_len, "node index out of bounds");
+        index * self.node_byte_len..(index + 1) * self.node_byte_len
+    }
+}
+
+impl<T> IsMerkleTreeStorage<T> for MmapStorage<T>
+where
+    T: FixedSizeNode + Clone,
+{
+    fn len(&self) -> usize {
+        self.nodes_len
+    }
+
+    fn get_node(&self, index: usize) -> T {
+        match self.cache.get(index) {
+            Some(node) => node.clone(),
+            None => self.read(index),
+        }
+    }
+
+    fn set_node(&mut self, index: usize, node: T) {
+        let bytes = self.node_bytes(index);
+        node.write_bytes(&mut self.map[bytes]);
+        if let Some(cached_node) = self.cache.get_mut(index) {
+            *cached_node = node;
+        }
+    }
+}
+
+fn invalid_input(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        hash::poseidon::starknet::PoseidonCairoStark252,
+        merkle_tree::{
+            backends::{field_element::TreePoseidon, types::Keccak256Backend},
+            merkle::MerkleTree,
+        },
+    };
+    use lambdaworks_math::field::fields::{
+        fft_friendly::stark_252_prime_field::Stark252PrimeField,
+        u64_goldilocks_field::Goldilocks64Field,
+    };
+    use std::path::PathBuf;
+
+    type FE = FieldElement<Goldilocks64Field>;
+    type KeccakBackend = Keccak256Backend<Goldilocks64Field>;
+
+    /// A file in the temporary directory that is removed when dropped.
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str) -> Self {
+            let file_name = format!("lambdaworks-{}-{}", std::process::id(), name);
+            Self(std::env::temp_dir().join(file_name))
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn tree_in_a_file_is_the_tree_in_memory() {
+        let file = TempFile::new("tree_in_a_file_is_the_tree_in_memory");
+        let values: Vec<FE> = (0..1000).map(FE::from).collect();
+
+        let storage = MmapStorage::create(&file.0, values.len(), 3).unwrap();
+        let mut tree =
+            MerkleTree::<KeccakBackend, _>::build_in_storage(values.iter().copied(), storage)
+                .unwrap();
+        let mut memory_tree = MerkleTree::<KeccakBackend>::build(&values).unwrap();
+        assert_eq!(tree.root, memory_tree.root);
+
+        let proof = tree.get_proof_by_pos(999).unwrap();
+        assert!(proof.verify::<KeccakBackend>(&tree.root, 999, &FE::from(999)));
+
+        tree.update_leaves(&[(1, FE::from(7)), (998, FE::from(8))])
+            .unwrap();
+        tree.update_leaf(1023, &FE::from(9)).unwrap();
+        memory_tree
+            .update_leaves(&[(1, FE::from(7)), (998, FE::from(8)), (1023, FE::from(9))])
+            .unwrap();
+        assert_eq!(tree.root, memory_tree.root);
+        assert_eq!(tree.get_leaf_hash(998), memory_tree.get_leaf_hash(998));
+    }
+
+    #[test]
+    fn flushed_tree_can_be_opened_again() {
+        type Felt = FieldElement<Stark252PrimeField>;
+        type PoseidonBackend = TreePoseidon<PoseidonCairoStark252>;
+        let file = TempFile::new("flushed_tree_can_be_opened_again");
+
+        let storage = MmapStorage::create(&file.0, 37, 2).unwrap();
+        let tree =
+            MerkleTree::<PoseidonBackend, _>::build_in_storage((0..37).map(Felt::from), storage)
+                .unwrap();
+        let multi_proof = tree.get_multi_proof(&[3, 36]).unwrap();
+        tree.storage().flush().unwrap();
+        let root = tree.root;
+        drop(tree);
+
+        let storage = MmapStorage::<Felt>::open(&file.0, 10).unwrap();
+        assert_eq!(storage.len(), 127);
+        let tree = MerkleTree::<PoseidonBackend, _>::from_storage(storage).unwrap();
+        assert_eq!(tree.root, root);
+        assert_eq!(
+            tree.get_multi_proof(&[3, 36]).unwrap().nodes,
+            multi_proof.nodes
+        );
+        assert!(multi_proof.verify::<PoseidonBackend>(
+            &root,
+            &[3, 36],
+            &[Felt::from(3), Felt::from(36)]
+        ));
+    }
+
+    #[test]
+    fn files_without_a_tree_are_rejected() {
+        let file = TempFile::new("files_without_a_tree_are_rejected");
+        std::fs::write(&file.0, [0; 64]).unwrap();
+        assert!(MmapStorage::<[u8; 32]>::open(&file.0, 1).is_err());
+        assert!(MmapStorage::<[u8; 32]>::create(&file.0, 0, 1).is_err());
+
+        let storage = MmapStorage::<[u8; 32]>::create(&file.0, 4, 1).unwrap();
+        assert!(
+            MerkleTree::<KeccakBackend, _>::build_in_storage((0..5).map(FE::from), storage)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn files_with_invalid_nodes_are_rejected() {
+        type Felt = FieldElement<Stark252PrimeField>;
+        let file = TempFile::new("files_with_invalid_nodes_are_rejected");
+        std::fs::write(&file.0, [0xff; 32 * 7]).unwrap();
+
+        let error = MmapStorage::<Felt>::open(&file.0, 2).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(MmapStorage::<[u8; 32]>::open(&file.0, 2).is_ok());
+    }
+}
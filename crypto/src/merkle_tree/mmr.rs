@@ -0,0 +1,331 @@
+use alloc::vec::Vec;
+
+use super::traits::IsMerkleTreeBackend;
+
+/// An append-only list of leaves committed as a Merkle Mountain Range: a list of perfect Merkle
+/// trees, the mountains, whose sizes are the powers of two of the binary decomposition of the
+/// number of leaves, sorted from the largest to the smallest. Appending a leaf adds a mountain of
+/// one leaf, and then merges the last two mountains while they have the same size.
+///
+/// The root is the result of bagging the peaks of the mountains from right to left, so the root of
+/// the peaks `[P0, P1, P2]` is `H(P0, H(P1, P2))`.
+///
+/// Since nodes are never modified, the root that the range had for any previous number of leaves
+/// can be computed, and proofs can be built against it.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "B::Node: serde::Serialize",
+        deserialize = "B::Node: serde::Deserialize<'de>"
+    ))
+)]
+pub struct MerkleMountainRange<B: IsMerkleTreeBackend> {
+    /// Nodes of each height, from left to right. The first level holds the leaves.
+    levels: Vec<Vec<B::Node>>,
+}
+
+impl<B> MerkleMountainRange<B>
+where
+    B: IsMerkleTreeBackend,
+{
+    pub fn new() -> Self {
+        Self { levels: Vec::new() }
+    }
+
+    /// Number of leaves appended so far.
+    pub fn len(&self) -> usize {
+        self.levels.first().map_or(0, Vec::len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends a leaf and returns its position.
+    pub fn append(&mut self, value: &B::Data) -> usize {
+        let leaf_index = self.len();
+        let mut node = B::hash_data(value);
+        for height in 0.. {
+            if self.levels.len() == height {
+                self.levels.push(Vec::new());
+            }
+            let level = &mut self.levels[height];
+            level.push(node);
+            if level.len() % 2 == 1 {
+                break;
+            }
+            node = B::hash_new_parent(&level[level.len() - 2], &level[level.len() - 1]);
+        }
+        leaf_index
+    }
+
+    /// Returns the peaks of the mountains, from left to right.
+    pub fn peaks(&self) -> Vec<B::Node> {
+        self.peaks_at(self.len())
+    }
+
+    /// Returns the current root, or `None` if there are no leaves.
+    pub fn root(&self) -> Option<B::Node> {
+        self.root_at(self.len())
+    }
+
+    /// Returns the root that the range had when it had `leaves_count` leaves, or `None` if it
+    /// never had that many leaves or if `leaves_count` is zero.
+    pub fn root_at(&self, leaves_count: usize) -> Option<B::Node> {
+        if leaves_count > self.len() {
+            return None;
+        }
+        bag_peaks::<B>(&self.peaks_at(leaves_count))
+    }
+
+    /// Returns the proof of the leaf at `leaf_index` against the current root.
+    pub fn get_proof(&self, leaf_index: usize) -> Option<MmrProof<B::Node>> {
+        self.get_proof_at(leaf_index, self.len())
+    }
+
+    /// Returns the proof of the leaf at `leaf_index` against the root that the range had when it
+    /// had `leaves_count` leaves.
+    pub fn get_proof_at(
+        &self,
+        leaf_index: usize,
+        leaves_count: usize,
+    ) -> Option<MmrProof<B::Node>> {
+        if leaves_count > self.len() {
+            return None;
+        }
+        let (height, mountain) = find_mountain(leaf_index, leaves_count)?;
+
+        let merkle_path = (0..height)
+            .map(|level| self.levels[level][(leaf_index >> level) ^ 1].clone())
+            .collect();
+        let mut peaks = self.peaks_at(leaves_count);
+        peaks.remove(mountain);
+
+        Some(MmrProof {
+            leaves_count,
+            merkle_path,
+            peaks,
+        })
+    }
+
+    fn peaks_at(&self, leaves_count: usize) -> Vec<B::Node> {
+        mountain_heights(leaves_count)
+            .map(|height| self.levels[height][(leaves_count >> height) - 1].clone())
+            .collect()
+    }
+}
+
+impl<B> Default for MerkleMountainRange<B>
+where
+    B: IsMerkleTreeBackend,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Proves that a leaf belongs to a Merkle Mountain Range with `leaves_count` leaves. It holds the
+/// merkle path of the leaf in its mountain, from the leaf to the peak, and the peaks of the other
+/// mountains from left to right.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MmrProof<T: PartialEq + Eq> {
+    pub leaves_count: usize,
+    pub merkle_path: Vec<T>,
+    pub peaks: Vec<T>,
+}
+
+impl<T: PartialEq + Eq + Clone> MmrProof<T> {
+    pub fn verify<B>(&self, root_hash: &B::Node, leaf_index: usize, value: &B::Data) -> bool
+    where
+        B: IsMerkleTreeBackend<Node = T>,
+    {
+        let Some((height, mountain)) = find_mountain(leaf_index, self.leaves_count) else {
+            return false;
+        };
+        if self.merkle_path.len() != height
+            || self.peaks.len() + 1 != self.leaves_count.count_ones() as usize
+        {
+            return false;
+        }
+
+        let mut index = leaf_index;
+        let mut peak = B::hash_data(value);
+        for sibling_node in self.merkle_path.iter() {
+            peak = if index & 1 == 0 {
+                B::hash_new_parent(&peak, sibling_node)
+            } else {
+                B::hash_new_parent(sibling_node, &peak)
+            };
+            index >>= 1;
+        }
+
+        let mut peaks = self.peaks.clone();
+        peaks.insert(mountain, peak);
+        bag_peaks::<B>(&peaks).is_some_and(|root| &root == root_hash)
+    }
+}
+
+/// Returns the heights of the mountains of a range with `leaves_count` leaves, from left to right.
+fn mountain_heights(leaves_count: usize) -> impl Iterator<Item = usize> {
+    (0..usize::BITS as usize)
+        .rev()
+        .filter(move |height| (leaves_count >> height) & 1 == 1)
+}
+
+/// Returns the height of the mountain that holds the leaf at `leaf_index`, and its position among
+/// the mountains.
+fn find_mountain(leaf_index: usize, leaves_count: usize) -> Option<(usize, usize)> {
+    let mut mountain_start = 0;
+    for (mountain, height) in mountain_heights(leaves_count).enumerate() {
+        mountain_start += 1 << height;
+        if leaf_index < mountain_start {
+            return Some((height, mountain));
+        }
+    }
+    None
+}
+
+fn bag_peaks<B: IsMerkleTreeBackend>(peaks: &[B::Node]) -> Option<B::Node> {
+    peaks
+        .iter()
+        .rev()
+        .cloned()
+        .reduce(|bagged, peak| B::hash_new_parent(&peak, &bagged))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::merkle_tree::{
+        backends::types::Keccak256Backend, merkle::MerkleTree, test_merkle::TestBackend,
+    };
+    use lambdaworks_math::field::{
+        element::FieldElement,
+        fields::{u64_goldilocks_field::Goldilocks64Field, u64_prime_field::U64PrimeField},
+    };
+
+    type FE = FieldElement<Goldilocks64Field>;
+    type KeccakBackend = Keccak256Backend<Goldilocks64Field>;
+
+    fn build_mmr(leaves_count: u64) -> MerkleMountainRange<KeccakBackend> {
+        let mut mmr = MerkleMountainRange::new();
+        for i in 0..leaves_count {
+            assert_eq!(mmr.append(&FE::from(i)), i as usize);
+        }
+        mmr
+    }
+
+    #[test]
+    // leaves | 1 | 2 | 3 | 4 | 5 | 6 | 7 |, hashed to | 2 | 4 | 6 | 8 | 10 | 12 | 1 |
+    // peaks  | 7 | 9 | 1 |, root 7 + (9 + 1) = 17 = 4 mod 13
+    fn mmr_of_seven_leaves_bags_its_three_peaks() {
+        type U64PF = U64PrimeField<13>;
+        type FE = FieldElement<U64PF>;
+        let mut mmr = MerkleMountainRange::<TestBackend<U64PF>>::new();
+        for i in 1..8 {
+            mmr.append(&FE::new(i));
+        }
+
+        assert_eq!(mmr.peaks(), vec![FE::new(7), FE::new(9), FE::new(1)]);
+        assert_eq!(mmr.root(), Some(FE::new(4)));
+    }
+
+    #[test]
+    fn mmr_of_a_power_of_two_leaves_has_the_root_of_the_dense_tree() {
+        let mmr = build_mmr(16);
+        let values: Vec<FE> = (0..16).map(FE::from).collect();
+        let dense_tree = MerkleTree::<KeccakBackend>::build(&values).unwrap();
+
+        assert_eq!(mmr.peaks(), vec![dense_tree.root]);
+        assert_eq!(mmr.root(), Some(dense_tree.root));
+    }
+
+    #[test]
+    fn proofs_of_every_leaf_verify() {
+        for leaves_count in 1..20 {
+            let mmr = build_mmr(leaves_count);
+            let root = mmr.root().unwrap();
+            for leaf_index in 0..leaves_count {
+                let proof = mmr.get_proof(leaf_index as usize).unwrap();
+                assert!(proof.verify::<KeccakBackend>(
+                    &root,
+                    leaf_index as usize,
+                    &FE::from(leaf_index)
+                ));
+                assert!(!proof.verify::<KeccakBackend>(
+                    &root,
+                    leaf_index as usize,
+                    &FE::from(leaves_count)
+                ));
+            }
+            assert!(mmr.get_proof(leaves_count as usize).is_none());
+        }
+    }
+
+    #[test]
+    fn proofs_against_old_roots_verify() {
+        let mmr = build_mmr(11);
+        let old_mmr = build_mmr(6);
+        assert_eq!(mmr.root_at(6), old_mmr.root());
+        assert_eq!(mmr.root_at(0), None);
+        assert_eq!(mmr.root_at(12), None);
+
+        let old_root = mmr.root_at(6).unwrap();
+        let proof = mmr.get_proof_at(4, 6).unwrap();
+        assert!(proof.verify::<KeccakBackend>(&old_root, 4, &FE::from(4)));
+        assert!(!proof.verify::<KeccakBackend>(&mmr.root().unwrap(), 4, &FE::from(4)));
+        assert!(mmr.get_proof_at(6, 6).is_none());
+        assert!(mmr.get_proof_at(4, 12).is_none());
+    }
+
+    #[test]
+    fn proofs_with_wrong_shapes_are_rejected() {
+        let mmr = build_mmr(7);
+        let root = mmr.root().unwrap();
+        let proof = mmr.get_proof(5).unwrap();
+
+        assert!(!proof.verify::<KeccakBackend>(&root, 4, &FE::from(5)));
+        assert!(!proof.verify::<KeccakBackend>(&root, 7, &FE::from(5)));
+
+        let mut wrong_count = proof.clone();
+        wrong_count.leaves_count = 6;
+        assert!(!wrong_count.verify::<KeccakBackend>(&root, 5, &FE::from(5)));
+
+        let mut missing_peak = proof;
+        missing_peak.peaks.pop();
+        assert!(!missing_peak.verify::<KeccakBackend>(&root, 5, &FE::from(5)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialized_range_round_trips() {
+        let mmr = build_mmr(11);
+
+        let serialized = serde_json::to_string(&mmr).unwrap();
+        let mut deserialized: MerkleMountainRange<KeccakBackend> =
+            serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.len(), mmr.len());
+        assert_eq!(deserialized.root(), mmr.root());
+        deserialized.append(&FE::from(11));
+        assert_eq!(deserialized.root(), build_mmr(12).root());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialized_proof_round_trips() {
+        let mmr = build_mmr(11);
+        let proof = mmr.get_proof(9).unwrap();
+
+        let serialized = serde_json::to_string(&proof).unwrap();
+        let deserialized: MmrProof<[u8; 32]> = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.leaves_count, proof.leaves_count);
+        assert_eq!(deserialized.merkle_path, proof.merkle_path);
+        assert_eq!(deserialized.peaks, proof.peaks);
+        assert!(deserialized.verify::<KeccakBackend>(&mmr.root().unwrap(), 9, &FE::from(9)));
+    }
+}
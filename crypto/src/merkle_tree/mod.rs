@@ -1,6 +1,12 @@
 pub mod backends;
+pub mod incremental;
 pub mod merkle;
+#[cfg(feature = "mmap")]
+pub mod mmap_storage;
+pub mod mmr;
 pub mod proof;
+pub mod sparse_merkle;
+pub mod storage;
 #[cfg(test)]
 pub mod test_merkle;
 pub mod traits;
@@ -37,6 +37,95 @@ impl<T: PartialEq + Eq> Proof<T> {
     }
 }
 
+/// Stores the nodes needed to verify the merkle paths of several leaves at once.
+/// The nodes are sorted from the level of the leaves to the level below the root, and nodes of the
+/// same level are sorted from left to right. A node is omitted when it can be computed from the
+/// opened leaves, so paths that meet share their nodes above that point.
+///
+/// For example, for the tree
+///
+/// ```text
+/// Root              ABCD
+///                  /    \
+/// Level 1         AB    CD
+///                /  \  /  \
+/// Level 0       A   B C    D
+/// Leaf index    0   1 2    3
+/// ```
+///
+/// the multi-proof of the leaves `[0, 1]` is `[CD]`, the one of `[2, 0]` is `[B, D]`, the one of
+/// `[0, 3, 1]` is `[C]`, and the one of all of them is empty.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultiProof<T: PartialEq + Eq> {
+    pub nodes: Vec<T>,
+    /// Height of the tree, i.e. the length of the merkle path of each leaf.
+    pub depth: usize,
+}
+
+impl<T: PartialEq + Eq> MultiProof<T> {
+    /// Verifies that `values[i]` is the leaf at position `positions[i]` for every `i`. Positions may
+    /// be repeated, as long as the values are the same.
+    pub fn verify<B>(&self, root_hash: &B::Node, positions: &[usize], values: &[B::Data]) -> bool
+    where
+        B: IsMerkleTreeBackend<Node = T>,
+    {
+        if positions.is_empty() || positions.len() != values.len() {
+            return false;
+        }
+        if positions
+            .iter()
+            .any(|position| position.checked_shr(self.depth as u32).unwrap_or(0) != 0)
+        {
+            return false;
+        }
+
+        let mut level: Vec<(usize, T)> = positions
+            .iter()
+            .zip(values)
+            .map(|(position, value)| (*position, B::hash_data(value)))
+            .collect();
+        level.sort_by_key(|(position, _)| *position);
+        if level
+            .windows(2)
+            .any(|pair| pair[0].0 == pair[1].0 && pair[0].1 != pair[1].1)
+        {
+            return false;
+        }
+        level.dedup_by_key(|(position, _)| *position);
+
+        let mut nodes = self.nodes.iter();
+        for _ in 0..self.depth {
+            let mut parents = Vec::with_capacity(level.len());
+            let mut i = 0;
+            while i < level.len() {
+                let (index, node) = &level[i];
+                let parent = match level.get(i + 1) {
+                    Some((next_index, sibling)) if index % 2 == 0 && *next_index == index + 1 => {
+                        i += 1;
+                        B::hash_new_parent(node, sibling)
+                    }
+                    _ => {
+                        let Some(sibling) = nodes.next() else {
+                            return false;
+                        };
+                        if index % 2 == 0 {
+                            B::hash_new_parent(node, sibling)
+                        } else {
+                            B::hash_new_parent(sibling, node)
+                        }
+                    }
+                };
+                parents.push((index >> 1, parent));
+                i += 1;
+            }
+            level = parents;
+        }
+
+        nodes.next().is_none() && &level[0].1 == root_hash
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<T> Serializable for Proof<T>
 where
@@ -71,12 +160,17 @@ mod tests {
 
     #[cfg(feature = "alloc")]
     use super::Proof;
-    use alloc::vec::Vec;
-    use lambdaworks_math::field::{element::FieldElement, fields::u64_prime_field::U64PrimeField};
+    use alloc::{vec, vec::Vec};
+    use lambdaworks_math::field::{
+        element::FieldElement,
+        fields::{u64_goldilocks_field::Goldilocks64Field, u64_prime_field::U64PrimeField},
+    };
     #[cfg(feature = "alloc")]
     use lambdaworks_math::traits::{Deserializable, Serializable};
 
-    use crate::merkle_tree::{merkle::MerkleTree, test_merkle::TestBackend};
+    use crate::merkle_tree::{
+        backends::types::Keccak256Backend, merkle::MerkleTree, test_merkle::TestBackend,
+    };
 
     /// Small field useful for starks, sometimes called min i goldilocks
     /// Used in miden and winterfell
@@ -87,6 +181,8 @@ mod tests {
     #[cfg(feature = "alloc")]
     pub type TestProofEcgfp5 = Proof<Ecgfp5FE>;
 
+    type GoldilocksFE = FieldElement<Goldilocks64Field>;
+
     const MODULUS: u64 = 13;
     type U64PF = U64PrimeField<MODULUS>;
     type FE = FieldElement<U64PF>;
@@ -177,4 +273,121 @@ mod tests {
             "The proof verification failed for the element at position 0."
         );
     }
+
+    #[test]
+    fn multi_proof_omits_the_nodes_that_can_be_computed_from_the_leaves() {
+        let values: Vec<GoldilocksFE> = (1..5).map(GoldilocksFE::from).collect();
+        let merkle_tree =
+            MerkleTree::<Keccak256Backend<Goldilocks64Field>>::build(&values).unwrap();
+        let paths: Vec<_> = (0..4)
+            .map(|pos| merkle_tree.get_proof_by_pos(pos).unwrap().merkle_path)
+            .collect();
+        let (b, d, c, cd) = (paths[0][0], paths[2][0], paths[3][0], paths[0][1]);
+
+        let cases: [(&[usize], Vec<[u8; 32]>); 4] = [
+            (&[0, 1], vec![cd]),
+            (&[2, 0], vec![b, d]),
+            (&[0, 3, 1], vec![c]),
+            (&[0, 1, 2, 3], vec![]),
+        ];
+        for (positions, expected_nodes) in cases {
+            let proof = merkle_tree.get_multi_proof(positions).unwrap();
+            assert_eq!(proof.nodes, expected_nodes);
+            assert_eq!(proof.depth, 2);
+
+            let leaves: Vec<_> = positions.iter().map(|pos| values[*pos]).collect();
+            assert!(proof.verify::<Keccak256Backend<Goldilocks64Field>>(
+                &merkle_tree.root,
+                positions,
+                &leaves
+            ));
+        }
+    }
+
+    #[test]
+    fn multi_proof_of_many_leaves_of_a_big_tree_verifies() {
+        let values: Vec<GoldilocksFE> = (1..10000).map(GoldilocksFE::from).collect();
+        let merkle_tree =
+            MerkleTree::<Keccak256Backend<Goldilocks64Field>>::build(&values).unwrap();
+        let positions = [9349, 17, 16, 5000, 9349, 9998, 4096, 18];
+        let leaves: Vec<_> = positions.iter().map(|pos| values[*pos]).collect();
+
+        let proof = merkle_tree.get_multi_proof(&positions).unwrap();
+        let single_proofs_len: usize = positions
+            .iter()
+            .map(|pos| {
+                merkle_tree
+                    .get_proof_by_pos(*pos)
+                    .unwrap()
+                    .merkle_path
+                    .len()
+            })
+            .sum();
+        assert!(proof.nodes.len() < single_proofs_len);
+        assert!(proof.verify::<Keccak256Backend<Goldilocks64Field>>(
+            &merkle_tree.root,
+            &positions,
+            &leaves
+        ));
+    }
+
+    #[test]
+    fn multi_proof_does_not_verify_wrong_openings() {
+        let values: Vec<GoldilocksFE> = (1..10000).map(GoldilocksFE::from).collect();
+        let merkle_tree =
+            MerkleTree::<Keccak256Backend<Goldilocks64Field>>::build(&values).unwrap();
+        let positions = [5, 6, 700];
+        let leaves: Vec<_> = positions.iter().map(|pos| values[*pos]).collect();
+        let proof = merkle_tree.get_multi_proof(&positions).unwrap();
+        let root = &merkle_tree.root;
+
+        let mut wrong_leaves = leaves.clone();
+        wrong_leaves[2] = GoldilocksFE::from(1);
+        assert!(!proof.verify::<Keccak256Backend<Goldilocks64Field>>(
+            root,
+            &positions,
+            &wrong_leaves
+        ));
+        assert!(!proof.verify::<Keccak256Backend<Goldilocks64Field>>(root, &[5, 6, 701], &leaves));
+        assert!(!proof.verify::<Keccak256Backend<Goldilocks64Field>>(root, &[5, 6], &leaves));
+        assert!(!proof.verify::<Keccak256Backend<Goldilocks64Field>>(
+            root,
+            &[5, 6, 700, 700],
+            &[leaves[0], leaves[1], leaves[2], values[699]]
+        ));
+        assert!(!proof.verify::<Keccak256Backend<Goldilocks64Field>>(
+            root,
+            &[5, 6, 700 + (1 << 14)],
+            &leaves
+        ));
+
+        let mut extra_node = proof.clone();
+        extra_node.nodes.push(*root);
+        assert!(
+            !extra_node.verify::<Keccak256Backend<Goldilocks64Field>>(root, &positions, &leaves)
+        );
+        let mut missing_node = proof;
+        missing_node.nodes.pop();
+        assert!(
+            !missing_node.verify::<Keccak256Backend<Goldilocks64Field>>(root, &positions, &leaves)
+        );
+    }
+
+    #[test]
+    fn multi_proof_of_out_of_bounds_positions_is_none() {
+        let values: Vec<FE> = (1..6).map(FE::new).collect();
+        let merkle_tree = MerkleTree::<TestBackend<U64PF>>::build(&values).unwrap();
+        assert!(merkle_tree.get_multi_proof(&[7]).is_some());
+        assert!(merkle_tree.get_multi_proof(&[0, 8]).is_none());
+        assert!(merkle_tree.get_multi_proof(&[]).is_none());
+    }
+
+    #[test]
+    fn verify_multi_proof_for_single_value() {
+        let values: Vec<FE> = vec![FE::new(1)];
+        let merkle_tree = MerkleTree::<TestBackend<U64PF>>::build(&values).unwrap();
+        let proof = merkle_tree.get_multi_proof(&[0, 0]).unwrap();
+        assert!(proof.nodes.is_empty());
+        assert!(proof.verify::<TestBackend<U64PF>>(&merkle_tree.root, &[0, 0], &[values[0]; 2]));
+    }
 }
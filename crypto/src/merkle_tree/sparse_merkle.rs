@@ -0,0 +1,438 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use lambdaworks_math::unsigned_integer::element::U256;
+
+use super::{merkle::Error, traits::IsMerkleTreeBackend};
+
+const MAX_DEPTH: usize = 256;
+
+/// A key-value Merkle tree of fixed `depth`, where the leaf of a key is its `depth` least
+/// significant bits, and that stores only the nodes of the non-empty subtrees.
+/// Empty leaves hold the node `empty_leaf`, and an empty subtree of height `h + 1` hashes to the
+/// parent of two empty subtrees of height `h`. A non-empty leaf is the hash of its value.
+///
+/// Keys with more than `depth` bits are rejected, so field elements can be used as keys of a tree
+/// whose depth is the bit size of the field through their representative.
+pub struct SparseMerkleTree<B: IsMerkleTreeBackend> {
+    pub root: B::Node,
+    depth: usize,
+    /// Root of an empty subtree of each height, from the leaves to the root.
+    empty_subtrees: Vec<B::Node>,
+    /// Non-empty nodes, indexed by their height and their position in the level.
+    nodes: BTreeMap<(usize, U256), B::Node>,
+    values: BTreeMap<U256, B::Data>,
+}
+
+impl<B> SparseMerkleTree<B>
+where
+    B: IsMerkleTreeBackend,
+{
+    /// Creates an empty tree of the given depth, which must be between 1 and 256.
+    pub fn new(depth: usize, empty_leaf: B::Node) -> Option<Self> {
+        if depth == 0 || depth > MAX_DEPTH {
+            return None;
+        }
+        let empty_subtrees = empty_subtrees::<B>(depth, empty_leaf);
+
+        Some(Self {
+            root: empty_subtrees[depth].clone(),
+            depth,
+            empty_subtrees,
+            nodes: BTreeMap::new(),
+            values: BTreeMap::new(),
+        })
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn get(&self, key: &U256) -> Option<&B::Data> {
+        self.values.get(key)
+    }
+
+    /// Inserts the value of a key or updates it, returning the previous one.
+    pub fn insert(&mut self, key: U256, value: B::Data) -> Result<Option<B::Data>, Error> {
+        self.check_key(&key)?;
+        self.update_path(&key, B::hash_data(&value));
+        Ok(self.values.insert(key, value))
+    }
+
+    /// Removes the value of a key, emptying its leaf, and returns it.
+    pub fn remove(&mut self, key: &U256) -> Result<Option<B::Data>, Error> {
+        self.check_key(key)?;
+        let value = self.values.remove(key);
+        if value.is_some() {
+            self.update_path(key, self.empty_subtrees[0].clone());
+        }
+        Ok(value)
+    }
+
+    /// Returns the path of the leaf of `key`, which proves either the value of the key or that
+    /// it has no value.
+    pub fn get_proof(&self, key: &U256) -> Result<SparseMerkleProof<B::Node>, Error> {
+        self.check_key(key)?;
+        let siblings = (0..self.depth)
+            .map(|height| {
+                let index = key >> height;
+                self.nodes
+                    .get(&(height, index ^ U256::from_u64(1)))
+                    .cloned()
+            })
+            .collect();
+        Ok(SparseMerkleProof { siblings })
+    }
+
+    fn check_key(&self, key: &U256) -> Result<(), Error> {
+        if fits_in_depth(key, self.depth) {
+            Ok(())
+        } else {
+            Err(Error::OutOfBounds)
+        }
+    }
+
+    /// Sets the leaf of `key` to `leaf` and recomputes its ancestors.
+    fn update_path(&mut self, key: &U256, leaf: B::Node) {
+        let mut node = leaf;
+        for height in 0..self.depth {
+            let index = key >> height;
+            if node == self.empty_subtrees[height] {
+                self.nodes.remove(&(height, index));
+            } else {
+                self.nodes.insert((height, index), node.clone());
+            }
+
+            let sibling = self
+                .nodes
+                .get(&(height, index ^ U256::from_u64(1)))
+                .unwrap_or(&self.empty_subtrees[height]);
+            node = if is_right_child(&index) {
+                B::hash_new_parent(sibling, &node)
+            } else {
+                B::hash_new_parent(&node, sibling)
+            };
+        }
+        self.root = node;
+    }
+}
+
+/// The siblings of the path from a leaf of a sparse Merkle tree to its root, sorted from the
+/// leaf to the root. A sibling is `None` when it's the root of an empty subtree.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SparseMerkleProof<T: PartialEq + Eq> {
+    pub siblings: Vec<Option<T>>,
+}
+
+impl<T: PartialEq + Eq + Clone> SparseMerkleProof<T> {
+    /// Verifies that `value` is the value of `key` in the tree of depth `depth` with root
+    /// `root_hash` and empty leaves `empty_leaf`. The proof must hold exactly `depth` siblings.
+    pub fn verify_membership<B>(
+        &self,
+        root_hash: &B::Node,
+        depth: usize,
+        key: &U256,
+        value: &B::Data,
+        empty_leaf: &B::Node,
+    ) -> bool
+    where
+        B: IsMerkleTreeBackend<Node = T>,
+    {
+        self.compute_root::<B>(depth, key, B::hash_data(value), empty_leaf)
+            .is_some_and(|root| &root == root_hash)
+    }
+
+    /// Verifies that `key` has no value in the tree of depth `depth` with root `root_hash` and
+    /// empty leaves `empty_leaf`. The proof must hold exactly `depth` siblings.
+    pub fn verify_non_membership<B>(
+        &self,
+        root_hash: &B::Node,
+        depth: usize,
+        key: &U256,
+        empty_leaf: &B::Node,
+    ) -> bool
+    where
+        B: IsMerkleTreeBackend<Node = T>,
+    {
+        self.compute_root::<B>(depth, key, empty_leaf.clone(), empty_leaf)
+            .is_some_and(|root| &root == root_hash)
+    }
+
+    fn compute_root<B>(&self, depth: usize, key: &U256, leaf: T, empty_leaf: &T) -> Option<T>
+    where
+        B: IsMerkleTreeBackend<Node = T>,
+    {
+        if self.siblings.len() != depth
+            || depth == 0
+            || depth > MAX_DEPTH
+            || !fits_in_depth(key, depth)
+        {
+            return None;
+        }
+
+        let mut empty_subtree = empty_leaf.clone();
+        let mut node = leaf;
+        for (height, sibling) in self.siblings.iter().enumerate() {
+            let sibling = sibling.as_ref().unwrap_or(&empty_subtree);
+            node = if is_right_child(&(key >> height)) {
+                B::hash_new_parent(sibling, &node)
+            } else {
+                B::hash_new_parent(&node, sibling)
+            };
+            empty_subtree = B::hash_new_parent(&empty_subtree, &empty_subtree);
+        }
+        Some(node)
+    }
+}
+
+fn empty_subtrees<B: IsMerkleTreeBackend>(depth: usize, empty_leaf: B::Node) -> Vec<B::Node> {
+    let mut empty_subtrees = Vec::with_capacity(depth + 1);
+    empty_subtrees.push(empty_leaf);
+    for height in 0..depth {
+        let empty_subtree = &empty_subtrees[height];
+        empty_subtrees.push(B::hash_new_parent(empty_subtree, empty_subtree));
+    }
+    empty_subtrees
+}
+
+fn fits_in_depth(key: &U256, depth: usize) -> bool {
+    depth >= MAX_DEPTH || key >> depth == U256::from_u64(0)
+}
+
+fn is_right_child(index: &U256) -> bool {
+    index.limbs[3] & 1 == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        hash::poseidon::starknet::PoseidonCairoStark252,
+        merkle_tree::{
+            backends::{field_element::TreePoseidon, types::Keccak256Backend},
+            merkle::MerkleTree,
+        },
+    };
+    use lambdaworks_math::{
+        field::{
+            element::FieldElement,
+            fields::{
+                fft_friendly::stark_252_prime_field::Stark252PrimeField,
+                u64_goldilocks_field::Goldilocks64Field,
+            },
+            traits::IsPrimeField,
+        },
+        traits::ByteConversion,
+    };
+
+    type FE = FieldElement<Goldilocks64Field>;
+    type KeccakBackend = Keccak256Backend<Goldilocks64Field>;
+    type Felt = FieldElement<Stark252PrimeField>;
+    type PoseidonBackend = TreePoseidon<PoseidonCairoStark252>;
+
+    const EMPTY: [u8; 32] = [0; 32];
+
+    fn key(value: u64) -> U256 {
+        U256::from_u64(value)
+    }
+
+    #[test]
+    fn full_sparse_tree_has_the_root_of_the_dense_tree() {
+        let values: Vec<FE> = (1..9).map(FE::from).collect();
+        let mut tree = SparseMerkleTree::<KeccakBackend>::new(3, EMPTY).unwrap();
+        for (i, value) in values.iter().enumerate().rev() {
+            tree.insert(key(i as u64), *value).unwrap();
+        }
+
+        let dense_tree = MerkleTree::<KeccakBackend>::build(&values).unwrap();
+        assert_eq!(tree.root, dense_tree.root);
+    }
+
+    #[test]
+    fn root_does_not_depend_on_the_order_of_insertions_and_removals() {
+        let empty_root = SparseMerkleTree::<KeccakBackend>::new(64, EMPTY)
+            .unwrap()
+            .root;
+
+        let mut tree_1 = SparseMerkleTree::<KeccakBackend>::new(64, EMPTY).unwrap();
+        tree_1.insert(key(7), FE::from(70)).unwrap();
+        tree_1.insert(key(u64::MAX), FE::from(1)).unwrap();
+        tree_1.insert(key(8), FE::from(80)).unwrap();
+
+        let mut tree_2 = SparseMerkleTree::<KeccakBackend>::new(64, EMPTY).unwrap();
+        tree_2.insert(key(8), FE::from(81)).unwrap();
+        tree_2.insert(key(7), FE::from(70)).unwrap();
+        tree_2.insert(key(3), FE::from(30)).unwrap();
+        assert_eq!(
+            tree_2.insert(key(8), FE::from(80)).unwrap(),
+            Some(FE::from(81))
+        );
+        tree_2.insert(key(u64::MAX), FE::from(1)).unwrap();
+        assert_eq!(tree_2.remove(&key(3)).unwrap(), Some(FE::from(30)));
+        assert_eq!(tree_2.remove(&key(3)).unwrap(), None);
+
+        assert_eq!(tree_1.root, tree_2.root);
+        assert_eq!(tree_2.get(&key(8)), Some(&FE::from(80)));
+        assert_eq!(tree_2.get(&key(3)), None);
+
+        for k in [7, 8, u64::MAX] {
+            tree_1.remove(&key(k)).unwrap();
+        }
+        assert_eq!(tree_1.root, empty_root);
+        assert!(tree_1.nodes.is_empty());
+    }
+
+    #[test]
+    fn membership_and_non_membership_proofs_verify() {
+        let mut tree = SparseMerkleTree::<KeccakBackend>::new(256, EMPTY).unwrap();
+        let big_key = U256::from_hex_unchecked(
+            "f000000000000000000000000000000000000000000000000000000000000001",
+        );
+        tree.insert(big_key, FE::from(1)).unwrap();
+        tree.insert(key(1), FE::from(2)).unwrap();
+        tree.insert(key(2), FE::from(3)).unwrap();
+
+        let proof = tree.get_proof(&big_key).unwrap();
+        assert_eq!(proof.siblings.len(), 256);
+        assert!(proof.verify_membership::<KeccakBackend>(
+            &tree.root,
+            tree.depth(),
+            &big_key,
+            &FE::from(1),
+            &EMPTY
+        ));
+        assert!(!proof.verify_membership::<KeccakBackend>(
+            &tree.root,
+            tree.depth(),
+            &big_key,
+            &FE::from(2),
+            &EMPTY
+        ));
+        assert!(!proof.verify_membership::<KeccakBackend>(
+            &tree.root,
+            tree.depth(),
+            &key(1),
+            &FE::from(1),
+            &EMPTY
+        ));
+        assert!(!proof.verify_non_membership::<KeccakBackend>(
+            &tree.root,
+            tree.depth(),
+            &big_key,
+            &EMPTY
+        ));
+
+        let absent_key = key(3);
+        let proof = tree.get_proof(&absent_key).unwrap();
+        assert!(proof.verify_non_membership::<KeccakBackend>(
+            &tree.root,
+            tree.depth(),
+            &absent_key,
+            &EMPTY
+        ));
+        assert!(!proof.verify_membership::<KeccakBackend>(
+            &tree.root,
+            tree.depth(),
+            &absent_key,
+            &FE::from(4),
+            &EMPTY
+        ));
+
+        tree.insert(absent_key, FE::from(4)).unwrap();
+        assert!(!proof.verify_non_membership::<KeccakBackend>(
+            &tree.root,
+            tree.depth(),
+            &absent_key,
+            &EMPTY
+        ));
+    }
+
+    #[test]
+    fn poseidon_tree_with_field_elements_as_keys() {
+        let depth = Stark252PrimeField::field_bit_size();
+        let empty_leaf = Felt::zero();
+        let mut tree = SparseMerkleTree::<PoseidonBackend>::new(depth, empty_leaf).unwrap();
+
+        let keys: Vec<Felt> = [Felt::from(5), -Felt::one(), Felt::from(1 << 20)].to_vec();
+        for (i, k) in keys.iter().enumerate() {
+            tree.insert(k.representative(), Felt::from(i as u64 + 10))
+                .unwrap();
+        }
+
+        for (i, k) in keys.iter().enumerate() {
+            let proof = tree.get_proof(&k.representative()).unwrap();
+            assert!(proof.verify_membership::<PoseidonBackend>(
+                &tree.root,
+                depth,
+                &k.representative(),
+                &Felt::from(i as u64 + 10),
+                &empty_leaf
+            ));
+        }
+
+        let absent_key = (-Felt::from(2)).representative();
+        let proof = tree.get_proof(&absent_key).unwrap();
+        assert!(proof.verify_non_membership::<PoseidonBackend>(
+            &tree.root,
+            depth,
+            &absent_key,
+            &empty_leaf
+        ));
+
+        let too_big_key = U256::from_bytes_be(&[0xff; 32]).unwrap();
+        assert!(tree.insert(too_big_key, Felt::one()).is_err());
+        assert!(tree.get_proof(&too_big_key).is_err());
+    }
+
+    #[test]
+    fn invalid_depths_and_keys_are_rejected() {
+        assert!(SparseMerkleTree::<KeccakBackend>::new(0, EMPTY).is_none());
+        assert!(SparseMerkleTree::<KeccakBackend>::new(257, EMPTY).is_none());
+
+        let mut tree = SparseMerkleTree::<KeccakBackend>::new(4, EMPTY).unwrap();
+        assert!(tree.insert(key(15), FE::one()).is_ok());
+        assert!(tree.insert(key(16), FE::one()).is_err());
+        assert!(tree.remove(&key(16)).is_err());
+
+        let proof = tree.get_proof(&key(15)).unwrap();
+        assert!(!proof.verify_membership::<KeccakBackend>(
+            &tree.root,
+            tree.depth(),
+            &key(31),
+            &FE::one(),
+            &EMPTY
+        ));
+        assert!(!SparseMerkleProof::<[u8; 32]> { siblings: vec![] }
+            .verify_non_membership::<KeccakBackend>(&tree.root, 0, &key(0), &EMPTY));
+    }
+
+    #[test]
+    fn proofs_of_another_depth_are_rejected() {
+        let mut tree = SparseMerkleTree::<KeccakBackend>::new(8, EMPTY).unwrap();
+        tree.insert(key(0), FE::one()).unwrap();
+        let proof = tree.get_proof(&key(0)).unwrap();
+        assert!(proof.verify_membership::<KeccakBackend>(
+            &tree.root,
+            8,
+            &key(0),
+            &FE::one(),
+            &EMPTY
+        ));
+
+        let mut short_proof = proof.clone();
+        short_proof.siblings.pop();
+        assert!(!short_proof.verify_membership::<KeccakBackend>(
+            &tree.root,
+            8,
+            &key(0),
+            &FE::one(),
+            &EMPTY
+        ));
+        assert!(!proof.verify_membership::<KeccakBackend>(
+            &tree.root,
+            9,
+            &key(0),
+            &FE::one(),
+            &EMPTY
+        ));
+    }
+}
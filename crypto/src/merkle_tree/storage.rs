@@ -0,0 +1,33 @@
+use alloc::vec::Vec;
+
+/// Holds the nodes of a `MerkleTree`, indexed like a binary heap: the root is the node `0`, and
+/// the children of the node `i` are the nodes `2i + 1` and `2i + 2`, so the leaves are the last
+/// half of the nodes.
+pub trait IsMerkleTreeStorage<T> {
+    /// Number of nodes of the tree.
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the node at `index`. It panics if `index` is out of bounds.
+    fn get_node(&self, index: usize) -> T;
+
+    /// Replaces the node at `index`. It panics if `index` is out of bounds.
+    fn set_node(&mut self, index: usize, node: T);
+}
+
+impl<T: Clone> IsMerkleTreeStorage<T> for Vec<T> {
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn get_node(&self, index: usize) -> T {
+        self[index].clone()
+    }
+
+    fn set_node(&mut self, index: usize, node: T) {
+        self[index] = node;
+    }
+}
@@ -0,0 +1,435 @@
+//! BLS signatures over BLS12-381, following the proof of possession ciphersuite
+//! `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_` of the IETF BLS signature draft.
+//! https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-bls-signature-05
+//!
+//! Public keys are in G1 and signatures in G2, both serialized in the compressed Zcash
+//! format, which is the variant used by Ethereum.
+//!
+//! Aggregating the public keys of a set of signers is only safe once every signer has
+//! proven possession of their secret key, see [`SecretKey::pop_prove`].
+//!
+//! The public key is computed with the constant time
+//! [`ShortWeierstrassProjectivePoint::operate_with_self_constant_time`], but that ladder needs
+//! a prime base field, so it doesn't apply to G2, whose points have coordinates in Fp2.
+//! Signatures and proofs of possession multiply the hashed message by the secret key with
+//! [`IsGroup::operate_with_self`], a double-and-add whose additions depend on the bits of the
+//! key, so the time it takes to sign leaks information about the secret key.
+
+use crate::hash::{
+    hash_to_curve::{os2ip_mod_p, IsHashToCurve},
+    hmac::hmac_sha256,
+};
+use alloc::vec::Vec;
+use core::fmt::Display;
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::{
+            curves::bls12_381::{
+                curve::BLS12381Curve,
+                default_types::{FrElement, FrField},
+                pairing::BLS12381AtePairing,
+                twist::BLS12381TwistCurve,
+            },
+            point::ShortWeierstrassProjectivePoint,
+            traits::Compress,
+        },
+        traits::{IsEllipticCurve, IsPairing},
+    },
+    field::element::FieldElement,
+    traits::ByteConversion,
+};
+use sha2::{Digest, Sha256};
+
+type G1Point = ShortWeierstrassProjectivePoint<BLS12381Curve>;
+type G2Point = ShortWeierstrassProjectivePoint<BLS12381TwistCurve>;
+
+/// Domain separation tag of the messages signed with [`SecretKey::sign`].
+pub const SIGNATURE_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+/// Domain separation tag of the proofs of possession.
+pub const POP_DST: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// Minimum amount of input keying material accepted by [`SecretKey::key_gen`].
+const MIN_IKM_LENGTH: usize = 32;
+/// Length of the output of HKDF that is reduced to a secret key, `L` in the draft.
+const KEY_GEN_OKM_LENGTH: usize = 48;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum BlsError {
+    /// Fewer than 32 bytes of input keying material were given to the key generation.
+    ShortKeyMaterial,
+    /// The bytes don't encode a scalar in `[1, r)`.
+    InvalidSecretKey,
+    /// The bytes don't encode a point of G1 other than the identity.
+    InvalidPublicKey,
+    /// The bytes don't encode a point of G2.
+    InvalidSignature,
+    /// There is nothing to aggregate.
+    EmptyAggregate,
+}
+
+impl Display for BlsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            BlsError::ShortKeyMaterial => {
+                write!(f, "The input keying material must have at least 32 bytes")
+            }
+            BlsError::InvalidSecretKey => write!(f, "Invalid secret key"),
+            BlsError::InvalidPublicKey => write!(f, "Invalid public key"),
+            BlsError::InvalidSignature => write!(f, "Invalid signature"),
+            BlsError::EmptyAggregate => write!(f, "Can't aggregate an empty list"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BlsError {}
+
+#[derive(Clone)]
+pub struct SecretKey(FrElement);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey(G1Point);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature(G2Point);
+
+impl SecretKey {
+    /// Derives a secret key from at least 32 bytes of secret input keying material,
+    /// as in the `KeyGen` procedure of the draft. `key_info` may be empty.
+    pub fn key_gen(ikm: &[u8], key_info: &[u8]) -> Result<Self, BlsError> {
+        if ikm.len() < MIN_IKM_LENGTH {
+            return Err(BlsError::ShortKeyMaterial);
+        }
+        let mut salt = Sha256::digest(b"BLS-SIG-KEYGEN-SALT-");
+        loop {
+            let prk = hmac_sha256(&salt, &[ikm, &[0]]);
+            let okm = hkdf_expand(
+                &prk,
+                &[key_info, &(KEY_GEN_OKM_LENGTH as u16).to_be_bytes()],
+                KEY_GEN_OKM_LENGTH,
+            );
+            let sk = os2ip_mod_p::<FrField>(&okm);
+            if sk != FrElement::zero() {
+                return Ok(Self(sk));
+            }
+            salt = Sha256::digest(salt);
+        }
+    }
+
+    /// Reads a secret key from its 32 bytes big-endian encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BlsError> {
+        if bytes.len() != 32 {
+            return Err(BlsError::InvalidSecretKey);
+        }
+        let sk = FrElement::from_bytes_be(bytes).map_err(|_| BlsError::InvalidSecretKey)?;
+        if sk == FrElement::zero() || sk.to_bytes_be() != bytes {
+            return Err(BlsError::InvalidSecretKey);
+        }
+        Ok(Self(sk))
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let mut bytes = [0; 32];
+        bytes.copy_from_slice(&self.0.to_bytes_be());
+        bytes
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(
+            BLS12381Curve::generator().operate_with_self_constant_time(&self.0.representative()),
+        )
+    }
+
+    pub fn sign(&self, msg: &[u8]) -> Signature {
+        Signature(hash_to_g2(msg, SIGNATURE_DST).operate_with_self(self.0.representative()))
+    }
+
+    /// Proves possession of the secret key by signing the public key with its own
+    /// domain separation tag.
+    pub fn pop_prove(&self) -> Signature {
+        let pk = self.public_key().to_bytes();
+        Signature(hash_to_g2(&pk, POP_DST).operate_with_self(self.0.representative()))
+    }
+}
+
+impl PublicKey {
+    /// Reads a compressed public key, checking that it is in G1 and is not the identity,
+    /// as in `KeyValidate`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BlsError> {
+        let mut compressed: [u8; 48] = bytes.try_into().map_err(|_| BlsError::InvalidPublicKey)?;
+        let point = BLS12381Curve::decompress_g1_point(&mut compressed)
+            .map_err(|_| BlsError::InvalidPublicKey)?;
+        let pk = Self(point);
+        if pk.0.is_neutral_element() || pk.to_bytes() != bytes {
+            return Err(BlsError::InvalidPublicKey);
+        }
+        Ok(pk)
+    }
+
+    pub fn to_bytes(&self) -> [u8; 48] {
+        BLS12381Curve::compress_g1_point(&self.0)
+    }
+
+    pub fn verify(&self, msg: &[u8], signature: &Signature) -> bool {
+        core_verify(&self.0, &hash_to_g2(msg, SIGNATURE_DST), &signature.0)
+    }
+
+    /// Checks a proof of possession made with [`SecretKey::pop_prove`].
+    pub fn pop_verify(&self, proof: &Signature) -> bool {
+        core_verify(&self.0, &hash_to_g2(&self.to_bytes(), POP_DST), &proof.0)
+    }
+
+    /// Adds up public keys, so that the result verifies the aggregate of their signatures
+    /// of a same message.
+    pub fn aggregate(public_keys: &[PublicKey]) -> Result<PublicKey, BlsError> {
+        let (first, rest) = public_keys.split_first().ok_or(BlsError::EmptyAggregate)?;
+        Ok(PublicKey(
+            rest.iter()
+                .fold(first.0.clone(), |acc, pk| acc.operate_with(&pk.0)),
+        ))
+    }
+}
+
+impl Signature {
+    /// Reads a compressed signature, checking that it is in G2.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BlsError> {
+        let mut compressed: [u8; 96] = bytes.try_into().map_err(|_| BlsError::InvalidSignature)?;
+        let point = BLS12381Curve::decompress_g2_point(&mut compressed)
+            .map_err(|_| BlsError::InvalidSignature)?;
+        let signature = Self(point);
+        if signature.to_bytes() != bytes {
+            return Err(BlsError::InvalidSignature);
+        }
+        Ok(signature)
+    }
+
+    pub fn to_bytes(&self) -> [u8; 96] {
+        BLS12381Curve::compress_g2_point(&self.0)
+    }
+
+    /// Adds up signatures into one that can be checked with [`fast_aggregate_verify`] or
+    /// [`aggregate_verify`].
+    pub fn aggregate(signatures: &[Signature]) -> Result<Signature, BlsError> {
+        let (first, rest) = signatures.split_first().ok_or(BlsError::EmptyAggregate)?;
+        Ok(Signature(rest.iter().fold(first.0.clone(), |acc, sig| {
+            acc.operate_with(&sig.0)
+        })))
+    }
+}
+
+/// Checks an aggregate signature of the same message by several signers, which must have
+/// proven possession of their keys.
+pub fn fast_aggregate_verify(public_keys: &[PublicKey], msg: &[u8], signature: &Signature) -> bool {
+    PublicKey::aggregate(public_keys).is_ok_and(|pk| pk.verify(msg, signature))
+}
+
+/// Checks an aggregate signature of `msgs[i]` by `public_keys[i]`.
+pub fn aggregate_verify(public_keys: &[PublicKey], msgs: &[&[u8]], signature: &Signature) -> bool {
+    if public_keys.is_empty() || public_keys.len() != msgs.len() {
+        return false;
+    }
+    let hashes: Vec<G2Point> = msgs
+        .iter()
+        .map(|msg| hash_to_g2(msg, SIGNATURE_DST))
+        .collect();
+    let neg_generator = BLS12381Curve::generator().neg();
+    let mut pairs: Vec<(&G1Point, &G2Point)> = public_keys
+        .iter()
+        .map(|pk| &pk.0)
+        .zip(hashes.iter())
+        .collect();
+    pairs.push((&neg_generator, &signature.0));
+    BLS12381AtePairing::compute_batch(&pairs).is_ok_and(|result| result == FieldElement::one())
+}
+
+/// Checks that `e(pk, h) = e(g1, signature)`.
+fn core_verify(pk: &G1Point, h: &G2Point, signature: &G2Point) -> bool {
+    let neg_generator = BLS12381Curve::generator().neg();
+    BLS12381AtePairing::compute_batch(&[(pk, h), (&neg_generator, signature)])
+        .is_ok_and(|result| result == FieldElement::one())
+}
+
+fn hash_to_g2(msg: &[u8], dst: &[u8]) -> G2Point {
+    BLS12381TwistCurve::hash_to_curve(msg, dst).expect("the domain separation tags are valid")
+}
+
+/// HKDF-Expand with SHA-256, RFC 5869, with `info` given as the concatenation of its parts.
+fn hkdf_expand(prk: &[u8], info: &[&[u8]], len: usize) -> Vec<u8> {
+    let mut okm = Vec::with_capacity(len);
+    let mut t: Vec<u8> = Vec::new();
+    let mut counter = 1u8;
+    while okm.len() < len {
+        let mut chunks = vec![t.as_slice()];
+        chunks.extend_from_slice(info);
+        let counter_bytes = [counter];
+        chunks.push(&counter_bytes);
+        t = hmac_sha256(prk, &chunks).to_vec();
+        okm.extend_from_slice(&t);
+        counter += 1;
+    }
+    okm.truncate(len);
+    okm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{decode_hex, encode_hex};
+
+    // Ethereum consensus spec tests, bls/sign and bls/aggregate
+    const SECRET_KEYS: [&str; 3] = [
+        "263dbd792f5b1be47ed85f8938c0f29586af0d3ac7b977f21c278fe1462040e3",
+        "47b8192d77bf871b62e87859d653922725724a5c031afeabc60bcef5ff665138",
+        "328388aff0d4a5b7dc9205abd374e7e98f3cd9f3418edb4eafda5fb16473d216",
+    ];
+    const PUBLIC_KEYS: [&str; 3] = [
+        "a491d1b0ecd9bb917989f0e74f0dea0422eac4a873e5e2644f368dffb9a6e20fd6e10c1b77654d067c0618f6e5a7f79a",
+        "b301803f8b5ac4a1133581fc676dfedc60d891dd5fa99028805e5ea5b08d3491af75d0707adab3b70c6a6a580217bf81",
+        "b53d21a4cfd562c469cc81514d4ce5a6b577d8403d32a394dc265dd190b47fa9f829fdd7963afdf972e5e77854051f6f",
+    ];
+    const MESSAGES: [[u8; 32]; 3] = [[0x00; 32], [0x56; 32], [0xab; 32]];
+    const SIGNATURES_OF_FIRST_MESSAGE: [&str; 3] = [
+        "b6ed936746e01f8ecf281f020953fbf1f01debd5657c4a383940b020b26507f6076334f91e2366c96e9ab279fb5158090352ea1c5b0c9274504f4f0e7053af24802e51e4568d164fe986834f41e55c8e850ce1f98458c0cfc9ab380b55285a55",
+        "b23c46be3a001c63ca711f87a005c200cc550b9429d5f4eb38d74322144f1b63926da3388979e5321012fb1a0526bcd100b5ef5fe72628ce4cd5e904aeaa3279527843fae5ca9ca675f4f51ed8f83bbf7155da9ecc9663100a885d5dc6df96d9",
+        "948a7cb99f76d616c2c564ce9bf4a519f1bea6b0a624a02276443c245854219fabb8d4ce061d255af5330b078d5380681751aa7053da2c98bae898edc218c75f07e24d8802a17cd1f6833b71e58f5eb5b94208b4d0bb3848cecb075ea21be115",
+    ];
+    const AGGREGATE_OF_FIRST_MESSAGE: &str = "9683b3e6701f9a4b706709577963110043af78a5b41991b998475a3d3fd62abf35ce03b33908418efc95a058494a8ae504354b9f626231f6b3f3c849dfdeaf5017c4780e2aee1850ceaf4b4d9ce70971a3d2cfcd97b7e5ecf6759f8da5f76d31";
+    // Ethereum consensus spec tests, bls/aggregate_verify
+    const AGGREGATE_OF_EACH_MESSAGE: &str = "9104e74b9dfd3ad502f25d6a5ef57db0ed7d9a0e00f3500586d8ce44231212542fcfaf87840539b398bf07626705cf1105d246ca1062c6c2e1a53029a0f790ed5e3cb1f52f8234dc5144c45fc847c0cd37a92d68e7c5ba7c648a8a339f171244";
+
+    fn secret_key(i: usize) -> SecretKey {
+        SecretKey::from_bytes(&decode_hex(SECRET_KEYS[i])).unwrap()
+    }
+
+    fn public_key(i: usize) -> PublicKey {
+        PublicKey::from_bytes(&decode_hex(PUBLIC_KEYS[i])).unwrap()
+    }
+
+    fn signature(hex: &str) -> Signature {
+        Signature::from_bytes(&decode_hex(hex)).unwrap()
+    }
+
+    // EIP-2333, test case 0
+    #[test]
+    fn key_gen_matches_the_eip_2333_master_key() {
+        let seed = decode_hex("c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04");
+        let sk = SecretKey::key_gen(&seed, b"").unwrap();
+        assert_eq!(
+            encode_hex(&sk.to_bytes()),
+            "0d7359d57963ab8fbbde1852dcf553fedbc31f464d80ee7d40ae683122b45070"
+        );
+    }
+
+    #[test]
+    fn key_gen_needs_32_bytes_of_key_material() {
+        assert_eq!(
+            SecretKey::key_gen(&[1; 31], b"").err(),
+            Some(BlsError::ShortKeyMaterial)
+        );
+        assert!(
+            SecretKey::key_gen(&[1; 32], b"").unwrap().to_bytes()
+                != SecretKey::key_gen(&[1; 32], b"info").unwrap().to_bytes()
+        );
+    }
+
+    #[test]
+    fn public_keys_and_signatures_match_the_ethereum_test_vectors() {
+        for i in 0..3 {
+            let sk = secret_key(i);
+            assert_eq!(encode_hex(&sk.public_key().to_bytes()), PUBLIC_KEYS[i]);
+            assert_eq!(
+                encode_hex(&sk.sign(&MESSAGES[0]).to_bytes()),
+                SIGNATURES_OF_FIRST_MESSAGE[i]
+            );
+        }
+    }
+
+    #[test]
+    fn verify_accepts_only_the_signed_message_and_key() {
+        let sig = signature(SIGNATURES_OF_FIRST_MESSAGE[0]);
+        assert!(public_key(0).verify(&MESSAGES[0], &sig));
+        assert!(!public_key(0).verify(&MESSAGES[1], &sig));
+        assert!(!public_key(1).verify(&MESSAGES[0], &sig));
+    }
+
+    #[test]
+    fn aggregate_signatures_match_the_ethereum_test_vectors() {
+        let signatures: Vec<Signature> = SIGNATURES_OF_FIRST_MESSAGE
+            .iter()
+            .map(|hex| signature(hex))
+            .collect();
+        let aggregate = Signature::aggregate(&signatures).unwrap();
+        assert_eq!(
+            encode_hex(&aggregate.to_bytes()),
+            AGGREGATE_OF_FIRST_MESSAGE
+        );
+        assert_eq!(Signature::aggregate(&[]), Err(BlsError::EmptyAggregate));
+    }
+
+    #[test]
+    fn fast_aggregate_verify_checks_a_common_message() {
+        let public_keys: Vec<PublicKey> = (0..3).map(public_key).collect();
+        let aggregate = signature(AGGREGATE_OF_FIRST_MESSAGE);
+        assert!(fast_aggregate_verify(
+            &public_keys,
+            &MESSAGES[0],
+            &aggregate
+        ));
+        assert!(!fast_aggregate_verify(
+            &public_keys[..2],
+            &MESSAGES[0],
+            &aggregate
+        ));
+        assert!(!fast_aggregate_verify(
+            &public_keys,
+            &MESSAGES[1],
+            &aggregate
+        ));
+        assert!(!fast_aggregate_verify(&[], &MESSAGES[0], &aggregate));
+    }
+
+    #[test]
+    fn aggregate_verify_checks_each_message() {
+        let public_keys: Vec<PublicKey> = (0..3).map(public_key).collect();
+        let msgs: Vec<&[u8]> = MESSAGES.iter().map(|msg| msg.as_slice()).collect();
+        let aggregate = signature(AGGREGATE_OF_EACH_MESSAGE);
+        assert!(aggregate_verify(&public_keys, &msgs, &aggregate));
+        let swapped = [msgs[1], msgs[0], msgs[2]];
+        assert!(!aggregate_verify(&public_keys, &swapped, &aggregate));
+        assert!(!aggregate_verify(&public_keys, &msgs[..2], &aggregate));
+    }
+
+    #[test]
+    fn proofs_of_possession_are_bound_to_their_key() {
+        let proof = secret_key(0).pop_prove();
+        assert!(public_key(0).pop_verify(&proof));
+        assert!(!public_key(1).pop_verify(&proof));
+        // A proof of possession is not a signature of the public key.
+        assert!(!public_key(0).verify(&public_key(0).to_bytes(), &proof));
+    }
+
+    #[test]
+    fn invalid_encodings_are_rejected() {
+        let mut infinity = [0; 48];
+        infinity[0] = 0xc0;
+        assert_eq!(
+            PublicKey::from_bytes(&infinity),
+            Err(BlsError::InvalidPublicKey)
+        );
+        assert_eq!(
+            PublicKey::from_bytes(&decode_hex(&PUBLIC_KEYS[0][2..])),
+            Err(BlsError::InvalidPublicKey)
+        );
+        // The G2 point with x = 2, which is on the curve but not in G2
+        let mut outside_subgroup = [0; 96];
+        outside_subgroup[0] = 0x80;
+        outside_subgroup[95] = 2;
+        assert!(matches!(
+            Signature::from_bytes(&outside_subgroup),
+            Err(BlsError::InvalidSignature)
+        ));
+        assert!(SecretKey::from_bytes(&[0; 32]).is_err());
+        assert!(SecretKey::from_bytes(&[0xff; 32]).is_err());
+    }
+}
@@ -0,0 +1,596 @@
+//! ECDSA over secp256k1 and secp256r1 with SHA-256, using the deterministic nonces of RFC 6979.
+//! https://www.rfc-editor.org/rfc/rfc6979
+//!
+//! Signing normalizes `s` to the lower half of the scalars, as Bitcoin and Ethereum require,
+//! and returns the [`RecoveryId`] needed to recover the public key from the signature.
+//! Public keys use the SEC 1 encodings, see https://www.secg.org/sec1-v2.pdf Section 2.3.3.
+//!
+//! The secret key and the nonces are multiplied with
+//! [`ShortWeierstrassProjectivePoint::operate_with_self_constant_time`] and the nonces are
+//! inverted with an exponentiation, so those steps don't branch on secret values.
+
+use crate::hash::hmac::hmac_sha256;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        glv::IsGLVCurve,
+        short_weierstrass::{
+            curves::{secp256k1::curve::Secp256k1Curve, secp256r1::curve::Secp256r1Curve},
+            point::ShortWeierstrassProjectivePoint,
+            traits::IsShortWeierstrass,
+        },
+        traits::{FromAffine, IsEllipticCurve},
+    },
+    field::{
+        element::FieldElement,
+        fields::{
+            secp256k1_scalarfield::Secp256k1ScalarField,
+            secp256r1_scalarfield::Secp256r1ScalarField,
+        },
+        traits::IsPrimeField,
+    },
+    traits::ByteConversion,
+    unsigned_integer::element::U256,
+};
+use sha2::{Digest, Sha256};
+
+/// Curves of 256 bits that can be used with ECDSA.
+pub trait IsEcdsaCurve:
+    IsShortWeierstrass<
+    BaseField: IsPrimeField<BaseType = U256, RepresentativeType = U256>,
+    PointRepresentation = ShortWeierstrassProjectivePoint<Self>,
+>
+{
+    /// Field of the scalars, whose modulus `n` is the order of the generator.
+    type ScalarField: IsPrimeField<BaseType = U256, RepresentativeType = U256>;
+
+    /// Returns `u1 * G + u2 * q` for public scalars, as needed to verify signatures. Its running
+    /// time may depend on the scalars.
+    fn double_scalar_mul(
+        u1: &U256,
+        u2: &U256,
+        q: &ShortWeierstrassProjectivePoint<Self>,
+    ) -> ShortWeierstrassProjectivePoint<Self> {
+        Self::generator()
+            .operate_with_self(*u1)
+            .operate_with(&q.operate_with_self(*u2))
+    }
+}
+
+impl IsEcdsaCurve for Secp256k1Curve {
+    type ScalarField = Secp256k1ScalarField;
+
+    /// Uses the endomorphism of secp256k1, which has prime order.
+    fn double_scalar_mul(
+        u1: &U256,
+        u2: &U256,
+        q: &ShortWeierstrassProjectivePoint<Self>,
+    ) -> ShortWeierstrassProjectivePoint<Self> {
+        Self::glv_mul(&Self::generator(), u1).operate_with(&Self::glv_mul(q, u2))
+    }
+}
+
+impl IsEcdsaCurve for Secp256r1Curve {
+    type ScalarField = Secp256r1ScalarField;
+}
+
+type Scalar<E> = FieldElement<<E as IsEcdsaCurve>::ScalarField>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum EcdsaError {
+    /// The bytes don't encode a scalar in `[1, n)`.
+    InvalidSecretKey,
+    /// The bytes are not the SEC 1 encoding of a point of the curve.
+    InvalidPublicKey,
+    /// The bytes don't encode a pair of scalars in `[1, n)`.
+    InvalidSignature,
+    /// The recovery id is not in `[0, 4)`.
+    InvalidRecoveryId,
+    /// No public key matches the signature and the recovery id.
+    RecoveryFailed,
+}
+
+impl Display for EcdsaError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EcdsaError::InvalidSecretKey => write!(f, "Invalid secret key"),
+            EcdsaError::InvalidPublicKey => write!(f, "Invalid public key"),
+            EcdsaError::InvalidSignature => write!(f, "Invalid signature"),
+            EcdsaError::InvalidRecoveryId => write!(f, "Invalid recovery id"),
+            EcdsaError::RecoveryFailed => write!(f, "Can't recover a public key"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EcdsaError {}
+
+#[derive(Clone)]
+pub struct SigningKey<E: IsEcdsaCurve> {
+    secret: Scalar<E>,
+    verifying_key: VerifyingKey<E>,
+}
+
+/// A public key, stored in affine coordinates.
+#[derive(Clone, Debug)]
+pub struct VerifyingKey<E: IsEcdsaCurve>(ShortWeierstrassProjectivePoint<E>);
+
+#[derive(Clone, Debug)]
+pub struct Signature<E: IsEcdsaCurve> {
+    r: Scalar<E>,
+    s: Scalar<E>,
+}
+
+/// The parity of the `y` coordinate of `R` in its lowest bit, and whether its `x`
+/// coordinate was reduced modulo `n` in the next one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RecoveryId(u8);
+
+impl<E: IsEcdsaCurve> SigningKey<E> {
+    /// Reads a secret key from its 32 bytes big-endian encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EcdsaError> {
+        let d = read_u256(bytes).ok_or(EcdsaError::InvalidSecretKey)?;
+        if d == U256::from_u64(0) || d >= scalar_modulus::<E>() {
+            return Err(EcdsaError::InvalidSecretKey);
+        }
+        let public = E::generator().operate_with_self_constant_time(&d);
+        Ok(Self {
+            secret: Scalar::<E>::new(d),
+            verifying_key: VerifyingKey(public.to_affine()),
+        })
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        u256_to_bytes(&self.secret.representative())
+    }
+
+    pub fn verifying_key(&self) -> &VerifyingKey<E> {
+        &self.verifying_key
+    }
+
+    /// Signs the SHA-256 digest of `msg`.
+    pub fn sign(&self, msg: &[u8]) -> (Signature<E>, RecoveryId) {
+        self.sign_prehash(&Sha256::digest(msg).into())
+    }
+
+    /// Signs a 32 bytes digest, returning a signature with low `s`.
+    pub fn sign_prehash(&self, digest: &[u8; 32]) -> (Signature<E>, RecoveryId) {
+        let n = scalar_modulus::<E>();
+        let z = scalar_from_digest::<E>(digest);
+        let mut nonces =
+            NonceGenerator::new(&self.to_bytes(), &u256_to_bytes(&z.representative()), n);
+        loop {
+            let k = nonces.next_nonce();
+            let r_point = E::generator()
+                .operate_with_self_constant_time(&k)
+                .to_affine();
+            let x = r_point.x().representative();
+            let r = Scalar::<E>::new(x);
+            if r == Scalar::<E>::zero() {
+                continue;
+            }
+            let k_inv = Scalar::<E>::new(k).pow(n - U256::from_u64(2));
+            let s = k_inv * (&z + &r * &self.secret);
+            if s == Scalar::<E>::zero() {
+                continue;
+            }
+            let recovery_id =
+                RecoveryId(is_odd(&r_point.y().representative()) as u8 | ((x >= n) as u8) << 1);
+            let signature = Signature { r, s };
+            return if signature.is_low_s() {
+                (signature, recovery_id)
+            } else {
+                (signature.normalize_s(), RecoveryId(recovery_id.0 ^ 1))
+            };
+        }
+    }
+}
+
+impl<E: IsEcdsaCurve> VerifyingKey<E> {
+    /// Reads a compressed or uncompressed SEC 1 encoding of a point other than the identity.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self, EcdsaError> {
+        let p = base_modulus::<E>();
+        let read_coordinate = |bytes: &[u8]| {
+            read_u256(bytes)
+                .filter(|coordinate| *coordinate < p)
+                .ok_or(EcdsaError::InvalidPublicKey)
+        };
+        let point = match (bytes.first(), bytes.len()) {
+            (Some(0x04), 65) => {
+                let x = read_coordinate(&bytes[1..33])?;
+                let y = read_coordinate(&bytes[33..])?;
+                ShortWeierstrassProjectivePoint::from_affine(
+                    FieldElement::new(x),
+                    FieldElement::new(y),
+                )
+                .map_err(|_| EcdsaError::InvalidPublicKey)?
+            }
+            (Some(prefix @ (0x02 | 0x03)), 33) => {
+                lift_x::<E>(read_coordinate(&bytes[1..])?, *prefix == 0x03)
+                    .ok_or(EcdsaError::InvalidPublicKey)?
+            }
+            _ => return Err(EcdsaError::InvalidPublicKey),
+        };
+        Ok(Self(point))
+    }
+
+    /// Returns the SEC 1 encoding of the point, of 33 bytes if `compressed` and 65 otherwise.
+    pub fn to_sec1_bytes(&self, compressed: bool) -> Vec<u8> {
+        let y = self.0.y().representative();
+        let mut bytes = Vec::with_capacity(65);
+        if compressed {
+            bytes.push(if is_odd(&y) { 0x03 } else { 0x02 });
+            bytes.extend_from_slice(&u256_to_bytes(&self.0.x().representative()));
+        } else {
+            bytes.push(0x04);
+            bytes.extend_from_slice(&u256_to_bytes(&self.0.x().representative()));
+            bytes.extend_from_slice(&u256_to_bytes(&y));
+        }
+        bytes
+    }
+
+    /// Checks a signature of the SHA-256 digest of `msg`. Signatures with high `s` are
+    /// accepted too; use [`Signature::is_low_s`] to reject them.
+    pub fn verify(&self, msg: &[u8], signature: &Signature<E>) -> bool {
+        self.verify_prehash(&Sha256::digest(msg).into(), signature)
+    }
+
+    pub fn verify_prehash(&self, digest: &[u8; 32], signature: &Signature<E>) -> bool {
+        let z = scalar_from_digest::<E>(digest);
+        let s_inv = signature.s.inv().expect("s is not zero");
+        let u1 = z * &s_inv;
+        let u2 = &signature.r * s_inv;
+        let r_point = E::double_scalar_mul(&u1.representative(), &u2.representative(), &self.0);
+        !r_point.is_neutral_element()
+            && Scalar::<E>::new(r_point.to_affine().x().representative()) == signature.r
+    }
+
+    /// Returns the public key that produced `signature` of `digest`.
+    pub fn recover_from_prehash(
+        digest: &[u8; 32],
+        signature: &Signature<E>,
+        recovery_id: RecoveryId,
+    ) -> Result<Self, EcdsaError> {
+        let mut x = signature.r.representative();
+        if recovery_id.is_x_reduced() {
+            let (sum, overflow) = U256::add(&x, &scalar_modulus::<E>());
+            if overflow {
+                return Err(EcdsaError::RecoveryFailed);
+            }
+            x = sum;
+        }
+        if x >= base_modulus::<E>() {
+            return Err(EcdsaError::RecoveryFailed);
+        }
+        let r_point = lift_x::<E>(x, recovery_id.is_y_odd()).ok_or(EcdsaError::RecoveryFailed)?;
+        let r_inv = signature.r.inv().expect("r is not zero");
+        let u1 = -(scalar_from_digest::<E>(digest) * &r_inv);
+        let u2 = &signature.s * r_inv;
+        let public = E::double_scalar_mul(&u1.representative(), &u2.representative(), &r_point);
+        if public.is_neutral_element() {
+            return Err(EcdsaError::RecoveryFailed);
+        }
+        Ok(Self(public.to_affine()))
+    }
+}
+
+impl<E: IsEcdsaCurve> PartialEq for VerifyingKey<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<E: IsEcdsaCurve> Eq for VerifyingKey<E> {}
+
+impl<E: IsEcdsaCurve> Signature<E> {
+    /// Reads the 64 bytes big-endian encoding of `r` followed by `s`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EcdsaError> {
+        if bytes.len() != 64 {
+            return Err(EcdsaError::InvalidSignature);
+        }
+        let n = scalar_modulus::<E>();
+        let read_scalar = |bytes: &[u8]| {
+            read_u256(bytes)
+                .filter(|scalar| *scalar != U256::from_u64(0) && *scalar < n)
+                .map(Scalar::<E>::new)
+                .ok_or(EcdsaError::InvalidSignature)
+        };
+        Ok(Self {
+            r: read_scalar(&bytes[..32])?,
+            s: read_scalar(&bytes[32..])?,
+        })
+    }
+
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&u256_to_bytes(&self.r.representative()));
+        bytes[32..].copy_from_slice(&u256_to_bytes(&self.s.representative()));
+        bytes
+    }
+
+    /// Whether `s` is at most `(n - 1) / 2`.
+    pub fn is_low_s(&self) -> bool {
+        self.s.representative() <= scalar_modulus::<E>() >> 1
+    }
+
+    /// Returns the signature with `s` replaced by `n - s` if it is high. Both verify the
+    /// same message, so only one of them should be accepted to avoid malleability.
+    pub fn normalize_s(&self) -> Self {
+        let s = if self.is_low_s() {
+            self.s.clone()
+        } else {
+            -&self.s
+        };
+        Self {
+            r: self.r.clone(),
+            s,
+        }
+    }
+}
+
+impl<E: IsEcdsaCurve> PartialEq for Signature<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.r == other.r && self.s == other.s
+    }
+}
+
+impl<E: IsEcdsaCurve> Eq for Signature<E> {}
+
+impl RecoveryId {
+    pub fn from_byte(byte: u8) -> Result<Self, EcdsaError> {
+        if byte < 4 {
+            Ok(Self(byte))
+        } else {
+            Err(EcdsaError::InvalidRecoveryId)
+        }
+    }
+
+    pub fn to_byte(self) -> u8 {
+        self.0
+    }
+
+    pub fn is_y_odd(self) -> bool {
+        self.0 & 1 == 1
+    }
+
+    pub fn is_x_reduced(self) -> bool {
+        self.0 & 2 == 2
+    }
+}
+
+/// The nonces of RFC 6979 Section 3.2 with HMAC-SHA256, for moduli of 256 bits.
+struct NonceGenerator {
+    k: [u8; 32],
+    v: [u8; 32],
+    modulus: U256,
+}
+
+impl NonceGenerator {
+    fn new(secret: &[u8; 32], reduced_digest: &[u8; 32], modulus: U256) -> Self {
+        let v = [1; 32];
+        let k = hmac_sha256(&[0; 32], &[&v, &[0], secret, reduced_digest]);
+        let v = hmac_sha256(&k, &[&v]);
+        let k = hmac_sha256(&k, &[&v, &[1], secret, reduced_digest]);
+        let v = hmac_sha256(&k, &[&v]);
+        Self { k, v, modulus }
+    }
+
+    /// Returns the next candidate in `[1, n)`.
+    fn next_nonce(&mut self) -> U256 {
+        loop {
+            self.v = hmac_sha256(&self.k, &[&self.v]);
+            let candidate = read_u256(&self.v).expect("v has 32 bytes");
+            let is_valid = candidate != U256::from_u64(0) && candidate < self.modulus;
+            self.k = hmac_sha256(&self.k, &[&self.v, &[0]]);
+            self.v = hmac_sha256(&self.k, &[&self.v]);
+            if is_valid {
+                return candidate;
+            }
+        }
+    }
+}
+
+pub(super) fn scalar_modulus<E: IsEcdsaCurve>() -> U256 {
+    E::ScalarField::modulus_minus_one() + U256::from_u64(1)
+}
+
+pub(super) fn base_modulus<E: IsEcdsaCurve>() -> U256 {
+    E::BaseField::modulus_minus_one() + U256::from_u64(1)
+}
+
+/// Returns the point with coordinate `x` whose `y` coordinate has parity `is_y_odd`.
+pub(super) fn lift_x<E: IsEcdsaCurve>(
+    x: U256,
+    is_y_odd: bool,
+) -> Option<ShortWeierstrassProjectivePoint<E>> {
+    let x = FieldElement::<E::BaseField>::new(x);
+    let (y, neg_y) = (x.square() * &x + E::a() * &x + E::b()).sqrt()?;
+    let y = if is_odd(&y.representative()) == is_y_odd {
+        y
+    } else {
+        neg_y
+    };
+    ShortWeierstrassProjectivePoint::from_affine(x, y).ok()
+}
+
+pub(super) fn is_odd(x: &U256) -> bool {
+    x.limbs[3] & 1 == 1
+}
+
+pub(super) fn read_u256(bytes: &[u8]) -> Option<U256> {
+    if bytes.len() != 32 {
+        return None;
+    }
+    U256::from_bytes_be(bytes).ok()
+}
+
+pub(super) fn u256_to_bytes(x: &U256) -> [u8; 32] {
+    let mut bytes = [0; 32];
+    for (chunk, limb) in bytes.chunks_exact_mut(8).zip(x.limbs) {
+        chunk.copy_from_slice(&limb.to_be_bytes());
+    }
+    bytes
+}
+
+/// The integer of the digest modulo `n`, which is `bits2int` followed by a reduction since
+/// the digest and `n` have the same length.
+fn scalar_from_digest<E: IsEcdsaCurve>(digest: &[u8; 32]) -> Scalar<E> {
+    Scalar::<E>::new(read_u256(digest).expect("the digest has 32 bytes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::decode_hex;
+
+    // RFC 6979 Appendix A.2.5
+    const P256_SECRET_KEY: &str =
+        "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+    const P256_PUBLIC_KEY: &str = "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299";
+
+    fn p256_key() -> SigningKey<Secp256r1Curve> {
+        SigningKey::from_bytes(&decode_hex(P256_SECRET_KEY)).unwrap()
+    }
+
+    fn k1_key() -> SigningKey<Secp256k1Curve> {
+        let mut secret = [0; 32];
+        secret[31] = 1;
+        SigningKey::from_bytes(&secret).unwrap()
+    }
+
+    #[test]
+    fn p256_signatures_match_the_rfc_6979_test_vectors() {
+        let key = p256_key();
+        assert_eq!(
+            key.verifying_key().to_sec1_bytes(false),
+            decode_hex(P256_PUBLIC_KEY)
+        );
+
+        // The `s` of the first vector is high, so it is replaced by `n - s`.
+        let vectors: [(&[u8], &str, &str, u8); 2] = [
+            (
+                b"sample",
+                "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716",
+                "0834e36ad29a83bf2bc9385e491d6099c8fdf9d1ed67aa7ea5f51f93782857a9",
+                1,
+            ),
+            (
+                b"test",
+                "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367",
+                "019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083",
+                0,
+            ),
+        ];
+        for (msg, r, s, recovery_id) in vectors {
+            let (signature, id) = key.sign(msg);
+            assert_eq!(signature.to_bytes().to_vec(), decode_hex(&[r, s].concat()));
+            assert_eq!(id, RecoveryId::from_byte(recovery_id).unwrap());
+            assert!(key.verifying_key().verify(msg, &signature));
+        }
+    }
+
+    #[test]
+    fn secp256k1_signature_matches_the_known_rfc_6979_vector() {
+        let (signature, id) = k1_key().sign(b"Satoshi Nakamoto");
+        assert_eq!(
+            signature.to_bytes().to_vec(),
+            decode_hex("934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d82442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5")
+        );
+        assert_eq!(id.to_byte(), 1);
+    }
+
+    #[test]
+    fn verify_rejects_other_messages_and_keys() {
+        let key = k1_key();
+        let (signature, _) = key.sign(b"message");
+        assert!(key.verifying_key().verify(b"message", &signature));
+        assert!(!key.verifying_key().verify(b"other message", &signature));
+
+        let other_key = SigningKey::<Secp256k1Curve>::from_bytes(&[7; 32]).unwrap();
+        assert!(!other_key.verifying_key().verify(b"message", &signature));
+    }
+
+    #[test]
+    fn high_s_signatures_verify_but_are_not_low_s() {
+        let key = p256_key();
+        let (signature, _) = key.sign(b"sample");
+        let high_s = Signature::<Secp256r1Curve> {
+            r: signature.r.clone(),
+            s: -&signature.s,
+        };
+        assert!(!high_s.is_low_s());
+        assert!(key.verifying_key().verify(b"sample", &high_s));
+        assert_eq!(high_s.normalize_s(), signature);
+    }
+
+    #[test]
+    fn recover_returns_the_signing_key() {
+        let digest: [u8; 32] = Sha256::digest(b"recover me").into();
+        let k1 = k1_key();
+        let (signature, id) = k1.sign_prehash(&digest);
+        assert_eq!(
+            VerifyingKey::recover_from_prehash(&digest, &signature, id).as_ref(),
+            Ok(k1.verifying_key())
+        );
+        let flipped = RecoveryId::from_byte(id.to_byte() ^ 1).unwrap();
+        assert_ne!(
+            VerifyingKey::recover_from_prehash(&digest, &signature, flipped).as_ref(),
+            Ok(k1.verifying_key())
+        );
+
+        let p256 = p256_key();
+        let (signature, id) = p256.sign_prehash(&digest);
+        assert_eq!(
+            VerifyingKey::recover_from_prehash(&digest, &signature, id).as_ref(),
+            Ok(p256.verifying_key())
+        );
+        assert_eq!(RecoveryId::from_byte(4), Err(EcdsaError::InvalidRecoveryId));
+    }
+
+    #[test]
+    fn sec1_encodings_round_trip() {
+        let key = p256_key();
+        for compressed in [true, false] {
+            let bytes = key.verifying_key().to_sec1_bytes(compressed);
+            assert_eq!(bytes.len(), if compressed { 33 } else { 65 });
+            assert_eq!(
+                VerifyingKey::<Secp256r1Curve>::from_sec1_bytes(&bytes).as_ref(),
+                Ok(key.verifying_key())
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_encodings_are_rejected() {
+        let mut off_curve = decode_hex(P256_PUBLIC_KEY);
+        off_curve[64] ^= 1;
+        assert_eq!(
+            VerifyingKey::<Secp256r1Curve>::from_sec1_bytes(&off_curve),
+            Err(EcdsaError::InvalidPublicKey)
+        );
+        let mut wrong_prefix = decode_hex(P256_PUBLIC_KEY);
+        wrong_prefix[0] = 0x05;
+        assert_eq!(
+            VerifyingKey::<Secp256r1Curve>::from_sec1_bytes(&wrong_prefix),
+            Err(EcdsaError::InvalidPublicKey)
+        );
+        assert_eq!(
+            VerifyingKey::<Secp256r1Curve>::from_sec1_bytes(&[0]),
+            Err(EcdsaError::InvalidPublicKey)
+        );
+
+        let n = u256_to_bytes(&scalar_modulus::<Secp256k1Curve>());
+        assert!(SigningKey::<Secp256k1Curve>::from_bytes(&[0; 32]).is_err());
+        assert!(SigningKey::<Secp256k1Curve>::from_bytes(&n).is_err());
+        assert_eq!(
+            Signature::<Secp256k1Curve>::from_bytes(&[n, [1; 32]].concat()),
+            Err(EcdsaError::InvalidSignature)
+        );
+        assert_eq!(
+            Signature::<Secp256k1Curve>::from_bytes(&[[1; 32], [0; 32]].concat()),
+            Err(EcdsaError::InvalidSignature)
+        );
+    }
+}
@@ -0,0 +1,372 @@
+//! EdDSA signatures over Ed448-Goldilocks with SHAKE256, as specified in RFC 8032 Section 5.2.
+//! https://www.rfc-editor.org/rfc/rfc8032
+//!
+//! Public keys are the 57 bytes encoding of a point and signatures are the encoding of the
+//! nonce point followed by the 57 bytes little-endian encoding of the scalar `S`.
+//! Messages can be signed under a context of up to 255 bytes, which is empty by default.
+//!
+//! There is no constant time scalar multiplication for Edwards curves yet: the public key and
+//! the nonce point `R` are computed with [`IsGroup::operate_with_self`], whose sequence of
+//! doublings and additions follows the bits of the secret scalar and of the nonce. The time it
+//! takes to create a key or to sign leaks information about them.
+
+use alloc::vec::Vec;
+use core::fmt::Display;
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        edwards::{curves::ed448_goldilocks::Ed448Goldilocks, point::EdwardsProjectivePoint},
+        traits::IsEllipticCurve,
+    },
+    field::{
+        element::FieldElement, fields::ed448_goldilocks_scalarfield::Ed448GoldilocksScalarField,
+        traits::IsPrimeField,
+    },
+    traits::ByteConversion,
+    unsigned_integer::element::UnsignedInteger,
+};
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+
+type Point = EdwardsProjectivePoint<Ed448Goldilocks>;
+type Scalar = FieldElement<Ed448GoldilocksScalarField>;
+
+/// Length of secret keys, public keys and scalars.
+const KEY_LENGTH: usize = 57;
+/// Length of the outputs of SHAKE256 used by Ed448.
+const HASH_LENGTH: usize = 2 * KEY_LENGTH;
+/// Prefix of the `dom4` domain separation of RFC 8032.
+const DOM4_PREFIX: &[u8] = b"SigEd448";
+/// Maximum length of a context.
+const MAX_CONTEXT_LENGTH: usize = 255;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Ed448Error {
+    /// A secret key must have 57 bytes.
+    InvalidSecretKey,
+    /// The bytes are not the encoding of a point of Ed448.
+    InvalidPublicKey,
+    /// A signature must have 114 bytes.
+    InvalidSignature,
+    /// Contexts can't be longer than 255 bytes.
+    ContextTooLong,
+}
+
+impl Display for Ed448Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Ed448Error::InvalidSecretKey => write!(f, "Invalid secret key"),
+            Ed448Error::InvalidPublicKey => write!(f, "Invalid public key"),
+            Ed448Error::InvalidSignature => write!(f, "Invalid signature"),
+            Ed448Error::ContextTooLong => {
+                write!(f, "The context can't be longer than 255 bytes")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Ed448Error {}
+
+#[derive(Clone)]
+pub struct SigningKey {
+    secret: [u8; KEY_LENGTH],
+    /// The secret scalar `s`, derived from the first half of the hash of the secret.
+    scalar: Scalar,
+    /// The second half of the hash of the secret, used to derive the nonces.
+    prefix: [u8; KEY_LENGTH],
+    verifying_key: VerifyingKey,
+}
+
+/// A public key, which keeps its encoding to hash it when signing and verifying.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKey {
+    point: Point,
+    encoding: [u8; KEY_LENGTH],
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature([u8; HASH_LENGTH]);
+
+impl SigningKey {
+    /// Reads a secret key from its 57 bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Ed448Error> {
+        let secret: [u8; KEY_LENGTH] =
+            bytes.try_into().map_err(|_| Ed448Error::InvalidSecretKey)?;
+        let hash = shake256(&[&secret]);
+
+        // Prune the buffer as in RFC 8032 Section 5.2.5
+        let mut buffer = [0u8; KEY_LENGTH];
+        buffer.copy_from_slice(&hash[..KEY_LENGTH]);
+        buffer[0] &= 0xfc;
+        buffer[KEY_LENGTH - 2] |= 0x80;
+        buffer[KEY_LENGTH - 1] = 0;
+        let scalar = Scalar::new(read_u448_le(&buffer[..KEY_LENGTH - 1]));
+
+        let mut prefix = [0u8; KEY_LENGTH];
+        prefix.copy_from_slice(&hash[KEY_LENGTH..]);
+        let point = Ed448Goldilocks::generator()
+            .operate_with_self(scalar.representative())
+            .to_affine();
+        Ok(Self {
+            secret,
+            scalar,
+            prefix,
+            verifying_key: VerifyingKey::from_point(point),
+        })
+    }
+
+    pub fn to_bytes(&self) -> [u8; KEY_LENGTH] {
+        self.secret
+    }
+
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Signs `msg` with an empty context.
+    pub fn sign(&self, msg: &[u8]) -> Signature {
+        self.sign_with_context(msg, &[])
+            .expect("the empty context is valid")
+    }
+
+    pub fn sign_with_context(&self, msg: &[u8], context: &[u8]) -> Result<Signature, Ed448Error> {
+        let dom4 = dom4(context)?;
+        let r = scalar_from_hash(&shake256(&[&dom4, &self.prefix, msg]));
+        let r_encoding = Ed448Goldilocks::generator()
+            .operate_with_self(r.representative())
+            .encode();
+        let k = scalar_from_hash(&shake256(&[
+            &dom4,
+            &r_encoding,
+            &self.verifying_key.encoding,
+            msg,
+        ]));
+        let s = r + k * &self.scalar;
+
+        let mut signature = [0u8; HASH_LENGTH];
+        signature[..KEY_LENGTH].copy_from_slice(&r_encoding);
+        signature[KEY_LENGTH..HASH_LENGTH - 1].copy_from_slice(&s.representative().to_bytes_le());
+        Ok(Signature(signature))
+    }
+}
+
+impl VerifyingKey {
+    fn from_point(point: Point) -> Self {
+        let mut encoding = [0u8; KEY_LENGTH];
+        encoding.copy_from_slice(&point.encode());
+        Self { point, encoding }
+    }
+
+    /// Reads a public key from the encoding of a point.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Ed448Error> {
+        let point = Point::decode(bytes).map_err(|_| Ed448Error::InvalidPublicKey)?;
+        Ok(Self::from_point(point))
+    }
+
+    pub fn to_bytes(&self) -> [u8; KEY_LENGTH] {
+        self.encoding
+    }
+
+    /// Verifies a signature of `msg` with an empty context.
+    pub fn verify(&self, msg: &[u8], signature: &Signature) -> bool {
+        self.verify_with_context(msg, &[], signature)
+    }
+
+    /// Verifies a signature with the cofactored equation `[4][S]B = [4]R + [4][k]A`.
+    pub fn verify_with_context(&self, msg: &[u8], context: &[u8], signature: &Signature) -> bool {
+        let Ok(dom4) = dom4(context) else {
+            return false;
+        };
+        let (r_encoding, s_encoding) = signature.0.split_at(KEY_LENGTH);
+        let Ok(r_point) = Point::decode(r_encoding) else {
+            return false;
+        };
+        // S must be smaller than the order of the group
+        if s_encoding[KEY_LENGTH - 1] != 0 {
+            return false;
+        }
+        let s = read_u448_le(&s_encoding[..KEY_LENGTH - 1]);
+        if s > Ed448GoldilocksScalarField::modulus_minus_one() {
+            return false;
+        }
+
+        let k = scalar_from_hash(&shake256(&[&dom4, r_encoding, &self.encoding, msg]));
+        let lhs = Ed448Goldilocks::generator().operate_with_self(s);
+        let rhs = r_point.operate_with(&self.point.operate_with_self(k.representative()));
+        lhs.operate_with(&rhs.neg())
+            .operate_with_self(4_u64)
+            .is_neutral_element()
+    }
+}
+
+impl Signature {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Ed448Error> {
+        bytes
+            .try_into()
+            .map(Self)
+            .map_err(|_| Ed448Error::InvalidSignature)
+    }
+
+    pub fn to_bytes(&self) -> [u8; HASH_LENGTH] {
+        self.0
+    }
+}
+
+/// `dom4(0, context)` of RFC 8032, which separates Ed448 from its prehashed variant.
+fn dom4(context: &[u8]) -> Result<Vec<u8>, Ed448Error> {
+    if context.len() > MAX_CONTEXT_LENGTH {
+        return Err(Ed448Error::ContextTooLong);
+    }
+    let mut dom4 = DOM4_PREFIX.to_vec();
+    dom4.extend_from_slice(&[0, context.len() as u8]);
+    dom4.extend_from_slice(context);
+    Ok(dom4)
+}
+
+/// 114 bytes of SHAKE256 of the concatenation of `data`.
+fn shake256(data: &[&[u8]]) -> [u8; HASH_LENGTH] {
+    let mut shake = Shake256::default();
+    for chunk in data {
+        shake.update(chunk);
+    }
+    let mut hash = [0u8; HASH_LENGTH];
+    shake.finalize_xof().read(&mut hash);
+    hash
+}
+
+/// Reduces a 114 bytes little-endian integer modulo the order of the group.
+fn scalar_from_hash(hash: &[u8; HASH_LENGTH]) -> Scalar {
+    // The hash is split in limbs of 448, 448 and 16 bits, which are less than 2^448
+    // and thus correctly reduced by `Scalar::new`.
+    let two_to_448 = Scalar::from(2).pow(448_u64);
+    hash.chunks(KEY_LENGTH - 1)
+        .rev()
+        .map(|chunk| Scalar::new(read_u448_le(chunk)))
+        .fold(Scalar::zero(), |acc, limb| acc * &two_to_448 + limb)
+}
+
+/// Reads a little-endian integer of at most 56 bytes.
+fn read_u448_le(bytes: &[u8]) -> UnsignedInteger<7> {
+    let mut padded = [0u8; KEY_LENGTH - 1];
+    padded[..bytes.len()].copy_from_slice(bytes);
+    UnsignedInteger::from_bytes_le(&padded).expect("56 bytes fit in 448 bits")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::decode_hex;
+
+    // RFC 8032 Section 7.4: the blank message, the 1 octet message and the 1 octet message
+    // with context "foo"
+    const VECTORS: [(&str, &str, &str, &str, &str); 3] = [
+        (
+            "6c82a562cb808d10d632be89c8513ebf6c929f34ddfa8c9f63c9960ef6e348a3528c8a3fcc2f044e39a3fc5b94492f8f032e7549a20098f95b",
+            "5fd7449b59b461fd2ce787ec616ad46a1da1342485a70e1f8a0ea75d80e96778edf124769b46c7061bd6783df1e50f6cd1fa1abeafe8256180",
+            "",
+            "",
+            "533a37f6bbe457251f023c0d88f976ae2dfb504a843e34d2074fd823d41a591f2b233f034f628281f2fd7a22ddd47d7828c59bd0a21bfd3980ff0d2028d4b18a9df63e006c5d1c2d345b925d8dc00b4104852db99ac5c7cdda8530a113a0f4dbb61149f05a7363268c71d95808ff2e652600",
+        ),
+        (
+            "c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e",
+            "43ba28f430cdff456ae531545f7ecd0ac834a55d9358c0372bfa0c6c6798c0866aea01eb00742802b8438ea4cb82169c235160627b4c3a9480",
+            "03",
+            "",
+            "26b8f91727bd62897af15e41eb43c377efb9c610d48f2335cb0bd0087810f4352541b143c4b981b7e18f62de8ccdf633fc1bf037ab7cd779805e0dbcc0aae1cbcee1afb2e027df36bc04dcecbf154336c19f0af7e0a6472905e799f1953d2a0ff3348ab21aa4adafd1d234441cf807c03a00",
+        ),
+        (
+            "c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463afbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e",
+            "43ba28f430cdff456ae531545f7ecd0ac834a55d9358c0372bfa0c6c6798c0866aea01eb00742802b8438ea4cb82169c235160627b4c3a9480",
+            "03",
+            "666f6f",
+            "d4f8f6131770dd46f40867d6fd5d5055de43541f8c5e35abbcd001b32a89f7d2151f7647f11d8ca2ae279fb842d607217fce6e042f6815ea000c85741de5c8da1144a6a1aba7f96de42505d7a7298524fda538fccbbb754f578c1cad10d54d0d5428407e85dcbc98a49155c13764e66c3c00",
+        ),
+    ];
+
+    #[test]
+    fn point_encoding_matches_rfc_8032() {
+        let g = Ed448Goldilocks::generator();
+        assert_eq!(
+            g.encode(),
+            decode_hex("14fa30f25b790898adc8d74e2c13bdfdc4397ce61cffd33ad7c2a0051e9c78874098a36c7373ea4b62c7c9563720768824bcb66e71463f6900")
+        );
+        assert_eq!(
+            g.operate_with_self(5u64).encode(),
+            decode_hex("eb35f4721473b44354221f88125540583cb3d259eea4d727710198b6f75165d8ce8fb13a82a10c26de0a58fde49c10b9d3ed17251a75fdad00")
+        );
+    }
+
+    #[test]
+    fn signatures_match_the_rfc_8032_test_vectors() {
+        for (secret, public, msg, context, expected) in VECTORS {
+            let key = SigningKey::from_bytes(&decode_hex(secret)).unwrap();
+            assert_eq!(key.verifying_key().to_bytes().to_vec(), decode_hex(public));
+            let (msg, context) = (decode_hex(msg), decode_hex(context));
+            let signature = key.sign_with_context(&msg, &context).unwrap();
+            assert_eq!(signature.to_bytes().to_vec(), decode_hex(expected));
+
+            let public_key = VerifyingKey::from_bytes(&decode_hex(public)).unwrap();
+            assert!(public_key.verify_with_context(&msg, &context, &signature));
+        }
+    }
+
+    #[test]
+    fn verify_rejects_tampered_signatures() {
+        let (_, public, msg, _, signature) = VECTORS[1];
+        let public_key = VerifyingKey::from_bytes(&decode_hex(public)).unwrap();
+        let msg = decode_hex(msg);
+        let signature = decode_hex(signature);
+        let valid = Signature::from_bytes(&signature).unwrap();
+        assert!(public_key.verify(&msg, &valid));
+
+        assert!(!public_key.verify(&[0x04], &valid));
+        assert!(!public_key.verify_with_context(&msg, b"foo", &valid));
+        let other_key = VerifyingKey::from_bytes(&decode_hex(VECTORS[0].1)).unwrap();
+        assert!(!other_key.verify(&msg, &valid));
+
+        for i in [0, 57, 112] {
+            let mut tampered = signature.clone();
+            tampered[i] ^= 1;
+            assert!(!public_key.verify(&msg, &Signature::from_bytes(&tampered).unwrap()));
+        }
+
+        // S + L is rejected although it is the same scalar
+        let s = read_u448_le(&signature[57..113]);
+        let l = Ed448GoldilocksScalarField::modulus_minus_one() + UnsignedInteger::from_u64(1);
+        let mut high_s = signature.clone();
+        high_s[57..113].copy_from_slice(&(s + l).to_bytes_le());
+        assert!(!public_key.verify(&msg, &Signature::from_bytes(&high_s).unwrap()));
+    }
+
+    #[test]
+    fn invalid_inputs_are_rejected() {
+        assert!(matches!(
+            SigningKey::from_bytes(&[0; 32]),
+            Err(Ed448Error::InvalidSecretKey)
+        ));
+        // y = 2 is not the y coordinate of any point
+        let mut public_key = [0; 57];
+        public_key[0] = 2;
+        assert_eq!(
+            VerifyingKey::from_bytes(&public_key),
+            Err(Ed448Error::InvalidPublicKey)
+        );
+        assert_eq!(
+            Signature::from_bytes(&[0; 64]),
+            Err(Ed448Error::InvalidSignature)
+        );
+
+        let key = SigningKey::from_bytes(&decode_hex(VECTORS[0].0)).unwrap();
+        assert_eq!(
+            key.sign_with_context(b"msg", &[0; 256]),
+            Err(Ed448Error::ContextTooLong)
+        );
+        let signature = key.sign(b"msg");
+        assert!(!key
+            .verifying_key()
+            .verify_with_context(b"msg", &[0; 256], &signature));
+    }
+}
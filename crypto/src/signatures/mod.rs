@@ -0,0 +1,4 @@
+pub mod bls;
+pub mod ecdsa;
+pub mod ed448;
+pub mod schnorr;
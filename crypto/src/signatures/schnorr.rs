@@ -0,0 +1,473 @@
+//! Schnorr signatures over secp256k1, as specified in BIP-340.
+//! https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+//!
+//! Public keys are the 32 bytes `x` coordinate of a point with even `y`, and signatures
+//! are the `x` coordinate of the nonce point followed by the scalar `s`.
+
+use super::ecdsa::{
+    base_modulus, is_odd, lift_x, read_u256, scalar_modulus, u256_to_bytes, IsEcdsaCurve,
+};
+use alloc::vec::Vec;
+use core::fmt::Display;
+use lambdaworks_math::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        short_weierstrass::{
+            curves::secp256k1::curve::Secp256k1Curve, point::ShortWeierstrassProjectivePoint,
+        },
+        traits::IsEllipticCurve,
+    },
+    field::{element::FieldElement, fields::secp256k1_scalarfield::Secp256k1ScalarField},
+    unsigned_integer::element::U256,
+};
+use sha2::{Digest, Sha256};
+
+type Point = ShortWeierstrassProjectivePoint<Secp256k1Curve>;
+type Scalar = FieldElement<Secp256k1ScalarField>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SchnorrError {
+    /// The bytes don't encode a scalar in `[1, n)`.
+    InvalidSecretKey,
+    /// The bytes are not the `x` coordinate of a point of secp256k1.
+    InvalidPublicKey,
+    /// A signature must have 64 bytes.
+    InvalidSignature,
+}
+
+impl Display for SchnorrError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SchnorrError::InvalidSecretKey => write!(f, "Invalid secret key"),
+            SchnorrError::InvalidPublicKey => write!(f, "Invalid public key"),
+            SchnorrError::InvalidSignature => write!(f, "Invalid signature"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SchnorrError {}
+
+#[derive(Clone)]
+pub struct SigningKey {
+    /// The secret key, negated if needed so that its public point has even `y`.
+    secret: Scalar,
+    verifying_key: VerifyingKey,
+}
+
+/// A public key, stored as the point with even `y` in affine coordinates.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKey(Point);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature([u8; 64]);
+
+impl SigningKey {
+    /// Reads a secret key from its 32 bytes big-endian encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SchnorrError> {
+        let d = read_u256(bytes).ok_or(SchnorrError::InvalidSecretKey)?;
+        if d == U256::from_u64(0) || d >= scalar_modulus::<Secp256k1Curve>() {
+            return Err(SchnorrError::InvalidSecretKey);
+        }
+        let public = Secp256k1Curve::generator()
+            .operate_with_self_constant_time(&d)
+            .to_affine();
+        let secret = Scalar::new(d);
+        Ok(if has_even_y(&public) {
+            Self {
+                secret,
+                verifying_key: VerifyingKey(public),
+            }
+        } else {
+            Self {
+                secret: -secret,
+                verifying_key: VerifyingKey(public.neg()),
+            }
+        })
+    }
+
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Signs `msg` with 32 bytes of fresh auxiliary randomness, which protect the nonce
+    /// against side channels. All zeros can be used when there is no randomness available.
+    pub fn sign(&self, msg: &[u8], aux_rand: &[u8; 32]) -> Signature {
+        let d = u256_to_bytes(&self.secret.representative());
+        let aux_hash = tagged_hash("BIP0340/aux", &[aux_rand]);
+        let t: Vec<u8> = d.iter().zip(aux_hash).map(|(a, b)| a ^ b).collect();
+        let public_key = self.verifying_key.to_bytes();
+        let nonce = tagged_hash("BIP0340/nonce", &[&t, &public_key, msg]);
+        let k = Scalar::new(read_u256(&nonce).expect("the hash has 32 bytes"));
+        assert!(
+            k != Scalar::zero(),
+            "the nonce is zero with negligible probability"
+        );
+
+        let r_point = Secp256k1Curve::generator()
+            .operate_with_self_constant_time(&k.representative())
+            .to_affine();
+        let k = if has_even_y(&r_point) { k } else { -k };
+        let r = u256_to_bytes(&r_point.x().representative());
+        let e = challenge(&r, &public_key, msg);
+
+        let mut signature = [0; 64];
+        signature[..32].copy_from_slice(&r);
+        signature[32..].copy_from_slice(&u256_to_bytes(&(k + e * &self.secret).representative()));
+        Signature(signature)
+    }
+}
+
+impl VerifyingKey {
+    /// Reads a public key from the `x` coordinate of the point, as in `lift_x`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SchnorrError> {
+        read_u256(bytes)
+            .filter(|x| *x < base_modulus::<Secp256k1Curve>())
+            .and_then(|x| lift_x::<Secp256k1Curve>(x, false))
+            .map(Self)
+            .ok_or(SchnorrError::InvalidPublicKey)
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        u256_to_bytes(&self.0.x().representative())
+    }
+
+    pub fn verify(&self, msg: &[u8], signature: &Signature) -> bool {
+        let (r, s) = signature.0.split_at(32);
+        let (Some(r_x), Some(s)) = (read_u256(r), read_u256(s)) else {
+            return false;
+        };
+        if r_x >= base_modulus::<Secp256k1Curve>() || s >= scalar_modulus::<Secp256k1Curve>() {
+            return false;
+        }
+        let e = challenge(r, &self.to_bytes(), msg);
+        let r_point = Secp256k1Curve::double_scalar_mul(&s, &(-e).representative(), &self.0);
+        if r_point.is_neutral_element() {
+            return false;
+        }
+        let r_point = r_point.to_affine();
+        has_even_y(&r_point) && r_point.x().representative() == r_x
+    }
+}
+
+impl Signature {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SchnorrError> {
+        bytes
+            .try_into()
+            .map(Self)
+            .map_err(|_| SchnorrError::InvalidSignature)
+    }
+
+    pub fn to_bytes(&self) -> [u8; 64] {
+        self.0
+    }
+}
+
+/// `SHA256(SHA256(tag) || SHA256(tag) || data)`, where `data` is the concatenation of its parts.
+fn tagged_hash(tag: &str, data: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag.as_bytes());
+    data.iter()
+        .fold(
+            Sha256::new().chain_update(tag_hash).chain_update(tag_hash),
+            |hasher, chunk| hasher.chain_update(chunk),
+        )
+        .finalize()
+        .into()
+}
+
+fn challenge(r: &[u8], public_key: &[u8], msg: &[u8]) -> Scalar {
+    let hash = tagged_hash("BIP0340/challenge", &[r, public_key, msg]);
+    Scalar::new(read_u256(&hash).expect("the hash has 32 bytes"))
+}
+
+fn has_even_y(point: &Point) -> bool {
+    !is_odd(&point.y().representative())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::decode_hex;
+
+    struct Vector {
+        index: usize,
+        /// Empty for the vectors that only test verification.
+        secret_key: &'static str,
+        public_key: &'static str,
+        aux_rand: &'static str,
+        msg: &'static str,
+        signature: &'static str,
+        valid: bool,
+    }
+
+    // https://github.com/bitcoin/bips/blob/master/bip-0340/test-vectors.csv
+    const VECTORS: [Vector; 19] = [
+        Vector {
+            index: 0,
+            secret_key: "0000000000000000000000000000000000000000000000000000000000000003",
+            public_key: "f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+            aux_rand: "0000000000000000000000000000000000000000000000000000000000000000",
+            msg: "0000000000000000000000000000000000000000000000000000000000000000",
+            signature: "e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca821525f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0",
+            valid: true,
+        },
+        Vector {
+            index: 1,
+            secret_key: "b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef",
+            public_key: "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+            aux_rand: "0000000000000000000000000000000000000000000000000000000000000001",
+            msg: "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+            signature: "6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de33418906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a",
+            valid: true,
+        },
+        Vector {
+            index: 2,
+            secret_key: "c90fdaa22168c234c4c6628b80dc1cd129024e088a67cc74020bbea63b14e5c9",
+            public_key: "dd308afec5777e13121fa72b9cc1b7cc0139715309b086c960e18fd969774eb8",
+            aux_rand: "c87aa53824b4d7ae2eb035a2b5bbbccc080e76cdc6d1692c4b0b62d798e6d906",
+            msg: "7e2d58d8b3bcdf1abadec7829054f90dda9805aab56c77333024b9d0a508b75c",
+            signature: "5831aaeed7b44bb74e5eab94ba9d4294c49bcf2a60728d8b4c200f50dd313c1bab745879a5ad954a72c45a91c3a51d3c7adea98d82f8481e0e1e03674a6f3fb7",
+            valid: true,
+        },
+        // test fails if msg is reduced modulo p or n
+        Vector {
+            index: 3,
+            secret_key: "0b432b2677937381aef05bb02a66ecd012773062cf3fa2549e44f58ed2401710",
+            public_key: "25d1dff95105f5253c4022f628a996ad3a0d95fbf21d468a1b33f8c160d8f517",
+            aux_rand: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            msg: "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            signature: "7eb0509757e246f19449885651611cb965ecc1a187dd51b64fda1edc9637d5ec97582b9cb13db3933705b32ba982af5af25fd78881ebb32771fc5922efc66ea3",
+            valid: true,
+        },
+        Vector {
+            index: 4,
+            secret_key: "",
+            public_key: "d69c3509bb99e412e68b0fe8544e72837dfa30746d8be2aa65975f29d22dc7b9",
+            aux_rand: "",
+            msg: "4df3c3f68fcc83b27e9d42c90431a72499f17875c81a599b566c9889b9696703",
+            signature: "00000000000000000000003b78ce563f89a0ed9414f5aa28ad0d96d6795f9c6376afb1548af603b3eb45c9f8207dee1060cb71c04e80f593060b07d28308d7f4",
+            valid: true,
+        },
+        // public key not on curve
+        Vector {
+            index: 5,
+            secret_key: "",
+            public_key: "eefdea4cdb677750a420fee807eacf21eb9898ae79b9768766e4faa04a2d4a34",
+            aux_rand: "",
+            msg: "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+            signature: "6cff5c3ba86c69ea4b7376f31a9bcb4f74c1976089b2d9963da2e5543e17776969e89b4c5564d00349106b8497785dd7d1d713a8ae82b32fa79d5f7fc407d39b",
+            valid: false,
+        },
+        // has_even_y(R) is false
+        Vector {
+            index: 6,
+            secret_key: "",
+            public_key: "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+            aux_rand: "",
+            msg: "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+            signature: "fff97bd5755eeea420453a14355235d382f6472f8568a18b2f057a14602975563cc27944640ac607cd107ae10923d9ef7a73c643e166be5ebeafa34b1ac553e2",
+            valid: false,
+        },
+        // negated message
+        Vector {
+            index: 7,
+            secret_key: "",
+            public_key: "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+            aux_rand: "",
+            msg: "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+            signature: "1fa62e331edbc21c394792d2ab1100a7b432b013df3f6ff4f99fcb33e0e1515f28890b3edb6e7189b630448b515ce4f8622a954cfe545735aaea5134fccdb2bd",
+            valid: false,
+        },
+        // negated s value
+        Vector {
+            index: 8,
+            secret_key: "",
+            public_key: "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+            aux_rand: "",
+            msg: "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+            signature: "6cff5c3ba86c69ea4b7376f31a9bcb4f74c1976089b2d9963da2e5543e177769961764b3aa9b2ffcb6ef947b6887a226e8d7c93e00c5ed0c1834ff0d0c2e6da6",
+            valid: false,
+        },
+        // sG - eP is infinite. Test fails in single verification if has_even_y(inf) is
+        // defined as true and x(inf) as 0
+        Vector {
+            index: 9,
+            secret_key: "",
+            public_key: "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+            aux_rand: "",
+            msg: "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+            signature: "0000000000000000000000000000000000000000000000000000000000000000123dda8328af9c23a94c1feecfd123ba4fb73476f0d594dcb65c6425bd186051",
+            valid: false,
+        },
+        // sG - eP is infinite. Test fails in single verification if has_even_y(inf) is
+        // defined as true and x(inf) as 1
+        Vector {
+            index: 10,
+            secret_key: "",
+            public_key: "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+            aux_rand: "",
+            msg: "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+            signature: "00000000000000000000000000000000000000000000000000000000000000017615fbaf5ae28864013c099742deadb4dba87f11ac6754f93780d5a1837cf197",
+            valid: false,
+        },
+        // sig[0:32] is not an X coordinate on the curve
+        Vector {
+            index: 11,
+            secret_key: "",
+            public_key: "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+            aux_rand: "",
+            msg: "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+            signature: "4a298dacae57395a15d0795ddbfd1dcb564da82b0f269bc70a74f8220429ba1d69e89b4c5564d00349106b8497785dd7d1d713a8ae82b32fa79d5f7fc407d39b",
+            valid: false,
+        },
+        // sig[0:32] is equal to field size
+        Vector {
+            index: 12,
+            secret_key: "",
+            public_key: "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+            aux_rand: "",
+            msg: "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+            signature: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f69e89b4c5564d00349106b8497785dd7d1d713a8ae82b32fa79d5f7fc407d39b",
+            valid: false,
+        },
+        // sig[32:64] is equal to curve order
+        Vector {
+            index: 13,
+            secret_key: "",
+            public_key: "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+            aux_rand: "",
+            msg: "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+            signature: "6cff5c3ba86c69ea4b7376f31a9bcb4f74c1976089b2d9963da2e5543e177769fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+            valid: false,
+        },
+        // public key is not a valid X coordinate because it exceeds the field size
+        Vector {
+            index: 14,
+            secret_key: "",
+            public_key: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30",
+            aux_rand: "",
+            msg: "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+            signature: "6cff5c3ba86c69ea4b7376f31a9bcb4f74c1976089b2d9963da2e5543e17776969e89b4c5564d00349106b8497785dd7d1d713a8ae82b32fa79d5f7fc407d39b",
+            valid: false,
+        },
+        // message of size 0
+        Vector {
+            index: 15,
+            secret_key: "0340034003400340034003400340034003400340034003400340034003400340",
+            public_key: "778caa53b4393ac467774d09497a87224bf9fab6f6e68b23086497324d6fd117",
+            aux_rand: "0000000000000000000000000000000000000000000000000000000000000000",
+            msg: "",
+            signature: "71535db165ecd9fbbc046e5ffaea61186bb6ad436732fccc25291a55895464cf6069ce26bf03466228f19a3a62db8a649f2d560fac652827d1af0574e427ab63",
+            valid: true,
+        },
+        // message of size 1
+        Vector {
+            index: 16,
+            secret_key: "0340034003400340034003400340034003400340034003400340034003400340",
+            public_key: "778caa53b4393ac467774d09497a87224bf9fab6f6e68b23086497324d6fd117",
+            aux_rand: "0000000000000000000000000000000000000000000000000000000000000000",
+            msg: "11",
+            signature: "08a20a0afef64124649232e0693c583ab1b9934ae63b4c3511f3ae1134c6a303ea3173bfea6683bd101fa5aa5dbc1996fe7cacfc5a577d33ec14564cec2bacbf",
+            valid: true,
+        },
+        // message of size 17
+        Vector {
+            index: 17,
+            secret_key: "0340034003400340034003400340034003400340034003400340034003400340",
+            public_key: "778caa53b4393ac467774d09497a87224bf9fab6f6e68b23086497324d6fd117",
+            aux_rand: "0000000000000000000000000000000000000000000000000000000000000000",
+            msg: "0102030405060708090a0b0c0d0e0f1011",
+            signature: "5130f39a4059b43bc7cac09a19ece52b5d8699d1a71e3c52da9afdb6b50ac370c4a482b77bf960f8681540e25b6771ece1e5a37fd80e5a51897c5566a97ea5a5",
+            valid: true,
+        },
+        // message of size 100
+        Vector {
+            index: 18,
+            secret_key: "0340034003400340034003400340034003400340034003400340034003400340",
+            public_key: "778caa53b4393ac467774d09497a87224bf9fab6f6e68b23086497324d6fd117",
+            aux_rand: "0000000000000000000000000000000000000000000000000000000000000000",
+            msg: "99999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999999",
+            signature: "403b12b0d8555a344175ea7ec746566303321e5dbfa8be6f091635163eca79a8585ed3e3170807e7c03b720fc54c7b23897fcba0e9d0b4a06894cfd249f22367",
+            valid: true,
+        },
+    ];
+
+    #[test]
+    fn signatures_match_the_bip_340_test_vectors() {
+        for vector in VECTORS {
+            let msg = decode_hex(vector.msg);
+            if !vector.secret_key.is_empty() {
+                let key = SigningKey::from_bytes(&decode_hex(vector.secret_key)).unwrap();
+                assert_eq!(
+                    key.verifying_key().to_bytes().to_vec(),
+                    decode_hex(vector.public_key),
+                    "wrong public key for index {}",
+                    vector.index
+                );
+                let aux_rand: [u8; 32] = decode_hex(vector.aux_rand).try_into().unwrap();
+                assert_eq!(
+                    key.sign(&msg, &aux_rand).to_bytes().to_vec(),
+                    decode_hex(vector.signature),
+                    "wrong signature for index {}",
+                    vector.index
+                );
+            }
+
+            let valid = match (
+                VerifyingKey::from_bytes(&decode_hex(vector.public_key)),
+                Signature::from_bytes(&decode_hex(vector.signature)),
+            ) {
+                (Ok(public_key), Ok(signature)) => public_key.verify(&msg, &signature),
+                _ => false,
+            };
+            assert_eq!(
+                valid, vector.valid,
+                "wrong verification result for index {}",
+                vector.index
+            );
+        }
+    }
+
+    #[test]
+    fn verify_rejects_tampered_signatures() {
+        let vector = &VECTORS[1];
+        let public_key = VerifyingKey::from_bytes(&decode_hex(vector.public_key)).unwrap();
+        let msg = decode_hex(vector.msg);
+        let signature = decode_hex(vector.signature);
+
+        let mut other_msg = msg.clone();
+        other_msg[0] ^= 1;
+        let valid = Signature::from_bytes(&signature).unwrap();
+        assert!(!public_key.verify(&other_msg, &valid));
+
+        for i in [0, 32, 63] {
+            let mut tampered = signature.clone();
+            tampered[i] ^= 1;
+            assert!(!public_key.verify(&msg, &Signature::from_bytes(&tampered).unwrap()));
+        }
+
+        // `s` equal to the order of the group
+        let mut high_s = signature.clone();
+        high_s[32..].copy_from_slice(&u256_to_bytes(&scalar_modulus::<Secp256k1Curve>()));
+        assert!(!public_key.verify(&msg, &Signature::from_bytes(&high_s).unwrap()));
+    }
+
+    #[test]
+    fn public_keys_must_be_on_the_curve() {
+        // x = 5 is not the x coordinate of any point of secp256k1
+        let mut x = [0; 32];
+        x[31] = 5;
+        assert_eq!(
+            VerifyingKey::from_bytes(&x),
+            Err(SchnorrError::InvalidPublicKey)
+        );
+        assert_eq!(
+            VerifyingKey::from_bytes(&[0xff; 32]),
+            Err(SchnorrError::InvalidPublicKey)
+        );
+        assert_eq!(
+            Signature::from_bytes(&[0; 63]),
+            Err(SchnorrError::InvalidSignature)
+        );
+    }
+}
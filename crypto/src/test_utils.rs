@@ -0,0 +1,14 @@
+use alloc::{string::String, vec::Vec};
+
+/// Decodes a string of hexadecimal digits, as the test vectors are written.
+pub fn decode_hex(hex: &str) -> Vec<u8> {
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// Encodes `bytes` as a string of lowercase hexadecimal digits.
+pub fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
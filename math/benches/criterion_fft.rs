@@ -46,6 +46,41 @@ pub fn fft_benchmarks(c: &mut Criterion) {
                 );
             },
         );
+        group.bench_with_input(
+            "Sequential from NR radix8",
+            &(input_nat.clone(), twiddles_bitrev.clone()),
+            |bench, (input, twiddles)| {
+                bench.iter_batched(
+                    || input.clone(),
+                    |mut input| {
+                        fft_functions::ordered_fft_nr8(&mut input, twiddles);
+                    },
+                    BatchSize::LargeInput,
+                );
+            },
+        );
+        group.bench_with_input(
+            "Sequential from NR radix16",
+            &(input_nat.clone(), twiddles_bitrev.clone()),
+            |bench, (input, twiddles)| {
+                bench.iter_batched(
+                    || input.clone(),
+                    |mut input| {
+                        fft_functions::ordered_fft_nr16(&mut input, twiddles);
+                    },
+                    BatchSize::LargeInput,
+                );
+            },
+        );
+        group.bench_with_input(
+            "Sequential four-step",
+            &(input_nat.clone(), twiddles_bitrev.clone()),
+            |bench, (input, twiddles)| {
+                bench.iter_with_large_drop(|| {
+                    fft_functions::ordered_four_step_fft(input, twiddles);
+                });
+            },
+        );
         if order % 2 == 0 {
             group.bench_with_input(
                 "Sequential from NR radix4",
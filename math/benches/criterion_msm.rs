@@ -54,6 +54,31 @@ pub fn msm_benchmarks_with_size(
             },
         );
 
+        group.bench_function(
+            BenchmarkId::new("Sequential Pippenger with signed digits", window_size),
+            |bench| {
+                bench
+                    .iter(|| black_box(pippenger::msm_with_signed_digits(cs, points, window_size)));
+            },
+        );
+
+        let precomputed = pippenger::PrecomputedBases::new(points, window_size);
+        group.bench_function(
+            BenchmarkId::new("Pippenger with precomputed bases", window_size),
+            |bench| {
+                bench.iter(|| black_box(precomputed.msm(cs)));
+            },
+        );
+
+        group.bench_function(
+            BenchmarkId::new("Sequential Pippenger with affine buckets", window_size),
+            |bench| {
+                bench.iter(|| {
+                    black_box(pippenger::msm_with_affine_buckets(cs, points, window_size))
+                });
+            },
+        );
+
         group.bench_function(
             BenchmarkId::new("Parallel Pippenger", window_size),
             |bench| {
@@ -3,7 +3,11 @@
 use criterion::black_box;
 use lambdaworks_math::fft::cpu::{
     bit_reversing::in_place_bit_reverse_permute,
-    fft::{in_place_nr_2radix_fft, in_place_nr_4radix_fft, in_place_rn_2radix_fft},
+    fft::{
+        in_place_nr_16radix_fft, in_place_nr_2radix_fft, in_place_nr_4radix_fft,
+        in_place_nr_8radix_fft, in_place_rn_2radix_fft,
+    },
+    four_step::four_step_fft,
     roots_of_unity::get_twiddles,
 };
 use lambdaworks_math::{field::traits::RootsConfig, polynomial::Polynomial};
@@ -22,6 +26,18 @@ pub fn ordered_fft_nr4(input: &mut [FE], twiddles: &[FE]) {
     in_place_nr_4radix_fft(input, twiddles);
 }
 
+pub fn ordered_fft_nr8(input: &mut [FE], twiddles: &[FE]) {
+    in_place_nr_8radix_fft(input, twiddles);
+}
+
+pub fn ordered_fft_nr16(input: &mut [FE], twiddles: &[FE]) {
+    in_place_nr_16radix_fft(input, twiddles);
+}
+
+pub fn ordered_four_step_fft(input: &[FE], twiddles: &[FE]) -> Vec<FE> {
+    four_step_fft(input, twiddles)
+}
+
 pub fn twiddles_generation(order: u64, config: RootsConfig) {
     get_twiddles::<F>(order, config).unwrap();
 }
@@ -1,3 +1,5 @@
+#[cfg(feature = "alloc")]
+use crate::unsigned_integer::element::UnsignedInteger;
 use crate::unsigned_integer::traits::IsUnsignedInteger;
 
 pub trait IsGroup: Clone + PartialEq + Eq {
@@ -14,18 +16,14 @@ pub trait IsGroup: Clone + PartialEq + Eq {
     /// Applies the group operation `times` times with itself
     /// The operation can be addition or multiplication depending on
     /// the notation of the particular group.
-    fn operate_with_self<T: IsUnsignedInteger>(&self, mut exponent: T) -> Self {
-        let mut result = Self::neutral_element();
-        let mut base = self.clone();
-
-        while exponent != T::from(0) {
-            if exponent & T::from(1) == T::from(1) {
-                result = Self::operate_with(&result, &base);
-            }
-            exponent >>= 1;
-            base = Self::operate_with(&base, &base);
-        }
-        result
+    fn operate_with_self<T: IsUnsignedInteger>(&self, exponent: T) -> Self {
+        double_and_add(self, exponent)
+    }
+
+    /// Applies the group operation between `self` and itself.
+    /// Groups with a cheaper formula for it than the general one override it.
+    fn double(&self) -> Self {
+        self.operate_with(self)
     }
 
     /// Applies the group operation between `self` and `other`.
@@ -34,4 +32,32 @@ pub trait IsGroup: Clone + PartialEq + Eq {
     fn operate_with(&self, other: &Self) -> Self;
 
     fn neg(&self) -> Self;
+
+    /// Computes the multiscalar multiplication `cs[0] * points[0] + ... + cs[n - 1] * points[n - 1]`
+    /// using Pippenger's algorithm with windows of `window_size` bits.
+    /// Groups with a cheaper way of accumulating the buckets override it.
+    #[cfg(feature = "alloc")]
+    fn pippenger_msm<const NUM_LIMBS: usize>(
+        cs: &[UnsignedInteger<NUM_LIMBS>],
+        points: &[Self],
+        window_size: usize,
+    ) -> Self {
+        crate::msm::pippenger::msm_with_signed_digits(cs, points, window_size)
+    }
+}
+
+/// Applies the group operation `exponent` times with `g`, going over the bits of `exponent` from
+/// the least significant one. It's the default [`IsGroup::operate_with_self`].
+pub fn double_and_add<G: IsGroup, T: IsUnsignedInteger>(g: &G, mut exponent: T) -> G {
+    let mut result = G::neutral_element();
+    let mut base = g.clone();
+
+    while exponent != T::from(0) {
+        if exponent & T::from(1) == T::from(1) {
+            result = G::operate_with(&result, &base);
+        }
+        exponent >>= 1;
+        base = G::operate_with(&base, &base);
+    }
+    result
 }
@@ -1,6 +1,9 @@
 pub use super::field::FqField;
+use crate::cyclic_group::IsGroup;
 use crate::elliptic_curve::edwards::point::EdwardsProjectivePoint;
-use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::elliptic_curve::glv::{IsGLVCurve, SignedU256};
+use crate::elliptic_curve::traits::{HasSubgroupCheck, IsEllipticCurve};
+use crate::unsigned_integer::element::U256;
 use crate::{elliptic_curve::edwards::traits::IsEdwards, field::element::FieldElement};
 
 pub type BaseBandersnatchFieldElement = FqField;
@@ -41,13 +44,117 @@ impl IsEdwards for BandersnatchCurve {
     }
 }
 
+/// Order of the main subgroup. The curve has cofactor 4.
+pub const SUBGROUP_ORDER: U256 =
+    U256::from_hex_unchecked("1cfb69d4ca675f520cce760202687600ff8f87007419047174fd06b52876e7e1");
+
+/// Coefficients of the endomorphism of degree 2 of the curve, which has complex multiplication
+/// by √−2. In affine coordinates it is
+/// 𝜓(𝑥, 𝑦) = (𝑥 (𝑐₁𝑦² + 𝑐₂) / 𝑦, (𝑐₃ − 𝑦²) / ((𝑐₃ − 2)𝑦² + 1)).
+pub const ENDO_COEFFICIENTS: [FieldElement<FqField>; 3] = [
+    FieldElement::from_hex_unchecked(
+        "50281ac0f92fc1b29d2a646fe1f5beb21ec0cb08e81f589296d082245cf9382d",
+    ),
+    FieldElement::from_hex_unchecked(
+        "50281ac0f92fc1b18286cad2f5efb51046bacb03fc19589296ce82245cf9382d",
+    ),
+    FieldElement::from_hex_unchecked(
+        "2123b4c7a71956a2d149cacda650bd7d2516918bf263672811f0feb1e8daef4d",
+    ),
+];
+
+impl IsGLVCurve for BandersnatchCurve {
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+    const LAMBDA: U256 = U256::from_hex_unchecked(
+        "094675f8802dbabe1ed62cabd72faed52fcae9470373ff02a3bfe574a0f308dc",
+    );
+    const LATTICE_BASIS: [[SignedU256; 2]; 2] = [
+        [
+            (
+                false,
+                U256::from_hex_unchecked("555fe2004be6928e4b02f94a9789181f"),
+            ),
+            (
+                true,
+                U256::from_hex_unchecked("814b3eee55e8f5df8e2591a23d61f44"),
+            ),
+        ],
+        [
+            (
+                false,
+                U256::from_hex_unchecked("102967ddcabd1ebbf1c4b23447ac3e88"),
+            ),
+            (
+                false,
+                U256::from_hex_unchecked("555fe2004be6928e4b02f94a9789181f"),
+            ),
+        ],
+    ];
+
+    fn endomorphism(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        let [c1, c2, c3] = &ENDO_COEFFICIENTS;
+        let [x, y, z] = p.coordinates();
+        let (y_square, z_square) = (y.square(), z.square());
+        let y_z_square = y * &z_square;
+        let denominator = (c3 - FieldElement::<FqField>::from(2)) * &y_square + &z_square;
+
+        Self::PointRepresentation::new([
+            x * (c1 * &y_square + c2 * &z_square) * &denominator,
+            &y_z_square * (c3 * z_square - y_square),
+            y_z_square * denominator,
+        ])
+    }
+}
+
+/// The subgroup check uses the endomorphism. For the first vector `(a1, b1)` of the lattice
+/// basis, `[a1]P + [b1]𝜓(P) = [a1 + b1 𝜆]P` is the neutral element when `P` is in the subgroup.
+/// The other points on the curve are `P + T`, with `T = (0, -1)` the point of order 2, which
+/// is in the kernel of 𝜓, so for them the sum is `[a1]T = T` because `a1` is odd. The check
+/// is a multiplication by two scalars of 128 bits instead of one of 253 bits.
+impl HasSubgroupCheck for BandersnatchCurve {
+    type OrderType = U256;
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+
+    fn is_in_subgroup(p: &Self::PointRepresentation) -> bool {
+        let [[(a1_is_negative, a1), (b1_is_negative, b1)], _] = Self::LATTICE_BASIS;
+        debug_assert!(!a1_is_negative && a1.limbs[3] & 1 == 1);
+
+        let endomorphism = Self::endomorphism(p);
+        let endomorphism = if b1_is_negative {
+            endomorphism.neg()
+        } else {
+            endomorphism
+        };
+        let both = p.operate_with(&endomorphism);
+
+        // Shamir's trick: both scalars share the doublings.
+        let bit = |k: &U256, i: usize| (k.limbs[3 - i / 64] >> (i % 64)) & 1 == 1;
+        let mut result = Self::PointRepresentation::neutral_element();
+        for i in (0..128).rev() {
+            result = result.double();
+            match (bit(&a1, i), bit(&b1, i)) {
+                (true, true) => result = result.operate_with(&both),
+                (true, false) => result = result.operate_with(p),
+                (false, true) => result = result.operate_with(&endomorphism),
+                (false, false) => {}
+            }
+        }
+        result.is_neutral_element()
+    }
+
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.double().double()
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
     use crate::{
         cyclic_group::IsGroup, elliptic_curve::traits::EllipticCurveError,
-        field::element::FieldElement, unsigned_integer::element::U256,
+        errors::DeserializationError, field::element::FieldElement,
+        unsigned_integer::element::U256,
     };
 
     #[allow(clippy::upper_case_acronyms)]
@@ -118,6 +225,66 @@ mod tests {
         assert_eq!(&g, &g);
     }
 
+    /// The generator plus the point (0, -1) of order 2.
+    fn point_outside_subgroup() -> EdwardsProjectivePoint<BandersnatchCurve> {
+        let torsion =
+            BandersnatchCurve::create_point_from_affine(FEE::zero(), -FEE::one()).unwrap();
+        BandersnatchCurve::generator().operate_with(&torsion)
+    }
+
+    #[test]
+    fn subgroup_check_works() {
+        assert!(BandersnatchCurve::is_in_subgroup(
+            &BandersnatchCurve::generator()
+        ));
+        assert!(!BandersnatchCurve::is_in_subgroup(&point_outside_subgroup()));
+    }
+
+    #[test]
+    fn subgroup_check_matches_the_multiplication_by_the_order() {
+        let torsion =
+            BandersnatchCurve::create_point_from_affine(FEE::zero(), -FEE::one()).unwrap();
+        let g = BandersnatchCurve::generator();
+        for point in [
+            EdwardsProjectivePoint::neutral_element(),
+            torsion.clone(),
+            g.operate_with_self(7_u16),
+            g.operate_with_self(7_u16).operate_with(&torsion),
+            point_1().neg(),
+        ] {
+            assert_eq!(
+                BandersnatchCurve::is_in_subgroup(&point),
+                point.operate_with_self(SUBGROUP_ORDER).is_neutral_element()
+            );
+        }
+    }
+
+    #[test]
+    fn clear_cofactor_maps_points_to_the_subgroup() {
+        let cleared = BandersnatchCurve::clear_cofactor(&point_outside_subgroup());
+        assert!(BandersnatchCurve::is_in_subgroup(&cleared));
+        assert_eq!(
+            cleared,
+            BandersnatchCurve::generator().operate_with_self(4_u16)
+        );
+    }
+
+    #[test]
+    fn decode_in_subgroup_rejects_points_outside_the_subgroup() {
+        let g = BandersnatchCurve::generator();
+        assert_eq!(
+            EdwardsProjectivePoint::decode_in_subgroup(&g.encode()).unwrap(),
+            g
+        );
+        assert_eq!(
+            EdwardsProjectivePoint::<BandersnatchCurve>::decode_in_subgroup(
+                &point_outside_subgroup().encode()
+            )
+            .unwrap_err(),
+            DeserializationError::PointNotInSubgroup
+        );
+    }
+
     #[test]
     fn operate_with_self_works_1() {
         let g = BandersnatchCurve::generator();
@@ -1,9 +1,18 @@
 use crate::{
+    cyclic_group::IsGroup,
     elliptic_curve::{
         edwards::{point::EdwardsProjectivePoint, traits::IsEdwards},
-        traits::IsEllipticCurve,
+        traits::{HasSubgroupCheck, IsEllipticCurve},
     },
-    field::{element::FieldElement, fields::p448_goldilocks_prime_field::P448GoldilocksPrimeField},
+    field::{
+        element::FieldElement,
+        fields::{
+            ed448_goldilocks_scalarfield::MontgomeryConfigEd448GoldilocksScalarField,
+            montgomery_backed_prime_fields::IsModulus,
+            p448_goldilocks_prime_field::P448GoldilocksPrimeField,
+        },
+    },
+    unsigned_integer::element::UnsignedInteger,
 };
 
 #[derive(Debug, Clone)]
@@ -33,12 +42,25 @@ impl IsEdwards for Ed448Goldilocks {
     }
 }
 
+/// Order of the main subgroup, the modulus of the scalar field. The curve has cofactor 4.
+pub const SUBGROUP_ORDER: UnsignedInteger<7> =
+    <MontgomeryConfigEd448GoldilocksScalarField as IsModulus<UnsignedInteger<7>>>::MODULUS;
+
+impl HasSubgroupCheck for Ed448Goldilocks {
+    type OrderType = UnsignedInteger<7>;
+    const SUBGROUP_ORDER: UnsignedInteger<7> = SUBGROUP_ORDER;
+
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.double().double()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
         cyclic_group::IsGroup, elliptic_curve::traits::EllipticCurveError,
-        field::element::FieldElement,
+        errors::DeserializationError, field::element::FieldElement,
     };
 
     #[allow(clippy::upper_case_acronyms)]
@@ -96,4 +118,73 @@ mod tests {
             EllipticCurveError::InvalidPoint
         )
     }
+
+    #[test]
+    fn decode_inverts_encode() {
+        for point in [
+            Ed448Goldilocks::generator(),
+            generator_times_5(),
+            Ed448Goldilocks::clear_cofactor(&point_1()),
+            Ed448Goldilocks::clear_cofactor(&point_1_times_7()).neg(),
+            EdwardsProjectivePoint::neutral_element(),
+        ] {
+            let decoded =
+                EdwardsProjectivePoint::<Ed448Goldilocks>::decode(&point.encode()).unwrap();
+            assert_eq!(decoded, point);
+        }
+    }
+
+    #[test]
+    fn decode_rejects_invalid_encodings() {
+        let g = Ed448Goldilocks::generator().encode();
+        assert_eq!(
+            EdwardsProjectivePoint::<Ed448Goldilocks>::decode(&g[..56]).unwrap_err(),
+            DeserializationError::InvalidAmountOfBytes
+        );
+
+        // Only the most significant bit of the last byte can be set
+        let mut bytes = g.clone();
+        bytes[56] |= 1;
+        assert!(EdwardsProjectivePoint::<Ed448Goldilocks>::decode(&bytes).is_err());
+
+        // y = p is not canonical
+        let mut bytes = [0xff; 57];
+        bytes[28] = 0xfe;
+        bytes[56] = 0;
+        assert!(EdwardsProjectivePoint::<Ed448Goldilocks>::decode(&bytes).is_err());
+
+        // The neutral element with x = -0
+        let mut bytes = [0; 57];
+        bytes[0] = 1;
+        bytes[56] = 0x80;
+        assert!(EdwardsProjectivePoint::<Ed448Goldilocks>::decode(&bytes).is_err());
+
+        // y = 2 is not the y coordinate of any point
+        let mut bytes = [0; 57];
+        bytes[0] = 2;
+        assert!(EdwardsProjectivePoint::<Ed448Goldilocks>::decode(&bytes).is_err());
+    }
+
+    #[test]
+    fn only_decode_in_subgroup_rejects_points_outside_the_subgroup() {
+        // The generator plus the point (0, -1) of order 2 is a valid encoding as in RFC 8032,
+        // but it is not in the subgroup
+        let torsion = Ed448Goldilocks::create_point_from_affine(FE::zero(), -FE::one()).unwrap();
+        let point = Ed448Goldilocks::generator().operate_with(&torsion);
+        let bytes = point.encode();
+        assert_eq!(
+            EdwardsProjectivePoint::<Ed448Goldilocks>::decode(&bytes).unwrap(),
+            point
+        );
+        assert_eq!(
+            EdwardsProjectivePoint::<Ed448Goldilocks>::decode_in_subgroup(&bytes).unwrap_err(),
+            DeserializationError::PointNotInSubgroup
+        );
+
+        let g = Ed448Goldilocks::generator();
+        assert_eq!(
+            EdwardsProjectivePoint::decode_in_subgroup(&g.encode()).unwrap(),
+            g
+        );
+    }
 }
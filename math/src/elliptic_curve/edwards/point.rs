@@ -6,6 +6,13 @@ use crate::{
     },
     field::element::FieldElement,
 };
+#[cfg(feature = "alloc")]
+use crate::{
+    elliptic_curve::traits::HasSubgroupCheck, errors::DeserializationError,
+    field::traits::IsPrimeField, traits::ByteConversion,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 use super::traits::IsEdwards;
 
@@ -113,6 +120,90 @@ impl<E: IsEdwards> IsGroup for EdwardsProjectivePoint<E> {
     }
 }
 
+/// Point encoding of RFC 8032 (Sections 5.1.2 and 5.2.2): the little-endian bytes of `y`
+/// with the lowest bit of `x` in the most significant bit of the last byte. The encoding
+/// takes `b / 8 + 1` bytes, where `b` is the bit size of the base field, so it is 32 bytes
+/// long for a 255 bits field and 57 for Ed448.
+#[cfg(feature = "alloc")]
+impl<E> EdwardsProjectivePoint<E>
+where
+    E: IsEdwards,
+    E::BaseField: IsPrimeField,
+    FieldElement<E::BaseField>: ByteConversion,
+{
+    fn encoding_len() -> usize {
+        E::BaseField::field_bit_size() / 8 + 1
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let point = self.to_affine();
+        let mut bytes = point.y().to_bytes_le();
+        bytes.resize(Self::encoding_len(), 0);
+        let x_sign = point.x().to_bytes_le()[0] & 1;
+        *bytes.last_mut().expect("the encoding is not empty") |= x_sign << 7;
+        bytes
+    }
+
+    /// Decodes a point as in RFC 8032, rejecting non canonical encodings of `y`. Points with a
+    /// component of small order are accepted, as in the RFC, so protocols that need points of
+    /// the subgroup of prime order should use [`Self::decode_in_subgroup`].
+    pub fn decode(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let len = Self::encoding_len();
+        if bytes.len() != len {
+            return Err(DeserializationError::InvalidAmountOfBytes);
+        }
+        let x_sign = bytes[len - 1] >> 7;
+        let mut y_bytes = bytes.to_vec();
+        y_bytes[len - 1] &= 0x7f;
+
+        let field_len = E::BaseField::field_bit_size().div_ceil(8);
+        if y_bytes[field_len..].iter().any(|byte| *byte != 0) {
+            return Err(DeserializationError::InvalidValue);
+        }
+        let y = FieldElement::<E::BaseField>::from_bytes_le(&y_bytes[..field_len])?;
+        let mut canonical_bytes = y.to_bytes_le();
+        canonical_bytes.resize(len, 0);
+        if canonical_bytes != y_bytes {
+            return Err(DeserializationError::InvalidValue);
+        }
+
+        // x^2 = (y^2 - 1) / (d y^2 - a)
+        let y_squared = y.square();
+        let denominator = E::d() * &y_squared - E::a();
+        if denominator == FieldElement::zero() {
+            return Err(DeserializationError::InvalidValue);
+        }
+        let x_squared = (y_squared - FieldElement::<E::BaseField>::one()) / denominator;
+        let (x, neg_x) = x_squared.sqrt().ok_or(DeserializationError::InvalidValue)?;
+        let x = if x.to_bytes_le()[0] & 1 == x_sign {
+            x
+        } else if x == FieldElement::zero() {
+            return Err(DeserializationError::InvalidValue);
+        } else {
+            neg_x
+        };
+        Ok(Self::new([x, y, FieldElement::one()]))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E> EdwardsProjectivePoint<E>
+where
+    E: IsEdwards + HasSubgroupCheck<PointRepresentation = Self>,
+    E::BaseField: IsPrimeField,
+    FieldElement<E::BaseField>: ByteConversion,
+{
+    /// Decodes a point like [`Self::decode`], and also rejects points outside the subgroup of
+    /// prime order.
+    pub fn decode_in_subgroup(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let point = Self::decode(bytes)?;
+        if !E::is_in_subgroup(&point) {
+            return Err(DeserializationError::PointNotInSubgroup);
+        }
+        Ok(point)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
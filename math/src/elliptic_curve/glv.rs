@@ -0,0 +1,330 @@
+#[cfg(feature = "alloc")]
+use crate::{
+    cyclic_group::{double_and_add, IsGroup},
+    elliptic_curve::wnaf,
+    unsigned_integer::traits::IsUnsignedInteger,
+};
+use crate::{elliptic_curve::traits::IsEllipticCurve, unsigned_integer::element::U256};
+
+/// Width of the non-adjacent forms of the halves of a GLV decomposition.
+pub const GLV_WNAF_WIDTH: usize = 5;
+
+/// An integer given as `(is_negative, absolute_value)`.
+pub type SignedU256 = (bool, U256);
+
+/// Trait for curves whose main subgroup has an endomorphism `φ` that is much cheaper than
+/// a scalar multiplication, while acting on the subgroup as the multiplication by a scalar `λ`.
+///
+/// The GLV method writes a scalar as `k = k1 + k2 * λ mod r`, with `k1` and `k2` of about half
+/// the bits of `r`, and computes `kP = k1 * P + k2 * φ(P)` sharing the doublings of both terms.
+/// See "Faster Point Multiplication on Elliptic Curves with Efficient Endomorphisms",
+/// by Gallant, Lambert and Vanstone.
+///
+/// The curves of prime order use it in [`IsGroup::operate_with_self`], see
+/// [`glv_operate_with_self`]. The other curves, like BLS12-381, BLS12-377 and Bandersnatch, have
+/// points outside the main subgroup, for which `φ(P)` is not `λP`. Their `operate_with_self` stays
+/// double-and-add, since it's how their points are checked to be in the subgroup and how their
+/// cofactors are cleared, so they use [`Self::glv_mul`] only for points known to be in the subgroup.
+pub trait IsGLVCurve: IsEllipticCurve {
+    /// The order `r` of the main subgroup.
+    const SUBGROUP_ORDER: U256;
+
+    /// The eigenvalue `λ` of the endomorphism, that is, `φ(P) = λP` for every `P` of order `r`.
+    const LAMBDA: U256;
+
+    /// A short basis `[[a1, b1], [a2, b2]]` of the lattice of pairs `(x, y)` with
+    /// `x + y * λ = 0 mod r`, with determinant `a1 * b2 - a2 * b1 = r`.
+    const LATTICE_BASIS: [[SignedU256; 2]; 2];
+
+    /// `round(2^256 * |b2| / r)` and `round(2^256 * |b1| / r)`, used to find the lattice
+    /// vector closest to `(k, 0)` without divisions.
+    const ROUNDING_FACTORS: [U256; 2] = [
+        div_round_shifted(&Self::LATTICE_BASIS[1][1].1, &Self::SUBGROUP_ORDER),
+        div_round_shifted(&Self::LATTICE_BASIS[0][1].1, &Self::SUBGROUP_ORDER),
+    ];
+
+    /// Returns `φ(P)`.
+    fn endomorphism(p: &Self::PointRepresentation) -> Self::PointRepresentation;
+
+    /// Returns `(k1, k2)` such that `k = k1 + k2 * λ mod r`, both of about half the bits of `r`.
+    fn decompose_scalar(k: &U256) -> (SignedU256, SignedU256) {
+        let [[a1, b1], [a2, b2]] = Self::LATTICE_BASIS;
+        let [g1, g2] = Self::ROUNDING_FACTORS;
+
+        // (c1, c2) are the coordinates of (k, 0) in the basis, rounded to the nearest integers:
+        // c1 = round(b2 * k / r) and c2 = round(-b1 * k / r).
+        let c1 = (b2.0, mul_shift_round(k, &g1));
+        let c2 = (!b1.0, mul_shift_round(k, &g2));
+
+        // (k1, k2) = (k, 0) - c1 * (a1, b1) - c2 * (a2, b2) is short, so it can be computed
+        // with wrapping arithmetic and read as a two's complement number.
+        let k1 = wrapping_sub(
+            &wrapping_sub(k, &wrapping_mul(c1, a1)),
+            &wrapping_mul(c2, a2),
+        );
+        let k2 = wrapping_neg(&U256::add(&wrapping_mul(c1, b1), &wrapping_mul(c2, b2)).0);
+        (to_signed(k1), to_signed(k2))
+    }
+
+    /// Returns `kP` using the GLV decomposition of `k`.
+    /// `P` must be in the main subgroup, otherwise `φ(P)` is not `λP` and the result is wrong.
+    #[cfg(feature = "alloc")]
+    fn glv_mul(p: &Self::PointRepresentation, k: &U256) -> Self::PointRepresentation {
+        let ((k1_is_negative, k1), (k2_is_negative, k2)) = Self::decompose_scalar(k);
+        let p1 = if k1_is_negative { p.neg() } else { p.clone() };
+        let p2 = Self::endomorphism(p);
+        let p2 = if k2_is_negative { p2.neg() } else { p2 };
+        wnaf::interleaved_wnaf(
+            &[p1, p2],
+            &[
+                wnaf::wnaf(k1, GLV_WNAF_WIDTH),
+                wnaf::wnaf(k2, GLV_WNAF_WIDTH),
+            ],
+        )
+    }
+}
+
+/// Returns `k * p` with the GLV method if `k` is smaller than the order of the main subgroup, and
+/// with double-and-add otherwise. Every point of the curve must be in the main subgroup.
+#[cfg(feature = "alloc")]
+pub fn glv_operate_with_self<C, T>(p: &C::PointRepresentation, k: T) -> C::PointRepresentation
+where
+    C: IsGLVCurve,
+    T: IsUnsignedInteger,
+{
+    match to_u256(k).filter(|k| *k < C::SUBGROUP_ORDER) {
+        Some(k) => C::glv_mul(p, &k),
+        None => double_and_add(p, k),
+    }
+}
+
+/// Returns `k` as a `U256`, or `None` if it has more than 256 bits.
+#[cfg(feature = "alloc")]
+fn to_u256<T: IsUnsignedInteger>(mut k: T) -> Option<U256> {
+    let (zero, one) = (T::from(0), T::from(1));
+    let mut result = U256::from_u64(0);
+    let mut i = 0;
+    while k != zero {
+        if i == 256 {
+            return None;
+        }
+        if k & one == one {
+            result.limbs[3 - i / 64] |= 1 << (i % 64);
+        }
+        k >>= 1;
+        i += 1;
+    }
+    Some(result)
+}
+
+/// Returns `round(2^256 * b / r)`, for `b < r`.
+pub const fn div_round_shifted(b: &U256, r: &U256) -> U256 {
+    // Long division of b * 2^256 + floor(r / 2) by r, where the high half leaves remainder `b`.
+    let low = r.const_shr(1);
+    let mut remainder = *b;
+    let mut quotient = U256::from_u64(0);
+    let mut i = 256;
+    while i > 0 {
+        i -= 1;
+        let (doubled, overflow) = U256::add(&remainder, &remainder);
+        let bit = (low.limbs[3 - i / 64] >> (i % 64)) & 1;
+        remainder = U256::add(&doubled, &U256::from_u64(bit)).0;
+        if overflow || U256::const_le(r, &remainder) {
+            remainder = U256::sub(&remainder, r).0;
+            quotient.limbs[3 - i / 64] |= 1 << (i % 64);
+        }
+    }
+    quotient
+}
+
+/// Returns `round(a * b / 2^256)`.
+fn mul_shift_round(a: &U256, b: &U256) -> U256 {
+    let (high, low) = U256::mul(a, b);
+    U256::add(&high, &U256::from_u64(low.limbs[0] >> 63)).0
+}
+
+fn wrapping_mul((is_negative, a): SignedU256, (other_is_negative, b): SignedU256) -> U256 {
+    let product = U256::mul(&a, &b).1;
+    if is_negative != other_is_negative {
+        wrapping_neg(&product)
+    } else {
+        product
+    }
+}
+
+fn wrapping_sub(a: &U256, b: &U256) -> U256 {
+    U256::sub(a, b).0
+}
+
+fn wrapping_neg(a: &U256) -> U256 {
+    wrapping_sub(&U256::from_u64(0), a)
+}
+
+fn to_signed(a: U256) -> SignedU256 {
+    if a.limbs[0] >> 63 == 1 {
+        (true, wrapping_neg(&a))
+    } else {
+        (false, a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cyclic_group::{double_and_add, IsGroup},
+        elliptic_curve::{
+            edwards::curves::bandersnatch::curve::BandersnatchCurve,
+            short_weierstrass::curves::{
+                bls12_377::curve::BLS12377Curve, bls12_381::curve::BLS12381Curve,
+                bn_254::curve::BN254Curve, pallas::curve::PallasCurve,
+                secp256k1::curve::Secp256k1Curve, vesta::curve::VestaCurve,
+            },
+        },
+        unsigned_integer::element::UnsignedInteger,
+    };
+    use proptest::prelude::*;
+
+    fn signed_mul_mod(a: SignedU256, b: &U256, r: &U256) -> U256 {
+        // Reduces (|a| * b) mod r with a 512-bit division, to check the decomposition.
+        let (high, low) = U256::mul(&a.1, b);
+        let mut limbs = [0; 8];
+        limbs[..4].copy_from_slice(&high.limbs);
+        limbs[4..].copy_from_slice(&low.limbs);
+        let mut wide_r = [0; 8];
+        wide_r[4..].copy_from_slice(&r.limbs);
+        let remainder = UnsignedInteger::<8>::from_limbs(limbs)
+            .div_rem(&UnsignedInteger::from_limbs(wide_r))
+            .1;
+        let remainder = U256::from_limbs(remainder.limbs[4..].try_into().unwrap());
+        if a.0 && remainder != U256::from_u64(0) {
+            r - remainder
+        } else {
+            remainder
+        }
+    }
+
+    fn check_decomposition<C: IsGLVCurve>(k: U256) {
+        let r = C::SUBGROUP_ORDER;
+        let ((k1_is_negative, k1), k2) = C::decompose_scalar(&k);
+
+        assert!(k1.bits_le() <= 129 && k2.1.bits_le() <= 129);
+        let k1 = signed_mul_mod((k1_is_negative, k1), &U256::from_u64(1), &r);
+        let k2_lambda = signed_mul_mod(k2, &C::LAMBDA, &r);
+        let (sum, overflow) = U256::add(&k1, &k2_lambda);
+        let sum = if overflow || sum >= r {
+            U256::sub(&sum, &r).0
+        } else {
+            sum
+        };
+        assert_eq!(sum, k.div_rem(&r).1);
+    }
+
+    fn check_glv_mul<C: IsGLVCurve>(k: U256)
+    where
+        C::PointRepresentation: core::fmt::Debug,
+    {
+        let g = C::generator();
+        assert_eq!(C::glv_mul(&g, &k), double_and_add(&g, k));
+    }
+
+    #[test]
+    fn endomorphism_is_the_multiplication_by_lambda() {
+        fn check<C: IsGLVCurve>()
+        where
+            C::PointRepresentation: core::fmt::Debug,
+        {
+            let g = double_and_add(&C::generator(), 12345_u64);
+            assert_eq!(C::endomorphism(&g), double_and_add(&g, C::LAMBDA));
+        }
+        check::<BLS12381Curve>();
+        check::<BLS12377Curve>();
+        check::<BN254Curve>();
+        check::<Secp256k1Curve>();
+        check::<PallasCurve>();
+        check::<VestaCurve>();
+        check::<BandersnatchCurve>();
+    }
+
+    #[test]
+    fn decomposition_of_edge_scalars() {
+        fn check<C: IsGLVCurve>() {
+            let r = C::SUBGROUP_ORDER;
+            for k in [
+                U256::from_u64(0),
+                U256::from_u64(1),
+                r - U256::from_u64(1),
+                r,
+                C::LAMBDA,
+                U256::from_limbs([u64::MAX; 4]),
+            ] {
+                check_decomposition::<C>(k);
+            }
+        }
+        check::<BLS12381Curve>();
+        check::<BLS12377Curve>();
+        check::<BN254Curve>();
+        check::<Secp256k1Curve>();
+        check::<PallasCurve>();
+        check::<VestaCurve>();
+        check::<BandersnatchCurve>();
+    }
+
+    #[test]
+    fn operate_with_self_of_prime_order_curves_matches_double_and_add() {
+        fn check<C: IsGLVCurve>()
+        where
+            C::PointRepresentation: core::fmt::Debug,
+        {
+            let g = C::generator();
+            let r = C::SUBGROUP_ORDER;
+            for k in [
+                U256::from_u64(0),
+                U256::from_u64(1),
+                r - U256::from_u64(1),
+                r,
+                r + U256::from_u64(1),
+                U256::from_limbs([u64::MAX; 4]),
+            ] {
+                assert_eq!(g.operate_with_self(k), double_and_add(&g, k));
+            }
+            assert_eq!(
+                g.operate_with_self(12345_u64),
+                double_and_add(&g, 12345_u64)
+            );
+            let wide_k = UnsignedInteger::<6>::from_limbs([1, 0, 0, 0, 0, 1]);
+            assert_eq!(g.operate_with_self(wide_k), double_and_add(&g, wide_k));
+        }
+        check::<BN254Curve>();
+        check::<Secp256k1Curve>();
+        check::<PallasCurve>();
+        check::<VestaCurve>();
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig { cases: 10, .. ProptestConfig::default() })]
+        #[test]
+        fn decomposition_is_short_and_correct(limbs: [u64; 4]) {
+            let k = U256::from_limbs(limbs);
+            check_decomposition::<BLS12381Curve>(k);
+            check_decomposition::<BLS12377Curve>(k);
+            check_decomposition::<BN254Curve>(k);
+            check_decomposition::<Secp256k1Curve>(k);
+            check_decomposition::<PallasCurve>(k);
+            check_decomposition::<VestaCurve>(k);
+            check_decomposition::<BandersnatchCurve>(k);
+        }
+
+        #[test]
+        fn glv_mul_matches_operate_with_self(limbs: [u64; 4]) {
+            let k = U256::from_limbs(limbs);
+            check_glv_mul::<BLS12381Curve>(k);
+            check_glv_mul::<BLS12377Curve>(k);
+            check_glv_mul::<BN254Curve>(k);
+            check_glv_mul::<Secp256k1Curve>(k);
+            check_glv_mul::<PallasCurve>(k);
+            check_glv_mul::<VestaCurve>(k);
+            check_glv_mul::<BandersnatchCurve>(k);
+        }
+    }
+}
@@ -1,6 +1,11 @@
 pub mod edwards;
+/// Scalar multiplication with the GLV method, for curves with an efficient endomorphism.
+pub mod glv;
 pub mod montgomery;
 /// Implementation of ProjectivePoint, a generic projective point in a curve.
 pub mod point;
 pub mod short_weierstrass;
 pub mod traits;
+/// Scalar multiplication with the windowed non-adjacent form of the scalar.
+#[cfg(feature = "alloc")]
+pub mod wnaf;
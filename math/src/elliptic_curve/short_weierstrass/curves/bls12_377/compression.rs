@@ -0,0 +1,261 @@
+use super::twist::BLS12377TwistCurve;
+#[cfg(feature = "alloc")]
+use crate::{elliptic_curve::short_weierstrass::traits::Compress, errors::DeserializationError};
+use crate::{
+    elliptic_curve::short_weierstrass::{
+        curves::bls12_377::{curve::BLS12377Curve, field_extension::Degree2ExtensionField, sqrt},
+        point::ShortWeierstrassProjectivePoint,
+        traits::{quadratic_y_flag, CompressedFormat, IsCompressibleField},
+    },
+    errors::ByteConversionError,
+    field::element::FieldElement,
+    traits::ByteConversion,
+};
+
+type G1Point = ShortWeierstrassProjectivePoint<BLS12377Curve>;
+type G2Point = ShortWeierstrassProjectivePoint<BLS12377TwistCurve>;
+
+/// Compression and decompression for points belonging to the BLS12-377, in the Zcash format
+/// used for BLS12-381, see https://github.com/zcash/librustzcash/blob/6e0364cd42a2b3d2b958a54771ef51a8db79dd29/pairing/src/bls12_381/README.md#serialization
+/// The base field of BLS12-377 also fits in 377 bits, leaving the three most significant bits of the big-endian
+/// encoding free for the compression, infinity and sign flags.
+/// G1 points are represented by their x coordinate in big-endian form (48 bytes).
+/// G2 points are represented by their x coordinate in big-endian form (96 bytes), following the order a * u + b.
+/// Both are [`ShortWeierstrassProjectivePoint::serialize_compressed`] and
+/// [`ShortWeierstrassProjectivePoint::deserialize_compressed`] with fixed sizes.
+#[cfg(feature = "alloc")]
+impl Compress for BLS12377Curve {
+    type G1Point = G1Point;
+
+    type G2Point = G2Point;
+
+    type G1Compressed = [u8; 48];
+
+    type G2Compressed = [u8; 96];
+
+    type Error = DeserializationError;
+
+    fn compress_g1_point(point: &Self::G1Point) -> Self::G1Compressed {
+        let mut bytes = [0_u8; 48];
+        bytes.copy_from_slice(&point.serialize_compressed());
+        bytes
+    }
+
+    fn decompress_g1_point(input_bytes: &mut [u8]) -> Result<Self::G1Point, Self::Error> {
+        G1Point::deserialize_compressed(input_bytes)
+    }
+
+    fn compress_g2_point(point: &Self::G2Point) -> Self::G2Compressed {
+        let mut bytes = [0_u8; 96];
+        bytes.copy_from_slice(&point.serialize_compressed());
+        bytes
+    }
+
+    fn decompress_g2_point(input_bytes: &mut [u8]) -> Result<Self::G2Point, Self::Error> {
+        G2Point::deserialize_compressed(input_bytes)
+    }
+}
+
+/// The elements `c0 + c1 * u` of the field of G2 are encoded as `c1` followed by `c0`.
+impl IsCompressibleField for Degree2ExtensionField {
+    #[cfg(feature = "alloc")]
+    fn to_bytes_be(x: &FieldElement<Self>) -> alloc::vec::Vec<u8> {
+        let [c0, c1] = x.value();
+        let mut bytes = c1.to_bytes_be();
+        bytes.extend(c0.to_bytes_be());
+        bytes
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<FieldElement<Self>, ByteConversionError> {
+        if bytes.len() != 2 * 48 {
+            return Err(ByteConversionError::InvalidValue);
+        }
+        let (c1, c0) = bytes.split_at(48);
+        Ok(FieldElement::new([
+            FieldElement::from_bytes_be(c0)?,
+            FieldElement::from_bytes_be(c1)?,
+        ]))
+    }
+
+    fn sqrt(x: &FieldElement<Self>) -> Option<FieldElement<Self>> {
+        sqrt::sqrt_qfe(x, 0)
+    }
+
+    fn y_flag(y: &FieldElement<Self>, format: CompressedFormat) -> bool {
+        quadratic_y_flag(y.value(), format)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{G1Point, G2Point};
+    use crate::cyclic_group::IsGroup;
+    use crate::elliptic_curve::short_weierstrass::curves::bls12_377::{
+        curve::BLS12377Curve, field_extension::BLS12377PrimeField, twist::BLS12377TwistCurve,
+    };
+    use crate::elliptic_curve::short_weierstrass::traits::Compress;
+    use crate::elliptic_curve::traits::{FromAffine, IsEllipticCurve};
+    use crate::errors::DeserializationError;
+    use crate::field::element::FieldElement;
+    use crate::traits::ByteConversion;
+
+    type BLS12377FieldElement = FieldElement<BLS12377PrimeField>;
+
+    /// Big-endian encoding of the modulus p of the base field.
+    fn modulus_bytes() -> [u8; 48] {
+        let mut bytes = [0_u8; 48];
+        bytes.copy_from_slice(&(-BLS12377FieldElement::one()).to_bytes_be());
+        // p - 1 ends in a zero byte, as p = 1 mod 2^46.
+        bytes[47] += 1;
+        bytes
+    }
+
+    #[test]
+    fn compressed_g1_generator_holds_its_x_coordinate() {
+        let g = BLS12377Curve::generator();
+        let mut compressed_g = BLS12377Curve::compress_g1_point(&g);
+        let first_byte = compressed_g.first().unwrap();
+
+        let first_byte_without_control_bits = (first_byte << 3) >> 3;
+        compressed_g[0] = first_byte_without_control_bits;
+
+        let compressed_g_x = BLS12377FieldElement::from_bytes_be(&compressed_g).unwrap();
+
+        assert_eq!(*g.x(), compressed_g_x);
+    }
+
+    #[test]
+    fn g1_points_round_trip() {
+        let g = BLS12377Curve::generator();
+        for point in [
+            G1Point::neutral_element(),
+            g.clone(),
+            g.neg(),
+            g.operate_with_self(2_u64),
+            g.operate_with_self(12345_u64).neg(),
+        ] {
+            let mut compressed = BLS12377Curve::compress_g1_point(&point);
+            assert_eq!(
+                BLS12377Curve::decompress_g1_point(&mut compressed).unwrap(),
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn g2_points_round_trip() {
+        let g = BLS12377TwistCurve::generator();
+        for point in [
+            G2Point::neutral_element(),
+            g.clone(),
+            g.neg(),
+            g.operate_with_self(2_u64),
+            g.operate_with_self(12345_u64).neg(),
+        ] {
+            let mut compressed = BLS12377Curve::compress_g2_point(&point);
+            assert_eq!(
+                BLS12377Curve::decompress_g2_point(&mut compressed).unwrap(),
+                point
+            );
+        }
+    }
+
+    #[test]
+    fn point_at_infinity_sets_the_infinity_flag() {
+        let compressed_g1 = BLS12377Curve::compress_g1_point(&G1Point::neutral_element());
+        let compressed_g2 = BLS12377Curve::compress_g2_point(&G2Point::neutral_element());
+
+        assert_eq!(compressed_g1[0] >> 6, 3_u8);
+        assert_eq!(compressed_g2[0] >> 6, 3_u8);
+    }
+
+    #[test]
+    fn decompression_rejects_uncompressed_values_and_wrong_lengths() {
+        assert_eq!(
+            BLS12377Curve::decompress_g1_point(&mut [0_u8; 48]).unwrap_err(),
+            DeserializationError::InvalidValue
+        );
+        assert_eq!(
+            BLS12377Curve::decompress_g2_point(&mut [0_u8; 96]).unwrap_err(),
+            DeserializationError::InvalidValue
+        );
+        assert_eq!(
+            BLS12377Curve::decompress_g1_point(&mut [1_u8 << 7; 47]).unwrap_err(),
+            DeserializationError::InvalidAmountOfBytes
+        );
+        assert_eq!(
+            BLS12377Curve::decompress_g2_point(&mut [1_u8 << 7; 48]).unwrap_err(),
+            DeserializationError::InvalidAmountOfBytes
+        );
+    }
+
+    #[test]
+    fn decompression_rejects_the_point_at_infinity_with_other_bits_set() {
+        let mut sign_flag_set = [0_u8; 48];
+        sign_flag_set[0] = 0b1110_0000;
+        let mut x_set = [0_u8; 96];
+        x_set[0] = 0b1100_0000;
+        x_set[95] = 1;
+
+        assert_eq!(
+            BLS12377Curve::decompress_g1_point(&mut sign_flag_set).unwrap_err(),
+            DeserializationError::InvalidValue
+        );
+        assert_eq!(
+            BLS12377Curve::decompress_g2_point(&mut x_set).unwrap_err(),
+            DeserializationError::InvalidValue
+        );
+    }
+
+    #[test]
+    fn decompression_rejects_x_not_reduced_modulo_p() {
+        let mut g1_x_equal_to_p = modulus_bytes();
+        g1_x_equal_to_p[0] |= 1 << 7;
+        // The coefficient of u of x is p.
+        let mut g2_x_with_c1_equal_to_p = [0_u8; 96];
+        g2_x_with_c1_equal_to_p[..48].copy_from_slice(&modulus_bytes());
+        g2_x_with_c1_equal_to_p[0] |= 1 << 7;
+
+        assert_eq!(
+            BLS12377Curve::decompress_g1_point(&mut g1_x_equal_to_p).unwrap_err(),
+            DeserializationError::InvalidValue
+        );
+        assert_eq!(
+            BLS12377Curve::decompress_g2_point(&mut g2_x_with_c1_equal_to_p).unwrap_err(),
+            DeserializationError::InvalidValue
+        );
+    }
+
+    #[test]
+    fn g1_decompression_rejects_points_outside_the_subgroup() {
+        // (1, y) is on the curve but not in G1.
+        let x = BLS12377FieldElement::one();
+        let y = (x.pow(3_u64) + BLS12377FieldElement::one())
+            .sqrt()
+            .unwrap()
+            .0;
+        assert!(!G1Point::from_affine(x, y).unwrap().is_in_subgroup());
+
+        let mut compressed = [0_u8; 48];
+        compressed[0] |= 1 << 7;
+        compressed[47] = 1;
+
+        assert_eq!(
+            BLS12377Curve::decompress_g1_point(&mut compressed).unwrap_err(),
+            DeserializationError::PointNotInSubgroup
+        );
+    }
+
+    #[test]
+    fn g2_decompression_rejects_points_outside_the_subgroup() {
+        // x = 2 is the abscissa of a point of the twist that is not in G2.
+        let mut compressed = [0_u8; 96];
+        compressed[0] |= 1 << 7;
+        compressed[95] = 2;
+
+        assert_eq!(
+            BLS12377Curve::decompress_g2_point(&mut compressed).unwrap_err(),
+            DeserializationError::PointNotInSubgroup
+        );
+    }
+}
@@ -4,12 +4,16 @@ use super::{
     twist::BLS12377TwistCurve,
 };
 use crate::cyclic_group::IsGroup;
+use crate::elliptic_curve::glv::{IsGLVCurve, SignedU256};
 use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
-use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::elliptic_curve::traits::{HasSubgroupCheck, IsEllipticCurve};
 use crate::unsigned_integer::element::U256;
 
 use crate::{
-    elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
+    elliptic_curve::short_weierstrass::traits::{
+        CompressedFormat, HasCompressedFormat, IsShortWeierstrass,
+    },
+    field::element::FieldElement,
 };
 
 pub const SUBGROUP_ORDER: U256 =
@@ -48,6 +52,14 @@ impl IsShortWeierstrass for BLS12377Curve {
     }
 }
 
+impl HasCompressedFormat for BLS12377Curve {
+    const COMPRESSED_FORMAT: CompressedFormat = CompressedFormat::Zcash;
+}
+
+impl HasCompressedFormat for BLS12377TwistCurve {
+    const COMPRESSED_FORMAT: CompressedFormat = CompressedFormat::Zcash;
+}
+
 /// This is equal to the frobenius trace of the BLS12 377 curve minus one or seed value z.
 pub const MILLER_LOOP_CONSTANT: u64 = 0x8508c00000000001;
 
@@ -90,6 +102,38 @@ impl ShortWeierstrassProjectivePoint<BLS12377Curve> {
             .neg()
             == self.phi()
     }
+
+    /// Maps the point to the subgroup of order `r` multiplying it by the cofactor of G1.
+    pub fn clear_cofactor(&self) -> Self {
+        self.operate_with_self(CURVE_COFACTOR)
+    }
+}
+
+impl IsGLVCurve for BLS12377Curve {
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+    const LAMBDA: U256 = U256::from_hex_unchecked(
+        "12ab655e9a2ca55660b44d1e5c37b00114885f32400000000000000000000000",
+    );
+    const LATTICE_BASIS: [[SignedU256; 2]; 2] = [
+        [
+            (false, U256::from_u64(1)),
+            (
+                true,
+                U256::from_hex_unchecked("452217cc900000010a11800000000000"),
+            ),
+        ],
+        [
+            (
+                false,
+                U256::from_hex_unchecked("452217cc900000010a11800000000001"),
+            ),
+            (false, U256::from_u64(1)),
+        ],
+    ];
+
+    fn endomorphism(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.phi()
+    }
 }
 
 impl ShortWeierstrassProjectivePoint<BLS12377TwistCurve> {
@@ -111,6 +155,46 @@ impl ShortWeierstrassProjectivePoint<BLS12377TwistCurve> {
     pub fn is_in_subgroup(&self) -> bool {
         self.psi() == self.operate_with_self(MILLER_LOOP_CONSTANT)
     }
+
+    /// Maps the point to the subgroup of order `r` multiplying it by the effective cofactor of
+    /// G2 of the method of Budroni and Pintore:
+    /// `h_eff * P = (z² - z - 1) * P + (z - 1) * 𝜓(P) + 𝜓²(2P)`.
+    /// https://eprint.iacr.org/2017/419.pdf
+    pub fn clear_cofactor(&self) -> Self {
+        let t1 = self.operate_with_self(MILLER_LOOP_CONSTANT);
+        let t2 = self.psi();
+        let t3 = self.double().psi().psi().operate_with(&t2.neg());
+        let t2 = t1.operate_with(&t2).operate_with_self(MILLER_LOOP_CONSTANT);
+        t3.operate_with(&t2)
+            .operate_with(&t1.neg())
+            .operate_with(&self.neg())
+    }
+}
+
+impl HasSubgroupCheck for BLS12377Curve {
+    type OrderType = U256;
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+
+    fn is_in_subgroup(p: &Self::PointRepresentation) -> bool {
+        p.is_in_subgroup()
+    }
+
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.clear_cofactor()
+    }
+}
+
+impl HasSubgroupCheck for BLS12377TwistCurve {
+    type OrderType = U256;
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+
+    fn is_in_subgroup(p: &Self::PointRepresentation) -> bool {
+        p.is_in_subgroup()
+    }
+
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.clear_cofactor()
+    }
 }
 
 #[cfg(test)]
@@ -250,6 +334,53 @@ mod tests {
         assert!(g.is_in_subgroup())
     }
 
+    fn g1_point_outside_subgroup() -> ShortWeierstrassProjectivePoint<BLS12377Curve> {
+        let y = FpE::new_base("93741768985aaeaf572b30d4510780dddfc6167676650e2aba8a11cb2f179f44d1d244eb9b31dd00c3320fe8e604cb");
+        BLS12377Curve::create_point_from_affine(FpE::one(), y).unwrap()
+    }
+
+    fn g2_point_outside_subgroup() -> ShortWeierstrassProjectivePoint<BLS12377TwistCurve> {
+        let y = Fp2::new([
+            FpE::new_base("2f728d6cca7d59f7bc720bbe47e01bf65df9a72cad04932286bb5e6881e355d14d9f53f164345d3d152ae32db8a52b"),
+            FpE::new_base("11182649c532870aec2a8e1aa2b6da4e6135217af1db9cd617a45556e71c9f1fe4c2ac76a56c3043fed1386cc3452a5"),
+        ]);
+        BLS12377TwistCurve::create_point_from_affine(Fp2::from(2), y).unwrap()
+    }
+
+    #[test]
+    fn g1_point_outside_subgroup_is_rejected() {
+        let p = g1_point_outside_subgroup();
+        assert!(!p.is_in_subgroup());
+        assert!(!p.operate_with_self(SUBGROUP_ORDER).is_neutral_element());
+    }
+
+    #[test]
+    fn g2_point_outside_subgroup_is_rejected() {
+        let p = g2_point_outside_subgroup();
+        assert!(!p.is_in_subgroup());
+        assert!(!p.operate_with_self(SUBGROUP_ORDER).is_neutral_element());
+    }
+
+    #[test]
+    fn clear_cofactor_maps_g1_points_to_the_subgroup() {
+        let cleared = BLS12377Curve::clear_cofactor(&g1_point_outside_subgroup());
+        assert!(!cleared.is_neutral_element());
+        assert!(BLS12377Curve::is_in_subgroup(&cleared));
+        assert!(cleared
+            .operate_with_self(SUBGROUP_ORDER)
+            .is_neutral_element());
+    }
+
+    #[test]
+    fn clear_cofactor_maps_g2_points_to_the_subgroup() {
+        let cleared = BLS12377TwistCurve::clear_cofactor(&g2_point_outside_subgroup());
+        assert!(!cleared.is_neutral_element());
+        assert!(BLS12377TwistCurve::is_in_subgroup(&cleared));
+        assert!(cleared
+            .operate_with_self(SUBGROUP_ORDER)
+            .is_neutral_element());
+    }
+
     #[test]
     fn g2_conjugate_works() {
         let a = Fp2::zero();
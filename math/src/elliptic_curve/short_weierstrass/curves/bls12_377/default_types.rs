@@ -0,0 +1,60 @@
+use crate::{
+    field::{
+        element::FieldElement,
+        fields::montgomery_backed_prime_fields::{IsModulus, MontgomeryBackendPrimeField},
+        traits::IsFFTField,
+    },
+    unsigned_integer::element::{UnsignedInteger, U256},
+};
+
+#[derive(Clone, Debug)]
+pub struct FrConfig;
+
+/// Modulus of bls 12 377 subgroup
+impl IsModulus<U256> for FrConfig {
+    const MODULUS: U256 = U256::from_hex_unchecked(
+        "12ab655e9a2ca55660b44d1e5c37b00159aa76fed00000010a11800000000001",
+    );
+}
+
+/// FrField using MontgomeryBackend for bls 12 377
+pub type FrField = MontgomeryBackendPrimeField<FrConfig, 4>;
+/// FrElement using MontgomeryBackend for bls 12 377
+pub type FrElement = FieldElement<FrField>;
+
+/// The primitive root of unity is `22^((r - 1) / 2^47)`, as 22 generates the multiplicative group.
+impl IsFFTField for FrField {
+    const TWO_ADICITY: u64 = 47;
+    const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: Self::BaseType = UnsignedInteger::from_hex_unchecked(
+        "11d4b7f60cb92cc160c69477d1a8a12f9b506ee363e3f04a476ef4a4ec2a895e",
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::traits::IsField;
+
+    #[test]
+    fn two_adic_primitive_root_of_unity_has_the_right_order() {
+        let root = FrElement::from_raw(<FrField as IsField>::from_base_type(
+            FrField::TWO_ADIC_PRIMITVE_ROOT_OF_UNITY,
+        ));
+        let half_order = 1_u64 << (FrField::TWO_ADICITY - 1);
+        assert_eq!(root.pow(half_order), -FrElement::one());
+        assert_eq!(root.pow(half_order * 2), FrElement::one());
+    }
+
+    #[test]
+    fn fft_and_interpolation_work_for_large_domains() {
+        use crate::polynomial::Polynomial;
+
+        let coefficients: alloc::vec::Vec<FrElement> = (0..64_u64).map(FrElement::from).collect();
+        let poly = Polynomial::new(&coefficients);
+        let evaluations = Polynomial::evaluate_fft::<FrField>(&poly, 1, None).unwrap();
+        assert_eq!(
+            Polynomial::interpolate_fft::<FrField>(&evaluations).unwrap(),
+            poly
+        );
+    }
+}
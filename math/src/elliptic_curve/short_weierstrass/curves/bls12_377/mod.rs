@@ -1,4 +1,7 @@
+pub mod compression;
 pub mod curve;
+pub mod default_types;
 pub mod field_extension;
 pub mod pairing;
+pub mod sqrt;
 pub mod twist;
@@ -0,0 +1,149 @@
+use crate::field::traits::LegendreSymbol;
+
+use super::{
+    curve::{BLS12377FieldElement, BLS12377TwistCurveFieldElement},
+    field_extension::FP2_RESIDUE,
+};
+use core::cmp::Ordering;
+
+#[must_use]
+pub fn select_sqrt_value_from_third_bit(
+    sqrt_1: BLS12377FieldElement,
+    sqrt_2: BLS12377FieldElement,
+    third_bit: u8,
+) -> BLS12377FieldElement {
+    match (
+        sqrt_1.representative().cmp(&sqrt_2.representative()),
+        third_bit,
+    ) {
+        (Ordering::Greater, 0) => sqrt_2,
+        (Ordering::Greater, _) | (Ordering::Less, 0) | (Ordering::Equal, _) => sqrt_1,
+        (Ordering::Less, _) => sqrt_2,
+    }
+}
+
+/// Computes a square root of `input = a + b * u` in Fp2 = Fp[u] / (u^2 + 5).
+/// * `third_bit` - if 1, then the square root is the greater one, otherwise it is the smaller one.
+#[must_use]
+pub fn sqrt_qfe(
+    input: &BLS12377TwistCurveFieldElement,
+    third_bit: u8,
+) -> Option<BLS12377TwistCurveFieldElement> {
+    // Algorithm 8, https://eprint.iacr.org/2012/685.pdf, adapted to the non-residue -5.
+    if *input == BLS12377TwistCurveFieldElement::zero() {
+        Some(BLS12377TwistCurveFieldElement::zero())
+    } else {
+        let a = input.value()[0].clone();
+        let b = input.value()[1].clone();
+        if b == BLS12377FieldElement::zero() {
+            // second part is zero
+            match a.sqrt() {
+                Some((y_sqrt_1, y_sqrt_2)) => {
+                    let y_aux = select_sqrt_value_from_third_bit(y_sqrt_1, y_sqrt_2, third_bit);
+                    Some(BLS12377TwistCurveFieldElement::new([
+                        y_aux,
+                        BLS12377FieldElement::zero(),
+                    ]))
+                }
+                None => {
+                    // a is not a square in Fp, so the root is a multiple of u:
+                    // (y * u)^2 = y^2 * FP2_RESIDUE = a.
+                    let (y_sqrt_1, y_sqrt_2) = (a * FP2_RESIDUE.inv().ok()?).sqrt()?;
+                    let y_aux = select_sqrt_value_from_third_bit(y_sqrt_1, y_sqrt_2, third_bit);
+                    Some(BLS12377TwistCurveFieldElement::new([
+                        BLS12377FieldElement::zero(),
+                        y_aux,
+                    ]))
+                }
+            }
+        } else {
+            // second part of the input field number is non-zero
+            // alpha is the norm of the input, a^2 - FP2_RESIDUE * b^2.
+            let alpha = a.pow(2u64) - FP2_RESIDUE * b.pow(2u64);
+            let gamma = alpha.legendre_symbol();
+            match gamma {
+                LegendreSymbol::One => {
+                    let two = BLS12377FieldElement::from(2u64);
+                    let two_inv = two.inv().unwrap();
+                    // calculate the square root of alpha
+                    let (y_sqrt1, y_sqrt2) = alpha.sqrt()?;
+                    let mut delta = (a.clone() + y_sqrt1) * two_inv.clone();
+
+                    let legendre_delta = delta.legendre_symbol();
+                    if legendre_delta == LegendreSymbol::MinusOne {
+                        delta = (a + y_sqrt2) * two_inv;
+                    };
+                    let (x_sqrt_1, x_sqrt_2) = delta.sqrt()?;
+                    let x_0 = select_sqrt_value_from_third_bit(x_sqrt_1, x_sqrt_2, third_bit);
+                    let x_1 = b * (two * x_0.clone()).inv().unwrap();
+                    Some(BLS12377TwistCurveFieldElement::new([x_0, x_1]))
+                }
+                LegendreSymbol::MinusOne => None,
+                LegendreSymbol::Zero => {
+                    unreachable!("The input is zero, but we already handled this case.")
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{
+        curve::{BLS12377FieldElement, BLS12377TwistCurveFieldElement},
+        twist::BLS12377TwistCurve,
+    };
+    use crate::elliptic_curve::{
+        short_weierstrass::traits::IsShortWeierstrass, traits::IsEllipticCurve,
+    };
+
+    #[test]
+    fn sqrt_qfe_of_the_generator_y_squared() {
+        let g = BLS12377TwistCurve::generator().to_affine();
+        let y_squared = g.x().pow(3_u64) + BLS12377TwistCurve::b();
+
+        let root = super::sqrt_qfe(&y_squared, 0).unwrap();
+
+        assert_eq!(root.square(), y_squared);
+        assert!(root == *g.y() || root == -g.y());
+    }
+
+    #[test]
+    fn sqrt_qfe_of_a_point_with_small_x() {
+        let x = BLS12377TwistCurveFieldElement::new([
+            BLS12377FieldElement::from(2),
+            BLS12377FieldElement::zero(),
+        ]);
+        let y_squared = x.pow(3_u64) + BLS12377TwistCurve::b();
+
+        let root = super::sqrt_qfe(&y_squared, 1).unwrap();
+
+        assert_eq!(root.square(), y_squared);
+    }
+
+    #[test]
+    fn sqrt_qfe_of_a_base_field_non_residue() {
+        // -5 is not a square in Fp, but it is u^2 in Fp2.
+        let input = BLS12377TwistCurveFieldElement::new([
+            -BLS12377FieldElement::from(5),
+            BLS12377FieldElement::zero(),
+        ]);
+        assert!(input.value()[0].sqrt().is_none());
+
+        let root = super::sqrt_qfe(&input, 0).unwrap();
+
+        assert_eq!(root.square(), input);
+        assert_eq!(root.value()[0], BLS12377FieldElement::zero());
+    }
+
+    #[test]
+    fn sqrt_qfe_of_a_non_square_is_none() {
+        // u is not a square in Fp2, as its norm 5 is not a square in Fp.
+        let input = BLS12377TwistCurveFieldElement::new([
+            BLS12377FieldElement::zero(),
+            BLS12377FieldElement::one(),
+        ]);
+
+        assert!(super::sqrt_qfe(&input, 0).is_none());
+    }
+}
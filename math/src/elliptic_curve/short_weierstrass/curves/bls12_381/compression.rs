@@ -3,7 +3,7 @@ use crate::{
     elliptic_curve::short_weierstrass::{
         curves::bls12_381::{curve::BLS12381Curve, field_extension::Degree2ExtensionField, sqrt},
         point::ShortWeierstrassProjectivePoint,
-        traits::Compress,
+        traits::{quadratic_y_flag, Compress, CompressedFormat, IsCompressibleField},
     },
     field::element::FieldElement,
 };
@@ -140,20 +140,8 @@ impl Compress for BLS12381Curve {
             x_bytes[0] |= 1 << 7;
 
             // Set the 3rd bit based on y value.
-            let y_neg = -y;
-
-            match (
-                y.value()[0]
-                    .representative()
-                    .cmp(&y_neg.value()[0].representative()),
-                y.value()[1]
-                    .representative()
-                    .cmp(&y_neg.value()[1].representative()),
-            ) {
-                (Ordering::Greater, _) | (Ordering::Equal, Ordering::Greater) => {
-                    x_bytes[0] |= 1 << 5;
-                }
-                (_, _) => (),
+            if is_lexicographically_largest(y) {
+                x_bytes[0] |= 1 << 5;
             }
             x_bytes
         }
@@ -194,10 +182,64 @@ impl Compress for BLS12381Curve {
         const VALUE: BLS12381FieldElement = BLS12381FieldElement::from_hex_unchecked("4");
         let b_param_qfe = FieldElement::<Degree2ExtensionField>::new([VALUE, VALUE]);
 
-        let y = sqrt::sqrt_qfe(&(x.pow(3_u64) + b_param_qfe), third_bit)
+        let y = sqrt::sqrt_qfe(&(x.pow(3_u64) + b_param_qfe), 0)
             .ok_or(ByteConversionError::InvalidValue)?;
+        let y = if is_lexicographically_largest(&y) == (third_bit == 1) {
+            y
+        } else {
+            -y
+        };
+
+        let point =
+            Self::G2Point::from_affine(x, y).map_err(|_| ByteConversionError::InvalidValue)?;
+
+        point
+            .is_in_subgroup()
+            .then_some(point)
+            .ok_or(ByteConversionError::PointNotInSubgroup)
+    }
+}
 
-        Self::G2Point::from_affine(x, y).map_err(|_| ByteConversionError::InvalidValue)
+/// Whether `y` is greater than `-y`, comparing the coefficients of `i` first, as in the
+/// sign bit of the Zcash encoding of G2 points.
+fn is_lexicographically_largest(y: &FieldElement<Degree2ExtensionField>) -> bool {
+    let y_neg = -y;
+    match y.value()[1]
+        .representative()
+        .cmp(&y_neg.value()[1].representative())
+    {
+        Ordering::Equal => y.value()[0].representative() > y_neg.value()[0].representative(),
+        ordering => ordering == Ordering::Greater,
+    }
+}
+
+/// The elements `c0 + c1 * u` of the field of G2 are encoded as `c1` followed by `c0`.
+impl IsCompressibleField for Degree2ExtensionField {
+    #[cfg(feature = "alloc")]
+    fn to_bytes_be(x: &FieldElement<Self>) -> alloc::vec::Vec<u8> {
+        let [c0, c1] = x.value();
+        let mut bytes = c1.to_bytes_be();
+        bytes.extend(c0.to_bytes_be());
+        bytes
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<FieldElement<Self>, ByteConversionError> {
+        if bytes.len() != 2 * 48 {
+            return Err(ByteConversionError::InvalidValue);
+        }
+        let (c1, c0) = bytes.split_at(48);
+        Ok(FieldElement::new([
+            FieldElement::from_bytes_be(c0)?,
+            FieldElement::from_bytes_be(c1)?,
+        ]))
+    }
+
+    fn sqrt(x: &FieldElement<Self>) -> Option<FieldElement<Self>> {
+        sqrt::sqrt_qfe(x, 0)
+    }
+
+    fn y_flag(y: &FieldElement<Self>, format: CompressedFormat) -> bool {
+        quadratic_y_flag(y.value(), format)
     }
 }
 
@@ -318,11 +360,12 @@ mod tests {
 
     #[cfg(feature = "alloc")]
     #[test]
-    fn test_decompress_g2() {
+    fn test_decompress_g2_rejects_points_outside_the_subgroup() {
         use crate::{
             elliptic_curve::short_weierstrass::curves::bls12_381::{
                 field_extension::Degree2ExtensionField, twist::BLS12381TwistCurve,
             },
+            errors::ByteConversionError,
             field::element::FieldElement,
         };
 
@@ -330,7 +373,7 @@ mod tests {
         compressed_point[0] |= 1 << 7;
         compressed_point[95] |= 1 << 1;
 
-        // Valig G2 point coordinates:
+        // Coordinates of a point of the twist that is not in G2:
         let x_0 = BLS12381FieldElement::from_hex_unchecked("02");
         let x_1 = BLS12381FieldElement::from_hex_unchecked("0");
         let y_0 = BLS12381FieldElement::from_hex_unchecked("013a59858b6809fca4d9a3b6539246a70051a3c88899964a42bc9a69cf9acdd9dd387cfa9086b894185b9a46a402be73");
@@ -339,11 +382,13 @@ mod tests {
         let x: FieldElement<Degree2ExtensionField> = FieldElement::new([x_0, x_1]);
         let y: FieldElement<Degree2ExtensionField> = FieldElement::new([y_0, y_1]);
 
-        let valid_g2_point = BLS12381TwistCurve::create_point_from_affine(x, y).unwrap();
-
-        let decompressed_point = BLS12381Curve::decompress_g2_point(&mut compressed_point).unwrap();
+        let point = BLS12381TwistCurve::create_point_from_affine(x, y).unwrap();
+        assert!(!point.is_in_subgroup());
 
-        assert_eq!(valid_g2_point, decompressed_point);
+        assert_eq!(
+            BLS12381Curve::decompress_g2_point(&mut compressed_point).unwrap_err(),
+            ByteConversionError::PointNotInSubgroup
+        );
     }
 
     #[cfg(feature = "alloc")]
@@ -378,4 +423,27 @@ mod tests {
 
         assert_eq!(compress_point, valid_compressed_point);
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_compress_generator_g2_matches_the_zcash_encoding() {
+        use crate::elliptic_curve::short_weierstrass::curves::bls12_381::twist::BLS12381TwistCurve;
+
+        let g = BLS12381TwistCurve::generator();
+        let compressed = BLS12381Curve::compress_g2_point(&g);
+        let expected = "93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+        let expected_neg = "b3e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+        let hex = |bytes: &[u8]| {
+            bytes
+                .iter()
+                .map(|byte| alloc::format!("{byte:02x}"))
+                .collect::<alloc::string::String>()
+        };
+
+        assert_eq!(hex(&compressed), expected);
+        assert_eq!(
+            hex(&BLS12381Curve::compress_g2_point(&g.neg())),
+            expected_neg
+        );
+    }
 }
@@ -3,11 +3,15 @@ use super::{
     twist::BLS12381TwistCurve,
 };
 use crate::cyclic_group::IsGroup;
+use crate::elliptic_curve::glv::{IsGLVCurve, SignedU256};
 use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
-use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::elliptic_curve::traits::{HasSubgroupCheck, IsEllipticCurve};
 use crate::unsigned_integer::element::U256;
 use crate::{
-    elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
+    elliptic_curve::short_weierstrass::traits::{
+        CompressedFormat, HasCompressedFormat, IsShortWeierstrass,
+    },
+    field::element::FieldElement,
 };
 
 pub const SUBGROUP_ORDER: U256 =
@@ -45,6 +49,14 @@ impl IsShortWeierstrass for BLS12381Curve {
     }
 }
 
+impl HasCompressedFormat for BLS12381Curve {
+    const COMPRESSED_FORMAT: CompressedFormat = CompressedFormat::Zcash;
+}
+
+impl HasCompressedFormat for BLS12381TwistCurve {
+    const COMPRESSED_FORMAT: CompressedFormat = CompressedFormat::Zcash;
+}
+
 /// This is equal to the frobenius trace of the BLS12 381 curve minus one or seed value z.
 pub const MILLER_LOOP_CONSTANT: u64 = 0xd201000000010000;
 
@@ -86,6 +98,39 @@ impl ShortWeierstrassProjectivePoint<BLS12381Curve> {
             .neg()
             == self.phi()
     }
+
+    /// Maps the point to the subgroup of order `r` multiplying it by `1 - z`, the effective
+    /// cofactor of G1 used by the hash to curve suites of RFC 9380.
+    pub fn clear_cofactor(&self) -> Self {
+        self.operate_with_self(MILLER_LOOP_CONSTANT + 1)
+    }
+}
+
+impl IsGLVCurve for BLS12381Curve {
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+    const LAMBDA: U256 = U256::from_hex_unchecked(
+        "73eda753299d7d483339d80809a1d804a7780001fffcb7fcfffffffe00000001",
+    );
+    const LATTICE_BASIS: [[SignedU256; 2]; 2] = [
+        [
+            (false, U256::from_u64(1)),
+            (
+                true,
+                U256::from_hex_unchecked("ac45a4010001a40200000000ffffffff"),
+            ),
+        ],
+        [
+            (
+                false,
+                U256::from_hex_unchecked("ac45a4010001a4020000000100000000"),
+            ),
+            (false, U256::from_u64(1)),
+        ],
+    ];
+
+    fn endomorphism(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.phi()
+    }
 }
 
 impl ShortWeierstrassProjectivePoint<BLS12381TwistCurve> {
@@ -106,6 +151,50 @@ impl ShortWeierstrassProjectivePoint<BLS12381TwistCurve> {
     pub fn is_in_subgroup(&self) -> bool {
         self.psi() == self.operate_with_self(MILLER_LOOP_CONSTANT).neg()
     }
+
+    /// Maps the point to the subgroup of order `r` multiplying it by the effective cofactor of G2
+    /// used by the hash to curve suites of RFC 9380, with the method of Budroni and Pintore:
+    /// `h_eff * P = (z² - z - 1) * P + (z - 1) * 𝜓(P) + 𝜓²(2P)`.
+    /// https://www.rfc-editor.org/rfc/rfc9380.html#appendix-G.3
+    pub fn clear_cofactor(&self) -> Self {
+        // z * P, as z = -MILLER_LOOP_CONSTANT
+        let t1 = self.operate_with_self(MILLER_LOOP_CONSTANT).neg();
+        let t2 = self.psi();
+        let t3 = self.double().psi().psi().operate_with(&t2.neg());
+        let t2 = t1
+            .operate_with(&t2)
+            .operate_with_self(MILLER_LOOP_CONSTANT)
+            .neg();
+        t3.operate_with(&t2)
+            .operate_with(&t1.neg())
+            .operate_with(&self.neg())
+    }
+}
+
+impl HasSubgroupCheck for BLS12381Curve {
+    type OrderType = U256;
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+
+    fn is_in_subgroup(p: &Self::PointRepresentation) -> bool {
+        p.is_in_subgroup()
+    }
+
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.clear_cofactor()
+    }
+}
+
+impl HasSubgroupCheck for BLS12381TwistCurve {
+    type OrderType = U256;
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+
+    fn is_in_subgroup(p: &Self::PointRepresentation) -> bool {
+        p.is_in_subgroup()
+    }
+
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.clear_cofactor()
+    }
 }
 
 #[cfg(test)]
@@ -242,6 +331,20 @@ mod tests {
         assert!(!p.is_in_subgroup())
     }
 
+    #[test]
+    fn clear_cofactor_maps_g1_points_to_the_subgroup() {
+        let x = FEE::new_base("178212cbe4a3026c051d4f867364b3ea84af623f93233b347ffcd3d6b16f16e0a7aedbe1c78d33c6beca76b2b75c8486");
+        let y = FEE::new_base("13a8b1347e5b43bc4051754b2a29928b5df78cf03ca3b1f73d0424b09fccdef116c9f0ecbec7420a99b2dd785209e9d");
+        let p = BLS12381Curve::create_point_from_affine(x, y).unwrap();
+        assert!(p.clear_cofactor().is_in_subgroup());
+
+        let g = BLS12381Curve::generator();
+        assert_eq!(
+            g.clear_cofactor(),
+            g.operate_with_self(MILLER_LOOP_CONSTANT + 1)
+        );
+    }
+
     #[test]
     fn generator_g2_is_in_subgroup() {
         let g = BLS12381TwistCurve::generator();
@@ -270,6 +373,23 @@ mod tests {
         assert!(!p.is_in_subgroup())
     }
 
+    #[test]
+    fn clear_cofactor_maps_g2_points_to_the_subgroup() {
+        let x = FTE::new([
+            FEE::new(U384::from_hex_unchecked("97798b4a61ac301bbee71e36b5174e2f4adfe3e1729bdae1fcc9965ae84181be373aa80414823eed694f1270014012d")),
+            FEE::new(U384::from_hex_unchecked("c9852cc6e61868966249aec153b50b29b3c22409f4c7880fd13121981c103c8ef84d9ea29b552431360e82cf69219fa"))
+        ]);
+        let y = FTE::new([
+            FEE::new(U384::from_hex_unchecked("16cb3a60f3fa52c8273aceeb94c4c7303e8074aa9eedec7355bbb1e8cceedd4ec1497f573f62822140377b8e339619ed")),
+            FEE::new(U384::from_hex_unchecked("1cd919b08afe06bebe9adf6223a55868a6fd8b77efc5c67b60fff39be36e9b44b7f10db16827c83b43ad2dad1947778"))
+        ]);
+        let p = BLS12381TwistCurve::create_point_from_affine(x, y).unwrap();
+        let cleared = p.clear_cofactor();
+
+        assert!(cleared.is_in_subgroup());
+        assert!(!cleared.is_neutral_element());
+    }
+
     #[test]
     fn g2_conjugate_works() {
         let a = FTE::zero();
@@ -3,7 +3,9 @@ use crate::{
     elliptic_curve::short_weierstrass::{
         curves::bn_254::{curve::BN254Curve, field_extension::Degree2ExtensionField, sqrt},
         point::ShortWeierstrassProjectivePoint,
-        traits::{Compress, IsShortWeierstrass},
+        traits::{
+            quadratic_y_flag, Compress, CompressedFormat, IsCompressibleField, IsShortWeierstrass,
+        },
     },
     field::element::FieldElement,
 };
@@ -196,7 +198,43 @@ impl Compress for BN254Curve {
         let y = sqrt::sqrt_qfe(&(x.pow(3_u64) + b_param_qfe), second_bit)
             .ok_or(ByteConversionError::InvalidValue)?;
 
-        Self::G2Point::from_affine(x, y).map_err(|_| ByteConversionError::InvalidValue)
+        let point =
+            Self::G2Point::from_affine(x, y).map_err(|_| ByteConversionError::InvalidValue)?;
+
+        point
+            .is_in_subgroup()
+            .then_some(point)
+            .ok_or(ByteConversionError::PointNotInSubgroup)
+    }
+}
+
+/// The elements `c0 + c1 * u` of the field of G2 are encoded as `c1` followed by `c0`.
+impl IsCompressibleField for Degree2ExtensionField {
+    #[cfg(feature = "alloc")]
+    fn to_bytes_be(x: &FieldElement<Self>) -> alloc::vec::Vec<u8> {
+        let [c0, c1] = x.value();
+        let mut bytes = c1.to_bytes_be();
+        bytes.extend(c0.to_bytes_be());
+        bytes
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<FieldElement<Self>, ByteConversionError> {
+        if bytes.len() != 2 * 32 {
+            return Err(ByteConversionError::InvalidValue);
+        }
+        let (c1, c0) = bytes.split_at(32);
+        Ok(FieldElement::new([
+            FieldElement::from_bytes_be(c0)?,
+            FieldElement::from_bytes_be(c1)?,
+        ]))
+    }
+
+    fn sqrt(x: &FieldElement<Self>) -> Option<FieldElement<Self>> {
+        sqrt::sqrt_qfe(x, 0)
+    }
+
+    fn y_flag(y: &FieldElement<Self>, format: CompressedFormat) -> bool {
+        quadratic_y_flag(y.value(), format)
     }
 }
 
@@ -399,6 +437,37 @@ mod tests {
         assert_eq!(g_2, decompressed_g2);
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn g2_decompress_rejects_points_outside_the_subgroup() {
+        use crate::unsigned_integer::element::U256;
+
+        let q = G2Point::new([
+            Fp2E::new([
+                FpE::new(U256::from_hex_unchecked(
+                    "1800deef121f1e76426a00665e5c4479674322d4f75edaddde46bd5cd992f6ed",
+                )),
+                FpE::new(U256::from_hex_unchecked(
+                    "198e9393920daef312c20b9f1099ecefa8b45575d349b0a6f04c16d0d58af900",
+                )),
+            ]),
+            Fp2E::new([
+                FpE::new(U256::from_hex_unchecked(
+                    "22376289c558493c1d6cc413a5f07dcb54526a964e4e687b65a881aa9752faa2",
+                )),
+                FpE::new(U256::from_hex_unchecked(
+                    "05a7a5759338c23ca603c1c4adf979e004c2f3e3c5bad6f07693c59a85d600a9",
+                )),
+            ]),
+            Fp2E::one(),
+        ]);
+        let mut compressed_q = BN254Curve::compress_g2_point(&q);
+        assert_eq!(
+            BN254Curve::decompress_g2_point(&mut compressed_q).unwrap_err(),
+            ByteConversionError::PointNotInSubgroup
+        );
+    }
+
     #[test]
     fn g1_decompress_wrong_bytes_length() {
         let mut input_bytes: [u8; 31] = [0; 31];
@@ -4,10 +4,17 @@ use super::{
     twist::BN254TwistCurve,
 };
 use crate::cyclic_group::IsGroup;
+use crate::elliptic_curve::glv::{IsGLVCurve, SignedU256};
 use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
-use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::elliptic_curve::traits::{HasSubgroupCheck, IsEllipticCurve};
+use crate::unsigned_integer::element::U256;
+#[cfg(feature = "alloc")]
+use crate::unsigned_integer::{element::UnsignedInteger, traits::IsUnsignedInteger};
 use crate::{
-    elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
+    elliptic_curve::short_weierstrass::traits::{
+        CompressedFormat, HasCompressedFormat, IsShortWeierstrass,
+    },
+    field::element::FieldElement,
 };
 
 pub type BN254FieldElement = FieldElement<BN254PrimeField>;
@@ -37,6 +44,72 @@ impl IsShortWeierstrass for BN254Curve {
     fn b() -> FieldElement<Self::BaseField> {
         FieldElement::from(3)
     }
+
+    #[cfg(feature = "alloc")]
+    fn pippenger_msm<const NUM_LIMBS: usize>(
+        cs: &[UnsignedInteger<NUM_LIMBS>],
+        points: &[ShortWeierstrassProjectivePoint<Self>],
+        window_size: usize,
+    ) -> ShortWeierstrassProjectivePoint<Self> {
+        crate::msm::pippenger::glv_short_weierstrass_msm(cs, points, window_size)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn operate_with_self<T: IsUnsignedInteger>(
+        p: &ShortWeierstrassProjectivePoint<Self>,
+        exponent: T,
+    ) -> ShortWeierstrassProjectivePoint<Self> {
+        crate::elliptic_curve::glv::glv_operate_with_self::<Self, T>(p, exponent)
+    }
+}
+
+impl HasCompressedFormat for BN254Curve {
+    const COMPRESSED_FORMAT: CompressedFormat = CompressedFormat::Gnark;
+}
+
+impl HasCompressedFormat for BN254TwistCurve {
+    const COMPRESSED_FORMAT: CompressedFormat = CompressedFormat::Gnark;
+}
+
+/// 𝛽 : primitive cube root of unity of 𝐹ₚ, so that 𝜙(𝑥, 𝑦) = (𝛽𝑥, 𝑦) is an endomorphism of the curve.
+pub const CUBE_ROOT_OF_UNITY_G1: BN254FieldElement = FieldElement::from_hex_unchecked(
+    "30644e72e131a0295e6dd9e7e0acccb0c28f069fbb966e3de4bd44e5607cfd48",
+);
+
+/// Order of the main subgroup, which is the whole group of points of the curve.
+pub const SUBGROUP_ORDER: U256 =
+    U256::from_hex_unchecked("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001");
+
+/// Cofactor of G2, the subgroup of order `r` of the twist, which is `2p - r`.
+pub const TWIST_COFACTOR: U256 =
+    U256::from_hex_unchecked("30644e72e131a029b85045b68181585e06ceecda572a2489345f2299c0f9fa8d");
+
+impl IsGLVCurve for BN254Curve {
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+    const LAMBDA: U256 = U256::from_hex_unchecked(
+        "30644e72e131a029048b6e193fd84104cc37a73fec2bc5e9b8ca0b2d36636f23",
+    );
+    const LATTICE_BASIS: [[SignedU256; 2]; 2] = [
+        [
+            (
+                false,
+                U256::from_hex_unchecked("6f4d8248eeb859fc8211bbeb7d4f1128"),
+            ),
+            (true, U256::from_hex_unchecked("89d3256894d213e3")),
+        ],
+        [
+            (false, U256::from_hex_unchecked("89d3256894d213e3")),
+            (
+                false,
+                U256::from_hex_unchecked("6f4d8248eeb859fd0be4e1541221250b"),
+            ),
+        ],
+    ];
+
+    fn endomorphism(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        let [x, y, z] = p.coordinates();
+        Self::PointRepresentation::new([x * CUBE_ROOT_OF_UNITY_G1, y.clone(), z.clone()])
+    }
 }
 
 impl ShortWeierstrassProjectivePoint<BN254Curve> {
@@ -71,6 +144,32 @@ impl ShortWeierstrassProjectivePoint<BN254TwistCurve> {
     }
 }
 
+impl HasSubgroupCheck for BN254Curve {
+    type OrderType = U256;
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+
+    fn is_in_subgroup(_p: &Self::PointRepresentation) -> bool {
+        true
+    }
+
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.clone()
+    }
+}
+
+impl HasSubgroupCheck for BN254TwistCurve {
+    type OrderType = U256;
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+
+    fn is_in_subgroup(p: &Self::PointRepresentation) -> bool {
+        p.is_in_subgroup()
+    }
+
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.operate_with_self(TWIST_COFACTOR)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,9 +358,8 @@ mod tests {
         assert!(g.is_in_subgroup())
     }
 
-    #[test]
-    fn invalid_g2_is_not_in_subgroup() {
-        let q = ShortWeierstrassProjectivePoint::<BN254TwistCurve>::new([
+    fn g2_point_outside_subgroup() -> ShortWeierstrassProjectivePoint<BN254TwistCurve> {
+        ShortWeierstrassProjectivePoint::<BN254TwistCurve>::new([
             Fp2E::new([
                 FpE::new(U256::from_hex_unchecked(
                     "1800deef121f1e76426a00665e5c4479674322d4f75edaddde46bd5cd992f6ed",
@@ -279,10 +377,22 @@ mod tests {
                 )),
             ]),
             Fp2E::one(),
-        ]);
+        ])
+    }
+
+    #[test]
+    fn invalid_g2_is_not_in_subgroup() {
+        let q = g2_point_outside_subgroup();
         assert!(!q.is_in_subgroup())
     }
 
+    #[test]
+    fn clear_cofactor_maps_g2_points_to_the_subgroup() {
+        let cleared = BN254TwistCurve::clear_cofactor(&g2_point_outside_subgroup());
+        assert!(!cleared.is_neutral_element());
+        assert!(BN254TwistCurve::is_in_subgroup(&cleared));
+    }
+
     #[test]
     fn g2_conjugate_two_times_is_identity() {
         let a = Fp2E::zero();
@@ -1,11 +1,14 @@
 use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
-use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::elliptic_curve::traits::{HasSubgroupCheck, IsEllipticCurve};
 use crate::field::fields::montgomery_backed_prime_fields::{
     IsModulus, MontgomeryBackendPrimeField,
 };
 use crate::unsigned_integer::element::U256;
 use crate::{
-    elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
+    elliptic_curve::short_weierstrass::traits::{
+        CompressedFormat, HasCompressedFormat, IsShortWeierstrass,
+    },
+    field::element::FieldElement,
 };
 
 /// Grumpkin an elliptic curve on top of BN254 for SNARK efficient group operations used by the Aztec Protocol.
@@ -45,6 +48,23 @@ impl IsShortWeierstrass for GrumpkinCurve {
     }
 }
 
+impl HasCompressedFormat for GrumpkinCurve {
+    const COMPRESSED_FORMAT: CompressedFormat = CompressedFormat::Sec1;
+}
+
+impl HasSubgroupCheck for GrumpkinCurve {
+    type OrderType = U256;
+    const SUBGROUP_ORDER: U256 = FrConfig::MODULUS;
+
+    fn is_in_subgroup(_p: &Self::PointRepresentation) -> bool {
+        true
+    }
+
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.clone()
+    }
+}
+
 // Grumpkin Fp
 // p = 21888242871839275222246405745257275088548364400416034343698204186575808495617
 // Equal tp BN254 Fr
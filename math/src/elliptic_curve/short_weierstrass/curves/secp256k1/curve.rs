@@ -1,8 +1,15 @@
+use crate::elliptic_curve::glv::{IsGLVCurve, SignedU256};
 use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
-use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::elliptic_curve::traits::{HasSubgroupCheck, IsEllipticCurve};
 use crate::field::fields::secp256k1_field::Secp256k1PrimeField;
+use crate::unsigned_integer::element::U256;
+#[cfg(feature = "alloc")]
+use crate::unsigned_integer::{element::UnsignedInteger, traits::IsUnsignedInteger};
 use crate::{
-    elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
+    elliptic_curve::short_weierstrass::traits::{
+        CompressedFormat, HasCompressedFormat, IsShortWeierstrass,
+    },
+    field::element::FieldElement,
 };
 
 /// This implementation is not constant time and cannot be used to sign messages. You can use it to check signatures
@@ -34,6 +41,83 @@ impl IsShortWeierstrass for Secp256k1Curve {
     fn b() -> FieldElement<Self::BaseField> {
         FieldElement::from(7)
     }
+
+    #[cfg(feature = "alloc")]
+    fn pippenger_msm<const NUM_LIMBS: usize>(
+        cs: &[UnsignedInteger<NUM_LIMBS>],
+        points: &[ShortWeierstrassProjectivePoint<Self>],
+        window_size: usize,
+    ) -> ShortWeierstrassProjectivePoint<Self> {
+        crate::msm::pippenger::glv_short_weierstrass_msm(cs, points, window_size)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn operate_with_self<T: IsUnsignedInteger>(
+        p: &ShortWeierstrassProjectivePoint<Self>,
+        exponent: T,
+    ) -> ShortWeierstrassProjectivePoint<Self> {
+        crate::elliptic_curve::glv::glv_operate_with_self::<Self, T>(p, exponent)
+    }
+}
+
+impl HasCompressedFormat for Secp256k1Curve {
+    const COMPRESSED_FORMAT: CompressedFormat = CompressedFormat::Sec1;
+}
+
+impl HasSubgroupCheck for Secp256k1Curve {
+    type OrderType = U256;
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+
+    fn is_in_subgroup(_p: &Self::PointRepresentation) -> bool {
+        true
+    }
+
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.clone()
+    }
+}
+
+/// 𝛽 : primitive cube root of unity of 𝐹ₚ, so that 𝜙(𝑥, 𝑦) = (𝛽𝑥, 𝑦) is an endomorphism of the curve.
+pub const CUBE_ROOT_OF_UNITY: FieldElement<Secp256k1PrimeField> = FieldElement::from_hex_unchecked(
+    "851695d49a83f8ef919bb86153cbcb16630fb68aed0a766a3ec693d68e6afa40",
+);
+
+/// Order of the main subgroup, which is the whole group of points of the curve.
+pub const SUBGROUP_ORDER: U256 =
+    U256::from_hex_unchecked("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141");
+
+impl IsGLVCurve for Secp256k1Curve {
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+    const LAMBDA: U256 = U256::from_hex_unchecked(
+        "ac9c52b33fa3cf1f5ad9e3fd77ed9ba4a880b9fc8ec739c2e0cfc810b51283ce",
+    );
+    const LATTICE_BASIS: [[SignedU256; 2]; 2] = [
+        [
+            (
+                false,
+                U256::from_hex_unchecked("e4437ed6010e88286f547fa90abfe4c3"),
+            ),
+            (
+                true,
+                U256::from_hex_unchecked("3086d221a7d46bcde86c90e49284eb15"),
+            ),
+        ],
+        [
+            (
+                false,
+                U256::from_hex_unchecked("3086d221a7d46bcde86c90e49284eb15"),
+            ),
+            (
+                false,
+                U256::from_hex_unchecked("114ca50f7a8e2f3f657c1108d9d44cfd8"),
+            ),
+        ],
+    ];
+
+    fn endomorphism(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        let [x, y, z] = p.coordinates();
+        Self::PointRepresentation::new([x * CUBE_ROOT_OF_UNITY, y.clone(), z.clone()])
+    }
 }
 
 #[cfg(test)]
@@ -1,8 +1,12 @@
 use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
-use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::elliptic_curve::traits::{HasSubgroupCheck, IsEllipticCurve};
 use crate::field::fields::secp256r1_field::Secp256r1PrimeField;
+use crate::unsigned_integer::element::U256;
 use crate::{
-    elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
+    elliptic_curve::short_weierstrass::traits::{
+        CompressedFormat, HasCompressedFormat, IsShortWeierstrass,
+    },
+    field::element::FieldElement,
 };
 
 /// This implementation is not constant time and cannot be used to sign messages. You can use it to check signatures
@@ -39,6 +43,27 @@ impl IsShortWeierstrass for Secp256r1Curve {
     }
 }
 
+impl HasCompressedFormat for Secp256r1Curve {
+    const COMPRESSED_FORMAT: CompressedFormat = CompressedFormat::Sec1;
+}
+
+/// Order of the main subgroup, which is the whole group of points of the curve.
+pub const SUBGROUP_ORDER: U256 =
+    U256::from_hex_unchecked("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+
+impl HasSubgroupCheck for Secp256r1Curve {
+    type OrderType = U256;
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+
+    fn is_in_subgroup(_p: &Self::PointRepresentation) -> bool {
+        true
+    }
+
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.clone()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
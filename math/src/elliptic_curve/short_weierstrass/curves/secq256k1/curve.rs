@@ -1,8 +1,12 @@
 use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
-use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::elliptic_curve::traits::{HasSubgroupCheck, IsEllipticCurve};
 use crate::field::fields::secp256k1_scalarfield::Secp256k1ScalarField;
+use crate::unsigned_integer::element::U256;
 use crate::{
-    elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
+    elliptic_curve::short_weierstrass::traits::{
+        CompressedFormat, HasCompressedFormat, IsShortWeierstrass,
+    },
+    field::element::FieldElement,
 };
 
 #[derive(Clone, Debug)]
@@ -35,6 +39,27 @@ impl IsShortWeierstrass for Secq256k1Curve {
     }
 }
 
+impl HasCompressedFormat for Secq256k1Curve {
+    const COMPRESSED_FORMAT: CompressedFormat = CompressedFormat::Sec1;
+}
+
+/// Order of the main subgroup, which is the whole group of points of the curve.
+pub const SUBGROUP_ORDER: U256 =
+    U256::from_hex_unchecked("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+
+impl HasSubgroupCheck for Secq256k1Curve {
+    type OrderType = U256;
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+
+    fn is_in_subgroup(_p: &Self::PointRepresentation) -> bool {
+        true
+    }
+
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.clone()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
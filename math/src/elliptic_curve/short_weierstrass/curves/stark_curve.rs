@@ -1,11 +1,15 @@
 use crate::{
     elliptic_curve::{
-        short_weierstrass::{point::ShortWeierstrassProjectivePoint, traits::IsShortWeierstrass},
-        traits::IsEllipticCurve,
+        short_weierstrass::{
+            point::ShortWeierstrassProjectivePoint,
+            traits::{CompressedFormat, HasCompressedFormat, IsShortWeierstrass},
+        },
+        traits::{HasSubgroupCheck, IsEllipticCurve},
     },
     field::{
         element::FieldElement, fields::fft_friendly::stark_252_prime_field::Stark252PrimeField,
     },
+    unsigned_integer::element::U256,
 };
 
 #[derive(Clone, Debug)]
@@ -52,3 +56,24 @@ impl IsShortWeierstrass for StarkCurve {
         )
     }
 }
+
+impl HasCompressedFormat for StarkCurve {
+    const COMPRESSED_FORMAT: CompressedFormat = CompressedFormat::Sec1;
+}
+
+/// Order of the main subgroup, which is the whole group of points of the curve.
+pub const SUBGROUP_ORDER: U256 =
+    U256::from_hex_unchecked("0800000000000010ffffffffffffffffb781126dcae7b2321e66a241adc64d2f");
+
+impl HasSubgroupCheck for StarkCurve {
+    type OrderType = U256;
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+
+    fn is_in_subgroup(_p: &Self::PointRepresentation) -> bool {
+        true
+    }
+
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.clone()
+    }
+}
@@ -1,8 +1,15 @@
+use crate::elliptic_curve::glv::{IsGLVCurve, SignedU256};
 use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
-use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::elliptic_curve::traits::{HasSubgroupCheck, IsEllipticCurve};
 use crate::field::fields::vesta_field::Vesta255PrimeField;
+use crate::unsigned_integer::element::U256;
+#[cfg(feature = "alloc")]
+use crate::unsigned_integer::{element::UnsignedInteger, traits::IsUnsignedInteger};
 use crate::{
-    elliptic_curve::short_weierstrass::traits::IsShortWeierstrass, field::element::FieldElement,
+    elliptic_curve::short_weierstrass::traits::{
+        CompressedFormat, HasCompressedFormat, IsShortWeierstrass,
+    },
+    field::element::FieldElement,
 };
 
 #[derive(Clone, Debug)]
@@ -29,6 +36,83 @@ impl IsShortWeierstrass for VestaCurve {
     fn b() -> FieldElement<Self::BaseField> {
         FieldElement::from(5)
     }
+
+    #[cfg(feature = "alloc")]
+    fn pippenger_msm<const NUM_LIMBS: usize>(
+        cs: &[UnsignedInteger<NUM_LIMBS>],
+        points: &[ShortWeierstrassProjectivePoint<Self>],
+        window_size: usize,
+    ) -> ShortWeierstrassProjectivePoint<Self> {
+        crate::msm::pippenger::glv_short_weierstrass_msm(cs, points, window_size)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn operate_with_self<T: IsUnsignedInteger>(
+        p: &ShortWeierstrassProjectivePoint<Self>,
+        exponent: T,
+    ) -> ShortWeierstrassProjectivePoint<Self> {
+        crate::elliptic_curve::glv::glv_operate_with_self::<Self, T>(p, exponent)
+    }
+}
+
+impl HasCompressedFormat for VestaCurve {
+    const COMPRESSED_FORMAT: CompressedFormat = CompressedFormat::Sec1;
+}
+
+impl HasSubgroupCheck for VestaCurve {
+    type OrderType = U256;
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+
+    fn is_in_subgroup(_p: &Self::PointRepresentation) -> bool {
+        true
+    }
+
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        p.clone()
+    }
+}
+
+/// 𝛽 : primitive cube root of unity of 𝐹ₚ, so that 𝜙(𝑥, 𝑦) = (𝛽𝑥, 𝑦) is an endomorphism of the curve.
+pub const CUBE_ROOT_OF_UNITY: FieldElement<Vesta255PrimeField> = FieldElement::from_hex_unchecked(
+    "397e65a7d7c1ad71aee24b27e308f0a61259527ec1d4752e619d1840af55f1b1",
+);
+
+/// Order of the main subgroup, which is the whole group of points of the curve.
+pub const SUBGROUP_ORDER: U256 =
+    U256::from_hex_unchecked("40000000000000000000000000000000224698fc094cf91b992d30ed00000001");
+
+impl IsGLVCurve for VestaCurve {
+    const SUBGROUP_ORDER: U256 = SUBGROUP_ORDER;
+    const LAMBDA: U256 = U256::from_hex_unchecked(
+        "2d33357cb532458ed3552a23a8554e5005270d29d19fc7d27b7fd22f0201b547",
+    );
+    const LATTICE_BASIS: [[SignedU256; 2]; 2] = [
+        [
+            (
+                false,
+                U256::from_hex_unchecked("49e69d1640a899538cb1279300000001"),
+            ),
+            (
+                true,
+                U256::from_hex_unchecked("49e69d1640f049157fcae1c700000000"),
+            ),
+        ],
+        [
+            (
+                false,
+                U256::from_hex_unchecked("93cd3a2c8198e2690c7c095a00000001"),
+            ),
+            (
+                false,
+                U256::from_hex_unchecked("49e69d1640a899538cb1279300000001"),
+            ),
+        ],
+    ];
+
+    fn endomorphism(p: &Self::PointRepresentation) -> Self::PointRepresentation {
+        let [x, y, z] = p.coordinates();
+        Self::PointRepresentation::new([x * CUBE_ROOT_OF_UNITY, y.clone(), z.clone()])
+    }
 }
 
 #[cfg(test)]
@@ -2,17 +2,26 @@ use crate::{
     cyclic_group::IsGroup,
     elliptic_curve::{
         point::{JacobianPoint, ProjectivePoint},
-        traits::{EllipticCurveError, FromAffine, IsEllipticCurve},
+        traits::{EllipticCurveError, FromAffine, HasSubgroupCheck, IsEllipticCurve},
     },
     errors::DeserializationError,
-    field::element::FieldElement,
+    field::{element::FieldElement, traits::IsField},
     traits::{ByteConversion, Deserializable},
+    unsigned_integer::element::UnsignedInteger,
 };
 
 use super::traits::IsShortWeierstrass;
+#[cfg(feature = "alloc")]
+use super::traits::{CompressedFormat, HasCompressedFormat, IsCompressibleField};
 
 #[cfg(feature = "alloc")]
 use crate::traits::AsBytes;
+use crate::unsigned_integer::traits::IsUnsignedInteger;
+#[cfg(feature = "alloc")]
+use crate::{
+    elliptic_curve::{glv::IsGLVCurve, wnaf},
+    unsigned_integer::element::U256,
+};
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 #[derive(Clone, Debug)]
@@ -127,6 +136,110 @@ impl<E: IsShortWeierstrass> ShortWeierstrassProjectivePoint<E> {
 
         Self::new([x, y, z])
     }
+
+    /// Adds `other` with the complete formulas of https://eprint.iacr.org/2015/1060 (Algorithm 1),
+    /// which have no exceptional cases: doublings and the neutral element go through the same
+    /// field operations as any other pair of points.
+    pub fn operate_with_complete(&self, other: &Self) -> Self {
+        let [x1, y1, z1] = self.coordinates();
+        let [x2, y2, z2] = other.coordinates();
+        let a = E::a();
+        let b3 = E::b() * FieldElement::<E::BaseField>::from(3);
+
+        let t0 = x1 * x2;
+        let t1 = y1 * y2;
+        let t2 = z1 * z2;
+        let t3 = (x1 + y1) * (x2 + y2) - (&t0 + &t1);
+        let t4 = (x1 + z1) * (x2 + z2) - (&t0 + &t2);
+        let t5 = (y1 + z1) * (y2 + z2) - (&t1 + &t2);
+
+        let z3 = &a * &t4 + &b3 * &t2;
+        let x3 = &t1 - &z3;
+        let z3 = &t1 + z3;
+        let y3 = &x3 * &z3;
+
+        let t1 = &t0 + &t0 + &t0 + &a * &t2;
+        let t2 = &a * (&t0 - &a * &t2);
+        let t4 = &b3 * t4 + t2;
+
+        let y3 = y3 + &t1 * &t4;
+        let x3 = &t3 * x3 - &t5 * &t4;
+        let z3 = &t5 * z3 + t3 * t1;
+        Self::new([x3, y3, z3])
+    }
+
+    /// Multiplies the point by `exponent` with a Montgomery ladder over all its bits, using
+    /// [`Self::operate_with_complete`] and masked swaps, so that the sequence of operations doesn't
+    /// depend on the value of `exponent`. Use it with secret scalars, like signing keys and nonces.
+    ///
+    /// The running time also depends on the field arithmetic, which must be constant time too.
+    pub fn operate_with_self_constant_time<const NUM_LIMBS: usize, const M: usize>(
+        &self,
+        exponent: &UnsignedInteger<M>,
+    ) -> Self
+    where
+        E::BaseField: IsField<BaseType = UnsignedInteger<NUM_LIMBS>>,
+    {
+        let mut r0 = Self::neutral_element();
+        let mut r1 = self.clone();
+        for limb in exponent.limbs {
+            for i in (0..64).rev() {
+                let bit = (limb >> i) & 1;
+                Self::conditional_swap(&mut r0, &mut r1, bit);
+                r1 = r0.operate_with_complete(&r1);
+                r0 = r0.operate_with_complete(&r0);
+                Self::conditional_swap(&mut r0, &mut r1, bit);
+            }
+        }
+        r0
+    }
+
+    /// Multiplies the point by `exponent` with its width-4 non-adjacent form, which needs fewer
+    /// additions than [`IsGroup::operate_with_self`] but allocates a table of multiples and runs
+    /// in time that depends on `exponent`. Use it only with public scalars.
+    #[cfg(feature = "alloc")]
+    pub fn operate_with_self_wnaf<T: IsUnsignedInteger>(&self, exponent: T) -> Self {
+        wnaf::wnaf_operate_with_self(self, exponent)
+    }
+
+    /// Multiplies the point by `exponent` with the GLV method, see [`IsGLVCurve::glv_mul`].
+    /// The point must be in the main subgroup, and the running time depends on `exponent`.
+    #[cfg(feature = "alloc")]
+    pub fn operate_with_self_glv(&self, exponent: &U256) -> Self
+    where
+        E: IsGLVCurve<PointRepresentation = Self>,
+    {
+        E::glv_mul(self, exponent)
+    }
+
+    /// Swaps `a` and `b` when `choice` is 1 and leaves them as they are when it is 0,
+    /// without branching on `choice`.
+    fn conditional_swap<const NUM_LIMBS: usize>(a: &mut Self, b: &mut Self, choice: u64)
+    where
+        E::BaseField: IsField<BaseType = UnsignedInteger<NUM_LIMBS>>,
+    {
+        let mask = 0u64.wrapping_sub(choice);
+        let swap = |a: &FieldElement<E::BaseField>, b: &FieldElement<E::BaseField>| {
+            let mut a_limbs = a.value().limbs;
+            let mut b_limbs = b.value().limbs;
+            for (a_limb, b_limb) in a_limbs.iter_mut().zip(b_limbs.iter_mut()) {
+                let t = mask & (*a_limb ^ *b_limb);
+                *a_limb ^= t;
+                *b_limb ^= t;
+            }
+            (
+                FieldElement::from_raw(UnsignedInteger::from_limbs(a_limbs)),
+                FieldElement::from_raw(UnsignedInteger::from_limbs(b_limbs)),
+            )
+        };
+        let [ax, ay, az] = a.coordinates();
+        let [bx, by, bz] = b.coordinates();
+        let (ax, bx) = swap(ax, bx);
+        let (ay, by) = swap(ay, by);
+        let (az, bz) = swap(az, bz);
+        *a = Self::new([ax, ay, az]);
+        *b = Self::new([bx, by, bz]);
+    }
 }
 
 impl<E: IsEllipticCurve> PartialEq for ShortWeierstrassProjectivePoint<E> {
@@ -206,18 +319,38 @@ impl<E: IsShortWeierstrass> IsGroup for ShortWeierstrassProjectivePoint<E> {
         }
     }
 
+    fn double(&self) -> Self {
+        ShortWeierstrassProjectivePoint::double(self)
+    }
+
     /// Returns the additive inverse of the projective point `p`
     fn neg(&self) -> Self {
         let [px, py, pz] = self.coordinates();
         Self::new([px.clone(), -py, pz.clone()])
     }
+
+    /// Uses the MSM of the curve, see [`IsShortWeierstrass::pippenger_msm`].
+    #[cfg(feature = "alloc")]
+    fn pippenger_msm<const NUM_LIMBS: usize>(
+        cs: &[UnsignedInteger<NUM_LIMBS>],
+        points: &[Self],
+        window_size: usize,
+    ) -> Self {
+        E::pippenger_msm(cs, points, window_size)
+    }
+
+    /// Uses the scalar multiplication of the curve, see [`IsShortWeierstrass::operate_with_self`].
+    fn operate_with_self<T: IsUnsignedInteger>(&self, exponent: T) -> Self {
+        E::operate_with_self(self, exponent)
+    }
 }
 
+/// Compressed points are serialized with [`ShortWeierstrassProjectivePoint::serialize_compressed`],
+/// since decompressing them needs a square root in the base field.
 #[derive(PartialEq)]
 pub enum PointFormat {
     Projective,
     Uncompressed,
-    // Compressed,
 }
 
 #[derive(PartialEq)]
@@ -277,7 +410,15 @@ where
         }
         bytes
     }
+}
 
+impl<E> ShortWeierstrassProjectivePoint<E>
+where
+    E: IsShortWeierstrass + HasSubgroupCheck<PointRepresentation = Self>,
+    FieldElement<E::BaseField>: ByteConversion,
+{
+    /// Deserializes a point serialized with [`Self::serialize`], checking that it is on the
+    /// curve and in the subgroup of prime order.
     pub fn deserialize(
         bytes: &[u8],
         point_format: PointFormat,
@@ -312,7 +453,7 @@ where
                         Err(DeserializationError::FieldFromBytesError)
                     }
                 } else if E::defining_equation(&(&x / &z), &(&y / &z)) == FieldElement::zero() {
-                    Ok(Self::new([x, y, z]))
+                    Self::check_subgroup(Self::new([x, y, z]))
                 } else {
                     Err(DeserializationError::FieldFromBytesError)
                 }
@@ -335,13 +476,125 @@ where
                 }
 
                 if E::defining_equation(&x, &y) == FieldElement::zero() {
-                    Ok(Self::new([x, y, FieldElement::one()]))
+                    Self::check_subgroup(Self::new([x, y, FieldElement::one()]))
                 } else {
                     Err(DeserializationError::FieldFromBytesError)
                 }
             }
         }
     }
+
+    fn check_subgroup(point: Self) -> Result<Self, DeserializationError> {
+        if E::is_in_subgroup(&point) {
+            Ok(point)
+        } else {
+            Err(DeserializationError::PointNotInSubgroup)
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E> ShortWeierstrassProjectivePoint<E>
+where
+    E: HasCompressedFormat + HasSubgroupCheck<PointRepresentation = Self>,
+{
+    /// Serializes the point in the compressed format of its curve, see [`CompressedFormat`].
+    pub fn serialize_compressed(&self) -> Vec<u8> {
+        if self.is_neutral_element() {
+            let mut bytes = alloc::vec![0; Self::compressed_x_len()];
+            match E::COMPRESSED_FORMAT {
+                CompressedFormat::Sec1 => return alloc::vec![0],
+                CompressedFormat::Zcash => bytes[0] = 0b1100_0000,
+                CompressedFormat::Gnark => bytes[0] = 0b0100_0000,
+            }
+            return bytes;
+        }
+
+        let point = self.to_affine();
+        let mut bytes = E::BaseField::to_bytes_be(point.x());
+        let y_flag = E::BaseField::y_flag(point.y(), E::COMPRESSED_FORMAT);
+        match E::COMPRESSED_FORMAT {
+            CompressedFormat::Sec1 => bytes.insert(0, 0x02 | y_flag as u8),
+            CompressedFormat::Zcash => bytes[0] |= 0b1000_0000 | (y_flag as u8) << 5,
+            CompressedFormat::Gnark => bytes[0] |= 0b1000_0000 | (y_flag as u8) << 6,
+        }
+        bytes
+    }
+
+    /// Deserializes a point serialized with [`Self::serialize_compressed`], checking that the
+    /// encoding is canonical and that the point is on the curve and in the subgroup of prime
+    /// order.
+    pub fn deserialize_compressed(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let x_len = Self::compressed_x_len();
+        let (x_bytes, y_flag) = match E::COMPRESSED_FORMAT {
+            CompressedFormat::Sec1 => match (bytes.first(), bytes.len()) {
+                (Some(0x00), 1) => return Ok(Self::neutral_element()),
+                (Some(prefix @ (0x02 | 0x03)), len) if len == x_len + 1 => {
+                    (bytes[1..].to_vec(), *prefix == 0x03)
+                }
+                (Some(0x00 | 0x02 | 0x03), _) => {
+                    return Err(DeserializationError::InvalidAmountOfBytes)
+                }
+                _ => return Err(DeserializationError::InvalidValue),
+            },
+            CompressedFormat::Zcash | CompressedFormat::Gnark => {
+                if bytes.len() != x_len {
+                    return Err(DeserializationError::InvalidAmountOfBytes);
+                }
+                let (flag_bits, y_flag) = match (E::COMPRESSED_FORMAT, bytes[0] >> 5) {
+                    (CompressedFormat::Zcash, 0b110) | (CompressedFormat::Gnark, 0b010) => {
+                        return Self::neutral_element_if_empty(bytes, 0b1110_0000)
+                    }
+                    (CompressedFormat::Zcash, flags) if flags >> 1 == 0b10 => (3, flags & 1 == 1),
+                    (CompressedFormat::Gnark, flags) if flags >> 2 == 1 => (2, flags >> 1 & 1 == 1),
+                    _ => return Err(DeserializationError::InvalidValue),
+                };
+                let mut x_bytes = bytes.to_vec();
+                x_bytes[0] &= 0xff >> flag_bits;
+                (x_bytes, y_flag)
+            }
+        };
+
+        let x = E::BaseField::from_bytes_be(&x_bytes)?;
+        // Reject values of x that are not reduced modulo p
+        if E::BaseField::to_bytes_be(&x) != x_bytes {
+            return Err(DeserializationError::InvalidValue);
+        }
+
+        let y_squared = (x.square() + E::a()) * &x + E::b();
+        let y = E::BaseField::sqrt(&y_squared)
+            .filter(|y| y.square() == y_squared)
+            .ok_or(DeserializationError::InvalidValue)?;
+        let y_flag_of =
+            |y: &FieldElement<E::BaseField>| E::BaseField::y_flag(y, E::COMPRESSED_FORMAT);
+        let y = if y_flag_of(&y) == y_flag { y } else { -y };
+        if y_flag_of(&y) != y_flag {
+            // y is zero and its flag is set
+            return Err(DeserializationError::InvalidValue);
+        }
+        let point = Self::new([x, y, FieldElement::one()]);
+        if !E::is_in_subgroup(&point) {
+            return Err(DeserializationError::PointNotInSubgroup);
+        }
+        Ok(point)
+    }
+
+    /// Length of the big-endian encoding of `x`.
+    fn compressed_x_len() -> usize {
+        E::BaseField::to_bytes_be(&FieldElement::zero()).len()
+    }
+
+    /// Returns the point at infinity if only the flags given by `flags_mask` are set.
+    fn neutral_element_if_empty(
+        bytes: &[u8],
+        flags_mask: u8,
+    ) -> Result<Self, DeserializationError> {
+        if bytes[0] & !flags_mask == 0 && bytes[1..].iter().all(|byte| *byte == 0) {
+            Ok(Self::neutral_element())
+        } else {
+            Err(DeserializationError::InvalidValue)
+        }
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -368,7 +621,7 @@ where
 
 impl<E> Deserializable for ShortWeierstrassProjectivePoint<E>
 where
-    E: IsShortWeierstrass,
+    E: IsShortWeierstrass + HasSubgroupCheck<PointRepresentation = Self>,
     FieldElement<E::BaseField>: ByteConversion,
 {
     fn deserialize(bytes: &[u8]) -> Result<Self, DeserializationError>
@@ -489,6 +742,13 @@ impl<E: IsShortWeierstrass> ShortWeierstrassJacobianPoint<E> {
             Self::new([x3, y3, z3])
         }
     }
+
+    /// Multiplies the point by `exponent` with its width-4 non-adjacent form. Like
+    /// [`ShortWeierstrassProjectivePoint::operate_with_self_wnaf`], use it only with public scalars.
+    #[cfg(feature = "alloc")]
+    pub fn operate_with_self_wnaf<T: IsUnsignedInteger>(&self, exponent: T) -> Self {
+        wnaf::wnaf_operate_with_self(self, exponent)
+    }
 }
 
 impl<E: IsEllipticCurve> PartialEq for ShortWeierstrassJacobianPoint<E> {
@@ -587,6 +847,10 @@ impl<E: IsShortWeierstrass> IsGroup for ShortWeierstrassJacobianPoint<E> {
         Self::new([x3, y3, z3])
     }
 
+    fn double(&self) -> Self {
+        ShortWeierstrassJacobianPoint::double(self)
+    }
+
     /// Returns the additive inverse of the jacobian point `p`
     fn neg(&self) -> Self {
         let [x, y, z] = self.coordinates();
@@ -612,11 +876,34 @@ mod tests {
     #[allow(clippy::upper_case_acronyms)]
     type FEE = FieldElement<BLS12381PrimeField>;
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn wnaf_and_glv_multiplications_match_operate_with_self() {
+        let g = BLS12381Curve::generator();
+        let k = U256::from_hex_unchecked(
+            "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000",
+        );
+        let expected = g.operate_with_self(k);
+
+        assert_eq!(g.operate_with_self_wnaf(k), expected);
+        assert_eq!(g.operate_with_self_glv(&k), expected);
+
+        let g = ShortWeierstrassJacobianPoint::<BLS12381Curve>::from_affine(
+            g.x().clone(),
+            g.y().clone(),
+        )
+        .unwrap();
+        assert_eq!(g.operate_with_self_wnaf(k), g.operate_with_self(k));
+    }
+
     #[cfg(feature = "alloc")]
     fn point() -> ShortWeierstrassProjectivePoint<BLS12381Curve> {
         let x = FEE::new_base("36bb494facde72d0da5c770c4b16d9b2d45cfdc27604a25a1a80b020798e5b0dbd4c6d939a8f8820f042a29ce552ee5");
         let y = FEE::new_base("7acf6e49cc000ff53b06ee1d27056734019c0a1edfa16684da41ebb0c56750f73bc1b0eae4c6c241808a5e485af0ba0");
-        BLS12381Curve::create_point_from_affine(x, y).unwrap()
+        // Deserialization rejects points outside of G1
+        BLS12381Curve::create_point_from_affine(x, y)
+            .unwrap()
+            .clear_cofactor()
     }
 
     #[cfg(feature = "alloc")]
@@ -887,4 +1174,296 @@ mod tests {
             "Multiplication by order should result in the neutral element"
         );
     }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn complete_addition_matches_the_addition_in_every_case() {
+        let p = point();
+        let q = p.double().operate_with(&p);
+        let neutral = ShortWeierstrassProjectivePoint::<BLS12381Curve>::neutral_element();
+        let pairs = [
+            (p.clone(), q.clone()),
+            (p.clone(), p.clone()),
+            (p.clone(), p.neg()),
+            (p.clone(), neutral.clone()),
+            (neutral.clone(), q.clone()),
+            (neutral.clone(), neutral),
+        ];
+        for (a, b) in pairs {
+            assert_eq!(a.operate_with_complete(&b), a.operate_with(&b));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn complete_addition_works_on_curves_with_nonzero_a() {
+        use crate::elliptic_curve::short_weierstrass::curves::secp256r1::curve::Secp256r1Curve;
+
+        let g = Secp256r1Curve::generator();
+        let g3 = g.double().operate_with(&g);
+        assert_eq!(g.operate_with_complete(&g), g.double());
+        assert_eq!(g.operate_with_complete(&g3), g.double().double());
+        assert!(g.operate_with_complete(&g.neg()).is_neutral_element());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn constant_time_multiplication_matches_operate_with_self() {
+        use crate::unsigned_integer::element::U256;
+
+        let p = point();
+        let scalars = [
+            U256::from_u64(0),
+            U256::from_u64(1),
+            U256::from_u64(2),
+            U256::from_hex_unchecked(
+                "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000000",
+            ),
+            U256::from_hex_unchecked(
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            ),
+        ];
+        for k in scalars {
+            assert_eq!(
+                p.operate_with_self_constant_time(&k),
+                p.operate_with_self(k)
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn assert_compression_round_trips<E>()
+    where
+        E: HasCompressedFormat
+            + HasSubgroupCheck<PointRepresentation = ShortWeierstrassProjectivePoint<E>>,
+        FieldElement<E::BaseField>: ByteConversion,
+    {
+        let g = E::generator();
+        for point in [
+            ShortWeierstrassProjectivePoint::neutral_element(),
+            g.clone(),
+            g.neg(),
+            g.double(),
+            g.operate_with_self(5_u64).neg(),
+        ] {
+            let bytes = point.serialize_compressed();
+            assert_eq!(
+                ShortWeierstrassProjectivePoint::<E>::deserialize_compressed(&bytes).unwrap(),
+                point
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn compression_round_trips_on_every_curve() {
+        use crate::elliptic_curve::short_weierstrass::curves::{
+            bls12_377::curve::BLS12377Curve, bn_254::curve::BN254Curve,
+            grumpkin::curve::GrumpkinCurve, pallas::curve::PallasCurve,
+            secp256k1::curve::Secp256k1Curve, secp256r1::curve::Secp256r1Curve,
+            secq256k1::curve::Secq256k1Curve, stark_curve::StarkCurve, vesta::curve::VestaCurve,
+        };
+
+        assert_compression_round_trips::<BLS12381Curve>();
+        assert_compression_round_trips::<BLS12377Curve>();
+        assert_compression_round_trips::<BN254Curve>();
+        assert_compression_round_trips::<GrumpkinCurve>();
+        assert_compression_round_trips::<PallasCurve>();
+        assert_compression_round_trips::<VestaCurve>();
+        assert_compression_round_trips::<Secp256k1Curve>();
+        assert_compression_round_trips::<Secp256r1Curve>();
+        assert_compression_round_trips::<Secq256k1Curve>();
+        assert_compression_round_trips::<StarkCurve>();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn compression_round_trips_on_g2() {
+        use crate::elliptic_curve::short_weierstrass::curves::{
+            bls12_381::twist::BLS12381TwistCurve, bn_254::twist::BN254TwistCurve,
+        };
+
+        assert_compression_round_trips::<BLS12381TwistCurve>();
+        assert_compression_round_trips::<BN254TwistCurve>();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn g2_compression_matches_the_compress_trait() {
+        use crate::elliptic_curve::short_weierstrass::{
+            curves::{
+                bls12_381::twist::BLS12381TwistCurve,
+                bn_254::{curve::BN254Curve, twist::BN254TwistCurve},
+            },
+            traits::Compress,
+        };
+
+        let g = BLS12381TwistCurve::generator();
+        for point in [
+            g.clone(),
+            g.neg(),
+            g.operate_with_self(3_u64),
+            ShortWeierstrassProjectivePoint::neutral_element(),
+        ] {
+            let bytes = point.serialize_compressed();
+            assert_eq!(bytes, BLS12381Curve::compress_g2_point(&point));
+            assert_eq!(
+                BLS12381Curve::decompress_g2_point(&mut bytes.clone()).unwrap(),
+                point
+            );
+        }
+        let g = BN254TwistCurve::generator();
+        for point in [
+            g.clone(),
+            g.neg(),
+            g.operate_with_self(3_u64),
+            ShortWeierstrassProjectivePoint::neutral_element(),
+        ] {
+            let bytes = point.serialize_compressed();
+            assert_eq!(bytes, BN254Curve::compress_g2_point(&point));
+            assert_eq!(
+                BN254Curve::decompress_g2_point(&mut bytes.clone()).unwrap(),
+                point
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn sec1_compression_of_the_secp256k1_generator() {
+        use crate::elliptic_curve::short_weierstrass::curves::secp256k1::curve::Secp256k1Curve;
+        use crate::unsigned_integer::element::U256;
+
+        let g = Secp256k1Curve::generator();
+        let mut expected = U256::from_hex_unchecked(
+            "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        )
+        .to_bytes_be();
+        expected.insert(0, 0x02);
+        assert_eq!(g.serialize_compressed(), expected);
+        expected[0] = 0x03;
+        assert_eq!(g.neg().serialize_compressed(), expected);
+        assert_eq!(
+            ShortWeierstrassProjectivePoint::<Secp256k1Curve>::neutral_element()
+                .serialize_compressed(),
+            [0]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn flagged_compression_matches_the_compress_trait() {
+        use crate::elliptic_curve::short_weierstrass::{
+            curves::bn_254::curve::BN254Curve, traits::Compress,
+        };
+
+        let g = BLS12381Curve::generator();
+        for point in [
+            g.clone(),
+            g.neg(),
+            ShortWeierstrassProjectivePoint::neutral_element(),
+        ] {
+            assert_eq!(
+                point.serialize_compressed(),
+                BLS12381Curve::compress_g1_point(&point)
+            );
+        }
+        let g = BN254Curve::generator();
+        for point in [
+            g.clone(),
+            g.neg(),
+            ShortWeierstrassProjectivePoint::neutral_element(),
+        ] {
+            assert_eq!(
+                point.serialize_compressed(),
+                BN254Curve::compress_g1_point(&point)
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn deserialization_rejects_points_outside_the_subgroup() {
+        // (0, 2) is on the curve, but not in G1
+        let point = BLS12381Curve::create_point_from_affine(FEE::zero(), FEE::from(2)).unwrap();
+        for (format, same_format) in [
+            (PointFormat::Projective, PointFormat::Projective),
+            (PointFormat::Uncompressed, PointFormat::Uncompressed),
+        ] {
+            let bytes = point.serialize(same_format, Endianness::BigEndian);
+            assert_eq!(
+                ShortWeierstrassProjectivePoint::<BLS12381Curve>::deserialize(
+                    &bytes,
+                    format,
+                    Endianness::BigEndian
+                )
+                .unwrap_err(),
+                DeserializationError::PointNotInSubgroup
+            );
+        }
+        assert_eq!(
+            ShortWeierstrassProjectivePoint::<BLS12381Curve>::deserialize_compressed(
+                &point.serialize_compressed()
+            )
+            .unwrap_err(),
+            DeserializationError::PointNotInSubgroup
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn deserialize_compressed_rejects_invalid_encodings() {
+        use crate::elliptic_curve::short_weierstrass::curves::secp256k1::curve::Secp256k1Curve;
+        use crate::unsigned_integer::element::U256;
+        type Secp256k1Point = ShortWeierstrassProjectivePoint<Secp256k1Curve>;
+        type G1Point = ShortWeierstrassProjectivePoint<BLS12381Curve>;
+
+        let g = Secp256k1Curve::generator().serialize_compressed();
+        assert_eq!(
+            Secp256k1Point::deserialize_compressed(&g[..32]).unwrap_err(),
+            DeserializationError::InvalidAmountOfBytes
+        );
+        assert_eq!(
+            Secp256k1Point::deserialize_compressed(&[0; 33]).unwrap_err(),
+            DeserializationError::InvalidAmountOfBytes
+        );
+        let mut bytes = g.clone();
+        bytes[0] = 0x04;
+        assert_eq!(
+            Secp256k1Point::deserialize_compressed(&bytes).unwrap_err(),
+            DeserializationError::InvalidValue
+        );
+        // x = p is not reduced
+        let mut bytes = U256::from_hex_unchecked(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+        )
+        .to_bytes_be();
+        bytes.insert(0, 0x02);
+        assert_eq!(
+            Secp256k1Point::deserialize_compressed(&bytes).unwrap_err(),
+            DeserializationError::InvalidValue
+        );
+        // x = 5 is not the x coordinate of any point
+        let mut bytes = U256::from_u64(5).to_bytes_be();
+        bytes.insert(0, 0x02);
+        assert_eq!(
+            Secp256k1Point::deserialize_compressed(&bytes).unwrap_err(),
+            DeserializationError::InvalidValue
+        );
+
+        let g = BLS12381Curve::generator().serialize_compressed();
+        let mut uncompressed = g.clone();
+        uncompressed[0] &= 0x7f;
+        assert!(G1Point::deserialize_compressed(&uncompressed).is_err());
+        let mut infinity = [0; 48];
+        infinity[0] = 0b1110_0000;
+        assert!(G1Point::deserialize_compressed(&infinity).is_err());
+        infinity[0] = 0b1100_0000;
+        infinity[47] = 1;
+        assert!(G1Point::deserialize_compressed(&infinity).is_err());
+        infinity[47] = 0;
+        assert!(G1Point::deserialize_compressed(&infinity)
+            .unwrap()
+            .is_neutral_element());
+    }
 }
@@ -1,6 +1,15 @@
-use crate::cyclic_group::IsGroup;
+use crate::cyclic_group::{double_and_add, IsGroup};
+use crate::elliptic_curve::short_weierstrass::point::ShortWeierstrassProjectivePoint;
 use crate::elliptic_curve::traits::IsEllipticCurve;
+use crate::errors::ByteConversionError;
 use crate::field::element::FieldElement;
+use crate::field::traits::{IsField, IsPrimeField};
+use crate::traits::ByteConversion;
+use crate::unsigned_integer::traits::IsUnsignedInteger;
+#[cfg(feature = "alloc")]
+use crate::{msm::pippenger, unsigned_integer::element::UnsignedInteger};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::fmt::Debug;
 
 /// Trait to add elliptic curves behaviour to a struct.
@@ -18,6 +27,121 @@ pub trait IsShortWeierstrass: IsEllipticCurve + Clone + Debug {
     ) -> FieldElement<Self::BaseField> {
         y.square() - ((x.square() + Self::a()) * x + Self::b())
     }
+
+    /// Computes the multiscalar multiplication of points of the curve with Pippenger's algorithm,
+    /// with windows of `window_size` bits. Curves of prime order with an efficient endomorphism
+    /// override it with [`pippenger::glv_short_weierstrass_msm`].
+    #[cfg(feature = "alloc")]
+    fn pippenger_msm<const NUM_LIMBS: usize>(
+        cs: &[UnsignedInteger<NUM_LIMBS>],
+        points: &[ShortWeierstrassProjectivePoint<Self>],
+        window_size: usize,
+    ) -> ShortWeierstrassProjectivePoint<Self> {
+        pippenger::short_weierstrass_msm(cs, points, window_size)
+    }
+
+    /// Multiplies a point of the curve by `exponent` with double-and-add. Curves of prime order
+    /// with an efficient endomorphism override it with [`glv_operate_with_self`].
+    ///
+    /// [`glv_operate_with_self`]: crate::elliptic_curve::glv::glv_operate_with_self
+    fn operate_with_self<T: IsUnsignedInteger>(
+        p: &ShortWeierstrassProjectivePoint<Self>,
+        exponent: T,
+    ) -> ShortWeierstrassProjectivePoint<Self> {
+        double_and_add(p, exponent)
+    }
+}
+
+/// Layouts of the compressed encoding of a point, which is its `x` coordinate in big-endian
+/// followed or prefixed by the bits needed to recover `y`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompressedFormat {
+    /// SEC 1, Section 2.3.3: a byte `0x02` or `0x03` with the parity of `y` followed by `x`.
+    /// The point at infinity is the single byte `0x00`.
+    Sec1,
+    /// Zcash: the three most significant bits of `x` flag that the point is compressed,
+    /// that it is the point at infinity and that `y` is larger than `-y`.
+    /// The field needs at least three spare bits in its encoding.
+    Zcash,
+    /// gnark: the two most significant bits of `x` are `10` if `y` is smaller than `-y`,
+    /// `11` if it is larger and `01` for the point at infinity.
+    Gnark,
+}
+
+/// Curves whose points can be serialized in compressed form with
+/// [`ShortWeierstrassProjectivePoint::serialize_compressed`](super::point::ShortWeierstrassProjectivePoint::serialize_compressed).
+/// These are the curves over prime fields, and the twists of BLS12-377, BLS12-381 and BN254,
+/// whose points form G2 in their pairings.
+pub trait HasCompressedFormat: IsShortWeierstrass<BaseField: IsCompressibleField> {
+    const COMPRESSED_FORMAT: CompressedFormat;
+}
+
+/// Fields of the coordinates of the points with a compressed format. Elements of prime fields
+/// are encoded as their representative in big-endian. Elements `c0 + c1 * u` of the quadratic
+/// extensions of BLS12-377, BLS12-381 and BN254 are encoded as `c1` followed by `c0`, as in Zcash
+/// and gnark.
+pub trait IsCompressibleField: IsField {
+    /// Returns the big-endian encoding of `x`.
+    #[cfg(feature = "alloc")]
+    fn to_bytes_be(x: &FieldElement<Self>) -> Vec<u8>;
+
+    /// Reads an element encoded with [`Self::to_bytes_be`]. It may accept non canonical
+    /// encodings, which the caller has to reject.
+    fn from_bytes_be(bytes: &[u8]) -> Result<FieldElement<Self>, ByteConversionError>;
+
+    /// Returns a square root of `x`, if there is one.
+    fn sqrt(x: &FieldElement<Self>) -> Option<FieldElement<Self>>;
+
+    /// Returns the bit that tells `y` from `-y` in the encodings of `format`: the parity of `y`
+    /// for SEC 1, and whether `y` is larger than `-y` for the other formats.
+    fn y_flag(y: &FieldElement<Self>, format: CompressedFormat) -> bool;
+}
+
+impl<F> IsCompressibleField for F
+where
+    F: IsPrimeField,
+    FieldElement<F>: ByteConversion,
+{
+    #[cfg(feature = "alloc")]
+    fn to_bytes_be(x: &FieldElement<F>) -> Vec<u8> {
+        x.to_bytes_be()
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<FieldElement<F>, ByteConversionError> {
+        FieldElement::from_bytes_be(bytes)
+    }
+
+    fn sqrt(x: &FieldElement<F>) -> Option<FieldElement<F>> {
+        x.sqrt().map(|(y, _)| y)
+    }
+
+    fn y_flag(y: &FieldElement<F>, format: CompressedFormat) -> bool {
+        let y = y.representative();
+        let one = F::RepresentativeType::from(1);
+        match format {
+            CompressedFormat::Sec1 => y & one == one,
+            CompressedFormat::Zcash | CompressedFormat::Gnark => y > (F::modulus_minus_one() >> 1),
+        }
+    }
+}
+
+/// Implementation of [`IsCompressibleField::y_flag`] for an element `c0 + c1 * u` of a quadratic
+/// extension. It is the flag of the first nonzero coefficient: Zcash looks at `c1` first,
+/// while gnark and SEC 1, with the sign of RFC 9380 (Section 4.1), look at `c0` first.
+pub(crate) fn quadratic_y_flag<F>(y: &[FieldElement<F>; 2], format: CompressedFormat) -> bool
+where
+    F: IsCompressibleField,
+{
+    let [c0, c1] = y;
+    let (first, second) = match format {
+        CompressedFormat::Zcash => (c1, c0),
+        CompressedFormat::Sec1 | CompressedFormat::Gnark => (c0, c1),
+    };
+    if *first == FieldElement::zero() {
+        F::y_flag(second, format)
+    } else {
+        F::y_flag(first, format)
+    }
 }
 
 pub trait Compress {
@@ -2,6 +2,7 @@ use crate::{
     cyclic_group::IsGroup,
     errors::PairingError,
     field::{element::FieldElement, traits::IsField},
+    unsigned_integer::traits::IsUnsignedInteger,
 };
 use core::fmt::Debug;
 
@@ -32,6 +33,32 @@ pub trait IsEllipticCurve {
     }
 }
 
+/// Curves whose points of interest form a subgroup of prime order `r` of the group of points.
+/// Points read from untrusted sources must be checked to be in this subgroup, as points with
+/// a component of small order break the security of the protocols built on the curve.
+///
+/// On curves of prime order every point is in the subgroup, so their `is_in_subgroup` returns
+/// `true` and their `clear_cofactor` returns the point unchanged.
+pub trait HasSubgroupCheck: IsEllipticCurve {
+    /// Unsigned integer type that holds the order of the subgroup.
+    type OrderType: IsUnsignedInteger;
+
+    /// The order `r` of the subgroup.
+    const SUBGROUP_ORDER: Self::OrderType;
+
+    /// Returns whether `p` is in the subgroup of order `r`. The default implementation checks
+    /// that `r * p` is the neutral element. Curves with an efficient endomorphism override it
+    /// with a faster check.
+    fn is_in_subgroup(p: &Self::PointRepresentation) -> bool {
+        p.operate_with_self(Self::SUBGROUP_ORDER)
+            .is_neutral_element()
+    }
+
+    /// Maps `p` to the subgroup of order `r`, multiplying it by the cofactor or by a multiple
+    /// of it that is cheaper to compute.
+    fn clear_cofactor(p: &Self::PointRepresentation) -> Self::PointRepresentation;
+}
+
 pub trait FromAffine<F: IsField>: Sized {
     fn from_affine(x: FieldElement<F>, y: FieldElement<F>) -> Result<Self, EllipticCurveError>;
 }
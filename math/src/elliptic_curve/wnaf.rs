@@ -0,0 +1,150 @@
+use crate::{cyclic_group::IsGroup, unsigned_integer::traits::IsUnsignedInteger};
+use alloc::{vec, vec::Vec};
+
+/// Width of the NAF used by scalar multiplications, so that the odd multiples
+/// `P, 3P, 5P, 7P` are precomputed.
+pub const WNAF_WIDTH: usize = 4;
+
+/// Returns the width-`width` non-adjacent form of `k`, least significant digit first.
+///
+/// Every digit is either zero or odd with absolute value less than `2^(width - 1)`, and among
+/// any `width` consecutive digits at most one is non-zero. On average only one in `width + 1`
+/// digits is non-zero, while in the binary expansion it is one in two.
+pub fn wnaf<T: IsUnsignedInteger>(mut k: T, width: usize) -> Vec<i32> {
+    debug_assert!((2..16).contains(&width));
+    let zero = T::from(0);
+    let one = T::from(1);
+
+    let mut digits = Vec::new();
+    while k != zero {
+        if k & one == one {
+            let window = (0..width)
+                .filter(|&i| (k >> i) & one == one)
+                .fold(0_i32, |window, i| window | (1 << i));
+            k >>= width;
+            // Negative digits leave a carry, as `k - digit` is `k + 2^width - window`.
+            if window >= 1 << (width - 1) {
+                digits.push(window - (1 << width));
+                k = k + one;
+            } else {
+                digits.push(window);
+            }
+            digits.extend(core::iter::repeat_n(0, width - 1));
+        } else {
+            digits.push(0);
+            k >>= 1;
+        }
+    }
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    digits
+}
+
+/// Returns `k_1 * g_1 + ... + k_n * g_n`, where `nafs[i]` is the non-adjacent form of `k_i`
+/// as returned by [`wnaf`]. All the terms share the same doublings.
+pub fn interleaved_wnaf<G: IsGroup>(bases: &[G], nafs: &[Vec<i32>]) -> G {
+    debug_assert_eq!(bases.len(), nafs.len());
+    let tables: Vec<_> = bases
+        .iter()
+        .zip(nafs)
+        .map(|(g, naf)| odd_multiples(g, naf.iter().map(|d| d.unsigned_abs()).max()))
+        .collect();
+    let len = nafs.iter().map(Vec::len).max().unwrap_or(0);
+
+    (0..len).rev().fold(G::neutral_element(), |acc, i| {
+        tables
+            .iter()
+            .zip(nafs)
+            .fold(acc.double(), |acc, (table, naf)| match naf.get(i) {
+                Some(&digit) if digit > 0 => acc.operate_with(&table[digit as usize / 2]),
+                Some(&digit) if digit < 0 => acc.operate_with(&table[-digit as usize / 2].neg()),
+                _ => acc,
+            })
+    })
+}
+
+/// Returns `[g, 3g, 5g, ..., max_digit * g]`.
+fn odd_multiples<G: IsGroup>(g: &G, max_digit: Option<u32>) -> Vec<G> {
+    let Some(max_digit) = max_digit.filter(|&digit| digit > 0) else {
+        return vec![];
+    };
+    let double = g.double();
+    let mut table = Vec::with_capacity(max_digit as usize / 2 + 1);
+    table.push(g.clone());
+    for i in 1..=max_digit as usize / 2 {
+        table.push(table[i - 1].operate_with(&double));
+    }
+    table
+}
+
+/// Returns `k * g` using the width-[`WNAF_WIDTH`] non-adjacent form of `k`.
+pub fn wnaf_operate_with_self<G: IsGroup, T: IsUnsignedInteger>(g: &G, k: T) -> G {
+    interleaved_wnaf(core::slice::from_ref(g), &[wnaf(k, WNAF_WIDTH)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        elliptic_curve::{
+            short_weierstrass::curves::bls12_381::curve::BLS12381Curve, traits::IsEllipticCurve,
+        },
+        unsigned_integer::element::U256,
+    };
+
+    fn evaluate(digits: &[i32]) -> i128 {
+        digits
+            .iter()
+            .rev()
+            .fold(0, |acc, &digit| 2 * acc + digit as i128)
+    }
+
+    #[test]
+    fn wnaf_digits_represent_the_scalar() {
+        for k in (0..2000_u64).chain([u64::MAX, u64::MAX - 1, 1 << 63]) {
+            for width in 2..8 {
+                let digits = wnaf(k, width);
+                assert_eq!(evaluate(&digits), k as i128);
+                for (i, &digit) in digits.iter().enumerate() {
+                    assert!(digit == 0 || (digit % 2 != 0 && digit.abs() < 1 << (width - 1)));
+                    if digit != 0 {
+                        let next = &digits[i + 1..(i + width).min(digits.len())];
+                        assert!(next.iter().all(|&d| d == 0));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn wnaf_operate_with_self_matches_double_and_add() {
+        let g = BLS12381Curve::generator();
+        let k = U256::from_hex_unchecked(
+            "5b9a7bf2c3d4e2f1a0b9c8d7e6f5a4b3c2d1e0f9a8b7c6d5e4f3a2b1c0d9e8f7",
+        );
+        let expected = (0..256).rev().fold(
+            <BLS12381Curve as IsEllipticCurve>::PointRepresentation::neutral_element(),
+            |acc, i| {
+                let acc = acc.operate_with(&acc);
+                if (k >> i).limbs[3] & 1 == 1 {
+                    acc.operate_with(&g)
+                } else {
+                    acc
+                }
+            },
+        );
+
+        assert_eq!(wnaf_operate_with_self(&g, k), expected);
+        assert_eq!(wnaf_operate_with_self(&g, 0_u64), g.operate_with(&g.neg()));
+    }
+
+    #[test]
+    fn interleaved_wnaf_adds_the_terms() {
+        let g = BLS12381Curve::generator();
+        let h = g.operate_with_self(1234_u64);
+        let result = interleaved_wnaf(&[g.clone(), h], &[wnaf(77_u64, 4), wnaf(1000_u64, 5)]);
+
+        assert_eq!(result, g.operate_with_self(77_u64 + 1234 * 1000));
+    }
+}
@@ -21,6 +21,7 @@ pub enum DeserializationError {
     FieldFromBytesError,
     PointerSizeError,
     InvalidValue,
+    PointNotInSubgroup,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -34,6 +35,7 @@ impl From<ByteConversionError> for DeserializationError {
         match error {
             ByteConversionError::FromBEBytesError => DeserializationError::FieldFromBytesError,
             ByteConversionError::FromLEBytesError => DeserializationError::FieldFromBytesError,
+            ByteConversionError::PointNotInSubgroup => DeserializationError::PointNotInSubgroup,
             _ => DeserializationError::InvalidValue,
         }
     }
@@ -35,19 +35,12 @@ where
         #[allow(clippy::needless_range_loop)] // the suggestion would obfuscate a bit the algorithm
         for group in 0..group_count {
             let first_in_group = group * group_size;
-            let first_in_next_group = first_in_group + group_size / 2;
 
             let w = &twiddles[group]; // a twiddle factor is used per group
 
-            for i in first_in_group..first_in_next_group {
-                let wi = w * &input[i + group_size / 2];
-
-                let y0 = &input[i] + &wi;
-                let y1 = &input[i] - &wi;
-
-                input[i] = y0;
-                input[i + group_size / 2] = y1;
-            }
+            let (top, bottom) =
+                input[first_in_group..first_in_group + group_size].split_at_mut(group_size / 2);
+            E::fft_butterflies(top, bottom, w);
         }
         group_count *= 2;
         group_size /= 2;
@@ -177,6 +170,139 @@ where
     }
 }
 
+/// Number of consecutive blocks processed together by the fused radix kernels.
+const FUSED_BLOCKS_CHUNK: usize = 32;
+
+/// In-Place Radix-8 NR DIT FFT algorithm over a slice of two-adic field elements.
+/// It's required that the twiddle factors are in bit-reverse order. Else this function will not
+/// return fourier transformed values.
+/// Also the input size needs to be a power of two.
+/// It's recommended to use the current safe abstractions instead of this function.
+///
+/// Each pass loads 8 elements and applies three radix-2 stages to them while they are hot in
+/// cache, so the input is traversed a third of the times the radix-2 version does. If the
+/// number of stages isn't a multiple of three, the first pass uses a smaller radix.
+pub fn in_place_nr_8radix_fft<F, E>(input: &mut [FieldElement<E>], twiddles: &[FieldElement<F>])
+where
+    F: IsFFTField + IsSubFieldOf<E>,
+    E: IsField,
+{
+    in_place_nr_mixed_radix_fft(input, twiddles, 3);
+}
+
+/// In-Place Radix-16 NR DIT FFT algorithm over a slice of two-adic field elements.
+/// It's required that the twiddle factors are in bit-reverse order. Else this function will not
+/// return fourier transformed values.
+/// Also the input size needs to be a power of two.
+/// It's recommended to use the current safe abstractions instead of this function.
+///
+/// Same as [`in_place_nr_8radix_fft`], but four radix-2 stages are fused in each pass.
+pub fn in_place_nr_16radix_fft<F, E>(input: &mut [FieldElement<E>], twiddles: &[FieldElement<F>])
+where
+    F: IsFFTField + IsSubFieldOf<E>,
+    E: IsField,
+{
+    in_place_nr_mixed_radix_fft(input, twiddles, 4);
+}
+
+/// Runs the NR DIT FFT fusing `log_radix` stages per pass. The first pass takes the remaining
+/// `log2(n) % log_radix` stages, so any power of two size is supported.
+fn in_place_nr_mixed_radix_fft<F, E>(
+    input: &mut [FieldElement<E>],
+    twiddles: &[FieldElement<F>],
+    log_radix: u32,
+) where
+    F: IsFFTField + IsSubFieldOf<E>,
+    E: IsField,
+{
+    debug_assert!(input.len().is_power_of_two());
+
+    let log_n = input.len().trailing_zeros();
+    let mut group_count = 1;
+    let mut group_size = input.len();
+
+    let first_pass = log_n % log_radix;
+    if first_pass != 0 {
+        nr_fused_stages(input, twiddles, group_count, group_size, first_pass);
+        group_count <<= first_pass;
+        group_size >>= first_pass;
+    }
+    while group_count < input.len() {
+        nr_fused_stages(input, twiddles, group_count, group_size, log_radix);
+        group_count <<= log_radix;
+        group_size >>= log_radix;
+    }
+}
+
+/// Applies `log_radix` consecutive radix-2 NR DIT stages, starting from the stage that has
+/// `group_count` groups of `group_size` elements.
+fn nr_fused_stages<F, E>(
+    input: &mut [FieldElement<E>],
+    twiddles: &[FieldElement<F>],
+    group_count: usize,
+    group_size: usize,
+    log_radix: u32,
+) where
+    F: IsFFTField + IsSubFieldOf<E>,
+    E: IsField,
+{
+    match log_radix {
+        1 => nr_fused_stages_with_radix::<F, E, 2>(input, twiddles, group_count, group_size),
+        2 => nr_fused_stages_with_radix::<F, E, 4>(input, twiddles, group_count, group_size),
+        3 => nr_fused_stages_with_radix::<F, E, 8>(input, twiddles, group_count, group_size),
+        4 => nr_fused_stages_with_radix::<F, E, 16>(input, twiddles, group_count, group_size),
+        _ => unreachable!("radixes bigger than 16 are not supported"),
+    }
+}
+
+fn nr_fused_stages_with_radix<F, E, const RADIX: usize>(
+    input: &mut [FieldElement<E>],
+    twiddles: &[FieldElement<F>],
+    group_count: usize,
+    group_size: usize,
+) where
+    F: IsFFTField + IsSubFieldOf<E>,
+    E: IsField,
+{
+    // Distance between the elements of the same block.
+    let stride = group_size / RADIX;
+
+    for group in 0..group_count {
+        let first_in_group = group * group_size;
+
+        // The blocks are made of the elements `i + k * stride` for `k` in `0..RADIX`. Consecutive
+        // blocks are processed together so the innermost loop runs over contiguous elements.
+        for first_block in (0..stride).step_by(FUSED_BLOCKS_CHUNK) {
+            let blocks = FUSED_BLOCKS_CHUNK.min(stride - first_block);
+            let first_in_chunk = first_in_group + first_block;
+
+            // Within a block, the stage `l` splits it in `2^l` sub-groups, and the
+            // sub-group `b` uses the twiddle of the group `group * 2^l + b` of that stage.
+            let mut half = RADIX / 2;
+            let mut first_twiddle = group;
+            let mut sub_groups = 1;
+            while half > 0 {
+                for sub_group in 0..sub_groups {
+                    let w = &twiddles[first_twiddle + sub_group];
+                    let start = sub_group * 2 * half;
+
+                    for k in start..start + half {
+                        let (top, bottom) =
+                            input.split_at_mut(first_in_chunk + (k + half) * stride);
+                        let top = &mut top[first_in_chunk + k * stride..][..blocks];
+                        let bottom = &mut bottom[..blocks];
+
+                        E::fft_butterflies(top, bottom, w);
+                    }
+                }
+                half /= 2;
+                sub_groups *= 2;
+                first_twiddle *= 2;
+            }
+        }
+    }
+}
+
 #[cfg(all(test, feature = "alloc"))]
 mod tests {
     use crate::fft::cpu::bit_reversing::in_place_bit_reverse_permute;
@@ -206,6 +332,11 @@ mod tests {
             vec
         }
     }
+    prop_compose! {
+        fn field_vec_up_to(max_exp: u8)(vec in (0..max_exp).prop_flat_map(|i| collection::vec(field_element(), 1 << i))) -> alloc::vec::Vec<FE> {
+            vec
+        }
+    }
     prop_compose! {
         fn field_vec_r4(max_exp: u8)(vec in (1..max_exp).prop_flat_map(|i| collection::vec(field_element(), 1 << (2 * i)))) -> alloc::vec::Vec<FE> {
             vec
@@ -257,5 +388,35 @@ mod tests {
 
             prop_assert_eq!(expected, result);
         }
+
+        // Property-based test that ensures NR Radix-8 FFT gives the same result as a naive DFT.
+        #[test]
+        fn test_nr_8radix_fft_matches_naive_eval(coeffs in field_vec_up_to(10)) {
+            let expected = naive_matrix_dft_test(&coeffs);
+
+            let order = coeffs.len().trailing_zeros();
+            let twiddles = get_twiddles(order.into(), RootsConfig::BitReverse).unwrap();
+
+            let mut result = coeffs;
+            in_place_nr_8radix_fft::<F, F>(&mut result, &twiddles);
+            in_place_bit_reverse_permute(&mut result);
+
+            prop_assert_eq!(expected, result);
+        }
+
+        // Property-based test that ensures NR Radix-16 FFT gives the same result as a naive DFT.
+        #[test]
+        fn test_nr_16radix_fft_matches_naive_eval(coeffs in field_vec_up_to(10)) {
+            let expected = naive_matrix_dft_test(&coeffs);
+
+            let order = coeffs.len().trailing_zeros();
+            let twiddles = get_twiddles(order.into(), RootsConfig::BitReverse).unwrap();
+
+            let mut result = coeffs;
+            in_place_nr_16radix_fft::<F, F>(&mut result, &twiddles);
+            in_place_bit_reverse_permute(&mut result);
+
+            prop_assert_eq!(expected, result);
+        }
     }
 }
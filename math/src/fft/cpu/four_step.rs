@@ -0,0 +1,211 @@
+use crate::field::{
+    element::FieldElement,
+    traits::{IsFFTField, IsField, IsSubFieldOf},
+};
+use alloc::vec::Vec;
+
+use super::{bit_reversing::in_place_bit_reverse_permute, fft::in_place_nr_2radix_fft};
+
+/// Side of the square tiles used when transposing, chosen so a tile of both the source and the
+/// destination fit in the L1 cache.
+const TRANSPOSE_TILE_SIZE: usize = 16;
+
+/// Four-step (Bailey) FFT over a slice of two-adic field elements.
+/// It's required that the twiddle factors are in bit-reverse order, as in the NR algorithms, and
+/// the input size needs to be a power of two. Both the input and the output are naturally ordered.
+///
+/// The input of size `n = n1 * n2` is seen as a `n1 x n2` matrix and the transform is computed as:
+/// 1. `n2` FFTs of size `n1` over the columns,
+/// 2. a multiplication of the element `(j2, k1)` by `w^(j2 * k1)`, where `w` is the `n`-th root of unity,
+/// 3. `n1` FFTs of size `n2` over the rows,
+/// 4. a transposition to get the natural order.
+///
+/// The small FFTs work on contiguous rows that fit in cache, which makes this faster than the
+/// radix-2 algorithm for big inputs. The twiddles of the small FFTs are prefixes of `twiddles`.
+pub fn four_step_fft<F, E>(
+    input: &[FieldElement<E>],
+    twiddles: &[FieldElement<F>],
+) -> Vec<FieldElement<E>>
+where
+    F: IsFFTField + IsSubFieldOf<E>,
+    E: IsField,
+{
+    debug_assert!(input.len().is_power_of_two());
+    let (n1, n2) = split_size(input.len());
+    if n1 == 1 {
+        let mut result = input.to_vec();
+        in_place_ordered_fft(&mut result, twiddles);
+        return result;
+    }
+
+    // Step 1: the columns become the rows of the transposed matrix.
+    let mut columns = transpose(input, n1, n2);
+    for row in columns.chunks_mut(n1) {
+        in_place_ordered_fft(row, &twiddles[..n1 / 2]);
+    }
+
+    // Step 2
+    let w = &twiddles[input.len() / 4];
+    let mut row_base = FieldElement::<F>::one();
+    for row in columns.chunks_mut(n1) {
+        multiply_by_powers(row, &row_base);
+        row_base = &row_base * w;
+    }
+
+    // Step 3
+    let mut rows = transpose(&columns, n2, n1);
+    for row in rows.chunks_mut(n2) {
+        in_place_ordered_fft(row, &twiddles[..n2 / 2]);
+    }
+
+    // Step 4
+    transpose(&rows, n1, n2)
+}
+
+/// Same as [`four_step_fft`], but the small FFTs and the twiddle multiplications of each step
+/// are done in parallel over the rows.
+#[cfg(feature = "parallel")]
+pub fn parallel_four_step_fft<F, E>(
+    input: &[FieldElement<E>],
+    twiddles: &[FieldElement<F>],
+) -> Vec<FieldElement<E>>
+where
+    F: IsFFTField + IsSubFieldOf<E>,
+    E: IsField,
+    FieldElement<F>: Send + Sync,
+    FieldElement<E>: Send + Sync,
+{
+    use rayon::prelude::*;
+
+    debug_assert!(input.len().is_power_of_two());
+    let (n1, n2) = split_size(input.len());
+    if n1 == 1 {
+        let mut result = input.to_vec();
+        in_place_ordered_fft(&mut result, twiddles);
+        return result;
+    }
+
+    let mut columns = transpose(input, n1, n2);
+    columns.par_chunks_mut(n1).for_each(|row| {
+        in_place_ordered_fft(row, &twiddles[..n1 / 2]);
+    });
+
+    let w = &twiddles[input.len() / 4];
+    columns
+        .par_chunks_mut(n1)
+        .enumerate()
+        .for_each(|(j2, row)| multiply_by_powers(row, &w.pow(j2)));
+
+    let mut rows = transpose(&columns, n2, n1);
+    rows.par_chunks_mut(n2).for_each(|row| {
+        in_place_ordered_fft(row, &twiddles[..n2 / 2]);
+    });
+
+    transpose(&rows, n1, n2)
+}
+
+/// Splits `n` into `n1 * n2`, with both factors powers of two and `n1 <= n2`.
+fn split_size(n: usize) -> (usize, usize) {
+    let log_n = n.trailing_zeros();
+    let log_n1 = log_n / 2;
+    (1 << log_n1, 1 << (log_n - log_n1))
+}
+
+/// FFT from natural to natural order, used for the rows.
+fn in_place_ordered_fft<F, E>(input: &mut [FieldElement<E>], twiddles: &[FieldElement<F>])
+where
+    F: IsFFTField + IsSubFieldOf<E>,
+    E: IsField,
+{
+    in_place_nr_2radix_fft(input, twiddles);
+    in_place_bit_reverse_permute(input);
+}
+
+/// Multiplies the `i`-th element of `row` by `base^i`.
+fn multiply_by_powers<F, E>(row: &mut [FieldElement<E>], base: &FieldElement<F>)
+where
+    F: IsFFTField + IsSubFieldOf<E>,
+    E: IsField,
+{
+    let mut power = FieldElement::<F>::one();
+    for element in row.iter_mut().skip(1) {
+        power = &power * base;
+        *element = &power * &*element;
+    }
+}
+
+/// Returns the transpose of `input`, a `rows x cols` matrix in row-major order.
+/// The matrix is traversed in tiles to avoid a cache miss on every write.
+fn transpose<T: Clone>(input: &[T], rows: usize, cols: usize) -> Vec<T> {
+    debug_assert_eq!(input.len(), rows * cols);
+    let mut output = input.to_vec();
+
+    for row_tile in (0..rows).step_by(TRANSPOSE_TILE_SIZE) {
+        for col_tile in (0..cols).step_by(TRANSPOSE_TILE_SIZE) {
+            for row in row_tile..(row_tile + TRANSPOSE_TILE_SIZE).min(rows) {
+                for col in col_tile..(col_tile + TRANSPOSE_TILE_SIZE).min(cols) {
+                    output[col * rows + row] = input[row * cols + col].clone();
+                }
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fft::cpu::roots_of_unity::get_twiddles;
+    use crate::fft::test_helpers::naive_matrix_dft_test;
+    use crate::field::{test_fields::u64_test_field::U64TestField, traits::RootsConfig};
+    use proptest::{collection, prelude::*};
+
+    type F = U64TestField;
+    type FE = FieldElement<F>;
+
+    prop_compose! {
+        fn field_element()(num in any::<u64>().prop_filter("Avoid null coefficients", |x| x != &0)) -> FE {
+            FE::from(num)
+        }
+    }
+    prop_compose! {
+        fn field_vec(max_exp: u8)(vec in (0..max_exp).prop_flat_map(|i| collection::vec(field_element(), 1 << i))) -> Vec<FE> {
+            vec
+        }
+    }
+
+    proptest! {
+        // Property-based test that ensures the four-step FFT gives the same result as a naive DFT.
+        #[test]
+        fn test_four_step_fft_matches_naive_eval(coeffs in field_vec(10)) {
+            let expected = naive_matrix_dft_test(&coeffs);
+
+            let order = coeffs.len().trailing_zeros();
+            let twiddles = get_twiddles::<F>(order.into(), RootsConfig::BitReverse).unwrap();
+
+            let result = four_step_fft(&coeffs, &twiddles);
+
+            prop_assert_eq!(expected, result);
+        }
+
+        // Property-based test that ensures the parallel four-step FFT gives the same result as the sequential one.
+        #[test]
+        #[cfg(feature = "parallel")]
+        fn test_parallel_four_step_fft_matches_sequential(coeffs in field_vec(10)) {
+            let order = coeffs.len().trailing_zeros();
+            let twiddles = get_twiddles::<F>(order.into(), RootsConfig::BitReverse).unwrap();
+
+            let sequential = four_step_fft(&coeffs, &twiddles);
+            let parallel = parallel_four_step_fft(&coeffs, &twiddles);
+
+            prop_assert_eq!(sequential, parallel);
+        }
+    }
+
+    #[test]
+    fn transpose_of_a_rectangular_matrix() {
+        let matrix = [1, 2, 3, 4, 5, 6];
+        assert_eq!(transpose(&matrix, 2, 3), [1, 4, 2, 5, 3, 6]);
+    }
+}
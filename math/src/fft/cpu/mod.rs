@@ -1,6 +1,8 @@
 pub mod bit_reversing;
 pub mod fft;
 #[cfg(feature = "alloc")]
+pub mod four_step;
+#[cfg(feature = "alloc")]
 pub mod ops;
 #[cfg(feature = "alloc")]
 pub mod roots_of_unity;
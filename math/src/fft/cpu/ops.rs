@@ -6,21 +6,113 @@ use crate::{
     },
 };
 
-use super::{bit_reversing::in_place_bit_reverse_permute, fft::in_place_nr_2radix_fft};
+#[cfg(not(feature = "parallel"))]
+use super::four_step::four_step_fft;
+#[cfg(feature = "parallel")]
+use super::four_step::parallel_four_step_fft;
+use super::{
+    bit_reversing::in_place_bit_reverse_permute,
+    fft::{in_place_nr_16radix_fft, in_place_nr_2radix_fft, in_place_nr_8radix_fft},
+};
+
+/// Bound of the field elements the FFT works with. With the `parallel` feature it is
+/// `Send + Sync`, so that the four-step FFT can split its rows between threads. Without it, every
+/// type satisfies it.
+#[cfg(feature = "parallel")]
+pub trait MaybeSendSync: Send + Sync {}
+#[cfg(feature = "parallel")]
+impl<T: Send + Sync> MaybeSendSync for T {}
+
+/// Bound of the field elements the FFT works with. With the `parallel` feature it is
+/// `Send + Sync`, so that the four-step FFT can split its rows between threads. Without it, every
+/// type satisfies it.
+#[cfg(not(feature = "parallel"))]
+pub trait MaybeSendSync {}
+#[cfg(not(feature = "parallel"))]
+impl<T> MaybeSendSync for T {}
+
+/// Inputs with at least `2^RADIX_8_MIN_ORDER` elements use the radix-8 kernel, which goes over
+/// the input a third of the times. Smaller inputs fit in cache and radix-2 has the tighter loop.
+const RADIX_8_MIN_ORDER: u32 = 16;
+/// Inputs with at least `2^RADIX_16_MIN_ORDER` elements no longer fit in the L2 cache, and the
+/// radix-16 kernel goes over them a quarter of the times.
+const RADIX_16_MIN_ORDER: u32 = 20;
+/// Inputs with at least `2^FOUR_STEP_MIN_ORDER` elements use the four-step algorithm, whose small
+/// FFTs fit in cache even when the whole input is much bigger than the last level cache.
+/// With the `parallel` feature its small FFTs run in parallel.
+const FOUR_STEP_MIN_ORDER: u32 = 26;
 
 /// Executes Fast Fourier Transform over elements of a two-adic finite field `E` and domain in a
 /// subfield `F`. Usually used for fast polynomial evaluation.
-pub fn fft<F: IsFFTField + IsSubFieldOf<E>, E: IsField>(
+/// The algorithm is selected according to the input size: radix-2 for small inputs, radix-8 and
+/// radix-16 for medium ones and the four-step FFT for the biggest ones.
+pub fn fft<F, E>(
     input: &[FieldElement<E>],
     twiddles: &[FieldElement<F>],
-) -> Result<alloc::vec::Vec<FieldElement<E>>, FFTError> {
+) -> Result<alloc::vec::Vec<FieldElement<E>>, FFTError>
+where
+    F: IsFFTField + IsSubFieldOf<E>,
+    E: IsField,
+    FieldElement<F>: MaybeSendSync,
+    FieldElement<E>: MaybeSendSync,
+{
     if !input.len().is_power_of_two() {
         return Err(FFTError::InputError(input.len()));
     }
 
+    let order = input.len().trailing_zeros();
+    if order >= FOUR_STEP_MIN_ORDER {
+        #[cfg(feature = "parallel")]
+        return Ok(parallel_four_step_fft(input, twiddles));
+        #[cfg(not(feature = "parallel"))]
+        return Ok(four_step_fft(input, twiddles));
+    }
+
     let mut results = input.to_vec();
-    in_place_nr_2radix_fft(&mut results, twiddles);
+    if order >= RADIX_16_MIN_ORDER {
+        in_place_nr_16radix_fft(&mut results, twiddles);
+    } else if order >= RADIX_8_MIN_ORDER {
+        in_place_nr_8radix_fft(&mut results, twiddles);
+    } else {
+        in_place_nr_2radix_fft(&mut results, twiddles);
+    }
     in_place_bit_reverse_permute(&mut results);
 
     Ok(results)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fft::cpu::roots_of_unity::get_twiddles;
+    use crate::field::{test_fields::u64_test_field::U64TestField, traits::RootsConfig};
+
+    type F = U64TestField;
+    type FE = FieldElement<F>;
+
+    #[test]
+    fn fft_with_the_radix_8_kernel_matches_radix_2() {
+        let order = RADIX_8_MIN_ORDER;
+        let input: alloc::vec::Vec<FE> = (0..1u64 << order).map(FE::from).collect();
+        let twiddles = get_twiddles::<F>(order.into(), RootsConfig::BitReverse).unwrap();
+
+        let mut expected = input.clone();
+        in_place_nr_2radix_fft(&mut expected, &twiddles);
+        in_place_bit_reverse_permute(&mut expected);
+
+        assert_eq!(fft(&input, &twiddles).unwrap(), expected);
+    }
+
+    #[test]
+    fn fft_with_the_radix_16_kernel_matches_radix_2() {
+        let order = RADIX_16_MIN_ORDER;
+        let input: alloc::vec::Vec<FE> = (0..1u64 << order).map(FE::from).collect();
+        let twiddles = get_twiddles::<F>(order.into(), RootsConfig::BitReverse).unwrap();
+
+        let mut expected = input.clone();
+        in_place_nr_2radix_fft(&mut expected, &twiddles);
+        in_place_bit_reverse_permute(&mut expected);
+
+        assert_eq!(fft(&input, &twiddles).unwrap(), expected);
+    }
+}
@@ -15,7 +15,10 @@ use crate::fft::gpu::cuda::polynomial::{evaluate_fft_cuda, interpolate_fft_cuda}
 #[cfg(feature = "metal")]
 use crate::fft::gpu::metal::polynomial::{evaluate_fft_metal, interpolate_fft_metal};
 
-use super::cpu::{ops, roots_of_unity};
+use super::cpu::{
+    ops::{self, MaybeSendSync},
+    roots_of_unity,
+};
 
 impl<E: IsField> Polynomial<FieldElement<E>> {
     /// Returns `N` evaluations of this polynomial using FFT over a domain in a subfield F of E (so the results
@@ -26,7 +29,11 @@ impl<E: IsField> Polynomial<FieldElement<E>> {
         poly: &Polynomial<FieldElement<E>>,
         blowup_factor: usize,
         domain_size: Option<usize>,
-    ) -> Result<Vec<FieldElement<E>>, FFTError> {
+    ) -> Result<Vec<FieldElement<E>>, FFTError>
+    where
+        FieldElement<F>: MaybeSendSync,
+        FieldElement<E>: MaybeSendSync,
+    {
         let domain_size = domain_size.unwrap_or(0);
         let len = core::cmp::max(poly.coeff_len(), domain_size).next_power_of_two() * blowup_factor;
 
@@ -76,7 +83,11 @@ impl<E: IsField> Polynomial<FieldElement<E>> {
         blowup_factor: usize,
         domain_size: Option<usize>,
         offset: &FieldElement<F>,
-    ) -> Result<Vec<FieldElement<E>>, FFTError> {
+    ) -> Result<Vec<FieldElement<E>>, FFTError>
+    where
+        FieldElement<F>: MaybeSendSync,
+        FieldElement<E>: MaybeSendSync,
+    {
         let scaled = poly.scale(offset);
         Polynomial::evaluate_fft::<F>(&scaled, blowup_factor, domain_size)
     }
@@ -86,7 +97,11 @@ impl<E: IsField> Polynomial<FieldElement<E>> {
     /// This is considered to be the inverse operation of [Self::evaluate_fft()].
     pub fn interpolate_fft<F: IsFFTField + IsSubFieldOf<E>>(
         fft_evals: &[FieldElement<E>],
-    ) -> Result<Self, FFTError> {
+    ) -> Result<Self, FFTError>
+    where
+        FieldElement<F>: MaybeSendSync,
+        FieldElement<E>: MaybeSendSync,
+    {
         #[cfg(feature = "metal")]
         {
             if !F::field_name().is_empty() {
@@ -121,7 +136,11 @@ impl<E: IsField> Polynomial<FieldElement<E>> {
     pub fn interpolate_offset_fft<F: IsFFTField + IsSubFieldOf<E>>(
         fft_evals: &[FieldElement<E>],
         offset: &FieldElement<F>,
-    ) -> Result<Polynomial<FieldElement<E>>, FFTError> {
+    ) -> Result<Polynomial<FieldElement<E>>, FFTError>
+    where
+        FieldElement<F>: MaybeSendSync,
+        FieldElement<E>: MaybeSendSync,
+    {
         let scaled = Polynomial::interpolate_fft::<F>(fft_evals)?;
         Ok(scaled.scale(&offset.inv().unwrap()))
     }
@@ -134,6 +153,8 @@ pub fn compose_fft<F, E>(
 where
     F: IsFFTField + IsSubFieldOf<E>,
     E: IsField,
+    FieldElement<F>: MaybeSendSync,
+    FieldElement<E>: MaybeSendSync,
 {
     let poly_2_evaluations = Polynomial::evaluate_fft::<F>(poly_2, 1, None).unwrap();
 
@@ -149,6 +170,8 @@ pub fn evaluate_fft_cpu<F, E>(coeffs: &[FieldElement<E>]) -> Result<Vec<FieldEle
 where
     F: IsFFTField + IsSubFieldOf<E>,
     E: IsField,
+    FieldElement<F>: MaybeSendSync,
+    FieldElement<E>: MaybeSendSync,
 {
     let order = coeffs.len().trailing_zeros();
     let twiddles = roots_of_unity::get_twiddles::<F>(order.into(), RootsConfig::BitReverse)?;
@@ -162,6 +185,8 @@ pub fn interpolate_fft_cpu<F, E>(
 where
     F: IsFFTField + IsSubFieldOf<E>,
     E: IsField,
+    FieldElement<F>: MaybeSendSync,
+    FieldElement<E>: MaybeSendSync,
 {
     let order = fft_evals.len().trailing_zeros();
     let twiddles =
@@ -192,7 +217,10 @@ mod tests {
 
     fn gen_fft_and_naive_evaluation<F: IsFFTField>(
         poly: Polynomial<FieldElement<F>>,
-    ) -> (Vec<FieldElement<F>>, Vec<FieldElement<F>>) {
+    ) -> (Vec<FieldElement<F>>, Vec<FieldElement<F>>)
+    where
+        FieldElement<F>: MaybeSendSync,
+    {
         let len = poly.coeff_len().next_power_of_two();
         let order = len.trailing_zeros();
         let twiddles =
@@ -208,7 +236,10 @@ mod tests {
         poly: Polynomial<FieldElement<F>>,
         offset: FieldElement<F>,
         blowup_factor: usize,
-    ) -> (Vec<FieldElement<F>>, Vec<FieldElement<F>>) {
+    ) -> (Vec<FieldElement<F>>, Vec<FieldElement<F>>)
+    where
+        FieldElement<F>: MaybeSendSync,
+    {
         let len = poly.coeff_len().next_power_of_two();
         let order = (len * blowup_factor).trailing_zeros();
         let twiddles =
@@ -223,7 +254,10 @@ mod tests {
 
     fn gen_fft_and_naive_interpolate<F: IsFFTField>(
         fft_evals: &[FieldElement<F>],
-    ) -> (Polynomial<FieldElement<F>>, Polynomial<FieldElement<F>>) {
+    ) -> (Polynomial<FieldElement<F>>, Polynomial<FieldElement<F>>)
+    where
+        FieldElement<F>: MaybeSendSync,
+    {
         let order = fft_evals.len().trailing_zeros() as u64;
         let twiddles =
             get_powers_of_primitive_root(order, 1 << order, RootsConfig::Natural).unwrap();
@@ -237,7 +271,10 @@ mod tests {
     fn gen_fft_and_naive_coset_interpolate<F: IsFFTField>(
         fft_evals: &[FieldElement<F>],
         offset: &FieldElement<F>,
-    ) -> (Polynomial<FieldElement<F>>, Polynomial<FieldElement<F>>) {
+    ) -> (Polynomial<FieldElement<F>>, Polynomial<FieldElement<F>>)
+    where
+        FieldElement<F>: MaybeSendSync,
+    {
         let order = fft_evals.len().trailing_zeros() as u64;
         let twiddles = get_powers_of_primitive_root_coset(order, 1 << order, offset).unwrap();
 
@@ -249,7 +286,10 @@ mod tests {
 
     fn gen_fft_interpolate_and_evaluate<F: IsFFTField>(
         poly: Polynomial<FieldElement<F>>,
-    ) -> (Polynomial<FieldElement<F>>, Polynomial<FieldElement<F>>) {
+    ) -> (Polynomial<FieldElement<F>>, Polynomial<FieldElement<F>>)
+    where
+        FieldElement<F>: MaybeSendSync,
+    {
         let eval = Polynomial::evaluate_fft::<F>(&poly, 1, None).unwrap();
         let new_poly = Polynomial::interpolate_fft::<F>(&eval).unwrap();
 
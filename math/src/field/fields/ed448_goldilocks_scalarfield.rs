@@ -0,0 +1,19 @@
+use crate::{
+    field::fields::montgomery_backed_prime_fields::{IsModulus, MontgomeryBackendPrimeField},
+    unsigned_integer::element::UnsignedInteger,
+};
+
+type Ed448GoldilocksMontgomeryBackendScalarField<T> = MontgomeryBackendPrimeField<T, 7>;
+
+/// Order of the prime subgroup of Ed448-Goldilocks,
+/// L = 2^446 - 13818066809895115352007386748515426880336692474882178609894547503885
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MontgomeryConfigEd448GoldilocksScalarField;
+impl IsModulus<UnsignedInteger<7>> for MontgomeryConfigEd448GoldilocksScalarField {
+    const MODULUS: UnsignedInteger<7> = UnsignedInteger::from_hex_unchecked(
+        "3fffffffffffffffffffffffffffffffffffffffffffffffffffffff7cca23e9c44edb49aed63690216cc2728dc58f552378c292ab5844f3",
+    );
+}
+
+pub type Ed448GoldilocksScalarField =
+    Ed448GoldilocksMontgomeryBackendScalarField<MontgomeryConfigEd448GoldilocksScalarField>;
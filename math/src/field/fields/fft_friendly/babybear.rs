@@ -98,6 +98,8 @@ mod tests {
     mod test_babybear_31_fft {
         use super::*;
         #[cfg(not(any(feature = "metal", feature = "cuda")))]
+        use crate::fft::cpu::ops::MaybeSendSync;
+        #[cfg(not(any(feature = "metal", feature = "cuda")))]
         use crate::fft::cpu::roots_of_unity::{
             get_powers_of_primitive_root, get_powers_of_primitive_root_coset,
         };
@@ -111,7 +113,10 @@ mod tests {
         #[cfg(not(any(feature = "metal", feature = "cuda")))]
         fn gen_fft_and_naive_evaluation<F: IsFFTField>(
             poly: Polynomial<FieldElement<F>>,
-        ) -> (Vec<FieldElement<F>>, Vec<FieldElement<F>>) {
+        ) -> (Vec<FieldElement<F>>, Vec<FieldElement<F>>)
+        where
+            FieldElement<F>: MaybeSendSync,
+        {
             let len = poly.coeff_len().next_power_of_two();
             let order = len.trailing_zeros();
             let twiddles =
@@ -128,7 +133,10 @@ mod tests {
             poly: Polynomial<FieldElement<F>>,
             offset: FieldElement<F>,
             blowup_factor: usize,
-        ) -> (Vec<FieldElement<F>>, Vec<FieldElement<F>>) {
+        ) -> (Vec<FieldElement<F>>, Vec<FieldElement<F>>)
+        where
+            FieldElement<F>: MaybeSendSync,
+        {
             let len = poly.coeff_len().next_power_of_two();
             let order = (len * blowup_factor).trailing_zeros();
             let twiddles =
@@ -145,7 +153,10 @@ mod tests {
         #[cfg(not(any(feature = "metal", feature = "cuda")))]
         fn gen_fft_and_naive_interpolate<F: IsFFTField>(
             fft_evals: &[FieldElement<F>],
-        ) -> (Polynomial<FieldElement<F>>, Polynomial<FieldElement<F>>) {
+        ) -> (Polynomial<FieldElement<F>>, Polynomial<FieldElement<F>>)
+        where
+            FieldElement<F>: MaybeSendSync,
+        {
             let order = fft_evals.len().trailing_zeros() as u64;
             let twiddles =
                 get_powers_of_primitive_root(order, 1 << order, RootsConfig::Natural).unwrap();
@@ -160,7 +171,10 @@ mod tests {
         fn gen_fft_and_naive_coset_interpolate<F: IsFFTField>(
             fft_evals: &[FieldElement<F>],
             offset: &FieldElement<F>,
-        ) -> (Polynomial<FieldElement<F>>, Polynomial<FieldElement<F>>) {
+        ) -> (Polynomial<FieldElement<F>>, Polynomial<FieldElement<F>>)
+        where
+            FieldElement<F>: MaybeSendSync,
+        {
             let order = fft_evals.len().trailing_zeros() as u64;
             let twiddles = get_powers_of_primitive_root_coset(order, 1 << order, offset).unwrap();
 
@@ -173,7 +187,10 @@ mod tests {
         #[cfg(not(any(feature = "metal", feature = "cuda")))]
         fn gen_fft_interpolate_and_evaluate<F: IsFFTField>(
             poly: Polynomial<FieldElement<F>>,
-        ) -> (Polynomial<FieldElement<F>>, Polynomial<FieldElement<F>>) {
+        ) -> (Polynomial<FieldElement<F>>, Polynomial<FieldElement<F>>)
+        where
+            FieldElement<F>: MaybeSendSync,
+        {
             let eval = Polynomial::evaluate_fft::<F>(&poly, 1, None).unwrap();
             let new_poly = Polynomial::interpolate_fft::<F>(&eval).unwrap();
 
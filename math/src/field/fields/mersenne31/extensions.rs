@@ -1,8 +1,14 @@
 use super::field::Mersenne31Field;
-use crate::field::{
-    element::FieldElement,
-    errors::FieldError,
-    traits::{IsField, IsSubFieldOf},
+#[cfg(feature = "alloc")]
+use crate::traits::AsBytes;
+use crate::{
+    errors::ByteConversionError,
+    field::{
+        element::FieldElement,
+        errors::FieldError,
+        traits::{IsFFTField, IsField, IsSubFieldOf},
+    },
+    traits::ByteConversion,
 };
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
@@ -134,6 +140,63 @@ impl IsSubFieldOf<Degree2ExtensionField> for Mersenne31Field {
     }
 }
 
+/// Mersenne31 is not two-adic, but `p^2 - 1 = (p - 1) * (p + 1) = 2 * (2^30 - 1) * 2^31`, so the
+/// multiplicative group of its complex extension has a subgroup of order `2^32`.
+/// Its generator is `(2 + i)^((p^2 - 1) / 2^32)`.
+impl IsFFTField for Degree2ExtensionField {
+    const TWO_ADICITY: u64 = 32;
+
+    const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: Self::BaseType =
+        [FpE::const_from_raw(21189756), FpE::const_from_raw(42379512)];
+
+    fn field_name() -> &'static str {
+        "mersenne31_degree2"
+    }
+}
+
+impl ByteConversion for Fp2E {
+    #[cfg(feature = "alloc")]
+    fn to_bytes_be(&self) -> alloc::vec::Vec<u8> {
+        let mut byte_slice = ByteConversion::to_bytes_be(&self.value()[0]);
+        byte_slice.extend(ByteConversion::to_bytes_be(&self.value()[1]));
+        byte_slice
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_bytes_le(&self) -> alloc::vec::Vec<u8> {
+        let mut byte_slice = ByteConversion::to_bytes_le(&self.value()[0]);
+        byte_slice.extend(ByteConversion::to_bytes_le(&self.value()[1]));
+        byte_slice
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        const BYTES_PER_FIELD: usize = 4;
+        if bytes.len() < BYTES_PER_FIELD * 2 {
+            return Err(ByteConversionError::FromBEBytesError);
+        }
+        let x0 = FpE::from_bytes_be(&bytes[0..BYTES_PER_FIELD])?;
+        let x1 = FpE::from_bytes_be(&bytes[BYTES_PER_FIELD..BYTES_PER_FIELD * 2])?;
+        Ok(Self::new([x0, x1]))
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        const BYTES_PER_FIELD: usize = 4;
+        if bytes.len() < BYTES_PER_FIELD * 2 {
+            return Err(ByteConversionError::FromLEBytesError);
+        }
+        let x0 = FpE::from_bytes_le(&bytes[0..BYTES_PER_FIELD])?;
+        let x1 = FpE::from_bytes_le(&bytes[BYTES_PER_FIELD..BYTES_PER_FIELD * 2])?;
+        Ok(Self::new([x0, x1]))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsBytes for Fp2E {
+    fn as_bytes(&self) -> alloc::vec::Vec<u8> {
+        ByteConversion::to_bytes_be(self)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Degree4ExtensionField;
 
@@ -265,6 +328,92 @@ impl IsSubFieldOf<Degree4ExtensionField> for Mersenne31Field {
     }
 }
 
+impl IsSubFieldOf<Degree4ExtensionField> for Degree2ExtensionField {
+    fn add(
+        a: &Self::BaseType,
+        b: &<Degree4ExtensionField as IsField>::BaseType,
+    ) -> <Degree4ExtensionField as IsField>::BaseType {
+        [Fp2E::from_raw(*a) + &b[0], b[1].clone()]
+    }
+
+    fn sub(
+        a: &Self::BaseType,
+        b: &<Degree4ExtensionField as IsField>::BaseType,
+    ) -> <Degree4ExtensionField as IsField>::BaseType {
+        [Fp2E::from_raw(*a) - &b[0], -&b[1]]
+    }
+
+    fn mul(
+        a: &Self::BaseType,
+        b: &<Degree4ExtensionField as IsField>::BaseType,
+    ) -> <Degree4ExtensionField as IsField>::BaseType {
+        let a = Fp2E::from_raw(*a);
+        [&a * &b[0], &a * &b[1]]
+    }
+
+    fn div(
+        a: &Self::BaseType,
+        b: &<Degree4ExtensionField as IsField>::BaseType,
+    ) -> <Degree4ExtensionField as IsField>::BaseType {
+        let b_inv = Degree4ExtensionField::inv(b).unwrap();
+        <Self as IsSubFieldOf<Degree4ExtensionField>>::mul(a, &b_inv)
+    }
+
+    fn embed(a: Self::BaseType) -> <Degree4ExtensionField as IsField>::BaseType {
+        [Fp2E::from_raw(a), Fp2E::zero()]
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_subfield_vec(
+        b: <Degree4ExtensionField as IsField>::BaseType,
+    ) -> alloc::vec::Vec<Self::BaseType> {
+        b.into_iter().map(|x| x.to_raw()).collect()
+    }
+}
+
+impl ByteConversion for Fp4E {
+    #[cfg(feature = "alloc")]
+    fn to_bytes_be(&self) -> alloc::vec::Vec<u8> {
+        let mut byte_slice = ByteConversion::to_bytes_be(&self.value()[0]);
+        byte_slice.extend(ByteConversion::to_bytes_be(&self.value()[1]));
+        byte_slice
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_bytes_le(&self) -> alloc::vec::Vec<u8> {
+        let mut byte_slice = ByteConversion::to_bytes_le(&self.value()[0]);
+        byte_slice.extend(ByteConversion::to_bytes_le(&self.value()[1]));
+        byte_slice
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        const BYTES_PER_FIELD: usize = 8;
+        if bytes.len() < BYTES_PER_FIELD * 2 {
+            return Err(ByteConversionError::FromBEBytesError);
+        }
+        let x0 = Fp2E::from_bytes_be(&bytes[0..BYTES_PER_FIELD])?;
+        let x1 = Fp2E::from_bytes_be(&bytes[BYTES_PER_FIELD..BYTES_PER_FIELD * 2])?;
+        Ok(Self::new([x0, x1]))
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        const BYTES_PER_FIELD: usize = 8;
+        if bytes.len() < BYTES_PER_FIELD * 2 {
+            return Err(ByteConversionError::FromLEBytesError);
+        }
+        let x0 = Fp2E::from_bytes_le(&bytes[0..BYTES_PER_FIELD])?;
+        let x1 = Fp2E::from_bytes_le(&bytes[BYTES_PER_FIELD..BYTES_PER_FIELD * 2])?;
+        Ok(Self::new([x0, x1]))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsBytes for Fp4E {
+    fn as_bytes(&self) -> alloc::vec::Vec<u8> {
+        ByteConversion::to_bytes_be(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::ops::Neg;
@@ -633,4 +782,79 @@ mod tests {
         ]);
         assert_eq!(a * &b, a_extension * b);
     }
+
+    #[test]
+    fn two_adic_primitive_root_of_unity_has_order_two_to_the_32() {
+        let root = Fp2E::from_raw(Degree2ExtensionField::TWO_ADIC_PRIMITVE_ROOT_OF_UNITY);
+        assert_eq!(root.pow(1u64 << 32), Fp2E::one());
+        assert_ne!(root.pow(1u64 << 31), Fp2E::one());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fft_over_the_complex_extension_matches_the_evaluations() {
+        use crate::fft::cpu::roots_of_unity::get_powers_of_primitive_root;
+        use crate::field::traits::RootsConfig;
+        use crate::polynomial::Polynomial;
+
+        let coefficients: alloc::vec::Vec<Fp2E> = (0..16u64)
+            .map(|i| Fp2E::new([FpE::from(i * i + 1), FpE::from(3 * i)]))
+            .collect();
+        let polynomial = Polynomial::new(&coefficients);
+
+        let evaluations =
+            Polynomial::evaluate_fft::<Degree2ExtensionField>(&polynomial, 1, None).unwrap();
+        let domain =
+            get_powers_of_primitive_root::<Degree2ExtensionField>(4, 16, RootsConfig::Natural)
+                .unwrap();
+        let expected: alloc::vec::Vec<Fp2E> =
+            domain.iter().map(|x| polynomial.evaluate(x)).collect();
+
+        assert_eq!(evaluations, expected);
+    }
+
+    #[test]
+    fn embed_fp2_with_fp4() {
+        let a = Fp2E::new([FpE::from(3), FpE::from(5)]);
+        let a_extension = Fp4E::new([a.clone(), Fp2E::zero()]);
+        assert_eq!(a.to_extension::<Degree4ExtensionField>(), a_extension);
+    }
+
+    #[test]
+    fn mul_fp2_by_fp4() {
+        let a = Fp2E::new([FpE::from(3), FpE::from(5)]);
+        let b = Fp4E::new([
+            Fp2E::new([FpE::from(1), FpE::from(2)]),
+            Fp2E::new([FpE::from(3), FpE::from(4)]),
+        ]);
+        assert_eq!(&a * &b, a.to_extension::<Degree4ExtensionField>() * b);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fp4_to_bytes_from_bytes_be_is_the_identity() {
+        let x = Fp4E::new([
+            Fp2E::new([FpE::from(1), FpE::from(2)]),
+            Fp2E::new([
+                FpE::from(3),
+                FpE::from(MERSENNE_31_PRIME_FIELD_ORDER as u64 - 1),
+            ]),
+        ]);
+        let bytes = ByteConversion::to_bytes_be(&x);
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(Fp4E::from_bytes_be(&bytes).unwrap(), x);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fp2_to_bytes_from_bytes_le_is_the_identity() {
+        let x = Fp2E::new([FpE::from(7), FpE::from(11)]);
+        let bytes = ByteConversion::to_bytes_le(&x);
+        assert_eq!(Fp2E::from_bytes_le(&bytes).unwrap(), x);
+    }
+
+    #[test]
+    fn fp2_from_too_few_bytes_is_an_error() {
+        assert!(Fp2E::from_bytes_be(&[1, 2, 3]).is_err());
+    }
 }
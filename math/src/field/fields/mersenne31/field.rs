@@ -1,10 +1,14 @@
+#[cfg(feature = "alloc")]
+use crate::traits::AsBytes;
 use crate::{
-    errors::CreationError,
+    errors::{ByteConversionError, CreationError},
     field::{
         element::FieldElement,
         errors::FieldError,
+        packed::{self, mersenne31::PackedMersenne31Field},
         traits::{IsField, IsPrimeField},
     },
+    traits::ByteConversion,
 };
 use core::fmt::{self, Display};
 
@@ -150,6 +154,22 @@ impl IsField for Mersenne31Field {
     fn double(a: &u32) -> u32 {
         Self::weak_reduce(a << 1)
     }
+
+    #[inline(always)]
+    fn fft_butterflies<S: crate::field::traits::IsSubFieldOf<Self>>(
+        top: &mut [FieldElement<Self>],
+        bottom: &mut [FieldElement<Self>],
+        w: &FieldElement<S>,
+    ) {
+        packed::fft_butterflies::<PackedMersenne31Field, S>(top, bottom, w)
+    }
+
+    fn evaluate_polynomial<S: crate::field::traits::IsSubFieldOf<Self>>(
+        coefficients: &[FieldElement<S>],
+        x: &FieldElement<Self>,
+    ) -> FieldElement<Self> {
+        packed::evaluate_polynomial::<PackedMersenne31Field, S>(coefficients, x)
+    }
 }
 
 impl IsPrimeField for Mersenne31Field {
@@ -197,6 +217,39 @@ impl FieldElement<Mersenne31Field> {
     }
 }
 
+impl ByteConversion for FieldElement<Mersenne31Field> {
+    #[cfg(feature = "alloc")]
+    fn to_bytes_be(&self) -> alloc::vec::Vec<u8> {
+        self.representative().to_be_bytes().to_vec()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_bytes_le(&self) -> alloc::vec::Vec<u8> {
+        self.representative().to_le_bytes().to_vec()
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let bytes: [u8; 4] = bytes
+            .try_into()
+            .map_err(|_| ByteConversionError::FromBEBytesError)?;
+        Ok(Self::from(&u32::from_be_bytes(bytes)))
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let bytes: [u8; 4] = bytes
+            .try_into()
+            .map_err(|_| ByteConversionError::FromLEBytesError)?;
+        Ok(Self::from(&u32::from_le_bytes(bytes)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsBytes for FieldElement<Mersenne31Field> {
+    fn as_bytes(&self) -> alloc::vec::Vec<u8> {
+        self.to_bytes_be()
+    }
+}
+
 impl Display for FieldElement<Mersenne31Field> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:x}", self.representative())
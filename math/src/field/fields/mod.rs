@@ -1,3 +1,5 @@
+/// Implementation of Ed448-Goldilocks scalar field.
+pub mod ed448_goldilocks_scalarfield;
 /// Implementation of two-adic prime fields to use with the Fast Fourier Transform (FFT).
 pub mod fft_friendly;
 /// Implementation of the 32-bit Mersenne Prime field (p = 2^31 - 1)
@@ -13,6 +15,8 @@ pub mod secp256k1_field;
 pub mod secp256k1_scalarfield;
 /// Implementation of secp256r1 base field.
 pub mod secp256r1_field;
+/// Implementation of secp256r1 scalar field.
+pub mod secp256r1_scalarfield;
 pub mod u32_montgomery_backend_prime_field;
 /// Implementation of the u64 Goldilocks Prime field (p = 2^64 - 2^32 + 1)
 pub mod u64_goldilocks_field;
@@ -294,8 +294,8 @@ where
 
     fn field_bit_size() -> usize {
         let mut evaluated_bit = NUM_LIMBS * 64 - 1;
-        let max_element = M::MODULUS - UnsignedInteger::<NUM_LIMBS>::from_u128(1);
-        let one = UnsignedInteger::from_u128(1);
+        let max_element = M::MODULUS - UnsignedInteger::<NUM_LIMBS>::from_u64(1);
+        let one = UnsignedInteger::from_u64(1);
 
         while ((max_element >> evaluated_bit) & one) != one {
             evaluated_bit -= 1;
@@ -413,6 +413,12 @@ mod tests_u384_prime_fields {
         assert_eq!(U384F23::field_bit_size(), 5);
     }
 
+    #[test]
+    fn babybear_with_a_single_limb_uses_31_bits() {
+        use crate::field::fields::fft_friendly::babybear::Babybear31PrimeField;
+        assert_eq!(Babybear31PrimeField::field_bit_size(), 31);
+    }
+
     #[test]
     fn stark_252_prime_field_uses_252_bits() {
         assert_eq!(Stark252PrimeField::field_bit_size(), 252);
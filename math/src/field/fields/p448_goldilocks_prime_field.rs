@@ -1,7 +1,7 @@
-use crate::errors::CreationError;
+use crate::errors::{ByteConversionError, CreationError};
+use crate::field::element::FieldElement;
 use crate::field::errors::FieldError;
 use crate::field::traits::{IsField, IsPrimeField};
-#[cfg(feature = "lambdaworks-serde-binary")]
 use crate::traits::ByteConversion;
 use crate::unsigned_integer::element::UnsignedInteger;
 
@@ -206,7 +206,7 @@ impl IsPrimeField for P448GoldilocksPrimeField {
         Self::strong_reduce(&mut a);
 
         let mut r = U448::from_u64(0);
-        for i in (0..7).rev() {
+        for i in (0..8).rev() {
             r = r << 56;
             r = r + U448::from_u64(a.limbs[i]);
         }
@@ -227,6 +227,53 @@ impl IsPrimeField for P448GoldilocksPrimeField {
     }
 }
 
+/// Field elements are encoded in 56 bytes, each limb taking 7 of them.
+impl ByteConversion for FieldElement<P448GoldilocksPrimeField> {
+    #[cfg(feature = "alloc")]
+    fn to_bytes_be(&self) -> alloc::vec::Vec<u8> {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_bytes_le(&self) -> alloc::vec::Vec<u8> {
+        let mut value = *self.value();
+        P448GoldilocksPrimeField::strong_reduce(&mut value);
+        value
+            .limbs
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes()[..7].to_vec())
+            .collect()
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        if bytes.len() > 56 {
+            return Err(ByteConversionError::FromBEBytesError);
+        }
+        let mut le_bytes = [0u8; 56];
+        for (dst, src) in le_bytes.iter_mut().zip(bytes.iter().rev()) {
+            *dst = *src;
+        }
+        Self::from_bytes_le(&le_bytes)
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        if bytes.len() > 56 {
+            return Err(ByteConversionError::FromLEBytesError);
+        }
+        let mut padded = [0u8; 56];
+        padded[..bytes.len()].copy_from_slice(bytes);
+        let mut limbs = [0u64; 8];
+        for (limb, chunk) in limbs.iter_mut().zip(padded.chunks_exact(7)) {
+            let mut limb_bytes = [0u8; 8];
+            limb_bytes[..7].copy_from_slice(chunk);
+            *limb = u64::from_le_bytes(limb_bytes);
+        }
+        Ok(Self::new(U56x8 { limbs }))
+    }
+}
+
 impl P448GoldilocksPrimeField {
     /// Reduces the value in each limb to less than 2^57 (2^56 + 2^8 - 2 is the largest possible value in a limb after this reduction)
     /// Taken from https://sourceforge.net/p/ed448goldilocks/code/ci/master/tree/src/p448/arch_ref64/f_impl.h
@@ -377,6 +424,22 @@ mod tests {
         assert_eq!(r, U448::from_u64(42));
     }
 
+    #[test]
+    fn representative_keeps_the_most_significant_limb() {
+        let minus_one = P448GoldilocksPrimeField::neg(&P448GoldilocksPrimeField::one());
+        assert_eq!(
+            P448GoldilocksPrimeField::representative(&minus_one),
+            P448_GOLDILOCKS_PRIME_FIELD_ORDER - U448::from_u64(1)
+        );
+    }
+
+    #[test]
+    fn sqrt_works() {
+        let a = FieldElement::<P448GoldilocksPrimeField>::from_hex("4f1970c66bed0ded221d15a622bf36da9e146570470f1767ea6de324a3d3a46412ae1af72ab66511433b80e18b00938e2626a82bc70cc05e").unwrap();
+        let (r1, r2) = a.square().sqrt().unwrap();
+        assert!(r1 == a || r2 == a);
+    }
+
     #[test]
     fn p448_add_test_1() {
         let num1 = U56x8::from_hex("73c7941e36ee1e12b2105fb96634848d62def10bc1782576cfa7f54486820202847bbfb2e8f89ff7707f9913b8cf9b9efaf2029cfd6d3fa9").unwrap();
@@ -450,6 +513,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn byte_conversion_round_trips() {
+        let a = FieldElement::<P448GoldilocksPrimeField>::from_hex("4f1970c66bed0ded221d15a622bf36da9e146570470f1767ea6de324a3d3a46412ae1af72ab66511433b80e18b00938e2626a82bc70cc05e").unwrap();
+        let bytes_le = a.to_bytes_le();
+        assert_eq!(bytes_le.len(), 56);
+        assert_eq!(bytes_le[0], 0x5e);
+        assert_eq!(bytes_le[55], 0x4f);
+        assert_eq!(FieldElement::from_bytes_le(&bytes_le).unwrap(), a);
+        assert_eq!(FieldElement::from_bytes_be(&a.to_bytes_be()).unwrap(), a);
+    }
+
+    #[test]
+    fn from_bytes_reduces_values_above_the_modulus() {
+        // p + 1 = 2^448 - 2^224
+        let mut bytes = [0xff; 56];
+        bytes[..28].fill(0);
+        let one = FieldElement::<P448GoldilocksPrimeField>::from_bytes_le(&bytes).unwrap();
+        assert_eq!(one, FieldElement::one());
+        assert!(FieldElement::<P448GoldilocksPrimeField>::from_bytes_le(&[0; 57]).is_err());
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn to_hex_test() {
@@ -0,0 +1,17 @@
+use crate::{
+    field::fields::montgomery_backed_prime_fields::{IsModulus, MontgomeryBackendPrimeField},
+    unsigned_integer::element::U256,
+};
+
+type Secp256r1MontgomeryBackendScalarField<T> = MontgomeryBackendPrimeField<T, 4>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MontgomeryConfigSecp256r1ScalarField;
+impl IsModulus<U256> for MontgomeryConfigSecp256r1ScalarField {
+    const MODULUS: U256 = U256::from_hex_unchecked(
+        "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+    );
+}
+
+pub type Secp256r1ScalarField =
+    Secp256r1MontgomeryBackendScalarField<MontgomeryConfigSecp256r1ScalarField>;
@@ -1,6 +1,7 @@
 use crate::errors::CreationError;
 use crate::field::element::FieldElement;
 use crate::field::errors::FieldError;
+use crate::field::packed::{self, u32_montgomery::PackedU32MontgomeryBackendPrimeField};
 use crate::field::traits::IsField;
 use crate::field::traits::IsPrimeField;
 #[cfg(feature = "alloc")]
@@ -189,6 +190,24 @@ impl<const MODULUS: u32> IsField for U32MontgomeryBackendPrimeField<MODULUS> {
     fn from_base_type(x: Self::BaseType) -> Self::BaseType {
         MontgomeryAlgorithms::mul(&x, &Self::R2, &MODULUS, &Self::MU)
     }
+    #[inline(always)]
+    fn fft_butterflies<S: crate::field::traits::IsSubFieldOf<Self>>(
+        top: &mut [FieldElement<Self>],
+        bottom: &mut [FieldElement<Self>],
+        w: &FieldElement<S>,
+    ) {
+        packed::fft_butterflies::<PackedU32MontgomeryBackendPrimeField<MODULUS>, S>(top, bottom, w)
+    }
+
+    fn evaluate_polynomial<S: crate::field::traits::IsSubFieldOf<Self>>(
+        coefficients: &[FieldElement<S>],
+        x: &FieldElement<Self>,
+    ) -> FieldElement<Self> {
+        packed::evaluate_polynomial::<PackedU32MontgomeryBackendPrimeField<MODULUS>, S>(
+            coefficients,
+            x,
+        )
+    }
 }
 
 impl<const MODULUS: u32> IsPrimeField for U32MontgomeryBackendPrimeField<MODULUS> {
@@ -1,15 +1,20 @@
 use core::fmt::{self, Display};
 
-#[cfg(feature = "lambdaworks-serde-binary")]
-use crate::traits::ByteConversion;
+#[cfg(feature = "alloc")]
+use crate::traits::AsBytes;
 use crate::{
-    errors::CreationError,
+    errors::{ByteConversionError, CreationError},
     field::{
         element::FieldElement,
         errors::FieldError,
-        extensions::quadratic::{HasQuadraticNonResidue, QuadraticExtensionField},
-        traits::{IsField, IsPrimeField},
+        extensions::{
+            cubic::{CubicExtensionField, HasCubicNonResidue},
+            quadratic::{HasQuadraticNonResidue, QuadraticExtensionField},
+        },
+        packed::{self, goldilocks::PackedGoldilocks64Field},
+        traits::{IsFFTField, IsField, IsPrimeField},
     },
+    traits::ByteConversion,
 };
 
 /// Goldilocks Prime Field F_p where p = 2^64 - 2^32 + 1;
@@ -26,26 +31,34 @@ impl Goldilocks64Field {
 impl ByteConversion for u64 {
     #[cfg(feature = "alloc")]
     fn to_bytes_be(&self) -> alloc::vec::Vec<u8> {
-        unimplemented!()
+        u64::to_be_bytes(*self).into()
     }
 
     #[cfg(feature = "alloc")]
     fn to_bytes_le(&self) -> alloc::vec::Vec<u8> {
-        unimplemented!()
+        u64::to_le_bytes(*self).into()
     }
 
-    fn from_bytes_be(_bytes: &[u8]) -> Result<Self, crate::errors::ByteConversionError>
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError>
     where
         Self: Sized,
     {
-        unimplemented!()
+        let bytes: [u8; 8] = bytes
+            .get(0..8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(ByteConversionError::FromBEBytesError)?;
+        Ok(u64::from_be_bytes(bytes))
     }
 
-    fn from_bytes_le(_bytes: &[u8]) -> Result<Self, crate::errors::ByteConversionError>
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError>
     where
         Self: Sized,
     {
-        unimplemented!()
+        let bytes: [u8; 8] = bytes
+            .get(0..8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(ByteConversionError::FromLEBytesError)?;
+        Ok(u64::from_le_bytes(bytes))
     }
 }
 
@@ -148,6 +161,12 @@ impl IsField for Goldilocks64Field {
     fn from_base_type(x: u64) -> u64 {
         Self::representative(&x)
     }
+    fn evaluate_polynomial<S: crate::field::traits::IsSubFieldOf<Self>>(
+        coefficients: &[FieldElement<S>],
+        x: &FieldElement<Self>,
+    ) -> FieldElement<Self> {
+        packed::evaluate_polynomial::<PackedGoldilocks64Field, S>(coefficients, x)
+    }
 }
 
 impl IsPrimeField for Goldilocks64Field {
@@ -184,6 +203,53 @@ impl IsPrimeField for Goldilocks64Field {
     }
 }
 
+// p = 2^64 - 2^32 + 1 = 2^32 * (2^32 - 1) + 1, so there is a subgroup of order 2^32.
+// 7 generates the multiplicative group, and 7^((p - 1) / 2^32) is a primitive 2^32-th root of unity.
+impl IsFFTField for Goldilocks64Field {
+    const TWO_ADICITY: u64 = 32;
+
+    const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: Self::BaseType = 0x185629DCDA58878C;
+
+    fn field_name() -> &'static str {
+        "goldilocks64"
+    }
+}
+
+impl ByteConversion for FieldElement<Goldilocks64Field> {
+    #[cfg(feature = "alloc")]
+    fn to_bytes_be(&self) -> alloc::vec::Vec<u8> {
+        u64::to_be_bytes(self.representative()).into()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_bytes_le(&self) -> alloc::vec::Vec<u8> {
+        u64::to_le_bytes(self.representative()).into()
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let bytes: [u8; 8] = bytes
+            .get(0..8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(ByteConversionError::FromBEBytesError)?;
+        Ok(Self::from(u64::from_be_bytes(bytes)))
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        let bytes: [u8; 8] = bytes
+            .get(0..8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(ByteConversionError::FromLEBytesError)?;
+        Ok(Self::from(u64::from_le_bytes(bytes)))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsBytes for FieldElement<Goldilocks64Field> {
+    fn as_bytes(&self) -> alloc::vec::Vec<u8> {
+        self.to_bytes_be()
+    }
+}
+
 #[inline(always)]
 fn reduce_128(x: u128) -> u64 {
     //possibly split apart into separate function to ensure inline
@@ -226,6 +292,159 @@ impl HasQuadraticNonResidue<Goldilocks64Field> for Goldilocks64Field {
     }
 }
 
+// p^2 - 1 = (p - 1) * (p + 1) and (p + 1) / 2 is odd, so the quadratic extension has a
+// subgroup of order 2^33. Its generator is t^((p - 1) / 2^32), where t is the square root of 7.
+impl IsFFTField for Goldilocks64ExtensionField {
+    const TWO_ADICITY: u64 = 33;
+
+    const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: Self::BaseType = [
+        FieldElement::const_from_raw(0),
+        FieldElement::const_from_raw(0xD95051A31CF4A6EF),
+    ];
+}
+
+impl ByteConversion for FieldElement<Goldilocks64ExtensionField> {
+    #[cfg(feature = "alloc")]
+    fn to_bytes_be(&self) -> alloc::vec::Vec<u8> {
+        let mut byte_slice = ByteConversion::to_bytes_be(&self.value()[0]);
+        byte_slice.extend(ByteConversion::to_bytes_be(&self.value()[1]));
+        byte_slice
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_bytes_le(&self) -> alloc::vec::Vec<u8> {
+        let mut byte_slice = ByteConversion::to_bytes_le(&self.value()[0]);
+        byte_slice.extend(ByteConversion::to_bytes_le(&self.value()[1]));
+        byte_slice
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        const BYTES_PER_FIELD: usize = 8;
+        let x0 = FieldElement::from_bytes_be(
+            bytes
+                .get(0..BYTES_PER_FIELD)
+                .ok_or(ByteConversionError::FromBEBytesError)?,
+        )?;
+        let x1 = FieldElement::from_bytes_be(
+            bytes
+                .get(BYTES_PER_FIELD..BYTES_PER_FIELD * 2)
+                .ok_or(ByteConversionError::FromBEBytesError)?,
+        )?;
+        Ok(Self::new([x0, x1]))
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        const BYTES_PER_FIELD: usize = 8;
+        let x0 = FieldElement::from_bytes_le(
+            bytes
+                .get(0..BYTES_PER_FIELD)
+                .ok_or(ByteConversionError::FromLEBytesError)?,
+        )?;
+        let x1 = FieldElement::from_bytes_le(
+            bytes
+                .get(BYTES_PER_FIELD..BYTES_PER_FIELD * 2)
+                .ok_or(ByteConversionError::FromLEBytesError)?,
+        )?;
+        Ok(Self::new([x0, x1]))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsBytes for FieldElement<Goldilocks64ExtensionField> {
+    fn as_bytes(&self) -> alloc::vec::Vec<u8> {
+        self.to_bytes_be()
+    }
+}
+
+pub type Goldilocks64CubicExtensionField =
+    CubicExtensionField<Goldilocks64Field, Goldilocks64Field>;
+
+impl HasCubicNonResidue<Goldilocks64Field> for Goldilocks64Field {
+    // 7 generates the multiplicative group and 3 divides p - 1, so 7 is not a cube.
+    // Verifiable in Sage with
+    // `R.<x> = GF(p)[]; assert (x^3 - 7).is_irreducible()`
+    fn residue() -> FieldElement<Goldilocks64Field> {
+        FieldElement::from(Goldilocks64Field::from_u64(7u64))
+    }
+}
+
+// p^3 - 1 = (p - 1) * (p^2 + p + 1) and p^2 + p + 1 is odd, so the roots of unity
+// of the cubic extension are the ones of the base field.
+impl IsFFTField for Goldilocks64CubicExtensionField {
+    const TWO_ADICITY: u64 = 32;
+
+    const TWO_ADIC_PRIMITVE_ROOT_OF_UNITY: Self::BaseType = [
+        FieldElement::const_from_raw(Goldilocks64Field::TWO_ADIC_PRIMITVE_ROOT_OF_UNITY),
+        FieldElement::const_from_raw(0),
+        FieldElement::const_from_raw(0),
+    ];
+}
+
+impl ByteConversion for FieldElement<Goldilocks64CubicExtensionField> {
+    #[cfg(feature = "alloc")]
+    fn to_bytes_be(&self) -> alloc::vec::Vec<u8> {
+        let mut byte_slice = ByteConversion::to_bytes_be(&self.value()[0]);
+        byte_slice.extend(ByteConversion::to_bytes_be(&self.value()[1]));
+        byte_slice.extend(ByteConversion::to_bytes_be(&self.value()[2]));
+        byte_slice
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_bytes_le(&self) -> alloc::vec::Vec<u8> {
+        let mut byte_slice = ByteConversion::to_bytes_le(&self.value()[0]);
+        byte_slice.extend(ByteConversion::to_bytes_le(&self.value()[1]));
+        byte_slice.extend(ByteConversion::to_bytes_le(&self.value()[2]));
+        byte_slice
+    }
+
+    fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        const BYTES_PER_FIELD: usize = 8;
+        let x0 = FieldElement::from_bytes_be(
+            bytes
+                .get(0..BYTES_PER_FIELD)
+                .ok_or(ByteConversionError::FromBEBytesError)?,
+        )?;
+        let x1 = FieldElement::from_bytes_be(
+            bytes
+                .get(BYTES_PER_FIELD..BYTES_PER_FIELD * 2)
+                .ok_or(ByteConversionError::FromBEBytesError)?,
+        )?;
+        let x2 = FieldElement::from_bytes_be(
+            bytes
+                .get(BYTES_PER_FIELD * 2..BYTES_PER_FIELD * 3)
+                .ok_or(ByteConversionError::FromBEBytesError)?,
+        )?;
+        Ok(Self::new([x0, x1, x2]))
+    }
+
+    fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+        const BYTES_PER_FIELD: usize = 8;
+        let x0 = FieldElement::from_bytes_le(
+            bytes
+                .get(0..BYTES_PER_FIELD)
+                .ok_or(ByteConversionError::FromLEBytesError)?,
+        )?;
+        let x1 = FieldElement::from_bytes_le(
+            bytes
+                .get(BYTES_PER_FIELD..BYTES_PER_FIELD * 2)
+                .ok_or(ByteConversionError::FromLEBytesError)?,
+        )?;
+        let x2 = FieldElement::from_bytes_le(
+            bytes
+                .get(BYTES_PER_FIELD * 2..BYTES_PER_FIELD * 3)
+                .ok_or(ByteConversionError::FromLEBytesError)?,
+        )?;
+        Ok(Self::new([x0, x1, x2]))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsBytes for FieldElement<Goldilocks64CubicExtensionField> {
+    fn as_bytes(&self) -> alloc::vec::Vec<u8> {
+        self.to_bytes_be()
+    }
+}
+
 impl Display for FieldElement<Goldilocks64Field> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:x}", self.representative())?;
@@ -485,4 +704,128 @@ mod tests {
         let num = F::from_hex("B").unwrap();
         assert_eq!(F::to_hex(&num), "B");
     }
+
+    #[test]
+    fn two_adic_primitive_root_of_unity_has_order_two_to_the_32() {
+        let root = FieldElement::<F>::from(F::TWO_ADIC_PRIMITVE_ROOT_OF_UNITY);
+        assert_eq!(root.pow(1u64 << 32), FieldElement::one());
+        assert_ne!(root.pow(1u64 << 31), FieldElement::one());
+    }
+
+    #[test]
+    fn extension_two_adic_primitive_root_of_unity_has_order_two_to_the_33() {
+        let root = FieldElement::<Goldilocks64ExtensionField>::new(
+            Goldilocks64ExtensionField::TWO_ADIC_PRIMITVE_ROOT_OF_UNITY,
+        );
+        assert_eq!(root.pow(1u64 << 33), FieldElement::one());
+        assert_ne!(root.pow(1u64 << 32), FieldElement::one());
+    }
+
+    #[test]
+    fn cubic_extension_two_adic_primitive_root_of_unity_has_order_two_to_the_32() {
+        let root = FieldElement::<Goldilocks64CubicExtensionField>::new(
+            Goldilocks64CubicExtensionField::TWO_ADIC_PRIMITVE_ROOT_OF_UNITY,
+        );
+        assert_eq!(root.pow(1u64 << 32), FieldElement::one());
+        assert_ne!(root.pow(1u64 << 31), FieldElement::one());
+    }
+
+    #[test]
+    fn cubic_extension_mul_by_inv_is_one() {
+        let a = FieldElement::<Goldilocks64CubicExtensionField>::new([
+            FieldElement::from(3),
+            FieldElement::from(5),
+            FieldElement::from(7),
+        ]);
+        assert_eq!(&a * a.inv().unwrap(), FieldElement::one());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn byte_serialization_and_deserialization_works_be() {
+        let element = FieldElement::<F>::from(0x0123_4567_89AB_CDEF_u64);
+        let bytes = element.to_bytes_be();
+        assert_eq!(bytes, 0x0123_4567_89AB_CDEF_u64.to_be_bytes());
+        assert_eq!(FieldElement::<F>::from_bytes_be(&bytes).unwrap(), element);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn byte_serialization_and_deserialization_works_le() {
+        let element = FieldElement::<F>::from(0x0123_4567_89AB_CDEF_u64);
+        let bytes = element.to_bytes_le();
+        assert_eq!(bytes, 0x0123_4567_89AB_CDEF_u64.to_le_bytes());
+        assert_eq!(FieldElement::<F>::from_bytes_le(&bytes).unwrap(), element);
+    }
+
+    #[test]
+    fn from_bytes_reduces_values_bigger_than_the_order() {
+        let element = FieldElement::<F>::from_bytes_be(&u64::MAX.to_be_bytes()).unwrap();
+        assert_eq!(element.representative(), u32::MAX as u64 - 1);
+    }
+
+    #[test]
+    fn from_bytes_with_too_few_bytes_errors() {
+        assert!(FieldElement::<F>::from_bytes_be(&[1, 2, 3]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn extension_byte_serialization_and_deserialization_works() {
+        let element = FieldElement::<Goldilocks64ExtensionField>::new([
+            FieldElement::from(12345),
+            FieldElement::from(F::ORDER - 1),
+        ]);
+        let bytes = element.to_bytes_be();
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(
+            FieldElement::<Goldilocks64ExtensionField>::from_bytes_be(&bytes).unwrap(),
+            element
+        );
+    }
+
+    #[test]
+    fn extension_from_bytes_with_too_few_bytes_errors() {
+        let bytes = [1u8; 23];
+        assert!(FieldElement::<Goldilocks64ExtensionField>::from_bytes_be(&bytes[..15]).is_err());
+        assert!(FieldElement::<Goldilocks64ExtensionField>::from_bytes_le(&bytes[..15]).is_err());
+        assert!(FieldElement::<Goldilocks64CubicExtensionField>::from_bytes_be(&bytes).is_err());
+        assert!(FieldElement::<Goldilocks64CubicExtensionField>::from_bytes_le(&bytes).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn fft_evaluation_matches_naive_evaluation() {
+        use crate::polynomial::Polynomial;
+
+        let poly = Polynomial::new(&[
+            FieldElement::<F>::from(1),
+            FieldElement::from(2),
+            FieldElement::from(3),
+            FieldElement::from(4),
+        ]);
+        let evaluations = Polynomial::evaluate_fft::<F>(&poly, 1, None).unwrap();
+        let root = F::get_primitive_root_of_unity(2).unwrap();
+        let naive: alloc::vec::Vec<_> = (0..4u64).map(|i| poly.evaluate(&root.pow(i))).collect();
+        assert_eq!(evaluations, naive);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn fft_over_the_extension_interpolates_back() {
+        use crate::polynomial::Polynomial;
+
+        type Fp2E = FieldElement<Goldilocks64ExtensionField>;
+        let poly = Polynomial::new(&[
+            Fp2E::new([FieldElement::from(1), FieldElement::from(2)]),
+            Fp2E::new([FieldElement::from(3), FieldElement::from(4)]),
+            Fp2E::new([FieldElement::from(5), FieldElement::from(6)]),
+            Fp2E::new([FieldElement::from(7), FieldElement::from(8)]),
+        ]);
+        let evaluations =
+            Polynomial::evaluate_fft::<Goldilocks64ExtensionField>(&poly, 1, None).unwrap();
+        let interpolated =
+            Polynomial::interpolate_fft::<Goldilocks64ExtensionField>(&evaluations).unwrap();
+        assert_eq!(interpolated, poly);
+    }
 }
@@ -5,6 +5,8 @@ pub mod errors;
 pub mod extensions;
 /// Implementation of particular cases of fields.
 pub mod fields;
+/// Packed representations of small fields, to operate on several elements at once.
+pub mod packed;
 /// Field for test purposes.
 pub mod test_fields;
 /// Common behaviour for field elements.
@@ -0,0 +1,155 @@
+//! Lane-wise arithmetic over arrays of `u32` for prime fields with modulus below `2^31`, and
+//! over arrays of `u64` for the Goldilocks field.
+//!
+//! The functions over `u32` take and return arrays of [`U32_WIDTH`] lanes. The backend is chosen
+//! at compile time from the enabled target features: AVX-512 (16 lanes), AVX2 (8 lanes),
+//! NEON (4 lanes) or a portable implementation (8 lanes) written without branches so the
+//! compiler can vectorize it. They expect their inputs in `[0, p]` and return values in `[0, p]`.
+//!
+//! The Goldilocks functions take and return arrays of [`U64_WIDTH`] lanes: 8 with AVX-512 and
+//! 4 otherwise. None of these instruction sets multiply 64-bit lanes into 128 bits, so the
+//! products are assembled from the products of the 32-bit halves. They accept any `u64` and
+//! return canonical values, like the scalar field.
+
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+mod x86_avx512;
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+pub use x86_avx512::*;
+
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "avx2",
+    not(target_feature = "avx512f")
+))]
+mod x86_avx2;
+#[cfg(all(
+    target_arch = "x86_64",
+    target_feature = "avx2",
+    not(target_feature = "avx512f")
+))]
+pub use x86_avx2::*;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod aarch64_neon;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+pub use aarch64_neon::*;
+
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "avx2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+mod portable;
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "avx2"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+pub use portable::*;
+
+/// Number of 64-bit lanes of the packed fields over `u64`.
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f"))]
+pub const U64_WIDTH: usize = 8;
+/// Number of 64-bit lanes of the packed fields over `u64`.
+#[cfg(not(all(target_arch = "x86_64", target_feature = "avx512f")))]
+pub const U64_WIDTH: usize = 4;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MERSENNE_31: u32 = (1 << 31) - 1;
+    const BABYBEAR: u32 = 2013265921;
+
+    fn lanes(start: u32, step: u32, p: u32) -> [u32; U32_WIDTH] {
+        core::array::from_fn(|i| (start as u64 + step as u64 * i as u64) as u32 % p)
+    }
+
+    fn expected(
+        a: &[u32; U32_WIDTH],
+        b: &[u32; U32_WIDTH],
+        op: impl Fn(u64, u64) -> u64,
+    ) -> [u32; U32_WIDTH] {
+        core::array::from_fn(|i| op(a[i] as u64, b[i] as u64) as u32)
+    }
+
+    fn canonical(x: [u32; U32_WIDTH], p: u32) -> [u32; U32_WIDTH] {
+        x.map(|x| if x == p { 0 } else { x })
+    }
+
+    #[test]
+    fn lane_wise_operations_match_the_scalar_ones() {
+        let p = MERSENNE_31 as u64;
+        for (a, b) in [
+            (
+                lanes(0, 1, MERSENNE_31),
+                lanes(MERSENNE_31 - 1, 7, MERSENNE_31),
+            ),
+            (
+                lanes(1 << 30, 123456789, MERSENNE_31),
+                lanes(3, 987654321, MERSENNE_31),
+            ),
+        ] {
+            assert_eq!(
+                canonical(add_mod(&a, &b, MERSENNE_31), MERSENNE_31),
+                expected(&a, &b, |x, y| (x + y) % p)
+            );
+            assert_eq!(
+                canonical(sub_mod(&a, &b, MERSENNE_31), MERSENNE_31),
+                expected(&a, &b, |x, y| (x + p - y) % p)
+            );
+            assert_eq!(
+                canonical(mul_mersenne31(&a, &b), MERSENNE_31),
+                expected(&a, &b, |x, y| x * y % p)
+            );
+        }
+    }
+
+    #[test]
+    fn montgomery_multiplication_matches_the_scalar_one() {
+        use crate::field::fields::u32_montgomery_backend_prime_field::{
+            MontgomeryAlgorithms, U32MontgomeryBackendPrimeField,
+        };
+        const BABYBEAR_MU: u32 = U32MontgomeryBackendPrimeField::<BABYBEAR>::MU;
+
+        let a = lanes(5, 191919191, BABYBEAR);
+        let b = lanes(BABYBEAR - 1, 77777, BABYBEAR);
+        let expected: [u32; U32_WIDTH] = core::array::from_fn(|i| {
+            MontgomeryAlgorithms::mul(&a[i], &b[i], &BABYBEAR, &BABYBEAR_MU)
+        });
+
+        assert_eq!(mul_montgomery(&a, &b, BABYBEAR, BABYBEAR_MU), expected);
+    }
+
+    #[test]
+    fn goldilocks_operations_match_the_scalar_ones() {
+        use crate::field::{fields::u64_goldilocks_field::Goldilocks64Field, traits::IsField};
+
+        const P: u64 = Goldilocks64Field::ORDER;
+        // The edge cases around `p` and `2^64`, followed by pseudorandom values.
+        let values: [u64; 16] = core::array::from_fn(|i| match i {
+            0 => 0,
+            1 => 1,
+            2 => P - 1,
+            3 => P,
+            4 => P + 1,
+            5 => u64::MAX,
+            6 => 1 << 32,
+            7 => (1 << 32) - 1,
+            _ => (i as u64)
+                .wrapping_mul(0x9e3779b97f4a7c15)
+                .rotate_left(i as u32),
+        });
+        for i in 0..values.len() {
+            for j in 0..values.len() {
+                let a: [u64; U64_WIDTH] = core::array::from_fn(|k| values[(i + k) % values.len()]);
+                let b: [u64; U64_WIDTH] =
+                    core::array::from_fn(|k| values[(j + 3 * k) % values.len()]);
+                let expected = |op: fn(&u64, &u64) -> u64| -> [u64; U64_WIDTH] {
+                    core::array::from_fn(|k| op(&a[k], &b[k]))
+                };
+                assert_eq!(add_goldilocks(&a, &b), expected(Goldilocks64Field::add));
+                assert_eq!(sub_goldilocks(&a, &b), expected(Goldilocks64Field::sub));
+                assert_eq!(mul_goldilocks(&a, &b), expected(Goldilocks64Field::mul));
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+// The intrinsics are safe to call when the target feature is enabled in newer compilers, so
+// some of the `unsafe` blocks below are only required by older ones.
+#![allow(unused_unsafe)]
+
+use super::U64_WIDTH;
+use core::arch::aarch64::*;
+
+/// Whether the operations are done with SIMD instructions.
+pub const HAS_SIMD_BACKEND: bool = true;
+
+/// Number of 32-bit lanes of the packed fields over `u32`.
+pub const U32_WIDTH: usize = 4;
+
+#[inline(always)]
+fn load(a: &[u32; U32_WIDTH]) -> uint32x4_t {
+    // SAFETY: `a` is valid for reads of 128 bits.
+    unsafe { vld1q_u32(a.as_ptr()) }
+}
+
+#[inline(always)]
+fn store(a: uint32x4_t) -> [u32; U32_WIDTH] {
+    let mut out = [0u32; U32_WIDTH];
+    // SAFETY: `out` is valid for writes of 128 bits.
+    unsafe { vst1q_u32(out.as_mut_ptr(), a) };
+    out
+}
+
+/// Returns `a + b mod p`, lane by lane.
+#[inline(always)]
+pub fn add_mod(a: &[u32; U32_WIDTH], b: &[u32; U32_WIDTH], p: u32) -> [u32; U32_WIDTH] {
+    unsafe {
+        let sum = vaddq_u32(load(a), load(b));
+        store(vminq_u32(sum, vsubq_u32(sum, vdupq_n_u32(p))))
+    }
+}
+
+/// Returns `a - b mod p`, lane by lane.
+#[inline(always)]
+pub fn sub_mod(a: &[u32; U32_WIDTH], b: &[u32; U32_WIDTH], p: u32) -> [u32; U32_WIDTH] {
+    unsafe {
+        let diff = vsubq_u32(load(a), load(b));
+        store(vminq_u32(diff, vaddq_u32(diff, vdupq_n_u32(p))))
+    }
+}
+
+/// Returns `a * b mod 2^31 - 1`, lane by lane.
+#[inline(always)]
+pub fn mul_mersenne31(a: &[u32; U32_WIDTH], b: &[u32; U32_WIDTH]) -> [u32; U32_WIDTH] {
+    const P: u32 = (1 << 31) - 1;
+    unsafe {
+        let (a, b) = (load(a), load(b));
+        let low = vmull_u32(vget_low_u32(a), vget_low_u32(b));
+        let high = vmull_high_u32(a, b);
+
+        let mask = vdupq_n_u64(P as u64);
+        let low = vaddq_u64(vandq_u64(low, mask), vshrq_n_u64::<31>(low));
+        let high = vaddq_u64(vandq_u64(high, mask), vshrq_n_u64::<31>(high));
+
+        let reduced = vcombine_u32(vmovn_u64(low), vmovn_u64(high));
+        store(vminq_u32(reduced, vsubq_u32(reduced, vdupq_n_u32(P))))
+    }
+}
+
+/// Returns the Montgomery product `a * b * 2^-32 mod p`, lane by lane, where `mu = p^-1 mod 2^32`.
+#[inline(always)]
+pub fn mul_montgomery(
+    a: &[u32; U32_WIDTH],
+    b: &[u32; U32_WIDTH],
+    p: u32,
+    mu: u32,
+) -> [u32; U32_WIDTH] {
+    unsafe {
+        let (a, b) = (load(a), load(b));
+        let low = vmull_u32(vget_low_u32(a), vget_low_u32(b));
+        let high = vmull_high_u32(a, b);
+
+        let t = vmulq_u32(
+            vcombine_u32(vmovn_u64(low), vmovn_u64(high)),
+            vdupq_n_u32(mu),
+        );
+        let u_low = vmull_u32(vget_low_u32(t), vdup_n_u32(p));
+        let u_high = vmull_high_u32(t, vdupq_n_u32(p));
+
+        // The low halves of the products and `u` are equal, so only the high ones are subtracted.
+        let product_hi = vcombine_u32(vshrn_n_u64::<32>(low), vshrn_n_u64::<32>(high));
+        let u_hi = vcombine_u32(vshrn_n_u64::<32>(u_low), vshrn_n_u64::<32>(u_high));
+        let diff = vsubq_u32(product_hi, u_hi);
+        store(vminq_u32(diff, vaddq_u32(diff, vdupq_n_u32(p))))
+    }
+}
+
+/// `2^32 - 1`, which is `2^64 mod p` for the Goldilocks prime `p = 2^64 - 2^32 + 1`.
+const GOLDILOCKS_EPSILON: u64 = (1 << 32) - 1;
+const GOLDILOCKS_ORDER: u64 = GOLDILOCKS_EPSILON.wrapping_neg();
+
+/// Loads the lanes into two registers of two lanes each.
+#[inline(always)]
+fn load_u64(a: &[u64; U64_WIDTH]) -> [uint64x2_t; 2] {
+    // SAFETY: `a` is valid for reads of 256 bits.
+    unsafe { [vld1q_u64(a.as_ptr()), vld1q_u64(a.as_ptr().add(2))] }
+}
+
+#[inline(always)]
+fn store_u64(a: [uint64x2_t; 2]) -> [u64; U64_WIDTH] {
+    let mut out = [0u64; U64_WIDTH];
+    // SAFETY: `out` is valid for writes of 256 bits.
+    unsafe {
+        vst1q_u64(out.as_mut_ptr(), a[0]);
+        vst1q_u64(out.as_mut_ptr().add(2), a[1]);
+    }
+    out
+}
+
+/// Subtracts `p` from the lanes that are not smaller than it.
+#[inline(always)]
+fn canonicalize_goldilocks(a: uint64x2_t) -> uint64x2_t {
+    unsafe {
+        let p = vdupq_n_u64(GOLDILOCKS_ORDER);
+        vsubq_u64(a, vandq_u64(vcgeq_u64(a, p), p))
+    }
+}
+
+#[inline(always)]
+fn add_goldilocks_x2(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    unsafe {
+        let epsilon = vdupq_n_u64(GOLDILOCKS_EPSILON);
+        let (a, b) = (canonicalize_goldilocks(a), canonicalize_goldilocks(b));
+        // On overflow the sum lost `2^64 = p + epsilon`, and adding `epsilon` back can't overflow.
+        let sum = vaddq_u64(a, b);
+        let sum = vaddq_u64(sum, vandq_u64(vcltq_u64(sum, a), epsilon));
+        canonicalize_goldilocks(sum)
+    }
+}
+
+#[inline(always)]
+fn sub_goldilocks_x2(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    unsafe {
+        let epsilon = vdupq_n_u64(GOLDILOCKS_EPSILON);
+        let (a, b) = (canonicalize_goldilocks(a), canonicalize_goldilocks(b));
+        // On underflow the difference gained `2^64 = p + epsilon`, so `epsilon` is taken away.
+        let diff = vsubq_u64(a, b);
+        vsubq_u64(diff, vandq_u64(vcltq_u64(a, b), epsilon))
+    }
+}
+
+#[inline(always)]
+fn mul_goldilocks_x2(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    unsafe {
+        let epsilon = vdupq_n_u64(GOLDILOCKS_EPSILON);
+
+        // The 128-bit products are assembled from the products of the 32-bit halves.
+        let (a_lo, a_hi) = (vmovn_u64(a), vshrn_n_u64::<32>(a));
+        let (b_lo, b_hi) = (vmovn_u64(b), vshrn_n_u64::<32>(b));
+        let lo_lo = vmull_u32(a_lo, b_lo);
+        let lo_hi = vmull_u32(a_lo, b_hi);
+        let hi_lo = vmull_u32(a_hi, b_lo);
+        let hi_hi = vmull_u32(a_hi, b_hi);
+        let middle = vaddq_u64(
+            vshrq_n_u64::<32>(lo_lo),
+            vaddq_u64(vandq_u64(lo_hi, epsilon), vandq_u64(hi_lo, epsilon)),
+        );
+        let lo = vorrq_u64(vandq_u64(lo_lo, epsilon), vshlq_n_u64::<32>(middle));
+        let hi = vaddq_u64(
+            vaddq_u64(hi_hi, vshrq_n_u64::<32>(middle)),
+            vaddq_u64(vshrq_n_u64::<32>(lo_hi), vshrq_n_u64::<32>(hi_lo)),
+        );
+
+        // `lo + 2^64 hi = lo - hi_hi + epsilon hi_lo`, where `hi = 2^32 hi_hi + hi_lo`.
+        let hi_hi = vshrq_n_u64::<32>(hi);
+        let t0 = vsubq_u64(lo, hi_hi);
+        let t0 = vsubq_u64(t0, vandq_u64(vcltq_u64(lo, hi_hi), epsilon));
+        let hi_lo = vandq_u64(hi, epsilon);
+        let t1 = vsubq_u64(vshlq_n_u64::<32>(hi_lo), hi_lo);
+        let sum = vaddq_u64(t0, t1);
+        let sum = vaddq_u64(sum, vandq_u64(vcltq_u64(sum, t0), epsilon));
+        canonicalize_goldilocks(sum)
+    }
+}
+
+/// Returns `a + b mod 2^64 - 2^32 + 1`, lane by lane.
+#[inline(always)]
+pub fn add_goldilocks(a: &[u64; U64_WIDTH], b: &[u64; U64_WIDTH]) -> [u64; U64_WIDTH] {
+    let ([a0, a1], [b0, b1]) = (load_u64(a), load_u64(b));
+    store_u64([add_goldilocks_x2(a0, b0), add_goldilocks_x2(a1, b1)])
+}
+
+/// Returns `a - b mod 2^64 - 2^32 + 1`, lane by lane.
+#[inline(always)]
+pub fn sub_goldilocks(a: &[u64; U64_WIDTH], b: &[u64; U64_WIDTH]) -> [u64; U64_WIDTH] {
+    let ([a0, a1], [b0, b1]) = (load_u64(a), load_u64(b));
+    store_u64([sub_goldilocks_x2(a0, b0), sub_goldilocks_x2(a1, b1)])
+}
+
+/// Returns `a * b mod 2^64 - 2^32 + 1`, lane by lane.
+#[inline(always)]
+pub fn mul_goldilocks(a: &[u64; U64_WIDTH], b: &[u64; U64_WIDTH]) -> [u64; U64_WIDTH] {
+    let ([a0, a1], [b0, b1]) = (load_u64(a), load_u64(b));
+    store_u64([mul_goldilocks_x2(a0, b0), mul_goldilocks_x2(a1, b1)])
+}
@@ -0,0 +1,73 @@
+use super::U64_WIDTH;
+use crate::field::{fields::u64_goldilocks_field::Goldilocks64Field, traits::IsField};
+
+/// Whether the operations are done with SIMD instructions.
+pub const HAS_SIMD_BACKEND: bool = false;
+
+/// Number of 32-bit lanes of the packed fields over `u32`.
+pub const U32_WIDTH: usize = 8;
+
+/// Returns `a + b mod p`, lane by lane.
+#[inline(always)]
+pub fn add_mod(a: &[u32; U32_WIDTH], b: &[u32; U32_WIDTH], p: u32) -> [u32; U32_WIDTH] {
+    core::array::from_fn(|i| {
+        let sum = a[i] + b[i];
+        sum.min(sum.wrapping_sub(p))
+    })
+}
+
+/// Returns `a - b mod p`, lane by lane.
+#[inline(always)]
+pub fn sub_mod(a: &[u32; U32_WIDTH], b: &[u32; U32_WIDTH], p: u32) -> [u32; U32_WIDTH] {
+    core::array::from_fn(|i| {
+        let diff = a[i].wrapping_sub(b[i]);
+        diff.min(diff.wrapping_add(p))
+    })
+}
+
+/// Returns `a * b mod 2^31 - 1`, lane by lane.
+#[inline(always)]
+pub fn mul_mersenne31(a: &[u32; U32_WIDTH], b: &[u32; U32_WIDTH]) -> [u32; U32_WIDTH] {
+    const P: u32 = (1 << 31) - 1;
+    core::array::from_fn(|i| {
+        let product = a[i] as u64 * b[i] as u64;
+        let reduced = ((product & P as u64) + (product >> 31)) as u32;
+        reduced.min(reduced.wrapping_sub(P))
+    })
+}
+
+/// Returns the Montgomery product `a * b * 2^-32 mod p`, lane by lane, where `mu = p^-1 mod 2^32`.
+#[inline(always)]
+pub fn mul_montgomery(
+    a: &[u32; U32_WIDTH],
+    b: &[u32; U32_WIDTH],
+    p: u32,
+    mu: u32,
+) -> [u32; U32_WIDTH] {
+    core::array::from_fn(|i| {
+        let product = a[i] as u64 * b[i] as u64;
+        let t = (product as u32).wrapping_mul(mu);
+        let u = t as u64 * p as u64;
+        // The low halves of `product` and `u` are equal, so only the high ones are subtracted.
+        let diff = ((product >> 32) as u32).wrapping_sub((u >> 32) as u32);
+        diff.min(diff.wrapping_add(p))
+    })
+}
+
+/// Returns `a + b mod 2^64 - 2^32 + 1`, lane by lane.
+#[inline(always)]
+pub fn add_goldilocks(a: &[u64; U64_WIDTH], b: &[u64; U64_WIDTH]) -> [u64; U64_WIDTH] {
+    core::array::from_fn(|i| Goldilocks64Field::add(&a[i], &b[i]))
+}
+
+/// Returns `a - b mod 2^64 - 2^32 + 1`, lane by lane.
+#[inline(always)]
+pub fn sub_goldilocks(a: &[u64; U64_WIDTH], b: &[u64; U64_WIDTH]) -> [u64; U64_WIDTH] {
+    core::array::from_fn(|i| Goldilocks64Field::sub(&a[i], &b[i]))
+}
+
+/// Returns `a * b mod 2^64 - 2^32 + 1`, lane by lane.
+#[inline(always)]
+pub fn mul_goldilocks(a: &[u64; U64_WIDTH], b: &[u64; U64_WIDTH]) -> [u64; U64_WIDTH] {
+    core::array::from_fn(|i| Goldilocks64Field::mul(&a[i], &b[i]))
+}
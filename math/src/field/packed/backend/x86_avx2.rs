@@ -0,0 +1,201 @@
+// The intrinsics are safe to call when the target feature is enabled in newer compilers, so
+// some of the `unsafe` blocks below are only required by older ones.
+#![allow(unused_unsafe)]
+
+use super::U64_WIDTH;
+use core::arch::x86_64::*;
+
+/// Whether the operations are done with SIMD instructions.
+pub const HAS_SIMD_BACKEND: bool = true;
+
+/// Number of 32-bit lanes of the packed fields over `u32`.
+pub const U32_WIDTH: usize = 8;
+
+#[inline(always)]
+fn load(a: &[u32; U32_WIDTH]) -> __m256i {
+    // SAFETY: `a` is valid for reads of 256 bits and `loadu` has no alignment requirement.
+    unsafe { _mm256_loadu_si256(a.as_ptr().cast()) }
+}
+
+#[inline(always)]
+fn store(a: __m256i) -> [u32; U32_WIDTH] {
+    let mut out = [0u32; U32_WIDTH];
+    // SAFETY: `out` is valid for writes of 256 bits and `storeu` has no alignment requirement.
+    unsafe { _mm256_storeu_si256(out.as_mut_ptr().cast(), a) };
+    out
+}
+
+/// Returns `a + b mod p`, lane by lane.
+#[inline(always)]
+pub fn add_mod(a: &[u32; U32_WIDTH], b: &[u32; U32_WIDTH], p: u32) -> [u32; U32_WIDTH] {
+    unsafe {
+        let p = _mm256_set1_epi32(p as i32);
+        let sum = _mm256_add_epi32(load(a), load(b));
+        store(_mm256_min_epu32(sum, _mm256_sub_epi32(sum, p)))
+    }
+}
+
+/// Returns `a - b mod p`, lane by lane.
+#[inline(always)]
+pub fn sub_mod(a: &[u32; U32_WIDTH], b: &[u32; U32_WIDTH], p: u32) -> [u32; U32_WIDTH] {
+    unsafe {
+        let p = _mm256_set1_epi32(p as i32);
+        let diff = _mm256_sub_epi32(load(a), load(b));
+        store(_mm256_min_epu32(diff, _mm256_add_epi32(diff, p)))
+    }
+}
+
+/// Returns `a * b mod 2^31 - 1`, lane by lane.
+#[inline(always)]
+pub fn mul_mersenne31(a: &[u32; U32_WIDTH], b: &[u32; U32_WIDTH]) -> [u32; U32_WIDTH] {
+    const P: u32 = (1 << 31) - 1;
+    unsafe {
+        let (a, b) = (load(a), load(b));
+        // `mul_epu32` multiplies the even lanes, the odd ones are shifted into their place.
+        let even = _mm256_mul_epu32(a, b);
+        let odd = _mm256_mul_epu32(_mm256_srli_epi64::<32>(a), _mm256_srli_epi64::<32>(b));
+
+        let mask = _mm256_set1_epi64x(P as i64);
+        let even = _mm256_add_epi64(_mm256_and_si256(even, mask), _mm256_srli_epi64::<31>(even));
+        let odd = _mm256_add_epi64(_mm256_and_si256(odd, mask), _mm256_srli_epi64::<31>(odd));
+
+        let reduced = _mm256_blend_epi32::<0b10101010>(even, _mm256_slli_epi64::<32>(odd));
+        let p = _mm256_set1_epi32(P as i32);
+        store(_mm256_min_epu32(reduced, _mm256_sub_epi32(reduced, p)))
+    }
+}
+
+/// Returns the Montgomery product `a * b * 2^-32 mod p`, lane by lane, where `mu = p^-1 mod 2^32`.
+#[inline(always)]
+pub fn mul_montgomery(
+    a: &[u32; U32_WIDTH],
+    b: &[u32; U32_WIDTH],
+    p: u32,
+    mu: u32,
+) -> [u32; U32_WIDTH] {
+    unsafe {
+        let (a, b) = (load(a), load(b));
+        let p = _mm256_set1_epi32(p as i32);
+        let mu = _mm256_set1_epi32(mu as i32);
+
+        let even = _mm256_mul_epu32(a, b);
+        let odd = _mm256_mul_epu32(_mm256_srli_epi64::<32>(a), _mm256_srli_epi64::<32>(b));
+        let u_even = _mm256_mul_epu32(_mm256_mul_epu32(even, mu), p);
+        let u_odd = _mm256_mul_epu32(_mm256_mul_epu32(odd, mu), p);
+
+        // The low halves of the products and `u` are equal, so only the high ones are subtracted.
+        let product_hi = _mm256_blend_epi32::<0b10101010>(_mm256_srli_epi64::<32>(even), odd);
+        let u_hi = _mm256_blend_epi32::<0b10101010>(_mm256_srli_epi64::<32>(u_even), u_odd);
+        let diff = _mm256_sub_epi32(product_hi, u_hi);
+        store(_mm256_min_epu32(diff, _mm256_add_epi32(diff, p)))
+    }
+}
+
+/// `2^32 - 1`, which is `2^64 mod p` for the Goldilocks prime `p = 2^64 - 2^32 + 1`.
+const GOLDILOCKS_EPSILON: u64 = (1 << 32) - 1;
+const GOLDILOCKS_ORDER: u64 = GOLDILOCKS_EPSILON.wrapping_neg();
+
+#[inline(always)]
+fn load_u64(a: &[u64; U64_WIDTH]) -> __m256i {
+    // SAFETY: `a` is valid for reads of 256 bits and `loadu` has no alignment requirement.
+    unsafe { _mm256_loadu_si256(a.as_ptr().cast()) }
+}
+
+#[inline(always)]
+fn store_u64(a: __m256i) -> [u64; U64_WIDTH] {
+    let mut out = [0u64; U64_WIDTH];
+    // SAFETY: `out` is valid for writes of 256 bits and `storeu` has no alignment requirement.
+    unsafe { _mm256_storeu_si256(out.as_mut_ptr().cast(), a) };
+    out
+}
+
+/// Returns all ones in the lanes where `a < b` as unsigned integers. AVX2 only compares signed
+/// integers, so the sign bits are flipped first.
+#[inline(always)]
+fn lt_epu64(a: __m256i, b: __m256i) -> __m256i {
+    unsafe {
+        let sign = _mm256_set1_epi64x(i64::MIN);
+        _mm256_cmpgt_epi64(_mm256_xor_si256(b, sign), _mm256_xor_si256(a, sign))
+    }
+}
+
+/// Subtracts `p` from the lanes that are not smaller than it.
+#[inline(always)]
+fn canonicalize_goldilocks(a: __m256i) -> __m256i {
+    unsafe {
+        let p = _mm256_set1_epi64x(GOLDILOCKS_ORDER as i64);
+        _mm256_sub_epi64(a, _mm256_andnot_si256(lt_epu64(a, p), p))
+    }
+}
+
+/// Returns `a + b mod 2^64 - 2^32 + 1`, lane by lane.
+#[inline(always)]
+pub fn add_goldilocks(a: &[u64; U64_WIDTH], b: &[u64; U64_WIDTH]) -> [u64; U64_WIDTH] {
+    unsafe {
+        let epsilon = _mm256_set1_epi64x(GOLDILOCKS_EPSILON as i64);
+        let a = canonicalize_goldilocks(load_u64(a));
+        let b = canonicalize_goldilocks(load_u64(b));
+        // On overflow the sum lost `2^64 = p + epsilon`, and adding `epsilon` back can't overflow.
+        let sum = _mm256_add_epi64(a, b);
+        let sum = _mm256_add_epi64(sum, _mm256_and_si256(lt_epu64(sum, a), epsilon));
+        store_u64(canonicalize_goldilocks(sum))
+    }
+}
+
+/// Returns `a - b mod 2^64 - 2^32 + 1`, lane by lane.
+#[inline(always)]
+pub fn sub_goldilocks(a: &[u64; U64_WIDTH], b: &[u64; U64_WIDTH]) -> [u64; U64_WIDTH] {
+    unsafe {
+        let epsilon = _mm256_set1_epi64x(GOLDILOCKS_EPSILON as i64);
+        let a = canonicalize_goldilocks(load_u64(a));
+        let b = canonicalize_goldilocks(load_u64(b));
+        // On underflow the difference gained `2^64 = p + epsilon`, so `epsilon` is taken away.
+        let diff = _mm256_sub_epi64(a, b);
+        store_u64(_mm256_sub_epi64(
+            diff,
+            _mm256_and_si256(lt_epu64(a, b), epsilon),
+        ))
+    }
+}
+
+/// Returns `a * b mod 2^64 - 2^32 + 1`, lane by lane.
+#[inline(always)]
+pub fn mul_goldilocks(a: &[u64; U64_WIDTH], b: &[u64; U64_WIDTH]) -> [u64; U64_WIDTH] {
+    unsafe {
+        let (a, b) = (load_u64(a), load_u64(b));
+        let epsilon = _mm256_set1_epi64x(GOLDILOCKS_EPSILON as i64);
+
+        // The 128-bit products are assembled from the products of the 32-bit halves.
+        let a_hi = _mm256_srli_epi64::<32>(a);
+        let b_hi = _mm256_srli_epi64::<32>(b);
+        let lo_lo = _mm256_mul_epu32(a, b);
+        let lo_hi = _mm256_mul_epu32(a, b_hi);
+        let hi_lo = _mm256_mul_epu32(a_hi, b);
+        let hi_hi = _mm256_mul_epu32(a_hi, b_hi);
+        let middle = _mm256_add_epi64(
+            _mm256_srli_epi64::<32>(lo_lo),
+            _mm256_add_epi64(
+                _mm256_and_si256(lo_hi, epsilon),
+                _mm256_and_si256(hi_lo, epsilon),
+            ),
+        );
+        let lo = _mm256_blend_epi32::<0b10101010>(lo_lo, _mm256_slli_epi64::<32>(middle));
+        let hi = _mm256_add_epi64(
+            _mm256_add_epi64(hi_hi, _mm256_srli_epi64::<32>(middle)),
+            _mm256_add_epi64(
+                _mm256_srli_epi64::<32>(lo_hi),
+                _mm256_srli_epi64::<32>(hi_lo),
+            ),
+        );
+
+        // `lo + 2^64 hi = lo - hi_hi + epsilon hi_lo`, where `hi = 2^32 hi_hi + hi_lo`.
+        let hi_hi = _mm256_srli_epi64::<32>(hi);
+        let t0 = _mm256_sub_epi64(lo, hi_hi);
+        let t0 = _mm256_sub_epi64(t0, _mm256_and_si256(lt_epu64(lo, hi_hi), epsilon));
+        let hi_lo = _mm256_and_si256(hi, epsilon);
+        let t1 = _mm256_sub_epi64(_mm256_slli_epi64::<32>(hi_lo), hi_lo);
+        let sum = _mm256_add_epi64(t0, t1);
+        let sum = _mm256_add_epi64(sum, _mm256_and_si256(lt_epu64(sum, t0), epsilon));
+        store_u64(canonicalize_goldilocks(sum))
+    }
+}
@@ -0,0 +1,196 @@
+// The intrinsics are safe to call when the target feature is enabled in newer compilers, so
+// some of the `unsafe` blocks below are only required by older ones.
+#![allow(unused_unsafe)]
+
+use super::U64_WIDTH;
+use core::arch::x86_64::*;
+
+/// Whether the operations are done with SIMD instructions.
+pub const HAS_SIMD_BACKEND: bool = true;
+
+/// Number of 32-bit lanes of the packed fields over `u32`.
+pub const U32_WIDTH: usize = 16;
+
+/// Selects the odd 32-bit lanes in a blend.
+const ODD_LANES: __mmask16 = 0b1010101010101010;
+
+#[inline(always)]
+fn load(a: &[u32; U32_WIDTH]) -> __m512i {
+    // SAFETY: `a` is valid for reads of 512 bits and `loadu` has no alignment requirement.
+    unsafe { _mm512_loadu_si512(a.as_ptr().cast()) }
+}
+
+#[inline(always)]
+fn store(a: __m512i) -> [u32; U32_WIDTH] {
+    let mut out = [0u32; U32_WIDTH];
+    // SAFETY: `out` is valid for writes of 512 bits and `storeu` has no alignment requirement.
+    unsafe { _mm512_storeu_si512(out.as_mut_ptr().cast(), a) };
+    out
+}
+
+/// Returns `a + b mod p`, lane by lane.
+#[inline(always)]
+pub fn add_mod(a: &[u32; U32_WIDTH], b: &[u32; U32_WIDTH], p: u32) -> [u32; U32_WIDTH] {
+    unsafe {
+        let p = _mm512_set1_epi32(p as i32);
+        let sum = _mm512_add_epi32(load(a), load(b));
+        store(_mm512_min_epu32(sum, _mm512_sub_epi32(sum, p)))
+    }
+}
+
+/// Returns `a - b mod p`, lane by lane.
+#[inline(always)]
+pub fn sub_mod(a: &[u32; U32_WIDTH], b: &[u32; U32_WIDTH], p: u32) -> [u32; U32_WIDTH] {
+    unsafe {
+        let p = _mm512_set1_epi32(p as i32);
+        let diff = _mm512_sub_epi32(load(a), load(b));
+        store(_mm512_min_epu32(diff, _mm512_add_epi32(diff, p)))
+    }
+}
+
+/// Returns `a * b mod 2^31 - 1`, lane by lane.
+#[inline(always)]
+pub fn mul_mersenne31(a: &[u32; U32_WIDTH], b: &[u32; U32_WIDTH]) -> [u32; U32_WIDTH] {
+    const P: u32 = (1 << 31) - 1;
+    unsafe {
+        let (a, b) = (load(a), load(b));
+        // `mul_epu32` multiplies the even lanes, the odd ones are shifted into their place.
+        let even = _mm512_mul_epu32(a, b);
+        let odd = _mm512_mul_epu32(_mm512_srli_epi64::<32>(a), _mm512_srli_epi64::<32>(b));
+
+        let mask = _mm512_set1_epi64(P as i64);
+        let even = _mm512_add_epi64(_mm512_and_si512(even, mask), _mm512_srli_epi64::<31>(even));
+        let odd = _mm512_add_epi64(_mm512_and_si512(odd, mask), _mm512_srli_epi64::<31>(odd));
+
+        let reduced = _mm512_mask_blend_epi32(ODD_LANES, even, _mm512_slli_epi64::<32>(odd));
+        let p = _mm512_set1_epi32(P as i32);
+        store(_mm512_min_epu32(reduced, _mm512_sub_epi32(reduced, p)))
+    }
+}
+
+/// Returns the Montgomery product `a * b * 2^-32 mod p`, lane by lane, where `mu = p^-1 mod 2^32`.
+#[inline(always)]
+pub fn mul_montgomery(
+    a: &[u32; U32_WIDTH],
+    b: &[u32; U32_WIDTH],
+    p: u32,
+    mu: u32,
+) -> [u32; U32_WIDTH] {
+    unsafe {
+        let (a, b) = (load(a), load(b));
+        let p = _mm512_set1_epi32(p as i32);
+        let mu = _mm512_set1_epi32(mu as i32);
+
+        let even = _mm512_mul_epu32(a, b);
+        let odd = _mm512_mul_epu32(_mm512_srli_epi64::<32>(a), _mm512_srli_epi64::<32>(b));
+        let u_even = _mm512_mul_epu32(_mm512_mul_epu32(even, mu), p);
+        let u_odd = _mm512_mul_epu32(_mm512_mul_epu32(odd, mu), p);
+
+        // The low halves of the products and `u` are equal, so only the high ones are subtracted.
+        let product_hi = _mm512_mask_blend_epi32(ODD_LANES, _mm512_srli_epi64::<32>(even), odd);
+        let u_hi = _mm512_mask_blend_epi32(ODD_LANES, _mm512_srli_epi64::<32>(u_even), u_odd);
+        let diff = _mm512_sub_epi32(product_hi, u_hi);
+        store(_mm512_min_epu32(diff, _mm512_add_epi32(diff, p)))
+    }
+}
+
+/// `2^32 - 1`, which is `2^64 mod p` for the Goldilocks prime `p = 2^64 - 2^32 + 1`.
+const GOLDILOCKS_EPSILON: u64 = (1 << 32) - 1;
+const GOLDILOCKS_ORDER: u64 = GOLDILOCKS_EPSILON.wrapping_neg();
+
+#[inline(always)]
+fn load_u64(a: &[u64; U64_WIDTH]) -> __m512i {
+    // SAFETY: `a` is valid for reads of 512 bits and `loadu` has no alignment requirement.
+    unsafe { _mm512_loadu_si512(a.as_ptr().cast()) }
+}
+
+#[inline(always)]
+fn store_u64(a: __m512i) -> [u64; U64_WIDTH] {
+    let mut out = [0u64; U64_WIDTH];
+    // SAFETY: `out` is valid for writes of 512 bits and `storeu` has no alignment requirement.
+    unsafe { _mm512_storeu_si512(out.as_mut_ptr().cast(), a) };
+    out
+}
+
+/// Subtracts `p` from the lanes that are not smaller than it.
+#[inline(always)]
+fn canonicalize_goldilocks(a: __m512i) -> __m512i {
+    unsafe {
+        let p = _mm512_set1_epi64(GOLDILOCKS_ORDER as i64);
+        _mm512_mask_sub_epi64(a, _mm512_cmpge_epu64_mask(a, p), a, p)
+    }
+}
+
+/// Returns `a + b mod 2^64 - 2^32 + 1`, lane by lane.
+#[inline(always)]
+pub fn add_goldilocks(a: &[u64; U64_WIDTH], b: &[u64; U64_WIDTH]) -> [u64; U64_WIDTH] {
+    unsafe {
+        let epsilon = _mm512_set1_epi64(GOLDILOCKS_EPSILON as i64);
+        let a = canonicalize_goldilocks(load_u64(a));
+        let b = canonicalize_goldilocks(load_u64(b));
+        // On overflow the sum lost `2^64 = p + epsilon`, and adding `epsilon` back can't overflow.
+        let sum = _mm512_add_epi64(a, b);
+        let sum = _mm512_mask_add_epi64(sum, _mm512_cmplt_epu64_mask(sum, a), sum, epsilon);
+        store_u64(canonicalize_goldilocks(sum))
+    }
+}
+
+/// Returns `a - b mod 2^64 - 2^32 + 1`, lane by lane.
+#[inline(always)]
+pub fn sub_goldilocks(a: &[u64; U64_WIDTH], b: &[u64; U64_WIDTH]) -> [u64; U64_WIDTH] {
+    unsafe {
+        let epsilon = _mm512_set1_epi64(GOLDILOCKS_EPSILON as i64);
+        let a = canonicalize_goldilocks(load_u64(a));
+        let b = canonicalize_goldilocks(load_u64(b));
+        // On underflow the difference gained `2^64 = p + epsilon`, so `epsilon` is taken away.
+        let diff = _mm512_sub_epi64(a, b);
+        store_u64(_mm512_mask_sub_epi64(
+            diff,
+            _mm512_cmplt_epu64_mask(a, b),
+            diff,
+            epsilon,
+        ))
+    }
+}
+
+/// Returns `a * b mod 2^64 - 2^32 + 1`, lane by lane.
+#[inline(always)]
+pub fn mul_goldilocks(a: &[u64; U64_WIDTH], b: &[u64; U64_WIDTH]) -> [u64; U64_WIDTH] {
+    unsafe {
+        let (a, b) = (load_u64(a), load_u64(b));
+        let epsilon = _mm512_set1_epi64(GOLDILOCKS_EPSILON as i64);
+
+        // The 128-bit products are assembled from the products of the 32-bit halves.
+        let a_hi = _mm512_srli_epi64::<32>(a);
+        let b_hi = _mm512_srli_epi64::<32>(b);
+        let lo_lo = _mm512_mul_epu32(a, b);
+        let lo_hi = _mm512_mul_epu32(a, b_hi);
+        let hi_lo = _mm512_mul_epu32(a_hi, b);
+        let hi_hi = _mm512_mul_epu32(a_hi, b_hi);
+        let middle = _mm512_add_epi64(
+            _mm512_srli_epi64::<32>(lo_lo),
+            _mm512_add_epi64(
+                _mm512_and_si512(lo_hi, epsilon),
+                _mm512_and_si512(hi_lo, epsilon),
+            ),
+        );
+        let lo = _mm512_mask_blend_epi32(ODD_LANES, lo_lo, _mm512_slli_epi64::<32>(middle));
+        let hi = _mm512_add_epi64(
+            _mm512_add_epi64(hi_hi, _mm512_srli_epi64::<32>(middle)),
+            _mm512_add_epi64(
+                _mm512_srli_epi64::<32>(lo_hi),
+                _mm512_srli_epi64::<32>(hi_lo),
+            ),
+        );
+
+        // `lo + 2^64 hi = lo - hi_hi + epsilon hi_lo`, where `hi = 2^32 hi_hi + hi_lo`.
+        let hi_hi = _mm512_srli_epi64::<32>(hi);
+        let t0 = _mm512_sub_epi64(lo, hi_hi);
+        let t0 = _mm512_mask_sub_epi64(t0, _mm512_cmplt_epu64_mask(lo, hi_hi), t0, epsilon);
+        let hi_lo = _mm512_and_si512(hi, epsilon);
+        let t1 = _mm512_sub_epi64(_mm512_slli_epi64::<32>(hi_lo), hi_lo);
+        let sum = _mm512_add_epi64(t0, t1);
+        let sum = _mm512_mask_add_epi64(sum, _mm512_cmplt_epu64_mask(sum, t0), sum, epsilon);
+        store_u64(canonicalize_goldilocks(sum))
+    }
+}
@@ -0,0 +1,148 @@
+use super::{backend, IsPackedField, U32Lanes, U32_WIDTH};
+use crate::field::{
+    element::FieldElement,
+    errors::FieldError,
+    fields::mersenne31::field::{Mersenne31Field, MERSENNE_31_PRIME_FIELD_ORDER},
+    traits::{self, IsField},
+};
+
+/// Packed version of [`Mersenne31Field`], with [`U32_WIDTH`] lanes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PackedMersenne31Field;
+
+impl IsField for PackedMersenne31Field {
+    type BaseType = U32Lanes;
+
+    #[inline(always)]
+    fn add(a: &U32Lanes, b: &U32Lanes) -> U32Lanes {
+        U32Lanes(backend::add_mod(&a.0, &b.0, MERSENNE_31_PRIME_FIELD_ORDER))
+    }
+
+    #[inline(always)]
+    fn mul(a: &U32Lanes, b: &U32Lanes) -> U32Lanes {
+        U32Lanes(backend::mul_mersenne31(&a.0, &b.0))
+    }
+
+    #[inline(always)]
+    fn sub(a: &U32Lanes, b: &U32Lanes) -> U32Lanes {
+        U32Lanes(backend::sub_mod(&a.0, &b.0, MERSENNE_31_PRIME_FIELD_ORDER))
+    }
+
+    #[inline(always)]
+    fn neg(a: &U32Lanes) -> U32Lanes {
+        Self::sub(&Self::zero(), a)
+    }
+
+    /// Returns the inverse of every lane, or an error if any of them is zero.
+    fn inv(a: &U32Lanes) -> Result<U32Lanes, FieldError> {
+        let mut result = [0; U32_WIDTH];
+        for (inverse, lane) in result.iter_mut().zip(a.0.iter()) {
+            *inverse = Mersenne31Field::inv(lane)?;
+        }
+        Ok(U32Lanes(result))
+    }
+
+    fn div(a: &U32Lanes, b: &U32Lanes) -> U32Lanes {
+        let b_inv = Self::inv(b).expect("InvZeroError");
+        Self::mul(a, &b_inv)
+    }
+
+    fn eq(a: &U32Lanes, b: &U32Lanes) -> bool {
+        a.0.iter()
+            .zip(b.0.iter())
+            .all(|(a, b)| <Mersenne31Field as IsField>::eq(a, b))
+    }
+
+    fn one() -> U32Lanes {
+        U32Lanes([Mersenne31Field::one(); U32_WIDTH])
+    }
+
+    fn from_u64(x: u64) -> U32Lanes {
+        U32Lanes([Mersenne31Field::from_u64(x); U32_WIDTH])
+    }
+
+    fn from_base_type(x: U32Lanes) -> U32Lanes {
+        U32Lanes(x.0.map(Mersenne31Field::from_base_type))
+    }
+}
+
+impl IsPackedField for PackedMersenne31Field {
+    type Scalar = Mersenne31Field;
+
+    const WIDTH: usize = U32_WIDTH;
+
+    #[inline(always)]
+    fn broadcast(x: &FieldElement<Mersenne31Field>) -> FieldElement<Self> {
+        FieldElement::from_raw(U32Lanes([*x.value(); U32_WIDTH]))
+    }
+
+    #[inline(always)]
+    fn pack<S: traits::IsSubFieldOf<Mersenne31Field>>(
+        xs: &[FieldElement<S>],
+    ) -> FieldElement<Self> {
+        assert_eq!(xs.len(), U32_WIDTH);
+        FieldElement::from_raw(U32Lanes(core::array::from_fn(|i| {
+            S::embed(xs[i].value().clone())
+        })))
+    }
+
+    #[inline(always)]
+    fn unpack(x: &FieldElement<Self>, out: &mut [FieldElement<Mersenne31Field>]) {
+        assert_eq!(out.len(), U32_WIDTH);
+        for (element, lane) in out.iter_mut().zip(x.value().0) {
+            *element = FieldElement::from_raw(lane);
+        }
+    }
+
+    #[inline(always)]
+    fn lane(x: &FieldElement<Self>, i: usize) -> FieldElement<Mersenne31Field> {
+        FieldElement::from_raw(x.value().0[i])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type FE = FieldElement<Mersenne31Field>;
+    type PackedFE = FieldElement<PackedMersenne31Field>;
+
+    fn elements(seed: u64) -> [FE; U32_WIDTH] {
+        core::array::from_fn(|i| FE::from(seed.wrapping_mul(i as u64 + 1) ^ 0x5bd1e995))
+    }
+
+    #[test]
+    fn packed_operations_match_the_scalar_ones_in_every_lane() {
+        let a = elements(0x9e3779b97f4a7c15);
+        let b = elements(0xc2b2ae3d27d4eb4f);
+        let packed_a = PackedMersenne31Field::pack(&a);
+        let packed_b = PackedMersenne31Field::pack(&b);
+
+        let sum = packed_a + packed_b;
+        let difference = packed_a - packed_b;
+        let product = packed_a * packed_b;
+        let quotient = packed_a / packed_b;
+        for i in 0..U32_WIDTH {
+            assert_eq!(PackedMersenne31Field::lane(&sum, i), a[i] + b[i]);
+            assert_eq!(PackedMersenne31Field::lane(&difference, i), a[i] - b[i]);
+            assert_eq!(PackedMersenne31Field::lane(&product, i), a[i] * b[i]);
+            assert_eq!(PackedMersenne31Field::lane(&quotient, i), a[i] / b[i]);
+        }
+    }
+
+    #[test]
+    fn pack_and_unpack_are_inverses() {
+        let a = elements(17);
+        let mut unpacked = [FE::zero(); U32_WIDTH];
+        PackedMersenne31Field::unpack(&PackedMersenne31Field::pack(&a), &mut unpacked);
+        assert_eq!(unpacked, a);
+    }
+
+    #[test]
+    fn broadcast_one_is_one() {
+        assert_eq!(
+            PackedMersenne31Field::broadcast(&FE::one()),
+            PackedFE::one()
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! Packed representations of small prime fields. A packed element holds several elements of
+//! the field, its lanes, and every operation acts on all of them at once using SIMD
+//! instructions.
+//!
+//! The instructions are picked at compile time from the enabled target features, so building
+//! with `RUSTFLAGS="-C target-cpu=native"` uses AVX2, AVX-512 or NEON when available.
+//! Otherwise a portable implementation is used.
+//!
+//! The scalar fields use their packed counterparts in [`IsField::evaluate_polynomial`], and the
+//! 31-bit ones also in [`IsField::fft_butterflies`], so the FFT and polynomial evaluation get
+//! faster without changes in the calling code.
+
+use crate::field::{
+    element::FieldElement,
+    traits::{IsField, IsSubFieldOf},
+};
+#[cfg(feature = "lambdaworks-serde-binary")]
+use crate::{errors::ByteConversionError, traits::ByteConversion};
+
+mod backend;
+/// Packed Goldilocks field (p = 2^64 - 2^32 + 1).
+pub mod goldilocks;
+/// Packed Mersenne31 field (p = 2^31 - 1).
+pub mod mersenne31;
+/// Packed prime fields with 32-bit Montgomery representation, such as BabyBear.
+pub mod u32_montgomery;
+
+pub use backend::{U32_WIDTH, U64_WIDTH};
+
+/// A field whose elements are made of `WIDTH` elements of the field `Scalar`, with all the
+/// operations acting lane by lane.
+pub trait IsPackedField: IsField {
+    /// The field of each lane.
+    type Scalar: IsField;
+
+    /// The number of lanes.
+    const WIDTH: usize;
+
+    /// Returns the packed element with `x` in every lane.
+    fn broadcast(x: &FieldElement<Self::Scalar>) -> FieldElement<Self>;
+
+    /// Returns the packed element whose lanes are the elements of `xs`, embedded in `Scalar`.
+    /// Panics if `xs` doesn't have `WIDTH` elements.
+    fn pack<S: IsSubFieldOf<Self::Scalar>>(xs: &[FieldElement<S>]) -> FieldElement<Self>;
+
+    /// Writes the lanes of `x` into `out`.
+    /// Panics if `out` doesn't have `WIDTH` elements.
+    fn unpack(x: &FieldElement<Self>, out: &mut [FieldElement<Self::Scalar>]);
+
+    /// Returns the `i`-th lane of `x`.
+    fn lane(x: &FieldElement<Self>, i: usize) -> FieldElement<Self::Scalar>;
+}
+
+/// Polynomials with fewer coefficients than this number of lanes are evaluated one
+/// coefficient at a time.
+const MIN_PACKED_EVALUATION_CHUNKS: usize = 4;
+
+/// Implementation of [`IsField::fft_butterflies`] for the fields with a packed representation.
+/// The butterflies are computed `P::WIDTH` at a time, and the remaining ones one by one.
+/// Without a SIMD backend, packing and unpacking the lanes costs more than it saves, so all
+/// the butterflies are computed one by one.
+#[inline(always)]
+pub(crate) fn fft_butterflies<P, S>(
+    top: &mut [FieldElement<P::Scalar>],
+    bottom: &mut [FieldElement<P::Scalar>],
+    w: &FieldElement<S>,
+) where
+    P: IsPackedField,
+    S: IsSubFieldOf<P::Scalar>,
+{
+    debug_assert_eq!(top.len(), bottom.len());
+    let w: FieldElement<P::Scalar> = w.clone().to_extension();
+    let packed_w = P::broadcast(&w);
+
+    let packed_len = if backend::HAS_SIMD_BACKEND {
+        top.len() - top.len() % P::WIDTH
+    } else {
+        0
+    };
+    let (packed_top, top) = top.split_at_mut(packed_len);
+    let (packed_bottom, bottom) = bottom.split_at_mut(packed_len);
+    for (x, y) in packed_top
+        .chunks_exact_mut(P::WIDTH)
+        .zip(packed_bottom.chunks_exact_mut(P::WIDTH))
+    {
+        let packed_x = P::pack(x);
+        let packed_wy = &packed_w * P::pack(y);
+        P::unpack(&(&packed_x - &packed_wy), y);
+        P::unpack(&(packed_x + packed_wy), x);
+    }
+
+    for (x, y) in top.iter_mut().zip(bottom.iter_mut()) {
+        let wy = &w * &*y;
+        *y = &*x - &wy;
+        *x = &*x + wy;
+    }
+}
+
+/// Implementation of [`IsField::evaluate_polynomial`] for the fields with a packed
+/// representation. Writing `n = P::WIDTH`, the polynomial is split as
+/// `p(x) = q_0(x^n) + x * q_1(x^n) + ... + x^(n - 1) * q_(n - 1)(x^n)`, where `q_j` has the
+/// coefficients `j, j + n, j + 2n, ...` of `p`. The polynomials `q_j` are evaluated together
+/// with Horner's rule on packed elements, which makes the chain of dependent operations `n`
+/// times shorter.
+pub(crate) fn evaluate_polynomial<P, S>(
+    coefficients: &[FieldElement<S>],
+    x: &FieldElement<P::Scalar>,
+) -> FieldElement<P::Scalar>
+where
+    P: IsPackedField,
+    S: IsSubFieldOf<P::Scalar>,
+{
+    let horner = |coefficients: &[FieldElement<S>]| {
+        coefficients
+            .iter()
+            .rev()
+            .fold(FieldElement::<P::Scalar>::zero(), |acc, coeff| {
+                coeff + acc * x
+            })
+    };
+
+    if coefficients.len() < MIN_PACKED_EVALUATION_CHUNKS * P::WIDTH {
+        return horner(coefficients);
+    }
+
+    let chunks = coefficients.chunks_exact(P::WIDTH);
+    // The coefficients that don't fill a chunk are the ones of highest degree.
+    let remainder = chunks.remainder();
+    let packed_len = coefficients.len() - remainder.len();
+
+    let x_to_width = P::broadcast(&x.pow(P::WIDTH));
+    let packed_evaluation = chunks.rev().fold(FieldElement::<P>::zero(), |acc, chunk| {
+        P::pack(chunk) + acc * &x_to_width
+    });
+    let evaluation = (0..P::WIDTH)
+        .rev()
+        .fold(FieldElement::<P::Scalar>::zero(), |acc, i| {
+            P::lane(&packed_evaluation, i) + acc * x
+        });
+
+    evaluation + x.pow(packed_len) * horner(remainder)
+}
+
+/// Lanes of the packed fields over `u32`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct U32Lanes(pub [u32; U32_WIDTH]);
+
+/// Lanes of the packed fields over `u64`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct U64Lanes(pub [u64; U64_WIDTH]);
+
+/// Implements `ByteConversion` for the lanes by concatenating the bytes of every lane.
+#[cfg(feature = "lambdaworks-serde-binary")]
+macro_rules! impl_byte_conversion_for_lanes {
+    ($lanes:ident, $lane:ty, $width:ident) => {
+        impl ByteConversion for $lanes {
+            #[cfg(feature = "alloc")]
+            fn to_bytes_be(&self) -> alloc::vec::Vec<u8> {
+                self.0.iter().flat_map(|lane| lane.to_be_bytes()).collect()
+            }
+
+            #[cfg(feature = "alloc")]
+            fn to_bytes_le(&self) -> alloc::vec::Vec<u8> {
+                self.0.iter().flat_map(|lane| lane.to_le_bytes()).collect()
+            }
+
+            fn from_bytes_be(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+                const LANE_SIZE: usize = core::mem::size_of::<$lane>();
+                if bytes.len() != LANE_SIZE * $width {
+                    return Err(ByteConversionError::FromBEBytesError);
+                }
+                Ok(Self(core::array::from_fn(|i| {
+                    <$lane>::from_be_bytes(
+                        bytes[i * LANE_SIZE..(i + 1) * LANE_SIZE]
+                            .try_into()
+                            .unwrap(),
+                    )
+                })))
+            }
+
+            fn from_bytes_le(bytes: &[u8]) -> Result<Self, ByteConversionError> {
+                const LANE_SIZE: usize = core::mem::size_of::<$lane>();
+                if bytes.len() != LANE_SIZE * $width {
+                    return Err(ByteConversionError::FromLEBytesError);
+                }
+                Ok(Self(core::array::from_fn(|i| {
+                    <$lane>::from_le_bytes(
+                        bytes[i * LANE_SIZE..(i + 1) * LANE_SIZE]
+                            .try_into()
+                            .unwrap(),
+                    )
+                })))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "lambdaworks-serde-binary")]
+impl_byte_conversion_for_lanes!(U32Lanes, u32, U32_WIDTH);
+#[cfg(feature = "lambdaworks-serde-binary")]
+impl_byte_conversion_for_lanes!(U64Lanes, u64, U64_WIDTH);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::fields::{
+        fft_friendly::babybear_u32::Babybear31PrimeField, mersenne31::field::Mersenne31Field,
+        u64_goldilocks_field::Goldilocks64Field,
+    };
+    use crate::polynomial::Polynomial;
+    use alloc::vec::Vec;
+
+    fn elements<F: IsField>(len: usize, seed: u64) -> Vec<FieldElement<F>> {
+        (0..len as u64)
+            .map(|i| FieldElement::from(seed.wrapping_mul(i + 1).rotate_left(17) ^ i))
+            .collect()
+    }
+
+    fn naive_evaluation<F: IsField>(
+        coefficients: &[FieldElement<F>],
+        x: &FieldElement<F>,
+    ) -> FieldElement<F> {
+        coefficients
+            .iter()
+            .enumerate()
+            .fold(FieldElement::zero(), |acc, (i, coeff)| {
+                acc + coeff * x.pow(i)
+            })
+    }
+
+    fn packed_evaluation_matches_the_naive_one<F: IsField>() {
+        let x = FieldElement::<F>::from(0x123456789);
+        for len in [0, 1, 7, 63, 64, 65, 100, 257] {
+            let coefficients = elements::<F>(len, 0x9e3779b97f4a7c15);
+            let polynomial = Polynomial::new(&coefficients);
+            assert_eq!(polynomial.evaluate(&x), naive_evaluation(&coefficients, &x));
+        }
+    }
+
+    fn packed_butterflies_match_the_scalar_ones<P: IsPackedField>() {
+        let w = FieldElement::<P::Scalar>::from(0xabcdef);
+        for len in [1, 3, 16, 21, 64] {
+            let mut top = elements::<P::Scalar>(len, 3);
+            let mut bottom = elements::<P::Scalar>(len, 5);
+            let expected: (Vec<_>, Vec<_>) = top
+                .iter()
+                .zip(bottom.iter())
+                .map(|(x, y)| (x + &w * y, x - &w * y))
+                .unzip();
+
+            fft_butterflies::<P, P::Scalar>(&mut top, &mut bottom, &w);
+
+            assert_eq!((top, bottom), expected);
+        }
+    }
+
+    #[test]
+    fn packed_polynomial_evaluation_over_babybear() {
+        packed_evaluation_matches_the_naive_one::<Babybear31PrimeField>();
+    }
+
+    #[test]
+    fn packed_polynomial_evaluation_over_mersenne31() {
+        packed_evaluation_matches_the_naive_one::<Mersenne31Field>();
+    }
+
+    #[test]
+    fn packed_polynomial_evaluation_over_goldilocks() {
+        packed_evaluation_matches_the_naive_one::<Goldilocks64Field>();
+    }
+
+    #[test]
+    fn packed_butterflies_over_babybear() {
+        packed_butterflies_match_the_scalar_ones::<u32_montgomery::PackedBabybear31PrimeField>();
+    }
+
+    #[test]
+    fn packed_butterflies_over_mersenne31() {
+        packed_butterflies_match_the_scalar_ones::<mersenne31::PackedMersenne31Field>();
+    }
+
+    #[test]
+    fn packed_butterflies_over_goldilocks() {
+        packed_butterflies_match_the_scalar_ones::<goldilocks::PackedGoldilocks64Field>();
+    }
+}
@@ -0,0 +1,162 @@
+use super::{backend, IsPackedField, U32Lanes, U32_WIDTH};
+use crate::field::{
+    element::FieldElement,
+    errors::FieldError,
+    fields::u32_montgomery_backend_prime_field::U32MontgomeryBackendPrimeField,
+    traits::{self, IsField},
+};
+
+/// Packed version of [`U32MontgomeryBackendPrimeField`], with [`U32_WIDTH`] lanes.
+/// The modulus must be smaller than `2^31`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PackedU32MontgomeryBackendPrimeField<const MODULUS: u32>;
+
+/// Packed BabyBear field (p = 2^31 - 2^27 + 1).
+pub type PackedBabybear31PrimeField = PackedU32MontgomeryBackendPrimeField<2013265921>;
+
+impl<const MODULUS: u32> IsField for PackedU32MontgomeryBackendPrimeField<MODULUS> {
+    type BaseType = U32Lanes;
+
+    #[inline(always)]
+    fn add(a: &U32Lanes, b: &U32Lanes) -> U32Lanes {
+        U32Lanes(backend::add_mod(&a.0, &b.0, MODULUS))
+    }
+
+    #[inline(always)]
+    fn mul(a: &U32Lanes, b: &U32Lanes) -> U32Lanes {
+        U32Lanes(backend::mul_montgomery(
+            &a.0,
+            &b.0,
+            MODULUS,
+            U32MontgomeryBackendPrimeField::<MODULUS>::MU,
+        ))
+    }
+
+    #[inline(always)]
+    fn sub(a: &U32Lanes, b: &U32Lanes) -> U32Lanes {
+        U32Lanes(backend::sub_mod(&a.0, &b.0, MODULUS))
+    }
+
+    #[inline(always)]
+    fn neg(a: &U32Lanes) -> U32Lanes {
+        Self::sub(&Self::zero(), a)
+    }
+
+    /// Returns the inverse of every lane, or an error if any of them is zero.
+    fn inv(a: &U32Lanes) -> Result<U32Lanes, FieldError> {
+        let mut result = [0; U32_WIDTH];
+        for (inverse, lane) in result.iter_mut().zip(a.0.iter()) {
+            *inverse = U32MontgomeryBackendPrimeField::<MODULUS>::inv(lane)?;
+        }
+        Ok(U32Lanes(result))
+    }
+
+    fn div(a: &U32Lanes, b: &U32Lanes) -> U32Lanes {
+        let b_inv = Self::inv(b).expect("InvZeroError");
+        Self::mul(a, &b_inv)
+    }
+
+    fn eq(a: &U32Lanes, b: &U32Lanes) -> bool {
+        a == b
+    }
+
+    fn one() -> U32Lanes {
+        U32Lanes([U32MontgomeryBackendPrimeField::<MODULUS>::ONE; U32_WIDTH])
+    }
+
+    fn from_u64(x: u64) -> U32Lanes {
+        U32Lanes([U32MontgomeryBackendPrimeField::<MODULUS>::from_u64(x); U32_WIDTH])
+    }
+
+    fn from_base_type(x: U32Lanes) -> U32Lanes {
+        U32Lanes(x.0.map(U32MontgomeryBackendPrimeField::<MODULUS>::from_base_type))
+    }
+}
+
+impl<const MODULUS: u32> IsPackedField for PackedU32MontgomeryBackendPrimeField<MODULUS> {
+    type Scalar = U32MontgomeryBackendPrimeField<MODULUS>;
+
+    const WIDTH: usize = U32_WIDTH;
+
+    #[inline(always)]
+    fn broadcast(x: &FieldElement<Self::Scalar>) -> FieldElement<Self> {
+        FieldElement::from_raw(U32Lanes([*x.value(); U32_WIDTH]))
+    }
+
+    #[inline(always)]
+    fn pack<S: traits::IsSubFieldOf<Self::Scalar>>(xs: &[FieldElement<S>]) -> FieldElement<Self> {
+        assert_eq!(xs.len(), U32_WIDTH);
+        FieldElement::from_raw(U32Lanes(core::array::from_fn(|i| {
+            S::embed(xs[i].value().clone())
+        })))
+    }
+
+    #[inline(always)]
+    fn unpack(x: &FieldElement<Self>, out: &mut [FieldElement<Self::Scalar>]) {
+        assert_eq!(out.len(), U32_WIDTH);
+        for (element, lane) in out.iter_mut().zip(x.value().0) {
+            *element = FieldElement::from_raw(lane);
+        }
+    }
+
+    #[inline(always)]
+    fn lane(x: &FieldElement<Self>, i: usize) -> FieldElement<Self::Scalar> {
+        FieldElement::from_raw(x.value().0[i])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::fields::fft_friendly::babybear_u32::Babybear31PrimeField;
+
+    type FE = FieldElement<Babybear31PrimeField>;
+    type PackedFE = FieldElement<PackedBabybear31PrimeField>;
+
+    fn elements(seed: u64) -> [FE; U32_WIDTH] {
+        core::array::from_fn(|i| FE::from(seed.wrapping_mul(i as u64 + 1) % 2013265921))
+    }
+
+    #[test]
+    fn packed_operations_match_the_scalar_ones_in_every_lane() {
+        let a = elements(0x9e3779b97f4a7c15);
+        let b = elements(0xc2b2ae3d27d4eb4f);
+        let packed_a = PackedBabybear31PrimeField::pack(&a);
+        let packed_b = PackedBabybear31PrimeField::pack(&b);
+
+        let sum = packed_a + packed_b;
+        let difference = packed_a - packed_b;
+        let product = packed_a * packed_b;
+        let quotient = packed_a / packed_b;
+        for i in 0..U32_WIDTH {
+            assert_eq!(PackedBabybear31PrimeField::lane(&sum, i), a[i] + b[i]);
+            assert_eq!(
+                PackedBabybear31PrimeField::lane(&difference, i),
+                a[i] - b[i]
+            );
+            assert_eq!(PackedBabybear31PrimeField::lane(&product, i), a[i] * b[i]);
+            assert_eq!(PackedBabybear31PrimeField::lane(&quotient, i), a[i] / b[i]);
+        }
+    }
+
+    #[test]
+    fn pack_and_unpack_are_inverses() {
+        let a = elements(17);
+        let mut unpacked = [FE::zero(); U32_WIDTH];
+        PackedBabybear31PrimeField::unpack(&PackedBabybear31PrimeField::pack(&a), &mut unpacked);
+        assert_eq!(unpacked, a);
+    }
+
+    #[test]
+    fn broadcast_one_is_one() {
+        assert_eq!(
+            PackedBabybear31PrimeField::broadcast(&FE::one()),
+            PackedFE::one()
+        );
+    }
+
+    #[test]
+    fn negation_of_zero_is_zero() {
+        assert_eq!(-PackedFE::zero(), PackedFE::zero());
+    }
+}
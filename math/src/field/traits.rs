@@ -186,6 +186,42 @@ pub trait IsField: Debug + Clone {
     /// Takes as input an element of BaseType and returns the internal representation
     /// of that element in the field.
     fn from_base_type(x: Self::BaseType) -> Self::BaseType;
+
+    /// Applies the FFT butterfly `(x, y) -> (x + w * y, x - w * y)` to each pair of elements
+    /// of `top` and `bottom`, which must have the same length.
+    /// Fields with a packed representation override it to do several butterflies at once.
+    #[inline(always)]
+    fn fft_butterflies<S: IsSubFieldOf<Self>>(
+        top: &mut [FieldElement<Self>],
+        bottom: &mut [FieldElement<Self>],
+        w: &FieldElement<S>,
+    ) where
+        Self: Sized,
+    {
+        debug_assert_eq!(top.len(), bottom.len());
+        for (x, y) in top.iter_mut().zip(bottom.iter_mut()) {
+            let wy = w * &*y;
+            *y = &*x - &wy;
+            *x = &*x + wy;
+        }
+    }
+
+    /// Evaluates at `x` the polynomial with the given coefficients, in increasing degree order,
+    /// using Horner's rule.
+    /// Fields with a packed representation override it to evaluate several chunks at once.
+    #[inline(always)]
+    fn evaluate_polynomial<S: IsSubFieldOf<Self>>(
+        coefficients: &[FieldElement<S>],
+        x: &FieldElement<Self>,
+    ) -> FieldElement<Self>
+    where
+        Self: Sized,
+    {
+        coefficients
+            .iter()
+            .rev()
+            .fold(FieldElement::zero(), |acc, coeff| coeff + acc * x)
+    }
 }
 
 #[derive(PartialEq)]
@@ -1,8 +1,21 @@
-use crate::{cyclic_group::IsGroup, unsigned_integer::element::UnsignedInteger};
+use crate::{
+    cyclic_group::IsGroup,
+    elliptic_curve::{
+        glv::IsGLVCurve,
+        short_weierstrass::{point::ShortWeierstrassProjectivePoint, traits::IsShortWeierstrass},
+        traits::IsEllipticCurve,
+    },
+    field::element::FieldElement,
+    unsigned_integer::element::{UnsignedInteger, U256},
+};
 
 use super::naive::MSMError;
 
-use alloc::vec;
+use alloc::{vec, vec::Vec};
+
+/// Number of points from which `msm` accumulates the buckets of short Weierstrass points
+/// in affine coordinates, with [`msm_with_affine_buckets`].
+pub const AFFINE_BUCKETS_THRESHOLD: usize = 1 << 8;
 
 /// This function computes the multiscalar multiplication (MSM).
 ///
@@ -28,7 +41,108 @@ where
 
     let window_size = optimum_window_size(cs.len());
 
-    Ok(msm_with(cs, points, window_size))
+    Ok(G::pippenger_msm(cs, points, window_size))
+}
+
+/// Same as [`msm`], for curves with an efficient endomorphism `φ`.
+///
+/// Every `k * P` is split into `k1 * P + k2 * φ(P)` with the GLV decomposition of `k`, which
+/// doubles the number of points but halves the bits of the scalars, and so the number of windows.
+/// The points must be in the main subgroup of the curve.
+pub fn glv_msm<C>(
+    cs: &[U256],
+    points: &[C::PointRepresentation],
+) -> Result<C::PointRepresentation, MSMError>
+where
+    C: IsGLVCurve,
+{
+    if cs.len() != points.len() {
+        return Err(MSMError::LengthMismatch(cs.len(), points.len()));
+    }
+
+    let (half_cs, half_points) = glv_split::<C>(cs, points);
+    let window_size = optimum_window_size(half_cs.len());
+
+    Ok(C::PointRepresentation::pippenger_msm(
+        &half_cs,
+        &half_points,
+        window_size,
+    ))
+}
+
+/// Computes the MSM of points of a short Weierstrass curve, accumulating the buckets in affine
+/// coordinates when there are enough points for the shared inversions to pay off.
+/// It is the default of [`IsShortWeierstrass::pippenger_msm`].
+pub fn short_weierstrass_msm<const NUM_LIMBS: usize, E>(
+    cs: &[UnsignedInteger<NUM_LIMBS>],
+    points: &[ShortWeierstrassProjectivePoint<E>],
+    window_size: usize,
+) -> ShortWeierstrassProjectivePoint<E>
+where
+    E: IsShortWeierstrass,
+{
+    if points.len() >= AFFINE_BUCKETS_THRESHOLD {
+        msm_with_affine_buckets(cs, points, window_size)
+    } else {
+        msm_with_signed_digits(cs, points, window_size)
+    }
+}
+
+/// Same as [`short_weierstrass_msm`], splitting scalars of 256 bits with the GLV decomposition
+/// as in [`glv_msm`]. Curves override [`IsShortWeierstrass::pippenger_msm`] with it when all their
+/// points are in the main subgroup, that is, when the curve has prime order.
+///
+/// Every point must be in the main subgroup, where the endomorphism acts as the multiplication by
+/// `λ`. For other points the result is wrong.
+pub fn glv_short_weierstrass_msm<const NUM_LIMBS: usize, E>(
+    cs: &[UnsignedInteger<NUM_LIMBS>],
+    points: &[ShortWeierstrassProjectivePoint<E>],
+    window_size: usize,
+) -> ShortWeierstrassProjectivePoint<E>
+where
+    E: IsShortWeierstrass + IsGLVCurve<PointRepresentation = ShortWeierstrassProjectivePoint<E>>,
+{
+    // The halves of the decomposition have fewer limbs, so they never get here.
+    if NUM_LIMBS != 4 {
+        return short_weierstrass_msm(cs, points, window_size);
+    }
+    let cs: Vec<U256> = cs
+        .iter()
+        .map(|c| U256::from_limbs(c.limbs[..].try_into().unwrap()))
+        .collect();
+    let (half_cs, half_points) = glv_split::<E>(&cs, points);
+    short_weierstrass_msm(&half_cs, &half_points, window_size)
+}
+
+/// Returns the halves of the GLV decompositions of `cs` and the points they multiply.
+fn glv_split<C: IsGLVCurve>(
+    cs: &[U256],
+    points: &[C::PointRepresentation],
+) -> (Vec<UnsignedInteger<3>>, Vec<C::PointRepresentation>) {
+    let mut half_cs = Vec::with_capacity(2 * cs.len());
+    let mut half_points = Vec::with_capacity(2 * points.len());
+    for (k, p) in cs.iter().zip(points) {
+        let ((k1_is_negative, k1), (k2_is_negative, k2)) = C::decompose_scalar(k);
+        let endomorphism = C::endomorphism(p);
+        half_points.push(if k1_is_negative { p.neg() } else { p.clone() });
+        half_points.push(if k2_is_negative {
+            endomorphism.neg()
+        } else {
+            endomorphism
+        });
+        // The halves have at most 129 bits, so the most significant limb is always zero.
+        half_cs.push(UnsignedInteger::<3>::from_limbs([
+            k1.limbs[1],
+            k1.limbs[2],
+            k1.limbs[3],
+        ]));
+        half_cs.push(UnsignedInteger::<3>::from_limbs([
+            k2.limbs[1],
+            k2.limbs[2],
+            k2.limbs[3],
+        ]));
+    }
+    (half_cs, half_points)
 }
 
 fn optimum_window_size(data_length: usize) -> usize {
@@ -102,6 +216,361 @@ where
         .unwrap_or_else(G::neutral_element)
 }
 
+/// Same as [`msm_with`], but the scalars are split in signed digits with [`signed_digits`].
+///
+/// A point with a negative digit `-d` goes to the bucket of `d` negated, so there are only
+/// `2^(window_size - 1)` buckets per window instead of `2^window_size - 1`, at the cost of
+/// one more window.
+pub fn msm_with_signed_digits<const NUM_LIMBS: usize, G>(
+    cs: &[UnsignedInteger<NUM_LIMBS>],
+    points: &[G],
+    window_size: usize,
+) -> G
+where
+    G: IsGroup,
+{
+    const MIN_WINDOW_SIZE: usize = 2;
+    const MAX_WINDOW_SIZE: usize = 32;
+
+    let window_size = window_size.clamp(MIN_WINDOW_SIZE, MAX_WINDOW_SIZE);
+    let num_windows = num_signed_windows::<NUM_LIMBS>(window_size);
+    let digits: Vec<_> = cs
+        .iter()
+        .map(|k| signed_digits(k, window_size, num_windows))
+        .collect();
+    let mut buckets = vec![G::neutral_element(); 1 << (window_size - 1)];
+
+    (0..num_windows)
+        .rev()
+        .map(|window_idx| {
+            digits.iter().zip(points).for_each(|(digits, p)| {
+                add_to_bucket(&mut buckets, digits[window_idx], p);
+            });
+            reduce_buckets(&mut buckets)
+        })
+        // NOTE: this operation is non-associative and strictly sequential
+        .reduce(|t, g| t.operate_with_self(1_u64 << window_size).operate_with(&g))
+        .unwrap_or_else(G::neutral_element)
+}
+
+/// Number of signed digits of `window_size` bits needed for scalars of `NUM_LIMBS` limbs.
+/// There is one more than unsigned windows, to hold the last carry.
+fn num_signed_windows<const NUM_LIMBS: usize>(window_size: usize) -> usize {
+    64 * NUM_LIMBS / window_size + 1
+}
+
+/// Returns `num_windows` digits `d_j`, least significant first, with `k = sum_j d_j * 2^(j * window_size)`.
+///
+/// Every digit is in `[-2^(window_size - 1), 2^(window_size - 1)]`: when a window is at least
+/// `2^(window_size - 1)`, `2^window_size` is subtracted from it and carried to the next one.
+/// The last window never carries, so `num_windows` must leave it with a spare bit.
+pub fn signed_digits<const NUM_LIMBS: usize>(
+    k: &UnsignedInteger<NUM_LIMBS>,
+    window_size: usize,
+    num_windows: usize,
+) -> Vec<i64> {
+    let mask = (1_u64 << window_size) - 1;
+    let half = 1_i64 << (window_size - 1);
+    let mut carry = 0;
+    (0..num_windows)
+        .map(|window_idx| {
+            // We truncate the number to the least significative limb.
+            // This is ok because window_size < usize::BITS.
+            let shift = window_idx * window_size;
+            let window = if shift < 64 * NUM_LIMBS {
+                ((k >> shift).limbs[NUM_LIMBS - 1] & mask) as i64
+            } else {
+                0
+            };
+            let digit = window + carry;
+            if digit >= half && window_idx + 1 < num_windows {
+                carry = 1;
+                digit - (1 << window_size)
+            } else {
+                carry = 0;
+                digit
+            }
+        })
+        .collect()
+}
+
+/// Adds `p` to the bucket of `digit`, negated if `digit` is negative.
+fn add_to_bucket<G: IsGroup>(buckets: &mut [G], digit: i64, p: &G) {
+    match digit {
+        0 => {}
+        digit if digit > 0 => {
+            let idx = digit as usize - 1;
+            buckets[idx] = buckets[idx].operate_with(p);
+        }
+        digit => {
+            let idx = digit.unsigned_abs() as usize - 1;
+            buckets[idx] = buckets[idx].operate_with(&p.neg());
+        }
+    }
+}
+
+/// Returns `sum_i (i + 1) * buckets[i]`, and empties the buckets so they can be reused.
+fn reduce_buckets<G: IsGroup>(buckets: &mut [G]) -> G {
+    buckets
+        .iter_mut()
+        // This first part iterates buckets in descending order, generating an iterator with the sum of
+        // each bucket and all that came before as its items; i.e: (b_n, b_n + b_n-1, ..., b_n + ... + b_0)
+        .rev()
+        .scan(G::neutral_element(), |m, b| {
+            *m = m.operate_with(b); // Reduction step.
+            *b = G::neutral_element(); // Cleanup bucket slot to reuse in the next window.
+            Some(m.clone())
+        })
+        // This next part sums all elements of the iterator: (b_n) + (b_n + b_n-1) + ...
+        // This results in: (n + 1) * b_n + n * b_n-1 + ... + b_0
+        .reduce(|g, m| g.operate_with(&m))
+        .unwrap_or_else(G::neutral_element)
+}
+
+/// Multiples `2^(j * window_size) * g` of some fixed points `g`, for every window `j` of the
+/// signed digits of scalars of `NUM_LIMBS` limbs.
+///
+/// Computing an MSM over the same points many times, such as the powers of a KZG structured
+/// reference string or the points of a Groth16 proving key, can reuse them to put the digits
+/// of all the windows in the same buckets. That needs a single bucket reduction and no
+/// doublings between windows, in exchange for `num_windows` times the memory of the points.
+#[derive(Clone, Debug)]
+pub struct PrecomputedBases<const NUM_LIMBS: usize, G: IsGroup> {
+    window_size: usize,
+    num_windows: usize,
+    /// `multiples[i * num_windows + j]` is `2^(j * window_size) * points[i]`.
+    multiples: Vec<G>,
+}
+
+impl<const NUM_LIMBS: usize, G: IsGroup> PrecomputedBases<NUM_LIMBS, G> {
+    /// Precomputes the multiples of `points` for windows of `window_size` bits.
+    /// The window size can be larger than the one for a single MSM over the same points,
+    /// as the cost of the bucket reduction is paid only once for all the windows.
+    pub fn new(points: &[G], window_size: usize) -> Self {
+        const MIN_WINDOW_SIZE: usize = 2;
+        const MAX_WINDOW_SIZE: usize = 32;
+
+        let window_size = window_size.clamp(MIN_WINDOW_SIZE, MAX_WINDOW_SIZE);
+        let num_windows = num_signed_windows::<NUM_LIMBS>(window_size);
+        let multiples = points
+            .iter()
+            .flat_map(|p| {
+                core::iter::successors(Some(p.clone()), |p| {
+                    Some((0..window_size).fold(p.clone(), |p, _| p.double()))
+                })
+                .take(num_windows)
+            })
+            .collect();
+
+        Self {
+            window_size,
+            num_windows,
+            multiples,
+        }
+    }
+
+    /// Returns the number of points.
+    pub fn len(&self) -> usize {
+        self.multiples.len() / self.num_windows
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.multiples.is_empty()
+    }
+
+    /// Computes `cs[0] * points[0] + ... + cs[n - 1] * points[n - 1]`.
+    ///
+    /// There can be fewer scalars than points, in which case only the first `cs.len()` points
+    /// are used, as when committing to a polynomial of lower degree than the one supported by
+    /// a structured reference string.
+    pub fn msm(&self, cs: &[UnsignedInteger<NUM_LIMBS>]) -> Result<G, MSMError> {
+        if cs.len() > self.len() {
+            return Err(MSMError::LengthMismatch(cs.len(), self.len()));
+        }
+
+        let mut buckets = vec![G::neutral_element(); 1 << (self.window_size - 1)];
+        for (k, multiples) in cs.iter().zip(self.multiples.chunks_exact(self.num_windows)) {
+            let digits = signed_digits(k, self.window_size, self.num_windows);
+            for (&digit, p) in digits.iter().zip(multiples) {
+                add_to_bucket(&mut buckets, digit, p);
+            }
+        }
+
+        Ok(reduce_buckets(&mut buckets))
+    }
+}
+
+/// Same as [`msm_with`], but the points of each bucket are added up in affine coordinates.
+///
+/// The additions of a window are done in rounds, each one adding pairs of points of every bucket.
+/// An affine addition needs an inversion, so all the ones of a round share a single batch
+/// inversion, which makes them cheaper than the projective additions for large inputs.
+pub fn msm_with_affine_buckets<const NUM_LIMBS: usize, E>(
+    cs: &[UnsignedInteger<NUM_LIMBS>],
+    points: &[ShortWeierstrassProjectivePoint<E>],
+    window_size: usize,
+) -> ShortWeierstrassProjectivePoint<E>
+where
+    E: IsShortWeierstrass,
+{
+    const MIN_WINDOW_SIZE: usize = 2;
+    const MAX_WINDOW_SIZE: usize = 32;
+
+    let window_size = window_size.clamp(MIN_WINDOW_SIZE, MAX_WINDOW_SIZE);
+    let num_windows = num_signed_windows::<NUM_LIMBS>(window_size);
+    let n_buckets = 1 << (window_size - 1);
+
+    // The points at infinity don't add anything, the rest are moved to affine coordinates.
+    let (cs, points): (Vec<_>, Vec<_>) = cs
+        .iter()
+        .zip(points)
+        .filter(|(_, p)| !p.is_neutral_element())
+        .unzip();
+    let mut z_inverses: Vec<_> = points.iter().map(|p| p.z().clone()).collect();
+    FieldElement::inplace_batch_inverse(&mut z_inverses)
+        .expect("points other than infinity have non-zero z coordinate");
+    let points: Vec<AffinePoint<E>> = points
+        .iter()
+        .zip(&z_inverses)
+        .map(|(p, z_inv)| (p.x() * z_inv, p.y() * z_inv))
+        .collect();
+    let digits: Vec<_> = cs
+        .iter()
+        .map(|k| signed_digits(k, window_size, num_windows))
+        .collect();
+
+    (0..num_windows)
+        .rev()
+        .map(|window_idx| {
+            // Sort the points by bucket, so that bucket `i`, which is the one of the digits `±(i + 1)`,
+            // holds `sorted[starts[i]..starts[i + 1]]`. Points with a zero digit go to no bucket,
+            // and the ones with a negative digit are negated.
+            let mut starts = vec![0; n_buckets + 1];
+            for digits in &digits {
+                let digit = digits[window_idx].unsigned_abs() as usize;
+                if digit != 0 {
+                    starts[digit] += 1;
+                }
+            }
+            for i in 1..=n_buckets {
+                starts[i] += starts[i - 1];
+            }
+            let mut next_free = starts.clone();
+            let mut sorted = vec![None; starts[n_buckets]];
+            for (digits, (x, y)) in digits.iter().zip(&points) {
+                let digit = digits[window_idx];
+                if digit != 0 {
+                    let idx = digit.unsigned_abs() as usize - 1;
+                    let y = if digit > 0 { y.clone() } else { -y };
+                    sorted[next_free[idx]] = Some((x.clone(), y));
+                    next_free[idx] += 1;
+                }
+            }
+            let sorted = sorted.into_iter().flatten().collect();
+
+            // Do the reduction step for the buckets, with mixed additions as they are affine.
+            add_buckets_in_affine::<E>(sorted, starts)
+                .into_iter()
+                .rev()
+                .scan(
+                    ShortWeierstrassProjectivePoint::neutral_element(),
+                    |m, b| {
+                        if let Some((x, y)) = b {
+                            let b =
+                                ShortWeierstrassProjectivePoint::new([x, y, FieldElement::one()]);
+                            *m = m.operate_with_affine(&b);
+                        }
+                        Some(m.clone())
+                    },
+                )
+                .reduce(|g, m| g.operate_with(&m))
+                .unwrap_or_else(ShortWeierstrassProjectivePoint::neutral_element)
+        })
+        // NOTE: this operation is non-associative and strictly sequential
+        .reduce(|t, g| t.operate_with_self(1_u64 << window_size).operate_with(&g))
+        .unwrap_or_else(ShortWeierstrassProjectivePoint::neutral_element)
+}
+
+/// A point `(x, y)` of the curve in affine coordinates.
+type AffinePoint<E> = (
+    FieldElement<<E as IsEllipticCurve>::BaseField>,
+    FieldElement<<E as IsEllipticCurve>::BaseField>,
+);
+
+/// Returns the sum of each bucket, or `None` if it's the point at infinity, where bucket `i`
+/// holds `points[starts[i]..starts[i + 1]]`.
+fn add_buckets_in_affine<E: IsShortWeierstrass>(
+    mut points: Vec<AffinePoint<E>>,
+    mut starts: Vec<usize>,
+) -> Vec<Option<AffinePoint<E>>> {
+    let buckets = |starts: &[usize]| starts.windows(2).map(|w| w[0]..w[1]).collect::<Vec<_>>();
+
+    while buckets(&starts).iter().any(|bucket| bucket.len() > 1) {
+        // P + Q has slope (y_Q - y_P) / (x_Q - x_P), and 2P has slope (3 x_P^2 + a) / 2 y_P.
+        let mut inverses: Vec<_> = buckets(&starts)
+            .into_iter()
+            .flat_map(|bucket| points[bucket].chunks_exact(2))
+            .filter(|pair| !adds_to_infinity::<E>(&pair[0], &pair[1]))
+            .map(|pair| {
+                let ((px, py), (qx, _)) = (&pair[0], &pair[1]);
+                if px != qx {
+                    qx - px
+                } else {
+                    py.double()
+                }
+            })
+            .collect();
+        FieldElement::inplace_batch_inverse(&mut inverses)
+            .expect("slopes of points not adding to infinity have non-zero denominator");
+        let mut inverses = inverses.into_iter();
+
+        let mut next_points = Vec::with_capacity(points.len() / 2 + starts.len());
+        let mut next_starts = Vec::with_capacity(starts.len());
+        next_starts.push(0);
+        for bucket in buckets(&starts) {
+            for pair in points[bucket].chunks(2) {
+                match pair {
+                    [p] => next_points.push(p.clone()),
+                    [p, q] if !adds_to_infinity::<E>(p, q) => {
+                        let ((px, py), (qx, qy)) = (p, q);
+                        let inverse = inverses.next().expect("one inverse per addition");
+                        let slope = if px != qx {
+                            (qy - py) * inverse
+                        } else {
+                            let px_square = px.square();
+                            (&px_square + &px_square + px_square + E::a()) * inverse
+                        };
+                        let x = slope.square() - px - qx;
+                        let y = slope * (px - &x) - py;
+                        next_points.push((x, y));
+                    }
+                    _ => {}
+                }
+            }
+            next_starts.push(next_points.len());
+        }
+        points = next_points;
+        starts = next_starts;
+    }
+
+    let mut points = points.into_iter();
+    buckets(&starts)
+        .into_iter()
+        .map(|bucket| {
+            if bucket.is_empty() {
+                None
+            } else {
+                points.next()
+            }
+        })
+        .collect()
+}
+
+/// Returns whether `p + q` is the point at infinity, which happens when `q = -p`.
+fn adds_to_infinity<E: IsShortWeierstrass>(p: &AffinePoint<E>, q: &AffinePoint<E>) -> bool {
+    p.0 == q.0 && (p.1 != q.1 || p.1 == FieldElement::zero())
+}
+
 #[cfg(feature = "parallel")]
 // It has the following differences with the sequential one:
 //  1. It uses one vec per thread to store buckets.
@@ -166,11 +635,17 @@ mod tests {
     use crate::msm::{naive, pippenger};
     use crate::{
         elliptic_curve::{
-            short_weierstrass::curves::bls12_381::curve::BLS12381Curve, traits::IsEllipticCurve,
+            edwards::curves::bandersnatch::curve::BandersnatchCurve,
+            glv::IsGLVCurve,
+            short_weierstrass::curves::{
+                bls12_381::curve::BLS12381Curve, bn_254::curve::BN254Curve,
+                secp256k1::curve::Secp256k1Curve,
+            },
+            traits::IsEllipticCurve,
         },
         unsigned_integer::element::UnsignedInteger,
     };
-    use alloc::{format, vec::Vec};
+    use alloc::{format, vec, vec::Vec};
     use proptest::{collection, prelude::*, prop_assert_eq, prop_compose, proptest};
 
     const _CASES: u32 = 20;
@@ -201,6 +676,147 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pippenger_with_affine_buckets_handles_doubling_and_opposite_points() {
+        let g = BLS12381Curve::generator();
+        let p = g.operate_with_self(5_u64);
+        let points = vec![
+            p.clone(),
+            p.clone(),
+            p.neg(),
+            g.operate_with_self(7_u64),
+            <BLS12381Curve as IsEllipticCurve>::PointRepresentation::neutral_element(),
+            g.operate_with_self(7_u64).neg(),
+        ];
+        let cs = vec![UnsignedInteger::<6>::from_u64(3); points.len()];
+
+        let pippenger = pippenger::msm_with_affine_buckets(&cs, &points, 4);
+        let naive = naive::msm(&cs, &points).unwrap();
+
+        assert_eq!(pippenger, naive);
+        assert_eq!(pippenger, g.operate_with_self(15_u64));
+    }
+
+    #[test]
+    fn test_msm_above_the_affine_buckets_threshold_matches_naive_msm() {
+        let g = BLS12381Curve::generator();
+        let len = pippenger::AFFINE_BUCKETS_THRESHOLD;
+        let cs: Vec<_> = (0..len as u64)
+            .map(|i| UnsignedInteger::<6>::from_u64(i * i + 1) << (3 * (i as usize % 100)))
+            .collect();
+        let points: Vec<_> = (0..len as u64).map(|i| g.operate_with_self(i)).collect();
+
+        assert_eq!(
+            pippenger::msm(&cs, &points).unwrap(),
+            naive::msm(&cs, &points).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_signed_digits_represent_the_scalar() {
+        for k in [
+            0,
+            0b1011_0111,
+            u128::MAX,
+            1 << 127,
+            0x7fff_ffff_ffff_ffff_8000_0000_0000_0001,
+        ] {
+            let scalar = UnsignedInteger::<2>::from_limbs([(k >> 64) as u64, k as u64]);
+            for window_size in 2..=16 {
+                let num_windows = pippenger::num_signed_windows::<2>(window_size);
+                let digits = pippenger::signed_digits(&scalar, window_size, num_windows);
+
+                let half = 1_i64 << (window_size - 1);
+                assert!(digits.iter().all(|digit| (-half..=half).contains(digit)));
+                // The sum is checked modulo 2^128, where the scalar is its own representative.
+                let value = digits.iter().rev().fold(0_u128, |acc, &digit| {
+                    (acc << window_size).wrapping_add_signed(digit as i128)
+                });
+                assert_eq!(value, k);
+            }
+        }
+    }
+
+    #[test]
+    fn test_precomputed_bases_match_naive_msm() {
+        let g = BLS12381Curve::generator();
+        let points: Vec<_> = (1..30_u64).map(|i| g.operate_with_self(i * i)).collect();
+        let cs: Vec<_> = (1..30_u64)
+            .map(|i| UnsignedInteger::<6>::from_u64(u64::MAX / i) << (13 * i as usize))
+            .collect();
+
+        for window_size in [2, 5, 8, 13] {
+            let precomputed = pippenger::PrecomputedBases::new(&points, window_size);
+            assert_eq!(precomputed.len(), points.len());
+            assert_eq!(
+                precomputed.msm(&cs).unwrap(),
+                naive::msm(&cs, &points).unwrap()
+            );
+            // A prefix of the points is used when there are fewer scalars.
+            assert_eq!(
+                precomputed.msm(&cs[..10]).unwrap(),
+                naive::msm(&cs[..10], &points[..10]).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_precomputed_bases_with_more_scalars_than_points_fail() {
+        let points = vec![BLS12381Curve::generator(); 3];
+        let cs = vec![UnsignedInteger::<6>::from_u64(1); 4];
+
+        assert!(pippenger::PrecomputedBases::new(&points, 4)
+            .msm(&cs)
+            .is_err());
+    }
+
+    #[test]
+    fn test_glv_msm_matches_naive_msm() {
+        fn check<C: IsGLVCurve>()
+        where
+            C::PointRepresentation: core::fmt::Debug,
+        {
+            let g = C::generator();
+            let cs: Vec<_> = (1..40_u64)
+                .map(|i| {
+                    UnsignedInteger::<4>::from_u64(i).const_shl(6 * i as usize)
+                        - UnsignedInteger::from_u64(i)
+                })
+                .collect();
+            let points: Vec<_> = (1..40_u64).map(|i| g.operate_with_self(i * i)).collect();
+
+            assert_eq!(
+                pippenger::glv_msm::<C>(&cs, &points).unwrap(),
+                naive::msm(&cs, &points).unwrap()
+            );
+        }
+        check::<BLS12381Curve>();
+        check::<BN254Curve>();
+        check::<Secp256k1Curve>();
+        check::<BandersnatchCurve>();
+    }
+
+    #[test]
+    fn test_msm_of_prime_order_curves_with_glv_matches_naive_msm() {
+        fn check<C: IsGLVCurve>()
+        where
+            C::PointRepresentation: core::fmt::Debug,
+        {
+            let g = C::generator();
+            let cs: Vec<_> = (1..20_u64)
+                .map(|i| UnsignedInteger::<4>::from_limbs([u64::MAX / i, i, u64::MAX - i, i * i]))
+                .collect();
+            let points: Vec<_> = (1..20_u64).map(|i| g.operate_with_self(i)).collect();
+
+            assert_eq!(
+                pippenger::msm(&cs, &points).unwrap(),
+                naive::msm(&cs, &points).unwrap()
+            );
+        }
+        check::<BN254Curve>();
+        check::<Secp256k1Curve>();
+    }
+
     proptest! {
         #![proptest_config(ProptestConfig {
             cases: _CASES, .. ProptestConfig::default()
@@ -218,6 +834,32 @@ mod tests {
             prop_assert_eq!(naive, pippenger);
         }
 
+        // Property-based test that ensures `pippenger::msm_with_signed_digits` gives same result as `naive::msm`.
+        #[test]
+        fn test_pippenger_with_signed_digits_matches_naive_msm(window_size in 1.._MAX_WSIZE, cs in unsigned_integer_vec(), points in points_vec()) {
+            let min_len = cs.len().min(points.len());
+            let cs = cs[..min_len].to_vec();
+            let points = points[..min_len].to_vec();
+
+            let pippenger = pippenger::msm_with_signed_digits(&cs, &points, window_size);
+            let naive = naive::msm(&cs, &points).unwrap();
+
+            prop_assert_eq!(naive, pippenger);
+        }
+
+        // Property-based test that ensures `pippenger::msm_with_affine_buckets` gives same result as `naive::msm`.
+        #[test]
+        fn test_pippenger_with_affine_buckets_matches_naive_msm(window_size in 1.._MAX_WSIZE, cs in unsigned_integer_vec(), points in points_vec()) {
+            let min_len = cs.len().min(points.len());
+            let cs = cs[..min_len].to_vec();
+            let points = points[..min_len].to_vec();
+
+            let pippenger = pippenger::msm_with_affine_buckets(&cs, &points, window_size);
+            let naive = naive::msm(&cs, &points).unwrap();
+
+            prop_assert_eq!(naive, pippenger);
+        }
+
         // Property-based test that ensures `pippenger::msm_with` gives same result as `pippenger::parallel_msm_with`.
         #[test]
         #[cfg(feature = "parallel")]
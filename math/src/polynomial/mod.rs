@@ -1,7 +1,7 @@
 use super::field::element::FieldElement;
 use crate::field::traits::{IsField, IsPrimeField, IsSubFieldOf};
 use alloc::string::{String, ToString};
-use alloc::{borrow::ToOwned, format, vec, vec::Vec};
+use alloc::{format, vec, vec::Vec};
 use core::{fmt::Display, ops};
 pub mod dense_multilinear_poly;
 mod error;
@@ -100,12 +100,7 @@ impl<F: IsField> Polynomial<FieldElement<F>> {
         E: IsField,
         F: IsSubFieldOf<E>,
     {
-        self.coefficients
-            .iter()
-            .rev()
-            .fold(FieldElement::zero(), |acc, coeff| {
-                coeff + acc * x.to_owned()
-            })
+        E::evaluate_polynomial(&self.coefficients, x)
     }
 
     pub fn evaluate_slice(&self, input: &[FieldElement<F>]) -> Vec<FieldElement<F>> {
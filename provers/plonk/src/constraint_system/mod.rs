@@ -328,6 +328,56 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_prove_simple_program_1_over_bls12_377() {
+        use crate::test_utils::utils::bls12_377;
+        use lambdaworks_math::elliptic_curve::short_weierstrass::curves::bls12_377::default_types::FrField as BLS12377FrField;
+
+        // Program
+        let system = &mut ConstraintSystem::<BLS12377FrField>::new();
+
+        let e = system.new_variable();
+        let x = system.new_public_input();
+        let y = system.new_public_input();
+
+        let z = system.mul(&x, &e);
+        system.assert_eq(&y, &z);
+
+        // Common preprocessed input
+        let common_preprocessed_input = CommonPreprocessedInput::from_constraint_system(
+            system,
+            &bls12_377::ORDER_R_MINUS_1_ROOT_UNITY,
+        );
+
+        // Setup
+        let srs = bls12_377::test_srs(common_preprocessed_input.n);
+        let kzg = bls12_377::KZG::new(srs);
+        let verifying_key = setup(&common_preprocessed_input, &kzg);
+
+        // Prover
+        let inputs = HashMap::from([(x, FE::from(4)), (e, FE::from(3))]);
+        let assignments = system.solve(inputs).unwrap();
+        let public_inputs = system.public_input_values(&assignments);
+        let witness = Witness::new(assignments, system);
+
+        let prover = Prover::new(kzg.clone(), TestRandomFieldGenerator {});
+        let proof = prover.prove(
+            &witness,
+            &public_inputs,
+            &common_preprocessed_input,
+            &verifying_key,
+        );
+
+        // Verifier
+        let verifier = Verifier::new(kzg);
+        assert!(verifier.verify(
+            &proof,
+            &public_inputs,
+            &common_preprocessed_input,
+            &verifying_key
+        ));
+    }
+
     #[test]
     fn test_fibonacci() {
         let system = &mut ConstraintSystem::<U64PrimeField<65537>>::new();
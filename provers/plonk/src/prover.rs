@@ -281,7 +281,7 @@ impl<F, CS, R> Prover<F, CS, R>
 where
     F: IsField + IsFFTField,
     CS: IsCommitmentScheme<F>,
-    FieldElement<F>: ByteConversion,
+    FieldElement<F>: ByteConversion + Send + Sync,
     CS::Commitment: AsBytes,
     R: IsRandomFieldElementGenerator<F>,
 {
@@ -56,7 +56,10 @@ pub struct CommonPreprocessedInput<F: IsField> {
     pub s3_lagrange: Vec<FieldElement<F>>,
 }
 
-impl<F: IsFFTField> CommonPreprocessedInput<F> {
+impl<F: IsFFTField> CommonPreprocessedInput<F>
+where
+    FieldElement<F>: Send + Sync,
+{
     pub fn from_constraint_system(
         system: &ConstraintSystem<F>,
         order_r_minus_1_root_unity: &FieldElement<F>,
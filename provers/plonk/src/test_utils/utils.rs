@@ -86,8 +86,47 @@ fn identity_permutation<F: IsField>(
 /// to get random numbers to blind polynomials.
 #[derive(Clone)]
 pub struct TestRandomFieldGenerator;
-impl IsRandomFieldElementGenerator<FrField> for TestRandomFieldGenerator {
-    fn generate(&self) -> FrElement {
-        FrElement::zero()
+impl<F: IsField> IsRandomFieldElementGenerator<F> for TestRandomFieldGenerator {
+    fn generate(&self) -> FieldElement<F> {
+        FieldElement::zero()
+    }
+}
+
+/// The same tools for running plonk over the BLS12-377 pairing.
+pub mod bls12_377 {
+    use lambdaworks_crypto::commitments::kzg::{KateZaveruchaGoldberg, StructuredReferenceString};
+    use lambdaworks_math::{
+        cyclic_group::IsGroup,
+        elliptic_curve::{
+            short_weierstrass::curves::bls12_377::{
+                curve::BLS12377Curve,
+                default_types::{FrElement, FrField},
+                pairing::BLS12377AtePairing,
+                twist::BLS12377TwistCurve,
+            },
+            traits::IsEllipticCurve,
+        },
+    };
+
+    pub type KZG = KateZaveruchaGoldberg<FrField, BLS12377AtePairing>;
+    /// 22 generates the multiplicative group of the scalar field.
+    pub const ORDER_R_MINUS_1_ROOT_UNITY: FrElement = FrElement::from_hex_unchecked("16");
+
+    pub type G1Point = <BLS12377Curve as IsEllipticCurve>::PointRepresentation;
+    pub type G2Point = <BLS12377TwistCurve as IsEllipticCurve>::PointRepresentation;
+
+    /// Generates a test SRS for the BLS12377 curve
+    /// n is the number of constraints in the system.
+    pub fn test_srs(n: usize) -> StructuredReferenceString<G1Point, G2Point> {
+        let s = FrElement::from(2);
+        let g1 = <BLS12377Curve as IsEllipticCurve>::generator();
+        let g2 = <BLS12377TwistCurve as IsEllipticCurve>::generator();
+
+        let powers_main_group: Vec<G1Point> = (0..n + 3)
+            .map(|exp| g1.operate_with_self(s.pow(exp as u64).representative()))
+            .collect();
+        let powers_secondary_group = [g2.clone(), g2.operate_with_self(s.representative())];
+
+        StructuredReferenceString::new(&powers_main_group, &powers_secondary_group)
     }
 }
@@ -1,14 +1,40 @@
+use lambdaworks_crypto::commitments::kzg::KateZaveruchaGoldberg;
 use lambdaworks_crypto::commitments::traits::IsCommitmentScheme;
+use lambdaworks_crypto::fiat_shamir::default_transcript::DefaultTranscript;
 use lambdaworks_crypto::fiat_shamir::is_transcript::IsTranscript;
 use lambdaworks_math::cyclic_group::IsGroup;
+use lambdaworks_math::elliptic_curve::traits::IsPairing;
 use lambdaworks_math::field::element::FieldElement;
 use lambdaworks_math::field::traits::{IsFFTField, IsField, IsPrimeField};
 use lambdaworks_math::traits::{AsBytes, ByteConversion};
+use lambdaworks_math::unsigned_integer::element::UnsignedInteger;
 use std::marker::PhantomData;
 
 use crate::prover::Proof;
 use crate::setup::{new_strong_fiat_shamir_transcript, CommonPreprocessedInput, VerificationKey};
 
+/// Evaluations and commitments that a proof must open at `ζ`,
+/// batched with the challenge `υ`.
+struct OpeningClaims<F: IsField, C> {
+    zeta: FieldElement<F>,
+    upsilon: FieldElement<F>,
+    ys: [FieldElement<F>; 7],
+    commitments: [C; 7],
+}
+
+/// Reason why `Verifier::verify_batch` rejected a batch of proofs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchVerificationError {
+    /// The proof at this index is invalid on its own.
+    InvalidProof(usize),
+    /// The combined openings are invalid, but no proof fails when its openings are checked on
+    /// their own.
+    InvalidBatch,
+}
+
+/// A proof together with the public input it was generated for.
+pub type ProofWithPublicInput<'a, F, CS> = (&'a Proof<F, CS>, &'a [FieldElement<F>]);
+
 pub struct Verifier<F: IsField, CS: IsCommitmentScheme<F>> {
     commitment_scheme: CS,
     phantom: PhantomData<F>,
@@ -61,13 +87,15 @@ impl<F: IsField + IsFFTField, CS: IsCommitmentScheme<F>> Verifier<F, CS> {
         [beta, gamma, alpha, zeta, upsilon]
     }
 
-    pub fn verify(
+    /// Checks the gate and copy constraints of `p` at the challenge `ζ` and builds
+    /// the commitments and evaluations that must be opened to finish the verification.
+    fn check_constraints_and_compute_openings(
         &self,
         p: &Proof<F, CS>,
         public_input: &[FieldElement<F>],
         input: &CommonPreprocessedInput<F>,
         vk: &VerificationKey<CS::Commitment>,
-    ) -> bool
+    ) -> (bool, OpeningClaims<F, CS::Commitment>)
     where
         F: IsPrimeField + IsFFTField,
         CS: IsCommitmentScheme<F>,
@@ -178,12 +206,41 @@ impl<F: IsField + IsFFTField, CS: IsCommitmentScheme<F>> Verifier<F, CS> {
             vk.s1_1.clone(),
             vk.s2_1.clone(),
         ];
-        let batch_openings_check =
-            self.commitment_scheme
-                .verify_batch(&zeta, &ys, &commitments, &p.w_zeta_1, &upsilon);
+        let claims = OpeningClaims {
+            zeta,
+            upsilon,
+            ys,
+            commitments,
+        };
+        (constraints_check, claims)
+    }
+
+    pub fn verify(
+        &self,
+        p: &Proof<F, CS>,
+        public_input: &[FieldElement<F>],
+        input: &CommonPreprocessedInput<F>,
+        vk: &VerificationKey<CS::Commitment>,
+    ) -> bool
+    where
+        F: IsPrimeField + IsFFTField,
+        CS: IsCommitmentScheme<F>,
+        CS::Commitment: AsBytes + IsGroup,
+        FieldElement<F>: ByteConversion,
+    {
+        let (constraints_check, claims) =
+            self.check_constraints_and_compute_openings(p, public_input, input, vk);
+
+        let batch_openings_check = self.commitment_scheme.verify_batch(
+            &claims.zeta,
+            &claims.ys,
+            &claims.commitments,
+            &p.w_zeta_1,
+            &claims.upsilon,
+        );
 
         let single_opening_check = self.commitment_scheme.verify(
-            &(zeta * &input.omega),
+            &(claims.zeta * &input.omega),
             &p.z_zeta_omega,
             &p.z_1,
             &p.w_zeta_omega_1,
@@ -193,6 +250,85 @@ impl<F: IsField + IsFFTField, CS: IsCommitmentScheme<F>> Verifier<F, CS> {
     }
 }
 
+impl<const N: usize, F, P> Verifier<F, KateZaveruchaGoldberg<F, P>>
+where
+    F: IsPrimeField<RepresentativeType = UnsignedInteger<N>> + IsFFTField,
+    P: IsPairing,
+    P::G1Point: AsBytes,
+    FieldElement<F>: ByteConversion,
+{
+    /// Verifies many proofs for the same circuit and verification key at once.
+    /// The constraint checks are done per proof, while all the KZG openings are
+    /// combined with random scalars and checked with a single pairing computation.
+    /// If the batch is invalid, the index of the first failing proof is returned, or
+    /// `InvalidBatch` if none of them fails on its own.
+    pub fn verify_batch(
+        &self,
+        proofs: &[ProofWithPublicInput<F, KateZaveruchaGoldberg<F, P>>],
+        input: &CommonPreprocessedInput<F>,
+        vk: &VerificationKey<P::G1Point>,
+    ) -> Result<(), BatchVerificationError> {
+        let mut openings = Vec::with_capacity(2 * proofs.len());
+        for (i, (p, public_input)) in proofs.iter().enumerate() {
+            let (constraints_check, claims) =
+                self.check_constraints_and_compute_openings(p, public_input, input, vk);
+            if !constraints_check {
+                return Err(BatchVerificationError::InvalidProof(i));
+            }
+
+            // Same aggregation as `verify_batch` of the commitment scheme
+            let acc_commitment = claims.commitments.iter().rev().fold(
+                P::G1Point::neutral_element(),
+                |acc, point| {
+                    acc.operate_with_self(claims.upsilon.representative())
+                        .operate_with(point)
+                },
+            );
+            let acc_y = claims
+                .ys
+                .iter()
+                .rev()
+                .fold(FieldElement::zero(), |acc, y| acc * &claims.upsilon + y);
+            let zeta_omega = &claims.zeta * &input.omega;
+
+            openings.push((claims.zeta, acc_y, acc_commitment, p.w_zeta_1.clone()));
+            openings.push((
+                zeta_omega,
+                p.z_zeta_omega.clone(),
+                p.z_1.clone(),
+                p.w_zeta_omega_1.clone(),
+            ));
+        }
+
+        // The random scalars are sampled once every opening is fixed
+        let mut transcript = DefaultTranscript::<F>::default();
+        for (x, y, commitment, proof) in openings.iter() {
+            transcript.append_field_element(x);
+            transcript.append_field_element(y);
+            transcript.append_bytes(&commitment.as_bytes());
+            transcript.append_bytes(&proof.as_bytes());
+        }
+        let randomness: Vec<_> = (0..openings.len())
+            .map(|_| transcript.sample_field_element())
+            .collect();
+
+        if self.commitment_scheme.verify_many(&openings, &randomness) {
+            return Ok(());
+        }
+
+        // The batch failed, find the culprit by checking each proof's openings on its own
+        let failing_proof = openings.chunks(2).position(|pair| {
+            pair.iter().any(|(x, y, commitment, proof)| {
+                !self.commitment_scheme.verify(x, y, commitment, proof)
+            })
+        });
+        Err(failing_proof.map_or(
+            BatchVerificationError::InvalidBatch,
+            BatchVerificationError::InvalidProof,
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use lambdaworks_math::traits::Deserializable;
@@ -420,4 +556,80 @@ mod tests {
             &verifying_key
         ));
     }
+
+    #[test]
+    fn test_batch_verification_of_valid_proofs() {
+        // This is the circuit for x * e == y
+        let common_preprocessed_input = test_common_preprocessed_input_1();
+        let srs = test_srs(common_preprocessed_input.n);
+        let kzg = KZG::new(srs);
+        let verifying_key = setup(&common_preprocessed_input, &kzg);
+        let prover = Prover::new(kzg.clone(), TestRandomFieldGenerator {});
+
+        let public_inputs: Vec<Vec<FieldElement<_>>> = (1..4_u64)
+            .map(|x| vec![FieldElement::from(x), FieldElement::from(3 * x)])
+            .collect();
+        let proofs: Vec<_> = public_inputs
+            .iter()
+            .map(|public_input| {
+                let witness = test_witness_1(public_input[0].clone(), FieldElement::from(3_u64));
+                prover.prove(
+                    &witness,
+                    public_input,
+                    &common_preprocessed_input,
+                    &verifying_key,
+                )
+            })
+            .collect();
+        let batch: Vec<_> = proofs
+            .iter()
+            .zip(public_inputs.iter())
+            .map(|(proof, public_input)| (proof, public_input.as_slice()))
+            .collect();
+
+        let verifier = Verifier::new(kzg);
+        assert_eq!(
+            verifier.verify_batch(&batch, &common_preprocessed_input, &verifying_key),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_batch_verification_returns_the_index_of_an_invalid_proof() {
+        // This is the circuit for x * e == y
+        let common_preprocessed_input = test_common_preprocessed_input_1();
+        let srs = test_srs(common_preprocessed_input.n);
+        let kzg = KZG::new(srs);
+        let verifying_key = setup(&common_preprocessed_input, &kzg);
+        let prover = Prover::new(kzg.clone(), TestRandomFieldGenerator {});
+
+        let public_inputs: Vec<Vec<FieldElement<_>>> = (1..4_u64)
+            .map(|x| vec![FieldElement::from(x), FieldElement::from(3 * x)])
+            .collect();
+        let mut proofs: Vec<_> = public_inputs
+            .iter()
+            .map(|public_input| {
+                let witness = test_witness_1(public_input[0].clone(), FieldElement::from(3_u64));
+                prover.prove(
+                    &witness,
+                    public_input,
+                    &common_preprocessed_input,
+                    &verifying_key,
+                )
+            })
+            .collect();
+        // Only the opening proof is tampered, so the constraints still hold
+        proofs[1].w_zeta_omega_1 = proofs[1].w_zeta_omega_1.operate_with(&proofs[1].w_zeta_1);
+        let batch: Vec<_> = proofs
+            .iter()
+            .zip(public_inputs.iter())
+            .map(|(proof, public_input)| (proof, public_input.as_slice()))
+            .collect();
+
+        let verifier = Verifier::new(kzg);
+        assert_eq!(
+            verifier.verify_batch(&batch, &common_preprocessed_input, &verifying_key),
+            Err(BatchVerificationError::InvalidProof(1))
+        );
+    }
 }
@@ -1,7 +1,23 @@
+use blake2::Blake2s256;
+use lambdaworks_crypto::hash::{
+    blake3::Blake3,
+    monolith::{IsMersenne31Field, MonolithMersenne31},
+};
 use lambdaworks_crypto::merkle_tree::{
-    backends::types::{BatchKeccak256Backend, Keccak256Backend},
+    backends::{
+        field_element_vector::BatchMonolithBytesTree,
+        types::{
+            BatchBlake2s256Backend, BatchBlake3_256Backend, BatchKeccak256Backend, Keccak256Backend,
+        },
+    },
     merkle::MerkleTree,
+    traits::IsMerkleTreeBackend,
+};
+use lambdaworks_math::{
+    field::{element::FieldElement, traits::IsField},
+    traits::AsBytes,
 };
+use sha3::Keccak256;
 
 // Merkle Trees configuration
 
@@ -16,5 +32,45 @@ pub type FriMerkleTree<F> = MerkleTree<FriMerkleTreeBackend<F>>;
 pub const COMMITMENT_SIZE: usize = 32;
 pub type Commitment = [u8; COMMITMENT_SIZE];
 
-pub type BatchedMerkleTreeBackend<F> = BatchKeccak256Backend<F>;
-pub type BatchedMerkleTree<F> = MerkleTree<BatchedMerkleTreeBackend<F>>;
+/// A hash the prover and the verifier can commit to vectors of elements of `F` with. It is the
+/// type parameter `H` of `Prover` and `Verifier`, which is `Keccak256` by default, and a proof is
+/// only verified by a verifier with the same hash.
+pub trait IsCommitmentHash<F: IsField> {
+    /// The backend of the Merkle trees whose leaves are the committed vectors.
+    type MerkleTreeBackend: IsMerkleTreeBackend<Node = Commitment, Data = Vec<FieldElement<F>>>
+        + Clone;
+}
+
+impl<F: IsField> IsCommitmentHash<F> for Keccak256
+where
+    FieldElement<F>: AsBytes + Sync + Send,
+{
+    type MerkleTreeBackend = BatchKeccak256Backend<F>;
+}
+
+impl<F: IsField> IsCommitmentHash<F> for Blake2s256
+where
+    FieldElement<F>: AsBytes + Sync + Send,
+{
+    type MerkleTreeBackend = BatchBlake2s256Backend<F>;
+}
+
+impl<F: IsField> IsCommitmentHash<F> for Blake3
+where
+    FieldElement<F>: AsBytes + Sync + Send,
+{
+    type MerkleTreeBackend = BatchBlake3_256Backend<F>;
+}
+
+/// Monolith commits to elements of Mersenne31 and its extensions through their coordinates.
+impl<F, const WIDTH: usize, const NUM_FULL_ROUNDS: usize> IsCommitmentHash<F>
+    for MonolithMersenne31<WIDTH, NUM_FULL_ROUNDS>
+where
+    F: IsMersenne31Field,
+    FieldElement<F>: Sync + Send,
+{
+    type MerkleTreeBackend = BatchMonolithBytesTree<F, WIDTH, NUM_FULL_ROUNDS>;
+}
+
+pub type BatchedMerkleTreeBackend<F, H = Keccak256> = <H as IsCommitmentHash<F>>::MerkleTreeBackend;
+pub type BatchedMerkleTree<F, H = Keccak256> = MerkleTree<BatchedMerkleTreeBackend<F, H>>;
@@ -105,7 +105,10 @@ where
 
     /// Compute evaluations of the constraints zerofier over a LDE domain.
     #[allow(unstable_name_collisions)]
-    fn zerofier_evaluations_on_extended_domain(&self, domain: &Domain<F>) -> Vec<FieldElement<F>> {
+    fn zerofier_evaluations_on_extended_domain(&self, domain: &Domain<F>) -> Vec<FieldElement<F>>
+    where
+        FieldElement<F>: Send + Sync,
+    {
         let blowup_factor = domain.blowup_factor;
         let trace_length = domain.trace_roots_of_unity.len();
         let trace_primitive_root = &domain.trace_primitive_root;
@@ -17,7 +17,11 @@ pub fn validate_trace<A: AIR>(
     aux_trace_polys: &[Polynomial<FieldElement<A::FieldExtension>>],
     domain: &Domain<A::Field>,
     rap_challenges: &[FieldElement<A::FieldExtension>],
-) -> bool {
+) -> bool
+where
+    FieldElement<A::Field>: Send + Sync,
+    FieldElement<A::FieldExtension>: Send + Sync,
+{
     info!("Starting constraints validation over trace...");
     let mut ret = true;
 
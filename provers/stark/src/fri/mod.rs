@@ -2,7 +2,9 @@ pub mod fri_commitment;
 pub mod fri_decommit;
 mod fri_functions;
 
-use lambdaworks_crypto::fiat_shamir::is_transcript::IsTranscript;
+use lambdaworks_crypto::{
+    fiat_shamir::is_transcript::IsTranscript, merkle_tree::proof::MultiProof,
+};
 use lambdaworks_math::field::traits::{IsFFTField, IsField};
 use lambdaworks_math::traits::AsBytes;
 use lambdaworks_math::{
@@ -13,22 +15,22 @@ pub use lambdaworks_math::{
     polynomial::Polynomial,
 };
 
-use crate::config::{BatchedMerkleTree, BatchedMerkleTreeBackend};
+use crate::config::{BatchedMerkleTree, BatchedMerkleTreeBackend, Commitment, IsCommitmentHash};
 
 use self::fri_commitment::FriLayer;
 use self::fri_decommit::FriDecommitment;
 use self::fri_functions::fold_polynomial;
 
-pub fn commit_phase<F: IsFFTField + IsSubFieldOf<E>, E: IsField>(
+/// A FRI layer committed to with the hash `H`.
+pub type CommittedFriLayer<F, H> = FriLayer<F, BatchedMerkleTreeBackend<F, H>>;
+
+pub fn commit_phase<F: IsFFTField + IsSubFieldOf<E>, E: IsField, H: IsCommitmentHash<E>>(
     number_layers: usize,
     p_0: Polynomial<FieldElement<E>>,
     transcript: &mut impl IsTranscript<E>,
     coset_offset: &FieldElement<F>,
     domain_size: usize,
-) -> (
-    FieldElement<E>,
-    Vec<FriLayer<E, BatchedMerkleTreeBackend<E>>>,
-)
+) -> (FieldElement<E>, Vec<CommittedFriLayer<E, H>>)
 where
     FieldElement<F>: AsBytes + Sync + Send,
     FieldElement<E>: AsBytes + Sync + Send,
@@ -36,7 +38,7 @@ where
     let mut domain_size = domain_size;
 
     let mut fri_layer_list = Vec::with_capacity(number_layers);
-    let mut current_layer: FriLayer<E, BatchedMerkleTreeBackend<E>>;
+    let mut current_layer: CommittedFriLayer<E, H>;
     let mut current_poly = p_0;
 
     let mut coset_offset = coset_offset.clone();
@@ -49,7 +51,7 @@ where
 
         // Compute layer polynomial and domain
         current_poly = FieldElement::<F>::from(2) * fold_polynomial(&current_poly, &zeta);
-        current_layer = new_fri_layer(&current_poly, &coset_offset, domain_size);
+        current_layer = new_fri_layer::<F, E, H>(&current_poly, &coset_offset, domain_size);
         let new_data = &current_layer.merkle_tree.root;
         fri_layer_list.push(current_layer.clone()); // TODO: remove this clone
 
@@ -74,10 +76,13 @@ where
     (last_value, fri_layer_list)
 }
 
-pub fn query_phase<F: IsField>(
-    fri_layers: &Vec<FriLayer<F, BatchedMerkleTreeBackend<F>>>,
+/// Returns the symmetric evaluations of every layer for each query, together with a Merkle
+/// multi-proof of them for each layer. The leaf opened in a layer holds both the evaluation of the
+/// query and its symmetric, so the one of `iota` in layer `k` is `iota >> (k + 1)`.
+pub fn query_phase<F: IsField, H: IsCommitmentHash<F>>(
+    fri_layers: &Vec<CommittedFriLayer<F, H>>,
     iotas: &[usize],
-) -> Vec<FriDecommitment<F>>
+) -> (Vec<FriDecommitment<F>>, Vec<MultiProof<Commitment>>)
 where
     FieldElement<F>: AsBytes + Sync + Send,
 {
@@ -86,37 +91,42 @@ where
             .iter()
             .map(|iota_s| {
                 let mut layers_evaluations_sym = Vec::new();
-                let mut layers_auth_paths_sym = Vec::new();
 
                 let mut index = *iota_s;
                 for layer in fri_layers {
                     // symmetric element
                     let evaluation_sym = layer.evaluation[index ^ 1].clone();
-                    let auth_path_sym = layer.merkle_tree.get_proof_by_pos(index >> 1).unwrap();
                     layers_evaluations_sym.push(evaluation_sym);
-                    layers_auth_paths_sym.push(auth_path_sym);
 
                     index >>= 1;
                 }
 
                 FriDecommitment {
-                    layers_auth_paths: layers_auth_paths_sym,
                     layers_evaluations_sym,
                 }
             })
             .collect();
 
-        query_list
+        let mut indexes: Vec<usize> = iotas.to_vec();
+        let layers_multi_proofs = fri_layers
+            .iter()
+            .map(|layer| {
+                indexes.iter_mut().for_each(|index| *index >>= 1);
+                layer.merkle_tree.get_multi_proof(&indexes).unwrap()
+            })
+            .collect();
+
+        (query_list, layers_multi_proofs)
     } else {
-        vec![]
+        (vec![], vec![])
     }
 }
 
-pub fn new_fri_layer<F: IsFFTField + IsSubFieldOf<E>, E: IsField>(
+pub fn new_fri_layer<F: IsFFTField + IsSubFieldOf<E>, E: IsField, H: IsCommitmentHash<E>>(
     poly: &Polynomial<FieldElement<E>>,
     coset_offset: &FieldElement<F>,
     domain_size: usize,
-) -> crate::fri::fri_commitment::FriLayer<E, BatchedMerkleTreeBackend<E>>
+) -> CommittedFriLayer<E, H>
 where
     FieldElement<F>: AsBytes + Sync + Send,
     FieldElement<E>: AsBytes + Sync + Send,
@@ -131,7 +141,7 @@ where
         to_commit.push(vec![chunk[0].clone(), chunk[1].clone()]);
     }
 
-    let merkle_tree = BatchedMerkleTree::build(&to_commit).unwrap();
+    let merkle_tree = BatchedMerkleTree::<E, H>::build(&to_commit).unwrap();
 
     FriLayer::new(
         &evaluation,
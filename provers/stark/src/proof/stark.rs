@@ -1,6 +1,6 @@
 use std::collections::{BTreeSet, HashMap, HashSet};
 
-use lambdaworks_crypto::merkle_tree::proof::Proof;
+use lambdaworks_crypto::merkle_tree::proof::MultiProof;
 use lambdaworks_math::{
     field::{
         element::FieldElement,
@@ -24,8 +24,6 @@ use super::options::ProofOptions;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct PolynomialOpenings<F: IsField> {
-    pub proof: Proof<Commitment>,
-    pub proof_sym: Proof<Commitment>,
     pub evaluations: Vec<FieldElement<F>>,
     pub evaluations_sym: Vec<FieldElement<F>>,
 }
@@ -64,6 +62,13 @@ pub struct StarkProof<F: IsSubFieldOf<E>, E: IsField> {
     // Open(H₁(D_LDE, 𝜐ᵢ), Open(H₂(D_LDE, 𝜐ᵢ), Open(tⱼ(D_LDE), 𝜐ᵢ)
     // Open(H₁(D_LDE, -𝜐ᵢ), Open(H₂(D_LDE, -𝜐ᵢ), Open(tⱼ(D_LDE), -𝜐ᵢ)
     pub deep_poly_openings: DeepPolynomialOpenings<F, E>,
+    // Merkle multi-proofs of Open(tⱼ(D_LDE), 𝜐ᵢ) and Open(tⱼ(D_LDE), -𝜐ᵢ) for all queries
+    pub lde_trace_main_multi_proof: MultiProof<Commitment>,
+    pub lde_trace_aux_multi_proof: Option<MultiProof<Commitment>>,
+    // Merkle multi-proof of Open(Hᵢ(D_LDE), 𝜐ᵢ) and Open(Hᵢ(D_LDE), -𝜐ᵢ) for all queries
+    pub composition_poly_multi_proof: MultiProof<Commitment>,
+    // Merkle multi-proofs of Open(pₖ(Dₖ), −𝜐ₛ^(2ᵏ)) for all queries, one for each layer
+    pub fri_layers_multi_proofs: Vec<MultiProof<Commitment>>,
     // nonce obtained from grinding
     pub nonce: Option<u64>,
 }
@@ -189,7 +194,8 @@ impl StoneCompatibleSerializer {
     ///
     /// Where TraceMergedPaths is the merged authentication paths of the trace Merkle tree for all queries
     /// and similarly, CompositionMergedPaths is the merged authentication paths of the composition polynomial
-    /// Merkle tree for all queries (see the `merge_authentication_paths` method).
+    /// Merkle tree for all queries. These are the nodes of the Merkle multi-proofs of the openings
+    /// (see `MultiProof`).
     ///
     /// Example:
     /// If there are 6 queries [3, 1, 5, 2, 1, 3], then this method appends the
@@ -232,43 +238,15 @@ impl StoneCompatibleSerializer {
             }
         }
 
-        let fri_trace_query_indexes: Vec<_> = fri_query_indexes
-            .iter()
-            .flat_map(|query| vec![query * 2, query * 2 + 1])
-            .collect();
-
         // Append TraceMergedPaths
         //    Main trace
-        let fri_trace_paths: Vec<_> = proof
-            .deep_poly_openings
-            .iter()
-            .flat_map(|opening| {
-                vec![
-                    &opening.main_trace_polys.proof,
-                    &opening.main_trace_polys.proof_sym,
-                ]
-            })
-            .collect();
-        let nodes = Self::merge_authentication_paths(&fri_trace_paths, &fri_trace_query_indexes);
-        for node in nodes.iter() {
+        for node in proof.lde_trace_main_multi_proof.nodes.iter() {
             output.extend_from_slice(node);
         }
 
         //    Aux trace
-        let mut all_openings_aux_trace_polys_are_some = true;
-        let mut fri_trace_paths: Vec<&Proof<Commitment>> = Vec::new();
-        for opening in proof.deep_poly_openings.iter() {
-            if let Some(aux_trace_polys) = &opening.aux_trace_polys {
-                fri_trace_paths.push(&aux_trace_polys.proof);
-                fri_trace_paths.push(&aux_trace_polys.proof_sym);
-            } else {
-                all_openings_aux_trace_polys_are_some = false;
-            }
-        }
-        if all_openings_aux_trace_polys_are_some {
-            let nodes =
-                Self::merge_authentication_paths(&fri_trace_paths, &fri_trace_query_indexes);
-            for node in nodes.iter() {
+        if let Some(multi_proof) = &proof.lde_trace_aux_multi_proof {
+            for node in multi_proof.nodes.iter() {
                 output.extend_from_slice(node);
             }
         }
@@ -284,13 +262,7 @@ impl StoneCompatibleSerializer {
         }
 
         // Append CompositionMergedPaths
-        let fri_composition_paths: Vec<_> = proof
-            .deep_poly_openings
-            .iter()
-            .map(|opening| &opening.composition_poly.proof)
-            .collect();
-        let nodes = Self::merge_authentication_paths(&fri_composition_paths, fri_query_indexes);
-        for node in nodes.iter() {
+        for node in proof.composition_poly_multi_proof.nodes.iter() {
             output.extend_from_slice(node);
         }
     }
@@ -370,78 +342,13 @@ impl StoneCompatibleSerializer {
                 .map(|index| index >> 1)
                 .collect();
 
-            let layer_auth_paths: Vec<_> = proof
-                .query_list
-                .iter()
-                .map(|decommitment| &decommitment.layers_auth_paths[i])
-                .collect();
-
             // Append MergedPathsLayer_i
-            let nodes =
-                Self::merge_authentication_paths(&layer_auth_paths, &indexes_previous_layer);
-            for node in nodes.iter() {
+            for node in proof.fri_layers_multi_proofs[i].nodes.iter() {
                 output.extend_from_slice(node);
             }
         }
     }
 
-    /// Merges `n` authentication paths for `n` leaves into a list of the minimal number of nodes
-    /// needed to reach the Merkle root for all of them. The nodes of the merged authentication
-    /// paths are sorted from level 0 to the hightest level of the Merkle tree, and nodes at the
-    /// same level are sorted from left to right.
-    ///
-    /// Let's consider some examples. Suppose the Merkle tree is as follows:
-    ///
-    /// Root              ABCD
-    ///                  /    \
-    /// Level 1         AB    CD
-    ///                /  \  /  \
-    /// Level 0       A   B C    D
-    /// Leaf index    0   1 2    3
-    ///
-    /// All authentication paths are `pA = [B, CD]`, `pB = [A, CD]`, `pC = [D, AB]` and `pD = [C, AB]`.
-    ///
-    /// (1) `merge_authentication_paths([pA, pB], [0, 1]) = [CD]`.
-    /// (2) `merge_authentication_paths([pC, pA], [2, 0]) = [B, D]`.
-    /// (3) `merge_authentication_paths([pA, pD], [0, 3]) = [B, C]`.
-    /// (4) `merge_authentication_paths([pA, pD, pB], [0, 3, 1]) = [C]`.
-    /// (5) `merge_authentication_paths([pA, pB, pC, pD], [0, 1, 2, 3]) = []`.
-    ///
-    /// Input:
-    /// `authentication_paths`: The authentication paths to be merged.
-    /// `leaf_indexes`: The leaf indexes corresponding to the authentication paths.
-    ///
-    /// Output:
-    /// The merged authentication paths
-    fn merge_authentication_paths(
-        authentication_paths: &[&Proof<Commitment>],
-        leaf_indexes: &[usize],
-    ) -> Vec<Commitment> {
-        debug_assert_eq!(leaf_indexes.len(), authentication_paths.len());
-        let mut merkle_tree: HashMap<(usize, usize), Commitment> = HashMap::new();
-        for (index_previous_layer, path) in leaf_indexes.iter().zip(authentication_paths.iter()) {
-            let mut node_index = *index_previous_layer;
-            for (tree_level, node) in path.merkle_path.iter().enumerate() {
-                merkle_tree.insert((tree_level, node_index ^ 1), *node);
-                node_index >>= 1;
-            }
-        }
-
-        let mut result = Vec::new();
-        let mut level_indexes: BTreeSet<usize> = leaf_indexes.iter().copied().collect();
-        let merkle_tree_height = authentication_paths[0].merkle_path.len();
-        for tree_level in 0..merkle_tree_height {
-            for node_index in level_indexes.iter() {
-                let sibling_index = node_index ^ 1;
-                if !level_indexes.contains(&sibling_index) {
-                    let node = &merkle_tree[&(tree_level, sibling_index)];
-                    result.push(*node);
-                }
-            }
-            level_indexes = level_indexes.iter().map(|index| *index >> 1).collect();
-        }
-        result
-    }
     fn get_fri_query_indexes<A>(
         proof: &StarkProof<Stark252PrimeField, Stark252PrimeField>,
         public_inputs: &A::PublicInputs,
@@ -454,7 +361,7 @@ impl StoneCompatibleSerializer {
         let mut transcript = StoneProverTranscript::new(&public_inputs.as_bytes());
         let air = A::new(proof.trace_length, public_inputs, proof_options);
         let domain = Domain::<Stark252PrimeField>::new(&air);
-        let challenges = Verifier::step_1_replay_rounds_and_recover_challenges(
+        let challenges = Verifier::<A>::step_1_replay_rounds_and_recover_challenges(
             &air,
             proof,
             &domain,
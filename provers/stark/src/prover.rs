@@ -3,6 +3,7 @@ use std::marker::PhantomData;
 use std::time::Instant;
 
 use lambdaworks_crypto::fiat_shamir::is_transcript::IsTranscript;
+use lambdaworks_crypto::merkle_tree::proof::MultiProof;
 use lambdaworks_math::fft::cpu::bit_reversing::{in_place_bit_reverse_permute, reverse_index};
 use lambdaworks_math::fft::errors::FFTError;
 
@@ -13,6 +14,7 @@ use lambdaworks_math::{
     polynomial::Polynomial,
 };
 use log::info;
+use sha3::Keccak256;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
@@ -24,7 +26,7 @@ use crate::proof::stark::{DeepPolynomialOpenings, PolynomialOpenings};
 use crate::table::Table;
 use crate::trace::{columns2rows, LDETraceTable};
 
-use super::config::{BatchedMerkleTree, Commitment};
+use super::config::{BatchedMerkleTree, Commitment, IsCommitmentHash};
 use super::constraints::evaluator::ConstraintEvaluator;
 use super::domain::Domain;
 use super::fri::fri_decommit::FriDecommitment;
@@ -34,12 +36,15 @@ use super::proof::stark::{DeepPolynomialOpening, StarkProof};
 use super::trace::TraceTable;
 use super::traits::AIR;
 
-/// A default STARK prover implementing `IsStarkProver`.
-pub struct Prover<A: AIR> {
-    phantom: PhantomData<A>,
+/// A default STARK prover implementing `IsStarkProver`, which commits with the hash `H`.
+pub struct Prover<A: AIR, H = Keccak256> {
+    phantom: PhantomData<(A, H)>,
 }
 
-impl<A: AIR> IsStarkProver<A> for Prover<A> {}
+impl<A: AIR, H> IsStarkProver<A, H> for Prover<A, H> where
+    H: IsCommitmentHash<A::Field> + IsCommitmentHash<A::FieldExtension>
+{
+}
 
 #[derive(Debug)]
 pub enum ProvingError {
@@ -49,39 +54,42 @@ pub enum ProvingError {
 
 /// A container for the intermediate results of the commitments to a trace table, main or auxiliary in case of RAP,
 /// in the first round of the STARK Prove protocol.
-pub struct Round1CommitmentData<F>
+pub struct Round1CommitmentData<F, H = Keccak256>
 where
     F: IsField,
+    H: IsCommitmentHash<F>,
     FieldElement<F>: AsBytes + Send + Sync,
 {
     /// The result of the interpolation of the columns of the trace table.
     pub(crate) trace_polys: Vec<Polynomial<FieldElement<F>>>,
     /// The Merkle trees constructed to obtain the commitment of the entire trace table.
-    pub(crate) lde_trace_merkle_tree: BatchedMerkleTree<F>,
+    pub(crate) lde_trace_merkle_tree: BatchedMerkleTree<F, H>,
     /// The root of the Merkle tree in `lde_trace_merkle_tree`.
     pub(crate) lde_trace_merkle_root: Commitment,
 }
 
 /// A container for the results of the first round of the STARK Prove protocol.
-pub struct Round1<A>
+pub struct Round1<A, H = Keccak256>
 where
     A: AIR,
+    H: IsCommitmentHash<A::Field> + IsCommitmentHash<A::FieldExtension>,
     FieldElement<A::FieldExtension>: AsBytes + Sync + Send,
     FieldElement<A::Field>: AsBytes + Sync + Send,
 {
     /// The table of evaluations over the LDE of the main and auxiliary trace tables.
     pub(crate) lde_trace: LDETraceTable<A::Field, A::FieldExtension>,
     /// The intermediate results of the commitment to the main trace table.
-    pub(crate) main: Round1CommitmentData<A::Field>,
+    pub(crate) main: Round1CommitmentData<A::Field, H>,
     /// The intermediate results of the commitment to the auxiliary trace table in case of RAP.
-    pub(crate) aux: Option<Round1CommitmentData<A::FieldExtension>>,
+    pub(crate) aux: Option<Round1CommitmentData<A::FieldExtension, H>>,
     /// The challenges of the RAP round.
     pub(crate) rap_challenges: Vec<FieldElement<A::FieldExtension>>,
 }
 
-impl<A> Round1<A>
+impl<A, H> Round1<A, H>
 where
     A: AIR,
+    H: IsCommitmentHash<A::Field> + IsCommitmentHash<A::FieldExtension>,
     FieldElement<A::FieldExtension>: AsBytes + Sync + Send,
     FieldElement<A::Field>: AsBytes + Sync + Send,
 {
@@ -105,9 +113,10 @@ where
 }
 
 /// A container for the results of the second round of the STARK Prove protocol.
-pub struct Round2<F>
+pub struct Round2<F, H = Keccak256>
 where
     F: IsField,
+    H: IsCommitmentHash<F>,
     FieldElement<F>: AsBytes + Sync + Send,
 {
     /// The list of polynomials `H₀, ..., Hₙ` such that `H = ∑ᵢXⁱH(Xⁿ)`, where H is the composition polynomial.
@@ -115,7 +124,7 @@ where
     /// Evaluations of the composition polynomial parts over the LDE domain.
     pub(crate) lde_composition_poly_evaluations: Vec<Vec<FieldElement<F>>>,
     /// The Merkle tree built to compute the commitment to the composition polynomial parts.
-    pub(crate) composition_poly_merkle_tree: BatchedMerkleTree<F>,
+    pub(crate) composition_poly_merkle_tree: BatchedMerkleTree<F, H>,
     /// The commitment to the composition polynomial parts.
     pub(crate) composition_poly_root: Commitment,
 }
@@ -134,12 +143,20 @@ pub struct Round4<F: IsSubFieldOf<E>, E: IsField> {
     fri_last_value: FieldElement<E>,
     /// The commitments to the fold polynomials of the inner layers of FRI.
     fri_layers_merkle_roots: Vec<Commitment>,
-    /// The values and proofs of validity of the evaluations of the trace polynomials and the composition polynomials
+    /// The values of the evaluations of the trace polynomials and the composition polynomials
     /// parts at the domain values corresponding to the FRI query challenges and their symmetric counterparts.
     deep_poly_openings: DeepPolynomialOpenings<F, E>,
-    /// The values and proofs of validity of the evaluations of the fold polynomials of the inner
-    /// layers of FRI at the values corresponding to the symmetrics of the FRI query challenges.
+    /// The proofs of validity of the evaluations of the main trace polynomials, for all queries.
+    lde_trace_main_multi_proof: MultiProof<Commitment>,
+    /// The proofs of validity of the evaluations of the auxiliary trace polynomials, for all queries.
+    lde_trace_aux_multi_proof: Option<MultiProof<Commitment>>,
+    /// The proofs of validity of the evaluations of the composition polynomial parts, for all queries.
+    composition_poly_multi_proof: MultiProof<Commitment>,
+    /// The values of the evaluations of the fold polynomials of the inner layers of FRI at the
+    /// values corresponding to the symmetrics of the FRI query challenges.
     query_list: Vec<FriDecommitment<E>>,
+    /// The proofs of validity of the evaluations of each inner layer of FRI, for all queries.
+    fri_layers_multi_proofs: Vec<MultiProof<Commitment>>,
     /// The proof of work nonce.
     nonce: Option<u64>,
 }
@@ -156,6 +173,8 @@ pub fn evaluate_polynomial_on_lde_domain<F, E>(
 where
     F: IsFFTField + IsSubFieldOf<E>,
     E: IsField,
+    FieldElement<F>: Send + Sync,
+    FieldElement<E>: Send + Sync,
 {
     let evaluations = Polynomial::evaluate_offset_fft(p, blowup_factor, Some(domain_size), offset)?;
     let step = evaluations.len() / (domain_size * blowup_factor);
@@ -169,15 +188,19 @@ where
 /// https://lambdaclass.github.io/lambdaworks/starks/protocol.html
 /// The default implementation is complete and is compatible with Stone prover
 /// https://github.com/starkware-libs/stone-prover
-pub trait IsStarkProver<A: AIR> {
+/// The commitments are the roots of Merkle trees built with the hash `H`.
+pub trait IsStarkProver<A: AIR, H = Keccak256>
+where
+    H: IsCommitmentHash<A::Field> + IsCommitmentHash<A::FieldExtension>,
+{
     /// Returns the Merkle tree and the commitment to the vectors `vectors`.
     fn batch_commit_main(
         vectors: &[Vec<FieldElement<A::Field>>],
-    ) -> Option<(BatchedMerkleTree<A::Field>, Commitment)>
+    ) -> Option<(BatchedMerkleTree<A::Field, H>, Commitment)>
     where
         FieldElement<A::Field>: AsBytes + Sync + Send,
     {
-        let tree = BatchedMerkleTree::build(vectors)?;
+        let tree = BatchedMerkleTree::<A::Field, H>::build(vectors)?;
 
         let commitment = tree.root;
         Some((tree, commitment))
@@ -186,12 +209,12 @@ pub trait IsStarkProver<A: AIR> {
     /// Returns the Merkle tree and the commitment to the vectors `vectors`.
     fn batch_commit_extension(
         vectors: &[Vec<FieldElement<A::FieldExtension>>],
-    ) -> Option<(BatchedMerkleTree<A::FieldExtension>, Commitment)>
+    ) -> Option<(BatchedMerkleTree<A::FieldExtension, H>, Commitment)>
     where
         FieldElement<A::Field>: AsBytes + Sync + Send,
         FieldElement<A::FieldExtension>: AsBytes + Sync + Send,
     {
-        let tree = BatchedMerkleTree::build(vectors)?;
+        let tree = BatchedMerkleTree::<A::FieldExtension, H>::build(vectors)?;
 
         let commitment = tree.root;
         Some((tree, commitment))
@@ -212,7 +235,7 @@ pub trait IsStarkProver<A: AIR> {
     ) -> Option<(
         Vec<Polynomial<FieldElement<A::Field>>>,
         Vec<Vec<FieldElement<A::Field>>>,
-        BatchedMerkleTree<A::Field>,
+        BatchedMerkleTree<A::Field, H>,
         Commitment,
     )>
     where
@@ -265,7 +288,7 @@ pub trait IsStarkProver<A: AIR> {
     ) -> Option<(
         Vec<Polynomial<FieldElement<A::FieldExtension>>>,
         Vec<Vec<FieldElement<A::FieldExtension>>>,
-        BatchedMerkleTree<A::FieldExtension>,
+        BatchedMerkleTree<A::FieldExtension, H>,
         Commitment,
     )>
     where
@@ -337,7 +360,7 @@ pub trait IsStarkProver<A: AIR> {
         trace: &mut TraceTable<A::Field, A::FieldExtension>,
         domain: &Domain<A::Field>,
         transcript: &mut impl IsTranscript<A::FieldExtension>,
-    ) -> Result<Round1<A>, ProvingError>
+    ) -> Result<Round1<A, H>, ProvingError>
     where
         FieldElement<A::Field>: AsBytes + Send + Sync,
         A::FieldExtension: IsFFTField,
@@ -349,7 +372,7 @@ pub trait IsStarkProver<A: AIR> {
             return Err(ProvingError::EmptyCommitment);
         };
 
-        let main = Round1CommitmentData::<A::Field> {
+        let main = Round1CommitmentData::<A::Field, H> {
             trace_polys,
             lde_trace_merkle_tree: main_merkle_tree,
             lde_trace_merkle_root: main_merkle_root,
@@ -368,7 +391,7 @@ pub trait IsStarkProver<A: AIR> {
                 return Err(ProvingError::EmptyCommitment);
             };
             let aux_evaluations = aux_trace_polys_evaluations;
-            let aux = Some(Round1CommitmentData::<A::FieldExtension> {
+            let aux = Some(Round1CommitmentData::<A::FieldExtension, H> {
                 trace_polys: aux_trace_polys,
                 lde_trace_merkle_tree: aux_merkle_tree,
                 lde_trace_merkle_root: aux_merkle_root,
@@ -397,7 +420,7 @@ pub trait IsStarkProver<A: AIR> {
     /// composition polynomial.
     fn commit_composition_polynomial(
         lde_composition_poly_parts_evaluations: &[Vec<FieldElement<A::FieldExtension>>],
-    ) -> Option<(BatchedMerkleTree<A::FieldExtension>, Commitment)>
+    ) -> Option<(BatchedMerkleTree<A::FieldExtension, H>, Commitment)>
     where
         FieldElement<A::Field>: AsBytes + Sync + Send,
         FieldElement<A::FieldExtension>: AsBytes + Sync + Send,
@@ -428,10 +451,10 @@ pub trait IsStarkProver<A: AIR> {
     fn round_2_compute_composition_polynomial(
         air: &A,
         domain: &Domain<A::Field>,
-        round_1_result: &Round1<A>,
+        round_1_result: &Round1<A, H>,
         transition_coefficients: &[FieldElement<A::FieldExtension>],
         boundary_coefficients: &[FieldElement<A::FieldExtension>],
-    ) -> Result<Round2<A::FieldExtension>, ProvingError>
+    ) -> Result<Round2<A::FieldExtension, H>, ProvingError>
     where
         A: Send + Sync,
         FieldElement<A::Field>: AsBytes + Send + Sync,
@@ -487,8 +510,8 @@ pub trait IsStarkProver<A: AIR> {
     fn round_3_evaluate_polynomials_in_out_of_domain_element(
         air: &A,
         domain: &Domain<A::Field>,
-        round_1_result: &Round1<A>,
-        round_2_result: &Round2<A::FieldExtension>,
+        round_1_result: &Round1<A, H>,
+        round_2_result: &Round2<A::FieldExtension, H>,
         z: &FieldElement<A::FieldExtension>,
     ) -> Round3<A::FieldExtension>
     where
@@ -536,8 +559,8 @@ pub trait IsStarkProver<A: AIR> {
     fn round_4_compute_and_run_fri_on_the_deep_composition_polynomial(
         air: &A,
         domain: &Domain<A::Field>,
-        round_1_result: &Round1<A>,
-        round_2_result: &Round2<A::FieldExtension>,
+        round_1_result: &Round1<A, H>,
+        round_2_result: &Round2<A::FieldExtension, H>,
         round_3_result: &Round3<A::FieldExtension>,
         z: &FieldElement<A::FieldExtension>,
         transcript: &mut impl IsTranscript<A::FieldExtension>,
@@ -585,7 +608,7 @@ pub trait IsStarkProver<A: AIR> {
         let domain_size = domain.lde_roots_of_unity_coset.len();
 
         // FRI commit and query phases
-        let (fri_last_value, fri_layers) = fri::commit_phase::<A::Field, A::FieldExtension>(
+        let (fri_last_value, fri_layers) = fri::commit_phase::<A::Field, A::FieldExtension, H>(
             domain.root_order as usize,
             deep_composition_poly,
             transcript,
@@ -606,7 +629,8 @@ pub trait IsStarkProver<A: AIR> {
         let number_of_queries = air.options().fri_number_of_queries;
         let iotas = Self::sample_query_indexes(number_of_queries, domain, transcript);
 
-        let query_list = fri::query_phase(&fri_layers, &iotas);
+        let (query_list, fri_layers_multi_proofs) =
+            fri::query_phase::<A::FieldExtension, H>(&fri_layers, &iotas);
 
         let fri_layers_merkle_roots: Vec<_> = fri_layers
             .iter()
@@ -616,11 +640,36 @@ pub trait IsStarkProver<A: AIR> {
         let deep_poly_openings =
             Self::open_deep_composition_poly(domain, round_1_result, round_2_result, &iotas);
 
+        // The trace is opened at `2 * iota` and its symmetric `2 * iota + 1`, while each leaf of
+        // the composition polynomial tree holds both evaluations.
+        let trace_indexes: Vec<usize> = iotas
+            .iter()
+            .flat_map(|iota| [iota * 2, iota * 2 + 1])
+            .collect();
+        let lde_trace_main_multi_proof = round_1_result
+            .main
+            .lde_trace_merkle_tree
+            .get_multi_proof(&trace_indexes)
+            .unwrap();
+        let lde_trace_aux_multi_proof = round_1_result.aux.as_ref().map(|aux| {
+            aux.lde_trace_merkle_tree
+                .get_multi_proof(&trace_indexes)
+                .unwrap()
+        });
+        let composition_poly_multi_proof = round_2_result
+            .composition_poly_merkle_tree
+            .get_multi_proof(&iotas)
+            .unwrap();
+
         Round4 {
             fri_last_value,
             fri_layers_merkle_roots,
             deep_poly_openings,
+            lde_trace_main_multi_proof,
+            lde_trace_aux_multi_proof,
+            composition_poly_multi_proof,
             query_list,
+            fri_layers_multi_proofs,
             nonce,
         }
     }
@@ -642,7 +691,7 @@ pub trait IsStarkProver<A: AIR> {
     #[allow(clippy::too_many_arguments)]
     fn compute_deep_composition_poly(
         trace_polys: &[Polynomial<FieldElement<A::FieldExtension>>],
-        round_2_result: &Round2<A::FieldExtension>,
+        round_2_result: &Round2<A::FieldExtension, H>,
         round_3_result: &Round3<A::FieldExtension>,
         z: &FieldElement<A::FieldExtension>,
         primitive_root: &FieldElement<A::Field>,
@@ -746,11 +795,10 @@ pub trait IsStarkProver<A: AIR> {
         accumulator + trace_int
     }
 
-    /// Computes values and validity proofs of the evaluations of the composition polynomial parts
+    /// Computes the values of the evaluations of the composition polynomial parts
     /// at the domain value corresponding to the FRI query challenge `index` and its symmetric
     /// element.
     fn open_composition_poly(
-        composition_poly_merkle_tree: &BatchedMerkleTree<A::FieldExtension>,
         lde_composition_poly_evaluations: &[Vec<FieldElement<A::FieldExtension>>],
         index: usize,
     ) -> PolynomialOpenings<A::FieldExtension>
@@ -758,10 +806,6 @@ pub trait IsStarkProver<A: AIR> {
         FieldElement<A::Field>: AsBytes + Sync + Send,
         FieldElement<A::FieldExtension>: AsBytes + Sync + Send,
     {
-        let proof = composition_poly_merkle_tree
-            .get_proof_by_pos(index)
-            .unwrap();
-
         let lde_composition_poly_parts_evaluation: Vec<_> = lde_composition_poly_evaluations
             .iter()
             .flat_map(|part| {
@@ -773,8 +817,6 @@ pub trait IsStarkProver<A: AIR> {
             .collect();
 
         PolynomialOpenings {
-            proof: proof.clone(),
-            proof_sym: proof,
             evaluations: lde_composition_poly_parts_evaluation
                 .clone()
                 .into_iter()
@@ -788,12 +830,11 @@ pub trait IsStarkProver<A: AIR> {
         }
     }
 
-    /// Computes values and validity proofs of the evaluations of the trace polynomials
+    /// Computes the values of the evaluations of the trace polynomials
     /// at the domain value corresponding to the FRI query challenge `index` and its symmetric
     /// element.
     fn open_trace_polys<E>(
         domain: &Domain<A::Field>,
-        tree: &BatchedMerkleTree<E>,
         lde_trace: &Table<E>,
         challenge: usize,
     ) -> PolynomialOpenings<E>
@@ -808,8 +849,6 @@ pub trait IsStarkProver<A: AIR> {
         let index = challenge * 2;
         let index_sym = challenge * 2 + 1;
         PolynomialOpenings {
-            proof: tree.get_proof_by_pos(index).unwrap(),
-            proof_sym: tree.get_proof_by_pos(index_sym).unwrap(),
             evaluations: lde_trace
                 .get_row(reverse_index(index, domain_size as u64))
                 .to_vec(),
@@ -822,8 +861,8 @@ pub trait IsStarkProver<A: AIR> {
     /// Open the deep composition polynomial on a list of indexes and their symmetric elements.
     fn open_deep_composition_poly(
         domain: &Domain<A::Field>,
-        round_1_result: &Round1<A>,
-        round_2_result: &Round2<A::FieldExtension>,
+        round_1_result: &Round1<A, H>,
+        round_2_result: &Round2<A::FieldExtension, H>,
         indexes_to_open: &[usize],
     ) -> DeepPolynomialOpenings<A::Field, A::FieldExtension>
     where
@@ -835,21 +874,18 @@ pub trait IsStarkProver<A: AIR> {
         for index in indexes_to_open.iter() {
             let main_trace_opening = Self::open_trace_polys::<A::Field>(
                 domain,
-                &round_1_result.main.lde_trace_merkle_tree,
                 &round_1_result.lde_trace.main_table,
                 *index,
             );
 
             let composition_openings = Self::open_composition_poly(
-                &round_2_result.composition_poly_merkle_tree,
                 &round_2_result.lde_composition_poly_evaluations,
                 *index,
             );
 
-            let aux_trace_polys = round_1_result.aux.as_ref().map(|aux| {
+            let aux_trace_polys = round_1_result.aux.as_ref().map(|_| {
                 Self::open_trace_polys::<A::FieldExtension>(
                     domain,
-                    &aux.lde_trace_merkle_tree,
                     &round_1_result.lde_trace.aux_table,
                     *index,
                 )
@@ -1071,6 +1107,10 @@ pub trait IsStarkProver<A: AIR> {
             // Open(H₁(D_LDE, 𝜐₀), Open(H₂(D_LDE, 𝜐₀), Open(tⱼ(D_LDE), 𝜐₀)
             // Open(H₁(D_LDE, -𝜐ᵢ), Open(H₂(D_LDE, -𝜐ᵢ), Open(tⱼ(D_LDE), -𝜐ᵢ)
             deep_poly_openings: round_4_result.deep_poly_openings,
+            lde_trace_main_multi_proof: round_4_result.lde_trace_main_multi_proof,
+            lde_trace_aux_multi_proof: round_4_result.lde_trace_aux_multi_proof,
+            composition_poly_multi_proof: round_4_result.composition_poly_multi_proof,
+            fri_layers_multi_proofs: round_4_result.fri_layers_multi_proofs,
             // nonce obtained from grinding
             nonce: round_4_result.nonce,
 
@@ -1250,7 +1290,7 @@ mod tests {
 
         let air = Fibonacci2ColsShifted::new(proof.trace_length, &public_inputs, &options);
         let domain = Domain::new(&air);
-        Verifier::step_1_replay_rounds_and_recover_challenges(
+        Verifier::<Fibonacci2ColsShifted<_>>::step_1_replay_rounds_and_recover_challenges(
             &air,
             &proof,
             &domain,
@@ -1473,33 +1513,21 @@ mod tests {
     fn stone_compatibility_case_1_fri_query_phase_trace_terms_authentication_path() {
         let proof = stone_compatibility_case_1_proof();
 
-        // Trace poly auth path level 1
+        // Trace poly auth path level 1. The one of level 0 is the symmetric leaf, which is opened.
         assert_eq!(
-            proof.deep_poly_openings[0]
-                .main_trace_polys
-                .proof
-                .merkle_path[1]
-                .to_vec(),
+            proof.lde_trace_main_multi_proof.nodes[0].to_vec(),
             decode_hex("91b0c0b24b9d00067b0efab50832b76cf97192091624d42b86740666c5d369e6").unwrap()
         );
 
         // Trace poly auth path level 2
         assert_eq!(
-            proof.deep_poly_openings[0]
-                .main_trace_polys
-                .proof
-                .merkle_path[2]
-                .to_vec(),
+            proof.lde_trace_main_multi_proof.nodes[1].to_vec(),
             decode_hex("993b044db22444c0c0ebf1095b9a51faeb001c9b4dea36abe905f7162620dbbd").unwrap()
         );
 
         // Trace poly auth path level 3
         assert_eq!(
-            proof.deep_poly_openings[0]
-                .main_trace_polys
-                .proof
-                .merkle_path[3]
-                .to_vec(),
+            proof.lde_trace_main_multi_proof.nodes[2].to_vec(),
             decode_hex("5017abeca33fa82576b5c5c2c61792693b48c9d4414a407eef66b6029dae07ea").unwrap()
         );
     }
@@ -1530,31 +1558,19 @@ mod tests {
 
         // Composition poly auth path level 0
         assert_eq!(
-            proof.deep_poly_openings[0]
-                .composition_poly
-                .proof
-                .merkle_path[0]
-                .to_vec(),
+            proof.composition_poly_multi_proof.nodes[0].to_vec(),
             decode_hex("403b75a122eaf90a298e5d3db2cc7ca096db478078122379a6e3616e72da7546").unwrap()
         );
 
         // Composition poly auth path level 1
         assert_eq!(
-            proof.deep_poly_openings[0]
-                .composition_poly
-                .proof
-                .merkle_path[1]
-                .to_vec(),
+            proof.composition_poly_multi_proof.nodes[1].to_vec(),
             decode_hex("07950888c0355c204a1e83ecbee77a0a6a89f93d41cc2be6b39ddd1e727cc965").unwrap()
         );
 
         // Composition poly auth path level 2
         assert_eq!(
-            proof.deep_poly_openings[0]
-                .composition_poly
-                .proof
-                .merkle_path[2]
-                .to_vec(),
+            proof.composition_poly_multi_proof.nodes[2].to_vec(),
             decode_hex("58befe2c5de74cc5a002aa82ea219c5b242e761b45fd266eb95521e9f53f44eb").unwrap()
         );
     }
@@ -1567,10 +1583,7 @@ mod tests {
 
         assert_eq!(proof.query_list[0].layers_evaluations_sym.len(), 1);
 
-        assert_eq!(
-            proof.query_list[0].layers_auth_paths[0].merkle_path.len(),
-            2
-        );
+        assert_eq!(proof.fri_layers_multi_proofs[0].nodes.len(), 2);
     }
 
     #[test]
@@ -1591,13 +1604,13 @@ mod tests {
 
         // FRI layer 1 auth path level 0
         assert_eq!(
-            proof.query_list[0].layers_auth_paths[0].merkle_path[0].to_vec(),
+            proof.fri_layers_multi_proofs[0].nodes[0].to_vec(),
             decode_hex("0683622478e9e93cc2d18754872f043619f030b494d7ec8e003b1cbafe83b67b").unwrap()
         );
 
         // FRI layer 1 auth path level 1
         assert_eq!(
-            proof.query_list[0].layers_auth_paths[0].merkle_path[1].to_vec(),
+            proof.fri_layers_multi_proofs[0].nodes[1].to_vec(),
             decode_hex("7985d945abe659a7502698051ec739508ed6bab594984c7f25e095a0a57a2e55").unwrap()
         );
     }
@@ -1647,7 +1660,7 @@ mod tests {
 
         let air = Fibonacci2ColsShifted::new(proof.trace_length, &public_inputs, &options);
         let domain = Domain::new(&air);
-        Verifier::step_1_replay_rounds_and_recover_challenges(
+        Verifier::<Fibonacci2ColsShifted<_>>::step_1_replay_rounds_and_recover_challenges(
             &air,
             &proof,
             &domain,
@@ -1689,7 +1702,7 @@ mod tests {
 
         // FRI layer 7 auth path level 5
         assert_eq!(
-            proof.query_list[0].layers_auth_paths[7].merkle_path[5].to_vec(),
+            proof.fri_layers_multi_proofs[7].nodes[5].to_vec(),
             decode_hex("f12f159b548ca2c571a270870d43e7ec2ead78b3e93b635738c31eb9bcda3dda").unwrap()
         );
     }
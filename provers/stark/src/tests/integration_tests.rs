@@ -1,5 +1,9 @@
+use blake2::Blake2s256;
 #[cfg(not(feature = "metal"))]
 use lambdaworks_crypto::fiat_shamir::default_transcript::DefaultTranscript;
+use lambdaworks_crypto::hash::blake3::Blake3;
+#[cfg(not(feature = "metal"))]
+use lambdaworks_crypto::hash::monolith::MonolithMersenne31;
 use lambdaworks_math::field::{
     element::FieldElement, fields::fft_friendly::stark_252_prime_field::Stark252PrimeField,
 };
@@ -8,6 +12,12 @@ use lambdaworks_math::field::{
 use lambdaworks_math::field::fields::fft_friendly::{
     babybear::Babybear31PrimeField, quartic_babybear::Degree4BabyBearExtensionField,
 };
+#[cfg(not(feature = "metal"))]
+use lambdaworks_math::field::fields::mersenne31::extensions::Degree2ExtensionField;
+#[cfg(not(feature = "metal"))]
+use lambdaworks_math::field::fields::u64_goldilocks_field::{
+    Goldilocks64ExtensionField, Goldilocks64Field,
+};
 
 use crate::{
     examples::{
@@ -59,6 +69,56 @@ fn test_prove_fib() {
     ));
 }
 
+#[test_log::test]
+fn test_prove_fib_with_other_commitment_hashes() {
+    let trace = simple_fibonacci::fibonacci_trace([Felt252::from(1), Felt252::from(1)], 8);
+
+    let proof_options = ProofOptions::default_test_options();
+
+    let pub_inputs = FibonacciPublicInputs {
+        a0: Felt252::one(),
+        a1: Felt252::one(),
+    };
+
+    let proof = Prover::<FibonacciAIR<Stark252PrimeField>, Blake3>::prove(
+        &mut trace.clone(),
+        &pub_inputs,
+        &proof_options,
+        StoneProverTranscript::new(&[]),
+    )
+    .unwrap();
+    assert!(
+        Verifier::<FibonacciAIR<Stark252PrimeField>, Blake3>::verify(
+            &proof,
+            &pub_inputs,
+            &proof_options,
+            StoneProverTranscript::new(&[]),
+        )
+    );
+    assert!(!Verifier::<FibonacciAIR<Stark252PrimeField>>::verify(
+        &proof,
+        &pub_inputs,
+        &proof_options,
+        StoneProverTranscript::new(&[]),
+    ));
+
+    let proof = Prover::<FibonacciAIR<Stark252PrimeField>, Blake2s256>::prove(
+        &mut trace.clone(),
+        &pub_inputs,
+        &proof_options,
+        StoneProverTranscript::new(&[]),
+    )
+    .unwrap();
+    assert!(
+        Verifier::<FibonacciAIR<Stark252PrimeField>, Blake2s256>::verify(
+            &proof,
+            &pub_inputs,
+            &proof_options,
+            StoneProverTranscript::new(&[]),
+        )
+    );
+}
+
 #[test_log::test]
 fn test_prove_simple_periodic_8() {
     let mut trace = simple_periodic_cols::simple_periodic_trace::<Stark252PrimeField>(8);
@@ -218,6 +278,116 @@ fn test_prove_rap_fib() {
     ));
 }
 
+#[test_log::test]
+fn test_prove_rap_fib_rejects_tampered_multi_proofs() {
+    let steps = 16;
+    let mut trace = fibonacci_rap_trace([Felt252::from(1), Felt252::from(1)], steps);
+
+    let proof_options = ProofOptions::default_test_options();
+
+    let pub_inputs = FibonacciRAPPublicInputs {
+        steps,
+        a0: Felt252::one(),
+        a1: Felt252::one(),
+    };
+
+    let mut proof = Prover::<FibonacciRAP<Stark252PrimeField>>::prove(
+        &mut trace,
+        &pub_inputs,
+        &proof_options,
+        StoneProverTranscript::new(&[]),
+    )
+    .unwrap();
+
+    let verify = |proof: &_| {
+        Verifier::<FibonacciRAP<Stark252PrimeField>>::verify(
+            proof,
+            &pub_inputs,
+            &proof_options,
+            StoneProverTranscript::new(&[]),
+        )
+    };
+    assert!(verify(&proof));
+
+    proof.lde_trace_main_multi_proof.nodes[0][0] ^= 1;
+    assert!(!verify(&proof));
+    proof.lde_trace_main_multi_proof.nodes[0][0] ^= 1;
+
+    proof.lde_trace_aux_multi_proof.as_mut().unwrap().nodes[0][0] ^= 1;
+    assert!(!verify(&proof));
+    proof.lde_trace_aux_multi_proof.as_mut().unwrap().nodes[0][0] ^= 1;
+
+    proof.composition_poly_multi_proof.nodes[0][0] ^= 1;
+    assert!(!verify(&proof));
+    proof.composition_poly_multi_proof.nodes[0][0] ^= 1;
+
+    let last_node = proof.fri_layers_multi_proofs[0].nodes.pop().unwrap();
+    assert!(!verify(&proof));
+    proof.fri_layers_multi_proofs[0].nodes.push(last_node);
+
+    assert!(verify(&proof));
+}
+
+#[test_log::test]
+fn test_prove_rap_fib_rejects_multi_proofs_with_a_wrong_depth() {
+    let steps = 16;
+    let mut trace = fibonacci_rap_trace([Felt252::from(1), Felt252::from(1)], steps);
+
+    let proof_options = ProofOptions::default_test_options();
+
+    let pub_inputs = FibonacciRAPPublicInputs {
+        steps,
+        a0: Felt252::one(),
+        a1: Felt252::one(),
+    };
+
+    let mut proof = Prover::<FibonacciRAP<Stark252PrimeField>>::prove(
+        &mut trace,
+        &pub_inputs,
+        &proof_options,
+        StoneProverTranscript::new(&[]),
+    )
+    .unwrap();
+
+    let verify = |proof: &_| {
+        Verifier::<FibonacciRAP<Stark252PrimeField>>::verify(
+            proof,
+            &pub_inputs,
+            &proof_options,
+            StoneProverTranscript::new(&[]),
+        )
+    };
+    assert!(verify(&proof));
+
+    for delta in [1, usize::MAX] {
+        proof.lde_trace_main_multi_proof.depth =
+            proof.lde_trace_main_multi_proof.depth.wrapping_add(delta);
+        assert!(!verify(&proof));
+        proof.lde_trace_main_multi_proof.depth =
+            proof.lde_trace_main_multi_proof.depth.wrapping_sub(delta);
+
+        let aux_multi_proof = proof.lde_trace_aux_multi_proof.as_mut().unwrap();
+        aux_multi_proof.depth = aux_multi_proof.depth.wrapping_add(delta);
+        assert!(!verify(&proof));
+        let aux_multi_proof = proof.lde_trace_aux_multi_proof.as_mut().unwrap();
+        aux_multi_proof.depth = aux_multi_proof.depth.wrapping_sub(delta);
+
+        proof.composition_poly_multi_proof.depth =
+            proof.composition_poly_multi_proof.depth.wrapping_add(delta);
+        assert!(!verify(&proof));
+        proof.composition_poly_multi_proof.depth =
+            proof.composition_poly_multi_proof.depth.wrapping_sub(delta);
+
+        proof.fri_layers_multi_proofs[0].depth =
+            proof.fri_layers_multi_proofs[0].depth.wrapping_add(delta);
+        assert!(!verify(&proof));
+        proof.fri_layers_multi_proofs[0].depth =
+            proof.fri_layers_multi_proofs[0].depth.wrapping_sub(delta);
+    }
+
+    assert!(verify(&proof));
+}
+
 #[test_log::test]
 fn test_prove_dummy() {
     let trace_length = 16;
@@ -357,3 +527,120 @@ fn test_prove_log_read_only_memory() {
         DefaultTranscript::<Degree4BabyBearExtensionField>::new(&[]),
     ));
 }
+
+#[cfg(not(feature = "metal"))]
+#[test_log::test]
+fn test_prove_log_read_only_memory_goldilocks() {
+    let address_col = vec![
+        FieldElement::<Goldilocks64Field>::from(3), // a0
+        FieldElement::<Goldilocks64Field>::from(2), // a1
+        FieldElement::<Goldilocks64Field>::from(2), // a2
+        FieldElement::<Goldilocks64Field>::from(3), // a3
+        FieldElement::<Goldilocks64Field>::from(4), // a4
+        FieldElement::<Goldilocks64Field>::from(5), // a5
+        FieldElement::<Goldilocks64Field>::from(1), // a6
+        FieldElement::<Goldilocks64Field>::from(3), // a7
+    ];
+    let value_col = vec![
+        FieldElement::<Goldilocks64Field>::from(30), // v0
+        FieldElement::<Goldilocks64Field>::from(20), // v1
+        FieldElement::<Goldilocks64Field>::from(20), // v2
+        FieldElement::<Goldilocks64Field>::from(30), // v3
+        FieldElement::<Goldilocks64Field>::from(40), // v4
+        FieldElement::<Goldilocks64Field>::from(50), // v5
+        FieldElement::<Goldilocks64Field>::from(10), // v6
+        FieldElement::<Goldilocks64Field>::from(30), // v7
+    ];
+
+    let pub_inputs = LogReadOnlyPublicInputs {
+        a0: FieldElement::<Goldilocks64Field>::from(3),
+        v0: FieldElement::<Goldilocks64Field>::from(30),
+        a_sorted_0: FieldElement::<Goldilocks64Field>::from(1),
+        v_sorted_0: FieldElement::<Goldilocks64Field>::from(10),
+        m0: FieldElement::<Goldilocks64Field>::from(1),
+    };
+    let mut trace = read_only_logup_trace(address_col, value_col);
+    let proof_options = ProofOptions::default_test_options();
+    let proof = Prover::<LogReadOnlyRAP<Goldilocks64Field, Goldilocks64ExtensionField>>::prove(
+        &mut trace,
+        &pub_inputs,
+        &proof_options,
+        DefaultTranscript::<Goldilocks64ExtensionField>::new(&[]),
+    )
+    .unwrap();
+    assert!(Verifier::<
+        LogReadOnlyRAP<Goldilocks64Field, Goldilocks64ExtensionField>,
+    >::verify(
+        &proof,
+        &pub_inputs,
+        &proof_options,
+        DefaultTranscript::<Goldilocks64ExtensionField>::new(&[]),
+    ));
+}
+
+#[cfg(not(feature = "metal"))]
+#[test_log::test]
+fn test_prove_fib_mersenne31_complex_extension() {
+    type FE = FieldElement<Degree2ExtensionField>;
+
+    let mut trace = simple_fibonacci::fibonacci_trace([FE::one(), FE::one()], 8);
+
+    let proof_options = ProofOptions::default_test_options();
+
+    let pub_inputs = FibonacciPublicInputs {
+        a0: FE::one(),
+        a1: FE::one(),
+    };
+
+    let proof = Prover::<FibonacciAIR<Degree2ExtensionField>>::prove(
+        &mut trace,
+        &pub_inputs,
+        &proof_options,
+        DefaultTranscript::<Degree2ExtensionField>::new(&[]),
+    )
+    .unwrap();
+    assert!(Verifier::<FibonacciAIR<Degree2ExtensionField>>::verify(
+        &proof,
+        &pub_inputs,
+        &proof_options,
+        DefaultTranscript::<Degree2ExtensionField>::new(&[]),
+    ));
+}
+
+#[cfg(not(feature = "metal"))]
+#[test_log::test]
+fn test_prove_fib_mersenne31_complex_extension_with_monolith() {
+    type FE = FieldElement<Degree2ExtensionField>;
+    type Monolith = MonolithMersenne31<16, 5>;
+
+    let mut trace = simple_fibonacci::fibonacci_trace([FE::one(), FE::one()], 8);
+
+    let proof_options = ProofOptions::default_test_options();
+
+    let pub_inputs = FibonacciPublicInputs {
+        a0: FE::one(),
+        a1: FE::one(),
+    };
+
+    let proof = Prover::<FibonacciAIR<Degree2ExtensionField>, Monolith>::prove(
+        &mut trace,
+        &pub_inputs,
+        &proof_options,
+        DefaultTranscript::<Degree2ExtensionField>::new(&[]),
+    )
+    .unwrap();
+    assert!(
+        Verifier::<FibonacciAIR<Degree2ExtensionField>, Monolith>::verify(
+            &proof,
+            &pub_inputs,
+            &proof_options,
+            DefaultTranscript::<Degree2ExtensionField>::new(&[]),
+        )
+    );
+    assert!(!Verifier::<FibonacciAIR<Degree2ExtensionField>>::verify(
+        &proof,
+        &pub_inputs,
+        &proof_options,
+        DefaultTranscript::<Degree2ExtensionField>::new(&[]),
+    ));
+}
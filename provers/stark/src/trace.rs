@@ -183,6 +183,7 @@ where
     pub fn compute_trace_polys_main<S>(&self) -> Vec<Polynomial<FieldElement<F>>>
     where
         S: IsFFTField + IsSubFieldOf<F>,
+        FieldElement<S>: Send + Sync,
         FieldElement<F>: Send + Sync,
     {
         let columns = self.columns_main();
@@ -199,6 +200,7 @@ where
     pub fn compute_trace_polys_aux<S>(&self) -> Vec<Polynomial<FieldElement<E>>>
     where
         S: IsFFTField + IsSubFieldOf<F>,
+        FieldElement<F>: Send + Sync,
         FieldElement<E>: Send + Sync,
     {
         let columns = self.columns_aux();
@@ -1,33 +1,39 @@
 use super::{
-    config::BatchedMerkleTreeBackend,
+    config::{BatchedMerkleTreeBackend, IsCommitmentHash},
     domain::Domain,
     fri::fri_decommit::FriDecommitment,
     grinding,
     proof::{options::ProofOptions, stark::StarkProof},
     traits::{TransitionEvaluationContext, AIR},
 };
-use crate::{config::Commitment, proof::stark::DeepPolynomialOpening};
-use lambdaworks_crypto::{fiat_shamir::is_transcript::IsTranscript, merkle_tree::proof::Proof};
+use crate::config::Commitment;
+use lambdaworks_crypto::{
+    fiat_shamir::is_transcript::IsTranscript, merkle_tree::proof::MultiProof,
+};
 use lambdaworks_math::{
     fft::cpu::bit_reversing::reverse_index,
     field::{
         element::FieldElement,
-        traits::{IsFFTField, IsField, IsSubFieldOf},
+        traits::{IsFFTField, IsField},
     },
     traits::AsBytes,
 };
 #[cfg(not(feature = "test_fiat_shamir"))]
 use log::error;
+use sha3::Keccak256;
 use std::marker::PhantomData;
 #[cfg(feature = "instruments")]
 use std::time::Instant;
 
-/// A default STARK verifier implementing `IsStarkVerifier`.
-pub struct Verifier<A: AIR> {
-    phantom: PhantomData<A>,
+/// A default STARK verifier implementing `IsStarkVerifier`, for proofs committed with the hash `H`.
+pub struct Verifier<A: AIR, H = Keccak256> {
+    phantom: PhantomData<(A, H)>,
 }
 
-impl<A: AIR> IsStarkVerifier<A> for Verifier<A> {}
+impl<A: AIR, H> IsStarkVerifier<A, H> for Verifier<A, H> where
+    H: IsCommitmentHash<A::Field> + IsCommitmentHash<A::FieldExtension>
+{
+}
 
 /// A container holding the complete list of challenges sent to the prover along with the seed used
 /// to validate the proof-of-work nonce.
@@ -56,10 +62,16 @@ where
 }
 
 pub type DeepPolynomialEvaluations<F> = (Vec<FieldElement<F>>, Vec<FieldElement<F>>);
+/// The indexes of the leaves opened in a layer of FRI, and the leaves.
+pub type LayerOpenings<F> = (Vec<usize>, Vec<Vec<FieldElement<F>>>);
 
 /// The functionality of a STARK verifier providing methods to run the STARK Verify protocol
 /// https://lambdaclass.github.io/lambdaworks/starks/protocol.html
-pub trait IsStarkVerifier<A: AIR> {
+/// The commitments are the roots of Merkle trees built with the hash `H`.
+pub trait IsStarkVerifier<A: AIR, H = Keccak256>
+where
+    H: IsCommitmentHash<A::Field> + IsCommitmentHash<A::FieldExtension>,
+{
     fn sample_query_indexes(
         number_of_queries: usize,
         domain: &Domain<A::Field>,
@@ -217,7 +229,10 @@ pub trait IsStarkVerifier<A: AIR> {
         proof: &StarkProof<A::Field, A::FieldExtension>,
         domain: &Domain<A::Field>,
         challenges: &Challenges<A>,
-    ) -> bool {
+    ) -> bool
+    where
+        FieldElement<A::Field>: Send + Sync,
+    {
         let boundary_constraints = air.boundary_constraints(&challenges.rap_challenges);
 
         let trace_length = air.trace_length();
@@ -337,7 +352,9 @@ pub trait IsStarkVerifier<A: AIR> {
             .collect::<Vec<FieldElement<A::Field>>>();
         FieldElement::inplace_batch_inverse(&mut evaluation_point_inverse).unwrap();
 
-        proof
+        let mut layers_openings =
+            vec![(Vec::new(), Vec::new()); proof.fri_layers_merkle_roots.len()];
+        let folding_ok = proof
             .query_list
             .iter()
             .zip(&challenges.iotas)
@@ -352,9 +369,33 @@ pub trait IsStarkVerifier<A: AIR> {
                     eval,
                     &deep_poly_evaluations[i],
                     &deep_poly_evaluations_sym[i],
+                    &mut layers_openings,
                 );
                 result
-            })
+            });
+
+        // Verify the openings of all queries in each layer with its multi-proof. The leaves of the
+        // layer `k` hold pairs of evaluations over a domain `2^(k + 1)` times smaller than the LDE
+        // domain, so its tree is `k + 2` levels shorter than the ones of the trace.
+        let lde_depth = Self::lde_tree_depth(domain);
+        folding_ok
+            && proof.fri_layers_multi_proofs.len() == proof.fri_layers_merkle_roots.len()
+            && proof
+                .fri_layers_merkle_roots
+                .iter()
+                .zip(&proof.fri_layers_multi_proofs)
+                .zip(&layers_openings)
+                .enumerate()
+                .all(|(k, ((merkle_root, multi_proof), (indexes, leaves)))| {
+                    lde_depth >= k + 2
+                        && Self::verify_openings::<A::FieldExtension>(
+                            multi_proof,
+                            lde_depth - k - 2,
+                            merkle_root,
+                            indexes,
+                            leaves,
+                        )
+                })
     }
 
     /// Returns the field element element of the domain `domain` corresponding to the given FRI query index challenge `iota`.
@@ -377,97 +418,134 @@ pub trait IsStarkVerifier<A: AIR> {
         .clone()
     }
 
-    /// Verifies the validity of the opening proof.
-    fn verify_opening<E>(
-        proof: &Proof<Commitment>,
+    /// Returns the height of the Merkle trees of the trace, which have a leaf per element of the
+    /// LDE domain.
+    fn lde_tree_depth(domain: &Domain<A::Field>) -> usize {
+        domain.lde_roots_of_unity_coset.len().trailing_zeros() as usize
+    }
+
+    /// Verifies the validity of the openings of the leaves at `indexes` with their multi-proof.
+    /// The depth of the multi-proof is chosen by the prover, so it is checked against the height
+    /// `depth` of the committed tree. Otherwise, an inner node could be opened as if it were a leaf.
+    fn verify_openings<E>(
+        multi_proof: &MultiProof<Commitment>,
+        depth: usize,
         root: &Commitment,
-        index: usize,
-        value: &[FieldElement<E>],
+        indexes: &[usize],
+        values: &[Vec<FieldElement<E>>],
     ) -> bool
     where
-        FieldElement<A::Field>: AsBytes + Sync + Send,
         FieldElement<E>: AsBytes + Sync + Send,
         E: IsField,
-        A::Field: IsSubFieldOf<E>,
+        H: IsCommitmentHash<E>,
     {
-        proof.verify::<BatchedMerkleTreeBackend<E>>(root, index, &value.to_owned())
+        multi_proof.depth == depth
+            && multi_proof.verify::<BatchedMerkleTreeBackend<E, H>>(root, indexes, values)
     }
 
-    /// Verify opening Open(tⱼ(D_LDE), 𝜐) and Open(tⱼ(D_LDE), -𝜐) for all trace polynomials tⱼ,
-    /// where 𝜐 and -𝜐 are the elements corresponding to the index challenge `iota`.
+    /// Verify openings Open(tⱼ(D_LDE), 𝜐) and Open(tⱼ(D_LDE), -𝜐) for all trace polynomials tⱼ,
+    /// where 𝜐 and -𝜐 are the elements corresponding to each index challenge `iota`.
     fn verify_trace_openings(
         proof: &StarkProof<A::Field, A::FieldExtension>,
-        deep_poly_openings: &DeepPolynomialOpening<A::Field, A::FieldExtension>,
-        iota: usize,
+        domain: &Domain<A::Field>,
+        iotas: &[usize],
     ) -> bool
     where
         FieldElement<A::Field>: AsBytes + Sync + Send,
         FieldElement<A::FieldExtension>: AsBytes + Sync + Send,
     {
-        let index = iota * 2;
-        let index_sym = iota * 2 + 1;
-        let mut result = true;
-
-        result &= Self::verify_opening::<A::Field>(
-            &deep_poly_openings.main_trace_polys.proof,
-            &proof.lde_trace_main_merkle_root,
-            index,
-            &deep_poly_openings.main_trace_polys.evaluations,
-        );
-        result &= Self::verify_opening::<A::Field>(
-            &deep_poly_openings.main_trace_polys.proof_sym,
+        let indexes: Vec<usize> = iotas
+            .iter()
+            .flat_map(|iota| [iota * 2, iota * 2 + 1])
+            .collect();
+        let main_trace_values: Vec<_> = proof
+            .deep_poly_openings
+            .iter()
+            .flat_map(|opening| {
+                [
+                    opening.main_trace_polys.evaluations.clone(),
+                    opening.main_trace_polys.evaluations_sym.clone(),
+                ]
+            })
+            .collect();
+        let depth = Self::lde_tree_depth(domain);
+        let mut result = Self::verify_openings::<A::Field>(
+            &proof.lde_trace_main_multi_proof,
+            depth,
             &proof.lde_trace_main_merkle_root,
-            index_sym,
-            &deep_poly_openings.main_trace_polys.evaluations_sym,
+            &indexes,
+            &main_trace_values,
         );
 
+        let aux_trace_values: Option<Vec<_>> = proof
+            .deep_poly_openings
+            .iter()
+            .map(|opening| {
+                opening.aux_trace_polys.as_ref().map(|aux_trace_polys| {
+                    [
+                        aux_trace_polys.evaluations.clone(),
+                        aux_trace_polys.evaluations_sym.clone(),
+                    ]
+                })
+            })
+            .collect();
         match (
             proof.lde_trace_aux_merkle_root,
-            &deep_poly_openings.aux_trace_polys,
+            &proof.lde_trace_aux_multi_proof,
+            aux_trace_values,
         ) {
-            (None, Some(_)) => result = false,
-            (Some(_), None) => result = false,
-            (Some(aux_root), Some(aux_trace_polys_opening)) => {
-                result &= Self::verify_opening::<A::FieldExtension>(
-                    &aux_trace_polys_opening.proof,
+            (Some(aux_root), Some(aux_multi_proof), Some(aux_trace_values)) => {
+                let aux_trace_values: Vec<_> = aux_trace_values.into_iter().flatten().collect();
+                result &= Self::verify_openings::<A::FieldExtension>(
+                    aux_multi_proof,
+                    depth,
                     &aux_root,
-                    index,
-                    &aux_trace_polys_opening.evaluations,
-                );
-                result &= Self::verify_opening::<A::FieldExtension>(
-                    &aux_trace_polys_opening.proof_sym,
-                    &aux_root,
-                    index_sym,
-                    &aux_trace_polys_opening.evaluations_sym,
+                    &indexes,
+                    &aux_trace_values,
                 );
             }
-            _ => {}
+            (None, None, _)
+                if proof
+                    .deep_poly_openings
+                    .iter()
+                    .all(|opening| opening.aux_trace_polys.is_none()) => {}
+            _ => result = false,
         }
 
         result
     }
 
-    /// Verify opening Open(Hᵢ(D_LDE), 𝜐) and Open(Hᵢ(D_LDE), -𝜐) for all parts Hᵢof the composition
-    /// polynomial, where 𝜐 and -𝜐 are the elements corresponding to the index challenge `iota`.
-    fn verify_composition_poly_opening(
-        deep_poly_openings: &DeepPolynomialOpening<A::Field, A::FieldExtension>,
-        composition_poly_merkle_root: &Commitment,
-        iota: &usize,
+    /// Verify openings Open(Hᵢ(D_LDE), 𝜐) and Open(Hᵢ(D_LDE), -𝜐) for all parts Hᵢof the composition
+    /// polynomial, where 𝜐 and -𝜐 are the elements corresponding to each index challenge `iota`.
+    fn verify_composition_poly_openings(
+        proof: &StarkProof<A::Field, A::FieldExtension>,
+        domain: &Domain<A::Field>,
+        iotas: &[usize],
     ) -> bool
     where
         FieldElement<A::Field>: AsBytes + Sync + Send,
         FieldElement<A::FieldExtension>: AsBytes + Sync + Send,
     {
-        let mut value = deep_poly_openings.composition_poly.evaluations.clone();
-        value.extend_from_slice(&deep_poly_openings.composition_poly.evaluations_sym);
-
-        deep_poly_openings
-            .composition_poly
-            .proof
-            .verify::<BatchedMerkleTreeBackend<A::FieldExtension>>(
-                composition_poly_merkle_root,
-                *iota,
-                &value,
+        let values: Vec<_> = proof
+            .deep_poly_openings
+            .iter()
+            .map(|opening| {
+                let mut value = opening.composition_poly.evaluations.clone();
+                value.extend_from_slice(&opening.composition_poly.evaluations_sym);
+                value
+            })
+            .collect();
+
+        // Each leaf holds the evaluations at an element and its symmetric, so the tree is one level
+        // shorter than the ones of the trace.
+        let lde_depth = Self::lde_tree_depth(domain);
+        lde_depth >= 1
+            && Self::verify_openings::<A::FieldExtension>(
+                &proof.composition_poly_multi_proof,
+                lde_depth - 1,
+                &proof.composition_poly_root,
+                iotas,
+                &values,
             )
     }
 
@@ -476,50 +554,29 @@ pub trait IsStarkVerifier<A: AIR> {
     /// index challenges.
     fn step_4_verify_trace_and_composition_openings(
         proof: &StarkProof<A::Field, A::FieldExtension>,
+        domain: &Domain<A::Field>,
         challenges: &Challenges<A>,
     ) -> bool
     where
         FieldElement<A::Field>: AsBytes + Sync + Send,
         FieldElement<A::FieldExtension>: AsBytes + Sync + Send,
     {
-        challenges.iotas.iter().zip(&proof.deep_poly_openings).fold(
-            true,
-            |mut result, (iota_n, deep_poly_opening)| {
-                result &= Self::verify_composition_poly_opening(
-                    deep_poly_opening,
-                    &proof.composition_poly_root,
-                    iota_n,
-                );
-
-                result &= Self::verify_trace_openings(proof, deep_poly_opening, *iota_n);
-                result
-            },
-        )
+        Self::verify_composition_poly_openings(proof, domain, &challenges.iotas)
+            & Self::verify_trace_openings(proof, domain, &challenges.iotas)
     }
 
-    /// Verifies the openings of a fold polynomial of an inner layer of FRI.
-    fn verify_fri_layer_openings(
-        merkle_root: &Commitment,
-        auth_path_sym: &Proof<Commitment>,
+    /// Returns the leaf of an inner layer of FRI holding the evaluations of its fold polynomial at
+    /// the element corresponding to `iota` and its symmetric.
+    fn fri_layer_leaf(
         evaluation: &FieldElement<A::FieldExtension>,
         evaluation_sym: &FieldElement<A::FieldExtension>,
         iota: usize,
-    ) -> bool
-    where
-        FieldElement<A::Field>: AsBytes + Sync + Send,
-        FieldElement<A::FieldExtension>: AsBytes + Sync + Send,
-    {
-        let evaluations = if iota % 2 == 1 {
+    ) -> Vec<FieldElement<A::FieldExtension>> {
+        if iota % 2 == 1 {
             vec![evaluation_sym.clone(), evaluation.clone()]
         } else {
             vec![evaluation.clone(), evaluation_sym.clone()]
-        };
-
-        auth_path_sym.verify::<BatchedMerkleTreeBackend<A::FieldExtension>>(
-            merkle_root,
-            iota >> 1,
-            &evaluations,
-        )
+        }
     }
 
     /// Verify a single FRI query
@@ -530,6 +587,9 @@ pub trait IsStarkVerifier<A: AIR> {
     /// `evaluation_point_inv`: precomputed value of 𝜐⁻¹.
     /// `deep_composition_evaluation`: precomputed value of p₀(𝜐), where p₀ is the deep composition polynomial.
     /// `deep_composition_evaluation_sym`: precomputed value of p₀(-𝜐), where p₀ is the deep composition polynomial.
+    /// `layers_openings`: the indexes and leaves opened in each layer, to which the ones of this query
+    /// are added. Their validity is checked for all queries at once, with the multi-proof of the layer.
+    #[allow(clippy::too_many_arguments)]
     fn verify_query_and_sym_openings(
         proof: &StarkProof<A::Field, A::FieldExtension>,
         zetas: &[FieldElement<A::FieldExtension>],
@@ -538,6 +598,7 @@ pub trait IsStarkVerifier<A: AIR> {
         evaluation_point_inv: FieldElement<A::Field>,
         deep_composition_evaluation: &FieldElement<A::FieldExtension>,
         deep_composition_evaluation_sym: &FieldElement<A::FieldExtension>,
+        layers_openings: &mut [LayerOpenings<A::FieldExtension>],
     ) -> bool
     where
         FieldElement<A::Field>: AsBytes + Sync + Send,
@@ -559,35 +620,28 @@ pub trait IsStarkVerifier<A: AIR> {
             (p0_eval + p0_eval_sym) + evaluation_point_inv * &zetas[0] * (p0_eval - p0_eval_sym);
         let mut index = iota;
 
-        // For each FRI layer, starting from the layer 1: use the proof to verify the validity of values pᵢ(−𝜐^(2ⁱ)) (given by the prover) and
-        // pᵢ(𝜐^(2ⁱ)) (computed on the previous iteration by the verifier). Then use them to obtain pᵢ₊₁(𝜐^(2ⁱ⁺¹)).
+        // For each FRI layer, starting from the layer 1: collect the values pᵢ(−𝜐^(2ⁱ)) (given by the prover) and
+        // pᵢ(𝜐^(2ⁱ)) (computed on the previous iteration by the verifier), whose validity is verified with the
+        // multi-proof of the layer. Then use them to obtain pᵢ₊₁(𝜐^(2ⁱ⁺¹)).
         // Finally, check that the final value coincides with the given by the prover.
         fri_layers_merkle_roots
             .iter()
             .enumerate()
-            .zip(&fri_decommitment.layers_auth_paths)
+            .zip(layers_openings.iter_mut())
             .zip(&fri_decommitment.layers_evaluations_sym)
             .zip(evaluation_point_vec)
             .fold(
                 true,
-                |result,
-                 (
-                    (((i, merkle_root), auth_path_sym), evaluation_sym),
-                    evaluation_point_inv,
-                )| {
-                    // Verify opening Open(pᵢ(Dₖ), −𝜐^(2ⁱ)) and Open(pᵢ(Dₖ), 𝜐^(2ⁱ)).
+                |result, ((((i, _), (indexes, leaves)), evaluation_sym), evaluation_point_inv)| {
+                    // Opening Open(pᵢ(Dₖ), −𝜐^(2ⁱ)) and Open(pᵢ(Dₖ), 𝜐^(2ⁱ)).
                     // `v` is pᵢ(𝜐^(2ⁱ)).
                     // `evaluation_sym` is pᵢ(−𝜐^(2ⁱ)).
-                    let openings_ok = Self::verify_fri_layer_openings(
-                        merkle_root,
-                        auth_path_sym,
-                        &v,
-                        evaluation_sym,
-                        index,
-                    );
+                    indexes.push(index >> 1);
+                    leaves.push(Self::fri_layer_leaf(&v, evaluation_sym, index));
 
                     // Update `v` with next value pᵢ₊₁(𝜐^(2ⁱ⁺¹)).
-                    v = (&v + evaluation_sym) + evaluation_point_inv * &zetas[i + 1] * (&v - evaluation_sym);
+                    v = (&v + evaluation_sym)
+                        + evaluation_point_inv * &zetas[i + 1] * (&v - evaluation_sym);
 
                     // Update index for next iteration. The index of the squares in the next layer
                     // is obtained by halving the current index. This is due to the bit-reverse
@@ -595,10 +649,10 @@ pub trait IsStarkVerifier<A: AIR> {
                     index >>= 1;
 
                     if i < fri_decommitment.layers_evaluations_sym.len() - 1 {
-                        result & openings_ok
+                        result
                     } else {
                         // Check that final value is the given by the prover
-                        result & (v == proof.fri_last_value) & openings_ok
+                        result & (v == proof.fri_last_value)
                     }
                 },
             )
@@ -798,7 +852,7 @@ pub trait IsStarkVerifier<A: AIR> {
         let timer4 = Instant::now();
 
         #[allow(clippy::let_and_return)]
-        if !Self::step_4_verify_trace_and_composition_openings(proof, &challenges) {
+        if !Self::step_4_verify_trace_and_composition_openings(proof, &domain, &challenges) {
             error!("DEEP Composition Polynomial verification failed");
             return false;
         }